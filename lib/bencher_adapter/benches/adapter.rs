@@ -82,12 +82,95 @@ fn adapter_rust_bench(c: &mut Criterion) {
     });
 }
 
+// Each fixture is repeated until it is at least this many lines long
+const LARGE_LINE_COUNT: usize = 100_000;
+
+// Only the line oriented adapters are included,
+// as the JSON adapters deserialize their input in a single pass.
+const LARGE_FIXTURES: &[(Adapter, &str)] = &[
+    (
+        Adapter::RustBench,
+        include_str!("../tool_output/rust/bench/many.txt"),
+    ),
+    (
+        Adapter::RustCriterion,
+        include_str!("../tool_output/rust/criterion/many.txt"),
+    ),
+    (
+        Adapter::RustIai,
+        include_str!("../tool_output/rust/iai/two.txt"),
+    ),
+    (
+        Adapter::RustIaiCallgrind,
+        include_str!("../tool_output/rust/iai_callgrind/with-ge.txt"),
+    ),
+    (
+        Adapter::CppGoogle,
+        include_str!("../tool_output/cpp/google/two.txt"),
+    ),
+    (
+        Adapter::CppCatch2,
+        include_str!("../tool_output/cpp/catch2/four.txt"),
+    ),
+    (
+        Adapter::GoBench,
+        include_str!("../tool_output/go/bench/five.txt"),
+    ),
+    (
+        Adapter::JsBenchmark,
+        include_str!("../tool_output/js/benchmark/four.txt"),
+    ),
+    (
+        Adapter::JsTime,
+        include_str!("../tool_output/js/time/four.txt"),
+    ),
+    (
+        Adapter::PythonAsv,
+        include_str!("../tool_output/python/asv/six.txt"),
+    ),
+    (
+        Adapter::RubyBenchmark,
+        include_str!("../tool_output/ruby/benchmark/five.txt"),
+    ),
+];
+
+#[allow(clippy::integer_division)]
+fn large_fixture(fixture: &str) -> String {
+    let fixture = if fixture.ends_with('\n') {
+        fixture.to_owned()
+    } else {
+        format!("{fixture}\n")
+    };
+    let repeat = LARGE_LINE_COUNT / fixture.lines().count().max(1) + 1;
+    fixture.repeat(repeat)
+}
+
+fn adapter_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Adapter (100k lines)");
+    group.sample_size(10);
+    for (adapter, fixture) in LARGE_FIXTURES {
+        let input = large_fixture(fixture);
+        let settings = Settings::default();
+        group.bench_function(format!("Adapter::{adapter:?}"), |b| {
+            b.iter(|| adapter.convert(&input, settings));
+        });
+    }
+    // Magic tries each adapter in turn
+    let input = large_fixture(include_str!("../tool_output/rust/criterion/many.txt"));
+    let settings = Settings::default();
+    group.bench_function("Adapter::Magic (Rust Criterion)", |b| {
+        b.iter(|| Adapter::Magic.convert(&input, settings));
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     adapter_magic_json,
     adapter_json,
     adapter_magic_rust,
     adapter_rust,
-    adapter_rust_bench
+    adapter_rust_bench,
+    adapter_large
 );
 criterion_main!(benches);
@@ -1,8 +1,7 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
-    character::complete::{anychar, space0, space1},
+    character::complete::{space0, space1},
     combinator::{eof, map, map_res},
-    multi::many_till,
     sequence::tuple,
     IResult,
 };
@@ -10,7 +9,8 @@ use ordered_float::OrderedFloat;
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_number_as_f64, parse_u64, parse_units, NomError, Units,
+        parse_latency, parse_name_till, parse_number_as_f64, parse_u64, parse_units, NomError,
+        Units,
     },
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
@@ -115,17 +115,16 @@ fn parse_catch2_lines(
 
 fn parse_catch2_prelude_line(input: &str) -> IResult<&str, String> {
     map_res(
-        many_till(anychar, parse_catch2_prelude),
-        |(name_chars, _)| -> Result<String, NomError> { Ok(name_chars.into_iter().collect()) },
+        |input| parse_name_till(input, parse_catch2_prelude),
+        |(name, _)| -> Result<String, NomError> { Ok(name.to_owned()) },
     )(input)
 }
 
 fn parse_catch2_benchmark_time(input: &str) -> IResult<&str, (Option<String>, OrderedFloat<f64>)> {
     map_res(
-        many_till(anychar, parse_catch2_time),
-        |(name_chars, time)| -> Result<(Option<String>, OrderedFloat<f64>), NomError> {
-            let name = name_chars.into_iter().collect::<String>();
-            let name = (!name.is_empty()).then_some(name);
+        |input| parse_name_till(input, parse_catch2_time),
+        |(name, time)| -> Result<(Option<String>, OrderedFloat<f64>), NomError> {
+            let name = (!name.is_empty()).then(|| name.to_owned());
             Ok((name, time))
         },
     )(input)
@@ -166,11 +165,11 @@ fn parse_catch2_time(input: &str) -> IResult<&str, OrderedFloat<f64>> {
     map(
         tuple((
             space1,
-            parse_catch2_duration,
+            parse_latency,
             space1,
-            parse_catch2_duration,
+            parse_latency,
             space1,
-            parse_catch2_duration,
+            parse_latency,
             space0,
             eof,
         )),
@@ -178,15 +177,6 @@ fn parse_catch2_time(input: &str) -> IResult<&str, OrderedFloat<f64>> {
     )(input)
 }
 
-fn parse_catch2_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
-    map_res(
-        tuple((parse_number_as_f64, space1, parse_units)),
-        |(duration, _, units)| -> Result<OrderedFloat<f64>, NomError> {
-            Ok(latency_as_nanos(duration, units))
-        },
-    )(input)
-}
-
 #[cfg(test)]
 pub(crate) mod test_cpp_catch2 {
     use bencher_json::project::report::JsonAverage;
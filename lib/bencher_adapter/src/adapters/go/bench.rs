@@ -1,18 +1,15 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
-    combinator::{eof, map, map_res},
-    multi::many_till,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{eof, map, rest},
     sequence::tuple,
     IResult,
 };
 
 use crate::{
-    adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
-    },
+    adapters::util::{parse_benchmark_name_column, parse_f64, parse_latency, parse_u64},
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
 };
@@ -21,70 +18,72 @@ pub struct AdapterGoBench;
 
 impl Adaptable for AdapterGoBench {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
+
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Mean) | None => {},
+        Some(JsonAverage::Median) => return None,
+    }
 
-        let mut benchmark_metrics = Vec::new();
+    let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_go(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
+    for line in lines {
+        let line = line.as_ref();
+        if let Ok((remainder, benchmark_metric)) = parse_go(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
             }
         }
-
-        AdapterResults::new_latency(benchmark_metrics)
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
-    map_res(
+    map(
         tuple((
-            take_till1(|c| c == ' ' || c == '\t'),
+            parse_benchmark_name_column,
             space1,
             parse_u64,
             space1,
             parse_go_bench,
             alt((
                 map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
+                map(tuple((space1, parse_f64, space1, rest)), |_| ()),
             )),
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
-        },
+        |(benchmark_name, _, _iter, _, json_metric, ())| (benchmark_name, json_metric),
     )(input)
 }
 
 fn parse_go_bench(input: &str) -> IResult<&str, JsonNewMetric> {
-    map_res(
-        tuple((parse_f64, space1, parse_units, tag("/op"))),
-        |(duration, _, units, _)| -> Result<JsonNewMetric, NomError> {
-            let value = latency_as_nanos(duration, units);
-            Ok(JsonNewMetric {
-                value,
-                lower_value: None,
-                upper_value: None,
-            })
-        },
-    )(input)
+    map(tuple((parse_latency, tag("/op"))), |(value, _)| {
+        JsonNewMetric {
+            value,
+            lower_value: None,
+            upper_value: None,
+        }
+    })(input)
 }
 
 #[cfg(test)]
 pub(crate) mod test_go_bench {
-    use bencher_json::{project::report::JsonAverage, JsonNewMetric};
+    use bencher_json::{
+        project::report::{Adapter, JsonAverage},
+        JsonNewMetric,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
         adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
-        AdapterResults, Settings,
+        Adaptable, AdapterResults, Settings,
     };
 
     use super::{parse_go, AdapterGoBench};
@@ -218,6 +217,15 @@ pub(crate) mod test_go_bench {
         validate_adapter_go_bench(&results);
     }
 
+    #[test]
+    fn test_adapter_go_bench_convert() {
+        let contents = std::fs::read_to_string("./tool_output/go/bench/five.txt").unwrap();
+        let results = Adapter::GoBench
+            .convert(&contents, Settings::default())
+            .unwrap();
+        validate_adapter_go_bench(&results);
+    }
+
     pub fn validate_adapter_go_bench(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 5);
 
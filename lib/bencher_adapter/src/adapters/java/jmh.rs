@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
 
 use rust_decimal::Decimal;
@@ -28,7 +30,7 @@ pub struct Jmh(pub Vec<Benchmark>);
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Benchmark {
-    pub benchmark: BenchmarkName,
+    pub benchmark: String,
     pub primary_metric: PrimaryMetric,
     pub secondary_metrics: JsonAny,
 }
@@ -56,10 +58,11 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
         let mut benchmark_metrics = Vec::with_capacity(jmh.0.len());
         for benchmark in jmh.0 {
             let Benchmark {
-                benchmark: benchmark_name,
+                benchmark,
                 primary_metric,
                 ..
             } = benchmark;
+            let benchmark_name: BenchmarkName = decode_scala_name(&benchmark).parse()?;
             let PrimaryMetric {
                 score,
                 score_confidence,
@@ -107,6 +110,73 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
     }
 }
 
+// https://github.com/scala/scala/blob/2.13.x/src/library/scala/reflect/NameTransformer.scala
+const SCALA_OPERATORS: [(&str, char); 18] = [
+    ("$tilde", '~'),
+    ("$eq", '='),
+    ("$less", '<'),
+    ("$greater", '>'),
+    ("$bang", '!'),
+    ("$hash", '#'),
+    ("$percent", '%'),
+    ("$up", '^'),
+    ("$amp", '&'),
+    ("$bar", '|'),
+    ("$times", '*'),
+    ("$div", '/'),
+    ("$plus", '+'),
+    ("$minus", '-'),
+    ("$colon", ':'),
+    ("$bslash", '\\'),
+    ("$qmark", '?'),
+    ("$at", '@'),
+];
+
+/// Scala encodes symbolic and backticked method names in the bytecode,
+/// which JMH then uses as the benchmark name.
+/// Decode them back into the names from the source code,
+/// ie `bench.Seq.$plus$plus` to `bench.Seq.++` and `bench.Seq.my$u0020map` to `bench.Seq.my map`.
+fn decode_scala_name(name: &str) -> Cow<'_, str> {
+    if !name.contains('$') {
+        return Cow::Borrowed(name);
+    }
+
+    let mut decoded = String::with_capacity(name.len());
+    let mut remainder = name;
+    while let Some((prefix, rest)) = remainder
+        .find('$')
+        .and_then(|index| remainder.split_at_checked(index))
+    {
+        decoded.push_str(prefix);
+        if let Some((c, rest)) = decode_scala_unicode(rest).or_else(|| decode_scala_operator(rest))
+        {
+            decoded.push(c);
+            remainder = rest;
+        } else {
+            decoded.push('$');
+            remainder = rest.strip_prefix('$').unwrap_or(rest);
+        }
+    }
+    decoded.push_str(remainder);
+    Cow::Owned(decoded)
+}
+
+fn decode_scala_operator(input: &str) -> Option<(char, &str)> {
+    SCALA_OPERATORS
+        .iter()
+        .find_map(|(code, op)| input.strip_prefix(code).map(|rest| (*op, rest)))
+}
+
+// A Unicode escape, ie `$u0020` for a space
+fn decode_scala_unicode(input: &str) -> Option<(char, &str)> {
+    let (hex, rest) = input.strip_prefix("$u")?.split_at_checked(4)?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let c = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+    Some((c, rest))
+}
+
 #[cfg(test)]
 pub(crate) mod test_java_jmh {
     use bencher_json::project::report::JsonAverage;
@@ -119,7 +189,7 @@ pub(crate) mod test_java_jmh {
         AdapterResults, Settings,
     };
 
-    use super::AdapterJavaJmh;
+    use super::{decode_scala_name, AdapterJavaJmh};
 
     fn convert_java_jmh(suffix: &str) -> AdapterResults {
         let file_path = format!("./tool_output/java/jmh/{suffix}.json");
@@ -158,6 +228,67 @@ pub(crate) mod test_java_jmh {
         );
     }
 
+    #[test]
+    fn test_adapter_java_jmh_minutes() {
+        let results = convert_java_jmh("minutes");
+        assert_eq!(results.inner.len(), 2);
+
+        // Throughput is always operations per second
+        let metrics = results
+            .get("org.example.BatchBenchmark.importRecords")
+            .unwrap();
+        validate_throughput(metrics, 2.0, Some(1.9), Some(2.1));
+
+        let metrics = results
+            .get("org.example.BatchBenchmark.rebuildIndex")
+            .unwrap();
+        validate_latency(
+            metrics,
+            90_000_000_000.0,
+            Some(75_000_000_000.0),
+            Some(105_000_000_000.0),
+        );
+    }
+
+    #[test]
+    fn test_decode_scala_name() {
+        for (index, (expected, input)) in [
+            (
+                "org.openjdk.jmh.samples.JMHSample_01_HelloWorld.wellHelloThere",
+                "org.openjdk.jmh.samples.JMHSample_01_HelloWorld.wellHelloThere",
+            ),
+            ("bench.SeqBench.++", "bench.SeqBench.$plus$plus"),
+            ("bench.SeqBench.:+", "bench.SeqBench.$colon$plus"),
+            (
+                "bench.SeqBench.append one",
+                "bench.SeqBench.append$u0020one",
+            ),
+            (
+                "bench.SeqBench.is-empty?",
+                "bench.SeqBench.is$minusempty$qmark",
+            ),
+            ("bench.SeqBench.$u00", "bench.SeqBench.$u00"),
+            ("bench.SeqBench.$unknown", "bench.SeqBench.$unknown"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(expected, decode_scala_name(input), "#{index}: {input}");
+        }
+    }
+
+    #[test]
+    fn test_adapter_java_jmh_scala() {
+        let results = convert_java_jmh("scala");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench.SeqBench.++").unwrap();
+        validate_latency(metrics, 12.5, Some(12.0), Some(13.0));
+
+        let metrics = results.get("bench.SeqBench.append one").unwrap();
+        validate_latency(metrics, 1_500.0, Some(1_400.0), Some(1_600.0));
+    }
+
     #[test]
     fn test_adapter_java_jmh_average() {
         let file_path = "./tool_output/java/jmh/six.json";
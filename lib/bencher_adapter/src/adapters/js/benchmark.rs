@@ -2,17 +2,16 @@ use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 
 use nom::{
     bytes::complete::tag,
-    character::complete::{anychar, space1},
+    character::complete::space1,
     combinator::{eof, map, map_res},
-    multi::many_till,
     sequence::{delimited, tuple},
     IResult,
 };
 
 use crate::{
     adapters::util::{
-        nom_error, parse_benchmark_name_chars, parse_f64, parse_number_as_f64, parse_u64,
-        throughput_as_secs, NomError, Units,
+        nom_error, parse_benchmark_name, parse_f64, parse_name_till, parse_number_as_f64,
+        parse_u64, throughput_as_secs, NomError, Units,
     },
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
@@ -22,33 +21,42 @@ pub struct AdapterJsBenchmark;
 
 impl Adaptable for AdapterJsBenchmark {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
+
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Median) | None => {},
+        Some(JsonAverage::Mean) => return None,
+    }
 
-        let mut benchmark_metrics = Vec::new();
+    let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_benchmark(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
+    for line in lines {
+        let line = line.as_ref();
+        if let Ok((remainder, benchmark_metric)) = parse_benchmark(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
             }
         }
-
-        AdapterResults::new_throughput(benchmark_metrics)
     }
+
+    AdapterResults::new_throughput(benchmark_metrics)
 }
 
 fn parse_benchmark(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
     map_res(
-        many_till(anychar, parse_benchmark_time),
-        |(name_chars, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            if name_chars.is_empty() {
+        |input| parse_name_till(input, parse_benchmark_time),
+        |(name, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
+            if name.is_empty() {
                 return Err(nom_error(String::new()));
             }
-            let benchmark_name = parse_benchmark_name_chars(&name_chars)?;
+            let benchmark_name = parse_benchmark_name(name)?;
             Ok((benchmark_name, json_metric))
         },
     )(input)
@@ -21,22 +21,31 @@ pub struct AdapterJsTime;
 
 impl Adaptable for AdapterJsTime {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
-            return None;
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
+
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
+        return None;
+    }
 
-        let mut benchmark_metrics = Vec::new();
+    let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_time(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
+    for line in lines {
+        let line = line.as_ref();
+        if let Ok((remainder, benchmark_metric)) = parse_time(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
             }
         }
-
-        AdapterResults::new_latency(benchmark_metrics)
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_time(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
@@ -10,6 +10,7 @@ impl Adaptable for AdapterJson {
 
 #[cfg(test)]
 pub(crate) mod test_json {
+    use bencher_json::project::report::Adapter;
     use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
@@ -17,6 +18,7 @@ pub(crate) mod test_json {
     use crate::{
         adapters::test_util::{convert_file_path, validate_latency},
         results::adapter_results::AdapterResults,
+        AdapterResultsArray, Settings,
     };
 
     fn convert_json(suffix: &str) -> AdapterResults {
@@ -67,4 +69,15 @@ pub(crate) mod test_json {
             assert_eq!(metric.upper_value, None);
         }
     }
+
+    #[test]
+    fn test_adapter_json_convert_error() {
+        let input = "{\n  \"bench\": {\n    \"latency\": oops\n  }\n}";
+        let error =
+            AdapterResultsArray::new(&[input], Adapter::Json, Settings::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to convert results: iteration 0 failed at line 3, column 16 of 5\n  |\n3 |     \"latency\": oops\n  |                ^"
+        );
+    }
 }
@@ -1,28 +1,108 @@
-use crate::{
-    results::adapter_results::AdapterResults, Adaptable, AdapterCSharp, AdapterCpp, AdapterGo,
-    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, AdapterShell,
-    Settings,
-};
+use bencher_json::project::report::{Adapter, JsonAdapterConfidence, JsonAdapterSelection};
+
+use crate::{results::adapter_results::AdapterResults, Adaptable, Settings};
+
+/// The concrete adapters tried by the magic adapter, in order of precedence.
+/// The first adapter able to parse the results is selected.
+pub const MAGIC_ADAPTERS: [Adapter; 20] = [
+    Adapter::Json,
+    Adapter::CSharpDotNet,
+    Adapter::CppCatch2,
+    Adapter::CppGoogle,
+    Adapter::GoBench,
+    Adapter::JavaJmh,
+    Adapter::JsBenchmark,
+    Adapter::JsTime,
+    Adapter::PhpBench,
+    Adapter::PythonAsv,
+    Adapter::PythonPytest,
+    Adapter::RubyBenchmark,
+    Adapter::RustBench,
+    Adapter::RustCriterion,
+    Adapter::RustIai,
+    Adapter::RustIaiCallgrind,
+    Adapter::ScalaScalameter,
+    Adapter::ShellHyperfine,
+    Adapter::ShellMeasureCommand,
+    Adapter::SwiftXctest,
+];
 
 pub struct AdapterMagic;
 
 impl Adaptable for AdapterMagic {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJson::parse(input, settings)
-            .or_else(|| AdapterCSharp::parse(input, settings))
-            .or_else(|| AdapterCpp::parse(input, settings))
-            .or_else(|| AdapterGo::parse(input, settings))
-            .or_else(|| AdapterJava::parse(input, settings))
-            .or_else(|| AdapterJs::parse(input, settings))
-            .or_else(|| AdapterPython::parse(input, settings))
-            .or_else(|| AdapterRuby::parse(input, settings))
-            .or_else(|| AdapterRust::parse(input, settings))
-            .or_else(|| AdapterShell::parse(input, settings))
+        MAGIC_ADAPTERS
+            .iter()
+            .find_map(|adapter| adapter.convert(input, settings))
+    }
+}
+
+impl AdapterMagic {
+    /// Parse the results with every concrete adapter,
+    /// and select the first adapter that is able to parse them.
+    /// The other adapters that were also able to parse the results
+    /// determine how confident the selection is.
+    pub fn select(
+        input: &str,
+        settings: Settings,
+    ) -> Option<(AdapterResults, JsonAdapterSelection)> {
+        let mut parsed = MAGIC_ADAPTERS.iter().filter_map(|&adapter| {
+            adapter
+                .convert(input, settings)
+                .map(|results| (adapter, results))
+        });
+        let (adapter, results) = parsed.next()?;
+
+        let mut confidence = JsonAdapterConfidence::High;
+        let mut alternatives = Vec::new();
+        for (alternative, alternative_results) in parsed {
+            let alternative_confidence = if alternative_results.inner.len() > results.inner.len() {
+                JsonAdapterConfidence::Low
+            } else {
+                JsonAdapterConfidence::Medium
+            };
+            confidence = confidence.min(alternative_confidence);
+            alternatives.push(alternative);
+        }
+
+        Some((
+            results,
+            JsonAdapterSelection {
+                adapter,
+                confidence,
+                alternatives,
+            },
+        ))
+    }
+
+    /// Merge the selections from multiple iterations.
+    /// If the iterations selected different adapters, then confidence is low.
+    pub fn merge(
+        mut selection: JsonAdapterSelection,
+        other: JsonAdapterSelection,
+    ) -> JsonAdapterSelection {
+        if selection.adapter == other.adapter {
+            selection.confidence = selection.confidence.min(other.confidence);
+        } else {
+            selection.confidence = JsonAdapterConfidence::Low;
+            if !selection.alternatives.contains(&other.adapter) {
+                selection.alternatives.push(other.adapter);
+            }
+        }
+        for alternative in other.alternatives {
+            if alternative != selection.adapter && !selection.alternatives.contains(&alternative) {
+                selection.alternatives.push(alternative);
+            }
+        }
+        selection
     }
 }
 
 #[cfg(test)]
 mod test_magic {
+    use bencher_json::project::report::{Adapter, JsonAdapterConfidence};
+    use pretty_assertions::assert_eq;
+
     use super::AdapterMagic;
     use crate::adapters::{
         c_sharp::{dot_net::test_c_sharp_dot_net, AdapterCSharp},
@@ -31,15 +111,19 @@ mod test_magic {
         java::jmh::test_java_jmh,
         js::{benchmark::test_js_benchmark, time::test_js_time},
         json::test_json,
+        php::bench::test_php_bench,
         python::{asv::test_python_asv, pytest::test_python_pytest},
         ruby::benchmark::test_ruby_benchmark,
         rust::{
             bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
             iai_callgrind::test_rust_iai_callgrind,
         },
-        shell::hyperfine::test_shell_hyperfine,
+        scala::scalameter::test_scala_scalameter,
+        shell::{hyperfine::test_shell_hyperfine, measure_command::test_shell_measure_command},
+        swift::xctest::test_swift_xctest,
         test_util::convert_file_path,
     };
+    use crate::Settings;
 
     #[test]
     fn test_adapter_magic_json_latency() {
@@ -83,6 +167,22 @@ mod test_magic {
         test_java_jmh::validate_adapter_java_jmh(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_select_java_jmh() {
+        let input = std::fs::read_to_string("./tool_output/java/jmh/six.json").unwrap();
+        let (results, selection) = AdapterMagic::select(&input, Settings::default()).unwrap();
+        test_java_jmh::validate_adapter_java_jmh(&results);
+        assert_eq!(selection.adapter, Adapter::JavaJmh);
+        assert_eq!(selection.confidence, JsonAdapterConfidence::High);
+        assert!(selection.alternatives.is_empty());
+    }
+
+    #[test]
+    fn test_adapter_magic_select_none() {
+        assert!(AdapterMagic::select("", Settings::default()).is_none());
+        assert!(AdapterMagic::select("no benchmarks here", Settings::default()).is_none());
+    }
+
     #[test]
     fn test_adapter_magic_js_benchmark() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/js/benchmark/four.txt");
@@ -95,6 +195,12 @@ mod test_magic {
         test_js_time::validate_adapter_js_time(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_php_bench() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/php/bench/three.json");
+        test_php_bench::validate_adapter_php_bench(&results);
+    }
+
     #[test]
     fn test_adapter_python_asv() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/python/asv/six.txt");
@@ -143,9 +249,28 @@ mod test_magic {
         );
     }
 
+    #[test]
+    fn test_adapter_magic_scala_scalameter() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/scala/scalameter/three.txt");
+        test_scala_scalameter::validate_adapter_scala_scalameter(&results);
+    }
+
     #[test]
     fn test_adapter_magic_shell_hyperfine() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_magic_shell_measure_command() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/shell/measure_command/two.json");
+        test_shell_measure_command::validate_adapter_shell_measure_command(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_swift_xctest() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/swift/xctest/three.txt");
+        test_swift_xctest::validate_adapter_swift_xctest(&results);
+    }
 }
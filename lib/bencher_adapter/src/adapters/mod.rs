@@ -7,10 +7,13 @@ pub mod java;
 pub mod js;
 pub mod json;
 pub mod magic;
+pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust;
+pub mod scala;
 pub mod shell;
+pub mod swift;
 mod util;
 
 #[allow(dead_code, clippy::print_stdout, clippy::unnecessary_wraps)]
@@ -0,0 +1,190 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterError, Settings,
+};
+
+pub struct AdapterPhpBench;
+
+impl Adaptable for AdapterPhpBench {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<PhpBench>(input)
+            .ok()?
+            .convert(settings)
+            .ok()?
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PhpBench {
+    pub suites: Vec<Suite>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Suite {
+    pub benchmarks: Vec<Benchmark>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Benchmark {
+    pub class: String,
+    pub subjects: Vec<Subject>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Subject {
+    pub name: String,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Variant {
+    pub parameter_set: Option<String>,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stats {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub mean: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub mode: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub stdev: Decimal,
+}
+
+impl PhpBench {
+    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::new();
+        for suite in self.suites {
+            for benchmark in suite.benchmarks {
+                for subject in benchmark.subjects {
+                    for variant in subject.variants {
+                        let Variant {
+                            parameter_set,
+                            stats,
+                        } = variant;
+                        let Stats { mean, mode, stdev } = stats;
+
+                        // Each parameter set of a subject is its own benchmark,
+                        // ie `App\Bench\HashBench::benchSha1[short]`
+                        let benchmark_name: BenchmarkName =
+                            if let Some(parameter_set) = parameter_set {
+                                format!("{}::{}[{parameter_set}]", benchmark.class, subject.name)
+                            } else {
+                                format!("{}::{}", benchmark.class, subject.name)
+                            }
+                            .parse()?;
+
+                        // JSON output is always in micros
+                        let units = Units::Micro;
+                        // PHPBench does not report a median, so use the mode instead
+                        let average = match settings.average.unwrap_or_default() {
+                            JsonAverage::Mean => mean,
+                            JsonAverage::Median => mode,
+                        };
+                        let value = latency_as_nanos(average, units);
+                        let spread = latency_as_nanos(stdev, units);
+                        let json_metric = JsonNewMetric {
+                            value,
+                            lower_value: Some(value - spread),
+                            upper_value: Some(value + spread),
+                        };
+
+                        benchmark_metrics.push((benchmark_name, json_metric));
+                    }
+                }
+            }
+        }
+
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_php_bench {
+    use bencher_json::project::report::JsonAverage;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+        },
+        AdapterResults, Settings,
+    };
+
+    use super::AdapterPhpBench;
+
+    fn convert_php_bench(suffix: &str) -> AdapterResults {
+        let file_path = file_path(suffix);
+        convert_file_path::<AdapterPhpBench>(&file_path)
+    }
+
+    fn convert_php_bench_median(suffix: &str) -> AdapterResults {
+        let file_path = file_path(suffix);
+        convert_file_path_median::<AdapterPhpBench>(&file_path)
+    }
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/php/bench/{suffix}.json")
+    }
+
+    #[test]
+    fn test_adapter_php_bench_three() {
+        let three = "three";
+        let file_path = file_path(three);
+
+        let results = convert_php_bench(three);
+        validate_adapter_php_bench(&results);
+
+        let results = opt_convert_file_path::<AdapterPhpBench>(
+            &file_path,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        )
+        .unwrap();
+        validate_adapter_php_bench(&results);
+    }
+
+    pub fn validate_adapter_php_bench(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("App\\Bench\\HashBench::benchMd5").unwrap();
+        validate_latency(metrics, 1_250.0, Some(1_200.0), Some(1_300.0));
+
+        let metrics = results
+            .get("App\\Bench\\HashBench::benchSha1[short]")
+            .unwrap();
+        validate_latency(metrics, 2_500.0, Some(2_400.0), Some(2_600.0));
+
+        let metrics = results
+            .get("App\\Bench\\HashBench::benchSha1[long]")
+            .unwrap();
+        validate_latency(metrics, 10_500.0, Some(10_250.0), Some(10_750.0));
+    }
+
+    #[test]
+    fn test_adapter_php_bench_three_median() {
+        let results = convert_php_bench_median("three");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("App\\Bench\\HashBench::benchMd5").unwrap();
+        validate_latency(metrics, 1_200.0, Some(1_150.0), Some(1_250.0));
+
+        let metrics = results
+            .get("App\\Bench\\HashBench::benchSha1[short]")
+            .unwrap();
+        validate_latency(metrics, 2_400.0, Some(2_300.0), Some(2_500.0));
+
+        let metrics = results
+            .get("App\\Bench\\HashBench::benchSha1[long]")
+            .unwrap();
+        validate_latency(metrics, 10_000.0, Some(9_750.0), Some(10_250.0));
+    }
+}
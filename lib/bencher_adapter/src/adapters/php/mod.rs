@@ -0,0 +1,24 @@
+pub mod bench;
+
+use crate::{Adaptable, AdapterResults, Settings};
+use bench::AdapterPhpBench;
+
+pub struct AdapterPhp;
+
+impl Adaptable for AdapterPhp {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        AdapterPhpBench::parse(input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_php {
+    use super::AdapterPhp;
+    use crate::adapters::{php::bench::test_php_bench, test_util::convert_file_path};
+
+    #[test]
+    fn test_adapter_php_bench() {
+        let results = convert_file_path::<AdapterPhp>("./tool_output/php/bench/three.json");
+        test_php_bench::validate_adapter_php_bench(&results);
+    }
+}
@@ -1,16 +1,14 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
-    bytes::complete::{tag, take_until1, take_while1},
+    bytes::complete::{tag, take_while1},
     character::complete::{space0, space1},
-    combinator::{eof, map, map_res},
+    combinator::{eof, map},
     sequence::{delimited, tuple},
     IResult,
 };
 
 use crate::{
-    adapters::util::{
-        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
-    },
+    adapters::util::{latency_as_nanos, parse_benchmark_name_column, parse_f64, parse_units},
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
 };
@@ -19,27 +17,36 @@ pub struct AdapterPythonAsv;
 
 impl Adaptable for AdapterPythonAsv {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
 
-        let mut benchmark_metrics = Vec::new();
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Median) | None => {},
+        Some(JsonAverage::Mean) => return None,
+    }
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_asv(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
+    let mut benchmark_metrics = Vec::new();
+
+    for line in lines {
+        let line = line.as_ref();
+        if let Ok((remainder, benchmark_metric)) = parse_asv(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
             }
         }
-
-        AdapterResults::new_latency(benchmark_metrics)
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_asv(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
-    map_res(
+    map(
         tuple((
             tuple((
                 delimited(tag("["), tuple((space0, parse_f64, tag("%"))), tag("]")),
@@ -47,17 +54,11 @@ fn parse_asv(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
                 take_while1(|c| c == '·'),
                 space1,
             )),
-            take_until1(" "),
+            parse_benchmark_name_column,
             space1,
             parse_asv_time,
         )),
-        |(_, name, _, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            if name.is_empty() {
-                return Err(nom_error(String::new()));
-            }
-            let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
-        },
+        |(_, benchmark_name, _, json_metric)| (benchmark_name, json_metric),
     )(input)
 }
 
@@ -42,13 +42,13 @@ pub struct Benchmark {
 #[serde(rename_all = "snake_case")]
 pub struct Stats {
     #[serde(with = "rust_decimal::serde::float")]
-    pub mean: Decimal,
+    pub min: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
-    pub stddev: Decimal,
+    pub max: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
-    pub median: Decimal,
+    pub mean: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
-    pub iqr: Decimal,
+    pub median: Decimal,
 }
 
 impl Pytest {
@@ -62,24 +62,23 @@ impl Pytest {
                 stats,
             } = benchmark;
             let Stats {
+                min,
+                max,
                 mean,
-                stddev,
                 median,
-                iqr,
             } = stats;
 
             // JSON output is always in seconds
             let units = Units::Sec;
-            let (average, spread) = match settings.average.unwrap_or_default() {
-                JsonAverage::Mean => (mean, stddev),
-                JsonAverage::Median => (median, iqr),
+            let average = match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => mean,
+                JsonAverage::Median => median,
             };
-            let value = latency_as_nanos(average, units);
-            let spread = latency_as_nanos(spread, units);
+            // The lower and upper values are the fastest and slowest rounds
             let json_metric = JsonNewMetric {
-                value,
-                lower_value: Some(value - spread),
-                upper_value: Some(value + spread),
+                value: latency_as_nanos(average, units),
+                lower_value: Some(latency_as_nanos(min, units)),
+                upper_value: Some(latency_as_nanos(max, units)),
             };
 
             benchmark_metrics.push((benchmark_name, json_metric));
@@ -126,16 +125,16 @@ pub(crate) mod test_python_pytest {
         validate_latency(
             metrics,
             24_088.681_333_229_408,
-            Some(17_913.591_143_368_08),
-            Some(30_263.771_523_090_734),
+            Some(22_067.000_000_181_026),
+            Some(312_599.999_999_996_2),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
         validate_latency(
             metrics,
             2_985_030.672_661_863,
-            Some(2_810_500.507_247_766),
-            Some(3_159_560.838_075_959_6),
+            Some(2_734_935.999_999_965_5),
+            Some(3_903_139.999_999_805),
         );
     }
 
@@ -148,16 +147,16 @@ pub(crate) mod test_python_pytest {
         validate_latency(
             metrics,
             22_300.000_000_363_696,
-            Some(21_033.000_000_003_63),
-            Some(23_567.000_000_723_76),
+            Some(22_067.000_000_181_026),
+            Some(312_599.999_999_996_2),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
         validate_latency(
             metrics,
             2_960_582.500_000_308_3,
-            Some(2_740_893.500_000_618_4),
-            Some(3_180_271.499_999_998),
+            Some(2_734_935.999_999_965_5),
+            Some(3_903_139.999_999_805),
         );
     }
 
@@ -189,32 +188,32 @@ pub(crate) mod test_python_pytest {
         validate_latency(
             metrics,
             149.956_102_486_288_36,
-            Some(120.604_370_534_148_98),
-            Some(179.307_834_438_427_73),
+            Some(142.209_999_998_144_1),
+            Some(1_708.769_999_999_582),
         );
 
         let metrics = results.get("bench.py::test_sleep_2").unwrap();
         validate_latency(
             metrics,
             2_003_843_046.999_999_8,
-            Some(2_001_965_388.274_841),
-            Some(2_005_720_705.725_158_5),
+            Some(2_001_044_877.0),
+            Some(2_005_173_128.0),
         );
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
         validate_latency(
             metrics,
             28_857.540_124_844_24,
-            Some(23_621.602_642_835_765),
-            Some(34_093.477_606_852_71),
+            Some(27_831.000_000_144_71),
+            Some(190_987.999_999_947_53),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
         validate_latency(
             metrics,
             3_611_916.368_852_473,
-            Some(3_238_118.086_634_651_3),
-            Some(3_985_714.651_070_294_4),
+            Some(3_451_656.000_000_192),
+            Some(5_351_305.000_000_028),
         );
     }
 
@@ -225,32 +224,32 @@ pub(crate) mod test_python_pytest {
         validate_latency(
             metrics,
             143.760_000_002_046_7,
-            Some(143.090_000_004_342_24),
-            Some(144.429_999_999_751_15),
+            Some(142.209_999_998_144_1),
+            Some(1_708.769_999_999_582),
         );
 
         let metrics = results.get("bench.py::test_sleep_2").unwrap();
         validate_latency(
             metrics,
             2_005_124_842.999_999,
-            Some(2_002_304_321.999_998_8),
-            Some(2_007_945_363.999_999_3),
+            Some(2_001_044_877.0),
+            Some(2_005_173_128.0),
         );
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
         validate_latency(
             metrics,
             28_052.999_999_861_328,
-            Some(27_927.999_999_732_834),
-            Some(28_177.999_999_989_82),
+            Some(27_831.000_000_144_71),
+            Some(190_987.999_999_947_53),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
         validate_latency(
             metrics,
             3_471_104.000_000_169,
-            Some(3_369_463.000_000_072),
-            Some(3_572_745.000_000_266),
+            Some(3_451_656.000_000_192),
+            Some(5_351_305.000_000_028),
         );
     }
 }
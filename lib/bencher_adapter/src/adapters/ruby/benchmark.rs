@@ -1,15 +1,16 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     bytes::complete::tag,
-    character::complete::{anychar, space1},
+    character::complete::space1,
     combinator::{eof, map, map_res},
-    multi::many_till,
     sequence::{delimited, tuple},
     IResult,
 };
 
 use crate::{
-    adapters::util::{latency_as_nanos, parse_benchmark_name_chars, parse_f64, NomError, Units},
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name, parse_f64, parse_name_till, NomError, Units,
+    },
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
 };
@@ -18,31 +19,40 @@ pub struct AdapterRubyBenchmark;
 
 impl Adaptable for AdapterRubyBenchmark {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
-            return None;
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
 
-        let mut benchmark_metrics = Vec::new();
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
+        return None;
+    }
 
-        let mut header = false;
-        for line in input.lines() {
-            if !header {
-                header = parse_header(line).is_ok();
-                continue;
-            }
+    let mut benchmark_metrics = Vec::new();
 
-            if let Ok((remainder, benchmark_metric)) = parse_ruby(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                    continue;
-                }
-            }
+    let mut header = false;
+    for line in lines {
+        let line = line.as_ref();
+        if !header {
+            header = parse_header(line).is_ok();
+            continue;
+        }
 
-            header = false;
+        if let Ok((remainder, benchmark_metric)) = parse_ruby(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
+                continue;
+            }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        header = false;
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_header(input: &str) -> IResult<&str, ()> {
@@ -64,9 +74,9 @@ fn parse_header(input: &str) -> IResult<&str, ()> {
 
 fn parse_ruby(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
     map_res(
-        many_till(anychar, parse_ruby_benchmark),
+        |input| parse_name_till(input, parse_ruby_benchmark),
         |(name, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            let benchmark_name = parse_benchmark_name_chars(&name)?;
+            let benchmark_name = parse_benchmark_name(name)?;
             Ok((benchmark_name, json_metric))
         },
     )(input)
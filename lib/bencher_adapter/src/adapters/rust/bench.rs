@@ -1,15 +1,15 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
-    bytes::complete::{tag, take_until1},
+    bytes::complete::tag,
     character::complete::space1,
-    combinator::{eof, map, map_res},
+    combinator::{eof, map},
     sequence::{delimited, tuple},
     IResult,
 };
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_number_as_f64, parse_units, NomError,
+        latency_as_nanos, parse_benchmark_name_column, parse_number_as_f64, parse_units,
     },
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
@@ -19,41 +19,47 @@ pub struct AdapterRustBench;
 
 impl Adaptable for AdapterRustBench {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
 
-        let mut benchmark_metrics = Vec::new();
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Median) | None => {},
+        Some(JsonAverage::Mean) => return None,
+    }
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_cargo(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
+    let mut benchmark_metrics = Vec::new();
+
+    for line in lines {
+        let line = line.as_ref();
+        if let Ok((remainder, benchmark_metric)) = parse_cargo(line) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
             }
         }
-
-        AdapterResults::new_latency(benchmark_metrics)
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_cargo(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
-    map_res(
+    map(
         tuple((
             tag("test"),
             space1,
-            take_until1(" "),
+            parse_benchmark_name_column,
             space1,
             tag("..."),
             space1,
             parse_cargo_bench,
             eof,
         )),
-        |(_, _, name, _, _, _, json_metric, _)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
-        },
+        |(_, _, benchmark_name, _, _, _, json_metric, _)| (benchmark_name, json_metric),
     )(input)
 }
 
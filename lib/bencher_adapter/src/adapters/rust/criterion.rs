@@ -1,18 +1,14 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     bytes::complete::tag,
-    character::complete::{anychar, space1},
+    character::complete::space1,
     combinator::{eof, map, map_res},
-    multi::many_till,
     sequence::{delimited, tuple},
     IResult,
 };
-use ordered_float::OrderedFloat;
 
 use crate::{
-    adapters::util::{
-        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
-    },
+    adapters::util::{nom_error, parse_benchmark_name, parse_latency, parse_name_till, NomError},
     results::adapter_results::AdapterResults,
     Adaptable, Settings,
 };
@@ -21,26 +17,36 @@ pub struct AdapterRustCriterion;
 
 impl Adaptable for AdapterRustCriterion {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
+        parse_lines(input.lines(), settings)
+    }
+}
 
-        let mut benchmark_metrics = Vec::new();
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Mean) | None => {},
+        Some(JsonAverage::Median) => return None,
+    }
 
-        let mut prior_line = None;
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_criterion(prior_line, line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
-            }
+    let mut benchmark_metrics = Vec::new();
 
-            prior_line = Some(line);
+    let mut prior_line: Option<S> = None;
+    for line in lines {
+        if let Ok((remainder, benchmark_metric)) =
+            parse_criterion(prior_line.as_ref().map(AsRef::as_ref), line.as_ref())
+        {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
+            }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        prior_line = Some(line);
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
 }
 
 fn parse_criterion<'i>(
@@ -48,14 +54,14 @@ fn parse_criterion<'i>(
     input: &'i str,
 ) -> IResult<&'i str, (BenchmarkName, JsonNewMetric)> {
     map_res(
-        many_till(anychar, parse_criterion_time),
-        |(name_chars, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
-            let name: String = if name_chars.is_empty() {
-                prior_line.ok_or_else(|| nom_error(String::new()))?.into()
+        |input| parse_name_till(input, parse_criterion_time),
+        |(name, json_metric)| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
+            let name = if name.is_empty() {
+                prior_line.ok_or_else(|| nom_error(String::new()))?
             } else {
-                name_chars.into_iter().collect()
+                name
             };
-            let benchmark_name = parse_benchmark_name(&name)?;
+            let benchmark_name = parse_benchmark_name(name)?;
             Ok((benchmark_name, json_metric))
         },
     )(input)
@@ -76,13 +82,7 @@ fn parse_criterion_metric(input: &str) -> IResult<&str, JsonNewMetric> {
     map(
         delimited(
             tag("["),
-            tuple((
-                parse_criterion_duration,
-                space1,
-                parse_criterion_duration,
-                space1,
-                parse_criterion_duration,
-            )),
+            tuple((parse_latency, space1, parse_latency, space1, parse_latency)),
             tag("]"),
         ),
         |(lower_value, _, value, _, upper_value)| JsonNewMetric {
@@ -93,16 +93,12 @@ fn parse_criterion_metric(input: &str) -> IResult<&str, JsonNewMetric> {
     )(input)
 }
 
-fn parse_criterion_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
-    map(
-        tuple((parse_f64, space1, parse_units)),
-        |(duration, _, units)| latency_as_nanos(duration, units),
-    )(input)
-}
-
 #[cfg(test)]
 pub(crate) mod test_rust_criterion {
-    use bencher_json::{project::report::JsonAverage, JsonNewMetric};
+    use bencher_json::{
+        project::report::{Adapter, JsonAverage},
+        JsonNewMetric,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
@@ -257,4 +253,50 @@ pub(crate) mod test_rust_criterion {
         let metrics = results.get("Adapter::Rust").unwrap();
         validate_latency(metrics, 14884.0, Some(14881.0), Some(14887.0));
     }
+
+    #[test]
+    fn test_adapter_rust_criterion_ansi() {
+        let contents = std::fs::read_to_string("./tool_output/rust/criterion/ansi.txt").unwrap();
+        assert!(AdapterRustCriterion::parse(&contents, Settings::default()).is_none());
+
+        let results = Adapter::RustCriterion
+            .convert(&contents, Settings::default())
+            .unwrap();
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("Adapter::Magic (JSON)").unwrap();
+        validate_latency(
+            metrics,
+            3_463.200_000_000_000_3,
+            Some(3_462.299_999_999_999_7),
+            Some(3_464.100_000_000_000_3),
+        );
+
+        let metrics = results.get("Adapter::Json").unwrap();
+        validate_latency(metrics, 3479.6, Some(3_479.299_999_999_999_7), Some(3480.0));
+    }
+
+    // The performance budget is only meaningful for an optimized build:
+    // `cargo test --release --package bencher_adapter -- --ignored performance_budget`
+    #[test]
+    #[ignore]
+    #[allow(clippy::integer_division)]
+    fn test_adapter_rust_criterion_performance_budget() {
+        const LINE_COUNT: usize = 100_000;
+        const BUDGET: std::time::Duration = std::time::Duration::from_millis(250);
+
+        let contents = std::fs::read_to_string("./tool_output/rust/criterion/many.txt").unwrap();
+        let repeat = LINE_COUNT / contents.lines().count() + 1;
+        let contents = contents.repeat(repeat);
+        assert!(contents.lines().count() >= LINE_COUNT);
+
+        let start = std::time::Instant::now();
+        let results = AdapterRustCriterion::parse(&contents, Settings::default());
+        let elapsed = start.elapsed();
+        assert!(results.is_some());
+        assert!(
+            elapsed < BUDGET,
+            "Parsing {LINE_COUNT} lines of Criterion output took {elapsed:?} (budget {BUDGET:?})"
+        );
+    }
 }
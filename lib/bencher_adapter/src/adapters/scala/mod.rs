@@ -0,0 +1,35 @@
+pub mod scalameter;
+
+use crate::{adapters::java::jmh::AdapterJavaJmh, Adaptable, AdapterResults, Settings};
+use scalameter::AdapterScalaScalameter;
+
+pub struct AdapterScala;
+
+impl Adaptable for AdapterScala {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        // Scala benchmarks are often run with JMH through `sbt-jmh`
+        AdapterJavaJmh::parse(input, settings)
+            .or_else(|| AdapterScalaScalameter::parse(input, settings))
+    }
+}
+
+#[cfg(test)]
+mod test_scala {
+    use super::AdapterScala;
+    use crate::adapters::{
+        java::jmh::test_java_jmh, scala::scalameter::test_scala_scalameter,
+        test_util::convert_file_path,
+    };
+
+    #[test]
+    fn test_adapter_scala_jmh() {
+        let results = convert_file_path::<AdapterScala>("./tool_output/java/jmh/six.json");
+        test_java_jmh::validate_adapter_java_jmh(&results);
+    }
+
+    #[test]
+    fn test_adapter_scala_scalameter() {
+        let results = convert_file_path::<AdapterScala>("./tool_output/scala/scalameter/three.txt");
+        test_scala_scalameter::validate_adapter_scala_scalameter(&results);
+    }
+}
@@ -0,0 +1,210 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use nom::{
+    bytes::complete::{tag, take_until},
+    combinator::{eof, map, map_opt, rest},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    adapters::util::{parse_benchmark_name, parse_latency, NomError},
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+// ScalaMeter is usually run as an sbt test framework,
+// which prefixes each line of output with its log level.
+const SBT_INFO: &str = "[info]";
+
+pub struct AdapterScalaScalameter;
+
+impl Adaptable for AdapterScalaScalameter {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        parse_lines(input.lines(), settings)
+    }
+}
+
+/// Parse the output of the `ScalaMeter` `LoggingReporter`.
+/// Each curve starts with a `::Benchmark <scope>::` header,
+/// followed by the machine context and then one line per set of parameters.
+/// The curve ends with an empty line.
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Mean) | None => {},
+        Some(JsonAverage::Median) => return None,
+    }
+
+    let mut benchmark_metrics = Vec::new();
+
+    let mut scope = None;
+    for line in lines {
+        let line = line.as_ref();
+        let line = line.strip_prefix(SBT_INFO).unwrap_or(line).trim();
+        if let Ok((_, benchmark_scope)) = parse_scope(line) {
+            scope = Some(benchmark_scope.to_owned());
+        } else if line.is_empty() {
+            scope = None;
+        } else if let Some(scope) = &scope {
+            if let Ok((_, (params, json_metric))) = parse_measurement(line) {
+                if let Ok(benchmark_name) = to_benchmark_name(scope, params) {
+                    benchmark_metrics.push((benchmark_name, json_metric));
+                }
+            }
+        }
+    }
+
+    AdapterResults::new_latency(benchmark_metrics)
+}
+
+fn parse_scope(input: &str) -> IResult<&str, &str> {
+    map_opt(preceded(tag("::Benchmark "), rest), |scope: &str| {
+        scope.strip_suffix("::")
+    })(input)
+}
+
+fn parse_measurement(input: &str) -> IResult<&str, (&str, JsonNewMetric)> {
+    map(
+        tuple((
+            tag("Parameters("),
+            take_until("): "),
+            tag("): "),
+            parse_latency,
+            eof,
+        )),
+        |(_, params, _, value, _)| {
+            (
+                params,
+                JsonNewMetric {
+                    value,
+                    lower_value: None,
+                    upper_value: None,
+                },
+            )
+        },
+    )(input)
+}
+
+// Include the parameters in the benchmark name, ie `Range.map[size -> 300000]`
+fn to_benchmark_name(scope: &str, params: &str) -> Result<BenchmarkName, NomError> {
+    if params.is_empty() {
+        parse_benchmark_name(scope)
+    } else {
+        parse_benchmark_name(&format!("{scope}[{params}]"))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_scala_scalameter {
+    use bencher_json::{project::report::JsonAverage, JsonNewMetric};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        AdapterResults, Settings,
+    };
+
+    use super::{parse_measurement, parse_scope, AdapterScalaScalameter};
+
+    fn convert_scala_scalameter(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/scala/scalameter/{suffix}.txt");
+        convert_file_path::<AdapterScalaScalameter>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_scope() {
+        assert_eq!(
+            Ok(("", "Range.map")),
+            parse_scope("::Benchmark Range.map::")
+        );
+        assert_eq!(
+            Ok(("", "Collections.List.sum")),
+            parse_scope("::Benchmark Collections.List.sum::")
+        );
+        assert_eq!(true, parse_scope("::Benchmark Range.map").is_err());
+        assert_eq!(true, parse_scope("Range.map::").is_err());
+    }
+
+    #[test]
+    fn test_parse_measurement() {
+        assert_eq!(
+            Ok((
+                "",
+                (
+                    "size -> 300000",
+                    JsonNewMetric {
+                        value: 2_341_626.0.into(),
+                        lower_value: None,
+                        upper_value: None,
+                    }
+                )
+            )),
+            parse_measurement("Parameters(size -> 300000): 2.341626 ms")
+        );
+        assert_eq!(
+            Ok((
+                "",
+                (
+                    "",
+                    JsonNewMetric {
+                        value: 125_000.0.into(),
+                        lower_value: None,
+                        upper_value: None,
+                    }
+                )
+            )),
+            parse_measurement("Parameters(): 0.125 ms")
+        );
+        // Only time measurers are supported
+        assert_eq!(
+            true,
+            parse_measurement("Parameters(size -> 300000): 1024.0 kB").is_err()
+        );
+        assert_eq!(true, parse_measurement("cores: 8").is_err());
+    }
+
+    #[test]
+    fn test_adapter_scala_scalameter_average() {
+        let file_path = "./tool_output/scala/scalameter/three.txt";
+        let results = opt_convert_file_path::<AdapterScalaScalameter>(
+            file_path,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        )
+        .unwrap();
+        validate_adapter_scala_scalameter(&results);
+
+        assert_eq!(
+            None,
+            opt_convert_file_path::<AdapterScalaScalameter>(
+                file_path,
+                Settings {
+                    average: Some(JsonAverage::Median)
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_adapter_scala_scalameter_three() {
+        let results = convert_scala_scalameter("three");
+        validate_adapter_scala_scalameter(&results);
+    }
+
+    pub fn validate_adapter_scala_scalameter(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("Range.map[size -> 300000]").unwrap();
+        validate_latency(metrics, 2_341_626.0, None, None);
+
+        let metrics = results.get("Range.map[size -> 600000]").unwrap();
+        validate_latency(metrics, 4_830_510.0, None, None);
+
+        let metrics = results.get("List.sum").unwrap();
+        validate_latency(metrics, 125_000.0, None, None);
+    }
+}
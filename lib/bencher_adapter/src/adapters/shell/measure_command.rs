@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+// A .NET `TimeSpan` tick is 100 nanoseconds
+// https://learn.microsoft.com/en-us/dotnet/api/system.timespan.ticks
+const NANOS_PER_TICK: u64 = 100;
+
+pub struct AdapterShellMeasureCommand;
+
+impl Adaptable for AdapterShellMeasureCommand {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<MeasureCommand>(input)
+            .ok()?
+            .convert(settings)
+    }
+}
+
+/// A hashtable of `PowerShell` `Measure-Command` results piped to `ConvertTo-Json`,
+/// where each key is the benchmark name and each value is either a single `TimeSpan`
+/// or an array of `TimeSpan`s, one for each iteration.
+/// ie `@{ "build" = (1..10 | ForEach-Object { Measure-Command { ./build.ps1 } }) } | ConvertTo-Json`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct MeasureCommand(pub HashMap<BenchmarkName, TimeSpans>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum TimeSpans {
+    One(TimeSpan),
+    Many(Vec<TimeSpan>),
+}
+
+// https://learn.microsoft.com/en-us/dotnet/api/system.timespan
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeSpan {
+    pub ticks: u64,
+}
+
+impl TimeSpan {
+    fn as_nanos(self) -> OrderedFloat<f64> {
+        latency_as_nanos(self.ticks.saturating_mul(NANOS_PER_TICK), Units::Nano)
+    }
+}
+
+impl MeasureCommand {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::with_capacity(self.0.len());
+        for (benchmark_name, time_spans) in self.0 {
+            let json_metric = match time_spans {
+                TimeSpans::One(time_span) => JsonNewMetric {
+                    value: time_span.as_nanos(),
+                    lower_value: None,
+                    upper_value: None,
+                },
+                TimeSpans::Many(time_spans) => {
+                    let mut times = time_spans
+                        .into_iter()
+                        .map(TimeSpan::as_nanos)
+                        .collect::<Vec<_>>();
+                    times.sort_unstable();
+                    match settings.average.unwrap_or_default() {
+                        JsonAverage::Mean => mean_metric(&times)?,
+                        JsonAverage::Median => median_metric(&times)?,
+                    }
+                },
+            };
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean_metric(times: &[OrderedFloat<f64>]) -> Option<JsonNewMetric> {
+    if times.is_empty() {
+        return None;
+    }
+    let len = times.len() as f64;
+    let mean = times.iter().map(|time| time.into_inner()).sum::<f64>() / len;
+    let (lower_value, upper_value) = if times.len() > 1 {
+        let variance = times
+            .iter()
+            .map(|time| (time.into_inner() - mean).powi(2))
+            .sum::<f64>()
+            / (len - 1.0);
+        let std_dev = variance.sqrt();
+        (Some((mean - std_dev).into()), Some((mean + std_dev).into()))
+    } else {
+        (None, None)
+    };
+    Some(JsonNewMetric {
+        value: mean.into(),
+        lower_value,
+        upper_value,
+    })
+}
+
+// The times must already be sorted
+fn median_metric(times: &[OrderedFloat<f64>]) -> Option<JsonNewMetric> {
+    let (min, max) = (*times.first()?, *times.last()?);
+    let middle = times.len().checked_div(2)?;
+    let median = if times.len() % 2 == 0 {
+        (*times.get(middle - 1)? + *times.get(middle)?) / 2.0
+    } else {
+        *times.get(middle)?
+    };
+    Some(JsonNewMetric {
+        value: median,
+        lower_value: Some(min),
+        upper_value: Some(max),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_measure_command {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::AdapterShellMeasureCommand;
+
+    fn convert_shell_measure_command(suffix: &str) -> AdapterResults {
+        let file_path = file_path(suffix);
+        convert_file_path::<AdapterShellMeasureCommand>(&file_path)
+    }
+
+    fn convert_shell_measure_command_median(suffix: &str) -> AdapterResults {
+        let file_path = file_path(suffix);
+        convert_file_path_median::<AdapterShellMeasureCommand>(&file_path)
+    }
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/shell/measure_command/{suffix}.json")
+    }
+
+    #[test]
+    fn test_adapter_shell_measure_command_one() {
+        let results = convert_shell_measure_command("one");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        validate_latency(metrics, 102_345_600.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_measure_command_two() {
+        let results = convert_shell_measure_command("two");
+        validate_adapter_shell_measure_command(&results);
+    }
+
+    pub fn validate_adapter_shell_measure_command(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        validate_latency(
+            metrics,
+            102_345_600.0,
+            Some(101_234_500.0),
+            Some(103_456_700.0),
+        );
+
+        let metrics = results.get("sleep 0.2").unwrap();
+        validate_latency(
+            metrics,
+            202_000_000.0,
+            Some(199_597_917.570_107_13),
+            Some(204_402_082.429_892_87),
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_measure_command_two_median() {
+        let results = convert_shell_measure_command_median("two");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        validate_latency(
+            metrics,
+            102_345_600.0,
+            Some(101_234_500.0),
+            Some(103_456_700.0),
+        );
+
+        let metrics = results.get("sleep 0.2").unwrap();
+        validate_latency(
+            metrics,
+            201_200_000.0,
+            Some(200_100_000.0),
+            Some(204_700_000.0),
+        );
+    }
+}
@@ -1,24 +1,37 @@
 pub mod hyperfine;
+pub mod measure_command;
 
 use crate::{Adaptable, AdapterResults, Settings};
 use hyperfine::AdapterShellHyperfine;
+use measure_command::AdapterShellMeasureCommand;
 
 pub struct AdapterShell;
 
 impl Adaptable for AdapterShell {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterShellHyperfine::parse(input, settings)
+            .or_else(|| AdapterShellMeasureCommand::parse(input, settings))
     }
 }
 
 #[cfg(test)]
 mod test_shell {
     use super::AdapterShell;
-    use crate::adapters::{shell::hyperfine::test_shell_hyperfine, test_util::convert_file_path};
+    use crate::adapters::{
+        shell::{hyperfine::test_shell_hyperfine, measure_command::test_shell_measure_command},
+        test_util::convert_file_path,
+    };
 
     #[test]
     fn test_adapter_shell_hyperfine() {
         let results = convert_file_path::<AdapterShell>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_shell_measure_command() {
+        let results =
+            convert_file_path::<AdapterShell>("./tool_output/shell/measure_command/two.json");
+        test_shell_measure_command::validate_adapter_shell_measure_command(&results);
+    }
 }
@@ -0,0 +1,24 @@
+pub mod xctest;
+
+use crate::{Adaptable, AdapterResults, Settings};
+use xctest::AdapterSwiftXctest;
+
+pub struct AdapterSwift;
+
+impl Adaptable for AdapterSwift {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        AdapterSwiftXctest::parse(input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_swift {
+    use super::AdapterSwift;
+    use crate::adapters::{swift::xctest::test_swift_xctest, test_util::convert_file_path};
+
+    #[test]
+    fn test_adapter_swift_xctest() {
+        let results = convert_file_path::<AdapterSwift>("./tool_output/swift/xctest/three.txt");
+        test_swift_xctest::validate_adapter_swift_xctest(&results);
+    }
+}
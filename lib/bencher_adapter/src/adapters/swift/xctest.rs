@@ -0,0 +1,213 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until},
+    combinator::{map, map_res},
+    number::complete::double,
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    adapters::util::{latency_as_nanos, parse_benchmark_name, NomError, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+const TEST_CASE: &str = "Test Case '";
+const MEASURED: &str = "' measured [";
+
+pub struct AdapterSwiftXctest;
+
+impl Adaptable for AdapterSwiftXctest {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        parse_lines(input.lines(), settings)
+    }
+}
+
+/// Parse the performance test output of `xcodebuild test` or `swift test`.
+/// Each metric of a `measure` block is reported on its own line,
+/// ie `Test Case '-[MyAppTests.PerformanceTests testSort]' measured [Time, seconds] average: 0.011, relative standard deviation: 5.386%, ...`
+fn parse_lines<I, S>(lines: I, settings: Settings) -> Option<AdapterResults>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match settings.average {
+        Some(JsonAverage::Mean) | None => {},
+        Some(JsonAverage::Median) => return None,
+    }
+
+    let mut benchmark_metrics = Vec::new();
+    for line in lines {
+        if let Ok((_, benchmark_metric)) = parse_xctest(line.as_ref()) {
+            benchmark_metrics.push(benchmark_metric);
+        }
+    }
+
+    AdapterResults::new_latency(benchmark_metrics)
+}
+
+fn parse_xctest(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
+    map(
+        tuple((
+            // Xcode prefixes the line with the source location of the `measure` block
+            take_until(TEST_CASE),
+            tag(TEST_CASE),
+            map_res(take_until(MEASURED), to_benchmark_name),
+            tag(MEASURED),
+            parse_time_metric,
+            tag("] average: "),
+            double,
+            tag(", relative standard deviation: "),
+            double,
+            tag("%"),
+        )),
+        |(_, _, benchmark_name, _, units, _, average, _, relative_std_dev, _)| {
+            let value = latency_as_nanos(average, units);
+            let spread = value * relative_std_dev / 100.0;
+            let json_metric = JsonNewMetric {
+                value,
+                lower_value: Some(value - spread),
+                upper_value: Some(value + spread),
+            };
+            (benchmark_name, json_metric)
+        },
+    )(input)
+}
+
+// Only wall clock time metrics are supported.
+// Other metrics, such as CPU time and memory, are ignored.
+fn parse_time_metric(input: &str) -> IResult<&str, Units> {
+    alt((
+        // `XCTPerformanceMetric_WallClockTime` from `measure(_:)`
+        map(tag("Time, seconds"), |_| Units::Sec),
+        // `XCTClockMetric` from `measure(metrics:block:)`
+        map(tag("Clock Monotonic Time, s"), |_| Units::Sec),
+    ))(input)
+}
+
+// On Apple platforms, the test case is an Objective-C selector,
+// ie `-[MyAppTests.PerformanceTests testSort]` is named `MyAppTests.PerformanceTests.testSort`.
+// On Linux, the test case is already named `PerformanceTests.testSort`.
+fn to_benchmark_name(test_case: &str) -> Result<BenchmarkName, NomError> {
+    if let Some((class, method)) = test_case
+        .strip_prefix("-[")
+        .and_then(|selector| selector.strip_suffix(']'))
+        .and_then(|selector| selector.split_once(' '))
+    {
+        parse_benchmark_name(&format!("{class}.{method}"))
+    } else {
+        parse_benchmark_name(test_case)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_swift_xctest {
+    use bencher_json::project::report::JsonAverage;
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        AdapterResults, Settings,
+    };
+
+    use super::{parse_xctest, AdapterSwiftXctest};
+
+    fn convert_swift_xctest(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/swift/xctest/{suffix}.txt");
+        convert_file_path::<AdapterSwiftXctest>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_xctest() {
+        let (_, (benchmark_name, json_metric)) = parse_xctest(
+            "Test Case 'PerformanceTests.testSort' measured [Time, seconds] average: 0.250, relative standard deviation: 2.000%, values: [0.256112, 0.245901, 0.249874, 0.251230, 0.246883], performanceMetricID:org.swift.XCTPerformanceMetric_WallClockTime",
+        )
+        .unwrap();
+        assert_eq!(benchmark_name.as_ref(), "PerformanceTests.testSort");
+        assert_eq!(json_metric.value, OrderedFloat::from(250_000_000.0));
+        assert_eq!(
+            json_metric.lower_value,
+            Some(OrderedFloat::from(245_000_000.0))
+        );
+        assert_eq!(
+            json_metric.upper_value,
+            Some(OrderedFloat::from(255_000_000.0))
+        );
+
+        // Only time metrics are supported
+        assert_eq!(
+            true,
+            parse_xctest("Test Case '-[MyAppTests.PerformanceTests testSort]' measured [Memory Physical, kB] average: 1638.400, relative standard deviation: 0.000%").is_err()
+        );
+        assert_eq!(
+            true,
+            parse_xctest("Test Case '-[MyAppTests.PerformanceTests testSort]' started.").is_err()
+        );
+    }
+
+    #[test]
+    fn test_adapter_swift_xctest_average() {
+        let file_path = "./tool_output/swift/xctest/three.txt";
+        let results = opt_convert_file_path::<AdapterSwiftXctest>(
+            file_path,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        )
+        .unwrap();
+        validate_adapter_swift_xctest(&results);
+
+        assert_eq!(
+            None,
+            opt_convert_file_path::<AdapterSwiftXctest>(
+                file_path,
+                Settings {
+                    average: Some(JsonAverage::Median)
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_adapter_swift_xctest_three() {
+        let results = convert_swift_xctest("three");
+        validate_adapter_swift_xctest(&results);
+    }
+
+    pub fn validate_adapter_swift_xctest(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results
+            .get("MyAppTests.ImageTests.testResizePerformance")
+            .unwrap();
+        validate_latency(
+            metrics,
+            1_500_000_000.0,
+            Some(1_350_000_000.0),
+            Some(1_650_000_000.0),
+        );
+
+        let metrics = results
+            .get("MyAppTests.PerformanceTests.testParsePerformance")
+            .unwrap();
+        validate_latency(
+            metrics,
+            250_000_000.0,
+            Some(245_000_000.0),
+            Some(255_000_000.0),
+        );
+
+        let metrics = results
+            .get("MyAppTests.PerformanceTests.testSortPerformance")
+            .unwrap();
+        validate_latency(
+            metrics,
+            11_000_000.0,
+            Some(10_407_540.0),
+            Some(11_592_460.0),
+        );
+    }
+}
@@ -3,11 +3,12 @@ use std::{fmt, str::FromStr};
 use bencher_json::BenchmarkName;
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::digit1,
+    bytes::complete::{tag, take_till1},
+    character::complete::{digit1, space1},
     combinator::{map, map_res},
     error::ErrorKind as NomErrorKind,
     multi::fold_many1,
+    sequence::tuple,
     IResult,
 };
 use ordered_float::OrderedFloat;
@@ -92,6 +93,8 @@ pub enum Units {
     Micro,
     Milli,
     Sec,
+    Min,
+    Hour,
 }
 
 impl Units {
@@ -102,6 +105,8 @@ impl Units {
             Self::Micro => 1_000.0,
             Self::Milli => 1_000_000.0,
             Self::Sec => 1_000_000_000.0,
+            Self::Min => 60_000_000_000.0,
+            Self::Hour => 3_600_000_000_000.0,
         }
     }
 
@@ -112,10 +117,20 @@ impl Units {
             Self::Micro => 1.0 / 1_000_000.0,
             Self::Milli => 1.0 / 1_000.0,
             Self::Sec => 1.0,
+            Self::Min => 60.0,
+            Self::Hour => 3_600.0,
         }
     }
 }
 
+/// Parse a number followed by its units, ie `1.23 µs`, as a latency in nanoseconds
+pub fn parse_latency(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((parse_number_as_f64, space1, parse_units)),
+        |(duration, _, units)| latency_as_nanos(duration, units),
+    )(input)
+}
+
 pub fn parse_units(input: &str) -> IResult<&str, Units> {
     alt((
         map(tag("ps"), |_| Units::Pico),
@@ -125,6 +140,9 @@ pub fn parse_units(input: &str) -> IResult<&str, Units> {
         map(tag("us"), |_| Units::Micro),
         map(tag("ms"), |_| Units::Milli),
         map(tag("s"), |_| Units::Sec),
+        // JMH abbreviates minutes and hours, ie `ops/min` and `hr/op`
+        map(tag("min"), |_| Units::Min),
+        map(tag("hr"), |_| Units::Hour),
     ))(input)
 }
 
@@ -238,3 +256,180 @@ pub fn parse_benchmark_name(name: &str) -> Result<BenchmarkName, NomError> {
         Err(nom_error(name))
     }
 }
+
+/// Parse a benchmark name column, which ends at the first space or tab
+pub fn parse_benchmark_name_column(input: &str) -> IResult<&str, BenchmarkName> {
+    map_res(take_till1(|c| c == ' ' || c == '\t'), parse_benchmark_name)(input)
+}
+
+/// Parse a benchmark name followed by a suffix that starts with a space or tab.
+/// This is equivalent to `many_till(anychar, suffix)`,
+/// but the suffix is only tried at the start of each run of spaces and tabs,
+/// and the name is borrowed from the input instead of being collected one `char` at a time.
+pub fn parse_name_till<'i, O, F>(input: &'i str, mut suffix: F) -> IResult<&'i str, (&'i str, O)>
+where
+    F: FnMut(&'i str) -> IResult<&'i str, O>,
+{
+    let mut prior_space = false;
+    for (index, c) in input.char_indices() {
+        let space = c == ' ' || c == '\t';
+        if space && !prior_space {
+            if let Some((name, rest)) = input.split_at_checked(index) {
+                if let Ok((remainder, output)) = suffix(rest) {
+                    return Ok((remainder, (name, output)));
+                }
+            }
+        }
+        prior_space = space;
+    }
+    Err(nom::Err::Error(nom::error::make_error(
+        input,
+        NomErrorKind::ManyTill,
+    )))
+}
+
+#[cfg(test)]
+mod test_util_parsers {
+    use nom::{
+        bytes::complete::tag, character::complete::space1, combinator::eof, sequence::tuple,
+        IResult,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        parse_benchmark_name_column, parse_f64, parse_latency, parse_name_till,
+        parse_number_as_f64, parse_u64, parse_units, Units,
+    };
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_parse_units() {
+        for (index, (expected, input)) in [
+            (1.0e-3, "ps"),
+            (1.0, "ns"),
+            (1.0e3, "μs"),
+            (1.0e3, "µs"),
+            (1.0e3, "us"),
+            (1.0e6, "ms"),
+            (1.0e9, "s"),
+            (6.0e10, "min"),
+            (3.6e12, "hr"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (remainder, units) = parse_units(input).unwrap();
+            assert_eq!("", remainder, "#{index}: {input}");
+            assert_eq!(expected, units.as_nanos(), "#{index}: {input}");
+        }
+
+        for (index, input) in ["", "ks", "NS", " ns"].into_iter().enumerate() {
+            assert_eq!(true, parse_units(input).is_err(), "#{index}: {input}");
+        }
+
+        assert_eq!(true, "ms".parse::<Units>().is_ok());
+        assert_eq!(true, "ms/iter".parse::<Units>().is_err());
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(Ok(("", 1_234)), parse_u64("1,234"));
+        assert_eq!(Ok((" ns", 5_280)), parse_u64("5280 ns"));
+        assert_eq!(true, parse_u64("-1").is_err());
+
+        assert_eq!(Ok(("", 1_234.5)), parse_f64("1,234.5"));
+        assert_eq!(Ok(("", 1.0)), parse_f64("1"));
+        assert_eq!(true, parse_f64("1.2.3").is_err());
+
+        assert_eq!(Ok(("", 1_234.5)), parse_number_as_f64("1,234.5"));
+        assert_eq!(Ok(("", 1_234.0)), parse_number_as_f64("1,234"));
+        assert_eq!(true, parse_number_as_f64("1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_parse_latency() {
+        for (index, (expected, input)) in [
+            (Ok(("", 280.0.into())), "280 ns"),
+            (Ok(("", 1_500.0.into())), "1.5 µs"),
+            (Ok(("", 1_500.0.into())), "1.5 us"),
+            (Ok(("/op", 2_000_000.0.into())), "2 ms/op"),
+            (Ok((" ]", 2_000_000_000.0.into())), "2 s ]"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(expected, parse_latency(input), "#{index}: {input}");
+        }
+
+        for (index, input) in ["", "280", "280ns", "ns 280", "280 ks"]
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(true, parse_latency(input).is_err(), "#{index}: {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_benchmark_name_column() {
+        for (index, (expected_name, expected_remainder, input)) in [
+            ("tests::bench", "", "tests::bench"),
+            ("tests::bench", " ... bench", "tests::bench ... bench"),
+            ("BenchmarkFib10-8", "\t5000", "BenchmarkFib10-8\t5000"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (remainder, name) = parse_benchmark_name_column(input).unwrap();
+            assert_eq!(expected_remainder, remainder, "#{index}: {input}");
+            assert_eq!(expected_name, name.as_ref(), "#{index}: {input}");
+        }
+
+        for (index, input) in ["", " tests::bench", "\ttests::bench"]
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(
+                true,
+                parse_benchmark_name_column(input).is_err(),
+                "#{index}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_name_till() {
+        fn suffix(input: &str) -> IResult<&str, &str> {
+            let (remainder, (_, _, _, value, _)) =
+                tuple((space1, tag("x"), space1, tag("1"), eof))(input)?;
+            Ok((remainder, value))
+        }
+
+        for (index, (expected, input)) in [
+            (Ok(("", ("name", "1"))), "name x 1"),
+            (Ok(("", ("name with spaces", "1"))), "name with spaces x 1"),
+            (Ok(("", ("name", "1"))), "name  \t x   1"),
+            (Ok(("", ("name x 2", "1"))), "name x 2 x 1"),
+            (Ok(("", ("", "1"))), " x 1"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(
+                expected,
+                parse_name_till(input, suffix),
+                "#{index}: {input}"
+            );
+        }
+
+        for (index, input) in ["", "name", "name x 2", "name x 1 trailing"]
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(
+                true,
+                parse_name_till(input, suffix).is_err(),
+                "#{index}: {input}"
+            );
+        }
+    }
+}
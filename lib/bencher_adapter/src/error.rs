@@ -1,5 +1,13 @@
+use std::fmt;
+
+use bencher_json::project::report::Adapter;
 use thiserror::Error;
 
+// The maximum number of characters to show from the offending line
+const EXCERPT_WIDTH: usize = 80;
+// The number of characters to show before the offending column
+const EXCERPT_LEAD: usize = 40;
+
 #[derive(Error, Debug)]
 pub enum AdapterError {
     #[error("Failed to validate: {0}")]
@@ -7,5 +15,186 @@ pub enum AdapterError {
     #[error("Failed to parse benchmark units: {0}")]
     BenchmarkUnits(String),
     #[error("Failed to convert results: {0}")]
-    Convert(String),
+    Convert(ConvertError),
+    #[error("Failed to find benchmark for pair: {0}")]
+    PairBenchmark(bencher_json::BenchmarkName),
+    #[error("Failed to name benchmark pair ({candidate} / {baseline}): {err}")]
+    PairName {
+        baseline: bencher_json::BenchmarkName,
+        candidate: bencher_json::BenchmarkName,
+        err: bencher_json::ValidError,
+    },
+    #[error("Failed to merge runner results: expected {iterations} iteration(s) but found {runner_results}")]
+    RunnerResults {
+        iterations: usize,
+        runner_results: usize,
+    },
+    #[error("Failed to group results: {results} result(s) cannot be split into groups of {results_per_iteration}")]
+    ResultsPerIteration {
+        results: usize,
+        results_per_iteration: usize,
+    },
+}
+
+/// The position of a parsing error in the benchmark harness output
+/// Both the line and column are one-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An adapter that recognized the format of the benchmark harness output
+/// but was still unable to parse any results from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NearMiss {
+    pub adapter: Adapter,
+    /// Where the adapter failed to parse the output.
+    /// If `None`, the output was parsed but it contained no benchmark results.
+    pub location: Option<Location>,
+}
+
+#[derive(Debug)]
+pub struct ConvertError {
+    pub iteration: usize,
+    pub line_count: usize,
+    pub location: Option<Location>,
+    pub excerpt: Option<String>,
+    pub near_misses: Vec<NearMiss>,
+}
+
+impl ConvertError {
+    pub fn new(iteration: usize, input: &str, location: Option<Location>) -> Self {
+        let excerpt = location.and_then(|location| excerpt(input, location));
+        Self {
+            iteration,
+            line_count: input.lines().count(),
+            location,
+            excerpt,
+            near_misses: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_near_misses(mut self, near_misses: Vec<NearMiss>) -> Self {
+        self.near_misses = near_misses;
+        self
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "iteration {}", self.iteration)?;
+        if let Some(Location { line, column }) = self.location {
+            write!(
+                f,
+                " failed at line {line}, column {column} of {}",
+                self.line_count
+            )?;
+            if let Some(excerpt) = &self.excerpt {
+                write!(f, "\n{excerpt}")?;
+            }
+        } else {
+            write!(
+                f,
+                " found no benchmark results in {} line(s) of output",
+                self.line_count
+            )?;
+        }
+        if !self.near_misses.is_empty() {
+            write!(f, "\nNear misses:")?;
+            for NearMiss { adapter, location } in &self.near_misses {
+                if let Some(Location { line, column }) = location {
+                    write!(f, "\n  - {adapter}: failed at line {line}, column {column}")?;
+                } else {
+                    write!(f, "\n  - {adapter}: parsed, but found no benchmark results")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Render the offending line with a caret pointing at the column.
+// Long lines are windowed around the column so the excerpt stays short.
+fn excerpt(input: &str, Location { line, column }: Location) -> Option<String> {
+    let text = input.lines().nth(line.checked_sub(1)?)?;
+    let chars = text.chars().collect::<Vec<_>>();
+    let column = column.saturating_sub(1).min(chars.len());
+    let start = column.saturating_sub(EXCERPT_LEAD);
+    let end = (start + EXCERPT_WIDTH).min(chars.len());
+    let snippet = chars.get(start..end)?.iter().collect::<String>();
+
+    let gutter = line.to_string();
+    let padding = " ".repeat(gutter.len());
+    let caret = " ".repeat(column - start);
+    Some(format!(
+        "{padding} |\n{gutter} | {snippet}\n{padding} | {caret}^"
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::project::report::Adapter;
+    use pretty_assertions::assert_eq;
+
+    use super::{ConvertError, Location, NearMiss};
+
+    #[test]
+    fn test_convert_error_location() {
+        let input = "[\n  {\"benchmark\": \"foo\",\n  oops\n]";
+        let error = ConvertError::new(0, input, Some(Location { line: 3, column: 3 }));
+        assert_eq!(
+            error.to_string(),
+            "iteration 0 failed at line 3, column 3 of 4\n  |\n3 |   oops\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_convert_error_long_line() {
+        let input = format!("{}x{}", "a".repeat(100), "b".repeat(100));
+        let error = ConvertError::new(
+            1,
+            &input,
+            Some(Location {
+                line: 1,
+                column: 101,
+            }),
+        );
+        let excerpt = error.excerpt.unwrap();
+        let mut lines = excerpt.lines();
+        assert_eq!(lines.next(), Some("  |"));
+        let snippet = lines.next().unwrap().trim_start_matches("1 | ");
+        assert_eq!(snippet.len(), 80);
+        let caret = lines.next().unwrap().trim_start_matches("  | ");
+        assert_eq!(snippet.chars().nth(caret.len() - 1), Some('x'));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_convert_error_no_location() {
+        let error = ConvertError::new(2, "one\ntwo\nthree", None);
+        assert_eq!(
+            error.to_string(),
+            "iteration 2 found no benchmark results in 3 line(s) of output"
+        );
+    }
+
+    #[test]
+    fn test_convert_error_near_misses() {
+        let error = ConvertError::new(0, "{}", None).with_near_misses(vec![
+            NearMiss {
+                adapter: Adapter::Json,
+                location: None,
+            },
+            NearMiss {
+                adapter: Adapter::JavaJmh,
+                location: Some(Location { line: 1, column: 1 }),
+            },
+        ]);
+        assert_eq!(
+            error.to_string(),
+            "iteration 0 found no benchmark results in 1 line(s) of output\nNear misses:\n  - json: parsed, but found no benchmark results\n  - java_jmh: failed at line 1, column 1"
+        );
+    }
 }
@@ -0,0 +1,135 @@
+//! Golden output tests for every benchmark harness output sample in `tool_output`.
+//!
+//! Each sample at `tool_output/{language}/{tool}/{file}` is parsed with the adapter for its directory
+//! (ie `tool_output/rust/criterion` uses the `rust_criterion` adapter)
+//! and compared against the golden JSON file at `golden/{language}/{tool}/{file}.json`.
+//! If a golden file does not exist yet, it is created from the current results.
+//! To regenerate all of the golden files, run the tests with `BENCHER_GOLDEN=regen`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bencher_json::project::report::Adapter;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+use crate::{Adaptable, Settings};
+
+const TOOL_OUTPUT_DIR: &str = "tool_output";
+const GOLDEN_DIR: &str = "golden";
+const GOLDEN_ENV: &str = "BENCHER_GOLDEN";
+const GOLDEN_REGEN: &str = "regen";
+
+#[test]
+#[allow(clippy::print_stderr)]
+fn test_golden_outputs() {
+    let regen = std::env::var(GOLDEN_ENV).is_ok_and(|golden| golden == GOLDEN_REGEN);
+
+    let mut samples = Vec::new();
+    find_samples(Path::new(TOOL_OUTPUT_DIR), &mut samples);
+    samples.sort();
+    assert!(!samples.is_empty(), "No samples found in {TOOL_OUTPUT_DIR}");
+
+    for sample in samples {
+        let relative_path = sample
+            .strip_prefix(TOOL_OUTPUT_DIR)
+            .unwrap_or_else(|e| panic!("Invalid sample path {}: {e}", sample.display()));
+        let adapter = sample_adapter(relative_path);
+        let input = fs::read_to_string(&sample)
+            .unwrap_or_else(|e| panic!("Failed to read sample {}: {e}", sample.display()));
+        let results = adapter.convert(&input, Settings::default());
+        let actual = sort_keys(
+            serde_json::to_value(results)
+                .unwrap_or_else(|e| panic!("Failed to serialize {}: {e}", sample.display())),
+        );
+
+        let mut golden_path = Path::new(GOLDEN_DIR).join(relative_path).into_os_string();
+        golden_path.push(".json");
+        let golden_path = PathBuf::from(golden_path);
+        if regen || !golden_path.exists() {
+            write_golden(&golden_path, &actual);
+            eprintln!("Wrote golden output: {}", golden_path.display());
+            continue;
+        }
+
+        // Compare the serialized text, as parsing floats back into a `Value` is not always exact
+        let expected = fs::read_to_string(&golden_path)
+            .unwrap_or_else(|e| panic!("Failed to read golden {}: {e}", golden_path.display()));
+        assert_eq!(
+            expected,
+            golden_string(&golden_path, &actual),
+            "Golden output mismatch for {} (run with {GOLDEN_ENV}={GOLDEN_REGEN} to update)",
+            sample.display()
+        );
+    }
+}
+
+fn find_samples(dir: &Path, samples: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read directory {}: {e}", dir.display()));
+    for entry in entries {
+        let path = entry
+            .unwrap_or_else(|e| panic!("Failed to read entry in {}: {e}", dir.display()))
+            .path();
+        if path.is_dir() {
+            find_samples(&path, samples);
+        } else {
+            samples.push(path);
+        }
+    }
+}
+
+// The adapter name is the sample directory path joined with underscores
+fn sample_adapter(relative_path: &Path) -> Adapter {
+    let name = relative_path
+        .parent()
+        .map(|dir| {
+            dir.components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("_")
+        })
+        .unwrap_or_default();
+    serde_json::from_value(Value::String(name.clone())).unwrap_or_else(|e| {
+        panic!(
+            "No adapter ({name}) for sample {}: {e}",
+            relative_path.display()
+        )
+    })
+}
+
+// Sort object keys so that golden files are stable regardless of map ordering
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        },
+        Value::Array(array) => Value::Array(array.into_iter().map(sort_keys).collect()),
+        value @ (Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_)) => value,
+    }
+}
+
+fn write_golden(golden_path: &Path, value: &Value) {
+    if let Some(parent) = golden_path.parent() {
+        fs::create_dir_all(parent)
+            .unwrap_or_else(|e| panic!("Failed to create directory {}: {e}", parent.display()));
+    }
+    fs::write(golden_path, golden_string(golden_path, value))
+        .unwrap_or_else(|e| panic!("Failed to write golden {}: {e}", golden_path.display()));
+}
+
+fn golden_string(golden_path: &Path, value: &Value) -> String {
+    let mut golden = serde_json::to_string_pretty(value)
+        .unwrap_or_else(|e| panic!("Failed to serialize golden {}: {e}", golden_path.display()));
+    golden.push('\n');
+    golden
+}
@@ -1,5 +1,10 @@
+#![deny(clippy::unwrap_used, clippy::todo)]
+
 pub mod adapters;
 pub mod error;
+#[cfg(test)]
+#[allow(clippy::panic)]
+mod golden;
 pub mod results;
 
 use adapters::{
@@ -10,13 +15,18 @@ use adapters::{
     js::{benchmark::AdapterJsBenchmark, time::AdapterJsTime, AdapterJs},
     json::AdapterJson,
     magic::AdapterMagic,
+    php::bench::AdapterPhpBench,
     python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
     rust::{
         bench::AdapterRustBench, criterion::AdapterRustCriterion, iai::AdapterRustIai,
         iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
     },
-    shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
+    scala::scalameter::AdapterScalaScalameter,
+    shell::{
+        hyperfine::AdapterShellHyperfine, measure_command::AdapterShellMeasureCommand, AdapterShell,
+    },
+    swift::xctest::AdapterSwiftXctest,
 };
 use bencher_json::project::report::{Adapter, JsonAverage};
 pub use bencher_json::{BenchmarkName, JsonNewMetric};
@@ -33,6 +43,10 @@ pub trait Adaptable {
 
 impl Adaptable for Adapter {
     fn convert(&self, input: &str, settings: Settings) -> Option<AdapterResults> {
+        // Many tools emit colored output in CI,
+        // so remove any ANSI escape codes before parsing.
+        let input = bencher_json::strip_ansi_escapes(input);
+        let input = input.as_ref();
         match self {
             Adapter::Magic => AdapterMagic::parse(input, settings),
             Adapter::Json => AdapterJson::parse(input, settings),
@@ -58,13 +72,17 @@ impl Adaptable for Adapter {
             Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
             Adapter::RustIai => AdapterRustIai::parse(input, settings),
             Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse(input, settings),
+            Adapter::PhpBench => AdapterPhpBench::parse(input, settings),
+            Adapter::ScalaScalameter => AdapterScalaScalameter::parse(input, settings),
             Adapter::Shell => AdapterShell::parse(input, settings),
             Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
+            Adapter::ShellMeasureCommand => AdapterShellMeasureCommand::parse(input, settings),
+            Adapter::SwiftXctest => AdapterSwiftXctest::parse(input, settings),
         }
     }
 
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterMagic::parse(input, settings)
+        Adapter::Magic.convert(input, settings)
     }
 }
 
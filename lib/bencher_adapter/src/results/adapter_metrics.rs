@@ -1,4 +1,4 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::BTreeMap, str::FromStr};
 
 use bencher_json::{JsonNewMetric, MeasureNameId};
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,7 @@ pub struct AdapterMetrics {
     pub inner: MetricsMap,
 }
 
-pub type MetricsMap = HashMap<MeasureNameId, JsonNewMetric>;
+pub type MetricsMap = BTreeMap<MeasureNameId, JsonNewMetric>;
 
 impl From<MetricsMap> for AdapterMetrics {
     fn from(inner: MetricsMap) -> Self {
@@ -21,7 +21,7 @@ impl From<MetricsMap> for AdapterMetrics {
 
 impl AdapterMetrics {
     pub(crate) fn combined(self, mut other: Self, kind: CombinedKind) -> Self {
-        let mut metric_map = HashMap::new();
+        let mut metric_map = BTreeMap::new();
         for (measure, metric) in self.inner {
             let other_metric = other.inner.remove(&measure);
             let combined_metric = if let Some(other_metric) = other_metric {
@@ -41,6 +41,30 @@ impl AdapterMetrics {
         metric_map.into()
     }
 
+    // The ratio of each metric to its baseline metric for the same measure.
+    // Measures without a baseline or with a zero baseline value are skipped.
+    pub(crate) fn ratio(&self, baseline: &Self) -> Self {
+        let mut metric_map = BTreeMap::new();
+        for (measure, metric) in &self.inner {
+            let Some(baseline_metric) = baseline.inner.get(measure) else {
+                continue;
+            };
+            let denominator = baseline_metric.value;
+            if denominator == 0.0 {
+                continue;
+            }
+            metric_map.insert(
+                measure.clone(),
+                JsonNewMetric {
+                    value: metric.value / denominator,
+                    lower_value: metric.lower_value.map(|lower| lower / denominator),
+                    upper_value: metric.upper_value.map(|upper| upper / denominator),
+                },
+            );
+        }
+        metric_map.into()
+    }
+
     pub fn get(&self, key: &str) -> Option<&JsonNewMetric> {
         self.inner.get(&MeasureNameId::from_str(key).ok()?)
     }
@@ -50,7 +74,7 @@ impl std::ops::Div<usize> for AdapterMetrics {
     type Output = Self;
 
     fn div(self, rhs: usize) -> Self::Output {
-        let mut metric_map = HashMap::new();
+        let mut metric_map = BTreeMap::new();
         for (measure, metric) in self.inner {
             metric_map.insert(measure, metric / rhs);
         }
@@ -1,16 +1,18 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::BTreeMap, str::FromStr};
 
 use bencher_json::{
     project::{
         measure::built_in::{self, BuiltInMeasure},
         metric::Mean,
+        report::JsonBenchmarkPair,
     },
-    BenchmarkName, JsonNewMetric,
+    BenchmarkName, JsonNewMetric, JsonResultsMap,
 };
-use literally::hmap;
+use literally::bmap;
 use serde::{Deserialize, Serialize};
 
 use super::{adapter_metrics::AdapterMetrics, CombinedKind};
+use crate::AdapterError;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdapterResults {
@@ -18,7 +20,7 @@ pub struct AdapterResults {
     pub inner: ResultsMap,
 }
 
-pub type ResultsMap = HashMap<BenchmarkName, AdapterMetrics>;
+pub type ResultsMap = BTreeMap<BenchmarkName, AdapterMetrics>;
 
 impl From<ResultsMap> for AdapterResults {
     fn from(inner: ResultsMap) -> Self {
@@ -73,17 +75,17 @@ impl AdapterResults {
             return None;
         }
 
-        let mut results_map = HashMap::new();
+        let mut results_map = BTreeMap::new();
         for (benchmark_name, measure) in benchmark_metrics {
             let adapter_metrics = AdapterMetrics {
                 inner: match measure {
                     AdapterMeasure::Latency(json_metric) => {
-                        hmap! {
+                        bmap! {
                             built_in::default::Latency::name_id() => json_metric
                         }
                     },
                     AdapterMeasure::Throughput(json_metric) => {
-                        hmap! {
+                        bmap! {
                             built_in::default::Throughput::name_id() => json_metric
                         }
                     },
@@ -122,7 +124,7 @@ impl AdapterResults {
             return None;
         }
 
-        let mut results_map = HashMap::new();
+        let mut results_map = BTreeMap::new();
         for (benchmark_name, metrics) in benchmark_metrics {
             let metrics_value = results_map
                 .entry(benchmark_name)
@@ -159,7 +161,7 @@ impl AdapterResults {
             return None;
         }
 
-        let mut results_map = HashMap::new();
+        let mut results_map = BTreeMap::new();
         for (benchmark_name, metrics) in benchmark_metrics {
             let metrics_value = results_map
                 .entry(benchmark_name)
@@ -234,7 +236,7 @@ impl AdapterResults {
     }
 
     pub(crate) fn combined(self, mut other: Self, kind: CombinedKind) -> Self {
-        let mut results_map = HashMap::new();
+        let mut results_map = BTreeMap::new();
         for (benchmark_name, metrics) in self.inner {
             let other_metrics = other.inner.remove(&benchmark_name);
             let combined_metrics = if let Some(other_metrics) = other_metrics {
@@ -248,6 +250,61 @@ impl AdapterResults {
         results_map.into()
     }
 
+    /// Add the ratio of each candidate to its baseline as a new benchmark.
+    /// The ratio is only calculated for the measures that both benchmarks share.
+    pub fn pair(&mut self, pairs: &[JsonBenchmarkPair]) -> Result<(), AdapterError> {
+        for JsonBenchmarkPair {
+            baseline,
+            candidate,
+        } in pairs
+        {
+            let baseline_metrics = self
+                .inner
+                .get(baseline)
+                .ok_or_else(|| AdapterError::PairBenchmark(baseline.clone()))?;
+            let candidate_metrics = self
+                .inner
+                .get(candidate)
+                .ok_or_else(|| AdapterError::PairBenchmark(candidate.clone()))?;
+            let ratio_metrics = candidate_metrics.ratio(baseline_metrics);
+            if ratio_metrics.inner.is_empty() {
+                continue;
+            }
+            let ratio_name = BenchmarkName::from_str(&format!("{candidate} / {baseline}"))
+                .map_err(|err| AdapterError::PairName {
+                    baseline: baseline.clone(),
+                    candidate: candidate.clone(),
+                    err,
+                })?;
+            self.inner.insert(ratio_name, ratio_metrics);
+        }
+        Ok(())
+    }
+
+    /// Merge in results that were measured outside of the benchmark harness.
+    /// If both results have the same benchmark and measure, then the other metric is used.
+    pub fn merge(&mut self, other: JsonResultsMap) {
+        for (benchmark_name, metrics) in other {
+            self.inner
+                .entry(benchmark_name)
+                .or_default()
+                .inner
+                .extend(metrics);
+        }
+    }
+
+    /// Extend with results parsed from another input of the same iteration.
+    /// If both results have the same benchmark and measure, then the other metric is used.
+    pub fn extend(&mut self, other: Self) {
+        for (benchmark_name, metrics) in other.inner {
+            self.inner
+                .entry(benchmark_name)
+                .or_default()
+                .inner
+                .extend(metrics.inner);
+        }
+    }
+
     pub fn get(&self, key: &str) -> Option<&AdapterMetrics> {
         self.inner.get(&BenchmarkName::from_str(key).ok()?)
     }
@@ -271,7 +328,7 @@ impl std::iter::Sum for AdapterResults {
         I: Iterator<Item = Self>,
     {
         iter.into_iter().fold(
-            HashMap::new().into(),
+            BTreeMap::new().into(),
             |results: AdapterResults, other_results| results + other_results,
         )
     }
@@ -290,3 +347,132 @@ impl std::ops::Div<usize> for AdapterResults {
 }
 
 impl Mean for AdapterResults {}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::{
+            measure::built_in::{self, BuiltInMeasure},
+            report::JsonBenchmarkPair,
+        },
+        JsonNewMetric,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::AdapterResults;
+    use crate::adapters::test_util::validate_latency;
+
+    fn latency(value: f64) -> JsonNewMetric {
+        JsonNewMetric {
+            value: value.into(),
+            lower_value: Some((value / 2.0).into()),
+            upper_value: Some((value * 2.0).into()),
+        }
+    }
+
+    #[test]
+    fn test_adapter_results_pair() {
+        let mut results = AdapterResults::new_latency(vec![
+            ("old_impl".parse().unwrap(), latency(200.0)),
+            ("new_impl".parse().unwrap(), latency(150.0)),
+        ])
+        .unwrap();
+        results
+            .pair(&[JsonBenchmarkPair {
+                baseline: "old_impl".parse().unwrap(),
+                candidate: "new_impl".parse().unwrap(),
+            }])
+            .unwrap();
+
+        assert_eq!(results.inner.len(), 3);
+        let metrics = results.get("new_impl / old_impl").unwrap();
+        validate_latency(metrics, 0.75, Some(0.375), Some(1.5));
+    }
+
+    #[test]
+    fn test_adapter_results_pair_missing() {
+        let mut results =
+            AdapterResults::new_latency(vec![("old_impl".parse().unwrap(), latency(200.0))])
+                .unwrap();
+        let pair = JsonBenchmarkPair {
+            baseline: "old_impl".parse().unwrap(),
+            candidate: "new_impl".parse().unwrap(),
+        };
+        assert_eq!(
+            results.pair(&[pair]).unwrap_err().to_string(),
+            "Failed to find benchmark for pair: new_impl"
+        );
+    }
+
+    #[test]
+    fn test_adapter_results_merge() {
+        let mut results = AdapterResults::new_latency(vec![
+            ("a".parse().unwrap(), latency(1.0)),
+            ("b".parse().unwrap(), latency(2.0)),
+        ])
+        .unwrap();
+        let other = JsonNewMetric::results(vec![
+            (
+                "b".parse().unwrap(),
+                vec![(built_in::json::MaxRss::name_id(), latency(4.0))],
+            ),
+            (
+                "c".parse().unwrap(),
+                vec![(built_in::json::MaxRss::name_id(), latency(3.0))],
+            ),
+        ]);
+        results.merge(other);
+
+        assert_eq!(results.inner.len(), 3);
+        assert_eq!(results.get("a").unwrap().inner.len(), 1);
+        assert_eq!(results.get("b").unwrap().inner.len(), 2);
+        assert_eq!(results.get("c").unwrap().inner.len(), 1);
+    }
+
+    #[test]
+    fn test_adapter_results_extend() {
+        let mut results = AdapterResults::new_latency(vec![
+            ("a".parse().unwrap(), latency(1.0)),
+            ("b".parse().unwrap(), latency(2.0)),
+        ])
+        .unwrap();
+        let other = AdapterResults::new_latency(vec![
+            ("b".parse().unwrap(), latency(4.0)),
+            ("c".parse().unwrap(), latency(3.0)),
+        ])
+        .unwrap();
+        results.extend(other);
+
+        assert_eq!(results.inner.len(), 3);
+        validate_latency(results.get("a").unwrap(), 1.0, Some(0.5), Some(2.0));
+        validate_latency(results.get("b").unwrap(), 4.0, Some(2.0), Some(8.0));
+        validate_latency(results.get("c").unwrap(), 3.0, Some(1.5), Some(6.0));
+    }
+
+    #[test]
+    fn test_adapter_results_ordered() {
+        let results = AdapterResults::new_latency(vec![
+            ("b".parse().unwrap(), latency(2.0)),
+            ("c".parse().unwrap(), latency(3.0)),
+            ("a".parse().unwrap(), latency(1.0)),
+        ])
+        .unwrap();
+        let names = results
+            .inner
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a", "b", "c"]);
+
+        let reversed = AdapterResults::new_latency(vec![
+            ("a".parse().unwrap(), latency(1.0)),
+            ("c".parse().unwrap(), latency(3.0)),
+            ("b".parse().unwrap(), latency(2.0)),
+        ])
+        .unwrap();
+        assert_eq!(
+            serde_json::to_string(&results).unwrap(),
+            serde_json::to_string(&reversed).unwrap()
+        );
+    }
+}
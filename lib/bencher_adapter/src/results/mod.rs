@@ -1,11 +1,32 @@
-use std::collections::HashMap;
+use std::{cmp::Reverse, collections::BTreeMap};
 
-use bencher_json::project::{
-    metric::Mean,
-    report::{Adapter, JsonFold},
+use bencher_json::{
+    project::{
+        metric::Mean,
+        report::{Adapter, JsonAdapterSelection, JsonFold},
+    },
+    JsonResultsMap,
 };
 
-use crate::{Adaptable, AdapterError, Settings};
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+
+use crate::{
+    adapters::{
+        c_sharp::dot_net::DotNet,
+        cpp::google::Google,
+        java::jmh::Jmh,
+        magic::{AdapterMagic, MAGIC_ADAPTERS},
+        php::bench::PhpBench,
+        python::pytest::Pytest,
+        shell::{hyperfine::Hyperfine, measure_command::MeasureCommand},
+    },
+    error::{ConvertError, Location, NearMiss},
+    Adaptable, AdapterError, Settings,
+};
+
+// The maximum number of near miss adapters to report
+const MAX_NEAR_MISSES: usize = 3;
 
 pub mod adapter_metrics;
 pub mod adapter_results;
@@ -17,13 +38,18 @@ use results_reducer::ResultsReducer;
 #[derive(Debug, Clone)]
 pub struct AdapterResultsArray {
     pub inner: ResultsArray,
+    /// The adapter selected by the magic adapter, if it was used
+    pub selection: Option<JsonAdapterSelection>,
 }
 
 pub type ResultsArray = Vec<AdapterResults>;
 
 impl From<ResultsArray> for AdapterResultsArray {
     fn from(inner: ResultsArray) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            selection: None,
+        }
     }
 }
 
@@ -34,13 +60,79 @@ impl AdapterResultsArray {
         settings: Settings,
     ) -> Result<Self, AdapterError> {
         let mut parsed_results_array = Vec::new();
-        for &results in results_array {
-            let parsed_results = adapter
-                .convert(results, settings)
-                .ok_or_else(|| AdapterError::Convert((results).to_owned()))?;
+        let mut selection = None;
+        for (iteration, &results) in results_array.iter().enumerate() {
+            let parsed_results = if let Adapter::Magic = adapter {
+                AdapterMagic::select(results, settings).map(
+                    |(parsed_results, iteration_selection)| {
+                        selection = Some(match selection.take() {
+                            Some(selection) => AdapterMagic::merge(selection, iteration_selection),
+                            None => iteration_selection,
+                        });
+                        parsed_results
+                    },
+                )
+            } else {
+                adapter.convert(results, settings)
+            }
+            .ok_or_else(|| {
+                // The error location and excerpt must both come from the same input,
+                // which is the input with any ANSI escape codes removed as used by the adapters.
+                let results = bencher_json::strip_ansi_escapes(results);
+                AdapterError::Convert(
+                    ConvertError::new(iteration, &results, locate_error(adapter, &results))
+                        .with_near_misses(near_misses(adapter, &results)),
+                )
+            })?;
             parsed_results_array.push(parsed_results);
         }
-        Ok(parsed_results_array.into())
+        Ok(Self {
+            inner: parsed_results_array,
+            selection,
+        })
+    }
+
+    /// Group consecutive results into a single iteration,
+    /// such as when multiple results files are read for each iteration.
+    /// There must be exactly `results_per_iteration` results for each iteration.
+    pub fn group(&mut self, results_per_iteration: usize) -> Result<(), AdapterError> {
+        if results_per_iteration == 0 || self.inner.len() % results_per_iteration != 0 {
+            return Err(AdapterError::ResultsPerIteration {
+                results: self.inner.len(),
+                results_per_iteration,
+            });
+        }
+        let iterations = self
+            .inner
+            .len()
+            .checked_div(results_per_iteration)
+            .unwrap_or_default();
+        let mut grouped_results: ResultsArray = Vec::with_capacity(iterations);
+        for (index, results) in std::mem::take(&mut self.inner).into_iter().enumerate() {
+            match grouped_results.last_mut() {
+                Some(grouped) if index % results_per_iteration != 0 => {
+                    grouped.extend(results);
+                },
+                Some(_) | None => grouped_results.push(results),
+            }
+        }
+        self.inner = grouped_results;
+        Ok(())
+    }
+
+    /// Merge in the results measured by the benchmark runner.
+    /// There must be one set of runner results for each iteration.
+    pub fn merge(&mut self, runner_results: Vec<JsonResultsMap>) -> Result<(), AdapterError> {
+        if runner_results.len() != self.inner.len() {
+            return Err(AdapterError::RunnerResults {
+                iterations: self.inner.len(),
+                runner_results: runner_results.len(),
+            });
+        }
+        for (results, other) in self.inner.iter_mut().zip(runner_results) {
+            results.merge(other);
+        }
+        Ok(())
     }
 
     pub fn min(self) -> AdapterResults {
@@ -53,7 +145,7 @@ impl AdapterResultsArray {
 
     fn ord(self, ord_kind: OrdKind) -> AdapterResults {
         self.inner.into_iter().fold(
-            HashMap::new().into(),
+            BTreeMap::new().into(),
             |results: AdapterResults, other_results| {
                 results.combined(other_results, CombinedKind::Ord(ord_kind))
             },
@@ -87,6 +179,101 @@ impl AdapterResultsArray {
     }
 }
 
+// Only JSON based adapters are able to pinpoint where their input failed to parse.
+// Line based adapters skip over any lines that they do not recognize.
+fn locate_error(adapter: Adapter, input: &str) -> Option<Location> {
+    match json_error(adapter, input) {
+        JsonError::Error(e) => Some(error_location(&e)),
+        JsonError::NotJson | JsonError::None => None,
+    }
+}
+
+// When the magic adapter is not able to parse the results,
+// find the JSON based adapters that came the closest.
+// That is, the results are valid JSON that does not match the format of the adapter,
+// or the results match the format of the adapter but contain no benchmarks.
+fn near_misses(adapter: Adapter, input: &str) -> Vec<NearMiss> {
+    let Adapter::Magic = adapter else {
+        return Vec::new();
+    };
+    let mut near_misses = MAGIC_ADAPTERS
+        .iter()
+        .filter_map(|&adapter| {
+            let location = match json_error(adapter, input) {
+                JsonError::None => None,
+                JsonError::Error(e) if e.classify() == Category::Data => Some(error_location(&e)),
+                JsonError::NotJson | JsonError::Error(_) => return None,
+            };
+            Some(NearMiss { adapter, location })
+        })
+        .collect::<Vec<_>>();
+    // Matching the format is the nearest miss, followed by the furthest data error
+    near_misses.sort_by_key(|near_miss| near_miss.location.map(Reverse));
+    near_misses.truncate(MAX_NEAR_MISSES);
+    near_misses
+}
+
+enum JsonError {
+    // The adapter is not JSON based
+    NotJson,
+    // The input deserialized without error
+    None,
+    // The error from deserializing the input
+    Error(serde_json::Error),
+}
+
+fn json_error(adapter: Adapter, input: &str) -> JsonError {
+    match adapter {
+        Adapter::Json => json_deserialize_error::<AdapterResults>(input),
+        Adapter::CSharpDotNet => json_deserialize_error::<DotNet>(input),
+        Adapter::CppGoogle => json_deserialize_error::<Google>(input),
+        Adapter::JavaJmh => json_deserialize_error::<Jmh>(input),
+        Adapter::PhpBench => json_deserialize_error::<PhpBench>(input),
+        Adapter::PythonPytest => json_deserialize_error::<Pytest>(input),
+        Adapter::ShellHyperfine => json_deserialize_error::<Hyperfine>(input),
+        Adapter::ShellMeasureCommand => json_deserialize_error::<MeasureCommand>(input),
+        Adapter::Magic
+        | Adapter::CSharp
+        | Adapter::Cpp
+        | Adapter::CppCatch2
+        | Adapter::Go
+        | Adapter::GoBench
+        | Adapter::Java
+        | Adapter::Js
+        | Adapter::JsBenchmark
+        | Adapter::JsTime
+        | Adapter::Python
+        | Adapter::PythonAsv
+        | Adapter::Ruby
+        | Adapter::RubyBenchmark
+        | Adapter::Rust
+        | Adapter::RustBench
+        | Adapter::RustCriterion
+        | Adapter::RustIai
+        | Adapter::RustIaiCallgrind
+        | Adapter::ScalaScalameter
+        | Adapter::Shell
+        | Adapter::SwiftXctest => JsonError::NotJson,
+    }
+}
+
+fn json_deserialize_error<T>(input: &str) -> JsonError
+where
+    T: DeserializeOwned,
+{
+    match serde_json::from_str::<T>(input) {
+        Ok(_) => JsonError::None,
+        Err(e) => JsonError::Error(e),
+    }
+}
+
+fn error_location(e: &serde_json::Error) -> Location {
+    Location {
+        line: e.line(),
+        column: e.column(),
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum OrdKind {
     Min,
@@ -98,3 +285,50 @@ pub(crate) enum CombinedKind {
     Ord(OrdKind),
     Add,
 }
+
+#[cfg(test)]
+mod test {
+    use bencher_json::project::report::Adapter;
+    use pretty_assertions::assert_eq;
+
+    use super::AdapterResultsArray;
+    use crate::{adapters::test_util::validate_latency, Settings};
+
+    #[test]
+    fn test_adapter_results_array_group() {
+        let results = [
+            r#"{"a": {"latency": {"value": 1.0}}}"#,
+            r#"{"b": {"latency": {"value": 2.0}}}"#,
+            r#"{"a": {"latency": {"value": 3.0}}}"#,
+            r#"{"b": {"latency": {"value": 4.0}}}"#,
+        ];
+        let mut results_array =
+            AdapterResultsArray::new(&results, Adapter::Json, Settings::default()).unwrap();
+        results_array.group(2).unwrap();
+
+        assert_eq!(results_array.inner.len(), 2);
+        let first = results_array.inner.first().unwrap();
+        let second = results_array.inner.last().unwrap();
+        validate_latency(first.get("a").unwrap(), 1.0, None, None);
+        validate_latency(first.get("b").unwrap(), 2.0, None, None);
+        validate_latency(second.get("a").unwrap(), 3.0, None, None);
+        validate_latency(second.get("b").unwrap(), 4.0, None, None);
+
+        assert_eq!(
+            results_array.group(3).unwrap_err().to_string(),
+            "Failed to group results: 2 result(s) cannot be split into groups of 3"
+        );
+    }
+
+    #[test]
+    fn test_adapter_results_array_ansi_error() {
+        let results = ["{\n  \u{1b}[31m\"a\": oops\u{1b}[0m\n}"];
+        let error = AdapterResultsArray::new(&results, Adapter::Json, Settings::default())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Failed to convert results: iteration 0 failed at line 2, column 8 of 3\n  |\n2 |   \"a\": oops\n  |        ^"
+        );
+    }
+}
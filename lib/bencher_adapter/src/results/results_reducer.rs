@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use bencher_json::{project::metric::Median, BenchmarkName, JsonNewMetric, MeasureNameId};
 
@@ -8,7 +8,7 @@ use super::{
 
 #[derive(Debug, Clone, Default)]
 pub struct ResultsReducer {
-    pub inner: HashMap<BenchmarkName, MeasuresMap>,
+    pub inner: BTreeMap<BenchmarkName, MeasuresMap>,
 }
 
 impl From<AdapterResultsArray> for ResultsReducer {
@@ -33,7 +33,7 @@ impl ResultsReducer {
                     }
                 }
             } else {
-                let mut measures_map = HashMap::new();
+                let mut measures_map = BTreeMap::new();
                 for (measure, metric) in metrics.inner {
                     measures_map.insert(measure, vec![metric]);
                 }
@@ -50,12 +50,12 @@ impl ResultsReducer {
 
 #[derive(Debug, Clone)]
 pub struct MeasuresMap {
-    pub inner: HashMap<MeasureNameId, Vec<JsonNewMetric>>,
+    pub inner: BTreeMap<MeasureNameId, Vec<JsonNewMetric>>,
 }
 
 impl MeasuresMap {
     pub(crate) fn median(self) -> AdapterMetrics {
-        let mut metric_map = HashMap::new();
+        let mut metric_map = BTreeMap::new();
         for (measure, metric) in self.inner {
             if let Some(median) = JsonNewMetric::median(metric) {
                 metric_map.insert(measure, median);
@@ -1,6 +1,9 @@
-use bencher_json::{project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize};
+use bencher_json::{
+    project::boundary::BoundaryLimit, Boundary, CdfBoundary, ModelTest, SampleSize,
+};
 use slog::Logger;
 
+use crate::cusum::Cusum;
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
 use crate::mean::Mean;
@@ -106,6 +109,10 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::Percentile => {
+                Self::new_percentile(log, datum, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::Cusum => Self::new_cusum(log, datum, data, lower_boundary, upper_boundary),
         }
     }
 
@@ -212,6 +219,77 @@ impl MetricsBoundary {
         Ok(Some(Self { limits, outlier }))
     }
 
+    fn new_percentile(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary: Option<CdfBoundary> = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary: Option<CdfBoundary> = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(Quartiles { q2: median, .. }) = Quartiles::new(data) else {
+            return Ok(None);
+        };
+        // The lower boundary is measured from the bottom of the data,
+        // so a lower boundary of `0.95` is the 5th percentile.
+        let lower_percentile = if let Some(boundary) = lower_boundary {
+            let Some(percentile) = Quartiles::percentile(data, 1.0 - f64::from(boundary)) else {
+                return Ok(None);
+            };
+            Some(percentile)
+        } else {
+            None
+        };
+        let upper_percentile = if let Some(boundary) = upper_boundary {
+            let Some(percentile) = Quartiles::percentile(data, f64::from(boundary)) else {
+                return Ok(None);
+            };
+            Some(percentile)
+        } else {
+            None
+        };
+
+        let limits = MetricsLimits::new_percentile(log, median, lower_percentile, upper_percentile);
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self { limits, outlier }))
+    }
+
+    fn new_cusum(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // Get the cumulative sums of the historical data.
+        let Some(cusum) = Cusum::new(data) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_cusum(log, cusum, lower_boundary, upper_boundary);
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self { limits, outlier }))
+    }
+
     fn new_iqr(
         log: &Logger,
         datum: f64,
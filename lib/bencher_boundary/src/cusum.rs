@@ -0,0 +1,114 @@
+use bencher_json::CusumBoundary;
+
+use crate::mean::Mean;
+
+/// The allowable slack for each sample, in standard deviations.
+/// Shifts smaller than the slack do not accumulate, ie half of a one standard deviation shift.
+pub const SLACK: f64 = 0.5;
+
+/// The one-sided cumulative sums (CUSUM) of the standardized historical data.
+/// A gradual shift in the data accumulates in these sums,
+/// even if no single sample is an outlier on its own.
+/// The mean and standard deviation come from a reference window of the older half of the data,
+/// so that a shift in the most recent samples does not also move the baseline it is measured against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cusum {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub lower_sum: f64,
+    pub upper_sum: f64,
+}
+
+impl Cusum {
+    /// The data must be ordered from the most recent to the oldest sample.
+    pub fn new(data: &[f64]) -> Option<Self> {
+        let (recent, reference) = data.split_at_checked(data.len() - data.len().div_ceil(2))?;
+        let mean = Mean::new(reference)?;
+        let std_dev = mean.std_deviation(reference)?;
+        let Mean { mean } = mean;
+
+        // Accumulate the recent samples from the oldest to the most recent.
+        let (lower_sum, upper_sum) =
+            recent
+                .iter()
+                .rev()
+                .fold((0.0f64, 0.0f64), |(lower_sum, upper_sum), &datum| {
+                    let z = (datum - mean) / std_dev;
+                    (
+                        (lower_sum - z - SLACK).max(0.0),
+                        (upper_sum + z - SLACK).max(0.0),
+                    )
+                });
+
+        (lower_sum.is_finite() && upper_sum.is_finite()).then_some(Self {
+            mean,
+            std_dev,
+            lower_sum,
+            upper_sum,
+        })
+    }
+
+    // How far a new sample may be from the mean, in standard deviations,
+    // before the cumulative sum exceeds the decision interval.
+    pub fn headroom(sum: f64, boundary: CusumBoundary) -> f64 {
+        f64::from(boundary) + SLACK - sum
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::Cusum;
+
+    #[test]
+    fn test_cusum_empty() {
+        assert_eq!(Cusum::new(&[]), None);
+        assert_eq!(Cusum::new(&[1.0]), None);
+        assert_eq!(Cusum::new(&[1.0, 1.0, 1.0]), None);
+    }
+
+    #[test]
+    fn test_cusum_stable() {
+        let cusum = Cusum::new(&[4.0, 0.0, 4.0, 0.0]).unwrap();
+        assert_eq!(
+            cusum,
+            Cusum {
+                mean: 2.0,
+                std_dev: 2.0,
+                lower_sum: 0.0,
+                upper_sum: 0.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cusum_drift() {
+        // The most recent samples are the largest
+        let cusum = Cusum::new(&[10.0, 8.0, 6.0, 4.0, 2.0, 0.0]).unwrap();
+        assert_eq!(cusum.mean, 2.0);
+        assert_eq!(cusum.lower_sum, 0.0);
+        assert!(cusum.upper_sum > 1.0);
+
+        // The oldest samples are the largest
+        let cusum = Cusum::new(&[0.0, 2.0, 4.0, 6.0, 8.0, 10.0]).unwrap();
+        assert_eq!(cusum.mean, 8.0);
+        assert!(cusum.lower_sum > 1.0);
+        assert_eq!(cusum.upper_sum, 0.0);
+    }
+
+    #[test]
+    fn test_cusum_reference_window() {
+        // The recent shift does not move the reference mean or standard deviation
+        let cusum = Cusum::new(&[100.0, 100.0, 4.0, 0.0]).unwrap();
+        let reference = Cusum::new(&[4.0, 0.0, 4.0, 0.0]).unwrap();
+        assert_eq!(cusum.mean, reference.mean);
+        assert_eq!(cusum.std_dev, reference.std_dev);
+        assert!(cusum.upper_sum > reference.upper_sum);
+
+        // There must be at least two samples in the reference window
+        assert_eq!(Cusum::new(&[1.0, 2.0]), None);
+        assert!(Cusum::new(&[1.0, 2.0, 3.0]).is_some());
+    }
+}
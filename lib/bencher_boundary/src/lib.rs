@@ -1,4 +1,5 @@
 pub mod boundary;
+mod cusum;
 mod error;
 pub mod limits;
 mod ln;
@@ -1,6 +1,6 @@
-use bencher_json::{Boundary, IqrBoundary, PercentageBoundary};
+use bencher_json::{Boundary, CusumBoundary, IqrBoundary, PercentageBoundary};
 
-use crate::quartiles::Quartiles;
+use crate::{cusum::Cusum, quartiles::Quartiles};
 
 #[derive(Debug, PartialEq)]
 pub struct MetricsLimit {
@@ -35,6 +35,10 @@ impl MetricsLimit {
         Self { value: abs_limit }
     }
 
+    pub fn percentile(value: f64) -> Self {
+        Self { value }
+    }
+
     pub fn iqr_lower(quartiles: Quartiles, boundary: IqrBoundary) -> Self {
         Self {
             value: quartiles.q2 - quartiles.iqr(boundary),
@@ -70,6 +74,18 @@ impl MetricsLimit {
     fn delta(median: f64, delta_quartiles: Quartiles, boundary: IqrBoundary) -> f64 {
         median * delta_quartiles.iqr(boundary)
     }
+
+    pub fn cusum_lower(cusum: Cusum, boundary: CusumBoundary) -> Self {
+        Self {
+            value: cusum.mean - cusum.std_dev * Cusum::headroom(cusum.lower_sum, boundary),
+        }
+    }
+
+    pub fn cusum_upper(cusum: Cusum, boundary: CusumBoundary) -> Self {
+        Self {
+            value: cusum.mean + cusum.std_dev * Cusum::headroom(cusum.upper_sum, boundary),
+        }
+    }
 }
 
 impl From<MetricsLimit> for f64 {
@@ -91,7 +107,7 @@ impl From<Boundary> for MetricsLimit {
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{limits::MetricsLimit, quartiles::Quartiles};
+    use crate::{cusum::Cusum, limits::MetricsLimit, quartiles::Quartiles};
 
     #[test]
     fn test_limit_percentage_lower() {
@@ -181,4 +197,30 @@ mod test {
         let limit = MetricsLimit::delta_iqr_upper(quartiles, delta_quartiles, boundary);
         assert_eq!(limit.value, 5.0);
     }
+
+    #[test]
+    fn test_limit_cusum_lower() {
+        let cusum = Cusum {
+            mean: 2.0,
+            std_dev: 2.0,
+            lower_sum: 1.5,
+            upper_sum: 0.0,
+        };
+        let boundary = 4.0.try_into().unwrap();
+        let limit = MetricsLimit::cusum_lower(cusum, boundary);
+        assert_eq!(limit.value, -4.0);
+    }
+
+    #[test]
+    fn test_limit_cusum_upper() {
+        let cusum = Cusum {
+            mean: 2.0,
+            std_dev: 2.0,
+            lower_sum: 0.0,
+            upper_sum: 1.5,
+        };
+        let boundary = 4.0.try_into().unwrap();
+        let limit = MetricsLimit::cusum_upper(cusum, boundary);
+        assert_eq!(limit.value, 8.0);
+    }
 }
@@ -1,10 +1,11 @@
 use bencher_json::{
-    project::boundary::BoundaryLimit, Boundary, CdfBoundary, IqrBoundary, PercentageBoundary,
+    project::boundary::BoundaryLimit, Boundary, CdfBoundary, CusumBoundary, IqrBoundary,
+    PercentageBoundary,
 };
 use slog::{debug, Logger};
 use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
 
-use crate::{ln::Ln, quartiles::Quartiles, BoundaryError};
+use crate::{cusum::Cusum, ln::Ln, quartiles::Quartiles, BoundaryError};
 
 mod limit;
 
@@ -156,6 +157,27 @@ impl MetricsLimits {
         })
     }
 
+    pub fn new_percentile(
+        log: &Logger,
+        median: f64,
+        lower_percentile: Option<f64>,
+        upper_percentile: Option<f64>,
+    ) -> Self {
+        if lower_percentile.is_none() && upper_percentile.is_none() {
+            return Self::default();
+        }
+
+        debug!(
+            log,
+            "Percentile: median={median}, lower={lower_percentile:?}, upper={upper_percentile:?}"
+        );
+        Self {
+            baseline: Some(median),
+            lower: lower_percentile.map(MetricsLimit::percentile),
+            upper: upper_percentile.map(MetricsLimit::percentile),
+        }
+    }
+
     pub fn new_iqr(
         log: &Logger,
         quartiles: Quartiles,
@@ -197,6 +219,27 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_cusum(
+        log: &Logger,
+        cusum: Cusum,
+        lower_boundary: Option<CusumBoundary>,
+        upper_boundary: Option<CusumBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        debug!(log, "CUSUM: {cusum:?}");
+        let lower = lower_boundary.map(|boundary| MetricsLimit::cusum_lower(cusum, boundary));
+        let upper = upper_boundary.map(|boundary| MetricsLimit::cusum_upper(cusum, boundary));
+
+        Self {
+            baseline: Some(cusum.mean),
+            lower,
+            upper,
+        }
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
@@ -228,7 +271,7 @@ mod test {
     use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
-    use crate::{ln::Ln, quartiles::Quartiles};
+    use crate::{cusum::Cusum, ln::Ln, quartiles::Quartiles};
 
     use super::{MetricsLimit, MetricsLimits, NormalTestKind};
 
@@ -273,6 +316,13 @@ mod test {
     const IQR_Q2: f64 = 2.0;
     const IQR_Q3: f64 = 3.0;
 
+    const CUSUM: Cusum = Cusum {
+        mean: 2.0,
+        std_dev: 2.0,
+        lower_sum: 0.0,
+        upper_sum: 0.5,
+    };
+
     const QUARTILES: Quartiles = Quartiles {
         q1: IQR_Q1,
         q2: IQR_Q2,
@@ -299,6 +349,9 @@ mod test {
     const LOG_NORMAL_POSITIVE_OUTLIER: f64 = 3.0;
     const IQR_POSITIVE_OUTLIER: f64 = 6.0;
 
+    const PERCENTILE_LOWER: f64 = -1.25;
+    const PERCENTILE_UPPER: f64 = 1.25;
+
     #[test]
     fn test_limits_static_none() {
         let limits = MetricsLimits::new_static(None, None);
@@ -1354,4 +1407,102 @@ mod test {
         let side = limits.outlier(150.0);
         assert_eq!(side, Some(BoundaryLimit::Upper));
     }
+
+    #[test]
+    fn test_limits_percentile_none() {
+        let log = bootstrap_logger();
+        let limits = MetricsLimits::new_percentile(&log, NORMAL_ZERO, None, None);
+        assert_eq!(limits.baseline, None);
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(NORMAL_NEGATIVE_OUTLIER);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(NORMAL_POSITIVE_OUTLIER);
+        assert_eq!(side, None);
+    }
+
+    #[test]
+    fn test_limits_percentile_both() {
+        let log = bootstrap_logger();
+        let limits = MetricsLimits::new_percentile(
+            &log,
+            NORMAL_ZERO,
+            Some(PERCENTILE_LOWER),
+            Some(PERCENTILE_UPPER),
+        );
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(NORMAL_ZERO)
+        );
+        assert_eq!(
+            limits.lower,
+            Some(MetricsLimit {
+                value: PERCENTILE_LOWER
+            })
+        );
+        assert_eq!(
+            limits.upper,
+            Some(MetricsLimit {
+                value: PERCENTILE_UPPER
+            })
+        );
+
+        let side = limits.outlier(NORMAL_NEGATIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Lower));
+
+        let side = limits.outlier(NORMAL_NEGATIVE);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(NORMAL_ZERO);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(NORMAL_POSITIVE);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(NORMAL_POSITIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Upper));
+    }
+
+    #[test]
+    fn test_limits_cusum_none() {
+        let log = bootstrap_logger();
+        let limits = MetricsLimits::new_cusum(&log, CUSUM, None, None);
+        assert_eq!(limits.baseline, None);
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(-100.0);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(100.0);
+        assert_eq!(side, None);
+    }
+
+    #[test]
+    fn test_limits_cusum_both() {
+        let log = bootstrap_logger();
+        let boundary = 4.0.try_into().unwrap();
+        let limits = MetricsLimits::new_cusum(&log, CUSUM, Some(boundary), Some(boundary));
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(CUSUM.mean)
+        );
+        assert_eq!(limits.lower, Some(MetricsLimit { value: -7.0 }));
+        // The accumulated upper sum tightens the upper boundary limit
+        assert_eq!(limits.upper, Some(MetricsLimit { value: 10.0 }));
+
+        let side = limits.outlier(-8.0);
+        assert_eq!(side, Some(BoundaryLimit::Lower));
+
+        let side = limits.outlier(CUSUM.mean);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(9.0);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(11.0);
+        assert_eq!(side, Some(BoundaryLimit::Upper));
+    }
 }
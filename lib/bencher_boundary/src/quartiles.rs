@@ -41,6 +41,13 @@ impl Quartiles {
         })
     }
 
+    /// The value at the given percentile of the data, ie `0.95` for the 95th percentile
+    pub fn percentile(data: &[f64], percentile: f64) -> Option<f64> {
+        let mut data = data.to_vec();
+        data.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal));
+        Self::percentile_of_sorted(&data, percentile).and_then(|p| p.is_finite().then_some(p))
+    }
+
     pub fn iqr(self, boundary: IqrBoundary) -> f64 {
         (self.q3 - self.q1) * f64::from(boundary)
     }
@@ -294,6 +301,19 @@ mod test {
         assert_eq!(q.iqr(*FIVE_BOUNDARY), 56.25);
     }
 
+    #[test]
+    fn test_percentile() {
+        assert_eq!(Quartiles::percentile(DATA_ZERO, 0.95), None);
+        assert_eq!(Quartiles::percentile(DATA_ONE, 0.95), Some(1.0));
+        assert_eq!(Quartiles::percentile(DATA_FIVE, 0.5), Some(3.0));
+        assert_eq!(Quartiles::percentile(DATA_FIVE, 0.95), Some(4.8));
+        assert_eq!(Quartiles::percentile(DATA_FIVE_DESC, 0.95), Some(4.8));
+        assert_eq!(Quartiles::percentile(DATA_FIVE_DESC, 0.05), Some(1.2));
+        assert_eq!(Quartiles::percentile(DATA_FIVE_NEG, 0.25), Some(-4.0));
+        assert_eq!(Quartiles::percentile(DATA_FIVE_CONST, 0.99), Some(1.0));
+        assert_eq!(Quartiles::percentile(DATA_FIVE, 1.5), None);
+    }
+
     #[test]
     fn test_delta_quartiles_zero() {
         let q = Quartiles::new_delta(DATA_ZERO);
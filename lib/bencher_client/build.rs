@@ -8,7 +8,9 @@ fn main() {
     let src = "../../services/api/openapi.json";
     println!("cargo:rerun-if-changed={src}");
     let file = fs::File::open(src).unwrap();
-    let spec = serde_json::from_reader(file).unwrap();
+    let mut spec: serde_json::Value = serde_json::from_reader(file).unwrap();
+    remove_multipart_operations(&mut spec);
+    let spec = serde_json::from_value(spec).unwrap();
     let mut generator = progenitor::Generator::new(
         progenitor::GenerationSettings::default()
             .with_interface(progenitor::InterfaceStyle::Builder),
@@ -30,3 +32,23 @@ fn main() {
 
     fs::write(out_file, content).unwrap();
 }
+
+// Progenitor does not support `multipart/form-data` request bodies,
+// so those operations are left out of the generated client.
+fn remove_multipart_operations(spec: &mut serde_json::Value) {
+    let Some(paths) = spec
+        .get_mut("paths")
+        .and_then(serde_json::Value::as_object_mut)
+    else {
+        return;
+    };
+    for path_item in paths.values_mut() {
+        if let Some(operations) = path_item.as_object_mut() {
+            operations.retain(|_method, operation| {
+                operation
+                    .pointer("/requestBody/content/multipart~1form-data")
+                    .is_none()
+            });
+        }
+    }
+}
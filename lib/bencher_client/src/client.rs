@@ -1,12 +1,17 @@
 #![allow(clippy::absolute_paths)]
 
-use bencher_json::{Jwt, BENCHER_API_URL};
+use bencher_json::{
+    JsonAttachments, Jwt, ReportUuid, ResourceId, BENCHER_API_URL, BENCHER_CLIENT_VERSION_HEADER,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::time::{sleep, Duration};
 
 const DEFAULT_ATTEMPTS: usize = 10;
 const DEFAULT_RETRY_AFTER: u64 = 1;
 
+/// The version of this client, sent to the API server with every request
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// A client for the Bencher API
 #[derive(Debug, Clone)]
 pub struct BencherClient {
@@ -55,10 +60,39 @@ pub enum ClientError {
     #[error("Request failed with an unexpected response: {0:?}")]
     UnexpectedResponseErr(reqwest::Response),
 
+    #[error("Invalid content type: {0}")]
+    ContentType(reqwest::Error),
+
     #[error("Failed to send after {0} attempts")]
     SendTimeout(usize),
 }
 
+impl ClientError {
+    /// Whether the request failed because the API server was unreachable or briefly unavailable,
+    /// even after all of the retry attempts
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::SendTimeout(_) => true,
+            Self::ErrorResponse(error_response) => is_transient(error_response.status),
+            Self::UnexpectedResponseErr(response) => is_transient(response.status()),
+            Self::NoHost
+            | Self::HeaderValue(_)
+            | Self::BuildClient(_)
+            | Self::DeserializeResponse(_)
+            | Self::SerializeResponse(_)
+            | Self::InvalidRequest(_)
+            | Self::PreHookError(_)
+            | Self::InvalidUpgrade(_)
+            | Self::ResponseBodyError(_)
+            | Self::InvalidResponsePayloadStrict(_, _)
+            | Self::InvalidResponsePayload(_)
+            | Self::UnexpectedResponseOkStrict(_)
+            | Self::UnexpectedResponseOk(_)
+            | Self::ContentType(_) => false,
+        }
+    }
+}
+
 impl BencherClient {
     /// Create a new `BencherClient` with the given parameters
     ///
@@ -66,7 +100,7 @@ impl BencherClient {
     /// - `host`: The host URL
     /// - `token`: The JWT token
     /// - `attempts`: The number of attempts to make before giving up
-    /// - `retry_after`: The number of initial seconds to wait between attempts (exponential backoff)
+    /// - `retry_after`: The number of initial seconds to wait between attempts (exponential backoff with jitter)
     /// - `strict`: Do not retry parsing the response JSON if it fails to deserialize the original client type
     /// - `log`: Whether to log the response JSON to stdout
     pub fn new(
@@ -159,18 +193,7 @@ impl BencherClient {
         Json: DeserializeOwned + Serialize + TryFrom<T, Error = E>,
         E: std::error::Error + Send + Sync + 'static,
     {
-        let timeout = Duration::from_secs(15);
-        let mut client_builder = reqwest::ClientBuilder::new().connect_timeout(timeout);
-
-        if let Some(token) = &self.token {
-            let mut headers = reqwest::header::HeaderMap::new();
-            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
-                .map_err(ClientError::HeaderValue)?;
-            headers.insert("Authorization", bearer_token);
-            client_builder = client_builder.default_headers(headers);
-        }
-
-        let reqwest_client = client_builder.build().map_err(ClientError::BuildClient)?;
+        let reqwest_client = self.reqwest_client()?;
         let client = crate::codegen::Client::new_with_client(self.host.as_ref(), reqwest_client);
 
         let attempts = self.attempts;
@@ -187,18 +210,8 @@ impl BencherClient {
                     self.log(&json_response)?;
                     return Ok(json_response);
                 },
-                #[allow(clippy::print_stderr)]
                 Err(crate::codegen::Error::CommunicationError(e)) => {
-                    if self.log {
-                        eprintln!("\nSend attempt #{}/{attempts}: {e}", attempt + 1);
-                    }
-                    if attempt != max_attempts {
-                        if self.log {
-                            eprintln!("Will retry after {retry_after} second(s).");
-                        }
-                        sleep(Duration::from_secs(retry_after)).await;
-                        retry_after *= 2;
-                    }
+                    self.retry(attempt, &e, &mut retry_after).await;
                 },
                 Err(crate::codegen::Error::InvalidRequest(e)) => {
                     return Err(ClientError::InvalidRequest(e))
@@ -210,13 +223,18 @@ impl BencherClient {
                     let status = e.status();
                     let headers = e.headers().clone();
                     let http_error = e.into_inner();
-                    return Err(ClientError::ErrorResponse(ErrorResponse {
+                    let error_response = ErrorResponse {
                         status,
                         headers,
                         request_id: http_error.request_id,
                         error_code: http_error.error_code,
                         message: http_error.message,
-                    }));
+                    };
+                    if is_transient(status) && attempt != max_attempts {
+                        self.retry(attempt, &error_response, &mut retry_after).await;
+                    } else {
+                        return Err(ClientError::ErrorResponse(error_response));
+                    }
                 },
                 Err(crate::codegen::Error::InvalidUpgrade(e)) => {
                     return Err(ClientError::InvalidUpgrade(e))
@@ -238,7 +256,13 @@ impl BencherClient {
                     }
                 },
                 Err(crate::codegen::Error::UnexpectedResponse(response)) => {
-                    return if response.status().is_success() {
+                    // A proxy in front of the API server may respond with a non-JSON error
+                    let status = response.status();
+                    if is_transient(status) && attempt != max_attempts {
+                        self.retry(attempt, &status, &mut retry_after).await;
+                        continue;
+                    }
+                    return if status.is_success() {
                         if self.strict {
                             Err(ClientError::UnexpectedResponseOkStrict(response))
                         } else {
@@ -252,7 +276,90 @@ impl BencherClient {
                         }
                     } else {
                         Err(ClientError::UnexpectedResponseErr(response))
+                    };
+                },
+            }
+        }
+
+        Err(ClientError::SendTimeout(attempts))
+    }
+
+    /// Upload an attachment to a report
+    ///
+    /// The attachment endpoint takes a `multipart/form-data` request body,
+    /// which is not supported by the generated client.
+    ///
+    /// # Parameters
+    ///
+    /// - `project`: The slug or UUID of the project
+    /// - `report`: The UUID of the report
+    /// - `file_name`: The file name for the attachment
+    /// - `content_type`: The media type of the attachment
+    /// - `data`: The contents of the attachment
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new attachments or an `Error`
+    pub async fn upload_attachment(
+        &self,
+        project: &ResourceId,
+        report: ReportUuid,
+        file_name: String,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<JsonAttachments, ClientError> {
+        let mut url = self.host.clone();
+        url.set_path(&format!(
+            "/v0/projects/{project}/reports/{report}/attachments"
+        ));
+        let reqwest_client = self.reqwest_client()?;
+
+        let attempts = self.attempts;
+        let max_attempts = attempts.checked_sub(1).unwrap_or_default();
+        let mut retry_after = self.retry_after;
+
+        for attempt in 0..attempts {
+            let part = reqwest::multipart::Part::bytes(data.clone())
+                .file_name(file_name.clone())
+                .mime_str(content_type)
+                .map_err(ClientError::ContentType)?;
+            let form = reqwest::multipart::Form::new().part("file", part);
+            match reqwest_client
+                .post(url.clone())
+                .multipart(form)
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => {
+                    let json_attachments = response
+                        .json()
+                        .await
+                        .map_err(ClientError::ResponseBodyError)?;
+                    self.log(&json_attachments)?;
+                    return Ok(json_attachments);
+                },
+                Ok(response) => {
+                    let status = response.status();
+                    if is_transient(status) && attempt != max_attempts {
+                        self.retry(attempt, &status, &mut retry_after).await;
+                        continue;
                     }
+                    let headers = response.headers().clone();
+                    return Err(
+                        match response.json::<crate::codegen::types::Error>().await {
+                            Ok(http_error) => ClientError::ErrorResponse(ErrorResponse {
+                                status,
+                                headers,
+                                request_id: http_error.request_id,
+                                error_code: http_error.error_code,
+                                message: http_error.message,
+                            }),
+                            Err(e) => ClientError::ResponseBodyError(e),
+                        },
+                    );
+                },
+                Err(e) => {
+                    self.retry(attempt, &e, &mut retry_after).await;
                 },
             }
         }
@@ -260,6 +367,50 @@ impl BencherClient {
         Err(ClientError::SendTimeout(attempts))
     }
 
+    /// Log the failed attempt and wait before the next attempt, if there is one.
+    /// The wait time doubles after each attempt (exponential backoff),
+    /// and up to half of the wait time is added at random (jitter)
+    /// so that many clients do not all retry at the same time.
+    #[allow(clippy::print_stderr)]
+    async fn retry(&self, attempt: usize, err: &dyn std::fmt::Display, retry_after: &mut u64) {
+        let attempts = self.attempts;
+        if self.log {
+            eprintln!("\nSend attempt #{}/{attempts}: {err}", attempt + 1);
+        }
+        if attempt + 1 < attempts {
+            let delay = Duration::from_secs(*retry_after);
+            let jitter = delay.mul_f64(rand::random::<f64>() / 2.0);
+            let delay = delay + jitter;
+            if self.log {
+                eprintln!("Will retry after {:.2} second(s).", delay.as_secs_f64());
+            }
+            sleep(delay).await;
+            *retry_after = retry_after.saturating_mul(2);
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn reqwest_client(&self) -> Result<reqwest::Client, ClientError> {
+        let timeout = Duration::from_secs(15);
+        let client_builder = reqwest::ClientBuilder::new().connect_timeout(timeout);
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            BENCHER_CLIENT_VERSION_HEADER,
+            reqwest::header::HeaderValue::from_static(CLIENT_VERSION),
+        );
+        if let Some(token) = &self.token {
+            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
+                .map_err(ClientError::HeaderValue)?;
+            headers.insert("Authorization", bearer_token);
+        }
+
+        client_builder
+            .default_headers(headers)
+            .build()
+            .map_err(ClientError::BuildClient)
+    }
+
     #[allow(clippy::result_large_err)]
     fn log<T>(&self, response: &T) -> Result<(), ClientError>
     where
@@ -299,6 +450,18 @@ impl std::fmt::Display for ErrorResponse {
     }
 }
 
+/// Whether the response status is likely to succeed if the request is retried,
+/// such as when the API server is briefly unavailable
+fn is_transient(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::TOO_MANY_REQUESTS
+            | reqwest::StatusCode::BAD_GATEWAY
+            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+            | reqwest::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
 /// A builder for `BencherClient`
 #[derive(Debug, Clone, Default)]
 pub struct BencherClientBuilder {
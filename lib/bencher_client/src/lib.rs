@@ -10,10 +10,12 @@ mod codegen {
     include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
 }
 mod client;
+mod report;
 
 pub use bencher_json as json;
-pub use client::{BencherClient, BencherClientBuilder, ClientError, ErrorResponse};
+pub use client::{BencherClient, BencherClientBuilder, ClientError, ErrorResponse, CLIENT_VERSION};
 pub use codegen::*;
+pub use report::{ReportBuilder, ReportBuilderError};
 
 macro_rules! from_client {
     ($($name:ident),*) => {
@@ -28,9 +30,11 @@ macro_rules! from_client {
 }
 
 from_client!(
+    BenchmarkGlob,
     BenchmarkName,
     Boundary,
     BranchName,
+    Cidr,
     Email,
     GitHash,
     Index,
@@ -40,6 +44,8 @@ from_client!(
     ResourceId,
     ResourceName,
     SampleSize,
+    ScaleFactor,
+    Secret,
     Slug,
     Url,
     UserName,
@@ -111,8 +117,14 @@ try_from_client!(
     JsonAllowed,
     JsonProjects,
     JsonProject,
+    JsonProjectSchema,
+    JsonMetricKinds,
     JsonReports,
     JsonReport,
+    JsonReportRaw,
+    JsonReportResultList,
+    JsonReportsDeleted,
+    JsonRun,
     JsonPerf,
     JsonPlots,
     JsonPlot,
@@ -120,6 +132,7 @@ try_from_client!(
     JsonBranch,
     JsonBenchmarks,
     JsonBenchmark,
+    JsonFiles,
     JsonTestbeds,
     JsonTestbed,
     JsonMeasures,
@@ -135,13 +148,16 @@ try_from_client!(
     JsonPubUser,
     JsonTokens,
     JsonToken,
+    JsonProjectTokens,
+    JsonProjectToken,
     JsonSignup,
     JsonLogin,
     JsonConfirm,
     JsonAccept,
     JsonAuthAck,
     JsonAuthUser,
-    JsonBackupCreated,
+    JsonMaintenanceResults,
+    JsonOperation,
     JsonConfig,
     JsonConsole,
     JsonApiVersion,
@@ -0,0 +1,259 @@
+use bencher_json::{
+    project::{
+        measure::built_in::{default, BuiltInMeasure},
+        report::{Adapter, JsonReportSettings},
+    },
+    BenchmarkName, DateTime, GitHash, JsonMetricsMap, JsonNewMetric, JsonNewReport, JsonResultsMap,
+    NameId, ReportVersion, ValidError,
+};
+
+use crate::types;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReportBuilderError {
+    #[error("Invalid branch ({branch}): {err}")]
+    Branch { branch: String, err: ValidError },
+    #[error("Invalid testbed ({testbed}): {err}")]
+    Testbed { testbed: String, err: ValidError },
+    #[error("Invalid git hash ({hash}): {err}")]
+    Hash { hash: String, err: ValidError },
+    #[error("Invalid benchmark name ({benchmark}): {err}")]
+    Benchmark { benchmark: String, err: ValidError },
+    #[error("Invalid measure ({measure}): {err}")]
+    Measure { measure: String, err: ValidError },
+    #[error("A metric ({measure}) was added before any benchmark")]
+    NoBenchmark { measure: String },
+    #[error("Bounds were set before any metric was added")]
+    NoMetric,
+    #[error("No metrics were added to the report")]
+    Empty,
+    #[error("Failed to serialize report results: {0}")]
+    Serialize(serde_json::Error),
+}
+
+/// Build a report of custom measurements, without constructing the raw JSON by hand.
+///
+/// Metrics are added to the most recent benchmark,
+/// and bounds are set on the most recent metric.
+/// All names are validated when the report is built.
+///
+/// ```
+/// use bencher_client::ReportBuilder;
+///
+/// let report = ReportBuilder::new("main", "ci-runner")
+///     .benchmark("parse")
+///     .latency_ns(123.4)
+///     .bounds(120.0, 130.0)
+///     .benchmark("serialize")
+///     .throughput(5_000.0)
+///     .measure("max-rss", 1_024.0)
+///     .build()?;
+/// assert_eq!(report.results.len(), 1);
+/// # Ok::<(), bencher_client::ReportBuilderError>(())
+/// ```
+#[derive(Debug)]
+pub struct ReportBuilder {
+    branch: String,
+    testbed: String,
+    hash: Option<String>,
+    start_time: DateTime,
+    end_time: Option<DateTime>,
+    benchmarks: Vec<BenchmarkBuilder>,
+    // Errors are deferred until the report is built, so the builder methods can be chained
+    error: Option<ReportBuilderError>,
+}
+
+#[derive(Debug, Clone)]
+struct BenchmarkBuilder {
+    name: String,
+    metrics: Vec<(String, JsonNewMetric)>,
+}
+
+impl ReportBuilder {
+    /// Create a new report for the branch and testbed, which are created if they do not exist.
+    /// The start time of the report is set to now.
+    pub fn new<B, T>(branch: B, testbed: T) -> Self
+    where
+        B: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            branch: branch.into(),
+            testbed: testbed.into(),
+            hash: None,
+            start_time: DateTime::now(),
+            end_time: None,
+            benchmarks: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Set the full `git` commit hash for the report.
+    #[must_use]
+    pub fn hash<H>(mut self, hash: H) -> Self
+    where
+        H: Into<String>,
+    {
+        self.hash = Some(hash.into());
+        self
+    }
+
+    /// Set the start time for the report.
+    #[must_use]
+    pub fn start_time(mut self, start_time: DateTime) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Set the end time for the report.
+    /// If not set, the end time is set to when the report is built.
+    #[must_use]
+    pub fn end_time(mut self, end_time: DateTime) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Start a new benchmark.
+    /// All following metrics are added to this benchmark.
+    #[must_use]
+    pub fn benchmark<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.benchmarks.push(BenchmarkBuilder {
+            name: name.into(),
+            metrics: Vec::new(),
+        });
+        self
+    }
+
+    /// Add a `latency` metric in nanoseconds to the current benchmark.
+    #[must_use]
+    pub fn latency_ns(self, value: f64) -> Self {
+        self.measure(default::Latency::SLUG_STR, value)
+    }
+
+    /// Add a `throughput` metric in operations per second to the current benchmark.
+    #[must_use]
+    pub fn throughput(self, value: f64) -> Self {
+        self.measure(default::Throughput::SLUG_STR, value)
+    }
+
+    /// Add a metric for the measure UUID, slug, or name to the current benchmark.
+    /// If the measure does not exist, it will be created.
+    #[must_use]
+    pub fn measure<M>(mut self, measure: M, value: f64) -> Self
+    where
+        M: Into<String>,
+    {
+        let measure = measure.into();
+        if let Some(benchmark) = self.benchmarks.last_mut() {
+            benchmark.metrics.push((
+                measure,
+                JsonNewMetric {
+                    value: value.into(),
+                    lower_value: None,
+                    upper_value: None,
+                },
+            ));
+        } else {
+            self.error
+                .get_or_insert(ReportBuilderError::NoBenchmark { measure });
+        }
+        self
+    }
+
+    /// Set the lower and upper values of the most recent metric.
+    #[must_use]
+    pub fn bounds(mut self, lower_value: f64, upper_value: f64) -> Self {
+        let metric = self
+            .benchmarks
+            .last_mut()
+            .and_then(|benchmark| benchmark.metrics.last_mut());
+        if let Some((_, metric)) = metric {
+            metric.lower_value = Some(lower_value.into());
+            metric.upper_value = Some(upper_value.into());
+        } else {
+            self.error.get_or_insert(ReportBuilderError::NoMetric);
+        }
+        self
+    }
+
+    /// Validate and build the report.
+    /// The results are sent using the Bencher Metric Format (BMF) with the `json` adapter.
+    pub fn build(self) -> Result<JsonNewReport, ReportBuilderError> {
+        let Self {
+            branch,
+            testbed,
+            hash,
+            start_time,
+            end_time,
+            benchmarks,
+            error,
+        } = self;
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        let branch = branch
+            .parse::<NameId>()
+            .map_err(|err| ReportBuilderError::Branch { branch, err })?;
+        let testbed = testbed
+            .parse::<NameId>()
+            .map_err(|err| ReportBuilderError::Testbed { testbed, err })?;
+        let hash = hash
+            .map(|hash| {
+                hash.parse::<GitHash>()
+                    .map_err(|err| ReportBuilderError::Hash { hash, err })
+            })
+            .transpose()?;
+
+        let mut results = JsonResultsMap::new();
+        for BenchmarkBuilder { name, metrics } in benchmarks {
+            let benchmark_name =
+                name.parse::<BenchmarkName>()
+                    .map_err(|err| ReportBuilderError::Benchmark {
+                        benchmark: name,
+                        err,
+                    })?;
+            let metrics_map = results.entry(benchmark_name).or_default();
+            for (measure, metric) in metrics {
+                let measure_name_id = measure
+                    .parse::<NameId>()
+                    .map_err(|err| ReportBuilderError::Measure { measure, err })?;
+                metrics_map.insert(measure_name_id, metric);
+            }
+        }
+        if results.values().all(JsonMetricsMap::is_empty) {
+            return Err(ReportBuilderError::Empty);
+        }
+        let results = serde_json::to_string(&results).map_err(ReportBuilderError::Serialize)?;
+
+        Ok(JsonNewReport {
+            version: Some(ReportVersion::CURRENT),
+            branch,
+            hash,
+            start_point: None,
+            testbed,
+            environment: None,
+            thresholds: None,
+            start_time,
+            end_time: end_time.unwrap_or_else(DateTime::now),
+            results: vec![results],
+            runner_results: None,
+            settings: Some(JsonReportSettings {
+                adapter: Some(Adapter::Json),
+                ..Default::default()
+            }),
+            failure: None,
+        })
+    }
+}
+
+impl TryFrom<JsonNewReport> for types::JsonNewReport {
+    type Error = serde_json::Error;
+
+    fn try_from(json: JsonNewReport) -> Result<Self, Self::Error> {
+        serde_json::from_value(serde_json::to_value(json)?)
+    }
+}
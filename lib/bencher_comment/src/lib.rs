@@ -6,7 +6,7 @@ use std::{
 
 use bencher_json::{
     project::{
-        alert::AlertStatus,
+        alert::{AlertSeverity, AlertStatus},
         boundary::BoundaryLimit,
         plot::{LOWER_BOUNDARY, UPPER_BOUNDARY},
         report::{JsonReportIteration, JsonReportMeasure, JsonReportResult},
@@ -31,6 +31,15 @@ pub struct ReportComment {
     missing_threshold: HashSet<Measure>,
     json_report: JsonReport,
     source: String,
+    flamegraphs: Option<Flamegraphs>,
+}
+
+/// Links to the flamegraph attached to the report
+/// and to the flamegraph of the baseline report it is being compared against.
+#[derive(Debug, Clone)]
+pub struct Flamegraphs {
+    pub report: Url,
+    pub baseline: Option<Url>,
 }
 
 impl ReportComment {
@@ -44,9 +53,16 @@ impl ReportComment {
             missing_threshold: Measure::missing_threshold(&json_report),
             json_report,
             source,
+            flamegraphs: None,
         }
     }
 
+    #[must_use]
+    pub fn with_flamegraphs(mut self, flamegraphs: Flamegraphs) -> Self {
+        self.flamegraphs = Some(flamegraphs);
+        self
+    }
+
     pub fn human(&self) -> String {
         let mut text = String::new();
         self.human_results_list(&mut text);
@@ -89,7 +105,7 @@ impl ReportComment {
         text.push_str("\n\nView alerts:");
         for alert in &self.json_report.alerts {
             text.push_str(&format!(
-                "\n- {benchmark_name} ({measure_name}){iter}: {console_url}",
+                "\n- {benchmark_name} ({measure_name}){iter} [{severity}]: {console_url}",
                 benchmark_name = alert.benchmark.name,
                 measure_name = alert.threshold.measure.name,
                 iter = if self.multiple_iterations {
@@ -97,9 +113,18 @@ impl ReportComment {
                 } else {
                     String::new()
                 },
+                severity = alert.severity,
                 console_url = self.alert_perf_url(alert)
             ));
         }
+
+        if let Some(flamegraphs) = &self.flamegraphs {
+            text.push_str("\n\nView flamegraphs:");
+            text.push_str(&format!("\n- Regressed: {}", flamegraphs.report));
+            if let Some(baseline) = &flamegraphs.baseline {
+                text.push_str(&format!("\n- Baseline: {baseline}"));
+            }
+        }
     }
 
     pub fn json(&self) -> Result<String, serde_json::Error> {
@@ -216,6 +241,21 @@ impl ReportComment {
             alert = if alerts_len == 1 { "Alert" } else { "Alerts" },
         ));
         self.html_alerts_table(html);
+        self.html_flamegraphs(html);
+    }
+
+    fn html_flamegraphs(&self, html: &mut String) {
+        let Some(flamegraphs) = &self.flamegraphs else {
+            return;
+        };
+        html.push_str(&format!(
+            "<p>🔥 Flamegraphs: <a href=\"{url}\">regressed</a>",
+            url = flamegraphs.report
+        ));
+        if let Some(baseline) = &flamegraphs.baseline {
+            html.push_str(&format!(" | <a href=\"{baseline}\">baseline</a>"));
+        }
+        html.push_str("</p>");
     }
 
     fn html_alerts_table(&self, html: &mut String) {
@@ -314,8 +354,10 @@ impl ReportComment {
         ));
         html.push_str("<br />");
         html.push_str(&format!(
-            "🚨 <a href=\"{url}\">alert ({status})</a>",
+            "{icon} <a href=\"{url}\">{severity} alert ({status})</a>",
+            icon = alert_severity_icon(alert),
             url = self.resource_url(Resource::Alert(alert.uuid)),
+            severity = alert.severity,
             status = alert_status(alert),
         ));
         html.push_str("<br />");
@@ -509,8 +551,10 @@ impl ReportComment {
         if let Some(alert) = alert {
             html.push_str("<br />");
             html.push_str(&format!(
-                "🚨 <a href=\"{url}\">view alert ({status})</a>",
+                "{icon} <a href=\"{url}\">view {severity} alert ({status})</a>",
+                icon = alert_severity_icon(alert),
                 url = self.resource_url(Resource::Alert(alert.uuid)),
+                severity = alert.severity,
                 status = alert_status(alert),
             ));
         }
@@ -667,6 +711,7 @@ impl ReportComment {
             testbeds: vec![self.json_report.testbed.uuid],
             benchmarks: vec![benchmark.uuid],
             measures: vec![measure.uuid],
+            environment: self.json_report.environment.clone(),
             start_time: Some(
                 (self.json_report.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into(),
             ),
@@ -760,6 +805,13 @@ impl Measure {
     }
 }
 
+fn alert_severity_icon(alert: &JsonAlert) -> &str {
+    match alert.severity {
+        AlertSeverity::Warning => "⚠️",
+        AlertSeverity::Critical => "🚨",
+    }
+}
+
 fn alert_status(alert: &JsonAlert) -> &str {
     match alert.status {
         AlertStatus::Active => "🔔",
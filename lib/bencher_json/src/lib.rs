@@ -1,10 +1,11 @@
 use std::sync::LazyLock;
 
 pub use bencher_valid::{
-    BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
-    Index, IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary,
-    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, Units, Url,
-    UserName, ValidError, Window,
+    strip_ansi_escapes, BenchmarkGlob, BenchmarkName, Boundary, BranchName, CdfBoundary, Cidr,
+    Cron, CusumBoundary, DateTime, DateTimeMillis, Email, GitHash, Index, IqrBoundary, Jwt, Model,
+    ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary, ResourceId, ResourceIdKind,
+    ResourceName, SampleSize, Sanitize, ScaleFactor, Secret, Slug, Units, Url, UserName,
+    ValidError, Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -38,34 +39,56 @@ pub use project::{
     benchmark::{BenchmarkUuid, JsonBenchmark, JsonBenchmarks},
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{BranchUuid, JsonBranch, JsonBranches, JsonNewBranch, JsonNewStartPoint},
+    file::{JsonFiles, JsonNewLocations},
     head::{HeadUuid, JsonHead, JsonStartPoint, VersionUuid},
+    integration::{IntegrationUuid, JsonIntegration, JsonIntegrations, JsonNewIntegration},
     measure::{JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureUuid},
     metric::{
-        JsonMetric, JsonMetricsMap, JsonNewMetric, JsonOneMetric, JsonResultsMap, MeasureNameId,
-        MetricUuid,
+        JsonMetric, JsonMetricsMap, JsonNewMetric, JsonNewOneMetric, JsonOneMetric, JsonResultsMap,
+        MeasureNameId, MetricUuid,
     },
     model::{JsonModel, ModelUuid},
     perf::{JsonPerf, JsonPerfQuery, ReportBenchmarkUuid},
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
-    report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
+    report::{
+        AttachmentUuid, JsonAttachment, JsonAttachments, JsonNewReport, JsonReport,
+        JsonReportFailure, JsonReportRaw, JsonReportRerun, JsonReportResultList, JsonReports,
+        JsonReportsDeleted, JsonVersionedReport, ReportUuid, ReportVersion,
+    },
+    run::{JsonClaimProject, JsonNewRun, JsonRun},
+    schema::{JsonMetricKind, JsonMetricKinds, JsonProjectSchema},
     testbed::{JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedUuid},
     threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    threshold_change::{
+        JsonNewThresholdChange, JsonThresholdChange, JsonThresholdChanges, ThresholdChangeUuid,
+    },
+    threshold_template::{
+        JsonNewThresholdTemplate, JsonThresholdTemplate, JsonThresholdTemplates,
+        ThresholdTemplateUuid,
+    },
+    token::{JsonNewProjectToken, JsonProjectToken, JsonProjectTokens, ProjectTokenUuid},
+    webhook::{
+        JsonNewWebhook, JsonWebhook, JsonWebhookPayload, JsonWebhookRerunPayload, JsonWebhooks,
+        WebhookUuid,
+    },
     JsonNewProject, JsonProject, JsonProjects, ProjectUuid,
 };
 #[cfg(feature = "plus")]
 pub use system::{
     auth::JsonOAuth,
-    config::JsonConsole,
     payment::JsonPayment,
     server::{JsonServer, JsonServerStats, ServerUuid},
 };
 pub use system::{
     auth::{JsonAccept, JsonAuthAck, JsonAuthUser, JsonConfirm, JsonLogin, JsonSignup},
-    backup::{JsonBackup, JsonBackupCreated},
-    config::JsonConfig,
+    backup::{BackupUuid, JsonBackup, JsonServerBackup, JsonServerBackups},
+    config::{JsonConfig, JsonConsole},
+    maintenance::{JsonMaintenance, JsonMaintenanceResults},
+    operation::{JsonOperation, OperationUuid},
     restart::JsonRestart,
     spec::JsonSpec,
-    version::JsonApiVersion,
+    telemetry::JsonTelemetry,
+    version::{JsonApiVersion, BENCHER_CLIENT_VERSION_HEADER},
 };
 pub use user::{
     token::{JsonNewToken, JsonToken, JsonTokens, TokenUuid},
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::sync::LazyLock;
 
 use bencher_valid::{DateTime, ResourceName, Slug};
 use derive_more::Display;
@@ -13,6 +14,14 @@ pub mod member;
 pub mod plan;
 pub mod usage;
 
+pub const UNCLAIMED_ORGANIZATION_STR: &str = "Unclaimed Organization";
+#[allow(clippy::expect_used)]
+static UNCLAIMED_ORGANIZATION: LazyLock<ResourceName> = LazyLock::new(|| {
+    UNCLAIMED_ORGANIZATION_STR
+        .parse()
+        .expect("Failed to parse organization name.")
+});
+
 crate::typed_uuid::typed_uuid!(OrganizationUuid);
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -28,6 +37,16 @@ pub struct JsonNewOrganization {
     pub slug: Option<Slug>,
 }
 
+impl JsonNewOrganization {
+    /// The organization for a project created anonymously by `bencher run`
+    pub fn unclaimed() -> Self {
+        Self {
+            name: UNCLAIMED_ORGANIZATION.clone(),
+            slug: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonOrganizations(pub Vec<JsonOrganization>);
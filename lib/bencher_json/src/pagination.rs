@@ -50,7 +50,12 @@ impl<S> JsonPagination<S> {
         i64::from(self.per_page())
     }
 
-    fn per_page(&self) -> u8 {
+    /// The page number, starting at `1` for the first page
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn per_page(&self) -> u8 {
         self.per_page.unwrap_or(DEFAULT_PER_PAGE)
     }
 }
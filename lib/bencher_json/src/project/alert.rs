@@ -2,7 +2,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, JsonBenchmark, JsonBoundary, JsonMetric, JsonThreshold};
+use crate::{DateTime, JsonBenchmark, JsonBoundary, JsonMetric, JsonThreshold, ModelTest};
 
 use super::{boundary::BoundaryLimit, report::Iteration, report::ReportUuid};
 
@@ -26,6 +26,8 @@ pub struct JsonAlert {
     pub threshold: JsonThreshold,
     pub boundary: JsonBoundary,
     pub limit: BoundaryLimit,
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
     pub status: AlertStatus,
     pub created: DateTime,
     pub modified: DateTime,
@@ -104,6 +106,160 @@ mod alert_status {
     }
 }
 
+const WARNING_INT: i32 = 0;
+const CRITICAL_INT: i32 = 1;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    derive_more::Display,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AlertSeverity {
+    /// The metric exceeded the boundary limit but not the critical boundary limit.
+    #[display("warning")]
+    Warning = WARNING_INT,
+    /// The metric exceeded the critical boundary limit,
+    /// or the threshold model does not have a critical boundary.
+    #[default]
+    #[display("critical")]
+    Critical = CRITICAL_INT,
+}
+
+#[cfg(feature = "db")]
+mod alert_severity {
+    use super::{AlertSeverity, CRITICAL_INT, WARNING_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum AlertSeverityError {
+        #[error("Invalid alert severity value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AlertSeverity
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Warning => WARNING_INT.to_sql(out),
+                Self::Critical => CRITICAL_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AlertSeverity
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                WARNING_INT => Ok(Self::Warning),
+                CRITICAL_INT => Ok(Self::Critical),
+                value => Err(Box::new(AlertSeverityError::Invalid(value))),
+            }
+        }
+    }
+}
+
+const LIMIT_INT: i32 = 0;
+const DRIFT_INT: i32 = 1;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AlertKind {
+    /// A single metric exceeded the boundary limit.
+    #[default]
+    #[display("limit")]
+    Limit = LIMIT_INT,
+    /// A change-point detection model found a statistically significant shift
+    /// in the metric history, such as a gradual regression.
+    #[display("drift")]
+    Drift = DRIFT_INT,
+}
+
+impl From<ModelTest> for AlertKind {
+    fn from(test: ModelTest) -> Self {
+        match test {
+            ModelTest::Cusum => Self::Drift,
+            ModelTest::Static
+            | ModelTest::Percentage
+            | ModelTest::ZScore
+            | ModelTest::TTest
+            | ModelTest::LogNormal
+            | ModelTest::Iqr
+            | ModelTest::DeltaIqr
+            | ModelTest::Percentile => Self::Limit,
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod alert_kind {
+    use super::{AlertKind, DRIFT_INT, LIMIT_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum AlertKindError {
+        #[error("Invalid alert kind value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AlertKind
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Limit => LIMIT_INT.to_sql(out),
+                Self::Drift => DRIFT_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AlertKind
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                LIMIT_INT => Ok(Self::Limit),
+                DRIFT_INT => Ok(Self::Drift),
+                value => Err(Box::new(AlertKindError::Invalid(value))),
+            }
+        }
+    }
+}
+
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -129,6 +285,8 @@ pub enum UpdateAlertStatus {
 pub struct JsonPerfAlert {
     pub uuid: AlertUuid,
     pub limit: BoundaryLimit,
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
     pub status: AlertStatus,
     pub modified: DateTime,
 }
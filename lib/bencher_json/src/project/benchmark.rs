@@ -5,7 +5,7 @@ use bencher_valid::{BenchmarkName, DateTime, Slug};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::ProjectUuid;
+use crate::{BranchUuid, JsonMetric, MeasureUuid, ProjectUuid, ReportUuid, TestbedUuid};
 
 crate::typed_uuid::typed_uuid!(BenchmarkUuid);
 
@@ -39,6 +39,10 @@ pub struct JsonBenchmark {
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
+    /// The most recent metric for each branch, testbed, and measure.
+    /// This is only included when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<Vec<JsonBenchmarkLatest>>,
 }
 
 impl fmt::Display for JsonBenchmark {
@@ -47,6 +51,20 @@ impl fmt::Display for JsonBenchmark {
     }
 }
 
+/// The most recent metric for a benchmark on the current head of a branch for a testbed and measure.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBenchmarkLatest {
+    pub branch: BranchUuid,
+    pub testbed: TestbedUuid,
+    pub measure: MeasureUuid,
+    pub report: ReportUuid,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    pub metric: JsonMetric,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonUpdateBenchmark {
@@ -1,3 +1,4 @@
+use bencher_valid::ScaleFactor;
 use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -20,6 +21,16 @@ pub struct JsonBoundary {
     pub upper_limit: Option<OrderedFloat<f64>>,
 }
 
+impl JsonBoundary {
+    /// Normalize the boundary values by the testbed scale factor
+    pub fn normalize(&mut self, scale: ScaleFactor) {
+        let scale = f64::from(scale);
+        self.baseline = self.baseline.map(|value| OrderedFloat(value.0 * scale));
+        self.lower_limit = self.lower_limit.map(|value| OrderedFloat(value.0 * scale));
+        self.upper_limit = self.upper_limit.map(|value| OrderedFloat(value.0 * scale));
+    }
+}
+
 const LOWER_BOOL: bool = false;
 const UPPER_BOOL: bool = true;
 
@@ -46,6 +46,10 @@ pub struct JsonNewBranch {
     /// After the new branch is created, it is not kept in sync with the start point branch.
     /// If not provided, the new branch will have no historical data.
     pub start_point: Option<JsonNewStartPoint>,
+    /// If set to `true` and a branch with the same name or slug already exists,
+    /// then the existing branch is returned instead of creating a new one.
+    /// The start point of an existing branch is left unchanged.
+    pub upsert: Option<bool>,
 }
 
 impl JsonNewBranch {
@@ -54,6 +58,7 @@ impl JsonNewBranch {
             name: BRANCH_MAIN.clone(),
             slug: BRANCH_MAIN_SLUG.clone(),
             start_point: None,
+            upsert: None,
         }
     }
 }
@@ -95,6 +100,8 @@ pub struct JsonBranch {
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
+    pub merged: Option<DateTime>,
+    pub protection: JsonBranchProtection,
 }
 
 impl fmt::Display for JsonBranch {
@@ -116,6 +123,38 @@ pub struct JsonUpdateBranch {
     pub start_point: Option<JsonUpdateStartPoint>,
     /// Set whether the branch is archived.
     pub archived: Option<bool>,
+    /// The new protection settings for the branch.
+    /// The user must have `manage` permissions for the organization to change them.
+    pub protection: Option<JsonBranchProtection>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBranchProtection {
+    /// The branch can not be deleted.
+    pub delete: bool,
+    /// The thresholds for the branch can only be loosened or removed
+    /// by a user with `manage` permissions for the organization.
+    pub thresholds: bool,
+    /// Reports for the branch are only accepted from API tokens
+    /// that are limited to a scope or a single project.
+    pub reports: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMergeBranch {
+    /// The UUID, slug, or name of the branch that was merged into, such as `main`.
+    pub target: NameId,
+    /// The full `git` hash of the merge commit on the target branch.
+    /// Requires the `graft` field to be set.
+    pub hash: Option<GitHash>,
+    /// If set to `true`, the most recent metrics for the merged branch will be grafted onto the target branch
+    /// as the version for the merge commit.
+    /// This prevents a discontinuity in the target branch history at each merge.
+    /// Boundaries and alerts are not grafted.
+    pub graft: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,97 @@
+use bencher_valid::{BenchmarkName, ResourceName, Slug};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::BenchmarkUuid;
+
+/// A mapping of benchmarks to their source code locations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewLocations {
+    /// The source code locations of the benchmarks.
+    /// If a benchmark does not exist, it will be created.
+    /// If a benchmark already has a location, it will be replaced.
+    pub locations: Vec<JsonNewLocation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewLocation {
+    /// The name of the benchmark.
+    pub benchmark: BenchmarkName,
+    /// The path to the source file that contains the benchmark, relative to the repository root.
+    pub file: String,
+    /// The line number in the source file where the benchmark is defined.
+    pub line: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonFiles(pub Vec<JsonFile>);
+
+crate::from_vec!(JsonFiles[JsonFile]);
+
+/// The current status of the benchmarks in a source file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonFile {
+    /// The path to the source file, relative to the repository root.
+    pub file: String,
+    /// The benchmarks in the source file, sorted by line number.
+    pub benchmarks: Vec<JsonFileBenchmark>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonFileBenchmark {
+    pub uuid: BenchmarkUuid,
+    pub name: BenchmarkName,
+    /// The line number in the source file where the benchmark is defined.
+    pub line: Option<u32>,
+    /// The latest metric for each measure of the benchmark.
+    /// This is empty if the benchmark has no results for the branch and testbed.
+    pub metrics: Vec<JsonFileMetric>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonFileMetric {
+    /// The measure slug.
+    pub measure: Slug,
+    /// The units for the measure.
+    pub units: ResourceName,
+    /// The latest metric value.
+    pub value: f64,
+    /// The previous metric value, if any.
+    pub previous: Option<f64>,
+    /// The trend from the previous metric value to the latest metric value.
+    pub trend: JsonTrend,
+    /// Whether the latest metric generated an active alert.
+    pub alert: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonTrend {
+    /// There is no previous metric value.
+    New,
+    /// The latest metric value is greater than the previous metric value.
+    Up,
+    /// The latest metric value is less than the previous metric value.
+    Down,
+    /// The latest metric value is equal to the previous metric value.
+    Flat,
+}
+
+impl JsonTrend {
+    pub fn new(value: f64, previous: Option<f64>) -> Self {
+        match previous.and_then(|previous| value.partial_cmp(&previous)) {
+            None => Self::New,
+            Some(std::cmp::Ordering::Greater) => Self::Up,
+            Some(std::cmp::Ordering::Less) => Self::Down,
+            Some(std::cmp::Ordering::Equal) => Self::Flat,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use bencher_valid::{DateTime, NonEmpty, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::ProjectUuid;
+
+crate::typed_uuid::typed_uuid!(IntegrationUuid);
+
+/// The default issue title template.
+pub const DEFAULT_ISSUE_TITLE: &str = "Performance regression: {benchmark} ({measure})";
+/// The default issue body template.
+pub const DEFAULT_ISSUE_BODY: &str = "\
+A critical alert was generated for **{benchmark}** on branch `{branch}` with testbed `{testbed}`.
+
+| Measure | Value | Baseline | Delta | {limit} Limit |
+| --- | --- | --- | --- | --- |
+| {measure} | {value} | {baseline} | {delta} | {boundary} |
+
+- Alert: {alert_url}
+- Report: {report_url}
+
+This issue will be closed automatically once the alert is resolved.";
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewIntegration {
+    /// The issue tracker provider.
+    pub provider: IntegrationProvider,
+    /// The repository to open issues in.
+    /// For GitHub, this is `owner/repo`.
+    /// For GitLab, this is the full path of the project, ie `group/project`.
+    pub repository: NonEmpty,
+    /// The base URL for the provider API.
+    /// If not set, then the public GitHub or GitLab API is used.
+    pub api_url: Option<Url>,
+    /// The API token used to open and close issues.
+    /// It must have permission to write issues for the repository.
+    pub token: Secret,
+    /// The template for the issue title.
+    /// If not set, then a default template is used.
+    pub title: Option<NonEmpty>,
+    /// The template for the issue body.
+    /// If not set, then a default template is used.
+    pub body: Option<NonEmpty>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonIntegrations(pub Vec<JsonIntegration>);
+
+crate::from_vec!(JsonIntegrations[JsonIntegration]);
+
+/// An issue tracker integration for a project.
+/// When a critical alert is generated, an issue is opened.
+/// When a later report no longer generates an alert for the same threshold and benchmark,
+/// the alert is resolved and the issue is closed.
+/// The issue is also closed if the alert is dismissed or silenced.
+/// Templates may use the placeholders `{project}`, `{benchmark}`, `{measure}`, `{branch}`, `{testbed}`,
+/// `{value}`, `{baseline}`, `{delta}`, `{limit}`, `{boundary}`, `{alert_url}`, and `{report_url}`.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonIntegration {
+    pub uuid: IntegrationUuid,
+    pub project: ProjectUuid,
+    pub provider: IntegrationProvider,
+    pub repository: NonEmpty,
+    pub api_url: Url,
+    pub title: NonEmpty,
+    pub body: NonEmpty,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateIntegration {
+    /// The new repository to open issues in.
+    pub repository: Option<NonEmpty>,
+    /// The new base URL for the provider API.
+    pub api_url: Option<Url>,
+    /// The new API token used to open and close issues.
+    pub token: Option<Secret>,
+    /// The new template for the issue title.
+    pub title: Option<NonEmpty>,
+    /// The new template for the issue body.
+    pub body: Option<NonEmpty>,
+}
+
+const GITHUB_INT: i32 = 0;
+const GITLAB_INT: i32 = 1;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "lowercase")]
+#[repr(i32)]
+pub enum IntegrationProvider {
+    /// GitHub Issues
+    GitHub = GITHUB_INT,
+    /// GitLab Issues
+    GitLab = GITLAB_INT,
+}
+
+impl IntegrationProvider {
+    /// The default base URL for the provider API
+    pub fn default_api_url(self) -> &'static str {
+        match self {
+            Self::GitHub => "https://api.github.com",
+            Self::GitLab => "https://gitlab.com/api/v4",
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod integration_provider {
+    use super::{IntegrationProvider, GITHUB_INT, GITLAB_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum IntegrationProviderError {
+        #[error("Invalid integration provider value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for IntegrationProvider
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::GitHub => GITHUB_INT.to_sql(out),
+                Self::GitLab => GITLAB_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for IntegrationProvider
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                GITHUB_INT => Ok(Self::GitHub),
+                GITLAB_INT => Ok(Self::GitLab),
+                value => Err(Box::new(IntegrationProviderError::Invalid(value))),
+            }
+        }
+    }
+}
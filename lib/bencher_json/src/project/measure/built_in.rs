@@ -1,4 +1,4 @@
-use crate::JsonNewMeasure;
+use crate::{project::boundary::BoundaryLimit, JsonNewMeasure};
 use bencher_valid::NameId;
 
 pub trait BuiltInMeasure {
@@ -29,10 +29,25 @@ pub trait BuiltInMeasure {
             units: Self::UNITS_STR
                 .parse()
                 .expect("Failed to parse measure units."),
+            upsert: None,
         }
     }
 }
 
+/// The catalog of well-known measures that are added to every new project,
+/// along with the boundary limit that indicates a regression for each measure.
+pub fn catalog() -> Vec<(JsonNewMeasure, BoundaryLimit)> {
+    vec![
+        (default::Latency::new_json(), BoundaryLimit::Upper),
+        (default::Throughput::new_json(), BoundaryLimit::Lower),
+        (json::MaxRss::new_json(), BoundaryLimit::Upper),
+        (json::FileSize::new_json(), BoundaryLimit::Upper),
+        (json::BuildTime::new_json(), BoundaryLimit::Upper),
+        (json::Energy::new_json(), BoundaryLimit::Upper),
+        (iai::Instructions::new_json(), BoundaryLimit::Upper),
+    ]
+}
+
 macro_rules! create_measure {
     ($id:ident, $name:literal, $slug:literal, $units:expr) => {
         pub struct $id;
@@ -58,10 +73,27 @@ pub mod default {
 }
 
 pub mod json {
-    use bencher_valid::{BYTES, SECONDS};
+    use bencher_valid::{BYTES, JOULES, NANOSECONDS, SECONDS};
 
     create_measure!(BuildTime, "Build Time", "build-time", SECONDS);
     create_measure!(FileSize, "File Size", "file-size", BYTES);
+    create_measure!(
+        TimeToFirstByte,
+        "Time to First Byte",
+        "time-to-first-byte",
+        NANOSECONDS
+    );
+    create_measure!(MaxRss, "Max RSS", "max-rss", BYTES);
+    create_measure!(Energy, "Energy", "energy", JOULES);
+}
+
+pub mod report {
+    use bencher_valid::SECONDS;
+
+    /// The benchmark used to track the report duration
+    pub const BENCHMARK_NAME: &str = "bencher::report";
+
+    create_measure!(Duration, "Report Duration", "report-duration", SECONDS);
 }
 
 pub mod iai {
@@ -27,6 +27,9 @@ pub struct JsonNewMeasure {
     /// The units of measure.
     /// Maximum length is 64 characters.
     pub units: ResourceName,
+    /// If set to `true` and a measure with the same name or slug already exists,
+    /// then the existing measure is returned instead of creating a new one.
+    pub upsert: Option<bool>,
 }
 
 impl JsonNewMeasure {
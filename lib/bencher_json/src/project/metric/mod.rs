@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, collections::HashMap, fmt, iter::Sum, ops::Add};
+use std::{cmp::Ordering, collections::BTreeMap, fmt, iter::Sum, ops::Add};
 
-use bencher_valid::{BenchmarkName, DateTime, NameId};
+use bencher_valid::{BenchmarkName, DateTime, GitHash, NameId, ResourceName, ScaleFactor};
 use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -19,10 +19,10 @@ use super::{alert::JsonPerfAlert, report::Iteration, threshold::JsonThresholdMod
 crate::typed_uuid::typed_uuid!(MetricUuid);
 
 #[typeshare::typeshare]
-pub type JsonResultsMap = HashMap<BenchmarkName, JsonMetricsMap>;
+pub type JsonResultsMap = BTreeMap<BenchmarkName, JsonMetricsMap>;
 
 #[typeshare::typeshare]
-pub type JsonMetricsMap = HashMap<MeasureNameId, JsonNewMetric>;
+pub type JsonMetricsMap = BTreeMap<MeasureNameId, JsonNewMetric>;
 
 #[typeshare::typeshare]
 pub type MeasureNameId = NameId;
@@ -155,6 +155,40 @@ impl Mean for JsonNewMetric {}
 
 impl Median for JsonNewMetric {}
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewOneMetric {
+    /// Branch UUID, slug, or name.
+    /// If the branch does not exist, it will be created.
+    pub branch: NameId,
+    /// Full `git` commit hash.
+    /// All metrics with the same `git` commit hash will be considered part of the same branch version.
+    pub hash: Option<GitHash>,
+    /// Testbed UUID, slug, or name.
+    /// If the testbed does not exist, it will be created.
+    pub testbed: NameId,
+    /// The environment the metric was measured against, such as `staging` or `production`.
+    pub environment: Option<ResourceName>,
+    /// The name of the benchmark.
+    /// If the benchmark does not exist, it will be created.
+    pub benchmark: BenchmarkName,
+    /// Measure UUID, slug, or name.
+    /// If the measure does not exist, it will be created.
+    pub measure: MeasureNameId,
+    /// The value of the metric.
+    pub value: OrderedFloat<f64>,
+    /// The lower value of the metric, if any.
+    pub lower_value: Option<OrderedFloat<f64>>,
+    /// The upper value of the metric, if any.
+    pub upper_value: Option<OrderedFloat<f64>>,
+    /// Start time for the measurement. Must be an ISO 8601 formatted string.
+    /// If not provided, the current time will be used.
+    pub start_time: Option<DateTime>,
+    /// End time for the measurement. Must be an ISO 8601 formatted string.
+    /// If not provided, the start time will be used.
+    pub end_time: Option<DateTime>,
+}
+
 #[typeshare::typeshare]
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -165,6 +199,16 @@ pub struct JsonMetric {
     pub upper_value: Option<OrderedFloat<f64>>,
 }
 
+impl JsonMetric {
+    /// Normalize the metric values by the testbed scale factor
+    pub fn normalize(&mut self, scale: ScaleFactor) {
+        let scale = f64::from(scale);
+        self.value = OrderedFloat(self.value.0 * scale);
+        self.lower_value = self.lower_value.map(|value| OrderedFloat(value.0 * scale));
+        self.upper_value = self.upper_value.map(|value| OrderedFloat(value.0 * scale));
+    }
+}
+
 impl fmt::Display for JsonMetric {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.value)
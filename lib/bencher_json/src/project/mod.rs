@@ -1,4 +1,4 @@
-use std::{fmt, str::FromStr};
+use std::{fmt, str::FromStr, sync::LazyLock};
 
 use bencher_valid::{DateTime, ResourceName, Slug, Url};
 use derive_more::Display;
@@ -15,15 +15,31 @@ pub mod alert;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
+pub mod file;
 pub mod head;
+pub mod integration;
 pub mod measure;
 pub mod metric;
 pub mod model;
 pub mod perf;
 pub mod plot;
 pub mod report;
+pub mod run;
+pub mod schema;
 pub mod testbed;
 pub mod threshold;
+pub mod threshold_change;
+pub mod threshold_template;
+pub mod token;
+pub mod webhook;
+
+pub const UNCLAIMED_PROJECT_STR: &str = "Unclaimed Project";
+#[allow(clippy::expect_used)]
+static UNCLAIMED_PROJECT: LazyLock<ResourceName> = LazyLock::new(|| {
+    UNCLAIMED_PROJECT_STR
+        .parse()
+        .expect("Failed to parse project name.")
+});
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 
@@ -47,6 +63,19 @@ pub struct JsonNewProject {
     pub visibility: Option<Visibility>,
 }
 
+impl JsonNewProject {
+    /// A project created anonymously by `bencher run`.
+    /// Unclaimed projects are always public.
+    pub fn unclaimed() -> Self {
+        Self {
+            name: UNCLAIMED_PROJECT.clone(),
+            slug: None,
+            url: None,
+            visibility: Some(Visibility::Public),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonProjects(pub Vec<JsonProject>);
@@ -16,6 +16,8 @@ pub struct JsonModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
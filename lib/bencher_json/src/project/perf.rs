@@ -1,3 +1,4 @@
+use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::ser::{self, SerializeStruct};
@@ -5,12 +6,13 @@ use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 
 use crate::urlencoded::{
-    from_urlencoded_list, from_urlencoded_nullable_list, to_urlencoded, to_urlencoded_list,
-    to_urlencoded_optional_list, UrlEncodedError,
+    from_urlencoded, from_urlencoded_list, from_urlencoded_nullable_list, to_urlencoded,
+    to_urlencoded_list, to_urlencoded_optional_list, UrlEncodedError,
 };
 use crate::{
     BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, HeadUuid, JsonBenchmark, JsonBranch,
-    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
+    JsonMeasure, JsonNewMetric, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, ResourceName,
+    ScaleFactor, TestbedUuid,
 };
 
 use super::alert::JsonPerfAlert;
@@ -38,13 +40,48 @@ pub struct JsonPerfQueryParams {
     /// A comma separated list of testbed UUIDs to query.
     pub testbeds: String,
     /// A comma separated list of benchmark UUIDs to query.
+    /// This may be left empty if `benchmark_prefix` is set.
     pub benchmarks: String,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
+    /// Only include metrics from reports run against the given environment.
+    pub environment: Option<String>,
     /// Search for metrics after the given date time in milliseconds.
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Also query every benchmark in the project with a name that starts with the given prefix.
+    pub benchmark_prefix: Option<String>,
+    /// Aggregate the metrics for all of the queried benchmarks into a single series
+    /// for each branch, testbed, and measure.
+    pub aggregate: Option<JsonPerfAggregate>,
+    /// Only return metrics from reports that come after the report with the given UUID.
+    /// Metrics are ordered by version number, report start time, report, and then iteration.
+    /// This should be set to the UUID of the report for the last metric in the previous page.
+    pub after: Option<ReportUuid>,
+    /// The maximum number of metrics to return for each permutation.
+    /// A page always ends with the last iteration of a report,
+    /// so fewer metrics than the limit may be returned even if there are more.
+    pub limit: Option<u32>,
+    /// Normalize the metrics and boundaries by the scale factor of their testbed.
+    /// This allows for comparing metrics across testbeds with different hardware.
+    pub normalize: Option<bool>,
+}
+
+impl JsonPerfQueryParams {
+    pub fn cursor(&self) -> JsonPerfCursor {
+        JsonPerfCursor {
+            after: self.after,
+            limit: self.limit,
+        }
+    }
+}
+
+/// `JsonPerfCursor` is the keyset pagination for the metrics of each perf query permutation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonPerfCursor {
+    pub after: Option<ReportUuid>,
+    pub limit: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -64,6 +101,8 @@ pub struct JsonPerfImgQueryParams {
     pub benchmarks: String,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
+    /// Only include metrics from reports run against the given environment.
+    pub environment: Option<String>,
     /// Search for metrics after the given date time in milliseconds.
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
@@ -79,6 +118,7 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
         } = query;
@@ -88,8 +128,14 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
+            benchmark_prefix: None,
+            aggregate: None,
+            after: None,
+            limit: None,
+            normalize: None,
         }
     }
 }
@@ -104,6 +150,7 @@ pub struct JsonPerfQuery {
     pub testbeds: Vec<TestbedUuid>,
     pub benchmarks: Vec<BenchmarkUuid>,
     pub measures: Vec<MeasureUuid>,
+    pub environment: Option<ResourceName>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
 }
@@ -118,8 +165,14 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
+            benchmark_prefix,
+            aggregate: _,
+            after: _,
+            limit: _,
+            normalize: _,
         } = query_params;
 
         if branches.is_empty() {
@@ -128,7 +181,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
         if testbeds.is_empty() {
             return Err(UrlEncodedError::EmptyTestbeds);
         }
-        if benchmarks.is_empty() {
+        if benchmarks.is_empty() && benchmark_prefix.is_none() {
             return Err(UrlEncodedError::EmptyBenchmarks);
         }
         if measures.is_empty() {
@@ -138,8 +191,13 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
         let branches = from_urlencoded_list(&branches)?;
         let heads = from_urlencoded_nullable_list(heads.as_deref())?;
         let testbeds = from_urlencoded_list(&testbeds)?;
-        let benchmarks = from_urlencoded_list(&benchmarks)?;
+        let benchmarks = if benchmarks.is_empty() {
+            Vec::new()
+        } else {
+            from_urlencoded_list(&benchmarks)?
+        };
         let measures = from_urlencoded_list(&measures)?;
+        let environment = environment.as_deref().map(from_urlencoded).transpose()?;
 
         // Guarantee that the `heads` array is the same length as the `branches` array.
         let heads = size_heads_to_branches(&branches, &heads);
@@ -150,6 +208,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
         })
@@ -210,7 +269,7 @@ impl JsonPerfQuery {
         serde_urlencoded::to_string(query).map_err(Into::into)
     }
 
-    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 7], UrlEncodedError> {
+    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 8], UrlEncodedError> {
         QUERY_KEYS
             .into_iter()
             .zip([
@@ -219,6 +278,7 @@ impl JsonPerfQuery {
                 Some(self.testbeds()),
                 Some(self.benchmarks()),
                 Some(self.measures()),
+                self.environment(),
                 self.start_time_str(),
                 self.end_time_str(),
             ])
@@ -251,6 +311,10 @@ impl JsonPerfQuery {
         to_urlencoded_list(&self.measures)
     }
 
+    pub fn environment(&self) -> Option<String> {
+        self.environment.as_ref().map(to_urlencoded)
+    }
+
     pub fn start_time(&self) -> Option<DateTimeMillis> {
         self.start_time.map(Into::into)
     }
@@ -278,6 +342,7 @@ pub enum PerfQueryKey {
     Testbeds,
     Benchmarks,
     Measures,
+    Environment,
     StartTime,
     EndTime,
 }
@@ -287,10 +352,18 @@ pub const HEADS: &str = "heads";
 pub const TESTBEDS: &str = "testbeds";
 pub const BENCHMARKS: &str = "benchmarks";
 pub const MEASURES: &str = "measures";
+pub const ENVIRONMENT: &str = "environment";
 pub const START_TIME: &str = "start_time";
 pub const END_TIME: &str = "end_time";
-const QUERY_KEYS: [&str; 7] = [
-    BRANCHES, HEADS, TESTBEDS, BENCHMARKS, MEASURES, START_TIME, END_TIME,
+const QUERY_KEYS: [&str; 8] = [
+    BRANCHES,
+    HEADS,
+    TESTBEDS,
+    BENCHMARKS,
+    MEASURES,
+    ENVIRONMENT,
+    START_TIME,
+    END_TIME,
 ];
 
 #[typeshare::typeshare]
@@ -301,6 +374,8 @@ pub struct JsonPerf {
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
     pub results: Vec<JsonPerfMetrics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregates: Option<Vec<JsonPerfAggregateMetrics>>,
 }
 
 #[typeshare::typeshare]
@@ -330,6 +405,156 @@ pub struct JsonPerfMetric {
     pub alert: Option<JsonPerfAlert>,
 }
 
+impl JsonPerfMetric {
+    /// Normalize the metric and boundary values by the testbed scale factor
+    pub fn normalize(&mut self, scale: ScaleFactor) {
+        if scale.is_one() {
+            return;
+        }
+        self.metric.normalize(scale);
+        if let Some(boundary) = &mut self.boundary {
+            boundary.normalize(scale);
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonPerfAggregate {
+    Sum,
+    Mean,
+    Max,
+}
+
+/// The metrics for a set of benchmarks aggregated into a single series
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfAggregateMetrics {
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub measure: JsonMeasure,
+    pub aggregate: JsonPerfAggregate,
+    pub benchmarks: Vec<JsonBenchmark>,
+    pub metrics: Vec<JsonPerfAggregateMetric>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfAggregateMetric {
+    pub report: ReportUuid,
+    pub iteration: Iteration,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    pub version: JsonVersion,
+    pub metric: JsonNewMetric,
+    /// The number of benchmarks with a metric for the report iteration
+    pub benchmarks: u32,
+}
+
+impl JsonPerfAggregate {
+    /// Aggregate the perf results into a single series for each branch, testbed, and measure.
+    /// Each report iteration is aggregated over the benchmarks that have a metric for it.
+    /// The lower and upper values are only aggregated if every benchmark has them.
+    pub fn aggregate(self, results: &[JsonPerfMetrics]) -> Vec<JsonPerfAggregateMetrics> {
+        let mut aggregates: Vec<(JsonPerfAggregateMetrics, Vec<Vec<JsonNewMetric>>)> = Vec::new();
+        for result in results {
+            let index = if let Some(index) = aggregates.iter().position(|(aggregate, _)| {
+                aggregate.branch.uuid == result.branch.uuid
+                    && aggregate.testbed.uuid == result.testbed.uuid
+                    && aggregate.measure.uuid == result.measure.uuid
+            }) {
+                index
+            } else {
+                aggregates.push((
+                    JsonPerfAggregateMetrics {
+                        branch: result.branch.clone(),
+                        testbed: result.testbed.clone(),
+                        measure: result.measure.clone(),
+                        aggregate: self,
+                        benchmarks: Vec::new(),
+                        metrics: Vec::new(),
+                    },
+                    Vec::new(),
+                ));
+                aggregates.len() - 1
+            };
+            let Some((aggregate, values)) = aggregates.get_mut(index) else {
+                continue;
+            };
+            aggregate.benchmarks.push(result.benchmark.clone());
+            for perf_metric in &result.metrics {
+                let metric = JsonNewMetric {
+                    value: perf_metric.metric.value,
+                    lower_value: perf_metric.metric.lower_value,
+                    upper_value: perf_metric.metric.upper_value,
+                };
+                if let Some(position) = aggregate.metrics.iter().position(|aggregate_metric| {
+                    aggregate_metric.report == perf_metric.report
+                        && aggregate_metric.iteration == perf_metric.iteration
+                }) {
+                    if let Some(values) = values.get_mut(position) {
+                        values.push(metric);
+                    }
+                } else {
+                    aggregate.metrics.push(JsonPerfAggregateMetric {
+                        report: perf_metric.report,
+                        iteration: perf_metric.iteration,
+                        start_time: perf_metric.start_time,
+                        end_time: perf_metric.end_time,
+                        version: perf_metric.version.clone(),
+                        metric,
+                        benchmarks: 0,
+                    });
+                    values.push(vec![metric]);
+                }
+            }
+        }
+
+        aggregates
+            .into_iter()
+            .map(|(mut aggregate, values)| {
+                for (aggregate_metric, values) in aggregate.metrics.iter_mut().zip(values) {
+                    aggregate_metric.benchmarks = u32::try_from(values.len()).unwrap_or(u32::MAX);
+                    aggregate_metric.metric = JsonNewMetric {
+                        value: self
+                            .apply(values.iter().map(|metric| Some(metric.value)))
+                            .unwrap_or_default(),
+                        lower_value: self.apply(values.iter().map(|metric| metric.lower_value)),
+                        upper_value: self.apply(values.iter().map(|metric| metric.upper_value)),
+                    };
+                }
+                // Keep the same order as the perf metrics
+                aggregate.metrics.sort_by_key(|aggregate_metric| {
+                    (
+                        aggregate_metric.version.number.0,
+                        aggregate_metric.start_time.timestamp(),
+                    )
+                });
+                aggregate
+            })
+            .collect()
+    }
+
+    // Returns `None` if any of the values are missing
+    fn apply<I>(self, values: I) -> Option<OrderedFloat<f64>>
+    where
+        I: Iterator<Item = Option<OrderedFloat<f64>>>,
+    {
+        let values = values.collect::<Option<Vec<_>>>()?;
+        let sum = || values.iter().map(|value| value.0).sum::<f64>();
+        match self {
+            Self::Sum => Some(sum().into()),
+            #[allow(clippy::cast_precision_loss)]
+            Self::Mean => (!values.is_empty()).then(|| (sum() / values.len() as f64).into()),
+            Self::Max => values.iter().max().copied(),
+        }
+    }
+}
+
 #[cfg(feature = "table")]
 pub mod table {
     use std::fmt;
@@ -346,43 +571,47 @@ pub mod table {
 
     impl From<JsonPerf> for Table {
         fn from(json_perf: JsonPerf) -> Self {
-            let mut perf_table = Vec::new();
-            for result in json_perf.results {
-                for metric in result.metrics {
-                    let (baseline, lower_limit, upper_limit) =
-                        if let Some(boundary) = metric.boundary {
-                            (
-                                DisplayOption(boundary.baseline),
-                                DisplayOption(boundary.lower_limit),
-                                DisplayOption(boundary.upper_limit),
-                            )
-                        } else {
-                            (
-                                DisplayOption::default(),
-                                DisplayOption::default(),
-                                DisplayOption::default(),
-                            )
-                        };
-                    perf_table.push(PerfTable {
-                        project: json_perf.project.clone(),
-                        branch: result.branch.clone(),
-                        testbed: result.testbed.clone(),
-                        benchmark: result.benchmark.clone(),
-                        measure: result.measure.clone(),
-                        iteration: metric.iteration,
-                        start_time: metric.start_time,
-                        end_time: metric.end_time,
-                        version_number: metric.version.number,
-                        version_hash: DisplayOption(metric.version.hash),
-                        metric: metric.metric,
-                        baseline,
-                        lower_limit,
-                        upper_limit,
-                    });
-                }
+            Self::new(perf_table(json_perf))
+        }
+    }
+
+    /// Flatten the perf results into a row for each metric
+    pub fn perf_table(json_perf: JsonPerf) -> Vec<PerfTable> {
+        let mut perf_table = Vec::new();
+        for result in json_perf.results {
+            for metric in result.metrics {
+                let (baseline, lower_limit, upper_limit) = if let Some(boundary) = metric.boundary {
+                    (
+                        DisplayOption(boundary.baseline),
+                        DisplayOption(boundary.lower_limit),
+                        DisplayOption(boundary.upper_limit),
+                    )
+                } else {
+                    (
+                        DisplayOption::default(),
+                        DisplayOption::default(),
+                        DisplayOption::default(),
+                    )
+                };
+                perf_table.push(PerfTable {
+                    project: json_perf.project.clone(),
+                    branch: result.branch.clone(),
+                    testbed: result.testbed.clone(),
+                    benchmark: result.benchmark.clone(),
+                    measure: result.measure.clone(),
+                    iteration: metric.iteration,
+                    start_time: metric.start_time,
+                    end_time: metric.end_time,
+                    version_number: metric.version.number,
+                    version_hash: DisplayOption(metric.version.hash),
+                    metric: metric.metric,
+                    baseline,
+                    lower_limit,
+                    upper_limit,
+                });
             }
-            Self::new(perf_table)
         }
+        perf_table
     }
 
     #[derive(Tabled)]
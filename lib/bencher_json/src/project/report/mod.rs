@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt};
 
-use bencher_valid::{DateTime, DateTimeMillis, GitHash, Model};
+use bencher_valid::{BenchmarkName, DateTime, DateTimeMillis, GitHash, Model, ResourceName};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -8,16 +8,24 @@ use serde::{Deserialize, Serialize};
 use crate::{
     urlencoded::{from_urlencoded, to_urlencoded, UrlEncodedError},
     JsonAlert, JsonBenchmark, JsonBoundary, JsonBranch, JsonMeasure, JsonMetric, JsonProject,
-    JsonPubUser, JsonTestbed, NameId,
+    JsonPubUser, JsonResultsMap, JsonTestbed, NameId, WebhookUuid,
 };
 
 use super::{branch::JsonUpdateStartPoint, threshold::JsonThresholdModel};
 
+mod version;
+
+pub use version::{JsonVersionedReport, ReportVersion, ReportVersionError};
+
 crate::typed_uuid::typed_uuid!(ReportUuid);
+crate::typed_uuid::typed_uuid!(AttachmentUuid);
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonNewReport {
+    /// The version of the report schema.
+    /// If not provided, the report is assumed to be version `0` and is migrated to the current version.
+    pub version: Option<ReportVersion>,
     /// Branch UUID, slug, or name.
     /// If the branch does not exist, it will be created.
     pub branch: NameId,
@@ -38,6 +46,10 @@ pub struct JsonNewReport {
     /// Testbed UUID, slug, or name.
     /// If the testbed does not exist, it will be created.
     pub testbed: NameId,
+    /// The environment the benchmarks were run against, such as `staging` or `production`.
+    /// This allows the same benchmarks on the same testbed to be filtered by environment.
+    /// Threshold boundaries are only calculated from reports in the same environment.
+    pub environment: Option<ResourceName>,
     /// Thresholds to use for the branch, testbed, and measures in the report.
     /// If a threshold does not exist, it will be created.
     /// If a threshold exists and the model is different, it will be updated with the new model.
@@ -49,8 +61,15 @@ pub struct JsonNewReport {
     pub end_time: DateTime,
     /// An array of benchmarks results.
     pub results: Vec<String>,
+    /// An array of results measured by the benchmark runner, such as peak memory usage.
+    /// If provided, there must be one set of runner results for each of the benchmarks results.
+    /// Each set of runner results is merged with the adapter results for the same iteration.
+    pub runner_results: Option<Vec<JsonResultsMap>>,
     /// Settings for how to handle the report.
     pub settings: Option<JsonReportSettings>,
+    /// The exit code and standard error output of the benchmark command, if it failed.
+    /// This is stored with the report, so it is possible to see why a run failed.
+    pub failure: Option<JsonReportFailure>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,7 +85,7 @@ pub struct JsonReportThresholds {
     pub reset: Option<bool>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReportSettings {
     /// The benchmark harness adapter for parsing the benchmark results.
@@ -78,6 +97,100 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// Pairs of benchmarks run together to compare a candidate against a baseline.
+    /// For each pair, the ratio of the candidate to the baseline is added as its own benchmark,
+    /// named `{candidate} / {baseline}`, for every measure that both benchmarks share.
+    /// Comparing within a single run is far less noisy than comparing across runs.
+    pub pairs: Option<Vec<JsonBenchmarkPair>>,
+    /// Store the raw benchmark harness output alongside the report.
+    /// The raw output is compressed and can be retrieved later to audit the parsed results.
+    /// If the compressed output is larger than 1 MB, then it will not be stored.
+    pub raw: Option<bool>,
+    /// Track the total wall time of the report, from the start time to the end time.
+    /// The duration is recorded in seconds for the `bencher::report` benchmark
+    /// using the `Report Duration` measure, so thresholds can be set on it like any other metric.
+    pub duration: Option<bool>,
+    /// The `git` hash of the base commit to compare against, such as the merge base of a pull request.
+    /// Threshold evaluation only uses metrics up to and including the most recent version with this hash
+    /// on the branch, rather than the entire branch.
+    /// If the hash is not found on the branch, then the entire branch is used.
+    pub base_hash: Option<GitHash>,
+    /// The number of consecutive results that make up a single iteration, such as multiple results files.
+    /// Each of the results is parsed separately and then merged into a single set of results for the iteration.
+    /// If not specified, then each of the results is its own iteration.
+    pub results_per_iteration: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBenchmarkPair {
+    /// The name of the baseline benchmark, ie the denominator.
+    pub baseline: BenchmarkName,
+    /// The name of the candidate benchmark, ie the numerator.
+    pub candidate: BenchmarkName,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportFailure {
+    /// The exit code of the benchmark command.
+    pub exit_code: i32,
+    /// The standard error output of the benchmark command.
+    /// If the output is larger than 64 KB, then only the end of the output is stored.
+    pub stderr: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportRaw {
+    /// The UUID for the report.
+    pub report: ReportUuid,
+    /// The raw benchmark harness output for each iteration.
+    pub results: Vec<String>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportRerun {
+    /// The UUID for the report.
+    pub report: ReportUuid,
+    /// The project webhooks that were sent the rerun request.
+    pub webhooks: Vec<WebhookUuid>,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAttachments(pub Vec<JsonAttachment>);
+
+crate::from_vec!(JsonAttachments[JsonAttachment]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAttachment {
+    pub uuid: AttachmentUuid,
+    /// The file name of the attachment.
+    pub name: String,
+    /// The media type of the attachment, ie `image/svg+xml`.
+    pub content_type: String,
+    /// The size of the attachment in bytes.
+    pub size: u64,
+    pub created: DateTime,
+}
+
+/// The file stem used for flamegraph attachments, ie `flamegraph.svg`
+pub const FLAMEGRAPH_ATTACHMENT: &str = "flamegraph";
+
+impl JsonAttachment {
+    pub fn is_flamegraph(&self) -> bool {
+        self.name
+            .split_once('.')
+            .map_or(self.name.as_str(), |(stem, _)| stem)
+            == FLAMEGRAPH_ATTACHMENT
+    }
 }
 
 const MAGIC_INT: i32 = 0;
@@ -106,9 +219,13 @@ const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
 const SHELL_INT: i32 = 100;
 const SHELL_HYPERFINE_INT: i32 = 101;
+const SHELL_MEASURE_COMMAND_INT: i32 = 102;
+const SCALA_SCALAMETER_INT: i32 = 111;
+const PHP_BENCH_INT: i32 = 121;
+const SWIFT_XCTEST_INT: i32 = 131;
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
 #[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
@@ -142,6 +259,10 @@ pub enum Adapter {
     RubyBenchmark = RUBY_BENCHMARK_INT,
     Shell = SHELL_INT,
     ShellHyperfine = SHELL_HYPERFINE_INT,
+    ShellMeasureCommand = SHELL_MEASURE_COMMAND_INT,
+    ScalaScalameter = SCALA_SCALAMETER_INT,
+    PhpBench = PHP_BENCH_INT,
+    SwiftXctest = SWIFT_XCTEST_INT,
 }
 
 impl fmt::Display for Adapter {
@@ -173,6 +294,10 @@ impl fmt::Display for Adapter {
             Self::RubyBenchmark => write!(f, "ruby_benchmark"),
             Self::Shell => write!(f, "shell"),
             Self::ShellHyperfine => write!(f, "shell_hyperfine"),
+            Self::ShellMeasureCommand => write!(f, "shell_measure_command"),
+            Self::ScalaScalameter => write!(f, "scala_scalameter"),
+            Self::PhpBench => write!(f, "php_bench"),
+            Self::SwiftXctest => write!(f, "swift_xctest"),
         }
     }
 }
@@ -182,9 +307,10 @@ mod adapter {
     use super::{
         Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT, C_SHARP_DOT_NET_INT, C_SHARP_INT,
         GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT,
-        JS_TIME_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT, RUST_IAI_INT,
-        RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        JS_TIME_INT, MAGIC_INT, PHP_BENCH_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT,
+        RUBY_BENCHMARK_INT, RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT,
+        RUST_IAI_INT, RUST_INT, SCALA_SCALAMETER_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        SHELL_MEASURE_COMMAND_INT, SWIFT_XCTEST_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -229,6 +355,10 @@ mod adapter {
                 Self::RubyBenchmark => RUBY_BENCHMARK_INT.to_sql(out),
                 Self::Shell => SHELL_INT.to_sql(out),
                 Self::ShellHyperfine => SHELL_HYPERFINE_INT.to_sql(out),
+                Self::ShellMeasureCommand => SHELL_MEASURE_COMMAND_INT.to_sql(out),
+                Self::ScalaScalameter => SCALA_SCALAMETER_INT.to_sql(out),
+                Self::PhpBench => PHP_BENCH_INT.to_sql(out),
+                Self::SwiftXctest => SWIFT_XCTEST_INT.to_sql(out),
             }
         }
     }
@@ -266,6 +396,10 @@ mod adapter {
                 RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
                 SHELL_INT => Ok(Self::Shell),
                 SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
+                SHELL_MEASURE_COMMAND_INT => Ok(Self::ShellMeasureCommand),
+                SCALA_SCALAMETER_INT => Ok(Self::ScalaScalameter),
+                PHP_BENCH_INT => Ok(Self::PhpBench),
+                SWIFT_XCTEST_INT => Ok(Self::SwiftXctest),
                 value => Err(Box::new(AdapterError::Invalid(value))),
             }
         }
@@ -291,6 +425,59 @@ pub enum JsonFold {
     Median,
 }
 
+/// The adapter selected by the `magic` adapter to parse the results.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterSelection {
+    /// The adapter used to parse the results.
+    pub adapter: Adapter,
+    /// How confident the `magic` adapter is that it selected the right adapter.
+    pub confidence: JsonAdapterConfidence,
+    /// Any other adapters that were also able to parse the results.
+    pub alternatives: Vec<Adapter>,
+}
+
+impl fmt::Display for JsonAdapterSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} confidence)", self.adapter, self.confidence)?;
+        if !self.alternatives.is_empty() {
+            let alternatives = self
+                .alternatives
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, ", also matched: {alternatives}")?;
+        }
+        Ok(())
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonAdapterConfidence {
+    /// Another adapter parsed more benchmarks than the selected adapter.
+    Low,
+    /// Other adapters were also able to parse the results,
+    /// but none of them parsed more benchmarks than the selected adapter.
+    Medium,
+    /// No other adapter was able to parse the results.
+    High,
+}
+
+impl fmt::Display for JsonAdapterConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Low => write!(f, "low"),
+            Self::Medium => write!(f, "medium"),
+            Self::High => write!(f, "high"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReports(pub Vec<JsonReport>);
@@ -302,21 +489,45 @@ crate::from_vec!(JsonReports[JsonReport]);
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReport {
     pub uuid: ReportUuid,
-    pub user: JsonPubUser,
+    /// The user who created the report.
+    /// This is only missing for reports sent anonymously to an unclaimed project.
+    pub user: Option<JsonPubUser>,
     pub project: JsonProject,
     pub branch: JsonBranch,
     pub testbed: JsonTestbed,
+    pub environment: Option<ResourceName>,
     pub start_time: DateTime,
     pub end_time: DateTime,
     pub adapter: Adapter,
+    /// The adapter selected to parse the results, if the `magic` adapter was used.
+    /// This is only included in the response when creating a report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adapter_selection: Option<JsonAdapterSelection>,
     pub results: JsonReportResults,
     pub alerts: JsonReportAlerts,
+    /// The artifacts attached to the report, such as flamegraphs.
+    pub attachments: Option<JsonReportAttachments>,
+    /// The exit code and standard error output of the benchmark command, if it failed.
+    pub failure: Option<JsonReportFailure>,
     pub created: DateTime,
 }
 
+impl JsonReport {
+    /// The flamegraph attached to the report, if any
+    pub fn flamegraph(&self) -> Option<&JsonAttachment> {
+        self.attachments
+            .as_ref()?
+            .iter()
+            .find(|attachment| attachment.is_flamegraph())
+    }
+}
+
 #[typeshare::typeshare]
 pub type JsonReportResults = Vec<JsonReportIteration>;
 
+#[typeshare::typeshare]
+pub type JsonReportAttachments = Vec<JsonAttachment>;
+
 #[typeshare::typeshare]
 pub type JsonReportIteration = Vec<JsonReportResult>;
 
@@ -329,6 +540,12 @@ pub struct JsonReportResult {
     pub measures: Vec<JsonReportMeasure>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportResultList(pub Vec<JsonReportResult>);
+
+crate::from_vec!(JsonReportResultList[JsonReportResult]);
+
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -353,9 +570,15 @@ pub struct JsonReportQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Filter for reports before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Filter by the benchmark harness adapter used to parse the report results.
+    pub adapter: Option<Adapter>,
     /// If set to `true`, only return reports with an archived branch or testbed.
     /// If not set or set to `false`, only returns reports with non-archived branches and testbeds.
     pub archived: Option<bool>,
+    /// Only return reports that come after the report with the given UUID in the sort order.
+    /// This is a cursor for keyset pagination and should be set to the UUID of the last report in the previous page.
+    /// When set, the `page` query parameter is ignored.
+    pub after: Option<ReportUuid>,
 }
 
 #[derive(Debug, Clone)]
@@ -364,7 +587,9 @@ pub struct JsonReportQuery {
     pub testbed: Option<NameId>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub adapter: Option<Adapter>,
     pub archived: Option<bool>,
+    pub after: Option<ReportUuid>,
 }
 
 impl TryFrom<JsonReportQueryParams> for JsonReportQuery {
@@ -376,7 +601,9 @@ impl TryFrom<JsonReportQueryParams> for JsonReportQuery {
             testbed,
             start_time,
             end_time,
+            adapter,
             archived,
+            after,
         } = query_params;
 
         let branch = if let Some(branch) = branch {
@@ -395,7 +622,9 @@ impl TryFrom<JsonReportQueryParams> for JsonReportQuery {
             testbed,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            adapter,
             archived,
+            after,
         })
     }
 }
@@ -418,6 +647,62 @@ impl JsonReportQuery {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportsDeleteParams {
+    /// Delete reports for the branch with the given UUID, slug, or name exact match.
+    pub branch: String,
+    /// Delete reports that ended before the given date time in milliseconds.
+    pub before: DateTimeMillis,
+    /// If set to `true`, only return the reports that would be deleted without deleting them.
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonReportsDeleteQuery {
+    pub branch: NameId,
+    pub before: DateTime,
+    pub dry_run: Option<bool>,
+}
+
+impl TryFrom<JsonReportsDeleteParams> for JsonReportsDeleteQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonReportsDeleteParams) -> Result<Self, Self::Error> {
+        let JsonReportsDeleteParams {
+            branch,
+            before,
+            dry_run,
+        } = query_params;
+        Ok(Self {
+            branch: from_urlencoded(&branch)?,
+            before: before.into(),
+            dry_run,
+        })
+    }
+}
+
+impl JsonReportsDeleteQuery {
+    pub fn branch(&self) -> String {
+        to_urlencoded(&self.branch)
+    }
+
+    pub fn before(&self) -> DateTimeMillis {
+        self.before.into()
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportsDeleted {
+    /// The UUIDs of the reports that were deleted, from oldest to newest.
+    /// For a dry run, these are the reports that would have been deleted.
+    pub reports: Vec<ReportUuid>,
+    /// Whether this was a dry run, in which case no reports were deleted.
+    pub dry_run: bool,
+}
+
 #[typeshare::typeshare]
 #[derive(
     Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Display, Serialize, Deserialize,
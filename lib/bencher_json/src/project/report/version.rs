@@ -0,0 +1,258 @@
+use std::fmt;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{
+    de::{self, Deserializer},
+    Deserialize, Serialize,
+};
+use serde_json::{Map, Value};
+
+use super::JsonNewReport;
+
+const VERSION_KEY: &str = "version";
+
+/// The version of the report schema.
+/// Reports without a version are considered version `0`.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct ReportVersion(u32);
+
+impl ReportVersion {
+    /// Reports sent before the report schema was versioned
+    pub const V0: Self = Self(0);
+    /// Reports with the start point nested in a `start_point` object
+    pub const V1: Self = Self(1);
+    /// The current version of the report schema
+    pub const CURRENT: Self = Self::V1;
+}
+
+impl fmt::Display for ReportVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<ReportVersion> for u32 {
+    fn from(version: ReportVersion) -> Self {
+        version.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReportVersionError {
+    #[error("Report must be a JSON object")]
+    NotObject,
+    #[error("Invalid report version: {0}")]
+    Version(serde_json::Error),
+    #[error("Report version ({version}) is newer than the latest supported report version ({current}). Upgrade the Bencher API server in order to accept this report.", current = ReportVersion::CURRENT)]
+    Unsupported { version: ReportVersion },
+    #[error("Invalid report version {version} field ({field}): {value}")]
+    Field {
+        version: ReportVersion,
+        field: &'static str,
+        value: String,
+    },
+    #[error("Failed to deserialize report version {version}: {error}")]
+    Deserialize {
+        version: ReportVersion,
+        error: serde_json::Error,
+    },
+}
+
+/// A new report of any supported report schema version.
+/// When deserialized, older report versions are migrated to the current report version.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct JsonVersionedReport(pub JsonNewReport);
+
+impl From<JsonVersionedReport> for JsonNewReport {
+    fn from(versioned: JsonVersionedReport) -> Self {
+        versioned.0
+    }
+}
+
+impl TryFrom<Value> for JsonVersionedReport {
+    type Error = ReportVersionError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Object(mut report) = value else {
+            return Err(ReportVersionError::NotObject);
+        };
+        let version = match report.get(VERSION_KEY) {
+            Some(Value::Null) | None => ReportVersion::V0,
+            Some(version) => {
+                ReportVersion::deserialize(version).map_err(ReportVersionError::Version)?
+            },
+        };
+        if version > ReportVersion::CURRENT {
+            return Err(ReportVersionError::Unsupported { version });
+        }
+
+        // Migrate one version at a time, until the report is at the current version
+        if version < ReportVersion::V1 {
+            v0_to_v1(&mut report)?;
+        }
+        report.insert(
+            VERSION_KEY.into(),
+            Value::from(u32::from(ReportVersion::CURRENT)),
+        );
+
+        serde_json::from_value(Value::Object(report))
+            .map(Self)
+            .map_err(|error| ReportVersionError::Deserialize { version, error })
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonVersionedReport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer)?
+            .try_into()
+            .map_err(de::Error::custom)
+    }
+}
+
+// The versioned report has the same schema as the current report version
+#[cfg(feature = "schema")]
+impl JsonSchema for JsonVersionedReport {
+    fn schema_name() -> String {
+        JsonNewReport::schema_name()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        JsonNewReport::schema_id()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        JsonNewReport::json_schema(gen)
+    }
+}
+
+// Version `0` reports set the start point with top level `branch_start_point`,
+// `branch_start_point_hash`, and `branch_reset` fields.
+// Version `1` nests these in a `start_point` object.
+fn v0_to_v1(report: &mut Map<String, Value>) -> Result<(), ReportVersionError> {
+    const V0_START_POINT: [(&str, &str); 3] = [
+        ("branch_start_point", "branch"),
+        ("branch_start_point_hash", "hash"),
+        ("branch_reset", "reset"),
+    ];
+
+    let mut start_point = Map::new();
+    for (v0_field, v1_field) in V0_START_POINT {
+        let value = match report.remove(v0_field) {
+            Some(Value::Null) | None => continue,
+            // The start point branch used to be an array, of which only the first was used
+            Some(Value::Array(branches)) if v0_field == "branch_start_point" => {
+                match branches.into_iter().next() {
+                    Some(branch) => branch,
+                    None => continue,
+                }
+            },
+            Some(value) => value,
+        };
+        start_point.insert(v1_field.into(), value);
+    }
+
+    if start_point.is_empty() {
+        return Ok(());
+    }
+    match report.get("start_point") {
+        Some(Value::Null) | None => {
+            report.insert("start_point".into(), Value::Object(start_point));
+            Ok(())
+        },
+        Some(value) => Err(ReportVersionError::Field {
+            version: ReportVersion::V0,
+            field: "start_point",
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use super::{JsonVersionedReport, ReportVersion, ReportVersionError};
+
+    fn new_report(fields: Value) -> Value {
+        let mut report = json!({
+            "branch": "main",
+            "testbed": "localhost",
+            "start_time": "2024-01-01T00:00:00Z",
+            "end_time": "2024-01-01T00:01:00Z",
+            "results": []
+        });
+        if let (Value::Object(report), Value::Object(fields)) = (&mut report, fields) {
+            report.extend(fields);
+        }
+        report
+    }
+
+    #[test]
+    fn test_report_version_current() {
+        let value = new_report(
+            json!({ "version": 1, "start_point": { "branch": "develop", "reset": true } }),
+        );
+        let report = JsonVersionedReport::try_from(value).unwrap().0;
+        assert_eq!(report.version, Some(ReportVersion::CURRENT));
+        let start_point = report.start_point.unwrap();
+        assert_eq!(start_point.branch.unwrap().as_ref(), "develop");
+        assert_eq!(start_point.reset, Some(true));
+    }
+
+    #[test]
+    fn test_report_version_v0() {
+        let report = JsonVersionedReport::try_from(new_report(json!({})))
+            .unwrap()
+            .0;
+        assert_eq!(report.version, Some(ReportVersion::CURRENT));
+        assert!(report.start_point.is_none());
+
+        let value = new_report(
+            json!({ "branch_start_point": ["develop", "main"], "branch_start_point_hash": "1234567890abcdef1234567890abcdef12345678", "branch_reset": true }),
+        );
+        let report = JsonVersionedReport::try_from(value).unwrap().0;
+        assert_eq!(report.version, Some(ReportVersion::CURRENT));
+        let start_point = report.start_point.unwrap();
+        assert_eq!(start_point.branch.unwrap().as_ref(), "develop");
+        assert_eq!(
+            start_point.hash.unwrap().as_ref(),
+            "1234567890abcdef1234567890abcdef12345678"
+        );
+        assert_eq!(start_point.reset, Some(true));
+    }
+
+    #[test]
+    fn test_report_version_v0_conflict() {
+        let value = new_report(
+            json!({ "branch_start_point": "develop", "start_point": { "branch": "main" } }),
+        );
+        assert!(matches!(
+            JsonVersionedReport::try_from(value),
+            Err(ReportVersionError::Field { .. })
+        ));
+    }
+
+    #[test]
+    fn test_report_version_unsupported() {
+        let value = new_report(json!({ "version": u32::from(ReportVersion::CURRENT) + 1 }));
+        assert!(matches!(
+            JsonVersionedReport::try_from(value),
+            Err(ReportVersionError::Unsupported { .. })
+        ));
+
+        let value = new_report(json!({ "version": "one" }));
+        assert!(matches!(
+            JsonVersionedReport::try_from(value),
+            Err(ReportVersionError::Version(_))
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+use bencher_valid::Secret;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonReport, JsonVersionedReport};
+
+/// A new report sent anonymously to an unclaimed project
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewRun {
+    /// The key for an unclaimed project, as returned by a previous anonymous run.
+    /// If not provided, a new unclaimed project will be created.
+    pub key: Option<Secret>,
+    /// The report to add to the unclaimed project.
+    pub report: JsonVersionedReport,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRun {
+    /// The key for the unclaimed project.
+    /// Use it to send more reports to the same project
+    /// or to claim the project after signing up.
+    pub key: Secret,
+    pub report: JsonReport,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonClaimProject {
+    /// The key for the unclaimed project.
+    pub key: Secret,
+}
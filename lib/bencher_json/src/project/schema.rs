@@ -0,0 +1,115 @@
+use bencher_valid::{BenchmarkName, BranchName, Model, ResourceName, Slug, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{benchmark::BenchmarkUuid, measure::MeasureUuid, testbed::TestbedUuid, Visibility};
+
+/// The full configuration of a project.
+/// Only the fields that can be set by a user are included,
+/// and every list is sorted by slug so the output is stable across exports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectSchema {
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub url: Option<Url>,
+    pub visibility: Visibility,
+    /// The unarchived branches for the project.
+    pub branches: Vec<JsonSchemaBranch>,
+    /// The unarchived testbeds for the project.
+    pub testbeds: Vec<JsonSchemaTestbed>,
+    /// The unarchived measures for the project.
+    pub measures: Vec<JsonSchemaMeasure>,
+    /// The thresholds for the unarchived branches, testbeds, and measures of the project.
+    /// Thresholds are sorted by branch, testbed, and then measure slug.
+    pub thresholds: Vec<JsonSchemaThreshold>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSchemaBranch {
+    pub name: BranchName,
+    pub slug: Slug,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSchemaTestbed {
+    pub name: ResourceName,
+    pub slug: Slug,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSchemaMeasure {
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub units: ResourceName,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSchemaThreshold {
+    /// The branch slug for the threshold.
+    pub branch: Slug,
+    /// The testbed slug for the threshold.
+    pub testbed: Slug,
+    /// The measure slug for the threshold.
+    pub measure: Slug,
+    /// The current threshold model, if any.
+    pub model: Option<Model>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricKinds(pub Vec<JsonMetricKind>);
+
+crate::from_vec!(JsonMetricKinds[JsonMetricKind]);
+
+/// A kind of metric that can be tracked for a project, which is backed by a measure.
+/// This includes where data exists for the metric kind,
+/// so clients can populate their options without hardcoding them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricKind {
+    pub uuid: MeasureUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub units: ResourceName,
+    /// The direction in which the metric kind improves, if it is known.
+    pub direction: Option<MetricDirection>,
+    /// The unarchived benchmarks that have metrics for the metric kind, sorted by slug.
+    pub benchmarks: Vec<JsonMetricKindBenchmark>,
+    /// The unarchived testbeds that have metrics for the metric kind, sorted by slug.
+    pub testbeds: Vec<JsonMetricKindTestbed>,
+}
+
+/// The direction in which a metric kind improves.
+/// The direction is inferred from the boundaries of the thresholds for the measure.
+/// If there are no threshold boundaries, then well-known measures use their default direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MetricDirection {
+    /// An increase is a regression, such as for latency.
+    LowerIsBetter,
+    /// A decrease is a regression, such as for throughput.
+    HigherIsBetter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricKindBenchmark {
+    pub uuid: BenchmarkUuid,
+    pub name: BenchmarkName,
+    pub slug: Slug,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricKindTestbed {
+    pub uuid: TestbedUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+}
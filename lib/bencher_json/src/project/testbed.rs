@@ -1,7 +1,7 @@
 use std::fmt;
 use std::sync::LazyLock;
 
-use bencher_valid::{DateTime, ResourceName, Slug};
+use bencher_valid::{DateTime, ResourceName, ScaleFactor, Slug};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -37,6 +37,15 @@ pub struct JsonNewTestbed {
     /// If the provided or generated slug is already in use, a unique slug will be generated.
     /// Maximum length is 64 characters.
     pub slug: Option<Slug>,
+    /// The scale factor used to normalize metrics from this testbed
+    /// so that they can be compared to metrics from other testbeds.
+    /// Normalized metric values are the raw values multiplied by this factor.
+    /// Must be a positive number.
+    /// If not provided, the scale factor will be `1.0`.
+    pub scale: Option<ScaleFactor>,
+    /// If set to `true` and a testbed with the same name or slug already exists,
+    /// then the existing testbed is returned instead of creating a new one.
+    pub upsert: Option<bool>,
 }
 
 impl JsonNewTestbed {
@@ -44,6 +53,8 @@ impl JsonNewTestbed {
         Self {
             name: TESTBED_LOCALHOST.clone(),
             slug: TESTBED_LOCALHOST_SLUG.clone(),
+            scale: None,
+            upsert: None,
         }
     }
 }
@@ -62,6 +73,9 @@ pub struct JsonTestbed {
     pub project: ProjectUuid,
     pub name: ResourceName,
     pub slug: Slug,
+    /// The scale factor used to normalize metrics from this testbed.
+    #[serde(default)]
+    pub scale: ScaleFactor,
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
@@ -82,6 +96,9 @@ pub struct JsonUpdateTestbed {
     /// The preferred new slug for the testbed.
     /// Maximum length is 64 characters.
     pub slug: Option<Slug>,
+    /// The new scale factor used to normalize metrics from this testbed.
+    /// Set to `1.0` to stop scaling metrics from this testbed.
+    pub scale: Option<ScaleFactor>,
     /// Set whether the testbed is archived.
     pub archived: Option<bool>,
 }
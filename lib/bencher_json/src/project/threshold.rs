@@ -1,6 +1,6 @@
 use std::fmt;
 
-use bencher_valid::{DateTime, Model, NameId};
+use bencher_valid::{BenchmarkGlob, DateTime, Model, NameId};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{
@@ -24,6 +24,13 @@ pub struct JsonNewThreshold {
     pub testbed: NameId,
     /// The UUID, slug, or name of the threshold measure.
     pub measure: NameId,
+    /// A benchmark name or glob pattern of benchmark names for the threshold.
+    /// A `*` matches any number of characters and a `?` matches exactly one character.
+    /// Use this to give noisy benchmarks a looser threshold than the rest of the suite.
+    /// The most specific matching threshold is used for each benchmark.
+    /// If not set, then the threshold applies to all benchmarks without a more specific threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkGlob>,
     #[serde(flatten)]
     pub model: Model,
 }
@@ -43,6 +50,8 @@ pub struct JsonThreshold {
     pub branch: JsonBranch,
     pub testbed: JsonTestbed,
     pub measure: JsonMeasure,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkGlob>,
     pub model: Option<JsonModel>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -152,6 +161,7 @@ pub struct JsonRemoveModel {
 }
 
 impl<'de> Deserialize<'de> for JsonUpdateThreshold {
+    #[allow(clippy::too_many_lines)]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -162,6 +172,8 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
         const WINDOW_FIELD: &str = "window";
         const LOWER_BOUNDARY_FIELD: &str = "lower_boundary";
         const UPPER_BOUNDARY_FIELD: &str = "upper_boundary";
+        const LOWER_CRITICAL_FIELD: &str = "lower_critical";
+        const UPPER_CRITICAL_FIELD: &str = "upper_critical";
 
         const FIELDS: &[&str] = &[
             TEST_FIELD,
@@ -170,6 +182,8 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             WINDOW_FIELD,
             LOWER_BOUNDARY_FIELD,
             UPPER_BOUNDARY_FIELD,
+            LOWER_CRITICAL_FIELD,
+            UPPER_CRITICAL_FIELD,
         ];
 
         #[derive(Deserialize)]
@@ -181,6 +195,8 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             Window,
             LowerBoundary,
             UpperBoundary,
+            LowerCritical,
+            UpperCritical,
         }
 
         struct UpdateThresholdVisitor;
@@ -202,6 +218,8 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                 let mut window = None;
                 let mut lower_boundary = None;
                 let mut upper_boundary = None;
+                let mut lower_critical = None;
+                let mut upper_critical = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -241,6 +259,18 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             }
                             upper_boundary = Some(map.next_value()?);
                         },
+                        Field::LowerCritical => {
+                            if lower_critical.is_some() {
+                                return Err(de::Error::duplicate_field(LOWER_CRITICAL_FIELD));
+                            }
+                            lower_critical = Some(map.next_value()?);
+                        },
+                        Field::UpperCritical => {
+                            if upper_critical.is_some() {
+                                return Err(de::Error::duplicate_field(UPPER_CRITICAL_FIELD));
+                            }
+                            upper_critical = Some(map.next_value()?);
+                        },
                     }
                 }
 
@@ -253,6 +283,8 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             window,
                             lower_boundary,
                             upper_boundary,
+                            lower_critical,
+                            upper_critical,
                         },
                     })),
                     Some(None) => Ok(Self::Value::Remove(JsonRemoveModel { test: () })),
@@ -0,0 +1,44 @@
+use bencher_valid::{DateTime, Model};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonPubUser, ProjectUuid, ThresholdUuid};
+
+crate::typed_uuid::typed_uuid!(ThresholdChangeUuid);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewThresholdChange {
+    /// The UUID of the threshold to change.
+    pub threshold: ThresholdUuid,
+    /// The new model for the threshold.
+    /// If not set, the current model for the threshold is removed.
+    pub model: Option<Model>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonThresholdChanges(pub Vec<JsonThresholdChange>);
+
+crate::from_vec!(JsonThresholdChanges[JsonThresholdChange]);
+
+/// A change to a threshold for a branch with protected thresholds.
+/// The change is pending until it is approved by a different user.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonThresholdChange {
+    pub uuid: ThresholdChangeUuid,
+    pub project: ProjectUuid,
+    pub threshold: ThresholdUuid,
+    /// The new model for the threshold.
+    /// If not set, the current model for the threshold is removed.
+    pub model: Option<Model>,
+    /// The user who requested the change.
+    pub requester: JsonPubUser,
+    /// The user who approved the change, once it has been approved.
+    pub approver: Option<JsonPubUser>,
+    pub created: DateTime,
+    /// The date time the change was approved and applied to the threshold.
+    pub approved: Option<DateTime>,
+}
@@ -0,0 +1,46 @@
+use bencher_valid::{Boundary, DateTime, Model, ModelTest, SampleSize, Slug, Window};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{OrganizationUuid, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(ThresholdTemplateUuid);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewThresholdTemplate {
+    /// The slug of the measure to create thresholds for.
+    /// A threshold is only created for a measure with this exact slug.
+    pub measure: Slug,
+    #[serde(flatten)]
+    pub model: Model,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonThresholdTemplates(pub Vec<JsonThresholdTemplate>);
+
+crate::from_vec!(JsonThresholdTemplates[JsonThresholdTemplate]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonThresholdTemplate {
+    pub uuid: ThresholdTemplateUuid,
+    pub organization: OrganizationUuid,
+    /// If set, the template only applies to this project.
+    /// Otherwise, the template applies to all projects in the organization.
+    pub project: Option<ProjectUuid>,
+    pub measure: Slug,
+    pub test: ModelTest,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
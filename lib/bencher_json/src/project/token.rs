@@ -0,0 +1,55 @@
+use bencher_valid::{DateTime, Jwt, ResourceName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{user::token::TokenScope, ProjectUuid, UserUuid};
+
+crate::typed_uuid::typed_uuid!(ProjectTokenUuid);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewProjectToken {
+    /// The name of the token.
+    /// Maximum length is 64 characters.
+    pub name: ResourceName,
+    /// The time-to-live (TTL) for the token in seconds.
+    /// If not provided, the token will not expire for over 128 years.
+    pub ttl: Option<u32>,
+    /// The scope of the token within the project.
+    /// If not provided, the token has the `report:write` scope.
+    pub scope: Option<TokenScope>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectTokens(pub Vec<JsonProjectToken>);
+
+crate::from_vec!(JsonProjectTokens[JsonProjectToken]);
+
+/// An API token for a project.
+/// The token can only access its project, with the permissions of its scope,
+/// regardless of the project role of the user who created it.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectToken {
+    pub uuid: ProjectTokenUuid,
+    pub project: ProjectUuid,
+    /// The user who created the token.
+    pub creator: UserUuid,
+    pub name: ResourceName,
+    pub token: Jwt,
+    pub scope: TokenScope,
+    pub creation: DateTime,
+    pub expiration: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateProjectToken {
+    /// The new name of the token.
+    /// Maximum length is 64 characters.
+    pub name: Option<ResourceName>,
+}
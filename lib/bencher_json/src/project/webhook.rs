@@ -0,0 +1,84 @@
+use bencher_valid::{DateTime, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    JsonAlert, JsonBranch, JsonProject, JsonPubUser, JsonTestbed, ProjectUuid, ReportUuid,
+};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+
+/// The header with the Unix timestamp, in seconds, of when a webhook was sent.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Bencher-Timestamp";
+/// The header with the HMAC-SHA256 signature of a webhook, as `sha256=<hex>`.
+/// The signature is of the timestamp and the body, joined by a `.`,
+/// using the webhook secret as the key.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Bencher-Signature";
+/// The header with the event that triggered a webhook.
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Bencher-Event";
+/// The event sent when a report generates alerts.
+pub const WEBHOOK_ALERTS_EVENT: &str = "alerts";
+/// The event sent when a rerun is requested for a report.
+pub const WEBHOOK_RERUN_EVENT: &str = "rerun";
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL to send the webhook to.
+    pub url: Url,
+    /// The secret used to sign the webhook payload.
+    /// The secret is never returned.
+    pub secret: Secret,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+/// A webhook for a project.
+/// When a report generates alerts or a rerun is requested for a report,
+/// a signed JSON payload is sent to the webhook URL.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+/// The payload sent to a webhook when a report generates alerts.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookPayload {
+    pub webhook: WebhookUuid,
+    pub project: JsonProject,
+    pub report: ReportUuid,
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub alerts: Vec<JsonAlert>,
+    pub created: DateTime,
+}
+
+/// The payload sent to a webhook when a rerun is requested for a report.
+/// The branch head version is the version of the code that should be benchmarked again.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookRerunPayload {
+    pub webhook: WebhookUuid,
+    pub project: JsonProject,
+    pub report: ReportUuid,
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    /// The user who requested the rerun.
+    pub requester: JsonPubUser,
+    pub created: DateTime,
+}
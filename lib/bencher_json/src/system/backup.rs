@@ -3,6 +3,10 @@ use bencher_valid::DateTime;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::operation::JsonOperation;
+
+crate::typed_uuid::typed_uuid!(BackupUuid);
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonBackup {
@@ -19,15 +23,84 @@ pub struct JsonBackup {
     pub rm: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+const AWS_S3_INT: i32 = 0;
+const LOCAL_INT: i32 = 1;
+const AZURE_BLOB_INT: i32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
 #[serde(rename_all = "snake_case")]
+#[repr(i32)]
 pub enum JsonDataStore {
-    AwsS3,
+    AwsS3 = AWS_S3_INT,
+    Local = LOCAL_INT,
+    AzureBlob = AZURE_BLOB_INT,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
-pub struct JsonBackupCreated {
+pub struct JsonServerBackups(pub Vec<JsonServerBackup>);
+
+crate::from_vec!(JsonServerBackups[JsonServerBackup]);
+
+/// A database backup from the server backup history.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonServerBackup {
+    pub uuid: BackupUuid,
+    /// The operation that ran the backup.
+    pub operation: JsonOperation,
+    /// Whether the backup was started by the server backup schedule instead of by an admin.
+    pub scheduled: bool,
+    pub compress: bool,
+    pub data_store: Option<JsonDataStore>,
+    pub rm: bool,
+    /// The name of the backup file, once the database has been backed up.
+    pub file_name: Option<String>,
     pub created: DateTime,
 }
+
+#[cfg(feature = "db")]
+mod data_store {
+    use super::{JsonDataStore, AWS_S3_INT, AZURE_BLOB_INT, LOCAL_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum DataStoreError {
+        #[error("Invalid data store value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for JsonDataStore
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::AwsS3 => AWS_S3_INT.to_sql(out),
+                Self::Local => LOCAL_INT.to_sql(out),
+                Self::AzureBlob => AZURE_BLOB_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for JsonDataStore
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                AWS_S3_INT => Ok(Self::AwsS3),
+                LOCAL_INT => Ok(Self::Local),
+                AZURE_BLOB_INT => Ok(Self::AzureBlob),
+                value => Err(Box::new(DataStoreError::Invalid(value))),
+            }
+        }
+    }
+}
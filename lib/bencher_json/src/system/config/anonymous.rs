@@ -0,0 +1,17 @@
+use std::num::NonZeroU32;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Allow reports to be sent without an account to unclaimed projects with `POST /v0/run`
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAnonymous {
+    /// The maximum number of anonymous runs per hour from a single client IP address
+    pub max_runs_per_client: Option<NonZeroU32>,
+    /// The maximum number of new unclaimed projects per hour across all clients
+    pub max_new_projects: Option<NonZeroU32>,
+    /// The maximum number of reports per hour to a single unclaimed project
+    pub max_reports_per_project: Option<NonZeroU32>,
+}
@@ -0,0 +1,16 @@
+use bencher_valid::Cron;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::JsonBackup;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBackupSchedule {
+    /// The cron schedule for database backups, in UTC (ie `0 3 * * *` for every day at 03:00)
+    pub cron: Cron,
+    /// The same options as a manual backup
+    #[serde(flatten)]
+    pub backup: JsonBackup,
+}
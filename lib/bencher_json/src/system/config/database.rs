@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use bencher_valid::{Sanitize, Secret};
+use bencher_valid::{Sanitize, Secret, Url};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -28,7 +28,25 @@ pub enum DataStore {
         secret_access_key: Secret,
         // arn:aws:s3:<region>:<account-id>:accesspoint/<resource>[/backup-dir-path]
         // https://docs.aws.amazon.com/AmazonS3/latest/userguide/using-access-points.html
+        // If an `endpoint_url` is set, then <bucket>[/backup-dir-path]
         access_point: String,
+        // An S3 compatible endpoint, such as MinIO, Backblaze B2, or DigitalOcean Spaces
+        #[serde(skip_serializing_if = "Option::is_none")]
+        endpoint_url: Option<Url>,
+        // The region for the `endpoint_url`, which is required if an `endpoint_url` is set
+        // Otherwise, the region from the access point ARN is used
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+    },
+    Local {
+        // The directory is created if it does not exist
+        directory: PathBuf,
+    },
+    AzureBlob {
+        // https://<account>.blob.core.windows.net/<container>[/backup-dir-path]
+        container_url: Url,
+        // https://learn.microsoft.com/en-us/rest/api/storageservices/create-service-sas
+        sas_token: Secret,
     },
 }
 
@@ -38,6 +56,8 @@ impl Sanitize for DataStore {
             Self::AwsS3 {
                 secret_access_key, ..
             } => secret_access_key.sanitize(),
+            Self::Local { .. } => {},
+            Self::AzureBlob { sas_token, .. } => sas_token.sanitize(),
         }
     }
 }
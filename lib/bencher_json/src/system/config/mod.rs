@@ -3,17 +3,24 @@ use bencher_valid::Sanitize;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+mod anonymous;
+mod backup;
 mod console;
 mod database;
 mod logging;
+mod otel;
 mod plus;
+mod retention;
 mod security;
 mod server;
 mod smtp;
 
+pub use anonymous::JsonAnonymous;
+pub use backup::JsonBackupSchedule;
 pub use console::JsonConsole;
 pub use database::{DataStore, JsonDatabase};
 pub use logging::{IfExists, JsonLogging, LogLevel, ServerLog};
+pub use otel::JsonOtel;
 #[cfg(feature = "plus")]
 pub use plus::{
     cloud::{
@@ -22,10 +29,12 @@ pub use plus::{
         JsonCloud,
     },
     litestream::{JsonLitestream, JsonReplica},
+    mirror::{JsonMirror, JsonMirrorProject},
     stats::JsonStats,
     JsonPlus,
 };
-pub use security::JsonSecurity;
+pub use retention::JsonRetention;
+pub use security::{JsonEncryption, JsonSecurity};
 pub use server::{JsonServer, JsonTls};
 pub use smtp::JsonSmtp;
 
@@ -46,6 +55,14 @@ pub struct JsonConfig {
     pub database: JsonDatabase,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub smtp: Option<JsonSmtp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otel: Option<JsonOtel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<JsonRetention>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup: Option<JsonBackupSchedule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anonymous: Option<JsonAnonymous>,
     #[cfg(feature = "plus")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plus: Option<JsonPlus>,
@@ -56,6 +73,7 @@ impl Sanitize for JsonConfig {
         self.security.sanitize();
         self.database.sanitize();
         self.smtp.sanitize();
+        self.otel.sanitize();
         #[cfg(feature = "plus")]
         self.plus.sanitize();
     }
@@ -0,0 +1,33 @@
+use std::collections::BTreeMap;
+
+use bencher_valid::{NonEmpty, ResourceId, Sanitize, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonOtel {
+    /// The OTLP/HTTP traces endpoint to export spans to, ie `http://localhost:4318/v1/traces`
+    pub endpoint: Url,
+    /// Headers to send with each export request, ie for authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, Secret>>,
+    /// The service name for exported spans (default: `bencher-api`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_name: Option<NonEmpty>,
+    /// The slugs or UUIDs of the projects to trace.
+    /// If not provided, report ingestion is traced for all projects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Vec<ResourceId>>,
+}
+
+impl Sanitize for JsonOtel {
+    fn sanitize(&mut self) {
+        if let Some(headers) = &mut self.headers {
+            for value in headers.values_mut() {
+                value.sanitize();
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bencher_valid::{ResourceId, Sanitize, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMirror {
+    /// The Bencher API to mirror reports to (default: <https://api.bencher.dev>)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<Url>,
+    /// An API token for the mirror Bencher API
+    pub token: Secret,
+    /// Remove the `git` hash and environment from mirrored reports,
+    /// and replace the branch and testbed names with opaque identifiers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anonymize: Option<bool>,
+    /// The maximum number of attempts to mirror a report before it is dropped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    /// The projects to mirror
+    pub projects: Vec<JsonMirrorProject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMirrorProject {
+    /// The slug or UUID of the local project
+    pub project: ResourceId,
+    /// The slug or UUID of the project on the mirror Bencher API
+    pub mirror: ResourceId,
+}
+
+impl Sanitize for JsonMirror {
+    fn sanitize(&mut self) {
+        self.token.sanitize();
+    }
+}
@@ -8,11 +8,13 @@ use serde::{Deserialize, Serialize};
 pub mod cloud;
 pub mod github;
 pub mod litestream;
+pub mod mirror;
 pub mod stats;
 
 pub use cloud::JsonCloud;
 pub use github::JsonGitHub;
 pub use litestream::JsonLitestream;
+pub use mirror::JsonMirror;
 pub use stats::JsonStats;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +27,8 @@ pub struct JsonPlus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<JsonStats>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror: Option<JsonMirror>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cloud: Option<JsonCloud>,
 }
 
@@ -32,6 +36,7 @@ impl Sanitize for JsonPlus {
     fn sanitize(&mut self) {
         self.github.sanitize();
         self.litestream.sanitize();
+        self.mirror.sanitize();
         self.cloud.sanitize();
     }
 }
@@ -0,0 +1,12 @@
+use std::num::NonZeroU32;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetention {
+    /// Reports older than this number of days are pruned, along with all of their metrics
+    pub days: NonZeroU32,
+}
@@ -9,10 +9,34 @@ pub struct JsonSecurity {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issuer: Option<String>,
     pub secret_key: Secret,
+    /// Encrypt sensitive columns at rest, such as webhook secrets and integration tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<JsonEncryption>,
 }
 
 impl Sanitize for JsonSecurity {
     fn sanitize(&mut self) {
         self.secret_key.sanitize();
+        self.encryption.sanitize();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonEncryption {
+    /// The key used to encrypt all new values
+    pub key: Secret,
+    /// Previous keys that are still used to decrypt existing values.
+    /// On startup, all values encrypted with a previous key are re-encrypted with the current key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_keys: Option<Vec<Secret>>,
+}
+
+impl Sanitize for JsonEncryption {
+    fn sanitize(&mut self) {
+        self.key.sanitize();
+        for key in self.previous_keys.iter_mut().flatten() {
+            key.sanitize();
+        }
     }
 }
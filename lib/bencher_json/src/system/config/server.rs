@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 pub struct JsonServer {
     pub bind_address: SocketAddr,
     pub request_body_max_bytes: usize,
+    /// The maximum number of bytes allowed in a request body for reports and attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub large_request_body_max_bytes: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tls: Option<JsonTls>,
 }
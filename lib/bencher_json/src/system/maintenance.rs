@@ -0,0 +1,55 @@
+use bencher_valid::DateTime;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMaintenance {
+    /// The maintenance operations to run, in order.
+    /// If not set or empty, then all operations are run:
+    /// `integrity_check`, `analyze`, `vacuum`, and then `wal_checkpoint`.
+    pub operations: Option<Vec<JsonMaintenanceOperation>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonMaintenanceOperation {
+    /// Check the integrity of the entire database.
+    IntegrityCheck,
+    /// Gather statistics about the database to help the query planner.
+    Analyze,
+    /// Rebuild the database file, reclaiming unused space.
+    Vacuum,
+    /// Checkpoint the write-ahead log (WAL) into the database and truncate it.
+    WalCheckpoint,
+}
+
+impl JsonMaintenanceOperation {
+    pub const ALL: [Self; 4] = [
+        Self::IntegrityCheck,
+        Self::Analyze,
+        Self::Vacuum,
+        Self::WalCheckpoint,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMaintenanceResults {
+    pub results: Vec<JsonMaintenanceResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMaintenanceResult {
+    pub operation: JsonMaintenanceOperation,
+    /// Whether the operation completed successfully.
+    /// For an integrity check, this is `false` if any problems were found.
+    pub ok: bool,
+    /// The output of the operation, if any.
+    pub output: Vec<String>,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+}
@@ -1,8 +1,11 @@
 pub mod auth;
 pub mod backup;
 pub mod config;
+pub mod maintenance;
+pub mod operation;
 pub mod payment;
 pub mod restart;
 pub mod server;
 pub mod spec;
+pub mod telemetry;
 pub mod version;
@@ -0,0 +1,158 @@
+use bencher_valid::DateTime;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+crate::typed_uuid::typed_uuid!(OperationUuid);
+
+/// A long-running server operation.
+/// Poll the operation until its state is either `succeeded` or `failed`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonOperation {
+    pub uuid: OperationUuid,
+    pub kind: OperationKind,
+    pub state: OperationState,
+    pub progress: JsonOperationProgress,
+    /// The error message if the operation failed.
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonOperationProgress {
+    /// The number of steps that have completed.
+    pub completed: u32,
+    /// The total number of steps for the operation.
+    pub total: u32,
+}
+
+const BACKUP_INT: i32 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum OperationKind {
+    /// A server database backup.
+    Backup = BACKUP_INT,
+}
+
+const QUEUED_INT: i32 = 0;
+const RUNNING_INT: i32 = 1;
+const SUCCEEDED_INT: i32 = 2;
+const FAILED_INT: i32 = 3;
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum OperationState {
+    #[default]
+    /// The operation has been accepted but has not started yet.
+    Queued = QUEUED_INT,
+    /// The operation is running.
+    Running = RUNNING_INT,
+    /// The operation finished successfully.
+    Succeeded = SUCCEEDED_INT,
+    /// The operation finished with an error.
+    Failed = FAILED_INT,
+}
+
+impl OperationState {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+#[cfg(feature = "db")]
+mod operation_kind {
+    use super::{OperationKind, BACKUP_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum OperationKindError {
+        #[error("Invalid operation kind value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for OperationKind
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Backup => BACKUP_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for OperationKind
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                BACKUP_INT => Ok(Self::Backup),
+                value => Err(Box::new(OperationKindError::Invalid(value))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod operation_state {
+    use super::{OperationState, FAILED_INT, QUEUED_INT, RUNNING_INT, SUCCEEDED_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum OperationStateError {
+        #[error("Invalid operation state value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for OperationState
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Queued => QUEUED_INT.to_sql(out),
+                Self::Running => RUNNING_INT.to_sql(out),
+                Self::Succeeded => SUCCEEDED_INT.to_sql(out),
+                Self::Failed => FAILED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for OperationState
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                QUEUED_INT => Ok(Self::Queued),
+                RUNNING_INT => Ok(Self::Running),
+                SUCCEEDED_INT => Ok(Self::Succeeded),
+                FAILED_INT => Ok(Self::Failed),
+                value => Err(Box::new(OperationStateError::Invalid(value))),
+            }
+        }
+    }
+}
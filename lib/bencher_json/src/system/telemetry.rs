@@ -0,0 +1,34 @@
+use bencher_valid::DateTime;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::project::report::Adapter;
+
+/// Anonymous CLI usage telemetry.
+/// Benchmark data is never included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonTelemetry {
+    /// The version of the CLI.
+    pub version: String,
+    /// The operating system of the CLI, ie `linux`, `macos`, or `windows`.
+    pub os: String,
+    /// The CLI usage events.
+    pub events: Vec<JsonTelemetryEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonTelemetryEvent {
+    /// The subcommand that was run, ie `run` or `branch list`.
+    pub command: String,
+    /// The benchmark harness adapter that was used, if any.
+    pub adapter: Option<Adapter>,
+    /// How long the subcommand took to run in milliseconds.
+    pub duration: u64,
+    /// The class of error, if the subcommand failed, ie `Run::NoCommand`.
+    pub error: Option<String>,
+    /// When the subcommand was run.
+    pub created: DateTime,
+}
@@ -2,8 +2,84 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// The header used by clients to send their version to the API server
+pub const BENCHER_CLIENT_VERSION_HEADER: &str = "X-Bencher-Client-Version";
+/// The version of the API schema, used as the prefix for all API paths
+pub const BENCHER_API_SCHEMA_VERSION: &str = "v0";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonApiVersion {
+    /// The API server version.
     pub version: String,
+    /// The minimum client version supported by the API server.
+    /// Older clients may send payloads that the API server can no longer parse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_client_version: Option<String>,
+    /// The latest client version known to the API server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_client_version: Option<String>,
+    /// The API schema version, ie `v0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
+    /// The features that are enabled on the API server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<JsonServerFeatures>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonServerFeatures {
+    /// The API server is configured to use TLS.
+    pub tls: bool,
+    /// The API server is configured for GitHub OAuth.
+    pub oauth: bool,
+    /// The API server is configured for metered subscription plans.
+    pub plans: bool,
+}
+
+/// Parse a `major.minor.patch` version string into a comparable tuple.
+///
+/// Any pre-release or build metadata suffix is ignored.
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let version = version
+        .split_once(['-', '+'])
+        .map_or(version, |(version, _)| version);
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    parts.next().is_none().then_some((major, minor, patch))
+}
+
+/// Check if the `version` is older than the `other` version.
+///
+/// Returns `None` if either version can not be parsed.
+pub fn is_older_version(version: &str, other: &str) -> Option<bool> {
+    Some(parse_version(version)? < parse_version(other)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_older_version, parse_version};
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("0.4.32"), Some((0, 4, 32)));
+        assert_eq!(parse_version("v1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2.3-rc.1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2.3+build"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+        assert_eq!(parse_version("one.two.three"), None);
+    }
+
+    #[test]
+    fn test_is_older_version() {
+        assert_eq!(is_older_version("0.4.31", "0.4.32"), Some(true));
+        assert_eq!(is_older_version("0.4.32", "0.4.32"), Some(false));
+        assert_eq!(is_older_version("0.10.0", "0.9.9"), Some(false));
+        assert_eq!(is_older_version("bad", "0.4.32"), None);
+    }
 }
@@ -1,9 +1,9 @@
-use bencher_valid::{DateTime, Jwt, ResourceName};
+use bencher_valid::{Cidr, DateTime, Jwt, ResourceId, ResourceName};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::UserUuid;
+use crate::{ProjectUuid, UserUuid};
 
 crate::typed_uuid::typed_uuid!(TokenUuid);
 
@@ -17,6 +17,15 @@ pub struct JsonNewToken {
     /// The time-to-live (TTL) for the token in seconds.
     /// If not provided, the token will not expire for over 128 years.
     pub ttl: Option<u32>,
+    /// The scope of the token.
+    /// If not provided, the token has the same permissions as the user.
+    pub scope: Option<TokenScope>,
+    /// The slug or UUID of the only project the token can access.
+    /// If not provided, the token can access any project the user can.
+    pub project: Option<ResourceId>,
+    /// The IP addresses or CIDR ranges that the token can be used from.
+    /// If not provided, the token can be used from any IP address.
+    pub allowed_ips: Option<Vec<Cidr>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +44,9 @@ pub struct JsonToken {
     pub token: Jwt,
     pub creation: DateTime,
     pub expiration: DateTime,
+    pub scope: Option<TokenScope>,
+    pub project: Option<ProjectUuid>,
+    pub allowed_ips: Option<Vec<Cidr>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,4 +55,81 @@ pub struct JsonUpdateToken {
     /// The new name of the token.
     /// Maximum length is 64 characters.
     pub name: Option<ResourceName>,
+    /// Rotate the token.
+    /// A replacement token is issued and the current token is revoked.
+    pub rotate: Option<bool>,
+    /// The time-to-live (TTL) for the replacement token in seconds.
+    /// This can only be set when rotating the token.
+    /// If not provided, the replacement token has the same TTL as the current token.
+    pub ttl: Option<u32>,
+}
+
+const ADMIN_INT: i32 = 0;
+const PROJECT_READ_INT: i32 = 1;
+const REPORT_WRITE_INT: i32 = 2;
+
+/// The scope of an API token.
+/// The `admin` scope includes `report:write`, which includes `project:read`.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[repr(i32)]
+pub enum TokenScope {
+    /// The same permissions as the user.
+    #[serde(rename = "admin")]
+    #[display("admin")]
+    Admin = ADMIN_INT,
+    /// View projects.
+    #[serde(rename = "project:read")]
+    #[display("project:read")]
+    ProjectRead = PROJECT_READ_INT,
+    /// View projects and create reports, along with their branches, testbeds, and measures.
+    #[serde(rename = "report:write")]
+    #[display("report:write")]
+    ReportWrite = REPORT_WRITE_INT,
+}
+
+#[cfg(feature = "db")]
+mod token_scope {
+    use super::{TokenScope, ADMIN_INT, PROJECT_READ_INT, REPORT_WRITE_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum TokenScopeError {
+        #[error("Invalid token scope value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for TokenScope
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Admin => ADMIN_INT.to_sql(out),
+                Self::ProjectRead => PROJECT_READ_INT.to_sql(out),
+                Self::ReportWrite => REPORT_WRITE_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for TokenScope
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                ADMIN_INT => Ok(Self::Admin),
+                PROJECT_READ_INT => Ok(Self::ProjectRead),
+                REPORT_WRITE_INT => Ok(Self::ReportWrite),
+                value => Err(Box::new(TokenScopeError::Invalid(value))),
+            }
+        }
+    }
 }
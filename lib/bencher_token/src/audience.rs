@@ -3,6 +3,7 @@ use std::fmt;
 const AUDIENCE_AUTH: &str = "auth";
 const AUDIENCE_CLIENT: &str = "client";
 const AUDIENCE_API_KEY: &str = "api_key";
+const AUDIENCE_PROJECT_KEY: &str = "project_key";
 const AUDIENCE_INVITE: &str = "invite";
 
 #[derive(Debug, Copy, Clone)]
@@ -10,6 +11,7 @@ pub enum Audience {
     Auth,
     Client,
     ApiKey,
+    ProjectKey,
     Invite,
 }
 impl fmt::Display for Audience {
@@ -21,6 +23,7 @@ impl fmt::Display for Audience {
                 Self::Auth => AUDIENCE_AUTH,
                 Self::Client => AUDIENCE_CLIENT,
                 Self::ApiKey => AUDIENCE_API_KEY,
+                Self::ProjectKey => AUDIENCE_PROJECT_KEY,
                 Self::Invite => AUDIENCE_INVITE,
             }
         )
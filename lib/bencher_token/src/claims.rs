@@ -44,6 +44,16 @@ impl Claims {
         &self.sub
     }
 
+    /// API keys are only valid while they are stored for their user
+    pub fn is_api_key(&self) -> bool {
+        self.aud == Audience::ApiKey.to_string()
+    }
+
+    /// Project API keys are only valid while they are stored for their project
+    pub fn is_project_key(&self) -> bool {
+        self.aud == Audience::ProjectKey.to_string()
+    }
+
     pub fn issued_at(&self) -> DateTime {
         let date_time = DateTime::try_from(self.iat);
         debug_assert!(date_time.is_ok(), "Issued at time is invalid");
@@ -55,6 +55,10 @@ impl TokenKey {
         self.new_jwt(Audience::ApiKey, email, ttl, None)
     }
 
+    pub fn new_project_key(&self, email: Email, ttl: u32) -> Result<Jwt, TokenError> {
+        self.new_jwt(Audience::ProjectKey, email, ttl, None)
+    }
+
     pub fn new_invite(
         &self,
         email: Email,
@@ -103,7 +107,10 @@ impl TokenKey {
 
     pub fn validate_client(&self, token: &Jwt) -> Result<Claims, TokenError> {
         Ok(self
-            .validate(token, &[Audience::Client, Audience::ApiKey])?
+            .validate(
+                token,
+                &[Audience::Client, Audience::ApiKey, Audience::ProjectKey],
+            )?
             .claims)
     }
 
@@ -111,6 +118,10 @@ impl TokenKey {
         Ok(self.validate(token, &[Audience::ApiKey])?.claims)
     }
 
+    pub fn validate_project_key(&self, token: &Jwt) -> Result<Claims, TokenError> {
+        Ok(self.validate(token, &[Audience::ProjectKey])?.claims)
+    }
+
     pub fn validate_invite(&self, token: &Jwt) -> Result<InviteClaims, TokenError> {
         self.validate(token, &[Audience::Invite])?.claims.try_into()
     }
@@ -122,7 +133,7 @@ mod test {
 
     use bencher_json::{organization::member::OrganizationRole, Email, OrganizationUuid};
 
-    use crate::{Audience, DEFAULT_SECRET_KEY};
+    use crate::{Audience, TokenError, DEFAULT_SECRET_KEY};
 
     use super::TokenKey;
 
@@ -198,6 +209,8 @@ mod test {
         assert_eq!(claims.iss, BENCHER_DOT_DEV_ISSUER.to_owned());
         assert_eq!(claims.iat, claims.exp - i64::from(TTL));
         assert_eq!(claims.sub, *EMAIL);
+        assert!(claims.is_api_key());
+        assert!(!claims.is_project_key());
     }
 
     #[test]
@@ -209,6 +222,40 @@ mod test {
         sleep_for_a_second();
 
         assert!(secret_key.validate_api_key(&token).is_err());
+        assert!(matches!(
+            secret_key.validate_client(&token),
+            Err(TokenError::Expired { .. })
+        ));
+    }
+
+    #[test]
+    fn test_jwt_project_key() {
+        let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
+
+        let token = secret_key.new_project_key(EMAIL.clone(), TTL).unwrap();
+
+        let claims = secret_key.validate_project_key(&token).unwrap();
+
+        assert_eq!(claims.aud, Audience::ProjectKey.to_string());
+        assert_eq!(claims.iss, BENCHER_DOT_DEV_ISSUER.to_owned());
+        assert_eq!(claims.iat, claims.exp - i64::from(TTL));
+        assert_eq!(claims.sub, *EMAIL);
+        assert!(claims.is_project_key());
+
+        let claims = secret_key.validate_client(&token).unwrap();
+        assert!(claims.is_project_key());
+        assert!(secret_key.validate_api_key(&token).is_err());
+    }
+
+    #[test]
+    fn test_jwt_project_key_expired() {
+        let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
+
+        let token = secret_key.new_project_key(EMAIL.clone(), 0).unwrap();
+
+        sleep_for_a_second();
+
+        assert!(secret_key.validate_project_key(&token).is_err());
     }
 
     #[test]
@@ -0,0 +1,201 @@
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{benchmark_name::MAX_BENCHMARK_NAME_LEN, BenchmarkName, ValidError};
+
+const ANY_CHARS: char = '*';
+const ANY_CHAR: char = '?';
+
+/// A benchmark name or a glob pattern of benchmark names.
+/// A `*` matches any number of characters and a `?` matches exactly one character.
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct BenchmarkGlob(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(BenchmarkGlob);
+
+impl BenchmarkGlob {
+    /// Whether the glob is a plain benchmark name without any wildcards
+    pub fn is_exact(&self) -> bool {
+        !self.0.contains([ANY_CHARS, ANY_CHAR])
+    }
+
+    /// The number of literal (non-wildcard) characters in the glob.
+    /// A glob with more literal characters is more specific.
+    pub fn specificity(&self) -> usize {
+        self.0
+            .chars()
+            .filter(|c| *c != ANY_CHARS && *c != ANY_CHAR)
+            .count()
+    }
+
+    pub fn is_match(&self, benchmark_name: &BenchmarkName) -> bool {
+        let pattern = self.0.chars().collect::<Vec<_>>();
+        let name = benchmark_name.as_ref().chars().collect::<Vec<_>>();
+        glob_match(&pattern, &name)
+    }
+}
+
+// Iterative wildcard matching with backtracking to the last `*`
+fn glob_match(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while let Some(&name_char) = name.get(n) {
+        match pattern.get(p) {
+            Some(&ANY_CHARS) => {
+                backtrack = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == ANY_CHAR || c == name_char => {
+                p += 1;
+                n += 1;
+            },
+            _ => {
+                let Some((star_p, star_n)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star_p, star_n + 1));
+                p = star_p + 1;
+                n = star_n + 1;
+            },
+        }
+    }
+    pattern
+        .get(p..)
+        .map_or(true, |rest| rest.iter().all(|c| *c == ANY_CHARS))
+}
+
+impl FromStr for BenchmarkGlob {
+    type Err = ValidError;
+
+    fn from_str(benchmark_glob: &str) -> Result<Self, Self::Err> {
+        if is_valid_benchmark_glob(benchmark_glob) {
+            Ok(Self(benchmark_glob.into()))
+        } else {
+            Err(ValidError::BenchmarkGlob(benchmark_glob.into()))
+        }
+    }
+}
+
+impl AsRef<str> for BenchmarkGlob {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<BenchmarkGlob> for String {
+    fn from(benchmark_glob: BenchmarkGlob) -> Self {
+        benchmark_glob.0
+    }
+}
+
+impl From<BenchmarkName> for BenchmarkGlob {
+    fn from(benchmark_name: BenchmarkName) -> Self {
+        Self(benchmark_name.into())
+    }
+}
+
+impl<'de> Deserialize<'de> for BenchmarkGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BenchmarkGlobVisitor)
+    }
+}
+
+struct BenchmarkGlobVisitor;
+
+impl Visitor<'_> for BenchmarkGlobVisitor {
+    type Value = BenchmarkGlob;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a valid benchmark name or glob")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_benchmark_glob(benchmark_glob: &str) -> bool {
+    !benchmark_glob.trim().is_empty() && benchmark_glob.len() <= MAX_BENCHMARK_NAME_LEN
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BenchmarkGlob, BenchmarkName};
+
+    use super::is_valid_benchmark_glob;
+    use pretty_assertions::assert_eq;
+
+    fn is_match(glob: &str, name: &str) -> bool {
+        let glob: BenchmarkGlob = glob.parse().unwrap();
+        let name: BenchmarkName = name.parse().unwrap();
+        glob.is_match(&name)
+    }
+
+    #[test]
+    fn test_benchmark_glob() {
+        assert_eq!(true, is_valid_benchmark_glob("a"));
+        assert_eq!(true, is_valid_benchmark_glob("*"));
+        assert_eq!(true, is_valid_benchmark_glob("bench_*"));
+        assert_eq!(true, is_valid_benchmark_glob("bench_?::*"));
+
+        assert_eq!(false, is_valid_benchmark_glob(""));
+        assert_eq!(false, is_valid_benchmark_glob(" "));
+    }
+
+    #[test]
+    fn test_benchmark_glob_is_match() {
+        assert_eq!(true, is_match("bench_fib", "bench_fib"));
+        assert_eq!(false, is_match("bench_fib", "bench_fib_10"));
+
+        assert_eq!(true, is_match("*", "bench_fib"));
+        assert_eq!(true, is_match("bench_*", "bench_fib"));
+        assert_eq!(true, is_match("bench_*", "bench_"));
+        assert_eq!(true, is_match("*_fib", "bench_fib"));
+        assert_eq!(true, is_match("*fib*", "bench_fib_10"));
+        assert_eq!(true, is_match("bench_*_*", "bench_fib_10"));
+        assert_eq!(false, is_match("bench_*", "micro_fib"));
+        assert_eq!(false, is_match("*_fib", "bench_fib_10"));
+
+        assert_eq!(true, is_match("bench_fib_?", "bench_fib_1"));
+        assert_eq!(false, is_match("bench_fib_?", "bench_fib_10"));
+        assert_eq!(true, is_match("bench_fib_??", "bench_fib_10"));
+        assert_eq!(true, is_match("?*", "a"));
+        assert_eq!(false, is_match("??*", "a"));
+    }
+
+    #[test]
+    fn test_benchmark_glob_specificity() {
+        let glob: BenchmarkGlob = "bench_fib".parse().unwrap();
+        assert_eq!(true, glob.is_exact());
+        assert_eq!(glob.specificity(), 9);
+
+        let glob: BenchmarkGlob = "bench_*".parse().unwrap();
+        assert_eq!(false, glob.is_exact());
+        assert_eq!(glob.specificity(), 6);
+
+        let glob: BenchmarkGlob = "*".parse().unwrap();
+        assert_eq!(false, glob.is_exact());
+        assert_eq!(glob.specificity(), 0);
+    }
+}
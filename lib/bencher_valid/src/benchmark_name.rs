@@ -10,7 +10,7 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
-use crate::ValidError;
+use crate::{escape::normalize_name, ValidError};
 
 // In practice, this may need to be raised all the way up to 4096.
 pub(crate) const MAX_BENCHMARK_NAME_LEN: usize = 1024;
@@ -65,7 +65,8 @@ impl FromStr for BenchmarkName {
     type Err = ValidError;
 
     fn from_str(benchmark_name: &str) -> Result<Self, Self::Err> {
-        if is_valid_benchmark_name(benchmark_name) {
+        let benchmark_name = normalize_name(benchmark_name);
+        if is_valid_benchmark_name(&benchmark_name) {
             Ok(Self(benchmark_name.into()))
         } else {
             Err(ValidError::BenchmarkName(benchmark_name.into()))
@@ -136,6 +137,17 @@ mod test {
         assert_eq!(false, is_valid_benchmark_name(""));
     }
 
+    #[test]
+    fn test_benchmark_name_normalize() {
+        let benchmark_name: BenchmarkName = "\u{1b}[32mbench_name\u{1b}[0m".parse().unwrap();
+        assert_eq!(benchmark_name.as_ref(), "bench_name");
+
+        let benchmark_name: BenchmarkName = "bench\tname\u{0}".parse().unwrap();
+        assert_eq!(benchmark_name.as_ref(), "bench name");
+
+        assert!(BenchmarkName::from_str("\u{1b}[0m").is_err());
+    }
+
     #[test]
     fn test_benchmark_name_try_push_ok() {
         let mut benchmark_name: BenchmarkName = "0123456789".parse().unwrap();
@@ -143,7 +155,7 @@ mod test {
         assert_eq!(benchmark_name_len, 10);
 
         let other_benchmark_name_bytes: [u8; MAX_BENCHMARK_NAME_LEN - 11] =
-            [0; MAX_BENCHMARK_NAME_LEN - 11];
+            [b'0'; MAX_BENCHMARK_NAME_LEN - 11];
         let other_benchmark_name: BenchmarkName = std::str::from_utf8(&other_benchmark_name_bytes)
             .unwrap()
             .parse()
@@ -169,7 +181,7 @@ mod test {
         assert_eq!(benchmark_name_len, 10);
 
         let other_benchmark_name_bytes: [u8; MAX_BENCHMARK_NAME_LEN - 10] =
-            [0; MAX_BENCHMARK_NAME_LEN - 10];
+            [b'0'; MAX_BENCHMARK_NAME_LEN - 10];
         let other_benchmark_name: BenchmarkName = std::str::from_utf8(&other_benchmark_name_bytes)
             .unwrap()
             .parse()
@@ -0,0 +1,126 @@
+use derive_more::Display;
+use ipnet::IpNet;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, net::IpAddr, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+/// An IP address range in CIDR notation, ie `192.0.2.0/24` or `2001:db8::/32`.
+/// A single IP address is also accepted.
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct Cidr(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(Cidr);
+
+impl FromStr for Cidr {
+    type Err = ValidError;
+
+    fn from_str(cidr: &str) -> Result<Self, Self::Err> {
+        if is_valid_cidr(cidr) {
+            Ok(Self(cidr.into()))
+        } else {
+            Err(ValidError::Cidr(cidr.into()))
+        }
+    }
+}
+
+impl AsRef<str> for Cidr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Cidr> for String {
+    fn from(cidr: Cidr) -> Self {
+        cidr.0
+    }
+}
+
+impl Cidr {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        // An IPv4 client may be seen as an IPv4-mapped IPv6 address
+        parse_cidr(&self.0).is_some_and(|ip_net| ip_net.contains(&ip.to_canonical()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Cidr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CidrVisitor)
+    }
+}
+
+struct CidrVisitor;
+
+impl Visitor<'_> for CidrVisitor {
+    type Value = Cidr;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a valid CIDR")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+fn parse_cidr(cidr: &str) -> Option<IpNet> {
+    cidr.parse::<IpNet>()
+        .ok()
+        .or_else(|| cidr.parse::<IpAddr>().ok().map(Into::into))
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_cidr(cidr: &str) -> bool {
+    parse_cidr(cidr).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_valid_cidr, Cidr};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_cidr() {
+        assert_eq!(true, is_valid_cidr("192.0.2.0/24"));
+        assert_eq!(true, is_valid_cidr("192.0.2.1"));
+        assert_eq!(true, is_valid_cidr("2001:db8::/32"));
+        assert_eq!(true, is_valid_cidr("2001:db8::1"));
+
+        assert_eq!(false, is_valid_cidr(""));
+        assert_eq!(false, is_valid_cidr(" 192.0.2.0/24"));
+        assert_eq!(false, is_valid_cidr("192.0.2.0/33"));
+        assert_eq!(false, is_valid_cidr("192.0.2.256"));
+        assert_eq!(false, is_valid_cidr("example.com"));
+    }
+
+    #[test]
+    fn test_cidr_contains() {
+        let cidr: Cidr = "192.0.2.0/24".parse().unwrap();
+        assert_eq!(true, cidr.contains("192.0.2.42".parse().unwrap()));
+        assert_eq!(true, cidr.contains("::ffff:192.0.2.42".parse().unwrap()));
+        assert_eq!(false, cidr.contains("198.51.100.1".parse().unwrap()));
+
+        let cidr: Cidr = "2001:db8::1".parse().unwrap();
+        assert_eq!(true, cidr.contains("2001:db8::1".parse().unwrap()));
+        assert_eq!(false, cidr.contains("2001:db8::2".parse().unwrap()));
+    }
+}
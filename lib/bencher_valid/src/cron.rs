@@ -0,0 +1,298 @@
+use chrono::{Datelike, Duration, TimeZone, Timelike, Utc};
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+// Give up looking for the next match for schedules that never match, ie `0 0 30 2 *`
+const MAX_SEARCH_DAYS: i64 = 5 * 366;
+
+/// A cron schedule with five fields: minute, hour, day of the month, month, and day of the week.
+/// Each field may be `*`, a value, a range (`1-5`), a step (`*/15` or `0-30/10`), or a list of these (`0,30`).
+/// The `@yearly`, `@monthly`, `@weekly`, `@daily`, and `@hourly` shortcuts are also supported.
+/// All times are in UTC.
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Cron(String);
+
+impl FromStr for Cron {
+    type Err = ValidError;
+
+    fn from_str(cron: &str) -> Result<Self, Self::Err> {
+        if is_valid_cron(cron) {
+            Ok(Self(cron.into()))
+        } else {
+            Err(ValidError::Cron(cron.into()))
+        }
+    }
+}
+
+impl AsRef<str> for Cron {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Cron> for String {
+    fn from(cron: Cron) -> Self {
+        cron.0
+    }
+}
+
+impl<'de> Deserialize<'de> for Cron {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CronVisitor)
+    }
+}
+
+struct CronVisitor;
+
+impl Visitor<'_> for CronVisitor {
+    type Value = Cron;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a cron schedule")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl Cron {
+    /// The first time strictly after `after` that matches the schedule, to the minute.
+    /// Returns `None` if the schedule never matches.
+    pub fn next_after(&self, after: chrono::DateTime<Utc>) -> Option<chrono::DateTime<Utc>> {
+        Schedule::parse(&self.0)?.next_after(after)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_cron(cron: &str) -> bool {
+    crate::is_valid_non_empty(cron) && Schedule::parse(cron).is_some()
+}
+
+/// Each field is a bit set of the values that match
+#[derive(Debug, Clone, Copy)]
+struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl Schedule {
+    fn parse(cron: &str) -> Option<Self> {
+        let cron = match cron {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            cron => cron,
+        };
+        let mut fields = cron.split_whitespace();
+        let (Some(minute), Some(hour), Some(day), Some(month), Some(weekday), None) = (
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+        ) else {
+            return None;
+        };
+
+        // Both `0` and `7` are Sunday
+        let mut weekdays = parse_field(weekday, 0, 7)?;
+        if contains(weekdays, 7) {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Some(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        })
+    }
+
+    fn next_after(self, after: chrono::DateTime<Utc>) -> Option<chrono::DateTime<Utc>> {
+        let mut next = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = next + Duration::days(MAX_SEARCH_DAYS);
+        while next < limit {
+            if !contains(self.months, next.month()) {
+                let (year, month) = if next.month() == 12 {
+                    (next.year() + 1, 1)
+                } else {
+                    (next.year(), next.month() + 1)
+                };
+                next = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+            } else if !self.matches_day(next) {
+                next = (next.date_naive() + Duration::days(1))
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc();
+            } else if !contains(self.hours, next.hour()) {
+                next = next.with_minute(0)? + Duration::hours(1);
+            } else if !contains(self.minutes, next.minute()) {
+                next += Duration::minutes(1);
+            } else {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    // If both the day of the month and the day of the week are restricted,
+    // then the schedule matches if either of them match.
+    fn matches_day(self, date_time: chrono::DateTime<Utc>) -> bool {
+        let day = contains(self.days, date_time.day());
+        let weekday = contains(self.weekdays, date_time.weekday().num_days_from_sunday());
+        if self.any_day || self.any_weekday {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    let mut values = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<usize>().ok().filter(|s| *s > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (start.parse().ok()?, end.parse().ok()?)
+        } else {
+            let start = range.parse().ok()?;
+            // A single value with a step runs through the maximum, ie `5/15`
+            (start, if step > 1 { max } else { start })
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for value in (start..=end).step_by(step) {
+            values |= 1 << value;
+        }
+    }
+    Some(values)
+}
+
+fn contains(values: u64, value: u32) -> bool {
+    values & (1 << value) != 0
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_cron, Cron};
+
+    #[test]
+    fn test_cron() {
+        assert_eq!(true, is_valid_cron("* * * * *"));
+        assert_eq!(true, is_valid_cron("0 0 * * *"));
+        assert_eq!(true, is_valid_cron("*/15 * * * *"));
+        assert_eq!(true, is_valid_cron("0 3 * * 1-5"));
+        assert_eq!(true, is_valid_cron("0,30 9-17/2 1,15 * 0,7"));
+        assert_eq!(true, is_valid_cron("5/15 * * * *"));
+        assert_eq!(true, is_valid_cron("0 0 1 1 *"));
+        assert_eq!(true, is_valid_cron("@daily"));
+        assert_eq!(true, is_valid_cron("@hourly"));
+
+        assert_eq!(false, is_valid_cron(""));
+        assert_eq!(false, is_valid_cron(" 0 0 * * *"));
+        assert_eq!(false, is_valid_cron("0 0 * * * "));
+        assert_eq!(false, is_valid_cron("* * * *"));
+        assert_eq!(false, is_valid_cron("* * * * * *"));
+        assert_eq!(false, is_valid_cron("60 * * * *"));
+        assert_eq!(false, is_valid_cron("* 24 * * *"));
+        assert_eq!(false, is_valid_cron("* * 0 * *"));
+        assert_eq!(false, is_valid_cron("* * 32 * *"));
+        assert_eq!(false, is_valid_cron("* * * 13 *"));
+        assert_eq!(false, is_valid_cron("* * * * 8"));
+        assert_eq!(false, is_valid_cron("*/0 * * * *"));
+        assert_eq!(false, is_valid_cron("5-1 * * * *"));
+        assert_eq!(false, is_valid_cron("a * * * *"));
+        assert_eq!(false, is_valid_cron("@often"));
+    }
+
+    #[test]
+    fn test_cron_next_after() {
+        let after = Utc.with_ymd_and_hms(2024, 11, 15, 10, 20, 30).unwrap();
+        let next_after = |cron: &str| {
+            cron.parse::<Cron>()
+                .unwrap()
+                .next_after(after)
+                .map(|next| next.to_rfc3339())
+        };
+
+        assert_eq!(
+            Some("2024-11-15T10:21:00+00:00".to_owned()),
+            next_after("* * * * *")
+        );
+        assert_eq!(
+            Some("2024-11-15T10:30:00+00:00".to_owned()),
+            next_after("*/15 * * * *")
+        );
+        assert_eq!(
+            Some("2024-11-16T00:00:00+00:00".to_owned()),
+            next_after("@daily")
+        );
+        assert_eq!(
+            Some("2024-11-15T11:00:00+00:00".to_owned()),
+            next_after("@hourly")
+        );
+        // Friday to the next Monday
+        assert_eq!(
+            Some("2024-11-18T03:00:00+00:00".to_owned()),
+            next_after("0 3 * * 1")
+        );
+        // Sunday as `7`
+        assert_eq!(
+            Some("2024-11-17T00:00:00+00:00".to_owned()),
+            next_after("0 0 * * 7")
+        );
+        // Either the day of the month or the day of the week
+        assert_eq!(
+            Some("2024-11-18T00:00:00+00:00".to_owned()),
+            next_after("0 0 1 * 1")
+        );
+        assert_eq!(
+            Some("2025-01-01T00:00:00+00:00".to_owned()),
+            next_after("@yearly")
+        );
+        assert_eq!(
+            Some("2028-02-29T12:00:00+00:00".to_owned()),
+            next_after("0 12 29 2 *")
+        );
+        assert_eq!(None, next_after("0 0 30 2 *"));
+    }
+}
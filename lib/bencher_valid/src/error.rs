@@ -26,6 +26,8 @@ pub enum ValidError {
     BranchName(String),
     #[error("Failed to validate benchmark name: {0}")]
     BenchmarkName(String),
+    #[error("Failed to validate benchmark glob: {0}")]
+    BenchmarkGlob(String),
     #[error("Failed to validate name ID: {0}")]
     NameId(String),
     #[error("Failed to validate non-empty ID: {0}")]
@@ -38,12 +40,20 @@ pub enum ValidError {
     UrlToUrl(crate::Url, url::ParseError),
     #[error("Failed to validate git hash: {0}")]
     GitHash(String),
+    #[error("Failed to validate CIDR: {0}")]
+    Cidr(String),
     #[error("Failed to validate secret: {0}")]
     Secret(String),
+    #[error("Failed to validate cron schedule: {0}")]
+    Cron(String),
     #[error("Invalid model boundary: {0}")]
     Boundary(f64),
     #[error("Failed to parse model boundary: {0}")]
     BoundaryStr(std::num::ParseFloatError),
+    #[error("Invalid scale factor: {0}")]
+    ScaleFactor(f64),
+    #[error("Failed to parse scale factor: {0}")]
+    ScaleFactorStr(std::num::ParseFloatError),
     #[error("Invalid model sample size: {0}")]
     SampleSize(u32),
     #[error("Failed to parse model sample size: {0}")]
@@ -108,6 +118,13 @@ pub enum ValidError {
     Boundaries { lower: Boundary, upper: Boundary },
     #[error("Invalid model, no boundary provided")]
     NoBoundary,
+    #[error("Invalid model, critical boundary ({0}) provided without a boundary on the same side")]
+    NoCriticalBoundary(Boundary),
+    #[error("Invalid model, critical boundary ({critical}) is less permissive than boundary ({boundary})")]
+    CriticalBoundary {
+        boundary: Boundary,
+        critical: Boundary,
+    },
     #[error("Invalid static model, includes a minimum sample size: {0}")]
     StaticMinSampleSize(SampleSize),
     #[error("Invalid static model, includes a maximum sample size: {0}")]
@@ -120,4 +137,6 @@ pub enum ValidError {
     CdfBoundary(f64),
     #[error("Invalid inter-quartile range boundary: {0}")]
     IqrBoundary(f64),
+    #[error("Invalid cumulative sum boundary: {0}")]
+    CusumBoundary(f64),
 }
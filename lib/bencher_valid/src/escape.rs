@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+const CSI: char = '\u{9b}';
+
+/// Normalize a user or tool provided name
+/// ANSI escape sequences are removed,
+/// whitespace control characters (ie `\t`, `\n`, `\r`) are replaced with a single space,
+/// and all other control characters are removed.
+pub(crate) fn normalize_name(name: &str) -> Cow<'_, str> {
+    if !name.chars().any(char::is_control) {
+        return Cow::Borrowed(name);
+    }
+    let stripped = strip_ansi_escapes(name);
+    Cow::Owned(
+        stripped
+            .chars()
+            .filter_map(|c| {
+                if !c.is_control() {
+                    Some(c)
+                } else if c.is_whitespace() {
+                    Some(' ')
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Strip ANSI escape sequences from the input
+/// This handles Control Sequence Introducer (CSI), Operating System Command (OSC),
+/// and single character escape sequences.
+pub fn strip_ansi_escapes(input: &str) -> Cow<'_, str> {
+    if !input.contains([ESC, CSI]) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ESC => match chars.next() {
+                Some('[') => skip_csi(&mut chars),
+                Some(']') => skip_osc(&mut chars),
+                // Intermediate bytes followed by a single final byte
+                Some('\u{20}'..='\u{2f}') => {
+                    while chars
+                        .next_if(|c| matches!(c, '\u{20}'..='\u{2f}'))
+                        .is_some()
+                    {}
+                    chars.next();
+                },
+                // Single character escape sequence
+                Some(_) | None => {},
+            },
+            CSI => skip_csi(&mut chars),
+            _ => output.push(c),
+        }
+    }
+    Cow::Owned(output)
+}
+
+fn skip_csi<I>(chars: &mut std::iter::Peekable<I>)
+where
+    I: Iterator<Item = char>,
+{
+    // Parameter and intermediate bytes
+    while chars
+        .next_if(|c| matches!(c, '\u{20}'..='\u{3f}'))
+        .is_some()
+    {}
+    // Final byte
+    chars.next_if(|c| matches!(c, '\u{40}'..='\u{7e}'));
+}
+
+fn skip_osc<I>(chars: &mut std::iter::Peekable<I>)
+where
+    I: Iterator<Item = char>,
+{
+    while let Some(c) = chars.next() {
+        match c {
+            BEL => return,
+            ESC => {
+                chars.next_if_eq(&'\\');
+                return;
+            },
+            _ => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{normalize_name, strip_ansi_escapes};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_strip_ansi_escapes() {
+        assert_eq!(strip_ansi_escapes("plain"), "plain");
+        assert_eq!(strip_ansi_escapes("\u{1b}[1mbold\u{1b}[0m"), "bold");
+        assert_eq!(
+            strip_ansi_escapes("time:   \u{1b}[0m\u{1b}[1m3.4 ns\u{1b}[0m"),
+            "time:   3.4 ns"
+        );
+        assert_eq!(strip_ansi_escapes("\u{1b}[38;5;208morange"), "orange");
+        assert_eq!(
+            strip_ansi_escapes("\u{1b}]8;;https://bencher.dev\u{7}link\u{1b}]8;;\u{1b}\\"),
+            "link"
+        );
+        assert_eq!(strip_ansi_escapes("\u{1b}(Bcharset"), "charset");
+        assert_eq!(strip_ansi_escapes("\u{9b}31mred"), "red");
+        assert_eq!(strip_ansi_escapes("trailing\u{1b}"), "trailing");
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("bench_name"), "bench_name");
+        assert_eq!(normalize_name("bench ~ name!"), "bench ~ name!");
+        assert_eq!(
+            normalize_name("\u{1b}[32mbench_name\u{1b}[0m"),
+            "bench_name"
+        );
+        assert_eq!(normalize_name("bench\tname"), "bench name");
+        assert_eq!(normalize_name("bench\u{7}name\u{0}"), "benchname");
+        assert_eq!(normalize_name("bench\r\nname"), "bench  name");
+    }
+}
@@ -3,11 +3,15 @@ use std::str::FromStr;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+mod benchmark_glob;
 mod benchmark_name;
 mod branch_name;
+mod cidr;
+mod cron;
 mod date_time;
 mod email;
 mod error;
+mod escape;
 mod git_hash;
 mod index;
 mod jwt;
@@ -17,6 +21,7 @@ mod non_empty;
 mod plus;
 mod resource_id;
 mod resource_name;
+mod scale_factor;
 mod secret;
 mod slug;
 mod units;
@@ -26,16 +31,20 @@ mod user_name;
 pub use crate::git_hash::GitHash;
 pub use crate::slug::Slug;
 pub use crate::url::Url;
+pub use benchmark_glob::BenchmarkGlob;
 pub use benchmark_name::BenchmarkName;
 pub use branch_name::BranchName;
+pub use cidr::Cidr;
+pub use cron::Cron;
 pub use date_time::{DateTime, DateTimeMillis};
 pub use email::Email;
 pub use error::ValidError;
 use error::REGEX_ERROR;
+pub use escape::strip_ansi_escapes;
 pub use index::Index;
 pub use jwt::Jwt;
 pub use model::{
-    boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary},
+    boundary::{Boundary, CdfBoundary, CusumBoundary, IqrBoundary, PercentageBoundary},
     model_test::ModelTest,
     sample_size::SampleSize,
     window::Window,
@@ -50,8 +59,9 @@ pub use plus::{
 };
 pub use resource_id::{ResourceId, ResourceIdKind};
 pub use resource_name::ResourceName;
+pub use scale_factor::ScaleFactor;
 pub use secret::Secret;
-pub use units::{Units, BYTES, NANOSECONDS, SECONDS};
+pub use units::{Units, BYTES, JOULES, NANOSECONDS, SECONDS};
 pub use user_name::UserName;
 
 const MAX_LEN: usize = 64;
@@ -115,6 +115,10 @@ impl Boundary {
     pub fn is_valid_iqr(boundary: f64) -> bool {
         is_valid_iqr_boundary(boundary)
     }
+
+    pub fn is_valid_cusum(boundary: f64) -> bool {
+        is_valid_cusum_boundary(boundary)
+    }
 }
 
 #[cfg(feature = "db")]
@@ -252,6 +256,41 @@ impl From<IqrBoundary> for Boundary {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct CusumBoundary(OrderedFloat<f64>);
+
+impl TryFrom<f64> for CusumBoundary {
+    type Error = ValidError;
+
+    fn try_from(boundary: f64) -> Result<Self, Self::Error> {
+        // The cumulative sum decision interval must be greater than 0.0
+        Boundary::is_valid_cusum(boundary)
+            .then(|| Self(boundary.into()))
+            .ok_or(ValidError::CusumBoundary(boundary))
+    }
+}
+
+impl From<CusumBoundary> for f64 {
+    fn from(boundary: CusumBoundary) -> Self {
+        boundary.0.into()
+    }
+}
+
+impl TryFrom<Boundary> for CusumBoundary {
+    type Error = ValidError;
+
+    fn try_from(boundary: Boundary) -> Result<Self, Self::Error> {
+        f64::from(boundary).try_into()
+    }
+}
+
+impl From<CusumBoundary> for Boundary {
+    fn from(boundary: CusumBoundary) -> Self {
+        // This should never fail because Boundary is a superset of CusumBoundary
+        f64::from(boundary).try_into().unwrap_or(Boundary::ZERO)
+    }
+}
+
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub fn is_valid_boundary(boundary: f64) -> bool {
     boundary.is_finite()
@@ -276,6 +315,11 @@ pub fn is_valid_iqr_boundary(boundary: f64) -> bool {
     boundary >= 0.0
 }
 
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_cusum_boundary(boundary: f64) -> bool {
+    boundary > 0.0 && boundary.is_finite()
+}
+
 #[cfg(test)]
 mod test {
     use pretty_assertions::assert_eq;
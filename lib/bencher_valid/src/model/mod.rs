@@ -12,7 +12,7 @@ pub mod model_test;
 pub mod sample_size;
 pub mod window;
 
-use boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
+use boundary::{Boundary, CdfBoundary, CusumBoundary, IqrBoundary, PercentageBoundary};
 use model_test::ModelTest;
 use sample_size::SampleSize;
 use window::Window;
@@ -37,6 +37,16 @@ pub struct Model {
     /// The upper boundary used to calculate the upper boundary limit.
     /// The requirements for this field depend on which `test` is selected.
     pub upper_boundary: Option<Boundary>,
+    /// The lower boundary used to calculate the critical lower boundary limit.
+    /// If set, alerts for the lower boundary limit have a `warning` severity
+    /// and alerts for the critical lower boundary limit have a `critical` severity.
+    /// It must be at least as permissive as the `lower_boundary`.
+    pub lower_critical: Option<Boundary>,
+    /// The upper boundary used to calculate the critical upper boundary limit.
+    /// If set, alerts for the upper boundary limit have a `warning` severity
+    /// and alerts for the critical upper boundary limit have a `critical` severity.
+    /// It must be at least as permissive as the `upper_boundary`.
+    pub upper_critical: Option<Boundary>,
 }
 
 impl Model {
@@ -48,6 +58,8 @@ impl Model {
             window: None,
             lower_boundary: Some(Boundary::NINETY_NINE),
             upper_boundary: None,
+            lower_critical: None,
+            upper_critical: None,
         }
     }
 
@@ -59,12 +71,41 @@ impl Model {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_NINE),
+            lower_critical: None,
+            upper_critical: None,
         }
     }
 
     pub fn validate(self) -> Result<(), ValidError> {
         validate_model(self)
     }
+
+    /// Whether replacing the current model with this model would make the threshold more permissive.
+    /// Changing the test, sample sizes, or window is always considered to be loosening the model,
+    /// as is removing or widening any of the boundaries.
+    pub fn is_looser_than(self, current: Self) -> bool {
+        if self.test != current.test
+            || self.min_sample_size != current.min_sample_size
+            || self.max_sample_size != current.max_sample_size
+            || self.window != current.window
+        {
+            return true;
+        }
+        [
+            (self.lower_boundary, current.lower_boundary, Side::Lower),
+            (self.upper_boundary, current.upper_boundary, Side::Upper),
+            (self.lower_critical, current.lower_critical, Side::Lower),
+            (self.upper_critical, current.upper_critical, Side::Upper),
+        ]
+        .into_iter()
+        .any(|(boundary, current, side)| match (boundary, current) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(boundary), Some(current)) => {
+                boundary != current && is_permissive(self.test, side, boundary, current)
+            },
+        })
+    }
 }
 
 pub fn validate_model(model: Model) -> Result<(), ValidError> {
@@ -75,7 +116,11 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         window,
         lower_boundary,
         upper_boundary,
+        lower_critical,
+        upper_critical,
     } = model;
+    validate_critical(test, lower_boundary, lower_critical, Side::Lower)?;
+    validate_critical(test, upper_boundary, upper_critical, Side::Upper)?;
     match test {
         ModelTest::Static => {
             if let Some(&min_sample_size) = min_sample_size.as_ref() {
@@ -100,19 +145,88 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         },
         ModelTest::Percentage => {
             validate_sample_size(min_sample_size, max_sample_size)?;
+            validate_critical_boundary::<PercentageBoundary>(lower_critical, upper_critical)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal | ModelTest::Percentile => {
             validate_sample_size(min_sample_size, max_sample_size)?;
+            validate_critical_boundary::<CdfBoundary>(lower_critical, upper_critical)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
         ModelTest::Iqr | ModelTest::DeltaIqr => {
             validate_sample_size(min_sample_size, max_sample_size)?;
+            validate_critical_boundary::<IqrBoundary>(lower_critical, upper_critical)?;
             validate_boundary::<IqrBoundary>(lower_boundary, upper_boundary)
         },
+        ModelTest::Cusum => {
+            validate_sample_size(min_sample_size, max_sample_size)?;
+            validate_critical_boundary::<CusumBoundary>(lower_critical, upper_critical)?;
+            validate_boundary::<CusumBoundary>(lower_boundary, upper_boundary)
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Lower,
+    Upper,
+}
+
+// A critical boundary requires a boundary on the same side,
+// and the critical boundary must be at least as permissive as that boundary.
+fn validate_critical(
+    test: ModelTest,
+    boundary: Option<Boundary>,
+    critical: Option<Boundary>,
+    side: Side,
+) -> Result<(), ValidError> {
+    let Some(critical) = critical else {
+        return Ok(());
+    };
+    let Some(boundary) = boundary else {
+        return Err(ValidError::NoCriticalBoundary(critical));
+    };
+    if is_permissive(test, side, critical, boundary) {
+        Ok(())
+    } else {
+        Err(ValidError::CriticalBoundary { boundary, critical })
     }
 }
 
+// Whether the boundary is at least as permissive as the other boundary on the same side
+fn is_permissive(test: ModelTest, side: Side, boundary: Boundary, other: Boundary) -> bool {
+    match (test, side) {
+        // Static boundaries are absolute values,
+        // so a more permissive lower boundary is less than the other lower boundary.
+        (ModelTest::Static, Side::Lower) => f64::from(boundary) <= f64::from(other),
+        (ModelTest::Static, Side::Upper)
+        | (
+            ModelTest::Percentage
+            | ModelTest::ZScore
+            | ModelTest::TTest
+            | ModelTest::LogNormal
+            | ModelTest::Iqr
+            | ModelTest::DeltaIqr
+            | ModelTest::Percentile
+            | ModelTest::Cusum,
+            Side::Lower | Side::Upper,
+        ) => f64::from(boundary) >= f64::from(other),
+    }
+}
+
+fn validate_critical_boundary<B>(
+    lower: Option<Boundary>,
+    upper: Option<Boundary>,
+) -> Result<(), ValidError>
+where
+    B: TryFrom<Boundary, Error = ValidError>,
+{
+    for critical in [lower, upper].into_iter().flatten() {
+        B::try_from(critical)?;
+    }
+    Ok(())
+}
+
 fn validate_sample_size(
     min_sample_size: Option<SampleSize>,
     max_sample_size: Option<SampleSize>,
@@ -153,3 +267,118 @@ pub fn is_valid_model(model: &str) -> bool {
     };
     validate_model(model).is_ok()
 }
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{validate_model, Boundary, Model, ModelTest};
+
+    fn percentage(upper_boundary: f64, upper_critical: Option<f64>) -> Model {
+        Model {
+            test: ModelTest::Percentage,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: None,
+            upper_boundary: Some(Boundary::try_from(upper_boundary).unwrap()),
+            lower_critical: None,
+            upper_critical: upper_critical.map(|critical| Boundary::try_from(critical).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_model_critical() {
+        assert_eq!(true, validate_model(percentage(0.03, None)).is_ok());
+        assert_eq!(true, validate_model(percentage(0.03, Some(0.10))).is_ok());
+        assert_eq!(true, validate_model(percentage(0.03, Some(0.03))).is_ok());
+        assert_eq!(false, validate_model(percentage(0.10, Some(0.03))).is_ok());
+
+        let mut model = percentage(0.03, None);
+        model.lower_critical = Some(Boundary::ZERO);
+        assert_eq!(false, validate_model(model).is_ok());
+    }
+
+    #[test]
+    fn test_model_critical_static() {
+        let model = Model {
+            test: ModelTest::Static,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: Some(Boundary::try_from(10.0).unwrap()),
+            upper_boundary: Some(Boundary::try_from(20.0).unwrap()),
+            lower_critical: Some(Boundary::try_from(5.0).unwrap()),
+            upper_critical: Some(Boundary::try_from(25.0).unwrap()),
+        };
+        assert_eq!(true, validate_model(model).is_ok());
+
+        let model = Model {
+            lower_critical: Some(Boundary::try_from(15.0).unwrap()),
+            ..model
+        };
+        assert_eq!(false, validate_model(model).is_ok());
+    }
+
+    #[test]
+    fn test_model_cusum() {
+        let model = Model {
+            test: ModelTest::Cusum,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: None,
+            upper_boundary: Some(Boundary::try_from(4.0).unwrap()),
+            lower_critical: None,
+            upper_critical: Some(Boundary::try_from(8.0).unwrap()),
+        };
+        assert_eq!(true, validate_model(model).is_ok());
+
+        let model = Model {
+            upper_boundary: Some(Boundary::ZERO),
+            upper_critical: None,
+            ..model
+        };
+        assert_eq!(false, validate_model(model).is_ok());
+    }
+
+    #[test]
+    fn test_model_is_looser_than() {
+        let current = percentage(0.10, Some(0.20));
+        assert_eq!(false, current.is_looser_than(current));
+        assert_eq!(false, percentage(0.05, Some(0.20)).is_looser_than(current));
+        assert_eq!(true, percentage(0.15, Some(0.20)).is_looser_than(current));
+        assert_eq!(true, percentage(0.10, Some(0.30)).is_looser_than(current));
+        assert_eq!(true, percentage(0.10, None).is_looser_than(current));
+
+        let mut model = current;
+        model.lower_boundary = Some(Boundary::try_from(0.10).unwrap());
+        assert_eq!(false, model.is_looser_than(current));
+        assert_eq!(true, current.is_looser_than(model));
+
+        let mut model = current;
+        model.test = ModelTest::ZScore;
+        assert_eq!(true, model.is_looser_than(current));
+    }
+
+    #[test]
+    fn test_model_is_looser_than_static() {
+        let current = Model {
+            test: ModelTest::Static,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: Some(Boundary::try_from(10.0).unwrap()),
+            upper_boundary: Some(Boundary::try_from(20.0).unwrap()),
+            lower_critical: None,
+            upper_critical: None,
+        };
+        let model = Model {
+            lower_boundary: Some(Boundary::try_from(12.0).unwrap()),
+            upper_boundary: Some(Boundary::try_from(18.0).unwrap()),
+            ..current
+        };
+        assert_eq!(false, model.is_looser_than(current));
+        assert_eq!(true, current.is_looser_than(model));
+    }
+}
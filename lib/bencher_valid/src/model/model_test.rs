@@ -10,6 +10,8 @@ const T_TEST_INT: i32 = 1;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const PERCENTILE_INT: i32 = 50;
+const CUSUM_INT: i32 = 60;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,13 +30,15 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    Percentile = PERCENTILE_INT,
+    Cusum = CUSUM_INT,
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        ModelTest, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
-        Z_SCORE_INT,
+        ModelTest, CUSUM_INT, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT,
+        PERCENTILE_INT, STATIC_INT, T_TEST_INT, Z_SCORE_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -60,6 +64,8 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::Percentile => PERCENTILE_INT.to_sql(out),
+                Self::Cusum => CUSUM_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +84,8 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                PERCENTILE_INT => Ok(Self::Percentile),
+                CUSUM_INT => Ok(Self::Cusum),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
@@ -10,7 +10,7 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
-use crate::{is_valid_len, Slug, UserName, ValidError};
+use crate::{escape::normalize_name, is_valid_len, Slug, UserName, ValidError};
 
 #[typeshare::typeshare]
 #[derive(Debug, Display, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
@@ -26,7 +26,8 @@ impl FromStr for ResourceName {
     type Err = ValidError;
 
     fn from_str(resource_name: &str) -> Result<Self, Self::Err> {
-        if is_valid_resource_name(resource_name) {
+        let resource_name = normalize_name(resource_name);
+        if is_valid_resource_name(&resource_name) {
             Ok(Self(resource_name.into()))
         } else {
             Err(ValidError::ResourceName(resource_name.into()))
@@ -0,0 +1,160 @@
+use derive_more::Display;
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Double))]
+pub struct ScaleFactor(OrderedFloat<f64>);
+
+impl Default for ScaleFactor {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl ScaleFactor {
+    pub const ONE: Self = Self(OrderedFloat(1.0));
+
+    pub fn is_one(self) -> bool {
+        self == Self::ONE
+    }
+}
+
+impl TryFrom<f64> for ScaleFactor {
+    type Error = ValidError;
+
+    fn try_from(scale_factor: f64) -> Result<Self, Self::Error> {
+        is_valid_scale_factor(scale_factor)
+            .then(|| Self(scale_factor.into()))
+            .ok_or(ValidError::ScaleFactor(scale_factor))
+    }
+}
+
+impl From<ScaleFactor> for f64 {
+    fn from(scale_factor: ScaleFactor) -> Self {
+        scale_factor.0.into()
+    }
+}
+
+impl FromStr for ScaleFactor {
+    type Err = ValidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(f64::from_str(s).map_err(ValidError::ScaleFactorStr)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScaleFactor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(ScaleFactorVisitor)
+    }
+}
+
+struct ScaleFactorVisitor;
+
+impl Visitor<'_> for ScaleFactorVisitor {
+    type Value = ScaleFactor;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a positive floating point scale factor")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        #[allow(clippy::cast_precision_loss)]
+        (v as f64).try_into().map_err(E::custom)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.try_into().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::ScaleFactor;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Double, DB> for ScaleFactor
+    where
+        DB: diesel::backend::Backend,
+        for<'a> f64: diesel::serialize::ToSql<diesel::sql_types::Double, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(f64::from(*self));
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Double, DB> for ScaleFactor
+    where
+        DB: diesel::backend::Backend,
+        f64: diesel::deserialize::FromSql<diesel::sql_types::Double, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            f64::from_sql(bytes)?.try_into().map_err(Into::into)
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_scale_factor(scale_factor: f64) -> bool {
+    scale_factor.is_finite() && scale_factor > 0.0
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_scale_factor, ScaleFactor};
+
+    #[test]
+    fn test_scale_factor() {
+        assert_eq!(true, is_valid_scale_factor(1.0));
+        assert_eq!(true, is_valid_scale_factor(0.5));
+        assert_eq!(true, is_valid_scale_factor(2.0));
+        assert_eq!(true, is_valid_scale_factor(f64::MAX));
+
+        assert_eq!(false, is_valid_scale_factor(0.0));
+        assert_eq!(false, is_valid_scale_factor(-1.0));
+        assert_eq!(false, is_valid_scale_factor(f64::INFINITY));
+        assert_eq!(false, is_valid_scale_factor(f64::NEG_INFINITY));
+        assert_eq!(false, is_valid_scale_factor(f64::NAN));
+    }
+
+    #[test]
+    fn test_scale_factor_serde() {
+        let scale_factor: ScaleFactor = serde_json::from_str("1.5").unwrap();
+        assert_eq!(ScaleFactor(1.5.into()), scale_factor);
+        let scale_factor: ScaleFactor = serde_json::from_str("2").unwrap();
+        assert_eq!(ScaleFactor(2.0.into()), scale_factor);
+
+        assert!(serde_json::from_str::<ScaleFactor>("0.0").is_err());
+        assert!(serde_json::from_str::<ScaleFactor>("-1.0").is_err());
+    }
+}
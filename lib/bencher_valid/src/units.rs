@@ -7,6 +7,7 @@ use crate::ResourceName;
 pub const NANOSECONDS: &str = "nanoseconds (ns)";
 pub const SECONDS: &str = "seconds (s)";
 pub const BYTES: &str = "bytes (B)";
+pub const JOULES: &str = "joules (J)";
 
 #[derive(Debug, Clone)]
 pub struct Units {
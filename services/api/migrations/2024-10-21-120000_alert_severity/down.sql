@@ -0,0 +1,75 @@
+PRAGMA foreign_keys = off;
+-- model
+CREATE TABLE down_model (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    test INTEGER NOT NULL,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    window BIGINT,
+    lower_boundary DOUBLE,
+    upper_boundary DOUBLE,
+    created BIGINT NOT NULL,
+    replaced BIGINT,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE
+);
+INSERT INTO down_model(
+        id,
+        uuid,
+        threshold_id,
+        test,
+        min_sample_size,
+        max_sample_size,
+        window,
+        lower_boundary,
+        upper_boundary,
+        created,
+        replaced
+    )
+SELECT id,
+    uuid,
+    threshold_id,
+    test,
+    min_sample_size,
+    max_sample_size,
+    window,
+    lower_boundary,
+    upper_boundary,
+    created,
+    replaced
+FROM model;
+DROP TABLE model;
+ALTER TABLE down_model
+    RENAME TO model;
+-- alert
+CREATE TABLE down_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE
+);
+INSERT INTO down_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        status,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    status,
+    modified
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE down_alert
+    RENAME TO alert;
+-- index
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,6 @@
+CREATE TABLE report_raw (
+    id INTEGER PRIMARY KEY NOT NULL,
+    report_id INTEGER NOT NULL UNIQUE,
+    results BLOB NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+CREATE TABLE report_attachment (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    report_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    data BLOB,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE,
+    UNIQUE(report_id, name)
+);
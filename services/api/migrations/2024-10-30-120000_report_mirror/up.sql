@@ -0,0 +1,11 @@
+CREATE TABLE report_mirror (
+    id INTEGER PRIMARY KEY NOT NULL,
+    report_id INTEGER NOT NULL UNIQUE,
+    project TEXT NOT NULL,
+    report TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    next_attempt BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE
+);
+CREATE INDEX index_report_mirror_next_attempt ON report_mirror(next_attempt);
@@ -0,0 +1,11 @@
+-- An audit trail of users whose personal data has been scrubbed
+CREATE TABLE user_deletion (
+    id INTEGER PRIMARY KEY NOT NULL,
+    -- The user is kept with an opaque identity in order to retain report attribution
+    user_id INTEGER NOT NULL,
+    -- The user that requested the deletion, either the user themselves or a server admin
+    requester_id INTEGER NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id),
+    FOREIGN KEY (requester_id) REFERENCES user (id)
+);
@@ -0,0 +1 @@
+DROP TABLE benchmark_location;
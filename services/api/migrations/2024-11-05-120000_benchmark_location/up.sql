@@ -0,0 +1,9 @@
+CREATE TABLE benchmark_location (
+    id INTEGER PRIMARY KEY NOT NULL,
+    benchmark_id INTEGER NOT NULL UNIQUE,
+    file TEXT NOT NULL,
+    line INTEGER,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE
+);
+CREATE INDEX index_benchmark_location_file ON benchmark_location(file);
@@ -0,0 +1,12 @@
+-- Long-running server operations, such as database backups
+CREATE TABLE operation (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    kind INTEGER NOT NULL,
+    state INTEGER NOT NULL,
+    completed INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    error TEXT,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL
+);
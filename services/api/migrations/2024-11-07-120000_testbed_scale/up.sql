@@ -0,0 +1,42 @@
+PRAGMA foreign_keys = off;
+-- testbed
+CREATE TABLE up_testbed (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    -- The scale factor is used to normalize metrics across testbeds
+    scale DOUBLE NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+INSERT INTO up_testbed(
+        id,
+        uuid,
+        project_id,
+        name,
+        slug,
+        scale,
+        created,
+        modified,
+        archived
+    )
+SELECT id,
+    uuid,
+    project_id,
+    name,
+    slug,
+    1.0,
+    created,
+    modified,
+    archived
+FROM testbed;
+DROP TABLE testbed;
+ALTER TABLE up_testbed
+    RENAME TO testbed;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,2 @@
+DROP TABLE alert_issue;
+DROP TABLE integration;
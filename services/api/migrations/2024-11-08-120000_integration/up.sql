@@ -0,0 +1,28 @@
+-- Issue tracker integrations for a project
+CREATE TABLE integration (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    provider INTEGER NOT NULL,
+    repository TEXT NOT NULL,
+    api_url TEXT NOT NULL,
+    token TEXT NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+-- The issues opened for alerts by an integration
+CREATE TABLE alert_issue (
+    id INTEGER PRIMARY KEY NOT NULL,
+    alert_id INTEGER NOT NULL,
+    integration_id INTEGER NOT NULL,
+    number BIGINT NOT NULL,
+    url TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    closed BIGINT,
+    FOREIGN KEY (alert_id) REFERENCES alert (id) ON DELETE CASCADE,
+    FOREIGN KEY (integration_id) REFERENCES integration (id) ON DELETE CASCADE,
+    UNIQUE(alert_id, integration_id)
+);
@@ -0,0 +1,20 @@
+-- Default threshold templates for an organization or one of its projects
+CREATE TABLE threshold_template (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    project_id INTEGER,
+    measure TEXT NOT NULL,
+    test INTEGER NOT NULL,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    window BIGINT,
+    lower_boundary DOUBLE,
+    upper_boundary DOUBLE,
+    lower_critical DOUBLE,
+    upper_critical DOUBLE,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
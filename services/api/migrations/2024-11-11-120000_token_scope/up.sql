@@ -0,0 +1,43 @@
+PRAGMA foreign_keys = off;
+-- token
+CREATE TABLE up_token (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    jwt TEXT NOT NULL,
+    creation BIGINT NOT NULL,
+    expiration BIGINT NOT NULL,
+    -- If set, the token is limited to this scope
+    scope INTEGER,
+    -- If set, the token is limited to this project
+    project_id INTEGER,
+    FOREIGN KEY (user_id) REFERENCES user (id),
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+INSERT INTO up_token(
+        id,
+        uuid,
+        user_id,
+        name,
+        jwt,
+        creation,
+        expiration,
+        scope,
+        project_id
+    )
+SELECT id,
+    uuid,
+    user_id,
+    name,
+    jwt,
+    creation,
+    expiration,
+    NULL,
+    NULL
+FROM token;
+DROP TABLE token;
+ALTER TABLE up_token
+    RENAME TO token;
+CREATE INDEX index_token_jwt ON token(jwt);
+PRAGMA foreign_keys = on;
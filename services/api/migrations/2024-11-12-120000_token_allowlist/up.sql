@@ -0,0 +1,8 @@
+-- The IP addresses or CIDR ranges that an API token can be used from
+CREATE TABLE token_allowlist (
+    id INTEGER PRIMARY KEY NOT NULL,
+    token_id INTEGER NOT NULL,
+    cidr TEXT NOT NULL,
+    FOREIGN KEY (token_id) REFERENCES token (id) ON DELETE CASCADE,
+    UNIQUE(token_id, cidr)
+);
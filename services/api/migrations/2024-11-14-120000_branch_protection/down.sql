@@ -0,0 +1,46 @@
+PRAGMA foreign_keys = off;
+-- branch
+CREATE TABLE down_branch (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    head_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    merged BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (head_id) REFERENCES head (id),
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+INSERT INTO down_branch(
+        id,
+        uuid,
+        project_id,
+        name,
+        slug,
+        head_id,
+        created,
+        modified,
+        archived,
+        merged
+    )
+SELECT id,
+    uuid,
+    project_id,
+    name,
+    slug,
+    head_id,
+    created,
+    modified,
+    archived,
+    merged
+FROM branch;
+DROP TABLE branch;
+ALTER TABLE down_branch
+    RENAME TO branch;
+CREATE INDEX index_branch_head ON branch(uuid, project_id, head_id);
+PRAGMA foreign_keys = on;
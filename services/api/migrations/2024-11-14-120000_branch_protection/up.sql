@@ -0,0 +1,58 @@
+PRAGMA foreign_keys = off;
+-- branch
+CREATE TABLE up_branch (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    head_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    merged BIGINT,
+    -- The branch can not be deleted
+    protect_delete BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Only organization leaders can loosen or remove thresholds for the branch
+    protect_thresholds BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Only scoped API tokens can create reports for the branch
+    protect_reports BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (head_id) REFERENCES head (id),
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+INSERT INTO up_branch(
+        id,
+        uuid,
+        project_id,
+        name,
+        slug,
+        head_id,
+        created,
+        modified,
+        archived,
+        merged,
+        protect_delete,
+        protect_thresholds,
+        protect_reports
+    )
+SELECT id,
+    uuid,
+    project_id,
+    name,
+    slug,
+    head_id,
+    created,
+    modified,
+    archived,
+    merged,
+    FALSE,
+    FALSE,
+    FALSE
+FROM branch;
+DROP TABLE branch;
+ALTER TABLE up_branch
+    RENAME TO branch;
+CREATE INDEX index_branch_head ON branch(uuid, project_id, head_id);
+PRAGMA foreign_keys = on;
@@ -0,0 +1,13 @@
+-- The history of server database backups, both manual and scheduled
+CREATE TABLE backup (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    operation_id INTEGER NOT NULL,
+    scheduled BOOLEAN NOT NULL,
+    compress BOOLEAN NOT NULL,
+    data_store INTEGER,
+    rm BOOLEAN NOT NULL,
+    file_name TEXT,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (operation_id) REFERENCES operation (id) ON DELETE CASCADE
+);
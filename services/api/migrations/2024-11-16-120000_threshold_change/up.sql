@@ -0,0 +1,24 @@
+-- Pending and approved changes to thresholds for branches with protected thresholds
+CREATE TABLE threshold_change (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    threshold_id INTEGER NOT NULL,
+    requester_id INTEGER NOT NULL,
+    approver_id INTEGER,
+    -- A NULL test removes the current model
+    test INTEGER,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    window BIGINT,
+    lower_boundary DOUBLE,
+    upper_boundary DOUBLE,
+    lower_critical DOUBLE,
+    upper_critical DOUBLE,
+    created BIGINT NOT NULL,
+    approved BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE,
+    FOREIGN KEY (requester_id) REFERENCES user (id) ON DELETE CASCADE,
+    FOREIGN KEY (approver_id) REFERENCES user (id) ON DELETE CASCADE
+);
@@ -0,0 +1,39 @@
+PRAGMA foreign_keys = off;
+-- alert
+CREATE TABLE up_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    -- Existing alerts are all boundary limit alerts
+    kind INTEGER NOT NULL,
+    severity INTEGER NOT NULL,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE
+);
+INSERT INTO up_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        kind,
+        severity,
+        status,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    0,
+    severity,
+    status,
+    modified
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE up_alert
+    RENAME TO alert;
+-- index
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,54 @@
+PRAGMA foreign_keys = off;
+-- threshold
+CREATE TABLE up_threshold (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    -- A benchmark name or glob pattern of benchmark names
+    -- If NULL, then the threshold applies to all benchmarks
+    benchmark TEXT,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    FOREIGN KEY (model_id) REFERENCES model (id),
+    UNIQUE(branch_id, testbed_id, measure_id, benchmark)
+);
+INSERT INTO up_threshold(
+        id,
+        uuid,
+        project_id,
+        branch_id,
+        testbed_id,
+        measure_id,
+        benchmark,
+        model_id,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    branch_id,
+    testbed_id,
+    measure_id,
+    NULL,
+    model_id,
+    created,
+    modified
+FROM threshold;
+DROP TABLE threshold;
+ALTER TABLE up_threshold
+    RENAME TO threshold;
+-- index
+-- In SQLite, NULL values are distinct in a UNIQUE constraint,
+-- so only allow one threshold for all benchmarks.
+CREATE UNIQUE INDEX index_threshold_all_benchmarks ON threshold(branch_id, testbed_id, measure_id)
+WHERE benchmark IS NULL;
+PRAGMA foreign_keys = on;
\ No newline at end of file
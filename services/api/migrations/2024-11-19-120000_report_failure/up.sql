@@ -0,0 +1,8 @@
+-- The exit code and stderr of a benchmark command that failed
+CREATE TABLE report_failure (
+    id INTEGER PRIMARY KEY NOT NULL,
+    report_id INTEGER NOT NULL UNIQUE,
+    exit_code INTEGER NOT NULL,
+    stderr TEXT NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE
+);
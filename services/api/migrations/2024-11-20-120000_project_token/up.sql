@@ -0,0 +1,14 @@
+-- API tokens that belong to a project instead of a user
+CREATE TABLE project_token (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    creator_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    jwt TEXT NOT NULL UNIQUE,
+    scope INTEGER NOT NULL,
+    creation BIGINT NOT NULL,
+    expiration BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (creator_id) REFERENCES user (id) ON DELETE CASCADE
+);
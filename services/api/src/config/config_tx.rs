@@ -4,8 +4,8 @@ use std::sync::Arc;
 use bencher_json::system::config::{JsonLitestream, JsonPlus};
 use bencher_json::{
     system::config::{
-        IfExists, JsonConsole, JsonDatabase, JsonLogging, JsonSecurity, JsonServer, JsonSmtp,
-        JsonTls, LogLevel, ServerLog,
+        IfExists, JsonAnonymous, JsonConsole, JsonDatabase, JsonLogging, JsonOtel, JsonRetention,
+        JsonSecurity, JsonServer, JsonSmtp, JsonTls, LogLevel, ServerLog,
     },
     JsonConfig,
 };
@@ -20,16 +20,18 @@ use dropshot::{
 use slog::{debug, error, info, Logger};
 use tokio::sync::mpsc::Sender;
 
+#[cfg(feature = "otel")]
+use crate::context::Otel;
 #[cfg(feature = "plus")]
-use crate::model::server::QueryServer;
+use crate::{context::IssueTracker, model::server::QueryServer};
 use crate::{
-    context::{ApiContext, Database, DbConnection},
+    context::{ApiContext, BackupSchedule, Database, DbConnection, Encryption},
     endpoints::Api,
 };
 
-use super::Config;
 #[cfg(feature = "plus")]
 use super::{plus::Plus, DEFAULT_BUSY_TIMEOUT};
+use super::{Config, DEFAULT_LARGE_MAX_BODY_SIZE};
 
 const DATABASE_URL: &str = "DATABASE_URL";
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
@@ -53,12 +55,17 @@ pub enum ConfigTxError {
     Endpoint(bencher_json::ValidError),
     #[error("Failed to connect to database ({0}): {1}")]
     DatabaseConnection(String, diesel::ConnectionError),
+    #[error("Failed to rotate encrypted values: {0}")]
+    Encryption(crate::context::EncryptionError),
     #[error("Failed to parse data store: {0}")]
     DataStore(crate::context::DataStoreError),
     #[error("Failed to register endpoint: {0}")]
     Register(dropshot::ApiDescriptionRegisterError),
     #[error("Failed to create server: {0}")]
     CreateServer(Box<dyn std::error::Error + Send + Sync>),
+    #[cfg(feature = "otel")]
+    #[error("Failed to configure OpenTelemetry: {0}")]
+    Otel(crate::context::OtelError),
 
     #[cfg(feature = "plus")]
     #[error("{0}")]
@@ -86,6 +93,10 @@ impl ConfigTx {
             mut server,
             database,
             smtp,
+            otel,
+            retention,
+            backup,
+            anonymous,
             logging: _,
             #[cfg(feature = "plus")]
             plus,
@@ -97,8 +108,12 @@ impl ConfigTx {
             console,
             security,
             smtp,
+            otel,
+            retention,
+            anonymous,
             database,
             restart_tx,
+            &server,
             #[cfg(feature = "plus")]
             plus,
         )?;
@@ -115,6 +130,24 @@ impl ConfigTx {
         });
         let config_dropshot = into_config_dropshot(server);
 
+        if let Some(backup_schedule) = backup.map(BackupSchedule::from) {
+            info!(
+                log,
+                "Backing up database on schedule: {}",
+                backup_schedule.cron()
+            );
+            backup_schedule.spawn(log.clone(), context.database.clone());
+        }
+
+        if let Some(retention) = context.retention {
+            info!(log, "Retaining reports for {} days", retention.days());
+            retention.spawn(
+                log.clone(),
+                context.database.connection.clone(),
+                context.database.data_store.clone(),
+            );
+        }
+
         #[cfg(feature = "plus")]
         {
             let conn = context.database.connection.clone();
@@ -130,6 +163,10 @@ impl ConfigTx {
             } else {
                 (Some(context.licensor.clone()), None)
             };
+            if let Some(mirror) = context.mirror.clone() {
+                info!(log, "Mirroring reports");
+                mirror.spawn(log.clone(), conn.clone());
+            }
             query_server.spawn_stats(log.clone(), conn, context.stats, licensor, messenger);
         }
 
@@ -151,13 +188,18 @@ impl ConfigTx {
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 fn into_context(
     log: &Logger,
     console: JsonConsole,
     security: JsonSecurity,
     smtp: Option<JsonSmtp>,
+    otel: Option<JsonOtel>,
+    retention: Option<JsonRetention>,
+    anonymous: Option<JsonAnonymous>,
     json_database: JsonDatabase,
     restart_tx: Sender<()>,
+    server: &JsonServer,
     #[cfg(feature = "plus")] plus: Option<JsonPlus>,
 ) -> Result<ApiContext, ConfigTxError> {
     let console_url: url::Url = console.url.try_into().map_err(ConfigTxError::Endpoint)?;
@@ -177,23 +219,55 @@ fn into_context(
     info!(&log, "Running database migrations");
     run_migrations(&mut database_connection)?;
 
+    let JsonSecurity {
+        issuer,
+        secret_key,
+        encryption,
+    } = security;
+    let encryption = encryption.map(Encryption::from).unwrap_or_default();
+    if encryption.is_enabled() {
+        info!(&log, "Rotating encrypted values");
+        let rotated = encryption
+            .rotate_all(&mut database_connection)
+            .map_err(ConfigTxError::Encryption)?;
+        info!(&log, "Rotated {rotated} encrypted values");
+    }
+
     let data_store = if let Some(data_store) = json_database.data_store {
-        Some(data_store.try_into().map_err(ConfigTxError::DataStore)?)
+        Some(Arc::new(
+            data_store.try_into().map_err(ConfigTxError::DataStore)?,
+        ))
     } else {
         None
     };
 
     info!(&log, "Loading secret key");
     let token_key = TokenKey::new(
-        security.issuer.unwrap_or_else(|| console_url.to_string()),
-        &security.secret_key,
+        issuer.unwrap_or_else(|| console_url.to_string()),
+        &secret_key,
     );
 
+    #[cfg(feature = "otel")]
+    let otel = if let Some(otel) = otel {
+        info!(&log, "Exporting OpenTelemetry traces to {}", otel.endpoint);
+        Some(Otel::new(otel).map_err(ConfigTxError::Otel)?)
+    } else {
+        None
+    };
+    #[cfg(not(feature = "otel"))]
+    if otel.is_some() {
+        slog::warn!(
+            &log,
+            "OpenTelemetry is configured but this server was built without the `otel` feature"
+        );
+    }
+
     info!(&log, "Configuring Bencher Plus");
     #[cfg(feature = "plus")]
     let Plus {
         github,
         stats,
+        mirror,
         biller,
         licensor,
         indexer,
@@ -201,11 +275,19 @@ fn into_context(
 
     #[cfg(feature = "plus")]
     let is_bencher_cloud = bencher_json::is_bencher_cloud(&console_url) && biller.is_some();
+    // Anonymous runs are always allowed on Bencher Cloud,
+    // but they must be explicitly enabled for Bencher Self-Hosted.
+    #[cfg(feature = "plus")]
+    let anonymous = anonymous.or_else(|| is_bencher_cloud.then(JsonAnonymous::default));
+    if anonymous.is_some() {
+        info!(&log, "Allowing anonymous runs");
+    }
 
     debug!(&log, "Creating API context");
     Ok(ApiContext {
         console_url,
         token_key,
+        encryption,
         rbac: init_rbac().map_err(ConfigTxError::Polar)?.into(),
         messenger: smtp.into(),
         database: Database {
@@ -214,11 +296,25 @@ fn into_context(
             data_store,
         },
         restart_tx,
+        tls: server.tls.is_some(),
+        // The large body limit should never be smaller than the limit for all other requests
+        large_body_max_bytes: server
+            .large_request_body_max_bytes
+            .unwrap_or(DEFAULT_LARGE_MAX_BODY_SIZE)
+            .max(server.request_body_max_bytes),
+        retention: retention.map(Into::into),
+        anonymous: anonymous.map(Into::into),
+        #[cfg(feature = "otel")]
+        otel,
         #[cfg(feature = "plus")]
         github,
         #[cfg(feature = "plus")]
         stats,
         #[cfg(feature = "plus")]
+        mirror,
+        #[cfg(feature = "plus")]
+        issue_tracker: IssueTracker,
+        #[cfg(feature = "plus")]
         biller,
         #[cfg(feature = "plus")]
         licensor,
@@ -309,6 +405,7 @@ fn into_config_dropshot(server: JsonServer) -> ConfigDropshot {
     let JsonServer {
         bind_address,
         request_body_max_bytes,
+        large_request_body_max_bytes: _,
         tls: _,
     } = server;
     ConfigDropshot {
@@ -31,6 +31,8 @@ const DEFAULT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
 
 // 1 megabyte or 1_048_576 bytes
 const DEFAULT_MAX_BODY_SIZE: usize = 2 << 19;
+// 32 megabytes or 33_554_432 bytes
+const DEFAULT_LARGE_MAX_BODY_SIZE: usize = 2 << 24;
 #[cfg(debug_assertions)]
 const DEFAULT_DB_PATH: &str = "data/bencher.db";
 #[cfg(not(debug_assertions))]
@@ -202,10 +204,12 @@ impl Default for Config {
             security: JsonSecurity {
                 issuer: Some(DEFAULT_CONSOLE_URL.to_string()),
                 secret_key: DEFAULT_SECRET_KEY.clone(),
+                encryption: None,
             },
             server: JsonServer {
                 bind_address: *DEFAULT_BIND_ADDRESS,
                 request_body_max_bytes: DEFAULT_MAX_BODY_SIZE,
+                large_request_body_max_bytes: None,
                 tls: None,
             },
             database: JsonDatabase {
@@ -213,6 +217,10 @@ impl Default for Config {
                 data_store: None,
             },
             smtp: None,
+            otel: None,
+            retention: None,
+            backup: None,
+            anonymous: None,
             logging: JsonLogging {
                 name: API_NAME.into(),
                 log: ServerLog::StderrTerminal {
@@ -13,7 +13,7 @@ use chrono::NaiveTime;
 use tokio::runtime::Handle;
 use url::Url;
 
-use crate::context::Indexer;
+use crate::context::{Indexer, Mirror};
 
 // Run at 03:07:22 UTC by default (offset 11,242 seconds)
 #[allow(clippy::expect_used)]
@@ -26,6 +26,7 @@ pub struct Plus {
     pub github: Option<GitHub>,
     pub indexer: Option<Indexer>,
     pub stats: StatsSettings,
+    pub mirror: Option<Mirror>,
     pub biller: Option<Biller>,
     pub licensor: Licensor,
 }
@@ -46,6 +47,8 @@ pub enum PlusError {
     BingIndex(bencher_bing_index::BingIndexError),
     #[error("Google Index failed: {0}")]
     GoogleIndex(bencher_google_index::GoogleIndexError),
+    #[error("Failed to parse mirror host URL: {0}")]
+    MirrorHost(bencher_json::ValidError),
 }
 
 impl Plus {
@@ -55,6 +58,7 @@ impl Plus {
                 github: None,
                 indexer: None,
                 stats: StatsSettings::default(),
+                mirror: None,
                 biller: None,
                 licensor: Licensor::self_hosted().map_err(PlusError::LicenseSelfHosted)?,
             });
@@ -66,6 +70,8 @@ impl Plus {
 
         let stats = plus.stats.map(Into::into).unwrap_or_default();
 
+        let mirror = plus.mirror.map(TryInto::try_into).transpose()?;
+
         let Some(JsonCloud {
             billing,
             license_pem,
@@ -77,6 +83,7 @@ impl Plus {
                 github,
                 indexer: None,
                 stats,
+                mirror,
                 biller: None,
                 licensor: Licensor::self_hosted().map_err(PlusError::LicenseSelfHosted)?,
             });
@@ -101,6 +108,7 @@ impl Plus {
             github,
             indexer,
             stats,
+            mirror,
             biller,
             licensor,
         })
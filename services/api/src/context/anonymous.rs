@@ -0,0 +1,106 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    num::NonZeroU32,
+    time::{Duration, Instant},
+};
+
+use bencher_json::{system::config::JsonAnonymous, DateTime};
+use dropshot::HttpError;
+use tokio::sync::Mutex;
+
+use crate::{
+    context::DbConnection,
+    error::too_many_requests_error,
+    model::project::unclaimed_project::{InsertUnclaimedProject, QueryUnclaimedProject},
+};
+
+// The window used to rate limit anonymous runs
+const WINDOW_SECONDS: u32 = 60 * 60;
+const DEFAULT_MAX_RUNS_PER_CLIENT: u32 = 60;
+const DEFAULT_MAX_NEW_PROJECTS: u32 = 1_000;
+const DEFAULT_MAX_REPORTS_PER_PROJECT: u32 = 100;
+
+/// Rate limits for reports sent without an account to unclaimed projects
+#[derive(Debug)]
+pub struct Anonymous {
+    max_runs_per_client: u32,
+    max_new_projects: u32,
+    max_reports_per_project: u32,
+    // The time of each recent run by client IP address
+    clients: Mutex<HashMap<IpAddr, Vec<Instant>>>,
+}
+
+impl From<JsonAnonymous> for Anonymous {
+    fn from(anonymous: JsonAnonymous) -> Self {
+        let JsonAnonymous {
+            max_runs_per_client,
+            max_new_projects,
+            max_reports_per_project,
+        } = anonymous;
+        Self {
+            max_runs_per_client: max_runs_per_client
+                .map_or(DEFAULT_MAX_RUNS_PER_CLIENT, NonZeroU32::get),
+            max_new_projects: max_new_projects.map_or(DEFAULT_MAX_NEW_PROJECTS, NonZeroU32::get),
+            max_reports_per_project: max_reports_per_project
+                .map_or(DEFAULT_MAX_REPORTS_PER_PROJECT, NonZeroU32::get),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Anonymous {
+    /// Record a run from the client, unless it has already sent too many runs within the window
+    pub async fn check_client(&self, client: IpAddr) -> Result<(), HttpError> {
+        let window = Duration::from_secs(WINDOW_SECONDS.into());
+        let now = Instant::now();
+        let mut clients = self.clients.lock().await;
+        // Forget about any runs that have fallen out of the window, for all clients
+        clients.retain(|_, runs| {
+            runs.retain(|run| now.duration_since(*run) < window);
+            !runs.is_empty()
+        });
+        let runs = clients.entry(client).or_default();
+        if u32::try_from(runs.len()).unwrap_or(u32::MAX) < self.max_runs_per_client {
+            runs.push(now);
+            Ok(())
+        } else {
+            Err(too_many_requests_error(format!(
+                "Too many anonymous runs from {client}. Please try again later or sign up for an account."
+            )))
+        }
+    }
+
+    /// Verify that not too many unclaimed projects have been created within the window
+    pub fn check_new_project(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        let recent_projects = InsertUnclaimedProject::count_since(conn, window_start())?;
+        if recent_projects < i64::from(self.max_new_projects) {
+            Ok(())
+        } else {
+            Err(too_many_requests_error(
+                "Too many anonymous runs. Please try again later or sign up for an account.",
+            ))
+        }
+    }
+
+    /// Verify that the unclaimed project has not been sent too many reports within the window
+    pub fn check_reports(
+        &self,
+        conn: &mut DbConnection,
+        unclaimed_project: &QueryUnclaimedProject,
+    ) -> Result<(), HttpError> {
+        let recent_reports = unclaimed_project.reports_since(conn, window_start())?;
+        if recent_reports < i64::from(self.max_reports_per_project) {
+            Ok(())
+        } else {
+            Err(too_many_requests_error(format!(
+                "Too many reports for unclaimed project ({project_id}). Claim the project to remove this limit.",
+                project_id = unclaimed_project.project_id
+            )))
+        }
+    }
+}
+
+fn window_start() -> DateTime {
+    (DateTime::now().into_inner() - chrono::Duration::seconds(WINDOW_SECONDS.into())).into()
+}
@@ -0,0 +1,272 @@
+use std::{ffi::OsStr, path::PathBuf};
+
+use async_compression::tokio::write::GzipEncoder;
+use bencher_json::{
+    system::{backup::JsonDataStore, config::JsonBackupSchedule},
+    Cron, JsonBackup,
+};
+use chrono::Utc;
+use diesel::connection::SimpleConnection;
+use slog::Logger;
+use tokio::{
+    fs::remove_file,
+    io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter},
+};
+
+use crate::model::{
+    backup::{InsertBackup, QueryBackup},
+    operation::QueryOperation,
+};
+
+use super::{DataStoreError, Database};
+
+const BUFFER_SIZE: usize = 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("Failed to batch execute: {0}")]
+    BatchExecute(diesel::result::Error),
+    #[error("Failed to create backup file: {0}")]
+    CreateBackupFile(std::io::Error),
+    #[error("Failed to create compressed file: {0}")]
+    CreateZipFile(std::io::Error),
+    #[error("Failed to write to compressed file: {0}")]
+    WriteZipFile(std::io::Error),
+    #[error("Failed to close compressed file: {0}")]
+    CloseZipFile(std::io::Error),
+    #[error("Failed to remove backup file: {0}")]
+    RmBackupFile(std::io::Error),
+    #[error("Failed to remove compressed file: {0}")]
+    RmZipFile(std::io::Error),
+    #[error("{0}")]
+    DataStore(DataStoreError),
+    #[error("No data store")]
+    NoDataStore,
+    #[error("The configured data store is not {0:?}")]
+    WrongDataStore(JsonDataStore),
+}
+
+/// Backs up the database on a cron schedule
+#[derive(Debug, Clone)]
+pub struct BackupSchedule {
+    cron: Cron,
+    backup: JsonBackup,
+}
+
+impl From<JsonBackupSchedule> for BackupSchedule {
+    fn from(backup_schedule: JsonBackupSchedule) -> Self {
+        let JsonBackupSchedule { cron, backup } = backup_schedule;
+        Self { cron, backup }
+    }
+}
+
+impl BackupSchedule {
+    pub fn cron(&self) -> &Cron {
+        &self.cron
+    }
+
+    /// Backup the database in the background each time the schedule matches
+    pub fn spawn(self, log: Logger, database: Database) {
+        tokio::spawn(async move {
+            loop {
+                let now = Utc::now();
+                let Some(next) = self.cron.next_after(now) else {
+                    slog::error!(log, "Backup schedule ({}) never runs", self.cron);
+                    return;
+                };
+                tokio::time::sleep((next - now).to_std().unwrap_or_default()).await;
+
+                slog::info!(log, "Running scheduled database backup ({})", self.cron);
+                let queued = {
+                    let conn = &mut *database.connection.lock().await;
+                    InsertBackup::queue(conn, &self.backup, true)
+                };
+                match queued {
+                    Ok((query_backup, query_operation)) => {
+                        run_backup(
+                            &log,
+                            &database,
+                            &query_backup,
+                            &query_operation,
+                            self.backup.clone(),
+                        )
+                        .await;
+                    },
+                    Err(e) => slog::error!(log, "Failed to queue scheduled backup: {e}"),
+                }
+            }
+        });
+    }
+}
+
+/// Run a queued backup to completion, recording the result on its operation
+pub async fn run_backup(
+    log: &Logger,
+    database: &Database,
+    query_backup: &QueryBackup,
+    query_operation: &QueryOperation,
+    json_backup: JsonBackup,
+) {
+    let result = backup(log, database, query_backup, query_operation, json_backup).await;
+    if let Err(e) = &result {
+        slog::error!(log, "Failed to backup database: {e}");
+        #[cfg(feature = "sentry")]
+        sentry::capture_error(e);
+    }
+    if let Err(e) = query_operation.finish(&mut *database.connection.lock().await, result) {
+        slog::error!(
+            log,
+            "Failed to finish backup operation ({}): {e}",
+            query_operation.uuid
+        );
+    }
+}
+
+async fn backup(
+    log: &Logger,
+    database: &Database,
+    query_backup: &QueryBackup,
+    query_operation: &QueryOperation,
+    json_backup: JsonBackup,
+) -> Result<(), BackupError> {
+    if let Err(e) = query_operation.running(&mut *database.connection.lock().await) {
+        slog::warn!(log, "Failed to start backup operation: {e}");
+    }
+    let mut completed = 0;
+
+    // Create a database backup
+    let BackupFile {
+        file_path: backup_file_path,
+        file_name: backup_file_name,
+    } = backup_database(database).await?;
+    step(log, database, query_operation, &mut completed).await;
+
+    // Compress the database backup
+    let (source_path, file_name) = if json_backup.compress.unwrap_or_default() {
+        let compressed = compress_database(backup_file_path.clone(), &backup_file_name).await?;
+        step(log, database, query_operation, &mut completed).await;
+        compressed
+    } else {
+        (backup_file_path.clone(), backup_file_name)
+    };
+    if let Err(e) = query_backup.set_file_name(&mut *database.connection.lock().await, &file_name) {
+        slog::warn!(log, "Failed to record backup file name ({file_name}): {e}");
+    }
+
+    // Store the database backup in the data store
+    if let Some(json_data_store) = json_backup.data_store {
+        let data_store = database
+            .data_store
+            .as_ref()
+            .ok_or(BackupError::NoDataStore)?;
+        if !data_store.is_kind(json_data_store) {
+            return Err(BackupError::WrongDataStore(json_data_store));
+        }
+        data_store
+            .backup(&source_path, &file_name)
+            .await
+            .map_err(BackupError::DataStore)?;
+        step(log, database, query_operation, &mut completed).await;
+    }
+
+    // Remove the remaining database backup
+    if json_backup.rm.unwrap_or_default() {
+        remove_file(source_path)
+            .await
+            .map_err(BackupError::RmZipFile)?;
+        step(log, database, query_operation, &mut completed).await;
+    }
+
+    Ok(())
+}
+
+async fn step(
+    log: &Logger,
+    database: &Database,
+    query_operation: &QueryOperation,
+    completed: &mut u32,
+) {
+    *completed += 1;
+    if let Err(e) = query_operation.step(&mut *database.connection.lock().await, *completed) {
+        slog::warn!(log, "Failed to update backup operation progress: {e}");
+    }
+}
+
+struct BackupFile {
+    file_path: PathBuf,
+    file_name: String,
+}
+
+async fn backup_database(database: &Database) -> Result<BackupFile, BackupError> {
+    let mut file_path = database.path.clone();
+
+    let file_stem = file_path
+        .file_stem()
+        .unwrap_or_else(|| OsStr::new("bencher"))
+        .to_string_lossy();
+    let file_extension = file_path
+        .extension()
+        .unwrap_or_else(|| OsStr::new("db"))
+        .to_string_lossy();
+    let date_time = Utc::now();
+    let file_name = format!(
+        "backup-{file_stem}-{}.{file_extension}",
+        date_time.format("%Y-%m-%d-%H-%M-%S")
+    );
+    file_path.set_file_name(&file_name);
+    let file_path_str = file_path.to_string_lossy();
+    let query = format!("VACUUM INTO '{file_path_str}'");
+
+    database
+        .connection
+        .lock()
+        .await
+        .batch_execute(&query)
+        .map_err(BackupError::BatchExecute)?;
+
+    Ok(BackupFile {
+        file_path,
+        file_name,
+    })
+}
+
+async fn compress_database(
+    backup_file_path: PathBuf,
+    backup_file_name: &str,
+) -> Result<(PathBuf, String), BackupError> {
+    let backup_file = tokio::fs::File::open(&backup_file_path)
+        .await
+        .map_err(BackupError::CreateBackupFile)?;
+    let mut backup_data = BufReader::with_capacity(BUFFER_SIZE, backup_file);
+
+    let compress_file_name = format!("{backup_file_name}.gz");
+    let mut compress_file_path = backup_file_path.clone();
+    compress_file_path.set_file_name(&compress_file_name);
+    let compress_file = tokio::fs::File::create(&compress_file_path)
+        .await
+        .map_err(BackupError::CreateZipFile)?;
+    let compress_data = BufWriter::with_capacity(BUFFER_SIZE, compress_file);
+
+    let mut encoder = GzipEncoder::new(compress_data);
+    let mut data_buffer = [0; BUFFER_SIZE];
+    while let Ok(data_size) = backup_data.read(&mut data_buffer).await {
+        if data_size == 0 {
+            break;
+        }
+
+        encoder
+            .write_all(&data_buffer)
+            .await
+            .map_err(BackupError::WriteZipFile)?;
+    }
+    encoder
+        .shutdown()
+        .await
+        .map_err(BackupError::CloseZipFile)?;
+
+    remove_file(backup_file_path)
+        .await
+        .map_err(BackupError::RmBackupFile)?;
+
+    Ok((compress_file_path, compress_file_name))
+}
@@ -0,0 +1,167 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use bencher_json::{Secret, Url};
+
+use super::{DataStoreError, Storage};
+
+const ARN_AWS_S3: &str = "arn:aws:s3:";
+const COLON: char = ':';
+const ACCESSPOINT: &str = ":accesspoint/";
+
+pub struct AwsS3 {
+    client: aws_sdk_s3::Client,
+    // The access point ARN or bucket name
+    bucket: String,
+    path: Option<PathBuf>,
+}
+
+impl AwsS3 {
+    pub fn new(
+        access_key_id: String,
+        secret_access_key: Secret,
+        access_point: &str,
+        endpoint_url: Option<Url>,
+        region: Option<String>,
+    ) -> Result<Self, DataStoreError> {
+        if access_key_id.is_empty() {
+            return Err(DataStoreError::DataStore(
+                "AWS S3 access key ID is empty".to_owned(),
+            ));
+        }
+        let credentials = aws_credential_types::Credentials::new(
+            access_key_id,
+            secret_access_key,
+            None,
+            None,
+            "bencher",
+        );
+        let credentials_provider =
+            aws_credential_types::provider::SharedCredentialsProvider::new(credentials);
+
+        let (region, bucket, bucket_path) = if let Some(endpoint_url) = &endpoint_url {
+            let region = region.ok_or_else(|| {
+                DataStoreError::DataStore(format!(
+                    "AWS S3 region is required for endpoint URL: {endpoint_url}"
+                ))
+            })?;
+            let (bucket_name, bucket_path) = split_bucket_path(access_point);
+            (region, bucket_name, bucket_path)
+        } else {
+            parse_access_point(access_point)?
+        };
+
+        let mut config = aws_sdk_s3::Config::builder()
+            .credentials_provider(credentials_provider)
+            .region(aws_sdk_s3::config::Region::new(region));
+        // S3 compatible stores do not all support virtual hosted buckets
+        if let Some(endpoint_url) = endpoint_url {
+            config = config
+                .endpoint_url(endpoint_url.as_ref())
+                .force_path_style(true);
+        }
+        let client = aws_sdk_s3::Client::from_conf(config.build());
+
+        Ok(Self {
+            client,
+            bucket,
+            path: bucket_path,
+        })
+    }
+
+    fn key(&self, file_name: &str) -> String {
+        if let Some(bucket_path) = &self.path {
+            bucket_path.join(file_name).to_string_lossy().to_string()
+        } else {
+            file_name.to_owned()
+        }
+    }
+
+    async fn put_object(
+        &self,
+        file_name: &str,
+        body: aws_sdk_s3::primitives::ByteStream,
+    ) -> Result<(), DataStoreError> {
+        self.client
+            .put_object()
+            .bucket(self.bucket.clone())
+            .key(self.key(file_name))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+// arn:aws:s3:<region>:<account-id>:accesspoint/<resource>[/backup-dir-path]
+fn parse_access_point(
+    access_point: &str,
+) -> Result<(String, String, Option<PathBuf>), DataStoreError> {
+    let (region, accesspoint_arn) = access_point
+        .trim_start_matches(ARN_AWS_S3)
+        .split_once(COLON)
+        .ok_or_else(|| DataStoreError::DataStore(access_point.to_owned()))?;
+    let (account_id, resource) = accesspoint_arn
+        .split_once(ACCESSPOINT)
+        .ok_or_else(|| DataStoreError::DataStore(access_point.to_owned()))?;
+
+    let (bucket_name, bucket_path) = split_bucket_path(resource);
+    let bucket_arn = format!("{ARN_AWS_S3}{region}{COLON}{account_id}{ACCESSPOINT}{bucket_name}");
+
+    Ok((region.to_owned(), bucket_arn, bucket_path))
+}
+
+// <bucket>[/backup-dir-path]
+fn split_bucket_path(resource: &str) -> (String, Option<PathBuf>) {
+    if let Some((bucket_name, bucket_path)) = resource.split_once('/') {
+        (bucket_name.to_owned(), Some(PathBuf::from(bucket_path)))
+    } else {
+        (resource.to_owned(), None)
+    }
+}
+
+#[async_trait]
+impl Storage for AwsS3 {
+    // Stream the backup from disk instead of reading it all into memory
+    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
+        let body = aws_sdk_s3::primitives::ByteStream::from_path(source_path)
+            .await
+            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+        self.put_object(file_name, body).await
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), DataStoreError> {
+        self.put_object(key, data.into()).await
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DataStoreError> {
+        let object = self
+            .client
+            .get_object()
+            .bucket(self.bucket.clone())
+            .key(self.key(key))
+            .send()
+            .await
+            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+        let data = object
+            .body
+            .collect()
+            .await
+            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+        Ok(data.into_bytes().to_vec())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DataStoreError> {
+        self.client
+            .delete_object()
+            .bucket(self.bucket.clone())
+            .key(self.key(key))
+            .send()
+            .await
+            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use bencher_json::{Secret, Url};
+
+use super::{DataStoreError, Storage};
+
+// https://learn.microsoft.com/en-us/rest/api/storageservices/versioning-for-the-azure-storage-services
+const AZURE_STORAGE_VERSION: &str = "2023-11-03";
+
+pub struct AzureBlob {
+    client: reqwest::Client,
+    container_url: url::Url,
+    sas_token: Secret,
+}
+
+impl AzureBlob {
+    pub fn new(container_url: Url, sas_token: Secret) -> Result<Self, DataStoreError> {
+        let container_url = url::Url::try_from(container_url)
+            .map_err(|e| DataStoreError::DataStore(e.to_string()))?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            container_url,
+            sas_token,
+        })
+    }
+
+    // https://<account>.blob.core.windows.net/<container>[/backup-dir-path]/<key>?<sas-token>
+    fn blob_url(&self, key: &str) -> String {
+        format!(
+            "{container_url}/{key}?{sas_token}",
+            container_url = self.container_url.as_str().trim_end_matches('/'),
+            sas_token = self.sas_token.as_ref().trim_start_matches('?'),
+        )
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, DataStoreError> {
+        request
+            .header("x-ms-version", AZURE_STORAGE_VERSION)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            // Do not include the request URL, as it contains the SAS token
+            .map_err(|e| DataStoreError::AzureBlob(e.without_url().to_string()))
+    }
+}
+
+#[async_trait]
+impl Storage for AzureBlob {
+    // https://learn.microsoft.com/en-us/rest/api/storageservices/put-blob
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), DataStoreError> {
+        self.send(
+            self.client
+                .put(self.blob_url(key))
+                .header("x-ms-blob-type", "BlockBlob")
+                .body(data),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // https://learn.microsoft.com/en-us/rest/api/storageservices/get-blob
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DataStoreError> {
+        let response = self.send(self.client.get(self.blob_url(key))).await?;
+        let data = response
+            .bytes()
+            .await
+            .map_err(|e| DataStoreError::AzureBlob(e.without_url().to_string()))?;
+        Ok(data.to_vec())
+    }
+
+    // https://learn.microsoft.com/en-us/rest/api/storageservices/delete-blob
+    async fn delete(&self, key: &str) -> Result<(), DataStoreError> {
+        self.send(self.client.delete(self.blob_url(key))).await?;
+        Ok(())
+    }
+}
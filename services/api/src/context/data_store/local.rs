@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use super::{DataStoreError, Storage};
+
+pub struct Local {
+    directory: PathBuf,
+}
+
+impl Local {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    async fn path(&self, key: &str) -> Result<PathBuf, DataStoreError> {
+        let path = self.directory.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(DataStoreError::Local)?;
+        }
+        Ok(path)
+    }
+}
+
+#[async_trait]
+impl Storage for Local {
+    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
+        tokio::fs::copy(source_path, self.path(file_name).await?)
+            .await
+            .map_err(DataStoreError::Local)?;
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), DataStoreError> {
+        tokio::fs::write(self.path(key).await?, data)
+            .await
+            .map_err(DataStoreError::Local)
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DataStoreError> {
+        tokio::fs::read(self.directory.join(key))
+            .await
+            .map_err(DataStoreError::Local)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DataStoreError> {
+        tokio::fs::remove_file(self.directory.join(key))
+            .await
+            .map_err(DataStoreError::Local)
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use bencher_json::system::{backup::JsonDataStore, config::DataStore as DataStoreConfig};
+
+mod aws_s3;
+mod azure_blob;
+mod local;
+
+use aws_s3::AwsS3;
+use azure_blob::AzureBlob;
+use local::Local;
+
+/// An object storage backend for database backups and report attachments.
+/// Objects are addressed by a key relative to the root of the storage,
+/// such as `attachments/<uuid>`.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Store a copy of the file at `source_path` under `file_name`
+    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
+        let data = tokio::fs::read(source_path)
+            .await
+            .map_err(DataStoreError::ReadBackup)?;
+        self.put(file_name, data).await
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), DataStoreError>;
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DataStoreError>;
+
+    async fn delete(&self, key: &str) -> Result<(), DataStoreError>;
+}
+
+pub enum DataStore {
+    AwsS3(AwsS3),
+    Local(Local),
+    AzureBlob(AzureBlob),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DataStoreError {
+    #[error("Failed to configure data store: {0}")]
+    DataStore(String),
+    #[error("Failed to read database backup: {0}")]
+    ReadBackup(std::io::Error),
+    #[error("Failed to use AWS S3: {0}")]
+    AwsS3(String),
+    #[error("Failed to use local data store: {0}")]
+    Local(std::io::Error),
+    #[error("Failed to use Azure Blob Storage: {0}")]
+    AzureBlob(String),
+}
+
+impl TryFrom<DataStoreConfig> for DataStore {
+    type Error = DataStoreError;
+
+    fn try_from(data_store: DataStoreConfig) -> Result<Self, Self::Error> {
+        match data_store {
+            DataStoreConfig::AwsS3 {
+                access_key_id,
+                secret_access_key,
+                access_point,
+                endpoint_url,
+                region,
+            } => AwsS3::new(
+                access_key_id,
+                secret_access_key,
+                &access_point,
+                endpoint_url,
+                region,
+            )
+            .map(Self::AwsS3),
+            DataStoreConfig::Local { directory } => Ok(Self::Local(Local::new(directory))),
+            DataStoreConfig::AzureBlob {
+                container_url,
+                sas_token,
+            } => AzureBlob::new(container_url, sas_token).map(Self::AzureBlob),
+        }
+    }
+}
+
+impl DataStore {
+    fn storage(&self) -> &dyn Storage {
+        match self {
+            Self::AwsS3(aws_s3) => aws_s3,
+            Self::Local(local) => local,
+            Self::AzureBlob(azure_blob) => azure_blob,
+        }
+    }
+
+    /// Whether this is the data store requested for a database backup
+    pub fn is_kind(&self, json_data_store: JsonDataStore) -> bool {
+        matches!(
+            (self, json_data_store),
+            (Self::AwsS3(_), JsonDataStore::AwsS3)
+                | (Self::Local(_), JsonDataStore::Local)
+                | (Self::AzureBlob(_), JsonDataStore::AzureBlob)
+        )
+    }
+
+    pub async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
+        self.storage().backup(source_path, file_name).await
+    }
+
+    pub async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), DataStoreError> {
+        self.storage().put(key, data).await
+    }
+
+    pub async fn get(&self, key: &str) -> Result<Vec<u8>, DataStoreError> {
+        self.storage().get(key).await
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<(), DataStoreError> {
+        self.storage().delete(key).await
+    }
+}
@@ -0,0 +1,196 @@
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bencher_json::{system::config::JsonEncryption, Secret};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use http::StatusCode;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    context::DbConnection,
+    error::issue_error,
+    model::project::{integration::IntegrationId, webhook::WebhookId},
+    schema,
+};
+
+// Encrypted values are prefixed, so plaintext values from before encryption was enabled can still be read
+const PREFIX: &str = "enc:v1:";
+// AES-GCM uses a 96-bit nonce
+const NONCE_LEN: usize = 12;
+
+/// Encrypts sensitive columns at rest using the server encryption keys.
+/// New values are always encrypted with the current key,
+/// while previous keys are only used to decrypt existing values until they have been rotated.
+/// If no keys are configured then values are stored as plaintext.
+#[derive(Default)]
+pub struct Encryption {
+    keys: Vec<EncryptionKey>,
+}
+
+struct EncryptionKey {
+    id: String,
+    cipher: Aes256Gcm,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    #[error("Failed to encrypt value")]
+    Encrypt,
+    #[error("Failed to decrypt value")]
+    Decrypt,
+    #[error("Value is encrypted but no encryption key is configured")]
+    NoKey,
+    #[error("Value is encrypted with an unknown key ({0})")]
+    UnknownKey(String),
+    #[error("Malformed encrypted value")]
+    Malformed,
+    #[error("Failed to rotate encrypted values: {0}")]
+    Rotate(diesel::result::Error),
+}
+
+impl From<EncryptionError> for HttpError {
+    fn from(error: EncryptionError) -> Self {
+        issue_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to handle encrypted value",
+            "Failed to encrypt or decrypt a value stored at rest.",
+            error,
+        )
+    }
+}
+
+impl From<JsonEncryption> for Encryption {
+    fn from(encryption: JsonEncryption) -> Self {
+        let JsonEncryption { key, previous_keys } = encryption;
+        let keys = std::iter::once(key)
+            .chain(previous_keys.into_iter().flatten())
+            .map(|key| EncryptionKey::new(&key))
+            .collect();
+        Self { keys }
+    }
+}
+
+impl EncryptionKey {
+    fn new(secret: &Secret) -> Self {
+        // Derive a 256-bit key, so any secret string can be used as a key
+        let key = Sha256::digest(secret.as_ref().as_bytes());
+        // The key ID is a hash of the key, so it is safe to store alongside the value
+        let id = hex::encode(Sha256::digest(key).get(..4).unwrap_or_default());
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        Self { id, cipher }
+    }
+}
+
+impl Encryption {
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Encrypt the value with the current key,
+    /// or return the value as is if encryption is not enabled
+    pub fn encrypt(&self, value: &str) -> Result<String, EncryptionError> {
+        let Some(key) = self.keys.first() else {
+            return Ok(value.to_owned());
+        };
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = key
+            .cipher
+            .encrypt(&nonce, value.as_bytes())
+            .map_err(|_e| EncryptionError::Encrypt)?;
+        let mut sealed = nonce.to_vec();
+        sealed.extend(ciphertext);
+        Ok(format!("{PREFIX}{}:{}", key.id, STANDARD.encode(sealed)))
+    }
+
+    /// Decrypt the value with the key that was used to encrypt it.
+    /// Plaintext values are returned as is.
+    pub fn decrypt(&self, value: &str) -> Result<String, EncryptionError> {
+        let Some(sealed) = value.strip_prefix(PREFIX) else {
+            return Ok(value.to_owned());
+        };
+        if self.keys.is_empty() {
+            return Err(EncryptionError::NoKey);
+        }
+        let (id, sealed) = sealed.split_once(':').ok_or(EncryptionError::Malformed)?;
+        let key = self
+            .keys
+            .iter()
+            .find(|key| key.id == id)
+            .ok_or_else(|| EncryptionError::UnknownKey(id.to_owned()))?;
+        let sealed = STANDARD
+            .decode(sealed)
+            .map_err(|_e| EncryptionError::Malformed)?;
+        if sealed.len() < NONCE_LEN {
+            return Err(EncryptionError::Malformed);
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = key
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_e| EncryptionError::Decrypt)?;
+        String::from_utf8(plaintext).map_err(|_e| EncryptionError::Malformed)
+    }
+
+    /// A value needs to be rotated if it is not encrypted with the current key
+    pub fn needs_rotation(&self, value: &str) -> bool {
+        let Some(key) = self.keys.first() else {
+            return false;
+        };
+        value
+            .strip_prefix(PREFIX)
+            .and_then(|sealed| sealed.split_once(':'))
+            .map_or(true, |(id, _)| id != key.id)
+    }
+
+    /// Re-encrypt the value with the current key
+    pub fn rotate(&self, value: &str) -> Result<String, EncryptionError> {
+        self.decrypt(value)
+            .and_then(|plaintext| self.encrypt(&plaintext))
+    }
+
+    /// Re-encrypt all of the sensitive columns that are not encrypted with the current key.
+    /// This encrypts any plaintext values from before encryption was enabled
+    /// and rotates any values encrypted with a previous key.
+    /// Returns the number of values that were rotated.
+    pub fn rotate_all(&self, conn: &mut DbConnection) -> Result<usize, EncryptionError> {
+        if !self.is_enabled() {
+            return Ok(0);
+        }
+        let mut rotated = 0;
+
+        let webhooks = schema::webhook::table
+            .select((schema::webhook::id, schema::webhook::secret))
+            .load::<(WebhookId, String)>(conn)
+            .map_err(EncryptionError::Rotate)?;
+        for (id, secret) in webhooks {
+            if !self.needs_rotation(&secret) {
+                continue;
+            }
+            diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(id)))
+                .set(schema::webhook::secret.eq(self.rotate(&secret)?))
+                .execute(conn)
+                .map_err(EncryptionError::Rotate)?;
+            rotated += 1;
+        }
+
+        let integrations = schema::integration::table
+            .select((schema::integration::id, schema::integration::token))
+            .load::<(IntegrationId, String)>(conn)
+            .map_err(EncryptionError::Rotate)?;
+        for (id, token) in integrations {
+            if !self.needs_rotation(&token) {
+                continue;
+            }
+            diesel::update(schema::integration::table.filter(schema::integration::id.eq(id)))
+                .set(schema::integration::token.eq(self.rotate(&token)?))
+                .execute(conn)
+                .map_err(EncryptionError::Rotate)?;
+            rotated += 1;
+        }
+
+        Ok(rotated)
+    }
+}
@@ -0,0 +1,343 @@
+#![cfg(feature = "plus")]
+
+use std::{sync::Arc, time::Duration};
+
+use bencher_json::{
+    project::{
+        alert::{AlertSeverity, AlertStatus},
+        boundary::BoundaryLimit,
+        integration::IntegrationProvider,
+    },
+    AlertUuid, JsonAlert, JsonReport,
+};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use slog::Logger;
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::{
+    context::DbConnection,
+    model::project::{
+        integration::{InsertAlertIssue, QueryAlertIssue, QueryIntegration},
+        threshold::alert::QueryAlert,
+        QueryProject,
+    },
+    notify::{public_client, PublicUrlError},
+};
+
+// GitHub requires a user agent for all API requests
+const USER_AGENT: &str = "bencher";
+const ISSUE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Opens and closes issues for critical alerts using a project's issue tracker integrations
+#[derive(Debug, Clone)]
+pub struct IssueTracker;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssueError {
+    #[error("Invalid issue tracker URL: {0}")]
+    Url(url::ParseError),
+    // The integration token is sent with every request,
+    // so the issue tracker must be a public host.
+    #[error("Invalid issue tracker URL: {0}")]
+    Public(PublicUrlError),
+    #[error("Failed to serialize issue: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to send issue request: {0}")]
+    Send(reqwest::Error),
+    #[error("Issue tracker responded with an error ({status}): {body}")]
+    Response {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("Failed to parse issue tracker response: {0}")]
+    Parse(serde_json::Error),
+}
+
+/// A new issue for an alert, rendered from an integration's templates
+pub struct NewIssue {
+    alert: AlertUuid,
+    integration: QueryIntegration,
+    title: String,
+    body: String,
+}
+
+struct OpenedIssue {
+    number: i64,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubIssue {
+    number: i64,
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct GitLabIssue {
+    iid: i64,
+    web_url: String,
+}
+
+impl IssueTracker {
+    /// Render a new issue for each active critical alert in the report for each integration
+    pub fn new_issues(
+        console_url: &Url,
+        query_project: &QueryProject,
+        integrations: &[QueryIntegration],
+        json_report: &JsonReport,
+    ) -> Vec<NewIssue> {
+        let mut new_issues = Vec::new();
+        for alert in json_report.alerts.iter().filter(|alert| {
+            matches!(alert.severity, AlertSeverity::Critical)
+                && matches!(alert.status, AlertStatus::Active)
+        }) {
+            let values = template_values(console_url, query_project, alert);
+            for integration in integrations {
+                new_issues.push(NewIssue {
+                    alert: alert.uuid,
+                    integration: integration.clone(),
+                    title: render(integration.title.as_ref(), &values),
+                    body: render(integration.body.as_ref(), &values),
+                });
+            }
+        }
+        new_issues
+    }
+
+    /// Open the new issues in the background, recording each issue that was opened
+    pub fn spawn_open(&self, log: Logger, conn: Arc<Mutex<DbConnection>>, issues: Vec<NewIssue>) {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            for issue in issues {
+                let opened = match tracker.open(&issue).await {
+                    Ok(opened) => opened,
+                    Err(e) => {
+                        slog::error!(
+                            log,
+                            "Failed to open issue for alert ({}) in {}: {e}",
+                            issue.alert,
+                            issue.integration.repository
+                        );
+                        #[cfg(feature = "sentry")]
+                        sentry::capture_error(&e);
+                        continue;
+                    },
+                };
+                slog::info!(
+                    log,
+                    "Opened issue {} for alert ({})",
+                    opened.url,
+                    issue.alert
+                );
+                let conn = &mut *conn.lock().await;
+                if let Err(e) = QueryAlert::get_id(conn, issue.alert).and_then(|alert_id| {
+                    InsertAlertIssue::insert(
+                        conn,
+                        alert_id,
+                        issue.integration.id,
+                        opened.number,
+                        opened.url,
+                    )
+                }) {
+                    slog::error!(
+                        log,
+                        "Failed to record issue for alert ({}): {e}",
+                        issue.alert
+                    );
+                }
+            }
+        });
+    }
+
+    /// Close the open issues in the background, recording each issue that was closed
+    pub fn spawn_close(
+        &self,
+        log: Logger,
+        conn: Arc<Mutex<DbConnection>>,
+        issues: Vec<(QueryAlertIssue, QueryIntegration)>,
+    ) {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            for (alert_issue, integration) in issues {
+                if let Err(e) = tracker.close(&integration, alert_issue.number).await {
+                    slog::error!(log, "Failed to close issue {}: {e}", alert_issue.url);
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                    continue;
+                }
+                slog::info!(log, "Closed issue {}", alert_issue.url);
+                if let Err(e) = alert_issue.close(&mut *conn.lock().await) {
+                    slog::error!(
+                        log,
+                        "Failed to record closed issue {}: {e}",
+                        alert_issue.url
+                    );
+                }
+            }
+        });
+    }
+
+    async fn open(&self, issue: &NewIssue) -> Result<OpenedIssue, IssueError> {
+        let integration = &issue.integration;
+        let url = issues_url(integration);
+        match integration.provider {
+            IntegrationProvider::GitHub => {
+                let body = serde_json::json!({
+                    "title": issue.title,
+                    "body": issue.body,
+                });
+                let response = self
+                    .send(integration, reqwest::Method::POST, &url, &body)
+                    .await?;
+                let issue: GitHubIssue =
+                    serde_json::from_str(&response).map_err(IssueError::Parse)?;
+                Ok(OpenedIssue {
+                    number: issue.number,
+                    url: issue.html_url,
+                })
+            },
+            IntegrationProvider::GitLab => {
+                let body = serde_json::json!({
+                    "title": issue.title,
+                    "description": issue.body,
+                });
+                let response = self
+                    .send(integration, reqwest::Method::POST, &url, &body)
+                    .await?;
+                let issue: GitLabIssue =
+                    serde_json::from_str(&response).map_err(IssueError::Parse)?;
+                Ok(OpenedIssue {
+                    number: issue.iid,
+                    url: issue.web_url,
+                })
+            },
+        }
+    }
+
+    async fn close(&self, integration: &QueryIntegration, number: i64) -> Result<(), IssueError> {
+        let url = format!("{}/{number}", issues_url(integration));
+        match integration.provider {
+            IntegrationProvider::GitHub => {
+                let body = serde_json::json!({
+                    "state": "closed",
+                    "state_reason": "completed",
+                });
+                self.send(integration, reqwest::Method::PATCH, &url, &body)
+                    .await?;
+            },
+            IntegrationProvider::GitLab => {
+                let body = serde_json::json!({ "state_event": "close" });
+                self.send(integration, reqwest::Method::PUT, &url, &body)
+                    .await?;
+            },
+        }
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        integration: &QueryIntegration,
+        method: reqwest::Method,
+        url: &str,
+        body: &serde_json::Value,
+    ) -> Result<String, IssueError> {
+        let url = Url::parse(url).map_err(IssueError::Url)?;
+        let request = public_client(&url, ISSUE_TIMEOUT)
+            .await
+            .map_err(IssueError::Public)?
+            .request(method, url);
+        let request = match integration.provider {
+            IntegrationProvider::GitHub => request
+                .bearer_auth(&integration.token)
+                .header(reqwest::header::ACCEPT, "application/vnd.github+json"),
+            IntegrationProvider::GitLab => request.header("PRIVATE-TOKEN", &integration.token),
+        };
+        let response = request
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(body).map_err(IssueError::Serialize)?)
+            .send()
+            .await
+            .map_err(IssueError::Send)?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(IssueError::Response { status, body })
+        }
+    }
+}
+
+fn issues_url(integration: &QueryIntegration) -> String {
+    let api_url = integration.api_url.as_ref().trim_end_matches('/');
+    let repository = integration.repository.as_ref();
+    match integration.provider {
+        IntegrationProvider::GitHub => format!("{api_url}/repos/{repository}/issues"),
+        // GitLab identifies projects by their URL-encoded full path
+        IntegrationProvider::GitLab => format!(
+            "{api_url}/projects/{}/issues",
+            repository.replace('/', "%2F")
+        ),
+    }
+}
+
+fn template_values(
+    console_url: &Url,
+    query_project: &QueryProject,
+    alert: &JsonAlert,
+) -> Vec<(&'static str, String)> {
+    let value = alert.metric.value.into_inner();
+    let baseline = alert.boundary.baseline.map(OrderedFloat::into_inner);
+    let delta = baseline
+        .filter(|baseline| *baseline != 0.0)
+        .map(|baseline| format!("{:+.2}%", (value - baseline) / baseline * 100.0));
+    let (limit, boundary) = match alert.limit {
+        BoundaryLimit::Lower => ("Lower", alert.boundary.lower_limit),
+        BoundaryLimit::Upper => ("Upper", alert.boundary.upper_limit),
+    };
+    let console_path = if query_project.is_public() {
+        format!("/perf/{}", query_project.slug)
+    } else {
+        format!("/console/projects/{}", query_project.slug)
+    };
+    let console_link = |resource: &str, uuid: String| {
+        console_url
+            .join(&format!("{console_path}/{resource}/{uuid}"))
+            .map_or_else(|_| console_url.to_string(), Into::into)
+    };
+    vec![
+        ("project", query_project.name.to_string()),
+        ("benchmark", alert.benchmark.name.to_string()),
+        ("measure", alert.threshold.measure.name.to_string()),
+        ("branch", alert.threshold.branch.name.to_string()),
+        ("testbed", alert.threshold.testbed.name.to_string()),
+        ("value", value.to_string()),
+        (
+            "baseline",
+            baseline.map_or_else(|| "-".into(), |b| b.to_string()),
+        ),
+        ("delta", delta.unwrap_or_else(|| "-".into())),
+        ("limit", limit.into()),
+        (
+            "boundary",
+            boundary.map_or_else(|| "-".into(), |b| b.to_string()),
+        ),
+        ("alert_url", console_link("alerts", alert.uuid.to_string())),
+        (
+            "report_url",
+            console_link("reports", alert.report.to_string()),
+        ),
+    ]
+}
+
+/// Replace each `{placeholder}` in the template with its value
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(template.to_owned(), |rendered, (placeholder, value)| {
+            rendered.replace(&format!("{{{placeholder}}}"), value)
+        })
+}
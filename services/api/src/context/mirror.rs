@@ -0,0 +1,327 @@
+#![cfg(feature = "plus")]
+
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use bencher_json::{
+    project::{
+        metric::JsonResultsMap,
+        report::{Adapter, JsonReportSettings},
+    },
+    system::config::{JsonMirror, JsonMirrorProject},
+    DateTime, JsonNewMetric, JsonNewReport, JsonReport, NameId, ReportVersion, ResourceId, Secret,
+    BENCHER_API_URL,
+};
+use diesel::RunQueryDsl;
+use dropshot::HttpError;
+use slog::Logger;
+use tokio::sync::{Mutex, Notify};
+use url::Url;
+
+use crate::{
+    config::plus::PlusError,
+    context::DbConnection,
+    error::{issue_error, resource_conflict_err},
+    model::project::{
+        report::{
+            report_mirror::{InsertReportMirror, QueryReportMirror},
+            ReportId,
+        },
+        QueryProject,
+    },
+    schema,
+};
+
+// How often to check the queue for reports that are due to be retried
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+// The maximum number of queued reports to send at once
+const BATCH_SIZE: i64 = 16;
+const DEFAULT_MAX_ATTEMPTS: u32 = 10;
+// The first retry is after one minute, doubling up to a maximum of one day
+const RETRY_BASE_SECS: i64 = 60;
+const RETRY_MAX_SECS: i64 = 24 * 60 * 60;
+
+/// Forwards reports for selected projects to another Bencher instance
+#[derive(Debug, Clone)]
+pub struct Mirror {
+    host: Url,
+    token: Secret,
+    anonymize: bool,
+    max_attempts: u32,
+    projects: Vec<JsonMirrorProject>,
+    client: reqwest::Client,
+    notify: Arc<Notify>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MirrorError {
+    #[error("Failed to parse {kind} name ({name}): {err}")]
+    Name {
+        kind: &'static str,
+        name: String,
+        err: bencher_json::ValidError,
+    },
+    #[error("Failed to serialize mirrored report: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to send mirrored report: {0}")]
+    Send(reqwest::Error),
+    #[error("Mirror responded with an error ({status}): {body}")]
+    Response {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+}
+
+impl TryFrom<JsonMirror> for Mirror {
+    type Error = PlusError;
+
+    fn try_from(mirror: JsonMirror) -> Result<Self, Self::Error> {
+        let JsonMirror {
+            host,
+            token,
+            anonymize,
+            max_attempts,
+            projects,
+        } = mirror;
+        let host = host
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(PlusError::MirrorHost)?
+            .unwrap_or_else(|| BENCHER_API_URL.clone());
+        Ok(Self {
+            host,
+            token,
+            anonymize: anonymize.unwrap_or_default(),
+            max_attempts: max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS),
+            projects,
+            client: reqwest::Client::new(),
+            notify: Arc::new(Notify::new()),
+        })
+    }
+}
+
+impl Mirror {
+    /// Queue an accepted report to be mirrored, if its project is mirrored
+    pub fn enqueue(
+        &self,
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        report_id: ReportId,
+        json_report: &JsonReport,
+    ) -> Result<(), HttpError> {
+        let Some(mirror_project) = self.mirror_project(query_project) else {
+            return Ok(());
+        };
+        let report = mirror_report(json_report, self.anonymize)
+            .and_then(|json_new_report| {
+                serde_json::to_string(&json_new_report).map_err(MirrorError::Serialize)
+            })
+            .map_err(|e| {
+                issue_error(
+                    http::StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create mirrored report",
+                    &format!("Failed to create mirrored report for report ({report_id})"),
+                    e,
+                )
+            })?;
+        let insert_report_mirror =
+            InsertReportMirror::new(report_id, mirror_project.to_string(), report);
+        diesel::insert_into(schema::report_mirror::table)
+            .values(&insert_report_mirror)
+            .execute(conn)
+            .map_err(resource_conflict_err!(ReportMirror, report_id))?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    fn mirror_project(&self, query_project: &QueryProject) -> Option<&ResourceId> {
+        let uuid = query_project.uuid.to_string();
+        self.projects
+            .iter()
+            .find(|mirror_project| {
+                let project = mirror_project.project.as_ref();
+                project == uuid || project == query_project.slug.as_ref()
+            })
+            .map(|mirror_project| &mirror_project.mirror)
+    }
+
+    /// Send queued reports to the mirror in the background,
+    /// retrying with exponential backoff on failure.
+    pub fn spawn(self, log: Logger, conn: Arc<Mutex<DbConnection>>) {
+        tokio::spawn(async move {
+            #[allow(clippy::infinite_loop)]
+            loop {
+                // Wake up as soon as a report is queued or check for retries periodically
+                let _notified = tokio::time::timeout(POLL_INTERVAL, self.notify.notified()).await;
+                self.send_due(&log, &conn).await;
+            }
+        });
+    }
+
+    async fn send_due(&self, log: &Logger, conn: &Mutex<DbConnection>) {
+        let due = QueryReportMirror::due(&mut *conn.lock().await, DateTime::now(), BATCH_SIZE);
+        let due = match due {
+            Ok(due) => due,
+            Err(e) => {
+                slog::error!(log, "Failed to get queued mirror reports: {e}");
+                return;
+            },
+        };
+        for queued in due {
+            let sent = self.send(&queued.project, queued.report.clone()).await;
+            let conn = &mut *conn.lock().await;
+            let result = match sent {
+                Ok(()) => {
+                    slog::info!(
+                        log,
+                        "Mirrored report ({}) to {}",
+                        queued.report_id,
+                        self.host
+                    );
+                    queued.delete(conn)
+                },
+                Err(e) => {
+                    let attempts = queued.attempts.saturating_add(1);
+                    if u32::try_from(attempts).unwrap_or(u32::MAX) >= self.max_attempts {
+                        slog::error!(
+                            log,
+                            "Dropping mirrored report ({}) after {attempts} attempts: {e}",
+                            queued.report_id
+                        );
+                        #[cfg(feature = "sentry")]
+                        sentry::capture_error(&e);
+                        queued.delete(conn)
+                    } else {
+                        slog::warn!(
+                            log,
+                            "Failed to mirror report ({}) on attempt #{attempts}: {e}",
+                            queued.report_id
+                        );
+                        queued.retry(conn, attempts, next_attempt(attempts))
+                    }
+                },
+            };
+            if let Err(e) = result {
+                slog::error!(log, "Failed to update queued mirror report: {e}");
+            }
+        }
+    }
+
+    async fn send(&self, project: &str, report: String) -> Result<(), MirrorError> {
+        let mut url = self.host.clone();
+        url.set_path(&format!("/v0/projects/{project}/reports"));
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(self.token.as_ref())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(report)
+            .send()
+            .await
+            .map_err(MirrorError::Send)?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(MirrorError::Response { status, body })
+        }
+    }
+}
+
+fn next_attempt(attempts: i32) -> DateTime {
+    let delay = u32::try_from(attempts.saturating_sub(1))
+        .ok()
+        .and_then(|attempts| RETRY_BASE_SECS.checked_shl(attempts))
+        .map_or(RETRY_MAX_SECS, |delay| delay.min(RETRY_MAX_SECS));
+    (DateTime::now().into_inner() + chrono::Duration::seconds(delay)).into()
+}
+
+/// Convert an accepted report into a new report for the mirror.
+/// The results are sent using the Bencher Metric Format (BMF),
+/// so the mirror does not need to parse the original benchmark harness output.
+fn mirror_report(json_report: &JsonReport, anonymize: bool) -> Result<JsonNewReport, MirrorError> {
+    let (branch, testbed) = if anonymize {
+        (
+            anonymous_name("branch", &json_report.branch.uuid.to_string()),
+            anonymous_name("testbed", &json_report.testbed.uuid.to_string()),
+        )
+    } else {
+        (
+            json_report.branch.name.to_string(),
+            json_report.testbed.name.to_string(),
+        )
+    };
+    let hash = if anonymize {
+        None
+    } else {
+        json_report
+            .branch
+            .head
+            .version
+            .as_ref()
+            .and_then(|version| version.hash.clone())
+    };
+
+    let mut results = Vec::with_capacity(json_report.results.len());
+    for iteration in &json_report.results {
+        let mut results_map = JsonResultsMap::new();
+        for result in iteration {
+            let metrics = results_map
+                .entry(result.benchmark.name.clone())
+                .or_default();
+            for report_measure in &result.measures {
+                let measure = name_id("measure", report_measure.measure.name.as_ref())?;
+                let metric = &report_measure.metric;
+                metrics.insert(
+                    measure,
+                    JsonNewMetric {
+                        value: metric.value,
+                        lower_value: metric.lower_value,
+                        upper_value: metric.upper_value,
+                    },
+                );
+            }
+        }
+        results.push(serde_json::to_string(&results_map).map_err(MirrorError::Serialize)?);
+    }
+
+    Ok(JsonNewReport {
+        version: Some(ReportVersion::CURRENT),
+        branch: name_id("branch", &branch)?,
+        hash,
+        start_point: None,
+        testbed: name_id("testbed", &testbed)?,
+        environment: if anonymize {
+            None
+        } else {
+            json_report.environment.clone()
+        },
+        thresholds: None,
+        start_time: json_report.start_time,
+        end_time: json_report.end_time,
+        results,
+        runner_results: None,
+        settings: Some(JsonReportSettings {
+            adapter: Some(Adapter::Json),
+            ..Default::default()
+        }),
+        failure: if anonymize {
+            None
+        } else {
+            json_report.failure.clone()
+        },
+    })
+}
+
+// An opaque but stable name, ie `testbed-1a2b3c4d`
+fn anonymous_name(kind: &str, uuid: &str) -> String {
+    format!("{kind}-{}", uuid.get(..8).unwrap_or(uuid))
+}
+
+fn name_id(kind: &'static str, name: &str) -> Result<NameId, MirrorError> {
+    NameId::from_str(name).map_err(|err| MirrorError::Name {
+        kind,
+        name: name.to_owned(),
+        err,
+    })
+}
@@ -3,6 +3,8 @@ use bencher_billing::Biller;
 #[cfg(feature = "plus")]
 use bencher_github::GitHub;
 #[cfg(feature = "plus")]
+use bencher_json::JsonReport;
+#[cfg(feature = "plus")]
 use bencher_license::Licensor;
 use bencher_token::TokenKey;
 use tokio::sync::mpsc::Sender;
@@ -11,28 +13,60 @@ use url::Url;
 #[cfg(feature = "plus")]
 use crate::config::plus::StatsSettings;
 #[cfg(feature = "plus")]
-use crate::model::project::QueryProject;
+use crate::model::project::{
+    integration::{QueryAlertIssue, QueryIntegration},
+    report::ReportId,
+    threshold::alert::AlertId,
+    QueryProject,
+};
 
+mod anonymous;
+mod backup;
+mod data_store;
 mod database;
+mod encryption;
 mod indexer;
+mod issue;
 mod messenger;
+mod mirror;
+mod otel;
 mod rbac;
+mod retention;
 
-pub use database::{DataStoreError, Database, DbConnection};
+pub use anonymous::Anonymous;
+pub use backup::{run_backup, BackupSchedule};
+pub use data_store::{DataStore, DataStoreError, Storage};
+pub use database::{Database, DbConnection};
+pub use encryption::{Encryption, EncryptionError};
 #[cfg(feature = "plus")]
 pub use indexer::Indexer;
 #[cfg(feature = "plus")]
+pub use issue::IssueTracker;
+#[cfg(feature = "plus")]
 pub use messenger::ServerStatsBody;
 pub use messenger::{Body, ButtonBody, Email, Message, Messenger, NewUserBody};
+#[cfg(feature = "plus")]
+pub use mirror::Mirror;
+pub use otel::in_span;
+#[cfg(feature = "otel")]
+pub use otel::{Otel, OtelError};
 pub use rbac::{Rbac, RbacError};
+pub use retention::Retention;
 
 pub struct ApiContext {
     pub console_url: Url,
     pub token_key: TokenKey,
+    pub encryption: Encryption,
     pub rbac: Rbac,
     pub messenger: Messenger,
     pub database: Database,
     pub restart_tx: Sender<()>,
+    pub tls: bool,
+    pub large_body_max_bytes: usize,
+    pub retention: Option<Retention>,
+    pub anonymous: Option<Anonymous>,
+    #[cfg(feature = "otel")]
+    pub otel: Option<Otel>,
     #[cfg(feature = "plus")]
     pub github: Option<GitHub>,
     #[cfg(feature = "plus")]
@@ -40,6 +74,10 @@ pub struct ApiContext {
     #[cfg(feature = "plus")]
     pub stats: StatsSettings,
     #[cfg(feature = "plus")]
+    pub mirror: Option<Mirror>,
+    #[cfg(feature = "plus")]
+    pub issue_tracker: IssueTracker,
+    #[cfg(feature = "plus")]
     pub biller: Option<Biller>,
     #[cfg(feature = "plus")]
     pub licensor: Licensor,
@@ -95,6 +133,125 @@ impl ApiContext {
         }
     }
 
+    #[cfg(feature = "plus")]
+    pub async fn mirror_report(
+        &self,
+        log: &slog::Logger,
+        query_project: &QueryProject,
+        report_id: ReportId,
+        json_report: &JsonReport,
+    ) {
+        let Some(mirror) = &self.mirror else {
+            return;
+        };
+
+        if let Err(e) = mirror.enqueue(
+            &mut *self.conn().await,
+            query_project,
+            report_id,
+            json_report,
+        ) {
+            slog::error!(log, "{e}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&e);
+        }
+    }
+
+    #[cfg(feature = "plus")]
+    pub async fn open_alert_issues(
+        &self,
+        log: &slog::Logger,
+        query_project: &QueryProject,
+        json_report: &JsonReport,
+    ) {
+        if json_report.alerts.is_empty() {
+            return;
+        }
+
+        let integrations =
+            match QueryIntegration::all_for_project(&mut *self.conn().await, query_project)
+                .and_then(|integrations| {
+                    integrations
+                        .into_iter()
+                        .map(|integration| integration.decrypt(&self.encryption))
+                        .collect::<Result<Vec<_>, _>>()
+                }) {
+                Ok(integrations) => integrations,
+                Err(e) => {
+                    slog::error!(log, "{e}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                    return;
+                },
+            };
+        let new_issues =
+            IssueTracker::new_issues(&self.console_url, query_project, &integrations, json_report);
+        if new_issues.is_empty() {
+            return;
+        }
+
+        self.issue_tracker
+            .spawn_open(log.clone(), self.database.connection.clone(), new_issues);
+    }
+
+    #[cfg(feature = "plus")]
+    pub async fn close_alert_issues(&self, log: &slog::Logger, alert_ids: &[AlertId]) {
+        if alert_ids.is_empty() {
+            return;
+        }
+
+        let open_issues = QueryAlertIssue::open_for_alerts(&mut *self.conn().await, alert_ids);
+        self.close_issues(log, open_issues);
+    }
+
+    /// Close the issues for any alerts that have been resolved by the report
+    #[cfg(feature = "plus")]
+    pub async fn close_resolved_alert_issues(
+        &self,
+        log: &slog::Logger,
+        query_project: &QueryProject,
+        report_id: ReportId,
+    ) {
+        let open_issues = QueryAlertIssue::resolved_by_report(
+            &mut *self.conn().await,
+            query_project.id,
+            report_id,
+        );
+        self.close_issues(log, open_issues);
+    }
+
+    #[cfg(feature = "plus")]
+    fn close_issues(
+        &self,
+        log: &slog::Logger,
+        open_issues: Result<Vec<(QueryAlertIssue, QueryIntegration)>, dropshot::HttpError>,
+    ) {
+        let open_issues = match open_issues.and_then(|open_issues| {
+            open_issues
+                .into_iter()
+                .map(|(alert_issue, integration)| {
+                    integration
+                        .decrypt(&self.encryption)
+                        .map(|integration| (alert_issue, integration))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        }) {
+            Ok(open_issues) => open_issues,
+            Err(e) => {
+                slog::error!(log, "{e}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+                return;
+            },
+        };
+        if open_issues.is_empty() {
+            return;
+        }
+
+        self.issue_tracker
+            .spawn_close(log.clone(), self.database.connection.clone(), open_issues);
+    }
+
     #[cfg(feature = "plus")]
     pub async fn delete_index(&self, log: &slog::Logger, query_project: &QueryProject) {
         let Some(indexer) = &self.indexer else {
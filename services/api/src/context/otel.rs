@@ -0,0 +1,129 @@
+use std::future::Future;
+
+#[cfg(feature = "otel")]
+use bencher_json::{system::config::JsonOtel, ResourceId};
+#[cfg(feature = "otel")]
+use opentelemetry::{
+    global,
+    trace::{FutureExt, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+#[cfg(feature = "otel")]
+use opentelemetry_otlp::{WithExportConfig, WithHttpConfig};
+#[cfg(feature = "otel")]
+use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+
+#[cfg(feature = "otel")]
+use crate::model::project::QueryProject;
+
+#[cfg(feature = "otel")]
+const TRACER_NAME: &str = "bencher";
+#[cfg(feature = "otel")]
+const DEFAULT_SERVICE_NAME: &str = "bencher-api";
+
+/// Exports OpenTelemetry traces of report ingestion for selected projects
+#[cfg(feature = "otel")]
+#[derive(Debug, Clone)]
+pub struct Otel {
+    projects: Option<Vec<ResourceId>>,
+}
+
+#[cfg(feature = "otel")]
+#[derive(Debug, thiserror::Error)]
+pub enum OtelError {
+    #[error("Failed to create OTLP span exporter: {0}")]
+    Exporter(opentelemetry::trace::TraceError),
+}
+
+#[cfg(feature = "otel")]
+impl Otel {
+    /// Install the OTLP exporter as the global tracer provider
+    pub fn new(otel: JsonOtel) -> Result<Self, OtelError> {
+        let JsonOtel {
+            endpoint,
+            headers,
+            service_name,
+            projects,
+        } = otel;
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint.to_string())
+            .with_headers(
+                headers
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into()))
+                    .collect(),
+            )
+            .build()
+            .map_err(OtelError::Exporter)?;
+        let service_name = service_name.map_or_else(|| DEFAULT_SERVICE_NAME.to_owned(), Into::into);
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .with_resource(Resource::new([KeyValue::new("service.name", service_name)]))
+            .build();
+        global::set_tracer_provider(provider);
+        Ok(Self { projects })
+    }
+
+    fn is_traced(&self, query_project: &QueryProject) -> bool {
+        let Some(projects) = &self.projects else {
+            return true;
+        };
+        let uuid = query_project.uuid.to_string();
+        projects.iter().any(|project| {
+            let project = project.as_ref();
+            project == uuid || project == query_project.slug.as_ref()
+        })
+    }
+
+    /// Start a span for the ingestion of a report, if the project is traced
+    pub fn report_span(&self, query_project: &QueryProject) -> Option<ReportSpan> {
+        if !self.is_traced(query_project) {
+            return None;
+        }
+        let tracer = global::tracer(TRACER_NAME);
+        let span = tracer
+            .span_builder("report.create")
+            .with_attributes([
+                KeyValue::new("bencher.project.uuid", query_project.uuid.to_string()),
+                KeyValue::new("bencher.project.slug", query_project.slug.to_string()),
+            ])
+            .start(&tracer);
+        Some(ReportSpan(Context::current_with_span(span)))
+    }
+}
+
+/// The root span for the ingestion of a report
+#[cfg(feature = "otel")]
+pub struct ReportSpan(Context);
+
+#[cfg(feature = "otel")]
+impl ReportSpan {
+    /// Run the future within the report span.
+    /// All of the spans started with `in_span` while processing the report are children of this span.
+    pub async fn run<F>(self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        future.with_context(self.0).await
+    }
+}
+
+/// Run the future in a child span of the current report ingestion span.
+/// If the report is not being traced, then the future is run as is.
+#[cfg_attr(not(feature = "otel"), allow(unused_variables))]
+pub async fn in_span<F>(name: &'static str, future: F) -> F::Output
+where
+    F: Future,
+{
+    #[cfg(feature = "otel")]
+    {
+        let cx = Context::current();
+        if cx.has_active_span() {
+            let span = global::tracer(TRACER_NAME).start_with_context(name, &cx);
+            return future.with_context(cx.with_span(span)).await;
+        }
+    }
+    future.await
+}
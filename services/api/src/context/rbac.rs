@@ -75,17 +75,18 @@ impl Rbac {
         O: Into<Organization>,
     {
         let organization = organization.into();
-        self.is_allowed_unwrap(auth_user, permission, organization.clone())
-            .then_some(())
-            .ok_or_else(|| {
-                let mut auth_user = auth_user.clone();
-                auth_user.sanitize();
-                RbacError::IsAllowedOrganization {
-                    auth_user,
-                    permission,
-                    organization,
-                }
-            })
+        (auth_user.scope.allows_organization(permission)
+            && self.is_allowed_unwrap(auth_user, permission, organization.clone()))
+        .then_some(())
+        .ok_or_else(|| {
+            let mut auth_user = auth_user.clone();
+            auth_user.sanitize();
+            RbacError::IsAllowedOrganization {
+                auth_user,
+                permission,
+                organization,
+            }
+        })
     }
 
     pub fn is_allowed_project<P>(
@@ -98,16 +99,17 @@ impl Rbac {
         P: Into<Project>,
     {
         let project = project.into();
-        self.is_allowed_unwrap(auth_user, permission, project.clone())
-            .then_some(())
-            .ok_or_else(|| {
-                let mut auth_user = auth_user.clone();
-                auth_user.sanitize();
-                RbacError::IsAllowedProject {
-                    auth_user,
-                    permission,
-                    project,
-                }
-            })
+        (auth_user.scope.allows_project(permission, &project)
+            && self.is_allowed_unwrap(auth_user, permission, project.clone()))
+        .then_some(())
+        .ok_or_else(|| {
+            let mut auth_user = auth_user.clone();
+            auth_user.sanitize();
+            RbacError::IsAllowedProject {
+                auth_user,
+                permission,
+                project,
+            }
+        })
     }
 }
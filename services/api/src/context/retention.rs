@@ -0,0 +1,124 @@
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
+
+use bencher_json::{system::config::JsonRetention, DateTime};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use slog::Logger;
+use tokio::sync::Mutex;
+
+use crate::{
+    context::{DataStore, DbConnection},
+    error::resource_not_found_err,
+    model::project::{
+        branch::version::VersionId,
+        report::{report_attachment::QueryReportAttachment, QueryReport, ReportId},
+    },
+    schema,
+};
+
+// How often to prune reports that have fallen out of the retention period
+const PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+// The maximum number of reports to delete while holding the database connection
+const BATCH_SIZE: i64 = 100;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Prunes reports, along with all of their metrics, once they are older than the retention period
+#[derive(Debug, Clone, Copy)]
+pub struct Retention {
+    days: NonZeroU32,
+}
+
+impl From<JsonRetention> for Retention {
+    fn from(retention: JsonRetention) -> Self {
+        let JsonRetention { days } = retention;
+        Self { days }
+    }
+}
+
+impl Retention {
+    pub fn days(self) -> NonZeroU32 {
+        self.days
+    }
+
+    /// Reports created before the cutoff are pruned
+    fn cutoff(self) -> DateTime {
+        let retention = chrono::Duration::seconds(i64::from(self.days.get()) * SECONDS_PER_DAY);
+        (DateTime::now().into_inner() - retention).into()
+    }
+
+    /// Prune reports in the background once a day
+    pub fn spawn(
+        self,
+        log: Logger,
+        conn: Arc<Mutex<DbConnection>>,
+        data_store: Option<Arc<DataStore>>,
+    ) {
+        tokio::spawn(async move {
+            #[allow(clippy::infinite_loop)]
+            loop {
+                if let Err(e) = self.prune(&log, &conn, data_store.as_deref()).await {
+                    slog::error!(log, "Failed to prune reports: {e}");
+                }
+                tokio::time::sleep(PRUNE_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Delete all reports created before the retention cutoff.
+    /// Reports are deleted in batches, so other requests are not blocked for the entire run.
+    /// Returns the number of reports that were deleted.
+    pub async fn prune(
+        self,
+        log: &Logger,
+        conn: &Mutex<DbConnection>,
+        data_store: Option<&DataStore>,
+    ) -> Result<usize, HttpError> {
+        let cutoff = self.cutoff();
+        slog::info!(
+            log,
+            "Pruning reports older than {} days (created before {cutoff})",
+            self.days
+        );
+        let mut pruned = 0;
+        loop {
+            let (deleted, external_attachments) = {
+                let conn = &mut *conn.lock().await;
+                let reports = schema::report::table
+                    .filter(schema::report::created.lt(cutoff))
+                    .select((schema::report::id, schema::report::version_id))
+                    .limit(BATCH_SIZE)
+                    .load::<(ReportId, VersionId)>(conn)
+                    .map_err(resource_not_found_err!(Report, cutoff))?;
+                if reports.is_empty() {
+                    break;
+                }
+                let mut external_attachments = Vec::new();
+                for &(report_id, version_id) in &reports {
+                    // Get any attachments that are stored in the data store before they are deleted along with the report
+                    if data_store.is_some() {
+                        external_attachments
+                            .extend(QueryReportAttachment::external_keys(conn, report_id)?);
+                    }
+                    // This also removes the version of the report if no other reports use it
+                    QueryReport::delete(conn, report_id, version_id)?;
+                }
+                (reports.len(), external_attachments)
+            };
+            pruned += deleted;
+            if let Some(data_store) = data_store {
+                for key in external_attachments {
+                    if let Err(e) = data_store.delete(&key).await {
+                        slog::warn!(
+                            log,
+                            "Failed to delete attachment ({key}) from data store: {e}"
+                        );
+                        #[cfg(feature = "sentry")]
+                        sentry::capture_error(&e);
+                    }
+                }
+            }
+        }
+        slog::info!(log, "Pruned {pruned} reports older than {} days", self.days);
+        Ok(pruned)
+    }
+}
@@ -1,20 +1,103 @@
 use std::fmt;
 
 use dropshot::{
-    HttpResponseAccepted, HttpResponseCreated, HttpResponseDeleted, HttpResponseHeaders,
-    HttpResponseOk,
+    ApiEndpointResponse, Body, HttpError, HttpResponse, HttpResponseAccepted, HttpResponseCreated,
+    HttpResponseDeleted, HttpResponseHeaders, HttpResponseOk,
 };
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Serialize;
 
-use crate::util::headers::{CorsHeaders, TotalCount};
+use crate::util::headers::{CorsHeaders, PageLinks, TotalCount};
 
+// Response status policy:
+// - `200 OK`: The request has been fully completed, including any side effects.
+// - `201 Created`: A new resource has been fully created and is returned.
+// - `202 Accepted`: The request is valid, but its effect is still pending when the response is sent.
+//   For example, the server restarting or the user confirming their email.
+// - `204 No Content`: The resource has been deleted.
 pub type CorsResponse = HttpResponseHeaders<HttpResponseOk<()>, CorsHeaders>;
 pub type ResponseOk<T> = HttpResponseHeaders<HttpResponseOk<T>, CorsHeaders>;
 pub type ResponseCreated<T> = HttpResponseHeaders<HttpResponseCreated<T>, CorsHeaders>;
 pub type ResponseAccepted<T> = HttpResponseHeaders<HttpResponseAccepted<T>, CorsHeaders>;
 pub type ResponseDeleted = HttpResponseHeaders<HttpResponseDeleted, CorsHeaders>;
 
+/// The response for an upsert: `201 Created` for a new resource or `200 OK` for an existing one.
+/// `dropshot` only documents the `201 Created` response,
+/// so the `200 OK` response is added to the spec for each of the `Api::UPSERT_OPERATIONS`.
+pub enum ResponseUpserted<T>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    Created(ResponseCreated<T>),
+    Existing(ResponseOk<T>),
+}
+
+impl<T> HttpResponse for ResponseUpserted<T>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    fn to_result(self) -> Result<http::Response<Body>, HttpError> {
+        match self {
+            Self::Created(response) => response.to_result(),
+            Self::Existing(response) => response.to_result(),
+        }
+    }
+
+    fn response_metadata() -> ApiEndpointResponse {
+        ResponseCreated::<T>::response_metadata()
+    }
+
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Created(response) => response.status_code(),
+            Self::Existing(response) => response.status_code(),
+        }
+    }
+}
+
+/// Generate the `OPTIONS` endpoint for a path.
+/// It returns the CORS headers for the given methods,
+/// and it must take the same path and query parameters as the other endpoints for that path.
+/// All `OPTIONS` endpoints use this macro.
+/// The other endpoints are still written out, as their auth and responses differ for each resource.
+///
+/// ```ignore
+/// options_endpoint! {
+///     proj_benchmark_options,
+///     "/v0/projects/{project}/benchmarks/{benchmark}",
+///     ["projects", "benchmarks"],
+///     (_path_params: Path<ProjBenchmarkParams>),
+///     [Get, Patch, Delete]
+/// }
+/// ```
+macro_rules! options_endpoint {
+    (
+        $name:ident,
+        $path:literal,
+        [$($tag:literal),+ $(,)?],
+        ($($param:ident: $param_ty:ty),* $(,)?),
+        [$($(#[$attr:meta])* $method:ident),+ $(,)?] $(,)?
+    ) => {
+        #[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+        #[dropshot::endpoint {
+            method = OPTIONS,
+            path = $path,
+            tags = [$($tag),+]
+        }]
+        pub async fn $name(
+            _rqctx: dropshot::RequestContext<$crate::context::ApiContext>,
+            $($param: $param_ty),*
+        ) -> Result<$crate::endpoints::endpoint::CorsResponse, dropshot::HttpError> {
+            Ok($crate::endpoints::Endpoint::cors(&[
+                $($(#[$attr])* $crate::endpoints::endpoint::$method.into()),+
+            ]))
+        }
+    };
+}
+
+pub(crate) use options_endpoint;
+
 #[derive(Copy, Clone)]
 pub enum Endpoint {
     Get(Get),
@@ -159,31 +242,42 @@ impl Get {
         body: T,
         auth: bool,
         total_count: TotalCount,
+        page_links: &PageLinks,
     ) -> ResponseOk<T>
     where
         T: JsonSchema + Serialize + Send + Sync,
     {
         if auth {
-            Self::auth_response_ok_with_total_count(body, total_count)
+            Self::auth_response_ok_with_total_count(body, total_count, page_links)
         } else {
-            Self::pub_response_ok_with_total_count(body, total_count)
+            Self::pub_response_ok_with_total_count(body, total_count, page_links)
         }
     }
 
-    pub fn pub_response_ok_with_total_count<T>(body: T, total_count: TotalCount) -> ResponseOk<T>
+    pub fn pub_response_ok_with_total_count<T>(
+        body: T,
+        total_count: TotalCount,
+        page_links: &PageLinks,
+    ) -> ResponseOk<T>
     where
         T: JsonSchema + Serialize + Send + Sync,
     {
-        let headers = CorsHeaders::new_pub_with_total_count(&http::Method::from(Self), total_count);
+        let headers = CorsHeaders::new_pub_with_total_count(&http::Method::from(Self), total_count)
+            .with_page_links(page_links, total_count);
         Self::response_ok_with_total_count_inner(body, headers)
     }
 
-    pub fn auth_response_ok_with_total_count<T>(body: T, total_count: TotalCount) -> ResponseOk<T>
+    pub fn auth_response_ok_with_total_count<T>(
+        body: T,
+        total_count: TotalCount,
+        page_links: &PageLinks,
+    ) -> ResponseOk<T>
     where
         T: JsonSchema + Serialize + Send + Sync,
     {
         let headers =
-            CorsHeaders::new_auth_with_total_count(&http::Method::from(Self), total_count);
+            CorsHeaders::new_auth_with_total_count(&http::Method::from(Self), total_count)
+                .with_page_links(page_links, total_count);
         Self::response_ok_with_total_count_inner(body, headers)
     }
 
@@ -203,6 +297,19 @@ impl_response_ok!(Post);
 impl_response_created!(Post);
 impl_response_accepted!(Post);
 
+impl Post {
+    pub fn auth_response_upserted<T>(body: T, created: bool) -> ResponseUpserted<T>
+    where
+        T: JsonSchema + Serialize + Send + Sync + 'static,
+    {
+        if created {
+            ResponseUpserted::Created(Self::auth_response_created(body))
+        } else {
+            ResponseUpserted::Existing(Self::auth_response_ok(body))
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct Put;
 impl_method!(Put, PUT);
@@ -217,6 +324,7 @@ impl_response_ok!(Patch);
 #[derive(Copy, Clone)]
 pub struct Delete;
 impl_method!(Delete, DELETE);
+impl_response_ok!(Delete);
 
 impl Delete {
     pub fn response_deleted<T>(auth: bool) -> ResponseDeleted {
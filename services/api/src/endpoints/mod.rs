@@ -15,6 +15,10 @@ use crate::context::ApiContext;
 pub struct Api;
 
 impl Api {
+    /// The operations that return an existing resource with `200 OK` instead of creating a new one
+    pub const UPSERT_OPERATIONS: &[&str] =
+        &["proj_branch_post", "proj_testbed_post", "proj_measure_post"];
+
     #[allow(clippy::too_many_lines)]
     pub fn register(
         api: &mut ApiDescription<ApiContext>,
@@ -83,6 +87,16 @@ impl Api {
         api.register(organization::projects::org_projects_get)?;
         api.register(organization::projects::org_project_post)?;
 
+        // Organization Threshold Templates
+        if http_options {
+            api.register(organization::templates::org_templates_options)?;
+            api.register(organization::templates::org_template_options)?;
+        }
+        api.register(organization::templates::org_templates_get)?;
+        api.register(organization::templates::org_template_post)?;
+        api.register(organization::templates::org_template_get)?;
+        api.register(organization::templates::org_template_delete)?;
+
         #[cfg(feature = "plus")]
         {
             // Organization Plan
@@ -114,12 +128,28 @@ impl Api {
         api.register(project::projects::project_patch)?;
         api.register(project::projects::project_delete)?;
 
+        // Unclaimed Projects
+        if http_options {
+            api.register(project::run::run_options)?;
+            api.register(project::projects::project_claim_options)?;
+        }
+        api.register(project::run::run_post)?;
+        api.register(project::projects::project_claim_post)?;
+
         // Project Permission
         if http_options {
             api.register(project::allowed::proj_allowed_options)?;
         }
         api.register(project::allowed::proj_allowed_get)?;
 
+        // Project Schema
+        if http_options {
+            api.register(project::schema::proj_schema_options)?;
+            api.register(project::schema::proj_schema_metric_kinds_options)?;
+        }
+        api.register(project::schema::proj_schema_get)?;
+        api.register(project::schema::proj_schema_metric_kinds_get)?;
+
         // Reports
         if http_options {
             api.register(project::reports::proj_reports_options)?;
@@ -127,9 +157,38 @@ impl Api {
         }
         api.register(project::reports::proj_report_post)?;
         api.register(project::reports::proj_reports_get)?;
+        api.register(project::reports::proj_reports_delete)?;
         api.register(project::reports::proj_report_get)?;
         api.register(project::reports::proj_report_delete)?;
 
+        // Report Raw Results
+        if http_options {
+            api.register(project::reports::proj_report_raw_options)?;
+        }
+        api.register(project::reports::proj_report_raw_get)?;
+
+        // Report Reruns
+        if http_options {
+            api.register(project::reports::proj_report_rerun_options)?;
+        }
+        api.register(project::reports::proj_report_rerun_post)?;
+
+        // Report Results
+        if http_options {
+            api.register(project::reports::proj_report_results_options)?;
+        }
+        api.register(project::reports::proj_report_results_get)?;
+
+        // Report Attachments
+        if http_options {
+            api.register(project::attachments::proj_report_attachments_options)?;
+            api.register(project::attachments::proj_report_attachment_options)?;
+        }
+        api.register(project::attachments::proj_report_attachments_get)?;
+        api.register(project::attachments::proj_report_attachments_post)?;
+        api.register(project::attachments::proj_report_attachment_get)?;
+        api.register(project::attachments::proj_report_attachment_delete)?;
+
         // Perf
         if http_options {
             api.register(project::perf::proj_perf_options)?;
@@ -157,12 +216,14 @@ impl Api {
         if http_options {
             api.register(project::branches::proj_branches_options)?;
             api.register(project::branches::proj_branch_options)?;
+            api.register(project::branches::proj_branch_merge_options)?;
         }
         api.register(project::branches::proj_branches_get)?;
         api.register(project::branches::proj_branch_post)?;
         api.register(project::branches::proj_branch_get)?;
         api.register(project::branches::proj_branch_patch)?;
         api.register(project::branches::proj_branch_delete)?;
+        api.register(project::branches::proj_branch_merge_post)?;
 
         // Testbeds
         if http_options {
@@ -186,6 +247,13 @@ impl Api {
         api.register(project::benchmarks::proj_benchmark_patch)?;
         api.register(project::benchmarks::proj_benchmark_delete)?;
 
+        // Benchmark Files
+        if http_options {
+            api.register(project::files::proj_files_options)?;
+        }
+        api.register(project::files::proj_files_get)?;
+        api.register(project::files::proj_files_put)?;
+
         // Measures
         if http_options {
             api.register(project::measures::proj_measures_options)?;
@@ -199,9 +267,13 @@ impl Api {
 
         // Metrics
         if http_options {
+            api.register(project::metrics::proj_metrics_options)?;
             api.register(project::metrics::proj_metric_options)?;
+            api.register(project::openmetrics::proj_openmetrics_options)?;
         }
+        api.register(project::metrics::proj_metric_post)?;
         api.register(project::metrics::proj_metric_get)?;
+        api.register(project::openmetrics::proj_openmetrics_get)?;
 
         // Thresholds
         if http_options {
@@ -214,6 +286,27 @@ impl Api {
         api.register(project::thresholds::proj_threshold_put)?;
         api.register(project::thresholds::proj_threshold_delete)?;
 
+        // Threshold Changes
+        if http_options {
+            api.register(project::threshold_changes::proj_threshold_changes_options)?;
+            api.register(project::threshold_changes::proj_threshold_change_options)?;
+            api.register(project::threshold_changes::proj_threshold_change_approve_options)?;
+        }
+        api.register(project::threshold_changes::proj_threshold_changes_get)?;
+        api.register(project::threshold_changes::proj_threshold_change_post)?;
+        api.register(project::threshold_changes::proj_threshold_change_get)?;
+        api.register(project::threshold_changes::proj_threshold_change_approve_post)?;
+
+        // Threshold Templates
+        if http_options {
+            api.register(project::templates::proj_templates_options)?;
+            api.register(project::templates::proj_template_options)?;
+        }
+        api.register(project::templates::proj_templates_get)?;
+        api.register(project::templates::proj_template_post)?;
+        api.register(project::templates::proj_template_get)?;
+        api.register(project::templates::proj_template_delete)?;
+
         // Threshold Alerts
         if http_options {
             api.register(project::alerts::proj_alerts_options)?;
@@ -223,6 +316,38 @@ impl Api {
         api.register(project::alerts::proj_alert_get)?;
         api.register(project::alerts::proj_alert_patch)?;
 
+        // Integrations
+        if http_options {
+            api.register(project::integrations::proj_integrations_options)?;
+            api.register(project::integrations::proj_integration_options)?;
+        }
+        api.register(project::integrations::proj_integrations_get)?;
+        api.register(project::integrations::proj_integration_post)?;
+        api.register(project::integrations::proj_integration_get)?;
+        api.register(project::integrations::proj_integration_patch)?;
+        api.register(project::integrations::proj_integration_delete)?;
+
+        // Webhooks
+        if http_options {
+            api.register(project::webhooks::proj_webhooks_options)?;
+            api.register(project::webhooks::proj_webhook_options)?;
+        }
+        api.register(project::webhooks::proj_webhooks_get)?;
+        api.register(project::webhooks::proj_webhook_post)?;
+        api.register(project::webhooks::proj_webhook_get)?;
+        api.register(project::webhooks::proj_webhook_delete)?;
+
+        // Project Tokens
+        if http_options {
+            api.register(project::tokens::proj_tokens_options)?;
+            api.register(project::tokens::proj_token_options)?;
+        }
+        api.register(project::tokens::proj_tokens_get)?;
+        api.register(project::tokens::proj_token_post)?;
+        api.register(project::tokens::proj_token_get)?;
+        api.register(project::tokens::proj_token_patch)?;
+        api.register(project::tokens::proj_token_delete)?;
+
         // Users
         if http_options {
             api.register(user::users::users_options)?;
@@ -231,6 +356,7 @@ impl Api {
         api.register(user::users::users_get)?;
         api.register(user::users::user_get)?;
         api.register(user::users::user_patch)?;
+        api.register(user::users::user_delete)?;
 
         // Tokens
         if http_options {
@@ -250,6 +376,9 @@ impl Api {
             api.register(system::server::config::server_config_options)?;
             api.register(system::server::config::server_config_console_options)?;
             api.register(system::server::backup::server_backup_options)?;
+            api.register(system::server::backup::server_backups_options)?;
+            api.register(system::server::maintenance::server_maintenance_options)?;
+            api.register(system::server::retention::server_retention_run_options)?;
         }
         api.register(system::server::version::server_version_get)?;
         api.register(system::server::spec::server_spec_get)?;
@@ -258,6 +387,15 @@ impl Api {
         api.register(system::server::config::server_config_put)?;
         api.register(system::server::config::server_config_console_get)?;
         api.register(system::server::backup::server_backup_post)?;
+        api.register(system::server::backup::server_backups_get)?;
+        api.register(system::server::maintenance::server_maintenance_post)?;
+        api.register(system::server::retention::server_retention_run_delete)?;
+
+        // Operations
+        if http_options {
+            api.register(system::operations::operation_options)?;
+        }
+        api.register(system::operations::operation_get)?;
 
         #[cfg(feature = "plus")]
         {
@@ -271,6 +409,11 @@ impl Api {
                 // TODO remove in due time
                 api.register(system::server::stats::root_server_stats_post)?;
                 api.register(system::server::stats::server_stats_post)?;
+                // CLI telemetry
+                if http_options {
+                    api.register(system::server::telemetry::server_telemetry_options)?;
+                }
+                api.register(system::server::telemetry::server_telemetry_post)?;
             }
         }
 
@@ -6,10 +6,7 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
     model::{
         organization::{organization_role::Permission, QueryOrganization},
         user::auth::{AuthUser, BearerToken},
@@ -24,17 +21,12 @@ pub struct OrgAllowedParams {
     pub permission: OrganizationPermission,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/allowed/{permission}",
-    tags = ["organizations", "allowed"]
-}]
-pub async fn org_allowed_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgAllowedParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    org_allowed_options,
+    "/v0/organizations/{organization}/allowed/{permission}",
+    ["organizations", "allowed"],
+    (_path_params: Path<OrgAllowedParams>),
+    [Get]
 }
 
 #[endpoint {
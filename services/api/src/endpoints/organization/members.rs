@@ -15,11 +15,8 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::{ApiContext, Body, ButtonBody, DbConnection, Message},
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseAccepted, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseAccepted, ResponseDeleted, ResponseOk,
     },
     error::{forbidden_error, issue_error, resource_conflict_err, resource_not_found_err},
     model::{
@@ -30,7 +27,10 @@ use crate::{
         },
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 // TODO Custom max TTL
@@ -60,19 +60,16 @@ pub struct OrgMembersQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/members",
-    tags = ["organizations", "members"]
-}]
-pub async fn org_members_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgMembersParams>,
-    _pagination_params: Query<OrgMembersPagination>,
-    _query_params: Query<OrgMembersQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    org_members_options,
+    "/v0/organizations/{organization}/members",
+    ["organizations", "members"],
+    (
+        _path_params: Path<OrgMembersParams>,
+        _pagination_params: Query<OrgMembersPagination>,
+        _query_params: Query<OrgMembersQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List organization members
@@ -93,15 +90,21 @@ pub async fn org_members_get(
     query_params: Query<OrgMembersQuery>,
 ) -> Result<ResponseOk<JsonMembers>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         &auth_user,
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
-    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
 }
 
 async fn get_ls_inner(
@@ -335,17 +338,12 @@ pub struct OrgMemberParams {
     pub user: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/members/{user}",
-    tags = ["organizations", "members"]
-}]
-pub async fn org_member_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgMemberParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    org_member_options,
+    "/v0/organizations/{organization}/members/{user}",
+    ["organizations", "members"],
+    (_path_params: Path<OrgMemberParams>),
+    [Get, Patch, Delete]
 }
 
 /// View an organization member
@@ -3,4 +3,5 @@ pub mod members;
 pub mod organizations;
 pub mod plan;
 pub mod projects;
+pub mod templates;
 pub mod usage;
@@ -14,11 +14,8 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
     },
     error::{resource_conflict_err, resource_not_found_err},
     model::{
@@ -29,7 +26,11 @@ use crate::{
         user::auth::{AuthUser, BearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+        slug::insert_with_slug,
+    },
 };
 
 pub type OrganizationsPagination = JsonPagination<OrganizationsSort>;
@@ -50,18 +51,12 @@ pub struct OrganizationsQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations",
-    tags = ["organizations"]
-}]
-pub async fn organizations_options(
-    _rqctx: RequestContext<ApiContext>,
-    _pagination_params: Query<OrganizationsPagination>,
-    _query_params: Query<OrganizationsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    organizations_options,
+    "/v0/organizations",
+    ["organizations"],
+    (_pagination_params: Query<OrganizationsPagination>, _query_params: Query<OrganizationsQuery>),
+    [Get, Post]
 }
 
 /// List organizations
@@ -82,14 +77,20 @@ pub async fn organizations_get(
     query_params: Query<OrganizationsQuery>,
 ) -> Result<ResponseOk<JsonOrganizations>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         &auth_user,
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
-    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
 }
 
 async fn get_ls_inner(
@@ -190,11 +191,9 @@ async fn post_inner(
     auth_user: &AuthUser,
 ) -> Result<JsonOrganization, HttpError> {
     // Create the organization
-    let insert_organization =
+    let mut insert_organization =
         InsertOrganization::from_json(conn_lock!(context), json_organization)?;
-    diesel::insert_into(schema::organization::table)
-        .values(&insert_organization)
-        .execute(conn_lock!(context))
+    insert_with_slug!(conn_lock!(context), organization, insert_organization)
         .map_err(resource_conflict_err!(Organization, insert_organization))?;
     let query_organization = schema::organization::table
         .filter(schema::organization::uuid.eq(&insert_organization.uuid))
@@ -224,17 +223,12 @@ pub struct OrganizationParams {
     pub organization: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}",
-    tags = ["organizations"]
-}]
-pub async fn organization_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrganizationParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    organization_options,
+    "/v0/organizations/{organization}",
+    ["organizations"],
+    (_path_params: Path<OrganizationParams>),
+    [Get, Patch, Delete]
 }
 
 /// View an organization
@@ -15,9 +15,8 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk},
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
     },
     error::{
         forbidden_error, issue_error, resource_conflict_err, resource_conflict_error,
@@ -40,17 +39,12 @@ pub struct OrgPlanParams {
     pub organization: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/plan",
-    tags = ["organizations", "plan"]
-}]
-pub async fn org_plan_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgPlanParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into(), Delete.into()]))
+options_endpoint! {
+    org_plan_options,
+    "/v0/organizations/{organization}/plan",
+    ["organizations", "plan"],
+    (_path_params: Path<OrgPlanParams>),
+    [Get, Post, Delete]
 }
 
 #[endpoint {
@@ -1,10 +1,6 @@
 use bencher_json::{
-    project::{
-        measure::built_in::default::{Latency, Throughput},
-        ProjectRole,
-    },
-    DateTime, JsonDirection, JsonNewProject, JsonPagination, JsonProject, JsonProjects, ResourceId,
-    ResourceName,
+    project::ProjectRole, DateTime, JsonDirection, JsonNewProject, JsonPagination, JsonProject,
+    JsonProjects, ResourceId, ResourceName,
 };
 use bencher_rbac::organization::Permission;
 use diesel::{
@@ -21,25 +17,18 @@ use crate::model::organization::plan::PlanKind;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Post, ResponseCreated, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, Post, ResponseCreated, ResponseOk},
     error::{forbidden_error, resource_conflict_err, resource_not_found_err},
     model::{
         organization::QueryOrganization,
-        project::{
-            branch::InsertBranch,
-            measure::{InsertMeasure, QueryMeasure},
-            project_role::InsertProjectRole,
-            testbed::{InsertTestbed, QueryTestbed},
-            threshold::InsertThreshold,
-            InsertProject, QueryProject,
-        },
+        project::{project_role::InsertProjectRole, InsertProject, QueryProject},
         user::auth::{AuthUser, BearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -66,19 +55,16 @@ pub struct OrgProjectsQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/projects",
-    tags = ["organizations", "projects"]
-}]
-pub async fn org_projects_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgProjectsParams>,
-    _pagination_params: Query<OrgProjectsPagination>,
-    _query_params: Query<OrgProjectsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    org_projects_options,
+    "/v0/organizations/{organization}/projects",
+    ["organizations", "projects"],
+    (
+        _path_params: Path<OrgProjectsParams>,
+        _pagination_params: Query<OrgProjectsPagination>,
+        _query_params: Query<OrgProjectsQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List organization projects
@@ -99,15 +85,21 @@ pub async fn org_projects_get(
     query_params: Query<OrgProjectsQuery>,
 ) -> Result<ResponseOk<JsonProjects>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
         &auth_user,
     )
     .await?;
-    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
 }
 
 async fn get_ls_inner(
@@ -244,15 +236,7 @@ async fn post_inner(
         .is_allowed_organization(auth_user, Permission::Create, &insert_project)
         .map_err(forbidden_error)?;
 
-    diesel::insert_into(schema::project::table)
-        .values(&insert_project)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Project, insert_project))?;
-    let query_project = schema::project::table
-        .filter(schema::project::uuid.eq(&insert_project.uuid))
-        .first::<QueryProject>(conn_lock!(context))
-        .map_err(resource_not_found_err!(Project, insert_project))?;
-    slog::debug!(log, "Created project: {query_project:?}");
+    let query_project = insert_project.insert(log, context).await?;
 
     let timestamp = DateTime::now();
     // Connect the user to the project as a `Maintainer`
@@ -269,58 +253,6 @@ async fn post_inner(
         .map_err(resource_conflict_err!(ProjectRole, insert_proj_role))?;
     slog::debug!(log, "Added project role: {insert_proj_role:?}");
 
-    // Add a `main` branch to the project
-    let query_branch = InsertBranch::main(log, context, query_project.id).await?;
-    slog::debug!(log, "Added project branch: {query_branch:?}");
-    let branch_id = query_branch.id;
-
-    // Add a `localhost` testbed to the project
-    let insert_testbed = InsertTestbed::localhost(conn_lock!(context), query_project.id)?;
-    diesel::insert_into(schema::testbed::table)
-        .values(&insert_testbed)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Testbed, insert_testbed))?;
-    let testbed_id = QueryTestbed::get_id(conn_lock!(context), insert_testbed.uuid)?;
-    slog::debug!(log, "Added project testbed: {insert_testbed:?}");
-
-    // Add a `latency` measure to the project
-    let insert_measure =
-        InsertMeasure::from_measure::<Latency>(conn_lock!(context), query_project.id)?;
-    diesel::insert_into(schema::measure::table)
-        .values(&insert_measure)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Measure, insert_measure))?;
-    let measure_id = QueryMeasure::get_id(conn_lock!(context), insert_measure.uuid)?;
-    slog::debug!(log, "Added project measure: {insert_measure:?}");
-    // Add a `latency` threshold to the project
-    let threshold_id = InsertThreshold::upper_boundary(
-        conn_lock!(context),
-        query_project.id,
-        branch_id,
-        testbed_id,
-        measure_id,
-    )?;
-    slog::debug!(log, "Added project threshold: {threshold_id}");
-
-    // Add a `throughput` measure to the project
-    let insert_measure =
-        InsertMeasure::from_measure::<Throughput>(conn_lock!(context), query_project.id)?;
-    diesel::insert_into(schema::measure::table)
-        .values(&insert_measure)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Measure, insert_measure))?;
-    let measure_id = QueryMeasure::get_id(conn_lock!(context), insert_measure.uuid)?;
-    slog::debug!(log, "Added project measure: {insert_measure:?}");
-    // Add a `throughput` threshold to the project
-    let threshold_id = InsertThreshold::lower_boundary(
-        conn_lock!(context),
-        query_project.id,
-        branch_id,
-        testbed_id,
-        measure_id,
-    )?;
-    slog::debug!(log, "Added project threshold: {threshold_id}");
-
     #[cfg(feature = "plus")]
     context.update_index(log, &query_project).await;
 
@@ -0,0 +1,255 @@
+use bencher_json::{
+    JsonNewThresholdTemplate, JsonThresholdTemplate, JsonThresholdTemplates, ResourceId,
+    ThresholdTemplateUuid,
+};
+use bencher_rbac::organization::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::{bad_request_error, resource_conflict_err},
+    model::{
+        organization::QueryOrganization,
+        project::threshold::template::{InsertThresholdTemplate, QueryThresholdTemplate},
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgTemplatesParams {
+    /// The slug or UUID for an organization.
+    pub organization: ResourceId,
+}
+
+options_endpoint! {
+    org_templates_options,
+    "/v0/organizations/{organization}/templates",
+    ["organizations", "thresholds"],
+    (_path_params: Path<OrgTemplatesParams>),
+    [Get, Post]
+}
+
+/// List threshold templates for an organization
+///
+/// List all threshold templates for an organization.
+/// These templates apply to all projects in the organization.
+/// The user must have `view` permissions for the organization.
+/// Templates are sorted by creation date.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/templates",
+    tags = ["organizations", "thresholds"]
+}]
+pub async fn org_templates_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgTemplatesParams>,
+) -> Result<ResponseOk<JsonThresholdTemplates>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: OrgTemplatesParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplates, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::View,
+    )?;
+
+    Ok(
+        QueryThresholdTemplate::all_for_organization(conn_lock!(context), &query_organization)?
+            .into_iter()
+            .map(|template| template.into_json(&query_organization, None))
+            .collect(),
+    )
+}
+
+/// Create a threshold template for an organization
+///
+/// Create a threshold template for an organization.
+/// When a report is created for a branch and testbed that do not have a threshold for the template measure,
+/// a new threshold is created with the template model.
+/// A project threshold template for the same measure takes precedence.
+/// Existing thresholds are not changed.
+/// The user must have `manage` permissions for the organization.
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/templates",
+    tags = ["organizations", "thresholds"]
+}]
+pub async fn org_template_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgTemplatesParams>,
+    body: TypedBody<JsonNewThresholdTemplate>,
+) -> Result<ResponseCreated<JsonThresholdTemplate>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: OrgTemplatesParams,
+    json_template: JsonNewThresholdTemplate,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplate, HttpError> {
+    // Validate the new model
+    json_template.model.validate().map_err(bad_request_error)?;
+
+    // Verify that the user is allowed
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let insert_template =
+        InsertThresholdTemplate::from_json(query_organization.id, None, json_template);
+    diesel::insert_into(schema::threshold_template::table)
+        .values(&insert_template)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            ThresholdTemplate,
+            (&query_organization, &insert_template)
+        ))?;
+
+    QueryThresholdTemplate::get_for_organization(
+        conn_lock!(context),
+        &query_organization,
+        insert_template.uuid,
+    )
+    .map(|template| template.into_json(&query_organization, None))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgTemplateParams {
+    /// The slug or UUID for an organization.
+    pub organization: ResourceId,
+    /// The UUID for a threshold template.
+    pub template: ThresholdTemplateUuid,
+}
+
+options_endpoint! {
+    org_template_options,
+    "/v0/organizations/{organization}/templates/{template}",
+    ["organizations", "thresholds"],
+    (_path_params: Path<OrgTemplateParams>),
+    [Get, Delete]
+}
+
+/// View a threshold template for an organization
+///
+/// View a threshold template for an organization.
+/// The user must have `view` permissions for the organization.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/templates/{template}",
+    tags = ["organizations", "thresholds"]
+}]
+pub async fn org_template_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgTemplateParams>,
+) -> Result<ResponseOk<JsonThresholdTemplate>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OrgTemplateParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplate, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::View,
+    )?;
+
+    QueryThresholdTemplate::get_for_organization(
+        conn_lock!(context),
+        &query_organization,
+        path_params.template,
+    )
+    .map(|template| template.into_json(&query_organization, None))
+}
+
+/// Delete a threshold template for an organization
+///
+/// Delete a threshold template for an organization.
+/// Thresholds that have already been created from the template are not changed.
+/// The user must have `manage` permissions for the organization.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/templates/{template}",
+    tags = ["organizations", "thresholds"]
+}]
+pub async fn org_template_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgTemplateParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OrgTemplateParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_template = QueryThresholdTemplate::get_for_organization(
+        conn_lock!(context),
+        &query_organization,
+        path_params.template,
+    )?;
+
+    diesel::delete(
+        schema::threshold_template::table
+            .filter(schema::threshold_template::id.eq(query_template.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(
+        ThresholdTemplate,
+        query_template.uuid
+    ))?;
+
+    Ok(())
+}
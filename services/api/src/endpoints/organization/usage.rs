@@ -16,10 +16,7 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
     error::{forbidden_error, issue_error, payment_required_error, resource_not_found_err},
     model::{
         organization::{plan::QueryPlan, QueryOrganization},
@@ -37,17 +34,12 @@ pub struct OrgUsageParams {
     pub organization: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/organizations/{organization}/usage",
-    tags = ["organizations", "usage"]
-}]
-pub async fn org_usage_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<OrgUsageParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    org_usage_options,
+    "/v0/organizations/{organization}/usage",
+    ["organizations", "usage"],
+    (_path_params: Path<OrgUsageParams>),
+    [Get]
 }
 
 /// View organization metrics usage
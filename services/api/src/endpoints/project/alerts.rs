@@ -1,5 +1,5 @@
 use bencher_json::{
-    project::alert::{AlertStatus, JsonUpdateAlert},
+    project::alert::{AlertKind, AlertStatus, JsonUpdateAlert},
     AlertUuid, JsonAlert, JsonAlerts, JsonDirection, JsonPagination, ResourceId,
 };
 use bencher_rbac::project::Permission;
@@ -11,10 +11,7 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Patch, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, Patch, ResponseOk},
     error::{resource_conflict_err, resource_not_found_err},
     model::{
         project::{
@@ -24,7 +21,7 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::headers::TotalCount,
+    util::headers::{PageLinks, TotalCount},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -50,24 +47,24 @@ pub struct ProjAlertsQuery {
     /// Filter alerts by their status.
     /// If not set, returns all alerts.
     pub status: Option<AlertStatus>,
+    /// Filter alerts by their kind, either boundary `limit` or change-point `drift` alerts.
+    /// If not set, returns all alerts.
+    pub kind: Option<AlertKind>,
     /// If set to `true`, only returns archived alerts.
     /// If not set or set to `false`, only returns alerts with non-archived branches, testbeds, or measures.
     pub archived: Option<bool>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/alerts",
-    tags = ["projects", "alerts"]
-}]
-pub async fn proj_alerts_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjAlertsParams>,
-    _pagination_params: Query<ProjAlertsPagination>,
-    _query_params: Query<ProjAlertsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    proj_alerts_options,
+    "/v0/projects/{project}/alerts",
+    ["projects", "alerts"],
+    (
+        _path_params: Path<ProjAlertsParams>,
+        _pagination_params: Query<ProjAlertsPagination>,
+        _query_params: Query<ProjAlertsQuery>,
+    ),
+    [Get]
 }
 
 /// List alerts for a project
@@ -89,11 +86,13 @@ pub async fn proj_alerts_get(
     query_params: Query<ProjAlertsQuery>,
 ) -> Result<ResponseOk<JsonAlerts>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -101,6 +100,7 @@ pub async fn proj_alerts_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -181,6 +181,10 @@ fn get_ls_query<'q>(
         query = query.filter(schema::alert::status.eq(status));
     }
 
+    if let Some(kind) = query_params.kind {
+        query = query.filter(schema::alert::kind.eq(kind));
+    }
+
     if let Some(true) = query_params.archived {
         query = query.filter(
             schema::branch::archived
@@ -291,17 +295,12 @@ pub struct ProjAlertParams {
     pub alert: AlertUuid,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/alerts/{alert}",
-    tags = ["projects", "alerts"]
-}]
-pub async fn proj_alert_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjAlertParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+options_endpoint! {
+    proj_alert_options,
+    "/v0/projects/{project}/alerts/{alert}",
+    ["projects", "alerts"],
+    (_path_params: Path<ProjAlertParams>),
+    [Get, Patch]
 }
 
 /// View an alert
@@ -364,6 +363,8 @@ pub async fn proj_alert_patch(
 ) -> Result<ResponseOk<JsonAlert>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = patch_inner(
+        #[cfg(feature = "plus")]
+        &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
@@ -374,6 +375,7 @@ pub async fn proj_alert_patch(
 }
 
 async fn patch_inner(
+    #[cfg(feature = "plus")] log: &slog::Logger,
     context: &ApiContext,
     path_params: ProjAlertParams,
     json_alert: JsonUpdateAlert,
@@ -398,6 +400,12 @@ async fn patch_inner(
 
     let alert = QueryAlert::get(conn_lock!(context), query_alert.id)?;
 
+    // Close any issues that were opened for the alert, now that it has been dismissed
+    #[cfg(feature = "plus")]
+    if !matches!(alert.status, AlertStatus::Active) {
+        context.close_alert_issues(log, &[alert.id]).await;
+    }
+
     // Separate out this query to prevent a deadlock when getting the conn_lock
     alert.into_json(context).await
 }
@@ -6,10 +6,7 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
     model::{
         project::{project_role::Permission, QueryProject},
         user::auth::{AuthUser, BearerToken},
@@ -24,17 +21,12 @@ pub struct ProjAllowedParams {
     pub permission: ProjectPermission,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/allowed/{permission}",
-    tags = ["projects", "allowed"]
-}]
-pub async fn proj_allowed_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjAllowedParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    proj_allowed_options,
+    "/v0/projects/{project}/allowed/{permission}",
+    ["projects", "allowed"],
+    (_path_params: Path<ProjAllowedParams>),
+    [Get]
 }
 
 #[endpoint {
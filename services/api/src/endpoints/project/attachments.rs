@@ -0,0 +1,382 @@
+use bencher_json::{AttachmentUuid, JsonAttachment, JsonAttachments, ReportUuid, ResourceId};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, Body, HttpError, MultipartBody, Path, RequestContext};
+use http::{Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::{
+        bad_request_error, issue_error, payload_too_large_error, resource_conflict_err,
+        resource_not_found_err,
+    },
+    model::{
+        project::{
+            report::{
+                report_attachment::{
+                    attachment_name, InsertReportAttachment, QueryReportAttachment,
+                    MAX_ATTACHMENTS, MAX_ATTACHMENT_SIZE,
+                },
+                QueryReport, ReportId,
+            },
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken, PubBearerToken},
+    },
+    schema,
+};
+
+use super::reports::ProjReportParams;
+
+options_endpoint! {
+    proj_report_attachments_options,
+    "/v0/projects/{project}/reports/{report}/attachments",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportParams>),
+    [Get, Post]
+}
+
+/// List attachments for a report
+///
+/// List all of the artifacts attached to a report, sorted by file name.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/reports/{report}/attachments",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_attachments_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjReportParams>,
+) -> Result<ResponseOk<JsonAttachments>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjReportParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonAttachments, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+    let report_id = get_report_id(context, &query_project, path_params.report).await?;
+
+    Ok(QueryReportAttachment::list(conn_lock!(context), report_id)?
+        .into_iter()
+        .map(QueryReportAttachment::into_json)
+        .collect())
+}
+
+/// Upload attachments for a report
+///
+/// Attach artifacts, such as a flamegraph SVG or a benchmark harness report tarball, to a report.
+/// The request body must be `multipart/form-data`, and each file part is stored as a separate attachment.
+/// Each attachment may be at most 8 MB, and a report may have at most 16 attachments.
+/// The total size of the request is limited by the server `large_request_body_max_bytes`.
+/// Attachments are stored as they are uploaded, so any attachments before an invalid part are kept.
+/// If a data store is configured, then the attachments are stored there instead of in the database.
+/// The user must have `create` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/reports/{report}/attachments",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_attachments_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjReportParams>,
+    body: MultipartBody,
+) -> Result<ResponseCreated<JsonAttachments>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(rqctx.context(), path_params.into_inner(), body, &auth_user).await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjReportParams,
+    body: MultipartBody,
+    auth_user: &AuthUser,
+) -> Result<JsonAttachments, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+    let report_id = get_report_id(context, &query_project, path_params.report).await?;
+
+    let mut attachment_count = QueryReportAttachment::count(conn_lock!(context), report_id)?;
+    let mut multipart = body.content;
+    // Each attachment is stored as soon as it has been read,
+    // so only a single attachment is ever held in memory.
+    let mut total_size = 0;
+    let mut json_attachments = Vec::new();
+    while let Some(mut field) = multipart.next_field().await.map_err(bad_request_error)? {
+        let Some(file_name) = field.file_name() else {
+            return Err(bad_request_error(format!(
+                "Attachment part ({name}) is missing a file name",
+                name = field.name().unwrap_or_default()
+            )));
+        };
+        let name = attachment_name(file_name)?;
+        let content_type = field.content_type().map(ToString::to_string);
+
+        attachment_count += 1;
+        if attachment_count > MAX_ATTACHMENTS {
+            return Err(bad_request_error(format!(
+                "A report may have at most {MAX_ATTACHMENTS} attachments"
+            )));
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(bad_request_error)? {
+            if data.len() + chunk.len() > MAX_ATTACHMENT_SIZE {
+                return Err(payload_too_large_error(format!(
+                    "Attachment ({name}) is larger than the maximum size of {MAX_ATTACHMENT_SIZE} bytes"
+                )));
+            }
+            total_size += chunk.len();
+            if total_size > context.large_body_max_bytes {
+                return Err(payload_too_large_error(format!(
+                    "Attachments are larger than the maximum request size of {max} bytes",
+                    max = context.large_body_max_bytes
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        let insert_attachment = InsertReportAttachment::new(report_id, name, content_type, data);
+        json_attachments.push(store_attachment(context, report_id, insert_attachment).await?);
+    }
+    if json_attachments.is_empty() {
+        return Err(bad_request_error("No attachments were provided"));
+    }
+
+    Ok(json_attachments.into())
+}
+
+async fn store_attachment(
+    context: &ApiContext,
+    report_id: ReportId,
+    mut insert_attachment: InsertReportAttachment,
+) -> Result<JsonAttachment, HttpError> {
+    // If there is a data store, then store the attachment there instead of in the database
+    let data_store_key = if let Some(data_store) = context.database.data_store.as_ref() {
+        let key = insert_attachment.data_store_key();
+        let data = insert_attachment.data.take().unwrap_or_default();
+        data_store.put(&key, data).await.map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to store attachment in data store",
+                &format!(
+                    "Failed to store attachment ({name}) for report ({report_id}) in data store.",
+                    name = insert_attachment.name
+                ),
+                e,
+            )
+        })?;
+        Some(key)
+    } else {
+        None
+    };
+
+    let inserted = diesel::insert_into(schema::report_attachment::table)
+        .values(&insert_attachment)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            ReportAttachment,
+            (report_id, &insert_attachment.name)
+        ));
+    if let Err(e) = inserted {
+        if let (Some(data_store), Some(key)) =
+            (context.database.data_store.as_ref(), data_store_key)
+        {
+            // Clean up the orphaned attachment in the data store
+            if let Err(e) = data_store.delete(&key).await {
+                debug_assert!(
+                    false,
+                    "Failed to delete attachment ({key}) from data store: {e}"
+                );
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+            }
+        }
+        return Err(e);
+    }
+
+    Ok(
+        QueryReportAttachment::from_uuid(conn_lock!(context), report_id, insert_attachment.uuid)?
+            .into_json(),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjReportAttachmentParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a report.
+    pub report: ReportUuid,
+    /// The UUID for an attachment.
+    pub attachment: AttachmentUuid,
+}
+
+options_endpoint! {
+    proj_report_attachment_options,
+    "/v0/projects/{project}/reports/{report}/attachments/{attachment}",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportAttachmentParams>),
+    [Get, Delete]
+}
+
+/// Download an attachment for a report
+///
+/// Download the contents of an artifact attached to a report.
+/// The attachment is always served as a download and never rendered inline.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/reports/{report}/attachments/{attachment}",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_attachment_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjReportAttachmentParams>,
+) -> Result<Response<Body>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let (attachment, data) = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, attachment.content_type)
+        .header(
+            http::header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", attachment.name),
+        )
+        .header(http::header::X_CONTENT_TYPE_OPTIONS, "nosniff")
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(data.into())
+        .map_err(Into::into)
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjReportAttachmentParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<(QueryReportAttachment, Vec<u8>), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+    let report_id = get_report_id(context, &query_project, path_params.report).await?;
+
+    let attachment =
+        QueryReportAttachment::from_uuid(conn_lock!(context), report_id, path_params.attachment)?;
+    let data = attachment.data(context).await?;
+    Ok((attachment, data))
+}
+
+/// Delete an attachment for a report
+///
+/// Delete an artifact attached to a report.
+/// The user must have `delete` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/reports/{report}/attachments/{attachment}",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_attachment_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjReportAttachmentParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjReportAttachmentParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+    let report_id = get_report_id(context, &query_project, path_params.report).await?;
+
+    let attachment =
+        QueryReportAttachment::from_uuid(conn_lock!(context), report_id, path_params.attachment)?;
+    let is_external = attachment.is_external(conn_lock!(context))?;
+    diesel::delete(
+        schema::report_attachment::table.filter(schema::report_attachment::id.eq(attachment.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(ReportAttachment, attachment.uuid))?;
+
+    if let (true, Some(data_store)) = (is_external, context.database.data_store.as_ref()) {
+        data_store
+            .delete(&attachment.data_store_key())
+            .await
+            .map_err(|e| {
+                issue_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete attachment from data store",
+                    &format!(
+                        "Failed to delete attachment ({uuid}) from data store.",
+                        uuid = attachment.uuid
+                    ),
+                    e,
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
+async fn get_report_id(
+    context: &ApiContext,
+    query_project: &QueryProject,
+    report_uuid: ReportUuid,
+) -> Result<ReportId, HttpError> {
+    QueryReport::belonging_to(query_project)
+        .filter(schema::report::uuid.eq(report_uuid.to_string()))
+        .select(schema::report::id)
+        .first::<ReportId>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Report,
+            (query_project, report_uuid)
+        ))
+}
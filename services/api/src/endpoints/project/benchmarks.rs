@@ -1,9 +1,14 @@
+use std::collections::HashMap;
+
 use bencher_json::{
-    project::benchmark::{JsonNewBenchmark, JsonUpdateBenchmark},
-    BenchmarkName, JsonBenchmark, JsonBenchmarks, JsonDirection, JsonPagination, ResourceId,
+    project::benchmark::{JsonBenchmarkLatest, JsonNewBenchmark, JsonUpdateBenchmark},
+    BenchmarkName, JsonBenchmark, JsonBenchmarks, JsonDirection, JsonMetric, JsonPagination,
+    ResourceId,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
+    dsl::sql,
+    sql_types::{Bool, Double, Nullable},
     BelongingToDsl, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
     TextExpressionMethods,
 };
@@ -14,24 +19,67 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
     },
     error::{resource_conflict_err, resource_not_found_err},
     model::{
         project::{
-            benchmark::{InsertBenchmark, QueryBenchmark, UpdateBenchmark},
+            benchmark::{BenchmarkId, InsertBenchmark, QueryBenchmark, UpdateBenchmark},
+            branch::{BranchId, QueryBranch},
+            measure::{MeasureId, QueryMeasure},
+            testbed::{QueryTestbed, TestbedId},
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+        slug::insert_with_slug,
+    },
 };
 
+use latest::BenchmarkLatest;
+
+// The most recent metric for each benchmark, branch, testbed, and measure.
+// Only reports on the current head of each branch are included.
+// Archived branches, testbeds, and measures are excluded unless explicitly requested.
+const LATEST_METRICS_QUERY: &str = "
+SELECT benchmark_id, branch, testbed, measure, report, start_time, end_time, metric, value, lower_value, upper_value
+FROM (
+    SELECT
+        report_benchmark.benchmark_id AS benchmark_id,
+        branch.uuid AS branch,
+        testbed.uuid AS testbed,
+        measure.uuid AS measure,
+        report.uuid AS report,
+        report.start_time AS start_time,
+        report.end_time AS end_time,
+        metric.uuid AS metric,
+        metric.value AS value,
+        metric.lower_value AS lower_value,
+        metric.upper_value AS upper_value,
+        ROW_NUMBER() OVER (
+            PARTITION BY report_benchmark.benchmark_id, branch.id, testbed.id, measure.id
+            ORDER BY report.start_time DESC, report.id DESC, report_benchmark.iteration DESC
+        ) AS latest
+    FROM metric
+    INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+    INNER JOIN report ON report.id = report_benchmark.report_id
+    INNER JOIN branch ON branch.head_id = report.head_id
+    INNER JOIN testbed ON testbed.id = report.testbed_id
+    INNER JOIN measure ON measure.id = metric.measure_id
+    WHERE report.project_id = ?1
+        AND ((?2 IS NULL AND branch.archived IS NULL) OR branch.id = ?2)
+        AND ((?3 IS NULL AND testbed.archived IS NULL) OR testbed.id = ?3)
+        AND ((?4 IS NULL AND measure.archived IS NULL) OR measure.id = ?4)
+)
+WHERE latest = 1
+ORDER BY branch, testbed, measure
+";
+
 #[derive(Deserialize, JsonSchema)]
 pub struct ProjBenchmarksParams {
     /// The slug or UUID for a project.
@@ -46,6 +94,12 @@ pub enum ProjBenchmarksSort {
     /// Sort by benchmark name.
     #[default]
     Name,
+    /// Sort by benchmark creation date time.
+    Created,
+    /// Sort by the value of the most recent metric for the benchmark.
+    /// Only metrics for the `branch`, `testbed`, and `measure` are considered, if specified.
+    /// Benchmarks without any metrics are always sorted last.
+    Latest,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -57,21 +111,30 @@ pub struct ProjBenchmarksQuery {
     /// If set to `true`, only returns archived benchmarks.
     /// If not set or set to `false`, only returns non-archived benchmarks.
     pub archived: Option<bool>,
+    /// If set to `true`, include the most recent metric for each benchmark
+    /// for every branch, testbed, and measure.
+    pub with_latest: Option<bool>,
+    /// Only include the most recent metrics for this branch slug or UUID.
+    /// This applies to both `with_latest` and sorting by `latest`.
+    pub branch: Option<ResourceId>,
+    /// Only include the most recent metrics for this testbed slug or UUID.
+    /// This applies to both `with_latest` and sorting by `latest`.
+    pub testbed: Option<ResourceId>,
+    /// Only include the most recent metrics for this measure slug or UUID.
+    /// This applies to both `with_latest` and sorting by `latest`.
+    pub measure: Option<ResourceId>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/benchmarks",
-    tags = ["projects", "benchmarks"]
-}]
-pub async fn proj_benchmarks_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjBenchmarksParams>,
-    _pagination_params: Query<ProjBenchmarksPagination>,
-    _query_params: Query<ProjBenchmarksQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_benchmarks_options,
+    "/v0/projects/{project}/benchmarks",
+    ["projects", "benchmarks"],
+    (
+        _path_params: Path<ProjBenchmarksParams>,
+        _pagination_params: Query<ProjBenchmarksPagination>,
+        _query_params: Query<ProjBenchmarksQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List benchmarks for a project
@@ -80,6 +143,9 @@ pub async fn proj_benchmarks_options(
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 /// By default, the benchmarks are sorted in alphabetical order by name.
+/// They can also be sorted by creation date time or by the value of their most recent metric.
+/// If `with_latest` is set to `true`, then the most recent metric on the current head of each branch
+/// is included for each benchmark, optionally filtered by `branch`, `testbed`, and `measure`.
 /// The HTTP response header `X-Total-Count` contains the total number of benchmarks.
 #[endpoint {
     method = GET,
@@ -93,11 +159,13 @@ pub async fn proj_benchmarks_get(
     query_params: Query<ProjBenchmarksQuery>,
 ) -> Result<ResponseOk<JsonBenchmarks>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -105,6 +173,7 @@ pub async fn proj_benchmarks_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -122,29 +191,54 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let benchmarks = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryBenchmark>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Benchmark,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let latest_filter = LatestFilter::new(context, &query_project, &query_params).await?;
+
+    let benchmarks = get_ls_query(
+        &query_project,
+        &pagination_params,
+        &query_params,
+        latest_filter,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryBenchmark>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Benchmark,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
+
+    let mut latest = if let Some(true) = query_params.with_latest {
+        Some(get_latest(context, &query_project, latest_filter).await?)
+    } else {
+        None
+    };
 
     // Drop connection lock before iterating
     let json_benchmarks = benchmarks
         .into_iter()
-        .map(|benchmark| benchmark.into_json_for_project(&query_project))
+        .map(|benchmark| {
+            let benchmark_id = benchmark.id;
+            let mut json_benchmark = benchmark.into_json_for_project(&query_project);
+            if let Some(latest) = latest.as_mut() {
+                json_benchmark.latest = Some(latest.remove(&benchmark_id).unwrap_or_default());
+            }
+            json_benchmark
+        })
         .collect();
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
-        .count()
-        .get_result::<i64>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Plot,
-            (&query_project, &pagination_params, &query_params)
-        ))?
-        .try_into()?;
+    let total_count = get_ls_query(
+        &query_project,
+        &pagination_params,
+        &query_params,
+        latest_filter,
+    )
+    .count()
+    .get_result::<i64>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Plot,
+        (&query_project, &pagination_params, &query_params)
+    ))?
+    .try_into()?;
 
     Ok((json_benchmarks, total_count))
 }
@@ -153,6 +247,7 @@ fn get_ls_query<'q>(
     query_project: &'q QueryProject,
     pagination_params: &ProjBenchmarksPagination,
     query_params: &'q ProjBenchmarksQuery,
+    latest_filter: LatestFilter,
 ) -> schema::benchmark::BoxedQuery<'q, diesel::sqlite::Sqlite> {
     let mut query = QueryBenchmark::belonging_to(&query_project).into_boxed();
 
@@ -179,9 +274,161 @@ fn get_ls_query<'q>(
             Some(JsonDirection::Asc) | None => query.order(schema::benchmark::name.asc()),
             Some(JsonDirection::Desc) => query.order(schema::benchmark::name.desc()),
         },
+        ProjBenchmarksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::benchmark::created.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::benchmark::created.desc()),
+        },
+        ProjBenchmarksSort::Latest => {
+            let latest_value = latest_filter.latest_value_sql();
+            // Benchmarks without any metrics are sorted last, regardless of the direction
+            let has_no_value = sql::<Bool>(&format!("{latest_value} IS NULL"));
+            let latest_value = sql::<Nullable<Double>>(&latest_value);
+            match pagination_params.direction {
+                Some(JsonDirection::Asc) | None => query.order((
+                    has_no_value.asc(),
+                    latest_value.asc(),
+                    schema::benchmark::name.asc(),
+                )),
+                Some(JsonDirection::Desc) => query.order((
+                    has_no_value.asc(),
+                    latest_value.desc(),
+                    schema::benchmark::name.asc(),
+                )),
+            }
+        },
+    }
+}
+
+/// The branch, testbed, and measure used to find the most recent metrics for each benchmark
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_field_names)]
+struct LatestFilter {
+    branch_id: Option<BranchId>,
+    testbed_id: Option<TestbedId>,
+    measure_id: Option<MeasureId>,
+}
+
+impl LatestFilter {
+    async fn new(
+        context: &ApiContext,
+        query_project: &QueryProject,
+        query_params: &ProjBenchmarksQuery,
+    ) -> Result<Self, HttpError> {
+        let project_id = query_project.id;
+        let branch_id = if let Some(branch) = query_params.branch.as_ref() {
+            Some(QueryBranch::from_resource_id(conn_lock!(context), project_id, branch)?.id)
+        } else {
+            None
+        };
+        let testbed_id = if let Some(testbed) = query_params.testbed.as_ref() {
+            Some(QueryTestbed::from_resource_id(conn_lock!(context), project_id, testbed)?.id)
+        } else {
+            None
+        };
+        let measure_id = if let Some(measure) = query_params.measure.as_ref() {
+            Some(QueryMeasure::from_resource_id(conn_lock!(context), project_id, measure)?.id)
+        } else {
+            None
+        };
+        Ok(Self {
+            branch_id,
+            testbed_id,
+            measure_id,
+        })
+    }
+
+    // A correlated subquery for the value of the most recent metric for each benchmark.
+    // The IDs are integers, so they are safe to format directly into the query.
+    fn latest_value_sql(self) -> String {
+        let branch = self.branch_id.map_or_else(
+            || "branch.archived IS NULL".to_owned(),
+            |id| format!("branch.id = {id}"),
+        );
+        let testbed = self.testbed_id.map_or_else(
+            || "testbed.archived IS NULL".to_owned(),
+            |id| format!("testbed.id = {id}"),
+        );
+        let measure = self.measure_id.map_or_else(
+            || "measure.archived IS NULL".to_owned(),
+            |id| format!("measure.id = {id}"),
+        );
+        format!(
+            "(
+    SELECT metric.value
+    FROM metric
+    INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+    INNER JOIN report ON report.id = report_benchmark.report_id
+    INNER JOIN branch ON branch.head_id = report.head_id
+    INNER JOIN testbed ON testbed.id = report.testbed_id
+    INNER JOIN measure ON measure.id = metric.measure_id
+    WHERE report_benchmark.benchmark_id = benchmark.id
+        AND {branch}
+        AND {testbed}
+        AND {measure}
+    ORDER BY report.start_time DESC, report.id DESC, report_benchmark.iteration DESC
+    LIMIT 1
+)"
+        )
     }
 }
 
+// Get the most recent metrics for all benchmarks in the project with a single query
+async fn get_latest(
+    context: &ApiContext,
+    query_project: &QueryProject,
+    latest_filter: LatestFilter,
+) -> Result<HashMap<BenchmarkId, Vec<JsonBenchmarkLatest>>, HttpError> {
+    let project_id = query_project.id;
+    let LatestFilter {
+        branch_id,
+        testbed_id,
+        measure_id,
+    } = latest_filter;
+
+    let latest_metrics = diesel::sql_query(LATEST_METRICS_QUERY)
+        .bind::<diesel::sql_types::Integer, _>(project_id)
+        .bind::<Nullable<diesel::sql_types::Integer>, _>(branch_id)
+        .bind::<Nullable<diesel::sql_types::Integer>, _>(testbed_id)
+        .bind::<Nullable<diesel::sql_types::Integer>, _>(measure_id)
+        .load::<BenchmarkLatest>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Metric, query_project))?;
+
+    let mut latest = HashMap::<BenchmarkId, Vec<JsonBenchmarkLatest>>::new();
+    for latest_metric in latest_metrics {
+        let BenchmarkLatest {
+            benchmark_id,
+            branch,
+            testbed,
+            measure,
+            report,
+            start_time,
+            end_time,
+            metric,
+            value,
+            lower_value,
+            upper_value,
+        } = latest_metric;
+        latest
+            .entry(benchmark_id)
+            .or_default()
+            .push(JsonBenchmarkLatest {
+                branch,
+                testbed,
+                measure,
+                report,
+                start_time,
+                end_time,
+                metric: JsonMetric {
+                    uuid: metric,
+                    value: value.into(),
+                    lower_value: lower_value.map(Into::into),
+                    upper_value: upper_value.map(Into::into),
+                },
+            });
+    }
+    Ok(latest)
+}
+
 /// Create a benchmark
 ///
 /// Create a benchmark for a project.
@@ -223,12 +470,10 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    let insert_benchmark =
+    let mut insert_benchmark =
         InsertBenchmark::from_json(conn_lock!(context), query_project.id, json_benchmark)?;
 
-    diesel::insert_into(schema::benchmark::table)
-        .values(&insert_benchmark)
-        .execute(conn_lock!(context))
+    insert_with_slug!(conn_lock!(context), benchmark, insert_benchmark)
         .map_err(resource_conflict_err!(Benchmark, insert_benchmark))?;
 
     schema::benchmark::table
@@ -246,17 +491,12 @@ pub struct ProjBenchmarkParams {
     pub benchmark: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/benchmarks/{benchmark}",
-    tags = ["projects", "benchmarks"]
-}]
-pub async fn proj_benchmark_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjBenchmarkParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    proj_benchmark_options,
+    "/v0/projects/{project}/benchmarks/{benchmark}",
+    ["projects", "benchmarks"],
+    (_path_params: Path<ProjBenchmarkParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a benchmark
@@ -411,3 +651,38 @@ async fn delete_inner(
 
     Ok(())
 }
+
+// The `QueryableByName` derive macro generates fully qualified paths
+#[allow(unused_qualifications)]
+mod latest {
+    use bencher_json::{BranchUuid, DateTime, MeasureUuid, MetricUuid, ReportUuid, TestbedUuid};
+    use diesel::sql_types::{BigInt, Double, Integer, Nullable, Text};
+
+    use crate::model::project::benchmark::BenchmarkId;
+
+    #[derive(Debug, diesel::QueryableByName)]
+    pub struct BenchmarkLatest {
+        #[diesel(sql_type = Integer)]
+        pub benchmark_id: BenchmarkId,
+        #[diesel(sql_type = Text)]
+        pub branch: BranchUuid,
+        #[diesel(sql_type = Text)]
+        pub testbed: TestbedUuid,
+        #[diesel(sql_type = Text)]
+        pub measure: MeasureUuid,
+        #[diesel(sql_type = Text)]
+        pub report: ReportUuid,
+        #[diesel(sql_type = BigInt)]
+        pub start_time: DateTime,
+        #[diesel(sql_type = BigInt)]
+        pub end_time: DateTime,
+        #[diesel(sql_type = Text)]
+        pub metric: MetricUuid,
+        #[diesel(sql_type = Double)]
+        pub value: f64,
+        #[diesel(sql_type = Nullable<Double>)]
+        pub lower_value: Option<f64>,
+        #[diesel(sql_type = Nullable<Double>)]
+        pub upper_value: Option<f64>,
+    }
+}
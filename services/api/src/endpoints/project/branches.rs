@@ -1,6 +1,7 @@
 use bencher_json::{
-    project::branch::JsonUpdateBranch, BranchName, HeadUuid, JsonBranch, JsonBranches,
-    JsonDirection, JsonNewBranch, JsonPagination, ResourceId,
+    project::branch::{JsonMergeBranch, JsonUpdateBranch},
+    BranchName, DateTime, HeadUuid, JsonBranch, JsonBranches, JsonDirection, JsonNewBranch,
+    JsonPagination, ResourceId, Slug,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -8,6 +9,7 @@ use diesel::{
     TextExpressionMethods,
 };
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use slog::Logger;
@@ -15,24 +17,27 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseDeleted, ResponseOk, ResponseUpserted,
     },
     error::{
-        resource_conflict_err, resource_not_found_err, resource_not_found_error, BencherResource,
+        bad_request_error, resource_conflict_err, resource_conflict_error, resource_not_found_err,
+        resource_not_found_error, BencherResource,
     },
     model::{
         project::{
-            branch::{head::QueryHead, InsertBranch, QueryBranch, UpdateBranch},
+            branch::{
+                head::QueryHead, protection::BranchAuth, InsertBranch, QueryBranch, UpdateBranch,
+            },
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -62,19 +67,16 @@ pub struct ProjBranchesQuery {
     pub archived: Option<bool>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/branches",
-    tags = ["projects", "branches"]
-}]
-pub async fn proj_branches_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjBranchesParams>,
-    _pagination_params: Query<ProjBranchesPagination>,
-    _query_params: Query<ProjBranchesQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_branches_options,
+    "/v0/projects/{project}/branches",
+    ["projects", "branches"],
+    (
+        _path_params: Path<ProjBranchesParams>,
+        _pagination_params: Query<ProjBranchesPagination>,
+        _query_params: Query<ProjBranchesQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List branches for a project
@@ -96,11 +98,13 @@ pub async fn proj_branches_get(
     query_params: Query<ProjBranchesQuery>,
 ) -> Result<ResponseOk<JsonBranches>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -108,6 +112,7 @@ pub async fn proj_branches_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -195,6 +200,8 @@ fn get_ls_query<'q>(
 /// Create a branch
 ///
 /// Create a branch for a project.
+/// If `upsert` is set to `true` and a branch with the same name or slug already exists,
+/// then the existing branch is returned instead with a `200 OK` status and its start point is left unchanged.
 /// The user must have `create` permissions for the project.
 #[endpoint {
     method = POST,
@@ -206,9 +213,9 @@ pub async fn proj_branch_post(
     bearer_token: BearerToken,
     path_params: Path<ProjBranchesParams>,
     body: TypedBody<JsonNewBranch>,
-) -> Result<ResponseCreated<JsonBranch>, HttpError> {
+) -> Result<ResponseUpserted<JsonBranch>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(
+    let (json, created) = post_inner(
         &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
@@ -216,7 +223,7 @@ pub async fn proj_branch_post(
         &auth_user,
     )
     .await?;
-    Ok(Post::auth_response_created(json))
+    Ok(Post::auth_response_upserted(json, created))
 }
 
 async fn post_inner(
@@ -225,7 +232,7 @@ async fn post_inner(
     path_params: ProjBranchesParams,
     json_branch: JsonNewBranch,
     auth_user: &AuthUser,
-) -> Result<JsonBranch, HttpError> {
+) -> Result<(JsonBranch, bool), HttpError> {
     // Verify that the user is allowed
     let query_project = QueryProject::is_allowed(
         conn_lock!(context),
@@ -235,10 +242,38 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    let (query_branch, _query_head) =
-        InsertBranch::from_json(log, context, query_project.id, json_branch).await?;
+    let upsert = json_branch.upsert.unwrap_or_default();
+    let name = json_branch.name.clone();
+    let slug = Slug::unwrap_or_new(&name, json_branch.slug.clone());
+    let existing_branch = if upsert {
+        QueryBranch::from_name_or_slug(conn_lock!(context), query_project.id, name.as_ref(), &slug)?
+    } else {
+        None
+    };
+    if let Some(query_branch) = existing_branch {
+        let json = query_branch.into_json_for_project(conn_lock!(context), &query_project)?;
+        return Ok((json, false));
+    }
+
+    let (query_branch, created) =
+        match InsertBranch::from_json(log, context, query_project.id, json_branch).await {
+            Ok((query_branch, _query_head)) => (query_branch, true),
+            // A concurrent upsert may have created the same branch since it was looked up
+            Err(e) if upsert && e.status_code == StatusCode::CONFLICT => {
+                let query_branch = QueryBranch::from_name_or_slug(
+                    conn_lock!(context),
+                    query_project.id,
+                    name.as_ref(),
+                    &slug,
+                )?
+                .ok_or(e)?;
+                (query_branch, false)
+            },
+            Err(e) => return Err(e),
+        };
 
-    query_branch.into_json_for_project(conn_lock!(context), &query_project)
+    let json = query_branch.into_json_for_project(conn_lock!(context), &query_project)?;
+    Ok((json, created))
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -258,18 +293,12 @@ pub struct ProjBranchQuery {
     pub head: Option<HeadUuid>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/branches/{branch}",
-    tags = ["projects", "branches"]
-}]
-pub async fn proj_branch_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjBranchParams>,
-    _query_params: Query<ProjBranchQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    proj_branch_options,
+    "/v0/projects/{project}/branches/{branch}",
+    ["projects", "branches"],
+    (_path_params: Path<ProjBranchParams>, _query_params: Query<ProjBranchQuery>),
+    [Get, Patch, Delete]
 }
 
 /// View a branch
@@ -342,6 +371,7 @@ async fn get_one_inner(
 ///
 /// Update a branch for a project.
 /// The user must have `edit` permissions for the project.
+/// To change the branch protection, the user must have `manage` permissions for the organization.
 #[endpoint {
     method = PATCH,
     path =  "/v0/projects/{project}/branches/{branch}",
@@ -383,6 +413,8 @@ async fn patch_inner(
 
     let query_branch =
         QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &path_params.branch)?;
+    let branch_auth = BranchAuth::new(&context.rbac, auth_user, &query_project);
+    query_branch.check_protection_change(branch_auth, json_branch.protection)?;
 
     let (query_branch, _query_head) = query_branch
         .update_start_point_if_changed(
@@ -390,6 +422,7 @@ async fn patch_inner(
             context,
             query_project.id,
             json_branch.start_point.as_ref(),
+            branch_auth,
         )
         .await?;
 
@@ -414,6 +447,7 @@ async fn patch_inner(
 /// Delete a branch for a project.
 /// The user must have `delete` permissions for the project.
 /// All reports and thresholds that use this branch must be deleted first!
+/// A branch that is protected from deletion can not be deleted.
 #[endpoint {
     method = DELETE,
     path =  "/v0/projects/{project}/branches/{branch}",
@@ -445,6 +479,7 @@ async fn delete_inner(
 
     let query_branch =
         QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &path_params.branch)?;
+    query_branch.check_protected_delete()?;
 
     diesel::delete(schema::branch::table.filter(schema::branch::id.eq(query_branch.id)))
         .execute(conn_lock!(context))
@@ -452,3 +487,112 @@ async fn delete_inner(
 
     Ok(())
 }
+
+options_endpoint! {
+    proj_branch_merge_options,
+    "/v0/projects/{project}/branches/{branch}/merge",
+    ["projects", "branches"],
+    (_path_params: Path<ProjBranchParams>),
+    [Post]
+}
+
+/// Merge a branch
+///
+/// Mark a branch as merged into a target branch for a project.
+/// Optionally, the most recent metrics for the branch can be grafted onto the target branch
+/// as the version for the merge commit.
+/// This prevents a discontinuity in the target branch history at each merge.
+/// The user must have `edit` permissions for the project.
+/// A branch can only be merged once.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/branches/{branch}/merge",
+    tags = ["projects", "branches"]
+}]
+pub async fn proj_branch_merge_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjBranchParams>,
+    body: TypedBody<JsonMergeBranch>,
+) -> Result<ResponseOk<JsonBranch>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = merge_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn merge_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjBranchParams,
+    json_merge: JsonMergeBranch,
+    auth_user: &AuthUser,
+) -> Result<JsonBranch, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let query_branch =
+        QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &path_params.branch)?;
+    if query_branch.merged.is_some() {
+        return Err(resource_conflict_error(
+            BencherResource::Branch,
+            &query_branch,
+            "Branch has already been merged",
+        ));
+    }
+
+    let target_branch =
+        QueryBranch::from_name_id(conn_lock!(context), query_project.id, &json_merge.target)?;
+    if target_branch.id == query_branch.id {
+        return Err(bad_request_error(format!(
+            "Branch ({}) cannot be merged into itself",
+            query_branch.uuid
+        )));
+    }
+
+    if json_merge.graft.unwrap_or_default() {
+        let branch_auth = BranchAuth::new(&context.rbac, auth_user, &query_project);
+        let grafted = conn_lock!(context, |conn| query_branch.graft(
+            conn,
+            &target_branch,
+            json_merge.hash.as_ref(),
+            branch_auth
+        )?);
+        slog::debug!(
+            log,
+            "Grafted {grafted} reports from branch ({}) onto branch ({})",
+            query_branch.uuid,
+            target_branch.uuid
+        );
+    }
+
+    let timestamp = DateTime::now();
+    diesel::update(schema::branch::table.filter(schema::branch::id.eq(query_branch.id)))
+        .set((
+            schema::branch::merged.eq(timestamp),
+            schema::branch::modified.eq(timestamp),
+        ))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Branch,
+            (&query_branch, &target_branch)
+        ))?;
+
+    conn_lock!(context, |conn| QueryBranch::get(conn, query_branch.id)
+        .map_err(resource_not_found_err!(Branch, query_branch))
+        .and_then(
+            |branch| branch.into_json_for_project(conn, &query_project)
+        ))
+}
@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bencher_json::{
+    project::{
+        alert::AlertStatus,
+        file::{JsonFile, JsonFileBenchmark, JsonFileMetric, JsonTrend},
+    },
+    BenchmarkName, BenchmarkUuid, JsonFiles, JsonNewLocations, ResourceId, ResourceName, Slug,
+};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Get, Put, ResponseOk},
+    error::resource_not_found_err,
+    model::{
+        project::{
+            benchmark_location::InsertBenchmarkLocation, branch::QueryBranch,
+            testbed::QueryTestbed, QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+use latest::FileMetric;
+
+// The two most recent metrics for each located benchmark and measure
+// on the current head of the branch for the testbed.
+// Archived measures are excluded.
+const FILE_METRICS_QUERY: &str = "
+SELECT benchmark, measure, units, value, alert, latest
+FROM (
+    SELECT
+        benchmark.uuid AS benchmark,
+        measure.slug AS measure,
+        measure.units AS units,
+        metric.value AS value,
+        EXISTS (
+            SELECT 1
+            FROM boundary
+            INNER JOIN alert ON alert.boundary_id = boundary.id
+            WHERE boundary.metric_id = metric.id
+                AND alert.status = ?
+        ) AS alert,
+        ROW_NUMBER() OVER (
+            PARTITION BY benchmark.id, measure.id
+            ORDER BY report.start_time DESC, report.id DESC, report_benchmark.iteration DESC
+        ) AS latest
+    FROM metric
+    INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+    INNER JOIN report ON report.id = report_benchmark.report_id
+    INNER JOIN benchmark ON benchmark.id = report_benchmark.benchmark_id
+    INNER JOIN benchmark_location ON benchmark_location.benchmark_id = benchmark.id
+    INNER JOIN measure ON measure.id = metric.measure_id
+    WHERE report.project_id = ?
+        AND report.head_id = ?
+        AND report.testbed_id = ?
+        AND measure.archived IS NULL
+)
+WHERE latest <= 2
+ORDER BY benchmark, measure, latest
+";
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjFilesParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjFilesQuery {
+    /// The slug or UUID for the branch to get the latest metrics from.
+    pub branch: ResourceId,
+    /// The slug or UUID for the testbed to get the latest metrics from.
+    pub testbed: ResourceId,
+    /// Filter by source file path, exact match.
+    pub file: Option<String>,
+}
+
+options_endpoint! {
+    proj_files_options,
+    "/v0/projects/{project}/files",
+    ["projects", "benchmarks"],
+    (_path_params: Path<ProjFilesParams>),
+    [Get, Put]
+}
+
+/// List benchmark status by source file
+///
+/// List the located benchmarks for a project, grouped by source file.
+/// For each benchmark, the latest metric for each measure on the branch and testbed is included,
+/// along with its trend from the previous metric and whether it generated an active alert.
+/// This is intended for editor integrations that annotate benchmarks in their source files.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// Files are sorted by path, and benchmarks are sorted by line number.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/files",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_files_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjFilesParams>,
+    query_params: Query<ProjFilesQuery>,
+) -> Result<ResponseOk<JsonFiles>, HttpError> {
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let json = get_ls_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjFilesParams,
+    query_params: ProjFilesQuery,
+) -> Result<JsonFiles, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+    let query_branch =
+        QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &query_params.branch)?;
+    let query_testbed = QueryTestbed::from_resource_id(
+        conn_lock!(context),
+        query_project.id,
+        &query_params.testbed,
+    )?;
+
+    let mut query = schema::benchmark_location::table
+        .inner_join(schema::benchmark::table)
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .filter(schema::benchmark::archived.is_null())
+        .into_boxed();
+    if let Some(file) = query_params.file.as_ref() {
+        query = query.filter(schema::benchmark_location::file.eq(file));
+    }
+    let locations = query
+        .select((
+            schema::benchmark::uuid,
+            schema::benchmark::name,
+            schema::benchmark_location::file,
+            schema::benchmark_location::line,
+        ))
+        .load::<(BenchmarkUuid, BenchmarkName, String, Option<i32>)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            BenchmarkLocation,
+            (&query_project, &query_params)
+        ))?;
+
+    // A branch without a head has no metrics
+    let file_metrics = if let Some(head_id) = query_branch.head_id {
+        diesel::sql_query(FILE_METRICS_QUERY)
+            .bind::<diesel::sql_types::Integer, _>(AlertStatus::Active)
+            .bind::<diesel::sql_types::Integer, _>(query_project.id)
+            .bind::<diesel::sql_types::Integer, _>(head_id)
+            .bind::<diesel::sql_types::Integer, _>(query_testbed.id)
+            .load::<FileMetric>(conn_lock!(context))
+            .map_err(resource_not_found_err!(
+                Metric,
+                (&query_project, &query_branch, &query_testbed)
+            ))?
+    } else {
+        Vec::new()
+    };
+
+    Ok(into_json_files(locations, file_metrics))
+}
+
+fn into_json_files(
+    locations: Vec<(BenchmarkUuid, BenchmarkName, String, Option<i32>)>,
+    file_metrics: Vec<FileMetric>,
+) -> JsonFiles {
+    // The metrics are ordered from latest to previous for each benchmark and measure
+    let mut benchmark_metrics: HashMap<String, Vec<JsonFileMetric>> = HashMap::new();
+    for file_metric in file_metrics {
+        let metrics = benchmark_metrics.entry(file_metric.benchmark).or_default();
+        if file_metric.latest == 1 {
+            let (Ok(measure), Ok(units)) = (
+                file_metric.measure.parse::<Slug>(),
+                file_metric.units.parse::<ResourceName>(),
+            ) else {
+                continue;
+            };
+            metrics.push(JsonFileMetric {
+                measure,
+                units,
+                value: file_metric.value,
+                previous: None,
+                trend: JsonTrend::New,
+                alert: file_metric.alert,
+            });
+        } else if let Some(metric) = metrics
+            .last_mut()
+            .filter(|metric| metric.measure.as_ref() == file_metric.measure)
+        {
+            metric.previous = Some(file_metric.value);
+            metric.trend = JsonTrend::new(metric.value, metric.previous);
+        }
+    }
+
+    let mut files: BTreeMap<String, Vec<JsonFileBenchmark>> = BTreeMap::new();
+    for (uuid, name, file, line) in locations {
+        let metrics = benchmark_metrics
+            .remove(&uuid.to_string())
+            .unwrap_or_default();
+        files.entry(file).or_default().push(JsonFileBenchmark {
+            uuid,
+            name,
+            line: line.and_then(|line| u32::try_from(line).ok()),
+            metrics,
+        });
+    }
+
+    files
+        .into_iter()
+        .map(|(file, mut benchmarks)| {
+            benchmarks.sort_by(|a, b| {
+                a.line
+                    .cmp(&b.line)
+                    .then_with(|| a.name.as_ref().cmp(b.name.as_ref()))
+            });
+            JsonFile { file, benchmarks }
+        })
+        .collect()
+}
+
+/// Set benchmark source locations
+///
+/// Set the source file and line number for benchmarks in a project.
+/// If a benchmark does not exist, it will be created.
+/// If a benchmark already has a source location, it will be replaced.
+/// The user must have `edit` permissions for the project.
+/// Returns all of the located benchmarks for the project, without any metrics.
+#[endpoint {
+    method = PUT,
+    path =  "/v0/projects/{project}/files",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_files_put(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjFilesParams>,
+    body: TypedBody<JsonNewLocations>,
+) -> Result<ResponseOk<JsonFiles>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = put_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Put::auth_response_ok(json))
+}
+
+async fn put_inner(
+    context: &ApiContext,
+    path_params: ProjFilesParams,
+    json_locations: JsonNewLocations,
+    auth_user: &AuthUser,
+) -> Result<JsonFiles, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    for location in json_locations.locations {
+        InsertBenchmarkLocation::from_json(context, query_project.id, location)
+            .await?
+            .upsert(context)
+            .await?;
+    }
+
+    let locations = schema::benchmark_location::table
+        .inner_join(schema::benchmark::table)
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .filter(schema::benchmark::archived.is_null())
+        .select((
+            schema::benchmark::uuid,
+            schema::benchmark::name,
+            schema::benchmark_location::file,
+            schema::benchmark_location::line,
+        ))
+        .load::<(BenchmarkUuid, BenchmarkName, String, Option<i32>)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(BenchmarkLocation, &query_project))?;
+
+    Ok(into_json_files(locations, Vec::new()))
+}
+
+// The `QueryableByName` derive macro generates fully qualified paths
+#[allow(unused_qualifications)]
+mod latest {
+    use diesel::sql_types::{BigInt, Bool, Double, Text};
+
+    #[derive(Debug, diesel::QueryableByName)]
+    pub struct FileMetric {
+        #[diesel(sql_type = Text)]
+        pub benchmark: String,
+        #[diesel(sql_type = Text)]
+        pub measure: String,
+        #[diesel(sql_type = Text)]
+        pub units: String,
+        #[diesel(sql_type = Double)]
+        pub value: f64,
+        #[diesel(sql_type = Bool)]
+        pub alert: bool,
+        #[diesel(sql_type = BigInt)]
+        pub latest: i64,
+    }
+}
@@ -0,0 +1,304 @@
+use bencher_json::{
+    project::integration::JsonUpdateIntegration, IntegrationUuid, JsonIntegration,
+    JsonIntegrations, JsonNewIntegration, ResourceId,
+};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            integration::{InsertIntegration, QueryIntegration, UpdateIntegration},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjIntegrationsParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+options_endpoint! {
+    proj_integrations_options,
+    "/v0/projects/{project}/integrations",
+    ["projects", "integrations"],
+    (_path_params: Path<ProjIntegrationsParams>),
+    [Get, Post]
+}
+
+/// List integrations for a project
+///
+/// List all issue tracker integrations for a project.
+/// The user must have `view` permissions for the project.
+/// The API tokens for the integrations are never returned.
+/// Integrations are sorted by creation date.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/integrations",
+    tags = ["projects", "integrations"]
+}]
+pub async fn proj_integrations_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjIntegrationsParams>,
+) -> Result<ResponseOk<JsonIntegrations>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjIntegrationsParams,
+    auth_user: &AuthUser,
+) -> Result<JsonIntegrations, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    Ok(
+        QueryIntegration::all_for_project(conn_lock!(context), &query_project)?
+            .into_iter()
+            .map(|integration| integration.into_json_for_project(&query_project))
+            .collect(),
+    )
+}
+
+/// Create an integration
+///
+/// Create an issue tracker integration for a project.
+/// When a critical alert is generated for the project, an issue is opened with the integration.
+/// When a later report no longer generates an alert for the same threshold and benchmark,
+/// the alert is resolved and the issue is closed.
+/// The issue is also closed if the alert is dismissed or silenced.
+/// The user must have `manage` permissions for the project.
+/// Opening and closing issues is only available on Bencher Plus.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/integrations",
+    tags = ["projects", "integrations"]
+}]
+pub async fn proj_integration_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjIntegrationsParams>,
+    body: TypedBody<JsonNewIntegration>,
+) -> Result<ResponseCreated<JsonIntegration>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjIntegrationsParams,
+    json_integration: JsonNewIntegration,
+    auth_user: &AuthUser,
+) -> Result<JsonIntegration, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let insert_integration =
+        InsertIntegration::from_json(&context.encryption, query_project.id, json_integration)?;
+    diesel::insert_into(schema::integration::table)
+        .values(&insert_integration)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Integration,
+            (&query_project, insert_integration.uuid)
+        ))?;
+
+    QueryIntegration::get_with_uuid(conn_lock!(context), &query_project, insert_integration.uuid)
+        .map(|integration| integration.into_json_for_project(&query_project))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjIntegrationParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for an integration.
+    pub integration: IntegrationUuid,
+}
+
+options_endpoint! {
+    proj_integration_options,
+    "/v0/projects/{project}/integrations/{integration}",
+    ["projects", "integrations"],
+    (_path_params: Path<ProjIntegrationParams>),
+    [Get, Patch, Delete]
+}
+
+/// View an integration
+///
+/// View an issue tracker integration for a project.
+/// The user must have `view` permissions for the project.
+/// The API token for the integration is never returned.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/integrations/{integration}",
+    tags = ["projects", "integrations"]
+}]
+pub async fn proj_integration_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjIntegrationParams>,
+) -> Result<ResponseOk<JsonIntegration>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjIntegrationParams,
+    auth_user: &AuthUser,
+) -> Result<JsonIntegration, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    QueryIntegration::get_with_uuid(conn_lock!(context), &query_project, path_params.integration)
+        .map(|integration| integration.into_json_for_project(&query_project))
+}
+
+/// Update an integration
+///
+/// Update an issue tracker integration for a project.
+/// The user must have `manage` permissions for the project.
+/// Issues that have already been opened are not changed.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/integrations/{integration}",
+    tags = ["projects", "integrations"]
+}]
+pub async fn proj_integration_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjIntegrationParams>,
+    body: TypedBody<JsonUpdateIntegration>,
+) -> Result<ResponseOk<JsonIntegration>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjIntegrationParams,
+    json_integration: JsonUpdateIntegration,
+    auth_user: &AuthUser,
+) -> Result<JsonIntegration, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_integration = QueryIntegration::get_with_uuid(
+        conn_lock!(context),
+        &query_project,
+        path_params.integration,
+    )?;
+    let update_integration = UpdateIntegration::from_json(&context.encryption, json_integration)?;
+    diesel::update(
+        schema::integration::table.filter(schema::integration::id.eq(query_integration.id)),
+    )
+    .set(&update_integration)
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(Integration, query_integration.uuid))?;
+
+    QueryIntegration::get(conn_lock!(context), query_integration.id)
+        .map(|integration| integration.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Integration, query_integration.uuid))
+}
+
+/// Delete an integration
+///
+/// Delete an issue tracker integration for a project.
+/// The user must have `manage` permissions for the project.
+/// Issues that have already been opened are left as is.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/integrations/{integration}",
+    tags = ["projects", "integrations"]
+}]
+pub async fn proj_integration_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjIntegrationParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjIntegrationParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_integration = QueryIntegration::get_with_uuid(
+        conn_lock!(context),
+        &query_project,
+        path_params.integration,
+    )?;
+
+    diesel::delete(
+        schema::integration::table.filter(schema::integration::id.eq(query_integration.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(Integration, query_integration.uuid))?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use bencher_json::{
     project::measure::JsonUpdateMeasure, JsonDirection, JsonMeasure, JsonMeasures, JsonNewMeasure,
-    JsonPagination, ResourceId, ResourceName,
+    JsonPagination, ResourceId, ResourceName, Slug,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -8,17 +8,15 @@ use diesel::{
     TextExpressionMethods,
 };
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseDeleted, ResponseOk, ResponseUpserted,
     },
     error::{resource_conflict_err, resource_not_found_err},
     model::{
@@ -29,7 +27,11 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+        slug::insert_with_slug,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -59,19 +61,16 @@ pub struct ProjMeasuresQuery {
     pub archived: Option<bool>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/measures",
-    tags = ["projects", "measures"]
-}]
-pub async fn proj_measures_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjMeasuresParams>,
-    _pagination_params: Query<ProjMeasuresPagination>,
-    _query_params: Query<ProjMeasuresQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_measures_options,
+    "/v0/projects/{project}/measures",
+    ["projects", "measures"],
+    (
+        _path_params: Path<ProjMeasuresParams>,
+        _pagination_params: Query<ProjMeasuresPagination>,
+        _query_params: Query<ProjMeasuresQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List measures for a project
@@ -93,11 +92,13 @@ pub async fn proj_measures_get(
     query_params: Query<ProjMeasuresQuery>,
 ) -> Result<ResponseOk<JsonMeasures>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -105,6 +106,7 @@ pub async fn proj_measures_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -185,6 +187,8 @@ fn get_ls_query<'q>(
 /// Create a measure
 ///
 /// Create a measure for a project.
+/// If `upsert` is set to `true` and a measure with the same name or slug already exists,
+/// then the existing measure is returned instead with a `200 OK` status.
 /// The user must have `create` permissions for the project.
 #[endpoint {
     method = POST,
@@ -196,16 +200,16 @@ pub async fn proj_measure_post(
     bearer_token: BearerToken,
     path_params: Path<ProjMeasuresParams>,
     body: TypedBody<JsonNewMeasure>,
-) -> Result<ResponseCreated<JsonMeasure>, HttpError> {
+) -> Result<ResponseUpserted<JsonMeasure>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(
+    let (json, created) = post_inner(
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
         &auth_user,
     )
     .await?;
-    Ok(Post::auth_response_created(json))
+    Ok(Post::auth_response_upserted(json, created))
 }
 
 async fn post_inner(
@@ -213,7 +217,7 @@ async fn post_inner(
     path_params: ProjMeasuresParams,
     json_measure: JsonNewMeasure,
     auth_user: &AuthUser,
-) -> Result<JsonMeasure, HttpError> {
+) -> Result<(JsonMeasure, bool), HttpError> {
     // Verify that the user is allowed
     let query_project = QueryProject::is_allowed(
         conn_lock!(context),
@@ -223,18 +227,54 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    let insert_measure =
+    let upsert = json_measure.upsert.unwrap_or_default();
+    let name = json_measure.name.clone();
+    let slug = Slug::unwrap_or_new(&name, json_measure.slug.clone());
+    let existing_measure = if upsert {
+        QueryMeasure::from_name_or_slug(
+            conn_lock!(context),
+            query_project.id,
+            name.as_ref(),
+            &slug,
+        )?
+    } else {
+        None
+    };
+    if let Some(query_measure) = existing_measure {
+        return Ok((query_measure.into_json_for_project(&query_project), false));
+    }
+
+    let mut insert_measure =
         InsertMeasure::from_json(conn_lock!(context), query_project.id, json_measure)?;
 
-    diesel::insert_into(schema::measure::table)
-        .values(&insert_measure)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Measure, insert_measure))?;
+    // The connection lock must be released before looking up an existing measure
+    let insert_result = insert_with_slug!(
+        conn_lock!(context),
+        measure,
+        insert_measure,
+        upsert = upsert
+    )
+    .map_err(resource_conflict_err!(Measure, insert_measure));
+    match insert_result {
+        Ok(_) => {},
+        // A concurrent upsert may have created the same measure since it was looked up
+        Err(e) if upsert && e.status_code == StatusCode::CONFLICT => {
+            let query_measure = QueryMeasure::from_name_or_slug(
+                conn_lock!(context),
+                query_project.id,
+                name.as_ref(),
+                &slug,
+            )?
+            .ok_or(e)?;
+            return Ok((query_measure.into_json_for_project(&query_project), false));
+        },
+        Err(e) => return Err(e),
+    }
 
     schema::measure::table
         .filter(schema::measure::uuid.eq(&insert_measure.uuid))
         .first::<QueryMeasure>(conn_lock!(context))
-        .map(|measure| measure.into_json_for_project(&query_project))
+        .map(|measure| (measure.into_json_for_project(&query_project), true))
         .map_err(resource_not_found_err!(Measure, insert_measure))
 }
 
@@ -246,17 +286,12 @@ pub struct ProjMeasureParams {
     pub measure: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/measures/{measure}",
-    tags = ["projects", "measures"]
-}]
-pub async fn proj_measure_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjMeasureParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    proj_measure_options,
+    "/v0/projects/{project}/measures/{measure}",
+    ["projects", "measures"],
+    (_path_params: Path<ProjMeasureParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a measure
@@ -1,22 +1,22 @@
 use bencher_json::{
-    project::report::Iteration, DateTime, JsonOneMetric, MetricUuid, ReportUuid, ResourceId,
+    project::report::{Adapter, Iteration, JsonReportSettings},
+    DateTime, JsonNewMetric, JsonNewOneMetric, JsonNewReport, JsonOneMetric, JsonReport,
+    MetricUuid, ReportUuid, ReportVersion, ResourceId,
 };
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
 };
-use dropshot::{endpoint, HttpError, Path, RequestContext};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
-    error::resource_not_found_err,
+    endpoints::endpoint::{options_endpoint, Get, Post, ResponseCreated, ResponseOk},
+    error::{issue_error, resource_not_found_err},
     model::{
         project::{
             benchmark::QueryBenchmark,
@@ -29,32 +29,131 @@ use crate::{
             },
             QueryProject,
         },
-        user::auth::{AuthUser, PubBearerToken},
+        user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema, view,
 };
 
-use super::perf::threshold_model_alert;
+use super::{perf::threshold_model_alert, reports::ProjReportsParams};
 
 #[derive(Deserialize, JsonSchema)]
-pub struct ProjMetricParams {
+pub struct ProjMetricsParams {
     /// The slug or UUID for a project.
     pub project: ResourceId,
-    /// The UUID for a metric.
-    pub metric: MetricUuid,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+options_endpoint! {
+    proj_metrics_options,
+    "/v0/projects/{project}/metrics",
+    ["projects", "metrics"],
+    (_path_params: Path<ProjMetricsParams>),
+    [Post]
+}
+
+/// Create a metric
+///
+/// Create a single metric for a project.
+/// This is a lightweight alternative to creating a full report,
+/// useful for ad-hoc measurements from scripts or embedded devices.
+/// A new report is created that contains only the submitted metric.
+/// The branch, testbed, benchmark, and measure will be created if they do not exist.
+/// The user must have `create` permissions for the project.
 #[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/metrics/{metric}",
+    method = POST,
+    path =  "/v0/projects/{project}/metrics",
     tags = ["projects", "metrics"]
 }]
-pub async fn proj_metric_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjMetricParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+pub async fn proj_metric_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMetricsParams>,
+    body: TypedBody<JsonNewOneMetric>,
+) -> Result<ResponseCreated<JsonReport>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let ProjMetricsParams { project } = path_params.into_inner();
+    let json_report = new_report(body.into_inner())?;
+    let json = super::reports::post_inner(
+        &rqctx.log,
+        rqctx.context(),
+        ProjReportsParams { project },
+        json_report,
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+fn new_report(json_metric: JsonNewOneMetric) -> Result<JsonNewReport, HttpError> {
+    let JsonNewOneMetric {
+        branch,
+        hash,
+        testbed,
+        environment,
+        benchmark,
+        measure,
+        value,
+        lower_value,
+        upper_value,
+        start_time,
+        end_time,
+    } = json_metric;
+    let metric = JsonNewMetric {
+        value,
+        lower_value,
+        upper_value,
+    };
+    let results = JsonNewMetric::results(vec![(benchmark, vec![(measure, metric)])]);
+    let results = serde_json::to_string(&results).map_err(|e| {
+        issue_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to serialize metric results",
+            &format!("Failed to serialize metric results ({results:?}) for a new report."),
+            e,
+        )
+    })?;
+
+    let start_time = start_time.unwrap_or_else(DateTime::now);
+    let end_time = end_time.unwrap_or(start_time);
+    Ok(JsonNewReport {
+        version: Some(ReportVersion::CURRENT),
+        branch,
+        hash,
+        start_point: None,
+        testbed,
+        environment,
+        thresholds: None,
+        start_time,
+        end_time,
+        results: vec![results],
+        runner_results: None,
+        settings: Some(JsonReportSettings {
+            adapter: Some(Adapter::Json),
+            average: None,
+            fold: None,
+            pairs: None,
+            raw: None,
+            duration: None,
+            base_hash: None,
+            results_per_iteration: None,
+        }),
+        failure: None,
+    })
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMetricParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a metric.
+    pub metric: MetricUuid,
+}
+
+options_endpoint! {
+    proj_metric_options,
+    "/v0/projects/{project}/metrics/{metric}",
+    ["projects", "metrics"],
+    (_path_params: Path<ProjMetricParams>),
+    [Get]
 }
 
 /// View a metric
@@ -137,9 +236,10 @@ async fn get_one_inner(
                     schema::threshold::id,
                     schema::threshold::uuid,
                     schema::threshold::project_id,
-                    schema::threshold::measure_id,
                     schema::threshold::branch_id,
                     schema::threshold::testbed_id,
+                    schema::threshold::measure_id,
+                    schema::threshold::benchmark,
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
@@ -154,6 +254,8 @@ async fn get_one_inner(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::lower_critical,
+                    schema::model::upper_critical,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -162,6 +264,8 @@ async fn get_one_inner(
                     schema::alert::uuid,
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
+                    schema::alert::kind,
+                    schema::alert::severity,
                     schema::alert::status,
                     schema::alert::modified,
                 ).nullable(),
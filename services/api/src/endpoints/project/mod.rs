@@ -1,12 +1,22 @@
 pub mod alerts;
 pub mod allowed;
+pub mod attachments;
 pub mod benchmarks;
 pub mod branches;
+pub mod files;
+pub mod integrations;
 pub mod measures;
 pub mod metrics;
+pub mod openmetrics;
 pub mod perf;
 pub mod plots;
 pub mod projects;
 pub mod reports;
+pub mod run;
+pub mod schema;
+pub mod templates;
 pub mod testbeds;
+pub mod threshold_changes;
 pub mod thresholds;
+pub mod tokens;
+pub mod webhooks;
@@ -0,0 +1,218 @@
+use std::fmt::Write as _;
+
+use bencher_json::ResourceId;
+use diesel::RunQueryDsl;
+use dropshot::{endpoint, Body, HttpError, Path, RequestContext};
+use http::{Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::options_endpoint,
+    error::{issue_error, resource_not_found_err},
+    model::{
+        project::QueryProject,
+        user::auth::{AuthUser, PubBearerToken},
+    },
+};
+
+use latest::LatestMetric;
+
+const OPENMETRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+// The most recent metric for each branch, testbed, benchmark, and measure.
+// Only reports on the current head of each branch are included,
+// and anything that has been archived is excluded.
+const LATEST_METRICS_QUERY: &str = "
+SELECT branch, testbed, benchmark, measure, units, value, lower_value, upper_value, timestamp
+FROM (
+    SELECT
+        branch.slug AS branch,
+        testbed.slug AS testbed,
+        benchmark.name AS benchmark,
+        measure.slug AS measure,
+        measure.units AS units,
+        metric.value AS value,
+        metric.lower_value AS lower_value,
+        metric.upper_value AS upper_value,
+        report.end_time AS timestamp,
+        ROW_NUMBER() OVER (
+            PARTITION BY branch.id, testbed.id, benchmark.id, measure.id
+            ORDER BY report.start_time DESC, report.id DESC, report_benchmark.iteration DESC
+        ) AS latest
+    FROM metric
+    INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+    INNER JOIN report ON report.id = report_benchmark.report_id
+    INNER JOIN branch ON branch.head_id = report.head_id
+    INNER JOIN testbed ON testbed.id = report.testbed_id
+    INNER JOIN benchmark ON benchmark.id = report_benchmark.benchmark_id
+    INNER JOIN measure ON measure.id = metric.measure_id
+    WHERE report.project_id = ?
+        AND branch.archived IS NULL
+        AND testbed.archived IS NULL
+        AND benchmark.archived IS NULL
+        AND measure.archived IS NULL
+)
+WHERE latest = 1
+ORDER BY branch, testbed, benchmark, measure
+";
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjOpenMetricsParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+options_endpoint! {
+    proj_openmetrics_options,
+    "/v0/projects/{project}/openmetrics",
+    ["projects", "metrics"],
+    (_path_params: Path<ProjOpenMetricsParams>),
+    [Get]
+}
+
+/// Export the latest project metrics in the `OpenMetrics` format
+///
+/// Export the most recent metric for each branch, testbed, benchmark, and measure in a project
+/// using the `OpenMetrics` text format.
+/// This endpoint can be scraped by Prometheus in order to chart Bencher data in existing dashboards.
+/// Only reports on the current head of each branch are included, and archived dimensions are excluded.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/openmetrics",
+    tags = ["projects", "metrics"]
+}]
+pub async fn proj_openmetrics_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjOpenMetricsParams>,
+) -> Result<Response<Body>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let openmetrics = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(openmetrics.into())
+        .map_err(Into::into)
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjOpenMetricsParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<String, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let latest_metrics = diesel::sql_query(LATEST_METRICS_QUERY)
+        .bind::<diesel::sql_types::Integer, _>(query_project.id)
+        .load::<LatestMetric>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Metric, &query_project))?;
+
+    openmetrics(&query_project, &latest_metrics).map_err(|e| {
+        issue_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to write OpenMetrics",
+            &format!("Failed to write OpenMetrics for project: {query_project:?}"),
+            e,
+        )
+    })
+}
+
+// The metric name, help text, and value for each metric family
+type MetricFamily = (&'static str, &'static str, fn(&LatestMetric) -> Option<f64>);
+
+fn openmetrics(
+    query_project: &QueryProject,
+    latest_metrics: &[LatestMetric],
+) -> Result<String, std::fmt::Error> {
+    let mut openmetrics = String::new();
+    let families: [MetricFamily; 3] = [
+        ("bencher_metric", "The latest metric value", |metric| {
+            Some(metric.value)
+        }),
+        (
+            "bencher_metric_lower_value",
+            "The latest metric lower value",
+            |metric| metric.lower_value,
+        ),
+        (
+            "bencher_metric_upper_value",
+            "The latest metric upper value",
+            |metric| metric.upper_value,
+        ),
+    ];
+    for (name, help, value) in families {
+        writeln!(openmetrics, "# TYPE {name} gauge")?;
+        writeln!(openmetrics, "# HELP {name} {help}.")?;
+        for metric in latest_metrics {
+            let Some(value) = value(metric) else {
+                continue;
+            };
+            writeln!(
+                openmetrics,
+                "{name}{{project=\"{project}\",branch=\"{branch}\",testbed=\"{testbed}\",benchmark=\"{benchmark}\",measure=\"{measure}\",units=\"{units}\"}} {value} {timestamp}",
+                project = escape_label(query_project.slug.as_ref()),
+                branch = escape_label(&metric.branch),
+                testbed = escape_label(&metric.testbed),
+                benchmark = escape_label(&metric.benchmark),
+                measure = escape_label(&metric.measure),
+                units = escape_label(&metric.units),
+                timestamp = metric.timestamp,
+            )?;
+        }
+    }
+    writeln!(openmetrics, "# EOF")?;
+    Ok(openmetrics)
+}
+
+// https://github.com/OpenObservability/OpenMetrics/blob/main/specification/OpenMetrics.md#escaping
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
+
+// The `QueryableByName` derive macro generates fully qualified paths
+#[allow(unused_qualifications)]
+mod latest {
+    use diesel::sql_types::{BigInt, Double, Nullable, Text};
+
+    #[derive(Debug, diesel::QueryableByName)]
+    pub struct LatestMetric {
+        #[diesel(sql_type = Text)]
+        pub branch: String,
+        #[diesel(sql_type = Text)]
+        pub testbed: String,
+        #[diesel(sql_type = Text)]
+        pub benchmark: String,
+        #[diesel(sql_type = Text)]
+        pub measure: String,
+        #[diesel(sql_type = Text)]
+        pub units: String,
+        #[diesel(sql_type = Double)]
+        pub value: f64,
+        #[diesel(sql_type = Nullable<Double>)]
+        pub lower_value: Option<f64>,
+        #[diesel(sql_type = Nullable<Double>)]
+        pub upper_value: Option<f64>,
+        #[diesel(sql_type = BigInt)]
+        pub timestamp: i64,
+    }
+}
@@ -1,5 +1,5 @@
 use bencher_json::{
-    project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams},
+    project::perf::{JsonPerfCursor, JsonPerfImgQueryParams, JsonPerfQueryParams},
     JsonPerfQuery,
 };
 use bencher_plot::LinePlot;
@@ -8,28 +8,19 @@ use http::{Response, StatusCode};
 
 use crate::{
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get},
-        Endpoint,
-    },
+    endpoints::endpoint::options_endpoint,
     error::{bad_request_error, issue_error},
     model::user::auth::{AuthUser, PubBearerToken},
 };
 
-use super::ProjPerfParams;
+use super::{Aggregation, ProjPerfParams};
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/perf/img",
-    tags = ["projects", "perf"]
-}]
-pub async fn proj_perf_img_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjPerfParams>,
-    _query_params: Query<JsonPerfQueryParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    proj_perf_img_options,
+    "/v0/projects/{project}/perf/img",
+    ["projects", "perf"],
+    (_path_params: Path<ProjPerfParams>, _query_params: Query<JsonPerfQueryParams>),
+    [Get]
 }
 
 /// Generate a dynamic image of project performance metrics
@@ -84,7 +75,16 @@ async fn get_inner(
     json_perf_query: JsonPerfQuery,
     auth_user: Option<&AuthUser>,
 ) -> Result<Vec<u8>, HttpError> {
-    let json_perf = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
+    let json_perf = super::get_inner(
+        context,
+        path_params,
+        json_perf_query,
+        JsonPerfCursor::default(),
+        false,
+        Aggregation::default(),
+        auth_user,
+    )
+    .await?;
     LinePlot::new().draw(title, &json_perf).map_err(|e| {
         issue_error(
             StatusCode::INTERNAL_SERVER_ERROR,
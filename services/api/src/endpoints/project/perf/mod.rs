@@ -2,16 +2,18 @@ use bencher_json::{
     project::{
         alert::JsonPerfAlert,
         head::{JsonVersion, VersionNumber},
-        perf::{JsonPerfMetric, JsonPerfMetrics, JsonPerfQueryParams},
+        perf::{
+            JsonPerfAggregate, JsonPerfCursor, JsonPerfMetric, JsonPerfMetrics, JsonPerfQueryParams,
+        },
         report::Iteration,
         threshold::JsonThresholdModel,
     },
     BenchmarkUuid, BranchUuid, DateTime, GitHash, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ReportUuid, ResourceId, TestbedUuid,
+    ReportUuid, ResourceId, ResourceName, TestbedUuid,
 };
 use diesel::{
-    ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
-    SelectableHelper,
+    BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, TextExpressionMethods,
 };
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
 use schemars::JsonSchema;
@@ -20,10 +22,7 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
     error::{bad_request_error, resource_not_found_err},
     model::{
         project::{
@@ -31,6 +30,7 @@ use crate::{
             branch::{head::QueryHead, QueryBranch},
             measure::QueryMeasure,
             metric_boundary::QueryMetricBoundary,
+            report::ReportId,
             testbed::QueryTestbed,
             threshold::{
                 alert::QueryAlert, boundary::QueryBoundary, model::QueryModel, QueryThreshold,
@@ -52,18 +52,12 @@ pub struct ProjPerfParams {
     pub project: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/perf",
-    tags = ["projects", "perf"]
-}]
-pub async fn proj_perf_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjPerfParams>,
-    _query_params: Query<JsonPerfQueryParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    proj_perf_options,
+    "/v0/projects/{project}/perf",
+    ["projects", "perf"],
+    (_path_params: Path<ProjPerfParams>, _query_params: Query<JsonPerfQueryParams>),
+    [Get]
 }
 
 /// Query project performance metrics
@@ -72,6 +66,12 @@ pub async fn proj_perf_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 255 permutations for a single request.
 /// Therefore, only the first 255 permutations are returned.
+/// The metrics for each permutation can be paginated using the `after` and `limit` query parameters.
+/// To compare metrics across testbeds, set `normalize` to scale each metric by the scale factor of its testbed.
+/// To include every benchmark with a name that starts with a prefix, set `benchmark_prefix`.
+/// To combine the queried benchmarks into a single series for each branch, testbed, and measure,
+/// set `aggregate` to `sum`, `mean`, or `max`.
+/// The aggregated series are computed from the metrics returned in `results`.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -85,27 +85,43 @@ pub async fn proj_perf_get(
     path_params: Path<ProjPerfParams>,
     query_params: Query<JsonPerfQueryParams>,
 ) -> Result<ResponseOk<JsonPerf>, HttpError> {
+    let query_params = query_params.into_inner();
+    let cursor = query_params.cursor();
+    let normalize = query_params.normalize.unwrap_or_default();
+    let aggregation = Aggregation {
+        benchmark_prefix: query_params.benchmark_prefix.clone(),
+        aggregate: query_params.aggregate,
+    };
     // Second round of marshaling
-    let json_perf_query = query_params
-        .into_inner()
-        .try_into()
-        .map_err(bad_request_error)?;
+    let json_perf_query = query_params.try_into().map_err(bad_request_error)?;
 
     let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
     let json = get_inner(
         rqctx.context(),
         path_params.into_inner(),
         json_perf_query,
+        cursor,
+        normalize,
+        aggregation,
         auth_user.as_ref(),
     )
     .await?;
     Ok(Get::response_ok(json, auth_user.is_some()))
 }
 
+#[derive(Default)]
+struct Aggregation {
+    benchmark_prefix: Option<String>,
+    aggregate: Option<JsonPerfAggregate>,
+}
+
 async fn get_inner(
     context: &ApiContext,
     path_params: ProjPerfParams,
     json_perf_query: JsonPerfQuery,
+    cursor: JsonPerfCursor,
+    normalize: bool,
+    aggregation: Aggregation,
     auth_user: Option<&AuthUser>,
 ) -> Result<JsonPerf, HttpError> {
     let project = QueryProject::is_allowed_public(
@@ -119,17 +135,69 @@ async fn get_inner(
         branches,
         heads,
         testbeds,
-        benchmarks,
+        mut benchmarks,
         measures,
+        environment,
         start_time,
         end_time,
     } = json_perf_query;
 
+    let Aggregation {
+        benchmark_prefix,
+        aggregate,
+    } = aggregation;
+    if let Some(benchmark_prefix) = benchmark_prefix {
+        let prefix_benchmarks: Vec<BenchmarkUuid> = schema::benchmark::table
+            .filter(schema::benchmark::project_id.eq(project.id))
+            .filter(
+                schema::benchmark::name
+                    .like(like_prefix(&benchmark_prefix))
+                    .escape('\\'),
+            )
+            .filter(schema::benchmark::archived.is_null())
+            .order(schema::benchmark::name)
+            .select(schema::benchmark::uuid)
+            .load(conn_lock!(context))
+            .map_err(resource_not_found_err!(
+                Benchmark,
+                (&project, benchmark_prefix)
+            ))?;
+        for benchmark_uuid in prefix_benchmarks {
+            if !benchmarks.contains(&benchmark_uuid) {
+                benchmarks.push(benchmark_uuid);
+            }
+        }
+    }
+
     let times = Times {
         start_time,
         end_time,
     };
 
+    let JsonPerfCursor { after, limit } = cursor;
+    let after = if let Some(after) = after {
+        Some(
+            schema::report::table
+                .inner_join(schema::version::table)
+                .filter(schema::report::project_id.eq(project.id))
+                .filter(schema::report::uuid.eq(after))
+                .select((
+                    schema::version::number,
+                    schema::report::start_time,
+                    schema::report::id,
+                ))
+                .first::<ReportKey>(conn_lock!(context))
+                .map_err(resource_not_found_err!(Report, (&project, after)))?,
+        )
+    } else {
+        None
+    };
+    let page = Page {
+        after,
+        report: None,
+        limit,
+    };
+
     let results = perf_results(
         context,
         &project,
@@ -138,24 +206,50 @@ async fn get_inner(
         &testbeds,
         &benchmarks,
         &measures,
+        environment.as_ref(),
         times,
+        page,
+        normalize,
     )
     .await?;
 
+    let aggregates = aggregate.map(|aggregate| aggregate.aggregate(&results));
+
     Ok(JsonPerf {
         project: project.into_json(conn_lock!(context))?,
         start_time,
         end_time,
         results,
+        aggregates,
     })
 }
 
+// Match any benchmark name that starts with the prefix,
+// escaping the `LIKE` wildcards in the prefix itself.
+fn like_prefix(prefix: &str) -> String {
+    let escaped = prefix
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{escaped}%")
+}
+
 #[derive(Clone, Copy)]
 struct Times {
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
 }
 
+// The position of a report in the perf metric order
+type ReportKey = (VersionNumber, DateTime, ReportId);
+
+#[derive(Clone, Copy)]
+struct Page {
+    after: Option<ReportKey>,
+    report: Option<ReportUuid>,
+    limit: Option<u32>,
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn perf_results(
     context: &ApiContext,
@@ -165,7 +259,10 @@ async fn perf_results(
     testbeds: &[TestbedUuid],
     benchmarks: &[BenchmarkUuid],
     measures: &[MeasureUuid],
+    environment: Option<&ResourceName>,
     times: Times,
+    page: Page,
+    normalize: bool,
 ) -> Result<Vec<JsonPerfMetrics>, HttpError> {
     let permutations = branches.len() * testbeds.len() * benchmarks.len() * measures.len();
     let gt_max_permutations = permutations > MAX_PERMUTATIONS;
@@ -185,7 +282,7 @@ async fn perf_results(
                         return Ok(results);
                     }
 
-                    let pq = perf_query(
+                    let pq = paged_perf_query(
                         context,
                         project,
                         *branch_uuid,
@@ -193,14 +290,19 @@ async fn perf_results(
                         *testbed_uuid,
                         *benchmark_uuid,
                         *measure_uuid,
+                        environment,
                         times,
+                        page,
                     )
                     .await?;
 
                     let mut perf_metrics: Option<JsonPerfMetrics> = None;
-                    for (query_dimensions, perf_metric) in
+                    for (query_dimensions, mut perf_metric) in
                         pq.into_iter().map(|pq| split_perf_query(project, pq))
                     {
+                        if normalize {
+                            perf_metric.normalize(query_dimensions.testbed.scale);
+                        }
                         if let Some(perf_metrics) = &mut perf_metrics {
                             perf_metrics.metrics.push(perf_metric);
                         } else {
@@ -223,6 +325,77 @@ async fn perf_results(
     Ok(results)
 }
 
+// Get a page of perf metrics that always ends with the last iteration of a report.
+// This way, the last report in a page can be used as the cursor for the next page.
+#[allow(clippy::too_many_arguments)]
+async fn paged_perf_query(
+    context: &ApiContext,
+    project: &QueryProject,
+    branch_uuid: BranchUuid,
+    head_uuid: Option<HeadUuid>,
+    testbed_uuid: TestbedUuid,
+    benchmark_uuid: BenchmarkUuid,
+    measure_uuid: MeasureUuid,
+    environment: Option<&ResourceName>,
+    times: Times,
+    page: Page,
+) -> Result<Vec<PerfQuery>, HttpError> {
+    let mut pq = perf_query(
+        context,
+        project,
+        branch_uuid,
+        head_uuid,
+        testbed_uuid,
+        benchmark_uuid,
+        measure_uuid,
+        environment,
+        times,
+        page,
+    )
+    .await?;
+
+    let Some(limit) = page.limit.and_then(|limit| usize::try_from(limit).ok()) else {
+        return Ok(pq);
+    };
+    // One extra metric is queried to check if the last report is split across pages.
+    let Some(next_report) = pq
+        .get(limit)
+        .map(|(_, _, _, _, _, report_uuid, ..)| *report_uuid)
+    else {
+        return Ok(pq);
+    };
+    pq.truncate(limit);
+    while pq
+        .last()
+        .is_some_and(|(_, _, _, _, _, report_uuid, ..)| *report_uuid == next_report)
+    {
+        pq.pop();
+    }
+    if !pq.is_empty() {
+        return Ok(pq);
+    }
+
+    // A single report has more iterations than the limit,
+    // so return all of its iterations.
+    perf_query(
+        context,
+        project,
+        branch_uuid,
+        head_uuid,
+        testbed_uuid,
+        benchmark_uuid,
+        measure_uuid,
+        environment,
+        times,
+        Page {
+            after: page.after,
+            report: Some(next_report),
+            limit: None,
+        },
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 async fn perf_query(
     context: &ApiContext,
@@ -232,7 +405,9 @@ async fn perf_query(
     testbed_uuid: TestbedUuid,
     benchmark_uuid: BenchmarkUuid,
     measure_uuid: MeasureUuid,
+    environment: Option<&ResourceName>,
     times: Times,
+    page: Page,
 ) -> Result<Vec<PerfQuery>, HttpError> {
     let mut query = view::metric_boundary::table
         .inner_join(
@@ -281,6 +456,11 @@ async fn perf_query(
         query = query.filter(schema::branch::head_id.eq(schema::head::id.nullable()));
     }
 
+    // Filter for the report environment if it is provided.
+    if let Some(environment) = environment {
+        query = query.filter(schema::report::environment.eq(environment));
+    }
+
     let Times {
         start_time,
         end_time,
@@ -292,13 +472,41 @@ async fn perf_query(
         query = query.filter(schema::report::end_time.le(end_time));
     }
 
+    let Page {
+        after,
+        report,
+        limit,
+    } = page;
+    // Only include reports that come after the cursor report.
+    if let Some((version_number, start_time, report_id)) = after {
+        query = query.filter(
+            schema::version::number
+                .gt(version_number)
+                .or(schema::version::number.eq(version_number).and(
+                    schema::report::start_time
+                        .gt(start_time)
+                        .or(schema::report::start_time
+                            .eq(start_time)
+                            .and(schema::report::id.gt(report_id))),
+                )),
+        );
+    }
+    if let Some(report_uuid) = report {
+        query = query.filter(schema::report::uuid.eq(report_uuid));
+    }
+    if let Some(limit) = limit {
+        query = query.limit(i64::from(limit) + 1);
+    }
+
     let query = query
         // Order by the version number so that the oldest version is first.
         // Because multiple reports can use the same version (via git hash), order by the start time next.
+        // Then order by the report itself, so that the order is stable for pagination.
         // Then within a report order by the iteration number.
         .order((
             schema::version::number,
             schema::report::start_time,
+            schema::report::id,
             schema::report_benchmark::iteration,
         ))
         .select((
@@ -318,9 +526,10 @@ async fn perf_query(
                     schema::threshold::id,
                     schema::threshold::uuid,
                     schema::threshold::project_id,
-                    schema::threshold::measure_id,
                     schema::threshold::branch_id,
                     schema::threshold::testbed_id,
+                    schema::threshold::measure_id,
+                    schema::threshold::benchmark,
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
@@ -335,6 +544,8 @@ async fn perf_query(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::lower_critical,
+                    schema::model::upper_critical,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -343,6 +554,8 @@ async fn perf_query(
                     schema::alert::uuid,
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
+                    schema::alert::kind,
+                    schema::alert::severity,
                     schema::alert::status,
                     schema::alert::modified,
                 ).nullable(),
@@ -14,11 +14,8 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
     },
     error::{resource_conflict_err, resource_not_found_err},
     model::{
@@ -29,7 +26,10 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -58,19 +58,16 @@ pub struct ProjPlotsQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/plots",
-    tags = ["projects", "plots"]
-}]
-pub async fn proj_plots_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjPlotsParams>,
-    _pagination_params: Query<ProjPlotsPagination>,
-    _query_params: Query<ProjPlotsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_plots_options,
+    "/v0/projects/{project}/plots",
+    ["projects", "plots"],
+    (
+        _path_params: Path<ProjPlotsParams>,
+        _pagination_params: Query<ProjPlotsPagination>,
+        _query_params: Query<ProjPlotsQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List plots for a project
@@ -92,11 +89,13 @@ pub async fn proj_plots_get(
     query_params: Query<ProjPlotsQuery>,
 ) -> Result<ResponseOk<JsonPlots>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -104,6 +103,7 @@ pub async fn proj_plots_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -241,17 +241,12 @@ pub struct ProjPlotParams {
     pub plot: PlotUuid,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/plots/{plot}",
-    tags = ["projects", "plots"]
-}]
-pub async fn proj_plot_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjPlotParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    proj_plot_options,
+    "/v0/projects/{project}/plots/{plot}",
+    ["projects", "plots"],
+    (_path_params: Path<ProjPlotParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a plot
@@ -1,6 +1,8 @@
 use bencher_json::{
+    organization::member::OrganizationRole,
     project::{JsonUpdateProject, Visibility},
-    JsonDirection, JsonPagination, JsonProject, JsonProjects, ResourceId, ResourceName,
+    DateTime, JsonClaimProject, JsonDirection, JsonPagination, JsonProject, JsonProjects,
+    ResourceId, ResourceName,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -16,17 +18,22 @@ use crate::model::organization::plan::PlanKind;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Delete, Get, Patch, ResponseDeleted, ResponseOk},
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseDeleted, ResponseOk,
+    },
+    error::{
+        resource_conflict_err, resource_not_found_err, resource_not_found_error, BencherResource,
     },
-    error::{resource_conflict_err, resource_not_found_err},
     model::{
-        project::{QueryProject, UpdateProject},
+        organization::organization_role::InsertOrganizationRole,
+        project::{unclaimed_project::QueryUnclaimedProject, QueryProject, UpdateProject},
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 pub type ProjectsPagination = JsonPagination<ProjectsSort>;
@@ -47,18 +54,12 @@ pub struct ProjectsQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects",
-    tags = ["projects"]
-}]
-pub async fn projects_options(
-    _rqctx: RequestContext<ApiContext>,
-    _pagination_params: Query<ProjectsPagination>,
-    _query_params: Query<ProjectsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    projects_options,
+    "/v0/projects",
+    ["projects"],
+    (_pagination_params: Query<ProjectsPagination>, _query_params: Query<ProjectsQuery>),
+    [Get]
 }
 
 /// List projects
@@ -81,10 +82,12 @@ pub async fn projects_get(
     query_params: Query<ProjectsQuery>,
 ) -> Result<ResponseOk<JsonProjects>, HttpError> {
     let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -92,6 +95,7 @@ pub async fn projects_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -183,17 +187,12 @@ pub struct ProjectParams {
     pub project: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}",
-    tags = ["projects"]
-}]
-pub async fn project_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjectParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    project_options,
+    "/v0/projects/{project}",
+    ["projects"],
+    (_path_params: Path<ProjectParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a project
@@ -367,3 +366,92 @@ async fn delete_inner(
 
     Ok(())
 }
+
+options_endpoint! {
+    project_claim_options,
+    "/v0/projects/{project}/claim",
+    ["projects"],
+    (_path_params: Path<ProjectParams>),
+    [Post]
+}
+
+/// Claim a project
+///
+/// Claim an unclaimed project that was created anonymously by `bencher run`.
+/// The key for the unclaimed project must be provided.
+/// The user becomes a `leader` of the project's organization,
+/// and all of the anonymous reports for the project are attributed to the user.
+/// Once claimed, the key can no longer be used to access the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/claim",
+    tags = ["projects"]
+}]
+pub async fn project_claim_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjectParams>,
+    body: TypedBody<JsonClaimProject>,
+) -> Result<ResponseOk<JsonProject>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = claim_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn claim_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjectParams,
+    json_claim: JsonClaimProject,
+    auth_user: &AuthUser,
+) -> Result<JsonProject, HttpError> {
+    let query_project = QueryProject::from_resource_id(conn_lock!(context), &path_params.project)?;
+    let unclaimed_project = QueryUnclaimedProject::from_key(conn_lock!(context), &json_claim.key)?;
+    // Don't reveal whether the key is for a different project
+    if unclaimed_project.project_id != query_project.id {
+        return Err(resource_not_found_error(
+            BencherResource::UnclaimedProject,
+            &query_project,
+            "Unclaimed project key does not match project",
+        ));
+    }
+
+    let timestamp = DateTime::now();
+    // Connect the user to the organization of the unclaimed project as a `Leader`
+    let insert_org_role = InsertOrganizationRole {
+        user_id: auth_user.id(),
+        organization_id: query_project.organization_id,
+        role: OrganizationRole::Leader,
+        created: timestamp,
+        modified: timestamp,
+    };
+    diesel::insert_into(schema::organization_role::table)
+        .values(&insert_org_role)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(OrganizationRole, insert_org_role))?;
+
+    // Attribute all of the anonymous reports to the user
+    diesel::update(
+        schema::report::table
+            .filter(schema::report::project_id.eq(query_project.id))
+            .filter(schema::report::user_id.is_null()),
+    )
+    .set(schema::report::user_id.eq(auth_user.id()))
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(Report, query_project))?;
+
+    unclaimed_project.claim(conn_lock!(context))?;
+    slog::debug!(log, "Claimed project: {query_project:?}");
+
+    #[cfg(feature = "plus")]
+    context.update_index(log, &query_project).await;
+
+    query_project.into_json(conn_lock!(context))
+}
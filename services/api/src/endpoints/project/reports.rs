@@ -1,18 +1,18 @@
-use std::collections::HashMap;
-
 use bencher_json::{
-    project::{
-        head::VersionNumber,
-        report::{JsonReportQuery, JsonReportQueryParams},
+    project::report::{
+        Iteration, JsonReportQuery, JsonReportQueryParams, JsonReportsDeleteParams,
+        JsonReportsDeleteQuery,
     },
-    JsonDirection, JsonNewReport, JsonPagination, JsonReport, JsonReports, ReportUuid, ResourceId,
+    DateTime, JsonDirection, JsonNewReport, JsonPagination, JsonReport, JsonReportRaw,
+    JsonReportRerun, JsonReportResultList, JsonReports, JsonReportsDeleted, JsonVersionedReport,
+    ReportUuid, ResourceId,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
-    dsl::count, BelongingToDsl, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
-    RunQueryDsl, SelectableHelper,
+    BelongingToDsl, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl,
+    SelectableHelper, TextExpressionMethods,
 };
-use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
 use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -22,30 +22,45 @@ use slog::Logger;
 use crate::model::organization::plan::PlanKind;
 use crate::{
     conn_lock,
-    context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk},
-        Endpoint,
+    context::{in_span, ApiContext},
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseAccepted, ResponseCreated, ResponseDeleted,
+        ResponseOk,
     },
     error::{bad_request_error, issue_error, resource_conflict_err, resource_not_found_err},
     model::{
         project::{
             branch::{
-                head::HeadId,
+                protection::BranchAuth,
                 version::{QueryVersion, VersionId},
                 QueryBranch,
             },
-            report::{results::ReportResults, InsertReport, QueryReport, ReportId},
+            report::{
+                get_report_results,
+                report_attachment::QueryReportAttachment,
+                report_benchmark::ReportBenchmarkId,
+                report_failure::InsertReportFailure,
+                report_raw::{InsertReportRaw, QueryReportRaw},
+                results::ReportResults,
+                InsertReport, QueryReport, ReportId,
+            },
             testbed::QueryTestbed,
             threshold::InsertThreshold,
+            webhook::QueryWebhook,
             QueryProject,
         },
-        user::auth::{AuthUser, BearerToken, PubBearerToken},
+        user::{
+            auth::{AuthUser, BearerToken, PubBearerToken},
+            UserId,
+        },
     },
+    notify::{notify_alerts, notify_rerun, notify_webhooks},
     schema,
     util::{
-        headers::TotalCount,
+        headers::{PageLinks, TotalCount},
+        large_body::LargeBody,
         name_id::{filter_branch_name_id, filter_testbed_name_id},
+        search::Search,
     },
 };
 
@@ -65,19 +80,16 @@ pub enum ProjReportsSort {
     DateTime,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/reports",
-    tags = ["projects", "reports"]
-}]
-pub async fn proj_reports_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjReportsParams>,
-    _pagination_params: Query<ProjReportsPagination>,
-    _query_params: Query<JsonReportQueryParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_reports_options,
+    "/v0/projects/{project}/reports",
+    ["projects", "reports"],
+    (
+        _path_params: Path<ProjReportsParams>,
+        _pagination_params: Query<ProjReportsPagination>,
+        _query_params: Query<JsonReportQueryParams>,
+    ),
+    [Get, Post, Delete]
 }
 
 /// List reports for a project
@@ -86,6 +98,8 @@ pub async fn proj_reports_options(
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 /// By default, the reports are sorted by date time in reverse chronological order.
+/// For large projects, use the `after` query parameter with the UUID of the last report in the previous page
+/// to paginate with a stable cursor instead of a page number.
 /// The HTTP response header `X-Total-Count` contains the total number of reports.
 #[endpoint {
     method = GET,
@@ -105,12 +119,14 @@ pub async fn proj_reports_get(
         .map_err(bad_request_error)?;
 
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         &rqctx.log,
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         json_report_query,
     )
     .await?;
@@ -118,9 +134,30 @@ pub async fn proj_reports_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
+// Reports that come after the cursor report in the date time sort order.
+// The report ID is used as a tie breaker to guarantee a stable order.
+macro_rules! after_report {
+    ($cmp:ident, $cursor:ident) => {
+        schema::report::start_time
+            .$cmp($cursor.start_time)
+            .or(schema::report::start_time.eq($cursor.start_time).and(
+                schema::report::end_time
+                    .$cmp($cursor.end_time)
+                    .or(schema::report::end_time.eq($cursor.end_time).and(
+                        schema::report::created
+                            .$cmp($cursor.created)
+                            .or(schema::report::created
+                                .eq($cursor.created)
+                                .and(schema::report::id.$cmp($cursor.id))),
+                    )),
+            ))
+    };
+}
+
 async fn get_ls_inner(
     log: &Logger,
     context: &ApiContext,
@@ -136,8 +173,21 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let reports = get_ls_query(&query_project, &pagination_params, &query_params)?
-        .offset(pagination_params.offset())
+    let mut query = get_ls_query(&query_project, &pagination_params, &query_params)?;
+    // Use keyset pagination if a cursor is provided, otherwise fall back to offset pagination.
+    if let Some(after) = query_params.after {
+        let cursor = QueryReport::belonging_to(&query_project)
+            .filter(schema::report::uuid.eq(after))
+            .first::<QueryReport>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Report, (&query_project, after)))?;
+        query = match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.filter(after_report!(gt, cursor)),
+            Some(JsonDirection::Desc) | None => query.filter(after_report!(lt, cursor)),
+        };
+    } else {
+        query = query.offset(pagination_params.offset());
+    }
+    let reports = query
         .limit(pagination_params.limit())
         .load(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -148,7 +198,7 @@ async fn get_ls_inner(
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_reports = Vec::with_capacity(reports.len());
     for report in reports {
-        match report.into_json(log, context).await {
+        match report.into_json(log, context, true).await {
             Ok(report) => json_reports.push(report),
             Err(err) => {
                 debug_assert!(false, "{err}");
@@ -197,6 +247,10 @@ fn get_ls_query<'q>(
         query = query.filter(schema::report::end_time.le(end_time));
     }
 
+    if let Some(adapter) = query_params.adapter {
+        query = query.filter(schema::report::adapter.eq(adapter));
+    }
+
     if let Some(true) = query_params.archived {
         query = query.filter(
             schema::branch::archived
@@ -217,11 +271,13 @@ fn get_ls_query<'q>(
                 schema::report::start_time.asc(),
                 schema::report::end_time.asc(),
                 schema::report::created.asc(),
+                schema::report::id.asc(),
             )),
             Some(JsonDirection::Desc) | None => query.order((
                 schema::report::start_time.desc(),
                 schema::report::end_time.desc(),
                 schema::report::created.desc(),
+                schema::report::id.desc(),
             )),
         },
     }
@@ -274,25 +330,28 @@ pub async fn proj_report_post(
     rqctx: RequestContext<ApiContext>,
     bearer_token: BearerToken,
     path_params: Path<ProjReportsParams>,
-    body: TypedBody<JsonNewReport>,
+    body: LargeBody<JsonVersionedReport>,
 ) -> Result<ResponseCreated<JsonReport>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json_report = body
+        .into_inner(rqctx.context().large_body_max_bytes)
+        .await?;
     let json = post_inner(
         &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
-        body.into_inner(),
+        json_report.into(),
         &auth_user,
     )
     .await?;
     Ok(Post::auth_response_created(json))
 }
 
-async fn post_inner(
+pub(super) async fn post_inner(
     log: &Logger,
     context: &ApiContext,
     path_params: ProjReportsParams,
-    mut json_report: JsonNewReport,
+    json_report: JsonNewReport,
     auth_user: &AuthUser,
 ) -> Result<JsonReport, HttpError> {
     // Verify that the user is allowed
@@ -303,27 +362,96 @@ async fn post_inner(
         auth_user,
         Permission::Create,
     )?;
-    let project_id = project.id;
-
-    // Get or create the branch and testbed
-    let (branch_id, head_id) = QueryBranch::get_or_create(
+    let branch_auth = BranchAuth::new(&context.rbac, auth_user, &project);
+    create_report(
         log,
         context,
-        project_id,
-        &json_report.branch,
-        json_report.start_point.as_ref(),
+        project,
+        Some(auth_user.id()),
+        branch_auth,
+        json_report,
     )
-    .await?;
-    let testbed_id = QueryTestbed::get_or_create(context, project_id, &json_report.testbed).await?;
+    .await
+}
 
-    // Insert the thresholds for the report
-    InsertThreshold::from_report_json(
+/// Create a new report for a project that the user has already been verified to have access to.
+/// The user is only missing for reports sent anonymously to an unclaimed project.
+pub(super) async fn create_report(
+    log: &Logger,
+    context: &ApiContext,
+    project: QueryProject,
+    user_id: Option<UserId>,
+    branch_auth: BranchAuth,
+    json_report: JsonNewReport,
+) -> Result<JsonReport, HttpError> {
+    #[cfg(feature = "otel")]
+    if let Some(report_span) = context
+        .otel
+        .as_ref()
+        .and_then(|otel| otel.report_span(&project))
+    {
+        return report_span
+            .run(Box::pin(process_report(
+                log,
+                context,
+                project,
+                user_id,
+                branch_auth,
+                json_report,
+            )))
+            .await;
+    }
+    // The report processing future is large, so keep it on the heap
+    Box::pin(process_report(
         log,
         context,
-        project_id,
-        branch_id,
-        testbed_id,
-        json_report.thresholds.take(),
+        project,
+        user_id,
+        branch_auth,
+        json_report,
+    ))
+    .await
+}
+
+#[allow(clippy::too_many_lines)]
+async fn process_report(
+    log: &Logger,
+    context: &ApiContext,
+    project: QueryProject,
+    user_id: Option<UserId>,
+    branch_auth: BranchAuth,
+    mut json_report: JsonNewReport,
+) -> Result<JsonReport, HttpError> {
+    let project_id = project.id;
+
+    // Get or create the branch and testbed
+    let (branch_id, head_id, testbed_id) = in_span("report.branch_testbed", async {
+        let (branch_id, head_id) = QueryBranch::get_or_create(
+            log,
+            context,
+            project_id,
+            &json_report.branch,
+            json_report.start_point.as_ref(),
+            branch_auth,
+        )
+        .await?;
+        let testbed_id =
+            QueryTestbed::get_or_create(context, project_id, &json_report.testbed).await?;
+        Ok::<_, HttpError>((branch_id, head_id, testbed_id))
+    })
+    .await?;
+
+    // Insert the thresholds for the report
+    in_span(
+        "report.thresholds",
+        InsertThreshold::from_report_json(
+            log,
+            context,
+            project_id,
+            branch_id,
+            testbed_id,
+            json_report.thresholds.take(),
+        ),
     )
     .await?;
 
@@ -353,7 +481,7 @@ async fn post_inner(
 
     // Create a new report and add it to the database
     let insert_report = InsertReport::from_json(
-        auth_user.id(),
+        user_id,
         project_id,
         head_id,
         version_id,
@@ -379,28 +507,49 @@ async fn post_inner(
             )
         })?;
 
+    // Store the raw results for the report, if requested
+    if json_settings.raw.unwrap_or_default() {
+        store_raw_results(log, context, query_report.id, &json_report.results).await?;
+    }
+
+    // Store the exit code and stderr of the benchmark command, if it failed
+    if let Some(failure) = json_report.failure.take() {
+        InsertReportFailure::from_json(query_report.id, failure).insert(conn_lock!(context))?;
+    }
+
     #[cfg(feature = "plus")]
     let mut usage = 0;
 
     // Process and record the report results
-    let mut report_results =
-        ReportResults::new(project_id, branch_id, head_id, testbed_id, query_report.id);
+    let mut report_results = ReportResults::new(
+        project_id,
+        branch_id,
+        head_id,
+        testbed_id,
+        query_report.environment.clone(),
+        query_report.id,
+    );
     let results_array = json_report
         .results
         .iter()
         .map(AsRef::as_ref)
         .collect::<Vec<&str>>();
-    let processed_report = report_results
-        .process(
+    let processed_report = in_span(
+        "report.results",
+        report_results.process(
             log,
             context,
             &results_array,
+            json_report.runner_results.take(),
             adapter,
             json_settings,
+            json_report.start_time,
+            json_report.end_time,
             #[cfg(feature = "plus")]
             &mut usage,
-        )
-        .await;
+        ),
+    )
+    .await;
 
     #[cfg(feature = "plus")]
     plan_kind
@@ -408,9 +557,147 @@ async fn post_inner(
         .await?;
 
     // Don't return the error from processing the report until after the metrics usage has been checked
-    processed_report?;
+    let adapter_selection = processed_report?;
     // If the report was processed successfully, then return the report with the results
-    query_report.into_json(log, context).await
+    #[cfg(feature = "plus")]
+    let report_id = query_report.id;
+    let mut json_report =
+        in_span("report.json", query_report.into_json(log, context, true)).await?;
+    json_report.adapter_selection = adapter_selection;
+
+    // Queue the accepted report to be mirrored, if its project is mirrored
+    #[cfg(feature = "plus")]
+    context
+        .mirror_report(log, &project, report_id, &json_report)
+        .await;
+
+    // Open issues for any critical alerts, if the project has issue tracker integrations
+    #[cfg(feature = "plus")]
+    context.open_alert_issues(log, &project, &json_report).await;
+    // Close issues for any earlier alerts that no longer alert in this report
+    #[cfg(feature = "plus")]
+    context
+        .close_resolved_alert_issues(log, &project, report_id)
+        .await;
+
+    // Email the project members about any new alerts, if the server has an SMTP config
+    notify_alerts(log, context, &project, &json_report).await;
+    // Send any new alerts to the project webhooks
+    notify_webhooks(log, context, &project, &json_report).await;
+
+    Ok(json_report)
+}
+
+async fn store_raw_results(
+    log: &Logger,
+    context: &ApiContext,
+    report_id: ReportId,
+    results: &[String],
+) -> Result<(), HttpError> {
+    let insert_report_raw = InsertReportRaw::from_results(report_id, results)
+        .await
+        .map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to compress raw report results",
+                &format!("Failed to compress raw results for report ({report_id})"),
+                e,
+            )
+        })?;
+    let Some(insert_report_raw) = insert_report_raw else {
+        slog::warn!(
+            log,
+            "Raw results for report ({report_id}) are too large to store"
+        );
+        return Ok(());
+    };
+    diesel::insert_into(schema::report_raw::table)
+        .values(&insert_report_raw)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(ReportRaw, report_id))?;
+    Ok(())
+}
+
+/// Delete old reports for a branch
+///
+/// Delete all reports for a branch that ended before the given date time.
+/// The user must have `delete` permissions for the project.
+/// The branch must not be protected from deletion.
+/// Set the `dry_run` query parameter to `true` to list the reports that would be deleted without deleting them.
+/// If there are no more reports for a branch version, then that version will be deleted.
+/// All later branch versions will have their version numbers decremented.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/reports",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_reports_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjReportsParams>,
+    query_params: Query<JsonReportsDeleteParams>,
+) -> Result<ResponseOk<JsonReportsDeleted>, HttpError> {
+    // Second round of marshaling
+    let json_reports_delete_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = delete_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_reports_delete_query,
+        &auth_user,
+    )
+    .await?;
+    Ok(Delete::auth_response_ok(json))
+}
+
+async fn delete_ls_inner(
+    context: &ApiContext,
+    path_params: ProjReportsParams,
+    query_params: JsonReportsDeleteQuery,
+    auth_user: &AuthUser,
+) -> Result<JsonReportsDeleted, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_branch =
+        QueryBranch::from_name_id(conn_lock!(context), query_project.id, &query_params.branch)?;
+    query_branch.check_protected_delete()?;
+
+    let reports = QueryReport::belonging_to(&query_project)
+        .inner_join(schema::head::table)
+        .filter(schema::head::branch_id.eq(query_branch.id))
+        .filter(schema::report::end_time.lt(query_params.before))
+        .order((
+            schema::report::start_time.asc(),
+            schema::report::end_time.asc(),
+            schema::report::created.asc(),
+            schema::report::id.asc(),
+        ))
+        .select(schema::report::uuid)
+        .load::<ReportUuid>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Report,
+            (&query_project, &query_branch, &query_params)
+        ))?;
+
+    let dry_run = query_params.dry_run.unwrap_or_default();
+    if !dry_run {
+        for &report in &reports {
+            delete_report(context, &query_project, report).await?;
+        }
+    }
+
+    Ok(JsonReportsDeleted { reports, dry_run })
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -421,17 +708,20 @@ pub struct ProjReportParams {
     pub report: ReportUuid,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/reports/{report}",
-    tags = ["projects", "reports"]
-}]
-pub async fn proj_report_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjReportParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Delete.into()]))
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjReportQuery {
+    /// If set to `false`, the report results are left empty.
+    /// Use the report results route to paginate them instead.
+    /// If not set or set to `true`, all of the report results are included.
+    pub results: Option<bool>,
+}
+
+options_endpoint! {
+    proj_report_options,
+    "/v0/projects/{project}/reports/{report}",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportParams>, _query_params: Query<ProjReportQuery>),
+    [Get, Delete]
 }
 
 /// View a report
@@ -439,6 +729,8 @@ pub async fn proj_report_options(
 /// View a report for a project.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// For reports with many benchmarks, set `results` to `false`
+/// and use the report results route to paginate the results.
 #[endpoint {
     method = GET,
     path =  "/v0/projects/{project}/reports/{report}",
@@ -448,12 +740,14 @@ pub async fn proj_report_get(
     rqctx: RequestContext<ApiContext>,
     bearer_token: PubBearerToken,
     path_params: Path<ProjReportParams>,
+    query_params: Query<ProjReportQuery>,
 ) -> Result<ResponseOk<JsonReport>, HttpError> {
     let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
     let json = get_one_inner(
         &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
+        query_params.into_inner(),
         auth_user.as_ref(),
     )
     .await?;
@@ -464,6 +758,7 @@ async fn get_one_inner(
     log: &Logger,
     context: &ApiContext,
     path_params: ProjReportParams,
+    query_params: ProjReportQuery,
     auth_user: Option<&AuthUser>,
 ) -> Result<JsonReport, HttpError> {
     let query_project = QueryProject::is_allowed_public(
@@ -482,7 +777,222 @@ async fn get_one_inner(
         ))?;
 
     // Separate out this query to prevent a deadlock when getting the conn_lock
-    report.into_json(log, context).await
+    report
+        .into_json(log, context, query_params.results.unwrap_or(true))
+        .await
+}
+
+pub type ProjReportResultsPagination = JsonPagination<ProjReportResultsSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjReportResultsSort {
+    /// Sort by iteration and then by benchmark name.
+    #[default]
+    Iteration,
+}
+
+// The pagination params are included directly,
+// as `dropshot` does not allow for another query extractor alongside the bearer token and path.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjReportResultsQuery {
+    /// The field to sort by.
+    /// If not specified, the default sort field is used.
+    pub sort: Option<ProjReportResultsSort>,
+    /// The direction to sort by.
+    /// If not specified, the default sort direction is used.
+    pub direction: Option<JsonDirection>,
+    /// The number of items to return per page.
+    /// If not specified, the default number of items per page (8) is used.
+    pub per_page: Option<u8>,
+    /// The page number to return.
+    /// If not specified, the first page is returned.
+    pub page: Option<u32>,
+    /// Filter by iteration.
+    pub iteration: Option<Iteration>,
+    /// Search by benchmark name, slug, or UUID.
+    pub search: Option<Search>,
+}
+
+#[derive(Debug)]
+struct ProjReportResultsFilter {
+    iteration: Option<Iteration>,
+    search: Option<Search>,
+}
+
+impl ProjReportResultsQuery {
+    fn split(self) -> (ProjReportResultsPagination, ProjReportResultsFilter) {
+        let Self {
+            sort,
+            direction,
+            per_page,
+            page,
+            iteration,
+            search,
+        } = self;
+        (
+            JsonPagination {
+                sort,
+                direction,
+                per_page,
+                page,
+            },
+            ProjReportResultsFilter { iteration, search },
+        )
+    }
+}
+
+options_endpoint! {
+    proj_report_results_options,
+    "/v0/projects/{project}/reports/{report}/results",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportParams>, _query_params: Query<ProjReportResultsQuery>),
+    [Get]
+}
+
+/// List the results for a report
+///
+/// List the benchmark results for a report, one entry per benchmark per iteration.
+/// This is useful for reports with many benchmarks, where embedding all of the results in the report is too large.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// By default, the results are sorted by iteration and then by benchmark name.
+/// The HTTP response header `X-Total-Count` contains the total number of results.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/reports/{report}/results",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_results_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjReportParams>,
+    query_params: Query<ProjReportResultsQuery>,
+) -> Result<ResponseOk<JsonReportResultList>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let (pagination_params, filter_params) = query_params.into_inner().split();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
+    let (json, total_count) = get_results_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        pagination_params,
+        filter_params,
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok_with_total_count(
+        json,
+        auth_user.is_some(),
+        total_count,
+        &page_links,
+    ))
+}
+
+macro_rules! report_results_query {
+    ($report_id:expr, $query_params:expr) => {{
+        let mut query = schema::report_benchmark::table
+            .inner_join(schema::benchmark::table)
+            .filter(schema::report_benchmark::report_id.eq($report_id))
+            .into_boxed();
+        if let Some(iteration) = $query_params.iteration {
+            query = query.filter(schema::report_benchmark::iteration.eq(iteration));
+        }
+        if let Some(search) = $query_params.search.as_ref() {
+            query = query.filter(
+                schema::benchmark::name
+                    .like(search)
+                    .or(schema::benchmark::slug.like(search))
+                    .or(schema::benchmark::uuid.like(search)),
+            );
+        }
+        query
+    }};
+}
+
+async fn get_results_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjReportParams,
+    pagination_params: ProjReportResultsPagination,
+    query_params: ProjReportResultsFilter,
+    auth_user: Option<&AuthUser>,
+) -> Result<(JsonReportResultList, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let report_id = QueryReport::belonging_to(&query_project)
+        .filter(schema::report::uuid.eq(path_params.report.to_string()))
+        .select(schema::report::id)
+        .first::<ReportId>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Report,
+            (&query_project, path_params.report)
+        ))?;
+
+    // Page through the report benchmarks first,
+    // and then only get the results for the report benchmarks on this page.
+    let query = report_results_query!(report_id, query_params);
+    let ascending = match pagination_params.direction {
+        Some(JsonDirection::Asc) | None => true,
+        Some(JsonDirection::Desc) => false,
+    };
+    let query = match pagination_params.order() {
+        ProjReportResultsSort::Iteration => {
+            if ascending {
+                query.order((
+                    schema::report_benchmark::iteration.asc(),
+                    schema::benchmark::name.asc(),
+                ))
+            } else {
+                query.order((
+                    schema::report_benchmark::iteration.desc(),
+                    schema::benchmark::name.desc(),
+                ))
+            }
+        },
+    };
+    let report_benchmark_ids = query
+        .select(schema::report_benchmark::id)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<ReportBenchmarkId>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ReportBenchmark,
+            (&query_project, report_id, &pagination_params, &query_params)
+        ))?;
+
+    // The results are always grouped in ascending order
+    let results = get_report_results(
+        log,
+        context,
+        &query_project,
+        report_id,
+        Some(report_benchmark_ids),
+    )
+    .await?
+    .into_iter()
+    .flatten();
+    let json_results = if ascending {
+        results.collect()
+    } else {
+        results.rev().collect()
+    };
+
+    let total_count = report_results_query!(report_id, query_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ReportBenchmark,
+            (&query_project, report_id, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_results, total_count))
 }
 
 /// Delete a report
@@ -520,92 +1030,186 @@ async fn delete_inner(
         Permission::Delete,
     )?;
 
-    let (report_id, version_id) = QueryReport::belonging_to(&query_project)
-        .filter(schema::report::uuid.eq(path_params.report.to_string()))
+    delete_report(context, &query_project, path_params.report).await
+}
+
+async fn delete_report(
+    context: &ApiContext,
+    query_project: &QueryProject,
+    report_uuid: ReportUuid,
+) -> Result<(), HttpError> {
+    let (report_id, version_id) = QueryReport::belonging_to(query_project)
+        .filter(schema::report::uuid.eq(report_uuid.to_string()))
         .select((schema::report::id, schema::report::version_id))
         .first::<(ReportId, VersionId)>(conn_lock!(context))
         .map_err(resource_not_found_err!(
             Report,
-            (&query_project, path_params.report)
+            (query_project, report_uuid)
         ))?;
-    diesel::delete(schema::report::table.filter(schema::report::id.eq(report_id)))
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Report, report_id))?;
-
-    // If there are no more reports for this version, delete the version
-    // This is necessary because multiple reports can use the same version via a git hash
-    // This will cascade and delete all head versions for this version
-    // Before doing so, decrement all greater versions
-    // Otherwise, just return since the version is still in use
-    if schema::report::table
-        .filter(schema::report::version_id.eq(version_id))
-        .select(count(schema::report::id))
-        .first::<i64>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Version,
-            (&query_project, report_id, version_id)
-        ))?
-        != 0
-    {
-        return Ok(());
+    // Get any attachments that are stored in the data store before they are deleted along with the report
+    let external_attachments = if context.database.data_store.is_some() {
+        QueryReportAttachment::external_keys(conn_lock!(context), report_id)?
+    } else {
+        Vec::new()
+    };
+    conn_lock!(context, |conn| QueryReport::delete(
+        conn, report_id, version_id
+    ))?;
+    if let Some(data_store) = context.database.data_store.as_ref() {
+        for key in external_attachments {
+            if let Err(e) = data_store.delete(&key).await {
+                debug_assert!(
+                    false,
+                    "Failed to delete attachment ({key}) from data store: {e}"
+                );
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+            }
+        }
     }
 
-    let query_version = QueryVersion::get(conn_lock!(context), version_id)?;
-    // Get all heads that use this version
-    let heads = schema::head::table
-        .inner_join(
-            schema::head_version::table.on(schema::head_version::head_id.eq(schema::head::id)),
-        )
-        .filter(schema::head_version::version_id.eq(version_id))
-        .select(schema::head::id)
-        .load::<HeadId>(conn_lock!(context))
+    Ok(())
+}
+
+options_endpoint! {
+    proj_report_raw_options,
+    "/v0/projects/{project}/reports/{report}/raw",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportParams>),
+    [Get]
+}
+
+/// View the raw results for a report
+///
+/// View the raw benchmark harness output that was submitted for a report.
+/// The raw output is only available if it was requested to be stored when the report was created.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/reports/{report}/raw",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_raw_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjReportParams>,
+) -> Result<ResponseOk<JsonReportRaw>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_raw_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_raw_inner(
+    context: &ApiContext,
+    path_params: ProjReportParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonReportRaw, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let report_id = QueryReport::belonging_to(&query_project)
+        .filter(schema::report::uuid.eq(path_params.report.to_string()))
+        .select(schema::report::id)
+        .first::<ReportId>(conn_lock!(context))
         .map_err(resource_not_found_err!(
-            Head,
-            (&query_project, report_id, version_id)
+            Report,
+            (&query_project, path_params.report)
         ))?;
 
-    let mut version_map = HashMap::new();
-    // Get all versions greater than this one for each of the heads
-    for head_id in heads {
-        schema::version::table
-            .inner_join(schema::head_version::table)
-            .filter(schema::version::number.gt(query_version.number))
-            .filter(schema::head_version::head_id.eq(head_id))
-            .select((schema::version::id, schema::version::number))
-            .load::<(VersionId, VersionNumber)>(conn_lock!(context))
-            .map_err(resource_not_found_err!(
-                Version,
-                (&query_project, report_id, head_id, &query_version)
-            ))?
-            .into_iter()
-            .for_each(|(version_id, version_number)| {
-                version_map.insert(version_id, version_number);
-            });
-    }
+    QueryReportRaw::from_report_id(conn_lock!(context), report_id)?
+        .into_json(path_params.report)
+        .await
+}
 
-    // For each version greater than this one, decrement the version number
-    for (version_id, version_number) in version_map {
-        if let Err(e) =
-            diesel::update(schema::version::table.filter(schema::version::id.eq(version_id)))
-                .set(schema::version::number.eq(version_number.decrement()))
-                .execute(conn_lock!(context))
-        {
-            debug_assert!(
-                false,
-                "Failed to decrement version ({version_id}) number ({version_number}): {e}"
-            );
-            #[cfg(feature = "sentry")]
-            sentry::capture_error(&e);
-        }
-    }
+options_endpoint! {
+    proj_report_rerun_options,
+    "/v0/projects/{project}/reports/{report}/rerun",
+    ["projects", "reports"],
+    (_path_params: Path<ProjReportParams>),
+    [Post]
+}
 
-    // Finally delete the dangling version
-    diesel::delete(schema::version::table.filter(schema::version::id.eq(version_id)))
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(
-            Version,
-            (&query_project, report_id, &query_version)
+/// Request a rerun for a report
+///
+/// Request that the benchmarks for a report be run again.
+/// A signed `rerun` event is sent to each of the project webhooks,
+/// so that CI systems can benchmark the same branch version on the same testbed again.
+/// This is useful for checking whether a regression was just noise.
+/// The project must have at least one webhook.
+/// The user must have `create` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/reports/{report}/rerun",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_report_rerun_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjReportParams>,
+) -> Result<ResponseAccepted<JsonReportRerun>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_rerun_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_accepted(json))
+}
+
+async fn post_rerun_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjReportParams,
+    auth_user: &AuthUser,
+) -> Result<JsonReportRerun, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    let report = QueryReport::belonging_to(&query_project)
+        .filter(schema::report::uuid.eq(path_params.report.to_string()))
+        .first::<QueryReport>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Report,
+            (&query_project, path_params.report)
         ))?;
 
-    Ok(())
+    let webhooks = QueryWebhook::all_for_project(conn_lock!(context), &query_project)?
+        .into_iter()
+        .map(|webhook| webhook.decrypt(&context.encryption))
+        .collect::<Result<Vec<_>, _>>()?;
+    if webhooks.is_empty() {
+        return Err(bad_request_error(format!(
+            "Project ({}) does not have any webhooks to send the rerun request to. Add a webhook for your CI system first.",
+            query_project.slug
+        )));
+    }
+
+    // Separate out this query to prevent a deadlock when getting the conn_lock
+    let json_report = report.into_json(log, context, false).await?;
+    let requester = auth_user.user.clone().into_pub_json();
+    let webhooks = notify_rerun(log, webhooks, &json_report, &requester);
+
+    Ok(JsonReportRerun {
+        report: json_report.uuid,
+        webhooks,
+        created: DateTime::now(),
+    })
 }
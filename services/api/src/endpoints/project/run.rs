@@ -0,0 +1,135 @@
+use std::net::IpAddr;
+
+use bencher_json::{JsonNewOrganization, JsonNewProject, JsonNewRun, JsonRun, Secret};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, RequestContext};
+use slog::Logger;
+
+use crate::{
+    conn_lock,
+    context::{Anonymous, ApiContext},
+    endpoints::endpoint::{options_endpoint, Post, ResponseCreated},
+    error::{forbidden_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        organization::{InsertOrganization, QueryOrganization},
+        project::{
+            branch::protection::BranchAuth,
+            unclaimed_project::{InsertUnclaimedProject, QueryUnclaimedProject},
+            InsertProject, QueryProject,
+        },
+    },
+    schema,
+    util::{large_body::LargeBody, slug::insert_with_slug},
+};
+
+use super::reports::create_report;
+
+options_endpoint! {
+    run_options,
+    "/v0/run",
+    ["projects", "reports"],
+    (),
+    [Post]
+}
+
+/// Run anonymously
+///
+/// Create a report without a Bencher account.
+/// If no key is provided, then a new unclaimed project is created.
+/// Otherwise, the report is added to the unclaimed project for the key.
+/// The key is returned so that it can be used for subsequent runs
+/// and to claim the project after signing up.
+/// Anonymous runs must be enabled in the server config.
+/// They are rate limited per client IP address, in the number of new unclaimed projects,
+/// and in the number of reports per unclaimed project.
+/// If using the Bencher CLI, it is recommended to use the `bencher run --anonymous` subcommand
+/// instead of trying to create a report manually.
+#[endpoint {
+    method = POST,
+    path =  "/v0/run",
+    tags = ["projects", "reports"]
+}]
+pub async fn run_post(
+    rqctx: RequestContext<ApiContext>,
+    body: LargeBody<JsonNewRun>,
+) -> Result<ResponseCreated<JsonRun>, HttpError> {
+    let json_run = body
+        .into_inner(rqctx.context().large_body_max_bytes)
+        .await?;
+    let json = post_inner(
+        &rqctx.log,
+        rqctx.context(),
+        rqctx.request.remote_addr().ip(),
+        json_run,
+    )
+    .await?;
+    Ok(Post::pub_response_created(json))
+}
+
+async fn post_inner(
+    log: &Logger,
+    context: &ApiContext,
+    client: IpAddr,
+    json_run: JsonNewRun,
+) -> Result<JsonRun, HttpError> {
+    let Some(anonymous) = &context.anonymous else {
+        return Err(forbidden_error(
+            "Anonymous runs are not enabled on this server. Sign up for an account and create a project instead.",
+        ));
+    };
+    anonymous.check_client(client).await?;
+
+    let JsonNewRun { key, report } = json_run;
+    let (query_project, key) = if let Some(key) = key {
+        let query_project = conn_lock!(context, |conn| {
+            let unclaimed_project = QueryUnclaimedProject::from_key(conn, &key)?;
+            anonymous.check_reports(conn, &unclaimed_project)?;
+            unclaimed_project.project(conn)
+        })?;
+        (query_project, key)
+    } else {
+        new_unclaimed_project(log, context, anonymous).await?
+    };
+
+    // Anonymous reports are never from a scoped API token or an organization leader
+    let report = create_report(
+        log,
+        context,
+        query_project,
+        None,
+        BranchAuth::default(),
+        report.into(),
+    )
+    .await?;
+    Ok(JsonRun { key, report })
+}
+
+/// Create a new organization and project that do not have any members.
+/// The key is used to access the project until it is claimed.
+async fn new_unclaimed_project(
+    log: &Logger,
+    context: &ApiContext,
+    anonymous: &Anonymous,
+) -> Result<(QueryProject, Secret), HttpError> {
+    anonymous.check_new_project(conn_lock!(context))?;
+
+    let mut insert_organization =
+        InsertOrganization::from_json(conn_lock!(context), JsonNewOrganization::unclaimed())?;
+    insert_with_slug!(conn_lock!(context), organization, insert_organization)
+        .map_err(resource_conflict_err!(Organization, insert_organization))?;
+    let query_organization = schema::organization::table
+        .filter(schema::organization::uuid.eq(&insert_organization.uuid))
+        .first::<QueryOrganization>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Organization, insert_organization))?;
+
+    let insert_project = InsertProject::from_json(
+        conn_lock!(context),
+        &query_organization,
+        JsonNewProject::unclaimed(),
+    )?;
+    let query_project = insert_project.insert(log, context).await?;
+    let key = InsertUnclaimedProject::insert(conn_lock!(context), query_project.id)?;
+    slog::debug!(log, "Created unclaimed project: {query_project:?}");
+
+    Ok((query_project, key))
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use bencher_json::{
+    project::{
+        boundary::BoundaryLimit,
+        measure::built_in,
+        schema::{
+            JsonMetricKindBenchmark, JsonMetricKindTestbed, JsonSchemaBranch, JsonSchemaMeasure,
+            JsonSchemaTestbed, JsonSchemaThreshold, MetricDirection,
+        },
+    },
+    BenchmarkName, BenchmarkUuid, BranchName, JsonMetricKind, JsonMetricKinds, JsonProjectSchema,
+    MeasureUuid, ResourceId, ResourceName, Slug, TestbedUuid,
+};
+use diesel::{
+    BelongingToDsl, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use dropshot::{endpoint, HttpError, Path, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
+    error::resource_not_found_err,
+    model::{
+        project::{
+            branch::QueryBranch,
+            measure::{MeasureId, QueryMeasure},
+            testbed::QueryTestbed,
+            threshold::{
+                model::{ModelId, QueryModel},
+                QueryThreshold,
+            },
+            QueryProject,
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjSchemaParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+options_endpoint! {
+    proj_schema_options,
+    "/v0/projects/{project}/schema",
+    ["projects"],
+    (_path_params: Path<ProjSchemaParams>),
+    [Get]
+}
+
+/// Export a project schema
+///
+/// Export the full configuration of a project as canonical JSON.
+/// This includes the project's unarchived branches, testbeds, measures, and their thresholds.
+/// All lists are sorted by slug, so exports can be diffed and versioned.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/schema",
+    tags = ["projects"]
+}]
+pub async fn proj_schema_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjSchemaParams>,
+) -> Result<ResponseOk<JsonProjectSchema>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjSchemaParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonProjectSchema, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let branches = QueryBranch::belonging_to(&query_project)
+        .filter(schema::branch::archived.is_null())
+        .order(schema::branch::slug)
+        .select((schema::branch::name, schema::branch::slug))
+        .load::<(BranchName, Slug)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Branch, &query_project))?
+        .into_iter()
+        .map(|(name, slug)| JsonSchemaBranch { name, slug })
+        .collect();
+
+    let testbeds = QueryTestbed::belonging_to(&query_project)
+        .filter(schema::testbed::archived.is_null())
+        .order(schema::testbed::slug)
+        .select((schema::testbed::name, schema::testbed::slug))
+        .load::<(ResourceName, Slug)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Testbed, &query_project))?
+        .into_iter()
+        .map(|(name, slug)| JsonSchemaTestbed { name, slug })
+        .collect();
+
+    let measures = QueryMeasure::belonging_to(&query_project)
+        .filter(schema::measure::archived.is_null())
+        .order(schema::measure::slug)
+        .select((
+            schema::measure::name,
+            schema::measure::slug,
+            schema::measure::units,
+        ))
+        .load::<(ResourceName, Slug, ResourceName)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Measure, &query_project))?
+        .into_iter()
+        .map(|(name, slug, units)| JsonSchemaMeasure { name, slug, units })
+        .collect();
+
+    let thresholds = QueryThreshold::belonging_to(&query_project)
+        .inner_join(schema::branch::table)
+        .inner_join(schema::testbed::table)
+        .inner_join(schema::measure::table)
+        .filter(schema::branch::archived.is_null())
+        .filter(schema::testbed::archived.is_null())
+        .filter(schema::measure::archived.is_null())
+        .order((
+            schema::branch::slug,
+            schema::testbed::slug,
+            schema::measure::slug,
+        ))
+        .select((
+            schema::branch::slug,
+            schema::testbed::slug,
+            schema::measure::slug,
+            schema::threshold::model_id,
+        ))
+        .load::<(Slug, Slug, Slug, Option<ModelId>)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Threshold, &query_project))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_thresholds = Vec::with_capacity(thresholds.len());
+    for (branch, testbed, measure, model_id) in thresholds {
+        let model = if let Some(model_id) = model_id {
+            Some(QueryModel::get(conn_lock!(context), model_id)?.into_model())
+        } else {
+            None
+        };
+        json_thresholds.push(JsonSchemaThreshold {
+            branch,
+            testbed,
+            measure,
+            model,
+        });
+    }
+
+    let QueryProject {
+        name,
+        slug,
+        url,
+        visibility,
+        ..
+    } = query_project;
+    Ok(JsonProjectSchema {
+        name,
+        slug,
+        url,
+        visibility,
+        branches,
+        testbeds,
+        measures,
+        thresholds: json_thresholds,
+    })
+}
+
+options_endpoint! {
+    proj_schema_metric_kinds_options,
+    "/v0/projects/{project}/schema/metric-kinds",
+    ["projects"],
+    (_path_params: Path<ProjSchemaParams>),
+    [Get]
+}
+
+/// List the metric kinds for a project
+///
+/// List the kinds of metrics for a project, which are backed by its unarchived measures.
+/// Each metric kind includes its units, the direction in which it improves,
+/// and the unarchived benchmarks and testbeds that have metrics for it.
+/// This allows clients to populate their options without hardcoding them.
+/// All lists are sorted by slug.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/schema/metric-kinds",
+    tags = ["projects"]
+}]
+pub async fn proj_schema_metric_kinds_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjSchemaParams>,
+) -> Result<ResponseOk<JsonMetricKinds>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_metric_kinds_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+#[allow(clippy::too_many_lines)]
+async fn get_metric_kinds_inner(
+    context: &ApiContext,
+    path_params: ProjSchemaParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonMetricKinds, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let measures = QueryMeasure::belonging_to(&query_project)
+        .filter(schema::measure::archived.is_null())
+        .order(schema::measure::slug)
+        .select((
+            schema::measure::id,
+            schema::measure::uuid,
+            schema::measure::name,
+            schema::measure::slug,
+            schema::measure::units,
+        ))
+        .load::<(MeasureId, MeasureUuid, ResourceName, Slug, ResourceName)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Measure, &query_project))?;
+
+    let mut benchmarks = HashMap::<MeasureId, Vec<JsonMetricKindBenchmark>>::new();
+    for (measure_id, uuid, name, slug) in schema::metric::table
+        .inner_join(schema::measure::table)
+        .inner_join(schema::report_benchmark::table.inner_join(schema::benchmark::table))
+        .filter(schema::measure::project_id.eq(query_project.id))
+        .filter(schema::benchmark::archived.is_null())
+        .select((
+            schema::metric::measure_id,
+            schema::benchmark::uuid,
+            schema::benchmark::name,
+            schema::benchmark::slug,
+        ))
+        .distinct()
+        .order((schema::metric::measure_id, schema::benchmark::slug))
+        .load::<(MeasureId, BenchmarkUuid, BenchmarkName, Slug)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Benchmark, &query_project))?
+    {
+        benchmarks
+            .entry(measure_id)
+            .or_default()
+            .push(JsonMetricKindBenchmark { uuid, name, slug });
+    }
+
+    let mut testbeds = HashMap::<MeasureId, Vec<JsonMetricKindTestbed>>::new();
+    for (measure_id, uuid, name, slug) in schema::metric::table
+        .inner_join(schema::measure::table)
+        .inner_join(
+            schema::report_benchmark::table
+                .inner_join(schema::report::table.inner_join(schema::testbed::table)),
+        )
+        .filter(schema::measure::project_id.eq(query_project.id))
+        .filter(schema::testbed::archived.is_null())
+        .select((
+            schema::metric::measure_id,
+            schema::testbed::uuid,
+            schema::testbed::name,
+            schema::testbed::slug,
+        ))
+        .distinct()
+        .order((schema::metric::measure_id, schema::testbed::slug))
+        .load::<(MeasureId, TestbedUuid, ResourceName, Slug)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Testbed, &query_project))?
+    {
+        testbeds
+            .entry(measure_id)
+            .or_default()
+            .push(JsonMetricKindTestbed { uuid, name, slug });
+    }
+
+    // Track which boundaries are used by the current threshold models for each measure
+    let mut boundaries = HashMap::<MeasureId, (bool, bool)>::new();
+    for (measure_id, lower_boundary, upper_boundary) in QueryThreshold::belonging_to(&query_project)
+        .inner_join(
+            schema::model::table.on(schema::threshold::model_id.eq(schema::model::id.nullable())),
+        )
+        .select((
+            schema::threshold::measure_id,
+            schema::model::lower_boundary,
+            schema::model::upper_boundary,
+        ))
+        .load::<(MeasureId, Option<f64>, Option<f64>)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Threshold, &query_project))?
+    {
+        let (lower, upper) = boundaries.entry(measure_id).or_default();
+        *lower |= lower_boundary.is_some();
+        *upper |= upper_boundary.is_some();
+    }
+
+    Ok(measures
+        .into_iter()
+        .map(|(measure_id, uuid, name, slug, units)| {
+            let direction = match boundaries.get(&measure_id) {
+                Some((false, true)) => Some(MetricDirection::LowerIsBetter),
+                Some((true, false)) => Some(MetricDirection::HigherIsBetter),
+                // Both boundaries are used, so the direction is ambiguous
+                Some((true, true)) => None,
+                Some((false, false)) | None => built_in_direction(&slug),
+            };
+            JsonMetricKind {
+                uuid,
+                name,
+                slug,
+                units,
+                direction,
+                benchmarks: benchmarks.remove(&measure_id).unwrap_or_default(),
+                testbeds: testbeds.remove(&measure_id).unwrap_or_default(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .into())
+}
+
+fn built_in_direction(slug: &Slug) -> Option<MetricDirection> {
+    built_in::catalog()
+        .into_iter()
+        .find_map(|(measure, limit)| (measure.slug.as_ref() == Some(slug)).then_some(limit))
+        .map(|limit| match limit {
+            BoundaryLimit::Lower => MetricDirection::HigherIsBetter,
+            BoundaryLimit::Upper => MetricDirection::LowerIsBetter,
+        })
+}
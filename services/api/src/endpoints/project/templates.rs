@@ -0,0 +1,267 @@
+use bencher_json::{
+    JsonNewThresholdTemplate, JsonThresholdTemplate, JsonThresholdTemplates, ResourceId,
+    ThresholdTemplateUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::{bad_request_error, resource_conflict_err},
+    model::{
+        organization::QueryOrganization,
+        project::{
+            threshold::template::{InsertThresholdTemplate, QueryThresholdTemplate},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjTemplatesParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+options_endpoint! {
+    proj_templates_options,
+    "/v0/projects/{project}/templates",
+    ["projects", "thresholds"],
+    (_path_params: Path<ProjTemplatesParams>),
+    [Get, Post]
+}
+
+/// List threshold templates for a project
+///
+/// List all threshold templates for a project.
+/// These templates take precedence over the organization threshold templates.
+/// The user must have `view` permissions for the project.
+/// Templates are sorted by creation date.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/templates",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_templates_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTemplatesParams>,
+) -> Result<ResponseOk<JsonThresholdTemplates>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjTemplatesParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplates, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    let query_organization =
+        QueryOrganization::get(conn_lock!(context), query_project.organization_id)?;
+
+    Ok(
+        QueryThresholdTemplate::all_for_project(conn_lock!(context), &query_project)?
+            .into_iter()
+            .map(|template| template.into_json(&query_organization, Some(&query_project)))
+            .collect(),
+    )
+}
+
+/// Create a threshold template for a project
+///
+/// Create a threshold template for a project.
+/// When a report is created for a branch and testbed that do not have a threshold for the template measure,
+/// a new threshold is created with the template model.
+/// It takes precedence over an organization threshold template for the same measure.
+/// Existing thresholds are not changed.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/templates",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_template_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTemplatesParams>,
+    body: TypedBody<JsonNewThresholdTemplate>,
+) -> Result<ResponseCreated<JsonThresholdTemplate>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjTemplatesParams,
+    json_template: JsonNewThresholdTemplate,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplate, HttpError> {
+    // Validate the new model
+    json_template.model.validate().map_err(bad_request_error)?;
+
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+    let query_organization =
+        QueryOrganization::get(conn_lock!(context), query_project.organization_id)?;
+
+    let insert_template = InsertThresholdTemplate::from_json(
+        query_project.organization_id,
+        Some(query_project.id),
+        json_template,
+    );
+    diesel::insert_into(schema::threshold_template::table)
+        .values(&insert_template)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            ThresholdTemplate,
+            (&query_project, &insert_template)
+        ))?;
+
+    QueryThresholdTemplate::get_for_project(
+        conn_lock!(context),
+        &query_project,
+        insert_template.uuid,
+    )
+    .map(|template| template.into_json(&query_organization, Some(&query_project)))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjTemplateParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a threshold template.
+    pub template: ThresholdTemplateUuid,
+}
+
+options_endpoint! {
+    proj_template_options,
+    "/v0/projects/{project}/templates/{template}",
+    ["projects", "thresholds"],
+    (_path_params: Path<ProjTemplateParams>),
+    [Get, Delete]
+}
+
+/// View a threshold template for a project
+///
+/// View a threshold template for a project.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/templates/{template}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_template_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTemplateParams>,
+) -> Result<ResponseOk<JsonThresholdTemplate>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjTemplateParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdTemplate, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    let query_organization =
+        QueryOrganization::get(conn_lock!(context), query_project.organization_id)?;
+
+    QueryThresholdTemplate::get_for_project(
+        conn_lock!(context),
+        &query_project,
+        path_params.template,
+    )
+    .map(|template| template.into_json(&query_organization, Some(&query_project)))
+}
+
+/// Delete a threshold template for a project
+///
+/// Delete a threshold template for a project.
+/// Thresholds that have already been created from the template are not changed.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/templates/{template}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_template_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTemplateParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjTemplateParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_template = QueryThresholdTemplate::get_for_project(
+        conn_lock!(context),
+        &query_project,
+        path_params.template,
+    )?;
+
+    diesel::delete(
+        schema::threshold_template::table
+            .filter(schema::threshold_template::id.eq(query_template.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(
+        ThresholdTemplate,
+        query_template.uuid
+    ))?;
+
+    Ok(())
+}
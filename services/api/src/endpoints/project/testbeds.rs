@@ -1,6 +1,6 @@
 use bencher_json::{
     project::testbed::JsonUpdateTestbed, JsonDirection, JsonNewTestbed, JsonPagination,
-    JsonTestbed, JsonTestbeds, ResourceId, ResourceName,
+    JsonTestbed, JsonTestbeds, ResourceId, ResourceName, Slug,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -8,17 +8,15 @@ use diesel::{
     TextExpressionMethods,
 };
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use http::StatusCode;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseDeleted, ResponseOk, ResponseUpserted,
     },
     error::{resource_conflict_err, resource_not_found_err},
     model::{
@@ -29,7 +27,11 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+        slug::insert_with_slug,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -59,19 +61,16 @@ pub struct ProjTestbedsQuery {
     pub archived: Option<bool>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/testbeds",
-    tags = ["projects", "testbeds"]
-}]
-pub async fn proj_testbeds_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjTestbedsParams>,
-    _pagination_params: Query<ProjTestbedsPagination>,
-    _query_params: Query<ProjTestbedsQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_testbeds_options,
+    "/v0/projects/{project}/testbeds",
+    ["projects", "testbeds"],
+    (
+        _path_params: Path<ProjTestbedsParams>,
+        _pagination_params: Query<ProjTestbedsPagination>,
+        _query_params: Query<ProjTestbedsQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List testbeds for a project
@@ -93,11 +92,13 @@ pub async fn proj_testbeds_get(
     query_params: Query<ProjTestbedsQuery>,
 ) -> Result<ResponseOk<JsonTestbeds>, HttpError> {
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
@@ -105,6 +106,7 @@ pub async fn proj_testbeds_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -185,6 +187,8 @@ fn get_ls_query<'q>(
 /// Create a testbed
 ///
 /// Create a testbed for a project.
+/// If `upsert` is set to `true` and a testbed with the same name or slug already exists,
+/// then the existing testbed is returned instead with a `200 OK` status.
 /// The user must have `create` permissions for the project.
 #[endpoint {
     method = POST,
@@ -196,16 +200,16 @@ pub async fn proj_testbed_post(
     bearer_token: BearerToken,
     path_params: Path<ProjTestbedsParams>,
     body: TypedBody<JsonNewTestbed>,
-) -> Result<ResponseCreated<JsonTestbed>, HttpError> {
+) -> Result<ResponseUpserted<JsonTestbed>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(
+    let (json, created) = post_inner(
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
         &auth_user,
     )
     .await?;
-    Ok(Post::auth_response_created(json))
+    Ok(Post::auth_response_upserted(json, created))
 }
 
 async fn post_inner(
@@ -213,7 +217,7 @@ async fn post_inner(
     path_params: ProjTestbedsParams,
     json_testbed: JsonNewTestbed,
     auth_user: &AuthUser,
-) -> Result<JsonTestbed, HttpError> {
+) -> Result<(JsonTestbed, bool), HttpError> {
     // Verify that the user is allowed
     let query_project = QueryProject::is_allowed(
         conn_lock!(context),
@@ -223,18 +227,54 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    let insert_testbed =
-        InsertTestbed::from_json(conn_lock!(context), query_project.id, json_testbed.clone())?;
+    let upsert = json_testbed.upsert.unwrap_or_default();
+    let name = json_testbed.name.clone();
+    let slug = Slug::unwrap_or_new(&name, json_testbed.slug.clone());
+    let existing_testbed = if upsert {
+        QueryTestbed::from_name_or_slug(
+            conn_lock!(context),
+            query_project.id,
+            name.as_ref(),
+            &slug,
+        )?
+    } else {
+        None
+    };
+    if let Some(query_testbed) = existing_testbed {
+        return Ok((query_testbed.into_json_for_project(&query_project), false));
+    }
 
-    diesel::insert_into(schema::testbed::table)
-        .values(&insert_testbed)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Testbed, insert_testbed))?;
+    let mut insert_testbed =
+        InsertTestbed::from_json(conn_lock!(context), query_project.id, json_testbed)?;
+
+    // The connection lock must be released before looking up an existing testbed
+    let insert_result = insert_with_slug!(
+        conn_lock!(context),
+        testbed,
+        insert_testbed,
+        upsert = upsert
+    )
+    .map_err(resource_conflict_err!(Testbed, insert_testbed));
+    match insert_result {
+        Ok(_) => {},
+        // A concurrent upsert may have created the same testbed since it was looked up
+        Err(e) if upsert && e.status_code == StatusCode::CONFLICT => {
+            let query_testbed = QueryTestbed::from_name_or_slug(
+                conn_lock!(context),
+                query_project.id,
+                name.as_ref(),
+                &slug,
+            )?
+            .ok_or(e)?;
+            return Ok((query_testbed.into_json_for_project(&query_project), false));
+        },
+        Err(e) => return Err(e),
+    }
 
     schema::testbed::table
         .filter(schema::testbed::uuid.eq(&insert_testbed.uuid))
         .first::<QueryTestbed>(conn_lock!(context))
-        .map(|testbed| testbed.into_json_for_project(&query_project))
+        .map(|testbed| (testbed.into_json_for_project(&query_project), true))
         .map_err(resource_not_found_err!(Testbed, insert_testbed))
 }
 
@@ -246,17 +286,12 @@ pub struct ProjTestbedParams {
     pub testbed: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/testbeds/{testbed}",
-    tags = ["projects", "testbeds"]
-}]
-pub async fn proj_testbed_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjTestbedParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+options_endpoint! {
+    proj_testbed_options,
+    "/v0/projects/{project}/testbeds/{testbed}",
+    ["projects", "testbeds"],
+    (_path_params: Path<ProjTestbedParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a testbed
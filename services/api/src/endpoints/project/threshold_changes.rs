@@ -0,0 +1,331 @@
+use bencher_json::{
+    JsonNewThresholdChange, JsonThresholdChange, JsonThresholdChanges, ResourceId,
+    ThresholdChangeUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Get, Post, ResponseCreated, ResponseOk},
+    error::{bad_request_error, forbidden_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            branch::{protection::BranchAuth, QueryBranch},
+            threshold::{
+                change::{InsertThresholdChange, QueryThresholdChange},
+                QueryThreshold,
+            },
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjThresholdChangesParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjThresholdChangesQuery {
+    /// Filter by whether the change is still pending approval.
+    pub pending: Option<bool>,
+}
+
+options_endpoint! {
+    proj_threshold_changes_options,
+    "/v0/projects/{project}/threshold-changes",
+    ["projects", "thresholds"],
+    (
+        _path_params: Path<ProjThresholdChangesParams>,
+        _query_params: Query<ProjThresholdChangesQuery>
+    ),
+    [Get, Post]
+}
+
+/// List threshold changes for a project
+///
+/// List all requested threshold changes for a project, both pending and approved.
+/// Each change records the user who requested it and the user who approved it.
+/// The user must have `view` permissions for the project.
+/// Threshold changes are sorted by creation date, with the most recent first.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/threshold-changes",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_changes_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjThresholdChangesParams>,
+    query_params: Query<ProjThresholdChangesQuery>,
+) -> Result<ResponseOk<JsonThresholdChanges>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjThresholdChangesParams,
+    query_params: ProjThresholdChangesQuery,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdChanges, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let mut query = QueryThresholdChange::belonging_to(&query_project).into_boxed();
+    match query_params.pending {
+        Some(true) => query = query.filter(schema::threshold_change::approved.is_null()),
+        Some(false) => query = query.filter(schema::threshold_change::approved.is_not_null()),
+        None => {},
+    }
+    let threshold_changes = query
+        .order(schema::threshold_change::created.desc())
+        .load::<QueryThresholdChange>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ThresholdChange,
+            (&query_project, &query_params)
+        ))?;
+
+    conn_lock!(context, |conn| threshold_changes
+        .into_iter()
+        .map(|threshold_change| threshold_change.into_json_for_project(conn, &query_project))
+        .collect())
+}
+
+/// Request a threshold change for a project
+///
+/// Request a change to a threshold for a branch with protected thresholds.
+/// The thresholds for a protected branch can not be updated directly.
+/// Instead, the change is pending until it is approved by a different user with `manage` permissions for the project.
+/// If the model is not set, then the change removes the current model for the threshold.
+/// The user must have `edit` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/threshold-changes",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_change_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjThresholdChangesParams>,
+    body: TypedBody<JsonNewThresholdChange>,
+) -> Result<ResponseCreated<JsonThresholdChange>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjThresholdChangesParams,
+    json_threshold_change: JsonNewThresholdChange,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdChange, HttpError> {
+    // Validate the new model
+    if let Some(model) = json_threshold_change.model {
+        model.validate().map_err(bad_request_error)?;
+    }
+
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let query_threshold = QueryThreshold::get_with_uuid(
+        conn_lock!(context),
+        &query_project,
+        json_threshold_change.threshold,
+    )?;
+    let query_branch = QueryBranch::get(conn_lock!(context), query_threshold.branch_id)?;
+    if !query_branch.protect_thresholds {
+        return Err(bad_request_error(format!(
+            "The thresholds for branch ({}) are not protected. Update the threshold directly instead.",
+            query_branch.uuid
+        )));
+    }
+
+    let insert_threshold_change =
+        InsertThresholdChange::from_json(&query_threshold, auth_user.id(), &json_threshold_change);
+    diesel::insert_into(schema::threshold_change::table)
+        .values(&insert_threshold_change)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            ThresholdChange,
+            (&query_project, &insert_threshold_change)
+        ))?;
+
+    conn_lock!(context, |conn| QueryThresholdChange::get_with_uuid(
+        conn,
+        &query_project,
+        insert_threshold_change.uuid
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjThresholdChangeParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a threshold change.
+    pub change: ThresholdChangeUuid,
+}
+
+options_endpoint! {
+    proj_threshold_change_options,
+    "/v0/projects/{project}/threshold-changes/{change}",
+    ["projects", "thresholds"],
+    (_path_params: Path<ProjThresholdChangeParams>),
+    [Get]
+}
+
+/// View a threshold change for a project
+///
+/// View a requested threshold change for a project.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/threshold-changes/{change}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_change_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjThresholdChangeParams>,
+) -> Result<ResponseOk<JsonThresholdChange>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjThresholdChangeParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdChange, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    conn_lock!(context, |conn| QueryThresholdChange::get_with_uuid(
+        conn,
+        &query_project,
+        path_params.change
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+options_endpoint! {
+    proj_threshold_change_approve_options,
+    "/v0/projects/{project}/threshold-changes/{change}/approve",
+    ["projects", "thresholds"],
+    (_path_params: Path<ProjThresholdChangeParams>),
+    [Post]
+}
+
+/// Approve a threshold change for a project
+///
+/// Approve a pending threshold change for a project and apply it to the threshold.
+/// The user must have `manage` permissions for the project
+/// and must not be the same user who requested the change.
+/// If the change loosens or removes the current model,
+/// then the user must also have `manage` permissions for the organization.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/threshold-changes/{change}/approve",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_threshold_change_approve_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjThresholdChangeParams>,
+) -> Result<ResponseOk<JsonThresholdChange>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = approve_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn approve_inner(
+    context: &ApiContext,
+    path_params: ProjThresholdChangeParams,
+    auth_user: &AuthUser,
+) -> Result<JsonThresholdChange, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_threshold_change = QueryThresholdChange::get_with_uuid(
+        conn_lock!(context),
+        &query_project,
+        path_params.change,
+    )?;
+    if query_threshold_change.approved.is_some() {
+        return Err(bad_request_error(format!(
+            "Threshold change ({}) has already been approved",
+            query_threshold_change.uuid
+        )));
+    }
+    if query_threshold_change.requester_id == auth_user.id() {
+        return Err(forbidden_error(format!(
+            "Threshold change ({}) must be approved by a different user than the one who requested it",
+            query_threshold_change.uuid
+        )));
+    }
+
+    // Verify that the approver is allowed to loosen or remove the current model
+    let model = query_threshold_change.model();
+    let query_threshold =
+        QueryThreshold::get(conn_lock!(context), query_threshold_change.threshold_id)?;
+    let branch_auth = BranchAuth::new(&context.rbac, auth_user, &query_project);
+    query_threshold.check_protected(conn_lock!(context), branch_auth, model)?;
+
+    // Update the current threshold with the new model, if changed
+    query_threshold
+        .update_model_if_changed(context, model)
+        .await?;
+    query_threshold_change.approve(conn_lock!(context), auth_user.id())?;
+
+    conn_lock!(context, |conn| QueryThresholdChange::get(
+        conn,
+        query_threshold_change.id
+    )?
+    .into_json_for_project(conn, &query_project))
+}
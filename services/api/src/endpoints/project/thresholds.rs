@@ -17,11 +17,8 @@ use serde::Deserialize;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{
-            CorsResponse, Delete, Get, Post, Put, ResponseCreated, ResponseDeleted, ResponseOk,
-        },
-        Endpoint,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, Put, ResponseCreated, ResponseDeleted, ResponseOk,
     },
     error::{
         bad_request_error, resource_conflict_err, resource_not_found_err, resource_not_found_error,
@@ -39,7 +36,7 @@ use crate::{
     },
     schema,
     util::{
-        headers::TotalCount,
+        headers::{PageLinks, TotalCount},
         name_id::{filter_branch_name_id, filter_measure_name_id, filter_testbed_name_id},
     },
 };
@@ -62,19 +59,16 @@ pub enum ProjThresholdsSort {
     Modified,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/thresholds",
-    tags = ["projects", "thresholds"]
-}]
-pub async fn proj_thresholds_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjThresholdsParams>,
-    _pagination_params: Query<ProjThresholdsPagination>,
-    _query_params: Query<JsonThresholdQueryParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    proj_thresholds_options,
+    "/v0/projects/{project}/thresholds",
+    ["projects", "thresholds"],
+    (
+        _path_params: Path<ProjThresholdsParams>,
+        _pagination_params: Query<ProjThresholdsPagination>,
+        _query_params: Query<JsonThresholdQueryParams>,
+    ),
+    [Get, Post]
 }
 
 /// List thresholds for a project
@@ -102,11 +96,13 @@ pub async fn proj_thresholds_get(
         .map_err(bad_request_error)?;
 
     let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         json_threshold_query,
     )
     .await?;
@@ -114,6 +110,7 @@ pub async fn proj_thresholds_get(
         json,
         auth_user.is_some(),
         total_count,
+        &page_links,
     ))
 }
 
@@ -238,7 +235,8 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
 ///
 /// Create a threshold for a project.
 /// The user must have `create` permissions for the project.
-/// There can only be one threshold for any unique combination of: branch, testbed, and measure.
+/// There can only be one threshold for any unique combination of: branch, testbed, measure, and benchmark.
+/// If no benchmark is specified, then the threshold applies to all benchmarks without a more specific threshold.
 #[endpoint {
     method = POST,
     path =  "/v0/projects/{project}/thresholds",
@@ -295,6 +293,7 @@ async fn post_inner(
         branch_id,
         testbed_id,
         measure_id,
+        json_threshold.benchmark.clone(),
         json_threshold.model,
     )?;
 
@@ -325,18 +324,12 @@ pub struct ProjThresholdQuery {
     pub model: Option<ModelUuid>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/projects/{project}/thresholds/{threshold}",
-    tags = ["projects", "thresholds"]
-}]
-pub async fn proj_threshold_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<ProjThresholdParams>,
-    _query_params: Query<ProjThresholdQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Put.into(), Delete.into()]))
+options_endpoint! {
+    proj_threshold_options,
+    "/v0/projects/{project}/thresholds/{threshold}",
+    ["projects", "thresholds"],
+    (_path_params: Path<ProjThresholdParams>, _query_params: Query<ProjThresholdQuery>),
+    [Get, Put, Delete]
 }
 
 /// View a threshold
@@ -408,6 +401,9 @@ async fn get_one_inner(
 /// The user must have `edit` permissions for the project.
 /// The new model will be added to the threshold and used going forward.
 /// The old model will be replaced but still show up in the report history and alerts created when it was active.
+/// If the branch has protected thresholds, then the threshold can not be updated directly.
+/// Instead, request a threshold change with `POST /v0/projects/{project}/threshold-changes`,
+/// which must be approved by a different user with `manage` permissions for the project.
 #[endpoint {
     method = PUT,
     path =  "/v0/projects/{project}/thresholds/{threshold}",
@@ -458,6 +454,10 @@ async fn put_inner(
     let query_threshold =
         QueryThreshold::get_with_uuid(conn_lock!(context), &query_project, path_params.threshold)?;
 
+    // Changes to the thresholds for a protected branch must be requested and approved instead
+    QueryBranch::get(conn_lock!(context), query_threshold.branch_id)?
+        .check_protected_threshold_changes()?;
+
     // Update the current threshold with the new model, if changed
     query_threshold
         .update_model_if_changed(context, model)
@@ -475,6 +475,8 @@ async fn put_inner(
 /// Delete a threshold for a project.
 /// The user must have `delete` permissions for the project.
 /// A thresholds must be deleted before its branch, testbed, or measure can be deleted.
+/// If the branch has protected thresholds, then the model for the threshold must first be removed
+/// with a threshold change, which must be approved by a different user with `manage` permissions for the project.
 #[endpoint {
     method = DELETE,
     path =  "/v0/projects/{project}/thresholds/{threshold}",
@@ -506,6 +508,8 @@ async fn delete_inner(
 
     let query_threshold =
         QueryThreshold::get_with_uuid(conn_lock!(context), &query_project, path_params.threshold)?;
+    // The model for a protected threshold must be removed by an approved threshold change first
+    query_threshold.check_protected_change(conn_lock!(context), None)?;
 
     diesel::delete(schema::threshold::table.filter(schema::threshold::id.eq(query_threshold.id)))
         .execute(conn_lock!(context))
@@ -0,0 +1,404 @@
+use bencher_json::{
+    project::token::JsonUpdateProjectToken, JsonDirection, JsonNewProjectToken, JsonPagination,
+    JsonProjectToken, JsonProjectTokens, ProjectTokenUuid, ResourceId, ResourceName,
+};
+use bencher_rbac::project::Permission;
+use diesel::{
+    BelongingToDsl, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    TextExpressionMethods,
+};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            project_token::{InsertProjectToken, QueryProjectToken, UpdateProjectToken},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjTokensParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjTokensPagination = JsonPagination<ProjTokensSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjTokensSort {
+    /// Sort by token name.
+    #[default]
+    Name,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjTokensQuery {
+    /// Filter by token name, exact match.
+    pub name: Option<ResourceName>,
+    /// Search by token name or UUID.
+    pub search: Option<Search>,
+}
+
+options_endpoint! {
+    proj_tokens_options,
+    "/v0/projects/{project}/tokens",
+    ["projects", "tokens"],
+    (
+        _path_params: Path<ProjTokensParams>,
+        _pagination_params: Query<ProjTokensPagination>,
+        _query_params: Query<ProjTokensQuery>,
+    ),
+    [Get, Post]
+}
+
+/// List tokens for a project
+///
+/// List all API tokens for a project.
+/// The user must have `manage` permissions for the project.
+/// By default, the tokens are sorted in alphabetical order by name.
+/// The HTTP response header `X-Total-Count` contains the total number of tokens.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/tokens",
+    tags = ["projects", "tokens"]
+}]
+pub async fn proj_tokens_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjTokensParams>,
+    pagination_params: Query<ProjTokensPagination>,
+    query_params: Query<ProjTokensQuery>,
+) -> Result<ResponseOk<JsonProjectTokens>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        pagination_params,
+        query_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjTokensParams,
+    pagination_params: ProjTokensPagination,
+    query_params: ProjTokensQuery,
+    auth_user: &AuthUser,
+) -> Result<(JsonProjectTokens, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let tokens = get_ls_query(&query_project, &pagination_params, &query_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryProjectToken>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ProjectToken,
+            (&query_project, &pagination_params, &query_params)
+        ))?;
+
+    let json_tokens = conn_lock!(context, |conn| tokens
+        .into_iter()
+        .map(|query_token| query_token.into_json_for_project(conn, &query_project))
+        .collect::<Result<_, _>>()?);
+
+    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ProjectToken,
+            (&query_project, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_tokens, total_count))
+}
+
+fn get_ls_query<'q>(
+    query_project: &'q QueryProject,
+    pagination_params: &ProjTokensPagination,
+    query_params: &'q ProjTokensQuery,
+) -> schema::project_token::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+    let mut query = QueryProjectToken::belonging_to(query_project).into_boxed();
+
+    if let Some(name) = query_params.name.as_ref() {
+        query = query.filter(schema::project_token::name.eq(name));
+    }
+    if let Some(search) = query_params.search.as_ref() {
+        query = query.filter(
+            schema::project_token::name
+                .like(search)
+                .or(schema::project_token::uuid.like(search)),
+        );
+    }
+
+    match pagination_params.order() {
+        ProjTokensSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order((
+                schema::project_token::name.asc(),
+                schema::project_token::expiration.asc(),
+            )),
+            Some(JsonDirection::Desc) => query.order((
+                schema::project_token::name.desc(),
+                schema::project_token::expiration.desc(),
+            )),
+        },
+    }
+}
+
+/// Create a token for a project
+///
+/// Create an API token for a project.
+/// The token can only access the project, with the permissions of its scope.
+/// It does not depend on the project role of the user who created it,
+/// so it can be used by CI systems without a user API token.
+/// If a scope is not provided, the token can only view the project and create reports.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/tokens",
+    tags = ["projects", "tokens"]
+}]
+pub async fn proj_token_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTokensParams>,
+    body: TypedBody<JsonNewProjectToken>,
+) -> Result<ResponseCreated<JsonProjectToken>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjTokensParams,
+    json_token: JsonNewProjectToken,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectToken, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let insert_token =
+        InsertProjectToken::from_json(&context.token_key, &query_project, json_token, auth_user)?;
+    diesel::insert_into(schema::project_token::table)
+        .values(&insert_token)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            ProjectToken,
+            (&query_project, insert_token.uuid)
+        ))?;
+
+    conn_lock!(context, |conn| QueryProjectToken::get_with_uuid(
+        conn,
+        &query_project,
+        insert_token.uuid
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjTokenParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a project token.
+    pub token: ProjectTokenUuid,
+}
+
+options_endpoint! {
+    proj_token_options,
+    "/v0/projects/{project}/tokens/{token}",
+    ["projects", "tokens"],
+    (_path_params: Path<ProjTokenParams>),
+    [Get, Patch, Delete]
+}
+
+/// View a token for a project
+///
+/// View an API token for a project.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/tokens/{token}",
+    tags = ["projects", "tokens"]
+}]
+pub async fn proj_token_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTokenParams>,
+) -> Result<ResponseOk<JsonProjectToken>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjTokenParams,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectToken, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    conn_lock!(context, |conn| QueryProjectToken::get_with_uuid(
+        conn,
+        &query_project,
+        path_params.token
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+/// Update a token for a project
+///
+/// Update an API token for a project.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/tokens/{token}",
+    tags = ["projects", "tokens"]
+}]
+pub async fn proj_token_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTokenParams>,
+    body: TypedBody<JsonUpdateProjectToken>,
+) -> Result<ResponseOk<JsonProjectToken>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjTokenParams,
+    json_token: JsonUpdateProjectToken,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectToken, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_token =
+        QueryProjectToken::get_with_uuid(conn_lock!(context), &query_project, path_params.token)?;
+
+    let update_token = UpdateProjectToken::from(json_token);
+    diesel::update(
+        schema::project_token::table.filter(schema::project_token::id.eq(query_token.id)),
+    )
+    .set(&update_token)
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(
+        ProjectToken,
+        (&query_project, &query_token)
+    ))?;
+
+    conn_lock!(context, |conn| QueryProjectToken::get(
+        conn,
+        query_token.id
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+/// Delete a token for a project
+///
+/// Delete an API token for a project.
+/// The token can no longer be used once it has been deleted.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/tokens/{token}",
+    tags = ["projects", "tokens"]
+}]
+pub async fn proj_token_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjTokenParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjTokenParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_token =
+        QueryProjectToken::get_with_uuid(conn_lock!(context), &query_project, path_params.token)?;
+
+    diesel::delete(
+        schema::project_token::table.filter(schema::project_token::id.eq(query_token.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(ProjectToken, query_token.uuid))?;
+
+    Ok(())
+}
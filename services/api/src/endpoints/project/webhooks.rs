@@ -0,0 +1,235 @@
+use bencher_json::{JsonNewWebhook, JsonWebhook, JsonWebhooks, ResourceId, WebhookUuid};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{
+        options_endpoint, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    },
+    error::resource_conflict_err,
+    model::{
+        project::{
+            webhook::{InsertWebhook, QueryWebhook},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+options_endpoint! {
+    proj_webhooks_options,
+    "/v0/projects/{project}/webhooks",
+    ["projects", "webhooks"],
+    (_path_params: Path<ProjWebhooksParams>),
+    [Get, Post]
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// The user must have `view` permissions for the project.
+/// The secrets for the webhooks are never returned.
+/// Webhooks are sorted by creation date.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhooks, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    Ok(
+        QueryWebhook::all_for_project(conn_lock!(context), &query_project)?
+            .into_iter()
+            .map(|webhook| webhook.into_json_for_project(&query_project))
+            .collect(),
+    )
+}
+
+/// Create a webhook
+///
+/// Create a webhook for a project.
+/// When a report for the project generates alerts, a JSON payload with the alerts is `POST`ed to the webhook URL.
+/// The payload is signed with the webhook secret using HMAC-SHA256.
+/// The `X-Bencher-Signature` header is `sha256=` followed by the hex encoded signature
+/// of the `X-Bencher-Timestamp` header and the request body, joined by a `.`.
+/// The webhook URL must resolve to a public address, and redirects are not followed.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let insert_webhook =
+        InsertWebhook::from_json(&context.encryption, query_project.id, json_webhook)?;
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (&query_project, insert_webhook.uuid)
+        ))?;
+
+    QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, insert_webhook.uuid)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+options_endpoint! {
+    proj_webhook_options,
+    "/v0/projects/{project}/webhooks/{webhook}",
+    ["projects", "webhooks"],
+    (_path_params: Path<ProjWebhookParams>),
+    [Get, Delete]
+}
+
+/// View a webhook
+///
+/// View a webhook for a project.
+/// The user must have `view` permissions for the project.
+/// The secret for the webhook is never returned.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+/// Delete a webhook
+///
+/// Delete a webhook for a project.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook.uuid))?;
+
+    Ok(())
+}
@@ -4,23 +4,16 @@ use dropshot::{endpoint, HttpError, RequestContext, TypedBody};
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Post, ResponseAccepted},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseOk},
     model::user::auth::{AuthUser, BearerToken},
 };
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/auth/accept",
-    tags = ["auth", "organizations"]
-}]
-pub async fn auth_accept_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    auth_accept_options,
+    "/v0/auth/accept",
+    ["auth", "organizations"],
+    (),
+    [Get, Post]
 }
 
 #[endpoint {
@@ -32,10 +25,10 @@ pub async fn auth_accept_post(
     rqctx: RequestContext<ApiContext>,
     bearer_token: BearerToken,
     body: TypedBody<JsonAccept>,
-) -> Result<ResponseAccepted<JsonAuthAck>, HttpError> {
+) -> Result<ResponseOk<JsonAuthAck>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = post_inner(rqctx.context(), body.into_inner(), auth_user).await?;
-    Ok(Post::auth_response_accepted(json))
+    Ok(Post::auth_response_ok(json))
 }
 
 async fn post_inner(
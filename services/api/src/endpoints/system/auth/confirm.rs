@@ -5,26 +5,19 @@ use http::StatusCode;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseOk},
     error::{issue_error, unauthorized_error},
     model::user::QueryUser,
 };
 
 use super::CLIENT_TOKEN_TTL;
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/auth/confirm",
-    tags = ["auth"]
-}]
-pub async fn auth_confirm_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    auth_confirm_options,
+    "/v0/auth/confirm",
+    ["auth"],
+    (),
+    [Post]
 }
 
 #[endpoint {
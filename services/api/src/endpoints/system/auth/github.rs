@@ -8,10 +8,7 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Post, ResponseAccepted},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseOk},
     error::{issue_error, payment_required_error, unauthorized_error},
     model::{
         organization::plan::LicenseUsage,
@@ -23,16 +20,12 @@ use super::CLIENT_TOKEN_TTL;
 
 pub const GITHUB_OAUTH2: &str = "GitHub OAuth2";
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/auth/github",
-    tags = ["auth"]
-}]
-pub async fn auth_github_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    auth_github_options,
+    "/v0/auth/github",
+    ["auth"],
+    (),
+    [Get, Post]
 }
 
 #[endpoint {
@@ -43,9 +36,9 @@ pub async fn auth_github_options(
 pub async fn auth_github_post(
     rqctx: RequestContext<ApiContext>,
     body: TypedBody<JsonOAuth>,
-) -> Result<ResponseAccepted<JsonAuthUser>, HttpError> {
+) -> Result<ResponseOk<JsonAuthUser>, HttpError> {
     let json = post_inner(&rqctx.log, rqctx.context(), body.into_inner()).await?;
-    Ok(Post::pub_response_accepted(json))
+    Ok(Post::pub_response_ok(json))
 }
 
 async fn post_inner(
@@ -8,10 +8,7 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::{ApiContext, Body, ButtonBody, Message},
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseAccepted},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseAccepted},
     error::issue_error,
     model::user::QueryUser,
 };
@@ -19,16 +16,12 @@ use crate::{
 use super::AUTH_TOKEN_TTL;
 use super::TOKEN_ARG;
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/auth/login",
-    tags = ["auth"]
-}]
-pub async fn auth_login_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    auth_login_options,
+    "/v0/auth/login",
+    ["auth"],
+    (),
+    [Post]
 }
 
 #[endpoint {
@@ -7,10 +7,7 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::{ApiContext, Body, ButtonBody, Message},
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseAccepted},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseAccepted},
     error::{forbidden_error, issue_error},
     model::user::InsertUser,
 };
@@ -18,16 +15,12 @@ use crate::{
 use super::AUTH_TOKEN_TTL;
 use super::TOKEN_ARG;
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/auth/signup",
-    tags = ["auth"]
-}]
-pub async fn auth_signup_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    auth_signup_options,
+    "/v0/auth/signup",
+    ["auth"],
+    (),
+    [Post]
 }
 
 #[endpoint {
@@ -1,4 +1,5 @@
 pub mod auth;
+pub mod operations;
 pub mod payments;
 pub mod root;
 pub mod server;
@@ -0,0 +1,58 @@
+use bencher_json::{JsonOperation, OperationUuid};
+use dropshot::{endpoint, HttpError, Path, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
+    model::{
+        operation::QueryOperation,
+        user::{admin::AdminUser, auth::BearerToken},
+    },
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OperationParams {
+    /// The UUID for an operation.
+    pub operation: OperationUuid,
+}
+
+options_endpoint! {
+    operation_options,
+    "/v0/operations/{operation}",
+    ["server"],
+    (_path_params: Path<OperationParams>),
+    [Get]
+}
+
+/// View an operation
+///
+/// View the state of a long-running server operation, such as a database backup.
+/// Operations are returned with a `202 Accepted` status by the routes that start them.
+/// Poll this route until the operation state is either `succeeded` or `failed`.
+/// If the operation failed, then its error message is included.
+/// The user must be an admin on the server to use this route.
+#[endpoint {
+    method = GET,
+    path =  "/v0/operations/{operation}",
+    tags = ["server"]
+}]
+pub async fn operation_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OperationParams>,
+) -> Result<ResponseOk<JsonOperation>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner()).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OperationParams,
+) -> Result<JsonOperation, HttpError> {
+    QueryOperation::from_uuid(conn_lock!(context), path_params.operation)
+        .map(QueryOperation::into_json)
+}
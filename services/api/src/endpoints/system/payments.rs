@@ -11,10 +11,7 @@ use http::StatusCode;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseCreated},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseCreated},
     error::{forbidden_error, issue_error, resource_not_found_err},
     model::{
         organization::QueryOrganization,
@@ -25,16 +22,12 @@ use crate::{
     },
 };
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/payments",
-    tags = ["payments"]
-}]
-pub async fn payments_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    payments_options,
+    "/v0/payments",
+    ["payments"],
+    (),
+    [Post]
 }
 
 #[endpoint {
@@ -99,16 +92,12 @@ async fn post_inner(
     })
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/checkout",
-    tags = ["checkout"]
-}]
-pub async fn checkouts_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    checkouts_options,
+    "/v0/checkout",
+    ["checkout"],
+    (),
+    [Post]
 }
 
 #[endpoint {
@@ -1,44 +1,115 @@
-use std::{ffi::OsStr, path::PathBuf};
-
-use async_compression::tokio::write::GzipEncoder;
-use bencher_json::system::backup::JsonDataStore;
-use bencher_json::{DateTime, JsonBackup, JsonBackupCreated, JsonRestart};
-use chrono::Utc;
-use diesel::connection::SimpleConnection;
-use dropshot::{endpoint, HttpError, RequestContext, TypedBody};
-use tokio::fs::remove_file;
-use tokio::io::{AsyncReadExt, BufWriter};
-use tokio::io::{AsyncWriteExt, BufReader};
+use bencher_json::{
+    JsonBackup, JsonDirection, JsonOperation, JsonPagination, JsonRestart, JsonServerBackups,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     conn_lock,
-    context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseCreated},
-        Endpoint,
+    context::{run_backup, ApiContext},
+    endpoints::endpoint::{options_endpoint, Get, Post, ResponseAccepted, ResponseOk},
+    error::resource_not_found_err,
+    model::{
+        backup::{InsertBackup, QueryBackup},
+        user::{admin::AdminUser, auth::BearerToken},
     },
-    error::bad_request_error,
-    model::user::{admin::AdminUser, auth::BearerToken},
+    schema,
+    util::headers::{PageLinks, TotalCount},
 };
 
-const BUFFER_SIZE: usize = 1024;
+pub type ServerBackupsPagination = JsonPagination<ServerBackupsSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerBackupsSort {
+    /// Sort by date time created.
+    #[default]
+    Created,
+}
+
+options_endpoint! {
+    server_backups_options,
+    "/v0/server/backups",
+    ["server"],
+    (_pagination_params: Query<ServerBackupsPagination>),
+    [Get]
+}
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+/// List server backups
+///
+/// List the history of database backups for the server, both manual and scheduled.
+/// Each backup includes the operation that ran it.
+/// By default, the backups are sorted by date time created in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of backups.
+/// The user must be an admin on the server to use this route.
 #[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/backup",
+    method = GET,
+    path =  "/v0/server/backups",
     tags = ["server"]
 }]
-pub async fn server_backup_options(
-    _rqctx: RequestContext<ApiContext>,
-    _body: TypedBody<JsonRestart>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+pub async fn server_backups_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    pagination_params: Query<ServerBackupsPagination>,
+) -> Result<ResponseOk<JsonServerBackups>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
+    let (json, total_count) = get_ls_inner(rqctx.context(), pagination_params).await?;
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    pagination_params: ServerBackupsPagination,
+) -> Result<(JsonServerBackups, TotalCount), HttpError> {
+    let query = schema::backup::table.into_boxed();
+    let query = match pagination_params.order() {
+        ServerBackupsSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::backup::created.asc()),
+            Some(JsonDirection::Desc) | None => query.order(schema::backup::created.desc()),
+        },
+    };
+    let backups = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryBackup>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Backup, &pagination_params))?;
+
+    let json_backups = conn_lock!(context, |conn| backups
+        .into_iter()
+        .map(|query_backup| query_backup.into_json(conn))
+        .collect::<Result<_, _>>()?);
+
+    let total_count = schema::backup::table
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Backup, &pagination_params))?
+        .try_into()?;
+
+    Ok((json_backups, total_count))
+}
+
+options_endpoint! {
+    server_backup_options,
+    "/v0/server/backup",
+    ["server"],
+    (_body: TypedBody<JsonRestart>),
+    [Post]
 }
 
 /// Backup server
 ///
 /// Backup the API server database.
+/// The backup runs in the background, and an operation is returned to track its progress.
+/// Use the operation UUID to poll for the result of the backup.
+/// The backup is also recorded in the server backup history.
 /// The user must be an admin on the server to use this route.
 #[endpoint {
     method = POST,
@@ -49,159 +120,34 @@ pub async fn server_backup_post(
     rqctx: RequestContext<ApiContext>,
     bearer_token: BearerToken,
     body: TypedBody<JsonBackup>,
-) -> Result<ResponseCreated<JsonBackupCreated>, HttpError> {
+) -> Result<ResponseAccepted<JsonOperation>, HttpError> {
     let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(rqctx.context(), body.into_inner()).await?;
-    Ok(Post::auth_response_created(json))
+    let json = post_inner(&rqctx, body.into_inner()).await?;
+    Ok(Post::auth_response_accepted(json))
 }
 
 async fn post_inner(
-    context: &ApiContext,
-    json_backup: JsonBackup,
-) -> Result<JsonBackupCreated, HttpError> {
-    backup(context, json_backup)
-        .await
-        .map_err(bad_request_error)
-}
-
-#[derive(Debug, thiserror::Error)]
-pub enum BackupError {
-    #[error("Failed to batch execute: {0}")]
-    BatchExecute(diesel::result::Error),
-    #[error("Failed to create backup file: {0}")]
-    CreateBackupFile(std::io::Error),
-    #[error("Failed to create compressed file: {0}")]
-    CreateZipFile(std::io::Error),
-    #[error("Failed to write to compressed file: {0}")]
-    WriteZipFile(std::io::Error),
-    #[error("Failed to close compressed file: {0}")]
-    CloseZipFile(std::io::Error),
-    #[error("Failed to remove backup file: {0}")]
-    RmBackupFile(std::io::Error),
-    #[error("Failed to remove compressed file: {0}")]
-    RmZipFile(std::io::Error),
-    #[error("{0}")]
-    DataStore(crate::context::DataStoreError),
-    #[error("No data store")]
-    NoDataStore,
-    #[error("Failed to remove file: {0}")]
-    RmFile(std::io::Error),
-}
-
-async fn backup(
-    context: &ApiContext,
+    rqctx: &RequestContext<ApiContext>,
     json_backup: JsonBackup,
-) -> Result<JsonBackupCreated, BackupError> {
-    // Create a database backup
-    let Backup {
-        file_path: backup_file_path,
-        file_name: backup_file_name,
-        created,
-    } = backup_database(context).await?;
-
-    // Compress the database backup
-    let (source_path, file_name) = if json_backup.compress.unwrap_or_default() {
-        compress_database(backup_file_path.clone(), &backup_file_name).await?
-    } else {
-        (backup_file_path.clone(), backup_file_name)
-    };
-
-    // Store the database backup in AWS S3
-    if let Some(JsonDataStore::AwsS3) = json_backup.data_store {
-        if let Some(data_store) = &context.database.data_store {
-            data_store
-                .backup(&source_path, &file_name)
-                .await
-                .map_err(BackupError::DataStore)?;
-        } else {
-            return Err(BackupError::NoDataStore);
-        };
-    }
-
-    // Remove the remaining database backup
-    if json_backup.rm.unwrap_or_default() {
-        remove_file(source_path)
-            .await
-            .map_err(BackupError::RmZipFile)?;
-    }
-
-    Ok(JsonBackupCreated { created })
-}
-
-struct Backup {
-    file_path: PathBuf,
-    file_name: String,
-    created: DateTime,
-}
-
-async fn backup_database(context: &ApiContext) -> Result<Backup, BackupError> {
-    let mut file_path = context.database.path.clone();
-
-    let file_stem = file_path
-        .file_stem()
-        .unwrap_or_else(|| OsStr::new("bencher"))
-        .to_string_lossy();
-    let file_extension = file_path
-        .extension()
-        .unwrap_or_else(|| OsStr::new("db"))
-        .to_string_lossy();
-    let date_time = Utc::now();
-    let file_name = format!(
-        "backup-{file_stem}-{}.{file_extension}",
-        date_time.format("%Y-%m-%d-%H-%M-%S")
-    );
-    file_path.set_file_name(&file_name);
-    let file_path_str = file_path.to_string_lossy();
-    let query = format!("VACUUM INTO '{file_path_str}'");
-
-    conn_lock!(context)
-        .batch_execute(&query)
-        .map_err(BackupError::BatchExecute)?;
-
-    Ok(Backup {
-        file_path,
-        file_name,
-        created: date_time.into(),
-    })
-}
-
-async fn compress_database(
-    backup_file_path: PathBuf,
-    backup_file_name: &str,
-) -> Result<(PathBuf, String), BackupError> {
-    let backup_file = tokio::fs::File::open(&backup_file_path)
-        .await
-        .map_err(BackupError::CreateBackupFile)?;
-    let mut backup_data = BufReader::with_capacity(BUFFER_SIZE, backup_file);
-
-    let compress_file_name = format!("{backup_file_name}.gz");
-    let mut compress_file_path = backup_file_path.clone();
-    compress_file_path.set_file_name(&compress_file_name);
-    let compress_file = tokio::fs::File::create(&compress_file_path)
-        .await
-        .map_err(BackupError::CreateZipFile)?;
-    let compress_data = BufWriter::with_capacity(BUFFER_SIZE, compress_file);
-
-    let mut encoder = GzipEncoder::new(compress_data);
-    let mut data_buffer = [0; BUFFER_SIZE];
-    while let Ok(data_size) = backup_data.read(&mut data_buffer).await {
-        if data_size == 0 {
-            break;
-        }
-
-        encoder
-            .write_all(&data_buffer)
-            .await
-            .map_err(BackupError::WriteZipFile)?;
-    }
-    encoder
-        .shutdown()
-        .await
-        .map_err(BackupError::CloseZipFile)?;
-
-    remove_file(backup_file_path)
-        .await
-        .map_err(BackupError::RmBackupFile)?;
-
-    Ok((compress_file_path, compress_file_name))
+) -> Result<JsonOperation, HttpError> {
+    let context = rqctx.context();
+    let (query_backup, query_operation) =
+        InsertBackup::queue(conn_lock!(context), &json_backup, false)?;
+    let json_operation = query_operation.clone().into_json();
+
+    // The backup outlives the request, so it holds its own reference to the database
+    let database = context.database.clone();
+    let log = rqctx.log.clone();
+    tokio::spawn(async move {
+        run_backup(
+            &log,
+            &database,
+            &query_backup,
+            &query_operation,
+            json_backup,
+        )
+        .await;
+    });
+
+    Ok(json_operation)
 }
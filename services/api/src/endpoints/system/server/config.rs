@@ -9,10 +9,7 @@ use slog::Logger;
 use crate::{
     config::{Config, BENCHER_CONFIG},
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Put, ResponseAccepted, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, Put, ResponseAccepted, ResponseOk},
     error::{bad_request_error, issue_error},
     model::user::{
         admin::AdminUser,
@@ -22,16 +19,12 @@ use crate::{
 
 use super::restart::countdown;
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/config",
-    tags = ["server", "config"]
-}]
-pub async fn server_config_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    server_config_options,
+    "/v0/server/config",
+    ["server", "config"],
+    (),
+    [Get]
 }
 
 /// View server configuration
@@ -115,16 +108,12 @@ async fn put_inner(
     Ok(json_config)
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-        method = OPTIONS,
-        path =  "/v0/server/config/console",
-        tags = ["server"]
-    }]
-pub async fn server_config_console_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    server_config_console_options,
+    "/v0/server/config/console",
+    ["server"],
+    (),
+    [Get]
 }
 
 /// View console configuration
@@ -0,0 +1,162 @@
+use bencher_json::{
+    system::maintenance::{JsonMaintenanceOperation, JsonMaintenanceResult},
+    DateTime, JsonMaintenance, JsonMaintenanceResults,
+};
+use diesel::{connection::SimpleConnection, RunQueryDsl};
+use dropshot::{endpoint, HttpError, RequestContext, TypedBody};
+use slog::Logger;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    endpoints::endpoint::{options_endpoint, Post, ResponseOk},
+    error::bad_request_error,
+    model::user::{admin::AdminUser, auth::BearerToken},
+};
+
+use pragma::{IntegrityCheck, WalCheckpoint};
+
+options_endpoint! {
+    server_maintenance_options,
+    "/v0/server/maintenance",
+    ["server"],
+    (),
+    [Post]
+}
+
+/// Run server database maintenance
+///
+/// Run maintenance operations on the API server database.
+/// The operations are run in order, and the result of each operation is returned.
+/// While an operation is running, all other database requests will wait for it to finish.
+/// The user must be an admin on the server to use this route.
+#[endpoint {
+    method = POST,
+    path =  "/v0/server/maintenance",
+    tags = ["server"]
+}]
+pub async fn server_maintenance_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    body: TypedBody<JsonMaintenance>,
+) -> Result<ResponseOk<JsonMaintenanceResults>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(&rqctx.log, rqctx.context(), body.into_inner()).await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn post_inner(
+    log: &Logger,
+    context: &ApiContext,
+    json_maintenance: JsonMaintenance,
+) -> Result<JsonMaintenanceResults, HttpError> {
+    let operations = match json_maintenance.operations {
+        Some(operations) if !operations.is_empty() => operations,
+        Some(_) | None => JsonMaintenanceOperation::ALL.to_vec(),
+    };
+
+    let total = operations.len();
+    let mut results = Vec::with_capacity(total);
+    for (index, operation) in operations.into_iter().enumerate() {
+        slog::info!(
+            log,
+            "Running maintenance operation {}/{total}: {operation:?}",
+            index + 1
+        );
+        let start_time = DateTime::now();
+        let (ok, output) =
+            run_operation(conn_lock!(context), operation).map_err(bad_request_error)?;
+        let end_time = DateTime::now();
+        slog::info!(
+            log,
+            "Finished maintenance operation {}/{total}: {operation:?} ({}ms)",
+            index + 1,
+            (end_time.into_inner() - start_time.into_inner()).num_milliseconds()
+        );
+        results.push(JsonMaintenanceResult {
+            operation,
+            ok,
+            output,
+            start_time,
+            end_time,
+        });
+    }
+
+    Ok(JsonMaintenanceResults { results })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceError {
+    #[error("Failed to run {operation:?}: {error}")]
+    Operation {
+        operation: JsonMaintenanceOperation,
+        error: diesel::result::Error,
+    },
+}
+
+fn run_operation(
+    conn: &mut DbConnection,
+    operation: JsonMaintenanceOperation,
+) -> Result<(bool, Vec<String>), MaintenanceError> {
+    let map_err = |error| MaintenanceError::Operation { operation, error };
+    match operation {
+        JsonMaintenanceOperation::IntegrityCheck => {
+            let output = diesel::sql_query("PRAGMA integrity_check")
+                .load::<IntegrityCheck>(conn)
+                .map_err(map_err)?
+                .into_iter()
+                .map(|row| row.integrity_check)
+                .collect::<Vec<_>>();
+            // A healthy database returns a single row with the value `ok`
+            let ok = matches!(output.as_slice(), [row] if row == "ok");
+            Ok((ok, output))
+        },
+        JsonMaintenanceOperation::Analyze => {
+            conn.batch_execute("ANALYZE").map_err(map_err)?;
+            Ok((true, Vec::new()))
+        },
+        JsonMaintenanceOperation::Vacuum => {
+            conn.batch_execute("VACUUM").map_err(map_err)?;
+            Ok((true, Vec::new()))
+        },
+        JsonMaintenanceOperation::WalCheckpoint => {
+            let output = diesel::sql_query("PRAGMA wal_checkpoint(TRUNCATE)")
+                .load::<WalCheckpoint>(conn)
+                .map_err(map_err)?;
+            // The checkpoint is blocked if the database is busy
+            let ok = output.iter().all(|row| row.busy == 0);
+            let output = output
+                .into_iter()
+                .map(|row| {
+                    format!(
+                        "busy: {}, log: {}, checkpointed: {}",
+                        row.busy, row.log, row.checkpointed
+                    )
+                })
+                .collect();
+            Ok((ok, output))
+        },
+    }
+}
+
+// The `QueryableByName` derive macro generates fully qualified paths
+#[allow(unused_qualifications)]
+mod pragma {
+    use diesel::sql_types::{Integer, Text};
+
+    #[derive(diesel::QueryableByName)]
+    pub struct IntegrityCheck {
+        #[diesel(sql_type = Text)]
+        pub integrity_check: String,
+    }
+
+    #[derive(diesel::QueryableByName)]
+    pub struct WalCheckpoint {
+        #[diesel(sql_type = Integer)]
+        pub busy: i32,
+        #[diesel(sql_type = Integer)]
+        pub log: i32,
+        #[diesel(sql_type = Integer)]
+        pub checkpointed: i32,
+    }
+}
@@ -1,6 +1,9 @@
 pub mod backup;
 pub mod config;
+pub mod maintenance;
 pub mod restart;
+pub mod retention;
 pub mod spec;
 pub mod stats;
+pub mod telemetry;
 pub mod version;
@@ -5,26 +5,18 @@ use tokio::sync::mpsc::Sender;
 
 use crate::{
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Post, ResponseAccepted},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Post, ResponseAccepted},
     model::user::{admin::AdminUser, auth::BearerToken, UserId},
 };
 
 const DEFAULT_DELAY: u64 = 3;
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/restart",
-    tags = ["server"]
-}]
-pub async fn server_restart_options(
-    _rqctx: RequestContext<ApiContext>,
-    _body: TypedBody<JsonRestart>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Post.into()]))
+options_endpoint! {
+    server_restart_options,
+    "/v0/server/restart",
+    ["server"],
+    (_body: TypedBody<JsonRestart>),
+    [Post]
 }
 
 /// Restart server
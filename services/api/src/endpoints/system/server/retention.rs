@@ -0,0 +1,51 @@
+use dropshot::{endpoint, HttpError, RequestContext};
+use slog::Logger;
+
+use crate::{
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Delete, ResponseDeleted},
+    error::bad_request_error,
+    model::user::{admin::AdminUser, auth::BearerToken},
+};
+
+options_endpoint! {
+    server_retention_run_options,
+    "/v0/server/retention/run",
+    ["server"],
+    (),
+    [Delete]
+}
+
+/// Prune server reports
+///
+/// Prune all reports that are older than the server retention period, along with all of their metrics.
+/// Reports are otherwise pruned once a day in the background.
+/// The server must have a `retention` policy configured.
+/// The user must be an admin on the server to use this route.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/server/retention/run",
+    tags = ["server"]
+}]
+pub async fn server_retention_run_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+) -> Result<ResponseDeleted, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(&rqctx.log, rqctx.context()).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(log: &Logger, context: &ApiContext) -> Result<(), HttpError> {
+    let retention = context
+        .retention
+        .ok_or_else(|| bad_request_error("No retention policy is configured for this server"))?;
+    retention
+        .prune(
+            log,
+            &context.database.connection,
+            context.database.data_store.as_deref(),
+        )
+        .await?;
+    Ok(())
+}
@@ -3,23 +3,16 @@ use dropshot::{endpoint, HttpError, RequestContext};
 
 use crate::{
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
     SPEC,
 };
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/spec",
-    tags = ["server"]
-}]
-pub async fn server_spec_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    server_spec_options,
+    "/v0/server/spec",
+    ["server"],
+    (),
+    [Get]
 }
 
 /// View server OpenAPI specification
@@ -8,10 +8,7 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Post, ResponseAccepted, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, Post, ResponseAccepted, ResponseOk},
     error::issue_error,
     model::{
         server::QueryServer,
@@ -19,16 +16,12 @@ use crate::{
     },
 };
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/stats",
-    tags = ["server", "stats"]
-}]
-pub async fn server_stats_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    server_stats_options,
+    "/v0/server/stats",
+    ["server", "stats"],
+    (),
+    [Get, Post]
 }
 
 /// View server stats
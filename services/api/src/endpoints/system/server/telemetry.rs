@@ -0,0 +1,55 @@
+#![cfg(feature = "plus")]
+
+use bencher_json::JsonTelemetry;
+use dropshot::{endpoint, HttpError, RequestContext, TypedBody};
+use slog::Logger;
+
+use crate::{
+    context::ApiContext,
+    endpoints::endpoint::{options_endpoint, Post, ResponseAccepted},
+};
+
+options_endpoint! {
+    server_telemetry_options,
+    "/v0/server/telemetry",
+    ["server", "stats"],
+    (),
+    [Post]
+}
+
+/// Send CLI telemetry
+///
+/// ➕ Bencher Plus: Send anonymous CLI usage telemetry.
+/// Telemetry is opt-in and can be enabled with `bencher telemetry on`.
+/// Benchmark data is never included.
+#[endpoint {
+    method = POST,
+    path =  "/v0/server/telemetry",
+    tags = ["server", "stats"]
+}]
+pub async fn server_telemetry_post(
+    rqctx: RequestContext<ApiContext>,
+    body: TypedBody<JsonTelemetry>,
+) -> Result<ResponseAccepted<()>, HttpError> {
+    post_inner(&rqctx.log, rqctx.context(), body.into_inner())?;
+    Ok(Post::auth_response_accepted(()))
+}
+
+fn post_inner(
+    log: &Logger,
+    context: &ApiContext,
+    json_telemetry: JsonTelemetry,
+) -> Result<(), HttpError> {
+    let _biller = context.biller()?;
+
+    let JsonTelemetry {
+        version,
+        os,
+        events,
+    } = json_telemetry;
+    for event in events {
+        slog::info!(log, "CLI Telemetry ({version} {os}): {event:?}");
+    }
+
+    Ok(())
+}
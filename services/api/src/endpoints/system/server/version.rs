@@ -1,42 +1,73 @@
-use bencher_json::JsonApiVersion;
+use bencher_json::{
+    system::version::{is_older_version, JsonServerFeatures, BENCHER_API_SCHEMA_VERSION},
+    JsonApiVersion, BENCHER_CLIENT_VERSION_HEADER,
+};
 use dropshot::{endpoint, HttpError, RequestContext};
 
 use crate::{
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, ResponseOk},
-        Endpoint,
-    },
-    API_VERSION,
+    endpoints::endpoint::{options_endpoint, Get, ResponseOk},
+    API_VERSION, MIN_CLIENT_VERSION,
 };
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/server/version",
-    tags = ["server"]
-}]
-pub async fn server_version_options(
-    _rqctx: RequestContext<ApiContext>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    server_version_options,
+    "/v0/server/version",
+    ["server"],
+    (),
+    [Get]
 }
 
 /// View server version
 ///
 /// View the API server version.
 /// This is used to verify that the CLI and API server are compatible.
+/// The response includes the minimum client version supported by the API server,
+/// the latest client version known to the API server, the API schema version,
+/// and the features that are enabled on the API server.
 /// It can also be used as a simple endpoint to verify that the server is running.
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[allow(clippy::unused_async)]
 #[endpoint {
     method = GET,
     path = "/v0/server/version",
     tags = ["server"]
 }]
 pub async fn server_version_get(
-    _rqctx: RequestContext<ApiContext>,
+    rqctx: RequestContext<ApiContext>,
 ) -> Result<ResponseOk<JsonApiVersion>, HttpError> {
+    if let Some(client_version) = rqctx
+        .request
+        .headers()
+        .get(BENCHER_CLIENT_VERSION_HEADER)
+        .and_then(|header| header.to_str().ok())
+    {
+        if is_older_version(client_version, MIN_CLIENT_VERSION).unwrap_or_default() {
+            slog::warn!(
+                rqctx.log,
+                "Client version ({client_version}) is older than the minimum supported client version ({MIN_CLIENT_VERSION})"
+            );
+        }
+    }
+
     Ok(Get::pub_response_ok(JsonApiVersion {
         version: API_VERSION.into(),
+        min_client_version: Some(MIN_CLIENT_VERSION.into()),
+        latest_client_version: Some(API_VERSION.into()),
+        schema_version: Some(BENCHER_API_SCHEMA_VERSION.into()),
+        features: Some(server_features(rqctx.context())),
     }))
 }
+
+fn server_features(context: &ApiContext) -> JsonServerFeatures {
+    JsonServerFeatures {
+        tls: context.tls,
+        #[cfg(feature = "plus")]
+        oauth: context.github.is_some(),
+        #[cfg(not(feature = "plus"))]
+        oauth: false,
+        #[cfg(feature = "plus")]
+        plans: context.biller.is_some(),
+        #[cfg(not(feature = "plus"))]
+        plans: false,
+    }
+}
@@ -13,19 +13,19 @@ use uuid::Uuid;
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Patch, Post, ResponseCreated, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Get, Patch, Post, ResponseCreated, ResponseOk},
     error::{resource_conflict_err, resource_not_found_err},
     model::user::{
         auth::{AuthUser, BearerToken},
         same_user,
-        token::{InsertToken, QueryToken, UpdateToken},
+        token::{InsertToken, InsertTokenAllowlist, QueryToken, UpdateToken},
         QueryUser, UserId,
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -52,19 +52,16 @@ pub struct UserTokensQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/users/{user}/tokens",
-    tags = ["users", "tokens"]
-}]
-pub async fn user_tokens_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<UserTokensParams>,
-    _pagination_params: Query<UserTokensPagination>,
-    _query_params: Query<UserTokensQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+options_endpoint! {
+    user_tokens_options,
+    "/v0/users/{user}/tokens",
+    ["users", "tokens"],
+    (
+        _path_params: Path<UserTokensParams>,
+        _pagination_params: Query<UserTokensPagination>,
+        _query_params: Query<UserTokensQuery>,
+    ),
+    [Get, Post]
 }
 
 /// List tokens for a user
@@ -85,15 +82,21 @@ pub async fn user_tokens_get(
     query_params: Query<UserTokensQuery>,
 ) -> Result<ResponseOk<JsonTokens>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
         path_params.into_inner(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
         &auth_user,
     )
     .await?;
-    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
 }
 
 async fn get_ls_inner(
@@ -115,11 +118,10 @@ async fn get_ls_inner(
             (&pagination_params, &query_params, auth_user)
         ))?;
 
-    // Drop connection lock before iterating
-    let json_tokens = tokens
+    let json_tokens = conn_lock!(context, |conn| tokens
         .into_iter()
-        .map(|query_token| query_token.into_json_for_user(auth_user))
-        .collect();
+        .map(|query_token| query_token.into_json_for_user(conn, auth_user))
+        .collect::<Result<_, _>>()?);
 
     let total_count = get_ls_query(&pagination_params, &query_params, query_user.id)
         .count()
@@ -168,6 +170,8 @@ fn get_ls_query<'q>(
 /// Create a token
 ///
 /// Create an API token for a user.
+/// If an IP allowlist is provided, the token can only be used by clients that connect to the API server
+/// from one of those IP addresses or CIDR ranges.
 /// Only the authenticated user themselves and server admins have access to this endpoint.
 #[endpoint {
     method = POST,
@@ -197,6 +201,7 @@ async fn post_inner(
     json_token: JsonNewToken,
     auth_user: &AuthUser,
 ) -> Result<JsonToken, HttpError> {
+    let allowed_ips = json_token.allowed_ips.clone().unwrap_or_default();
     let insert_token = InsertToken::from_json(
         conn_lock!(context),
         &context.rbac,
@@ -211,11 +216,16 @@ async fn post_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Token, insert_token))?;
 
-    conn_lock!(context, |conn| schema::token::table
-        .filter(schema::token::uuid.eq(&insert_token.uuid))
-        .first::<QueryToken>(conn)
-        .map_err(resource_not_found_err!(Token, insert_token))?
-        .into_json(conn))
+    conn_lock!(context, |conn| {
+        let query_token = schema::token::table
+            .filter(schema::token::uuid.eq(&insert_token.uuid))
+            .first::<QueryToken>(conn)
+            .map_err(resource_not_found_err!(Token, insert_token))?;
+        if !allowed_ips.is_empty() {
+            InsertTokenAllowlist::insert(conn, query_token.id, allowed_ips)?;
+        }
+        query_token.into_json(conn)
+    })
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -226,17 +236,12 @@ pub struct UserTokenParams {
     pub token: Uuid,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/users/{user}/tokens/{token}",
-    tags = ["users", "tokens"]
-}]
-pub async fn user_token_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<UserTokenParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+options_endpoint! {
+    user_token_options,
+    "/v0/users/{user}/tokens/{token}",
+    ["users", "tokens"],
+    (_path_params: Path<UserTokenParams>),
+    [Get, Patch]
 }
 
 /// View a token
@@ -281,6 +286,8 @@ async fn get_one_inner(
 /// Update a token
 ///
 /// Update an API token for a user.
+/// If `rotate` is set, a replacement token is issued and the current token is revoked.
+/// The replacement token keeps the same UUID, scope, project, and IP allowlist.
 /// Only the authenticated user themselves and server admins have access to this endpoint.
 #[endpoint {
     method = PATCH,
@@ -319,7 +326,10 @@ async fn patch_inner(
         &path_params.token.to_string(),
     )?;
 
-    let update_token = UpdateToken::from(json_token);
+    // The current token is replaced in a single update,
+    // so it is revoked as soon as the replacement token is issued.
+    let update_token =
+        UpdateToken::from_json(&context.token_key, &query_user, &query_token, json_token)?;
     diesel::update(schema::token::table.filter(schema::token::id.eq(query_token.id)))
         .set(&update_token)
         .execute(conn_lock!(context))
@@ -8,22 +8,24 @@ use diesel::{
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 use crate::{
     conn_lock,
     context::ApiContext,
-    endpoints::{
-        endpoint::{CorsResponse, Get, Patch, ResponseOk},
-        Endpoint,
-    },
+    endpoints::endpoint::{options_endpoint, Delete, Get, Patch, ResponseDeleted, ResponseOk},
     error::{forbidden_error, resource_conflict_err, resource_not_found_err},
     model::user::{
         admin::AdminUser,
         auth::{AuthUser, BearerToken},
+        deletion::scrub_user,
         same_user, QueryUser, UpdateUser,
     },
     schema,
-    util::{headers::TotalCount, search::Search},
+    util::{
+        headers::{PageLinks, TotalCount},
+        search::Search,
+    },
 };
 
 pub type UsersPagination = JsonPagination<UsersSort>;
@@ -44,18 +46,12 @@ pub struct UsersQuery {
     pub search: Option<Search>,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/users",
-    tags = ["users"]
-}]
-pub async fn users_options(
-    _rqctx: RequestContext<ApiContext>,
-    _pagination_params: Query<UsersPagination>,
-    _query_params: Query<UsersQuery>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into()]))
+options_endpoint! {
+    users_options,
+    "/v0/users",
+    ["users"],
+    (_pagination_params: Query<UsersPagination>, _query_params: Query<UsersQuery>),
+    [Get]
 }
 
 /// List users
@@ -75,13 +71,19 @@ pub async fn users_get(
     query_params: Query<UsersQuery>,
 ) -> Result<ResponseOk<JsonUsers>, HttpError> {
     let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let pagination_params = pagination_params.into_inner();
+    let page_links = PageLinks::new(&rqctx, &pagination_params);
     let (json, total_count) = get_ls_inner(
         rqctx.context(),
-        pagination_params.into_inner(),
+        pagination_params,
         query_params.into_inner(),
     )
     .await?;
-    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+    Ok(Get::auth_response_ok_with_total_count(
+        json,
+        total_count,
+        &page_links,
+    ))
 }
 
 async fn get_ls_inner(
@@ -143,17 +145,12 @@ pub struct UserParams {
     pub user: ResourceId,
 }
 
-#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
-#[endpoint {
-    method = OPTIONS,
-    path =  "/v0/users/{user}",
-    tags = ["users"]
-}]
-pub async fn user_options(
-    _rqctx: RequestContext<ApiContext>,
-    _path_params: Path<UserParams>,
-) -> Result<CorsResponse, HttpError> {
-    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+options_endpoint! {
+    user_options,
+    "/v0/users/{user}",
+    ["users"],
+    (_path_params: Path<UserParams>),
+    [Get, Patch, Delete]
 }
 
 /// View a user
@@ -246,3 +243,53 @@ async fn patch_inner(
 
     Ok(QueryUser::get(conn_lock!(context), query_user.id)?.into_json())
 }
+
+/// Delete a user
+///
+/// Delete a user by scrubbing all of their personal data.
+/// The name, slug, and email for the user are replaced with opaque values,
+/// and all of their tokens and organization and project roles are removed.
+/// The user is kept as an opaque identity so that their reports remain attributed,
+/// and a record of the deletion is kept as an audit trail.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/users/{user}",
+    tags = ["users"]
+}]
+pub async fn user_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: UserParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    let user_uuid = query_user.uuid;
+    scrub_user(conn_lock!(context), &query_user, auth_user.id())?;
+    slog::info!(
+        log,
+        "Scrubbed personal data for user ({user_uuid}) as requested by user ({requester_uuid})",
+        user_uuid = user_uuid.to_string(),
+        requester_uuid = auth_user.uuid().to_string()
+    );
+
+    Ok(())
+}
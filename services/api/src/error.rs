@@ -13,8 +13,15 @@ pub enum BencherResource {
     OrganizationRole,
     Project,
     ProjectRole,
+    ProjectToken,
+    UnclaimedProject,
     Report,
+    ReportAttachment,
     ReportBenchmark,
+    ReportFailure,
+    #[cfg(feature = "plus")]
+    ReportMirror,
+    ReportRaw,
     Plot,
     PlotBranch,
     PlotTestbed,
@@ -26,14 +33,23 @@ pub enum BencherResource {
     HeadVersion,
     Testbed,
     Benchmark,
+    BenchmarkLocation,
     Measure,
     Metric,
     Threshold,
+    ThresholdChange,
+    ThresholdTemplate,
     Model,
     Boundary,
     Alert,
+    Integration,
+    AlertIssue,
+    Webhook,
     User,
+    UserDeletion,
     Token,
+    Operation,
+    Backup,
     #[cfg(feature = "plus")]
     Plan,
     #[cfg(feature = "plus")]
@@ -50,8 +66,15 @@ impl fmt::Display for BencherResource {
                 Self::OrganizationRole => "Organization Role",
                 Self::Project => "Project",
                 Self::ProjectRole => "Project Role",
+                Self::ProjectToken => "Project Token",
+                Self::UnclaimedProject => "Unclaimed Project",
                 Self::Report => "Report",
+                Self::ReportAttachment => "Report Attachment",
                 Self::ReportBenchmark => "Report Benchmark",
+                Self::ReportFailure => "Report Failure",
+                #[cfg(feature = "plus")]
+                Self::ReportMirror => "Report Mirror",
+                Self::ReportRaw => "Report Raw Results",
                 Self::Plot => "Plot",
                 Self::PlotBranch => "Plot Branch",
                 Self::PlotTestbed => "Plot Testbed",
@@ -63,14 +86,23 @@ impl fmt::Display for BencherResource {
                 Self::HeadVersion => "Head Version",
                 Self::Testbed => "Testbed",
                 Self::Benchmark => "Benchmark",
+                Self::BenchmarkLocation => "Benchmark Location",
                 Self::Measure => "Measure",
                 Self::Metric => "Metric",
                 Self::Threshold => "Threshold",
+                Self::ThresholdChange => "Threshold Change",
+                Self::ThresholdTemplate => "Threshold Template",
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::Integration => "Integration",
+                Self::AlertIssue => "Alert Issue",
+                Self::Webhook => "Webhook",
                 Self::User => "User",
+                Self::UserDeletion => "User Deletion",
                 Self::Token => "Token",
+                Self::Operation => "Operation",
+                Self::Backup => "Backup",
                 #[cfg(feature = "plus")]
                 Self::Plan => "Plan",
                 #[cfg(feature = "plus")]
@@ -124,6 +156,13 @@ where
     HttpError::for_client_error(None, StatusCode::CONFLICT, error.to_string())
 }
 
+pub fn payload_too_large_error<E>(error: E) -> HttpError
+where
+    E: fmt::Display,
+{
+    HttpError::for_client_error(None, StatusCode::PAYLOAD_TOO_LARGE, error.to_string())
+}
+
 pub fn locked_error<E>(error: E) -> HttpError
 where
     E: fmt::Display,
@@ -131,6 +170,13 @@ where
     HttpError::for_client_error(None, StatusCode::LOCKED, error.to_string())
 }
 
+pub fn too_many_requests_error<E>(error: E) -> HttpError
+where
+    E: fmt::Display,
+{
+    HttpError::for_client_error(None, StatusCode::TOO_MANY_REQUESTS, error.to_string())
+}
+
 pub fn resource_not_found_error<V, E>(resource: BencherResource, value: V, error: E) -> HttpError
 where
     V: fmt::Debug,
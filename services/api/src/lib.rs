@@ -1,4 +1,5 @@
 #![allow(clippy::result_large_err)]
+#![deny(clippy::unwrap_used, clippy::todo)]
 
 use bencher_json::JsonSpec;
 use std::sync::LazyLock;
@@ -8,6 +9,7 @@ pub mod context;
 pub mod endpoints;
 pub mod error;
 pub mod model;
+pub mod notify;
 #[allow(unused_qualifications)]
 pub mod schema;
 pub mod util;
@@ -15,6 +17,9 @@ pub mod util;
 pub mod view;
 
 pub const API_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The oldest client version that can still send compatible payloads.
+/// This must be bumped whenever a breaking change is made to a request body.
+pub const MIN_CLIENT_VERSION: &str = "0.4.32";
 
 pub const SPEC_STR: &str = include_str!("../openapi.json");
 #[allow(clippy::expect_used)]
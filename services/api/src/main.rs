@@ -1,3 +1,5 @@
+#![deny(clippy::unwrap_used, clippy::todo)]
+
 #[cfg(feature = "sentry")]
 use std::path::PathBuf;
 
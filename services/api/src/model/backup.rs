@@ -0,0 +1,124 @@
+use bencher_json::{
+    system::{
+        backup::{BackupUuid, JsonDataStore},
+        operation::OperationKind,
+    },
+    DateTime, JsonBackup, JsonServerBackup,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, backup as backup_table},
+};
+
+use super::operation::{InsertOperation, OperationId, QueryOperation};
+
+crate::util::typed_id::typed_id!(BackupId);
+
+#[derive(Debug, Clone, diesel::Queryable)]
+pub struct QueryBackup {
+    pub id: BackupId,
+    pub uuid: BackupUuid,
+    pub operation_id: OperationId,
+    pub scheduled: bool,
+    pub compress: bool,
+    pub data_store: Option<JsonDataStore>,
+    pub rm: bool,
+    pub file_name: Option<String>,
+    pub created: DateTime,
+}
+
+impl QueryBackup {
+    pub fn from_uuid(conn: &mut DbConnection, uuid: BackupUuid) -> Result<Self, HttpError> {
+        backup_table::table
+            .filter(backup_table::uuid.eq(uuid))
+            .first(conn)
+            .map_err(resource_not_found_err!(Backup, uuid))
+    }
+
+    /// Record the name of the backup file once the database has been backed up
+    pub fn set_file_name(&self, conn: &mut DbConnection, file_name: &str) -> Result<(), HttpError> {
+        diesel::update(backup_table::table.filter(backup_table::id.eq(self.id)))
+            .set(backup_table::file_name.eq(file_name))
+            .execute(conn)
+            .map_err(resource_conflict_err!(Backup, (self, file_name)))?;
+        Ok(())
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonServerBackup, HttpError> {
+        let query_operation = schema::operation::table
+            .filter(schema::operation::id.eq(self.operation_id))
+            .first::<QueryOperation>(conn)
+            .map_err(resource_not_found_err!(Operation, self))?;
+        let Self {
+            uuid,
+            scheduled,
+            compress,
+            data_store,
+            rm,
+            file_name,
+            created,
+            ..
+        } = self;
+        Ok(JsonServerBackup {
+            uuid,
+            operation: query_operation.into_json(),
+            scheduled,
+            compress,
+            data_store,
+            rm,
+            file_name,
+            created,
+        })
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = backup_table)]
+pub struct InsertBackup {
+    pub uuid: BackupUuid,
+    pub operation_id: OperationId,
+    pub scheduled: bool,
+    pub compress: bool,
+    pub data_store: Option<JsonDataStore>,
+    pub rm: bool,
+    pub file_name: Option<String>,
+    pub created: DateTime,
+}
+
+impl InsertBackup {
+    /// Queue a new backup operation and record it in the backup history
+    pub fn queue(
+        conn: &mut DbConnection,
+        json_backup: &JsonBackup,
+        scheduled: bool,
+    ) -> Result<(QueryBackup, QueryOperation), HttpError> {
+        let compress = json_backup.compress.unwrap_or_default();
+        let rm = json_backup.rm.unwrap_or_default();
+        // Create the database backup, then each of the optional steps
+        let total =
+            1 + u32::from(compress) + u32::from(json_backup.data_store.is_some()) + u32::from(rm);
+        let query_operation = InsertOperation::queue(conn, OperationKind::Backup, total)?;
+
+        let insert_backup = Self {
+            uuid: BackupUuid::new(),
+            operation_id: query_operation.id,
+            scheduled,
+            compress,
+            data_store: json_backup.data_store,
+            rm,
+            file_name: None,
+            created: query_operation.created,
+        };
+        diesel::insert_into(backup_table::table)
+            .values(&insert_backup)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Backup, &insert_backup))?;
+        let query_backup = QueryBackup::from_uuid(conn, insert_backup.uuid)?;
+
+        Ok((query_backup, query_operation))
+    }
+}
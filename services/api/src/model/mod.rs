@@ -1,3 +1,5 @@
+pub mod backup;
+pub mod operation;
 pub mod organization;
 pub mod project;
 pub mod server;
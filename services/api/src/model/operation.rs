@@ -0,0 +1,166 @@
+use bencher_json::{
+    system::operation::{JsonOperationProgress, OperationKind, OperationState},
+    DateTime, JsonOperation, OperationUuid,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::operation as operation_table,
+};
+
+crate::util::typed_id::typed_id!(OperationId);
+
+#[derive(Debug, Clone, diesel::Queryable)]
+pub struct QueryOperation {
+    pub id: OperationId,
+    pub uuid: OperationUuid,
+    pub kind: OperationKind,
+    pub state: OperationState,
+    pub completed: i32,
+    pub total: i32,
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryOperation {
+    pub fn from_uuid(conn: &mut DbConnection, uuid: OperationUuid) -> Result<Self, HttpError> {
+        operation_table::table
+            .filter(operation_table::uuid.eq(uuid))
+            .first(conn)
+            .map_err(resource_not_found_err!(Operation, uuid))
+    }
+
+    pub fn into_json(self) -> JsonOperation {
+        let Self {
+            uuid,
+            kind,
+            state,
+            completed,
+            total,
+            error,
+            created,
+            modified,
+            ..
+        } = self;
+        JsonOperation {
+            uuid,
+            kind,
+            state,
+            progress: JsonOperationProgress {
+                completed: u32::try_from(completed).unwrap_or_default(),
+                total: u32::try_from(total).unwrap_or_default(),
+            },
+            error,
+            created,
+            modified,
+        }
+    }
+
+    /// Start running the operation
+    pub fn running(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        self.update(conn, &UpdateOperation::state(OperationState::Running))
+    }
+
+    /// Record that another step of the operation has completed
+    pub fn step(&self, conn: &mut DbConnection, completed: u32) -> Result<(), HttpError> {
+        self.update(conn, &UpdateOperation::completed(completed))
+    }
+
+    /// Finish the operation, successfully or with an error
+    pub fn finish<E>(&self, conn: &mut DbConnection, result: Result<(), E>) -> Result<(), HttpError>
+    where
+        E: std::fmt::Display,
+    {
+        let update = match result {
+            Ok(()) => UpdateOperation {
+                completed: Some(self.total),
+                ..UpdateOperation::state(OperationState::Succeeded)
+            },
+            Err(e) => UpdateOperation {
+                error: Some(Some(e.to_string())),
+                ..UpdateOperation::state(OperationState::Failed)
+            },
+        };
+        self.update(conn, &update)
+    }
+
+    fn update(&self, conn: &mut DbConnection, update: &UpdateOperation) -> Result<(), HttpError> {
+        diesel::update(operation_table::table.filter(operation_table::id.eq(self.id)))
+            .set(update)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Operation, (self, update)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = operation_table)]
+pub struct InsertOperation {
+    pub uuid: OperationUuid,
+    pub kind: OperationKind,
+    pub state: OperationState,
+    pub completed: i32,
+    pub total: i32,
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertOperation {
+    /// Queue a new operation with the given number of steps
+    pub fn queue(
+        conn: &mut DbConnection,
+        kind: OperationKind,
+        total: u32,
+    ) -> Result<QueryOperation, HttpError> {
+        let timestamp = DateTime::now();
+        let insert_operation = Self {
+            uuid: OperationUuid::new(),
+            kind,
+            state: OperationState::Queued,
+            completed: 0,
+            total: i32::try_from(total).unwrap_or(i32::MAX),
+            error: None,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(operation_table::table)
+            .values(&insert_operation)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Operation, &insert_operation))?;
+        QueryOperation::from_uuid(conn, insert_operation.uuid)
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = operation_table)]
+pub struct UpdateOperation {
+    pub state: Option<OperationState>,
+    pub completed: Option<i32>,
+    pub error: Option<Option<String>>,
+    pub modified: DateTime,
+}
+
+impl UpdateOperation {
+    fn state(state: OperationState) -> Self {
+        Self {
+            state: Some(state),
+            completed: None,
+            error: None,
+            modified: DateTime::now(),
+        }
+    }
+
+    fn completed(completed: u32) -> Self {
+        Self {
+            state: None,
+            completed: Some(i32::try_from(completed).unwrap_or(i32::MAX)),
+            error: None,
+            modified: DateTime::now(),
+        }
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     util::{
         fn_get::{fn_from_uuid, fn_get, fn_get_id, fn_get_uuid},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
@@ -90,11 +90,9 @@ impl QueryBenchmark {
         }
 
         let benchmark = JsonNewBenchmark { name, slug: None };
-        let insert_benchmark =
+        let mut insert_benchmark =
             InsertBenchmark::from_json(conn_lock!(context), project_id, benchmark)?;
-        diesel::insert_into(schema::benchmark::table)
-            .values(&insert_benchmark)
-            .execute(conn_lock!(context))
+        insert_with_slug!(conn_lock!(context), benchmark, insert_benchmark)
             .map_err(resource_conflict_err!(Benchmark, &insert_benchmark))?;
 
         Self::from_uuid(conn_lock!(context), project_id, insert_benchmark.uuid)
@@ -125,6 +123,7 @@ impl QueryBenchmark {
             created,
             modified,
             archived,
+            latest: None,
         }
     }
 }
@@ -0,0 +1,72 @@
+use bencher_json::{project::file::JsonNewLocation, DateTime};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use super::{benchmark::BenchmarkId, ProjectId};
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    error::{bad_request_error, resource_conflict_err},
+    model::project::benchmark::QueryBenchmark,
+    schema::benchmark_location as benchmark_location_table,
+};
+
+crate::util::typed_id::typed_id!(BenchmarkLocationId);
+
+// The maximum length of a source file path
+const MAX_FILE_LEN: usize = 1024;
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = benchmark_location_table)]
+pub struct InsertBenchmarkLocation {
+    pub benchmark_id: BenchmarkId,
+    pub file: String,
+    pub line: Option<i32>,
+    pub modified: DateTime,
+}
+
+impl InsertBenchmarkLocation {
+    pub async fn from_json(
+        context: &ApiContext,
+        project_id: ProjectId,
+        location: JsonNewLocation,
+    ) -> Result<Self, HttpError> {
+        let JsonNewLocation {
+            benchmark,
+            file,
+            line,
+        } = location;
+        let file = file.trim().trim_start_matches("./").to_owned();
+        if file.is_empty() || file.len() > MAX_FILE_LEN {
+            return Err(bad_request_error(format!(
+                "Invalid source file path for benchmark ({benchmark}). Must be between 1 and {MAX_FILE_LEN} characters."
+            )));
+        }
+        let line = line
+            .map(i32::try_from)
+            .transpose()
+            .map_err(bad_request_error)?;
+        let benchmark_id = QueryBenchmark::get_or_create(context, project_id, benchmark).await?;
+        Ok(Self {
+            benchmark_id,
+            file,
+            line,
+            modified: DateTime::now(),
+        })
+    }
+
+    /// Replace any existing location for the benchmark
+    pub async fn upsert(self, context: &ApiContext) -> Result<(), HttpError> {
+        diesel::delete(
+            benchmark_location_table::table
+                .filter(benchmark_location_table::benchmark_id.eq(self.benchmark_id)),
+        )
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(BenchmarkLocation, &self))?;
+        diesel::insert_into(benchmark_location_table::table)
+            .values(&self)
+            .execute(conn_lock!(context))
+            .map_err(resource_conflict_err!(BenchmarkLocation, &self))?;
+        Ok(())
+    }
+}
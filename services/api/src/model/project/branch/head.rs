@@ -254,8 +254,11 @@ impl InsertHead {
                 .map_err(resource_conflict_err!(Head, (&query_branch, &update_head)))?;
             slog::debug!(log, "Updated old head to replaced: {update_head:?}");
             // Silence all alerts for the old head
-            let count = QueryAlert::silence_all(context, old_head_id).await?;
-            slog::debug!(log, "Silenced {count} alerts for old head");
+            let alert_ids = QueryAlert::silence_all(context, old_head_id).await?;
+            slog::debug!(log, "Silenced {} alerts for old head", alert_ids.len());
+            // Close any issues that were opened for the silenced alerts
+            #[cfg(feature = "plus")]
+            context.close_alert_issues(log, &alert_ids).await;
         }
 
         // Get the updated branch
@@ -1,37 +1,41 @@
 use bencher_json::{
-    project::branch::{JsonUpdateBranch, JsonUpdateStartPoint},
-    BranchName, BranchUuid, DateTime, JsonBranch, JsonNewBranch, NameId, NameIdKind, Slug,
+    project::branch::{JsonBranchProtection, JsonUpdateBranch, JsonUpdateStartPoint},
+    BranchName, BranchUuid, DateTime, GitHash, JsonBranch, JsonNewBranch, NameId, NameIdKind, Slug,
+};
+use diesel::{
+    ExpressionMethods, JoinOnDsl, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
 };
-use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
 use http::StatusCode;
 use slog::Logger;
 use version::{QueryVersion, VersionId};
 
-use super::{ProjectId, QueryProject};
+use super::{report::QueryReport, ProjectId, QueryProject};
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
     error::{
-        assert_parentage, issue_error, resource_conflict_err, resource_not_found_err,
-        BencherResource,
+        assert_parentage, forbidden_error, issue_error, resource_conflict_err,
+        resource_not_found_err, BencherResource,
     },
     schema::{self, branch as branch_table},
     util::{
         fn_get::{fn_from_uuid, fn_get, fn_get_id, fn_get_uuid},
-        name_id::{fn_eq_name_id, fn_from_name_id},
+        name_id::{fn_eq_name_id, fn_from_name_id, fn_from_name_or_slug},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
 pub mod head;
 pub mod head_version;
+pub mod protection;
 pub mod start_point;
 pub mod version;
 
 use head::{HeadId, InsertHead, QueryHead};
 use head_version::QueryHeadVersion;
+use protection::BranchAuth;
 use start_point::StartPoint;
 
 crate::util::typed_id::typed_id!(BranchId);
@@ -51,6 +55,10 @@ pub struct QueryBranch {
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
+    pub merged: Option<DateTime>,
+    pub protect_delete: bool,
+    pub protect_thresholds: bool,
+    pub protect_reports: bool,
 }
 
 impl QueryBranch {
@@ -59,6 +67,7 @@ impl QueryBranch {
 
     fn_eq_name_id!(BranchName, branch);
     fn_from_name_id!(branch, Branch);
+    fn_from_name_or_slug!(branch, Branch);
 
     fn_get!(branch, BranchId);
     fn_get_id!(branch, BranchId, BranchUuid);
@@ -91,9 +100,11 @@ impl QueryBranch {
         project_id: ProjectId,
         branch: &NameId,
         start_point: Option<&JsonUpdateStartPoint>,
+        branch_auth: BranchAuth,
     ) -> Result<(BranchId, HeadId), HttpError> {
         let (query_branch, query_head) =
-            Self::get_or_create_inner(log, context, project_id, branch, start_point).await?;
+            Self::get_or_create_inner(log, context, project_id, branch, start_point, branch_auth)
+                .await?;
 
         if query_branch.archived.is_some() {
             let update_branch = UpdateBranch::unarchive();
@@ -112,13 +123,21 @@ impl QueryBranch {
         project_id: ProjectId,
         branch: &NameId,
         start_point: Option<&JsonUpdateStartPoint>,
+        branch_auth: BranchAuth,
     ) -> Result<(Self, QueryHead), HttpError> {
         let query_branch = Self::from_name_id(conn_lock!(context), project_id, branch);
 
         let http_error = match query_branch {
             Ok(branch) => {
+                branch.check_protected_reports(branch_auth)?;
                 return branch
-                    .update_start_point_if_changed(log, context, project_id, start_point)
+                    .update_start_point_if_changed(
+                        log,
+                        context,
+                        project_id,
+                        start_point,
+                        branch_auth,
+                    )
                     .await;
             },
             Err(e) => e,
@@ -133,11 +152,13 @@ impl QueryBranch {
                 name: slug.clone().into(),
                 slug: Some(slug),
                 start_point: start_point.cloned().and_then(Into::into),
+                upsert: None,
             },
             NameIdKind::Name(name) => JsonNewBranch {
                 name,
                 slug: None,
                 start_point: start_point.cloned().and_then(Into::into),
+                upsert: None,
             },
         };
         InsertBranch::from_json(log, context, project_id, branch).await
@@ -149,7 +170,17 @@ impl QueryBranch {
         context: &ApiContext,
         project_id: ProjectId,
         start_point: Option<&JsonUpdateStartPoint>,
+        branch_auth: BranchAuth,
     ) -> Result<(Self, QueryHead), HttpError> {
+        // Cloning the thresholds from a start point may loosen or remove the current thresholds.
+        if let Some(JsonUpdateStartPoint {
+            clone_thresholds: Some(true),
+            ..
+        }) = start_point
+        {
+            self.check_protected_thresholds(branch_auth)?;
+        }
+
         // Get the current start point, if one exists.
         let current_start_point = self.get_start_point(context).await?;
         // Get the new start point, if there is one specified.
@@ -289,6 +320,10 @@ impl QueryBranch {
             created,
             modified,
             archived,
+            merged,
+            protect_delete,
+            protect_thresholds,
+            protect_reports,
             ..
         } = self;
         assert_parentage(
@@ -313,8 +348,131 @@ impl QueryBranch {
             created,
             modified,
             archived,
+            merged,
+            protection: JsonBranchProtection {
+                delete: protect_delete,
+                thresholds: protect_thresholds,
+                reports: protect_reports,
+            },
         })
     }
+
+    /// Graft the most recent reports for the branch onto the target branch head,
+    /// as the version for the given `git` hash.
+    /// Returns the number of reports that were grafted.
+    pub fn graft(
+        &self,
+        conn: &mut DbConnection,
+        target: &QueryBranch,
+        hash: Option<&GitHash>,
+        branch_auth: BranchAuth,
+    ) -> Result<usize, HttpError> {
+        // Grafting creates reports for the target branch
+        target.check_protected_reports(branch_auth)?;
+        let head_id = self.head_id()?;
+        // Only consider versions with reports for the current branch head.
+        // Versions cloned from the start point belong to the start point branch.
+        let Some(version_id) = schema::report::table
+            .inner_join(schema::version::table)
+            .filter(schema::report::head_id.eq(head_id))
+            .order(schema::version::number.desc())
+            .select(schema::report::version_id)
+            .first::<VersionId>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(Version, (self, head_id)))?
+        else {
+            return Ok(0);
+        };
+
+        let query_reports = schema::report::table
+            .filter(schema::report::head_id.eq(head_id))
+            .filter(schema::report::version_id.eq(version_id))
+            .load::<QueryReport>(conn)
+            .map_err(resource_not_found_err!(Report, (self, head_id, version_id)))?;
+
+        let target_head_id = target.head_id()?;
+        let target_version_id =
+            QueryVersion::get_or_increment(conn, self.project_id, target_head_id, hash)?;
+        for query_report in &query_reports {
+            query_report.graft(conn, target_head_id, target_version_id)?;
+        }
+
+        Ok(query_reports.len())
+    }
+
+    pub fn protection(&self) -> JsonBranchProtection {
+        JsonBranchProtection {
+            delete: self.protect_delete,
+            thresholds: self.protect_thresholds,
+            reports: self.protect_reports,
+        }
+    }
+
+    /// Verify that the branch is not protected from deletion
+    pub fn check_protected_delete(&self) -> Result<(), HttpError> {
+        if self.protect_delete {
+            Err(forbidden_error(format!(
+                "Branch ({}) is protected from deletion. Remove its protection first.",
+                self.uuid
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verify that the user is allowed to loosen or remove the thresholds for the branch
+    pub fn check_protected_thresholds(&self, branch_auth: BranchAuth) -> Result<(), HttpError> {
+        if self.protect_thresholds && !branch_auth.org_leader {
+            Err(forbidden_error(format!(
+                "The thresholds for branch ({}) are protected. Only an organization leader can loosen or remove them.",
+                self.uuid
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verify that changes to the thresholds for the branch can be made directly.
+    /// Changes to protected thresholds must be requested and then approved by a different user.
+    pub fn check_protected_threshold_changes(&self) -> Result<(), HttpError> {
+        if self.protect_thresholds {
+            Err(forbidden_error(format!(
+                "The thresholds for branch ({}) are protected. Request a threshold change instead, which must be approved by another project maintainer.",
+                self.uuid
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verify that the user is allowed to create reports for the branch
+    pub fn check_protected_reports(&self, branch_auth: BranchAuth) -> Result<(), HttpError> {
+        if self.protect_reports && !branch_auth.scoped_token {
+            Err(forbidden_error(format!(
+                "Reports for branch ({}) are protected. Use an API token that is limited to a scope or project.",
+                self.uuid
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verify that the user is allowed to change the protection for the branch
+    pub fn check_protection_change(
+        &self,
+        branch_auth: BranchAuth,
+        protection: Option<JsonBranchProtection>,
+    ) -> Result<(), HttpError> {
+        match protection {
+            Some(protection) if protection != self.protection() && !branch_auth.org_leader => {
+                Err(forbidden_error(format!(
+                    "Only an organization leader can change the protection for branch ({}).",
+                    self.uuid
+                )))
+            },
+            Some(_) | None => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, diesel::Insertable)]
@@ -328,25 +486,16 @@ pub struct InsertBranch {
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
+    pub merged: Option<DateTime>,
+    pub protect_delete: bool,
+    pub protect_thresholds: bool,
+    pub protect_reports: bool,
 }
 
 impl InsertBranch {
-    pub async fn new(
-        context: &ApiContext,
-        project_id: ProjectId,
-        name: BranchName,
-        slug: Option<Slug>,
-    ) -> Result<Self, HttpError> {
-        let slug = conn_lock!(context, |conn| ok_slug!(
-            conn,
-            project_id,
-            &name,
-            slug,
-            branch,
-            QueryBranch
-        )?);
+    pub fn new(project_id: ProjectId, name: BranchName, slug: Slug) -> Self {
         let timestamp = DateTime::now();
-        Ok(Self {
+        Self {
             uuid: BranchUuid::new(),
             project_id,
             name,
@@ -355,7 +504,11 @@ impl InsertBranch {
             created: timestamp,
             modified: timestamp,
             archived: None,
-        })
+            merged: None,
+            protect_delete: false,
+            protect_thresholds: false,
+            protect_reports: false,
+        }
     }
 
     pub async fn from_json(
@@ -368,13 +521,26 @@ impl InsertBranch {
             name,
             slug,
             start_point,
+            upsert,
         } = branch;
+        let upsert = upsert.unwrap_or_default();
 
         // Create branch
-        let insert_branch = Self::new(context, project_id, name, slug).await?;
-        diesel::insert_into(schema::branch::table)
-            .values(&insert_branch)
-            .execute(conn_lock!(context))
+        // An upsert always uses the preferred slug
+        let slug = if upsert {
+            Slug::unwrap_or_new(&name, slug)
+        } else {
+            conn_lock!(context, |conn| ok_slug!(
+                conn,
+                project_id,
+                &name,
+                slug,
+                branch,
+                QueryBranch
+            )?)
+        };
+        let mut insert_branch = Self::new(project_id, name, slug);
+        insert_with_slug!(conn_lock!(context), branch, insert_branch, upsert = upsert)
             .map_err(resource_conflict_err!(Branch, insert_branch))?;
         slog::debug!(log, "Created branch {insert_branch:?}");
 
@@ -418,6 +584,9 @@ pub struct UpdateBranch {
     pub slug: Option<Slug>,
     pub modified: DateTime,
     pub archived: Option<Option<DateTime>>,
+    pub protect_delete: Option<bool>,
+    pub protect_thresholds: Option<bool>,
+    pub protect_reports: Option<bool>,
 }
 
 impl From<JsonUpdateBranch> for UpdateBranch {
@@ -427,6 +596,7 @@ impl From<JsonUpdateBranch> for UpdateBranch {
             slug,
             start_point: _,
             archived,
+            protection,
         } = update;
         let modified = DateTime::now();
         let archived = archived.map(|archived| archived.then_some(modified));
@@ -435,6 +605,9 @@ impl From<JsonUpdateBranch> for UpdateBranch {
             slug,
             modified,
             archived,
+            protect_delete: protection.map(|protection| protection.delete),
+            protect_thresholds: protection.map(|protection| protection.thresholds),
+            protect_reports: protection.map(|protection| protection.reports),
         }
     }
 }
@@ -446,6 +619,7 @@ impl UpdateBranch {
             slug: None,
             start_point: None,
             archived: Some(false),
+            protection: None,
         }
         .into()
     }
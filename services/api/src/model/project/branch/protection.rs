@@ -0,0 +1,28 @@
+use crate::{
+    context::Rbac,
+    model::{project::QueryProject, user::auth::AuthUser},
+};
+
+/// The permissions of the user making a change to a branch, used to enforce branch protection
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BranchAuth {
+    /// The change is made with an API token that is limited to a scope or a single project
+    pub scoped_token: bool,
+    /// The user has `manage` permissions for the organization that owns the branch
+    pub org_leader: bool,
+}
+
+impl BranchAuth {
+    pub fn new(rbac: &Rbac, auth_user: &AuthUser, query_project: &QueryProject) -> Self {
+        Self {
+            scoped_token: !auth_user.scope.is_unlimited(),
+            org_leader: rbac
+                .is_allowed_organization(
+                    auth_user,
+                    bencher_rbac::organization::Permission::Manage,
+                    query_project,
+                )
+                .is_ok(),
+        }
+    }
+}
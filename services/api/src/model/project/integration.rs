@@ -0,0 +1,360 @@
+use std::collections::HashSet;
+
+use bencher_json::{
+    project::integration::{
+        IntegrationProvider, JsonUpdateIntegration, DEFAULT_ISSUE_BODY, DEFAULT_ISSUE_TITLE,
+    },
+    DateTime, IntegrationUuid, JsonIntegration, JsonNewIntegration, NonEmpty, Url,
+};
+use diesel::{
+    BelongingToDsl, ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use dropshot::HttpError;
+
+use super::{
+    benchmark::BenchmarkId,
+    report::ReportId,
+    threshold::{alert::AlertId, ThresholdId},
+    ProjectId, QueryProject,
+};
+use crate::{
+    context::{DbConnection, Encryption},
+    error::{
+        assert_parentage, issue_error, resource_conflict_err, resource_not_found_err,
+        BencherResource,
+    },
+    schema::{self, alert_issue as alert_issue_table, integration as integration_table},
+    util::fn_get::fn_get,
+};
+
+crate::util::typed_id::typed_id!(IntegrationId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = integration_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryIntegration {
+    pub id: IntegrationId,
+    pub uuid: IntegrationUuid,
+    pub project_id: ProjectId,
+    pub provider: IntegrationProvider,
+    pub repository: NonEmpty,
+    pub api_url: Url,
+    pub token: String,
+    pub title: NonEmpty,
+    pub body: NonEmpty,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryIntegration {
+    fn_get!(integration, IntegrationId);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: IntegrationUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(query_project)
+            .filter(integration_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Integration, (query_project, uuid)))
+    }
+
+    pub fn all_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Vec<Self>, HttpError> {
+        Self::belonging_to(query_project)
+            .order(integration_table::created.asc())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(Integration, query_project))
+    }
+
+    /// Decrypt the integration token, so it can be used to authenticate with the issue tracker
+    pub fn decrypt(mut self, encryption: &Encryption) -> Result<Self, HttpError> {
+        self.token = encryption.decrypt(&self.token)?;
+        Ok(self)
+    }
+
+    pub fn into_json_for_project(self, project: &QueryProject) -> JsonIntegration {
+        let Self {
+            uuid,
+            project_id,
+            provider,
+            repository,
+            api_url,
+            title,
+            body,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Integration,
+            project_id,
+        );
+        JsonIntegration {
+            uuid,
+            project: project.uuid,
+            provider,
+            repository,
+            api_url,
+            title,
+            body,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = integration_table)]
+pub struct InsertIntegration {
+    pub uuid: IntegrationUuid,
+    pub project_id: ProjectId,
+    pub provider: IntegrationProvider,
+    pub repository: NonEmpty,
+    pub api_url: Url,
+    pub token: String,
+    pub title: NonEmpty,
+    pub body: NonEmpty,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertIntegration {
+    pub fn from_json(
+        encryption: &Encryption,
+        project_id: ProjectId,
+        integration: JsonNewIntegration,
+    ) -> Result<Self, HttpError> {
+        let JsonNewIntegration {
+            provider,
+            repository,
+            api_url,
+            token,
+            title,
+            body,
+        } = integration;
+        let api_url = if let Some(api_url) = api_url {
+            api_url
+        } else {
+            parse_default(provider.default_api_url())?
+        };
+        let title = if let Some(title) = title {
+            title
+        } else {
+            parse_default(DEFAULT_ISSUE_TITLE)?
+        };
+        let body = if let Some(body) = body {
+            body
+        } else {
+            parse_default(DEFAULT_ISSUE_BODY)?
+        };
+        let timestamp = DateTime::now();
+        Ok(Self {
+            uuid: IntegrationUuid::new(),
+            project_id,
+            provider,
+            repository,
+            api_url,
+            token: encryption.encrypt(token.as_ref())?,
+            title,
+            body,
+            created: timestamp,
+            modified: timestamp,
+        })
+    }
+}
+
+fn parse_default<T>(value: &str) -> Result<T, HttpError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value.parse().map_err(|e| {
+        issue_error(
+            http::StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to parse integration default",
+            &format!("Failed to parse integration default: {value}"),
+            e,
+        )
+    })
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = integration_table)]
+pub struct UpdateIntegration {
+    pub repository: Option<NonEmpty>,
+    pub api_url: Option<Url>,
+    pub token: Option<String>,
+    pub title: Option<NonEmpty>,
+    pub body: Option<NonEmpty>,
+    pub modified: DateTime,
+}
+
+impl UpdateIntegration {
+    pub fn from_json(
+        encryption: &Encryption,
+        update: JsonUpdateIntegration,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateIntegration {
+            repository,
+            api_url,
+            token,
+            title,
+            body,
+        } = update;
+        Ok(Self {
+            repository,
+            api_url,
+            token: token
+                .map(|token| encryption.encrypt(token.as_ref()))
+                .transpose()?,
+            title,
+            body,
+            modified: DateTime::now(),
+        })
+    }
+}
+
+crate::util::typed_id::typed_id!(AlertIssueId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = alert_issue_table)]
+pub struct QueryAlertIssue {
+    pub id: AlertIssueId,
+    pub alert_id: AlertId,
+    pub integration_id: IntegrationId,
+    pub number: i64,
+    pub url: String,
+    pub created: DateTime,
+    pub closed: Option<DateTime>,
+}
+
+impl QueryAlertIssue {
+    /// Get the open issues, along with their integrations, for the given alerts
+    pub fn open_for_alerts(
+        conn: &mut DbConnection,
+        alert_ids: &[AlertId],
+    ) -> Result<Vec<(Self, QueryIntegration)>, HttpError> {
+        alert_issue_table::table
+            .inner_join(integration_table::table)
+            .filter(alert_issue_table::alert_id.eq_any(alert_ids))
+            .filter(alert_issue_table::closed.is_null())
+            .select((QueryAlertIssue::as_select(), QueryIntegration::as_select()))
+            .load(conn)
+            .map_err(resource_not_found_err!(AlertIssue, alert_ids))
+    }
+
+    /// Get the open issues, along with their integrations, for the alerts that have been resolved by the report.
+    /// An alert is resolved once a later report has a boundary for the same threshold and benchmark
+    /// that did not generate an alert.
+    pub fn resolved_by_report(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        report_id: ReportId,
+    ) -> Result<Vec<(Self, QueryIntegration)>, HttpError> {
+        let boundaries = schema::boundary::table
+            .inner_join(schema::metric::table.inner_join(schema::report_benchmark::table))
+            .left_join(schema::alert::table)
+            .filter(schema::report_benchmark::report_id.eq(report_id))
+            .select((
+                schema::boundary::threshold_id,
+                schema::report_benchmark::benchmark_id,
+                schema::alert::id.nullable(),
+            ))
+            .load::<(ThresholdId, BenchmarkId, Option<AlertId>)>(conn)
+            .map_err(resource_not_found_err!(Boundary, report_id))?;
+        // A benchmark may have multiple iterations in the same report,
+        // so it is only resolved if none of its iterations generated an alert.
+        let alerting = boundaries
+            .iter()
+            .filter_map(|(threshold_id, benchmark_id, alert_id)| {
+                alert_id.map(|_| (*threshold_id, *benchmark_id))
+            })
+            .collect::<HashSet<_>>();
+        let resolved = boundaries
+            .into_iter()
+            .map(|(threshold_id, benchmark_id, _)| (threshold_id, benchmark_id))
+            .filter(|key| !alerting.contains(key))
+            .collect::<HashSet<_>>();
+        if resolved.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let open_issues =
+            alert_issue_table::table
+                .inner_join(integration_table::table)
+                .inner_join(schema::alert::table.inner_join(
+                    schema::boundary::table.inner_join(
+                        schema::metric::table.inner_join(schema::report_benchmark::table),
+                    ),
+                ))
+                .filter(integration_table::project_id.eq(project_id))
+                .filter(alert_issue_table::closed.is_null())
+                .select((
+                    schema::boundary::threshold_id,
+                    schema::report_benchmark::benchmark_id,
+                    QueryAlertIssue::as_select(),
+                    QueryIntegration::as_select(),
+                ))
+                .load::<(ThresholdId, BenchmarkId, Self, QueryIntegration)>(conn)
+                .map_err(resource_not_found_err!(AlertIssue, (project_id, report_id)))?;
+        Ok(open_issues
+            .into_iter()
+            .filter(|(threshold_id, benchmark_id, ..)| {
+                resolved.contains(&(*threshold_id, *benchmark_id))
+            })
+            .map(|(_, _, alert_issue, integration)| (alert_issue, integration))
+            .collect())
+    }
+
+    pub fn close(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::update(alert_issue_table::table.filter(alert_issue_table::id.eq(self.id)))
+            .set(alert_issue_table::closed.eq(DateTime::now()))
+            .execute(conn)
+            .map_err(resource_conflict_err!(AlertIssue, self))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = alert_issue_table)]
+pub struct InsertAlertIssue {
+    pub alert_id: AlertId,
+    pub integration_id: IntegrationId,
+    pub number: i64,
+    pub url: String,
+    pub created: DateTime,
+    pub closed: Option<DateTime>,
+}
+
+impl InsertAlertIssue {
+    pub fn insert(
+        conn: &mut DbConnection,
+        alert_id: AlertId,
+        integration_id: IntegrationId,
+        number: i64,
+        url: String,
+    ) -> Result<(), HttpError> {
+        let insert_alert_issue = Self {
+            alert_id,
+            integration_id,
+            number,
+            url,
+            created: DateTime::now(),
+            closed: None,
+        };
+        diesel::insert_into(alert_issue_table::table)
+            .values(&insert_alert_issue)
+            .execute(conn)
+            .map_err(resource_conflict_err!(AlertIssue, insert_alert_issue))?;
+        Ok(())
+    }
+}
@@ -16,9 +16,9 @@ use crate::{
     schema::{self, measure as measure_table},
     util::{
         fn_get::{fn_from_uuid, fn_get, fn_get_id, fn_get_uuid},
-        name_id::{fn_eq_name_id, fn_from_name_id},
+        name_id::{fn_eq_name_id, fn_from_name_id, fn_from_name_or_slug},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
@@ -49,6 +49,7 @@ impl QueryMeasure {
 
     fn_eq_name_id!(ResourceName, measure);
     fn_from_name_id!(measure, Measure);
+    fn_from_name_or_slug!(measure, Measure);
 
     fn_get!(measure, MeasureId);
     fn_get_id!(measure, MeasureId, MeasureUuid);
@@ -93,6 +94,10 @@ impl QueryMeasure {
             .or_else(|| built_in::default::Throughput::from_str(measure_str))
             .or_else(|| built_in::json::BuildTime::from_str(measure_str))
             .or_else(|| built_in::json::FileSize::from_str(measure_str))
+            .or_else(|| built_in::json::TimeToFirstByte::from_str(measure_str))
+            .or_else(|| built_in::json::MaxRss::from_str(measure_str))
+            .or_else(|| built_in::json::Energy::from_str(measure_str))
+            .or_else(|| built_in::report::Duration::from_str(measure_str))
             .or_else(|| built_in::iai::Instructions::from_str(measure_str))
             .or_else(|| built_in::iai::L1Accesses::from_str(measure_str))
             .or_else(|| built_in::iai::L2Accesses::from_str(measure_str))
@@ -125,19 +130,20 @@ impl QueryMeasure {
                     name: slug.clone().into(),
                     slug: Some(slug),
                     units: JsonNewMeasure::generic_unit(),
+                    upsert: None,
                 },
                 NameIdKind::Name(name) => JsonNewMeasure {
                     name,
                     slug: None,
                     units: JsonNewMeasure::generic_unit(),
+                    upsert: None,
                 },
             }
         };
 
-        let insert_measure = InsertMeasure::from_json(conn_lock!(context), project_id, measure)?;
-        diesel::insert_into(schema::measure::table)
-            .values(&insert_measure)
-            .execute(conn_lock!(context))
+        let mut insert_measure =
+            InsertMeasure::from_json(conn_lock!(context), project_id, measure)?;
+        insert_with_slug!(conn_lock!(context), measure, insert_measure)
             .map_err(resource_conflict_err!(Measure, insert_measure))?;
 
         Self::from_uuid(conn_lock!(context), project_id, insert_measure.uuid)
@@ -200,8 +206,18 @@ impl InsertMeasure {
         project_id: ProjectId,
         measure: JsonNewMeasure,
     ) -> Result<Self, HttpError> {
-        let JsonNewMeasure { name, slug, units } = measure;
-        let slug = ok_slug!(conn, project_id, &name, slug, measure, QueryMeasure)?;
+        let JsonNewMeasure {
+            name,
+            slug,
+            units,
+            upsert,
+        } = measure;
+        // An upsert always uses the preferred slug
+        let slug = if upsert.unwrap_or_default() {
+            Slug::unwrap_or_new(&name, slug)
+        } else {
+            ok_slug!(conn, project_id, &name, slug, measure, QueryMeasure)?
+        };
         let timestamp = DateTime::now();
         Ok(Self {
             uuid: MeasureUuid::new(),
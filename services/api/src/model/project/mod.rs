@@ -1,40 +1,54 @@
 use std::string::ToString;
 
 use bencher_json::{
-    project::{JsonProjectPatch, JsonProjectPatchNull, JsonUpdateProject, Visibility},
+    project::{
+        boundary::BoundaryLimit, measure::built_in, JsonProjectPatch, JsonProjectPatchNull,
+        JsonUpdateProject, Visibility,
+    },
     DateTime, JsonNewProject, JsonProject, ProjectUuid, ResourceId, ResourceName, Slug, Url,
 };
 use bencher_rbac::{project::Permission, Organization, Project};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
+use slog::Logger;
 
 use crate::{
-    context::{DbConnection, Rbac},
+    conn_lock,
+    context::{ApiContext, DbConnection, Rbac},
     error::{
-        assert_parentage, forbidden_error, resource_not_found_error, unauthorized_error,
-        BencherResource,
+        assert_parentage, forbidden_error, resource_conflict_err, resource_not_found_err,
+        resource_not_found_error, unauthorized_error, BencherResource,
     },
     model::{organization::QueryOrganization, user::auth::AuthUser},
     schema::{self, project as project_table},
     util::{
         fn_get::{fn_get, fn_get_uuid},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
 use super::organization::OrganizationId;
+use branch::InsertBranch;
+use measure::{InsertMeasure, QueryMeasure};
+use testbed::{InsertTestbed, QueryTestbed};
+use threshold::InsertThreshold;
 
 pub mod benchmark;
+pub mod benchmark_location;
 pub mod branch;
+pub mod integration;
 pub mod measure;
 pub mod metric;
 pub mod metric_boundary;
 pub mod plot;
 pub mod project_role;
+pub mod project_token;
 pub mod report;
 pub mod testbed;
 pub mod threshold;
+pub mod unclaimed_project;
+pub mod webhook;
 
 crate::util::typed_id::typed_id!(ProjectId);
 
@@ -230,6 +244,63 @@ impl InsertProject {
             modified: timestamp,
         })
     }
+
+    /// Create the project along with its default branch, testbed, measures, and thresholds
+    pub async fn insert(
+        mut self,
+        log: &Logger,
+        context: &ApiContext,
+    ) -> Result<QueryProject, HttpError> {
+        insert_with_slug!(conn_lock!(context), project, self)
+            .map_err(resource_conflict_err!(Project, self))?;
+        let query_project = schema::project::table
+            .filter(schema::project::uuid.eq(&self.uuid))
+            .first::<QueryProject>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Project, self))?;
+        slog::debug!(log, "Created project: {query_project:?}");
+
+        // Add a `main` branch to the project
+        let query_branch = InsertBranch::main(log, context, query_project.id).await?;
+        slog::debug!(log, "Added project branch: {query_branch:?}");
+        let branch_id = query_branch.id;
+
+        // Add a `localhost` testbed to the project
+        let mut insert_testbed = InsertTestbed::localhost(conn_lock!(context), query_project.id)?;
+        insert_with_slug!(conn_lock!(context), testbed, insert_testbed)
+            .map_err(resource_conflict_err!(Testbed, insert_testbed))?;
+        let testbed_id = QueryTestbed::get_id(conn_lock!(context), insert_testbed.uuid)?;
+        slog::debug!(log, "Added project testbed: {insert_testbed:?}");
+
+        // Add the catalog of well-known measures to the project
+        // along with a threshold for each in the direction of a regression
+        for (measure, limit) in built_in::catalog() {
+            let mut insert_measure =
+                InsertMeasure::from_json(conn_lock!(context), query_project.id, measure)?;
+            insert_with_slug!(conn_lock!(context), measure, insert_measure)
+                .map_err(resource_conflict_err!(Measure, insert_measure))?;
+            let measure_id = QueryMeasure::get_id(conn_lock!(context), insert_measure.uuid)?;
+            slog::debug!(log, "Added project measure: {insert_measure:?}");
+            let threshold_id = match limit {
+                BoundaryLimit::Lower => InsertThreshold::lower_boundary(
+                    conn_lock!(context),
+                    query_project.id,
+                    branch_id,
+                    testbed_id,
+                    measure_id,
+                ),
+                BoundaryLimit::Upper => InsertThreshold::upper_boundary(
+                    conn_lock!(context),
+                    query_project.id,
+                    branch_id,
+                    testbed_id,
+                    measure_id,
+                ),
+            }?;
+            slog::debug!(log, "Added project threshold: {threshold_id}");
+        }
+
+        Ok(query_project)
+    }
 }
 
 #[derive(Debug, Clone, diesel::AsChangeset)]
@@ -0,0 +1,173 @@
+use bencher_json::{
+    project::token::JsonUpdateProjectToken, user::token::TokenScope, DateTime, JsonNewProjectToken,
+    JsonProjectToken, Jwt, ProjectTokenUuid, ResourceName,
+};
+use bencher_token::TokenKey;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use http::StatusCode;
+
+use super::{ProjectId, QueryProject};
+use crate::{
+    context::DbConnection,
+    error::{
+        assert_parentage, bad_request_error, issue_error, resource_not_found_err, BencherResource,
+    },
+    model::user::{auth::AuthUser, QueryUser, UserId},
+    schema::project_token as project_token_table,
+    util::fn_get::fn_get,
+};
+
+crate::util::typed_id::typed_id!(ProjectTokenId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = project_token_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryProjectToken {
+    pub id: ProjectTokenId,
+    pub uuid: ProjectTokenUuid,
+    pub project_id: ProjectId,
+    pub creator_id: UserId,
+    pub name: ResourceName,
+    pub jwt: Jwt,
+    pub scope: TokenScope,
+    pub creation: DateTime,
+    pub expiration: DateTime,
+}
+
+impl QueryProjectToken {
+    fn_get!(project_token, ProjectTokenId);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: ProjectTokenUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(query_project)
+            .filter(project_token_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(ProjectToken, (query_project, uuid)))
+    }
+
+    pub fn get_with_jwt(conn: &mut DbConnection, jwt: &Jwt) -> Result<Self, HttpError> {
+        project_token_table::table
+            .filter(project_token_table::jwt.eq(jwt))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(ProjectToken, jwt))
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonProjectToken, HttpError> {
+        let Self {
+            uuid,
+            project_id,
+            creator_id,
+            name,
+            jwt,
+            scope,
+            creation,
+            expiration,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::ProjectToken,
+            project_id,
+        );
+        Ok(JsonProjectToken {
+            uuid,
+            project: project.uuid,
+            creator: QueryUser::get_uuid(conn, creator_id)?,
+            name,
+            token: jwt,
+            scope,
+            creation,
+            expiration,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = project_token_table)]
+pub struct InsertProjectToken {
+    pub uuid: ProjectTokenUuid,
+    pub project_id: ProjectId,
+    pub creator_id: UserId,
+    pub name: ResourceName,
+    pub jwt: Jwt,
+    pub scope: TokenScope,
+    pub creation: DateTime,
+    pub expiration: DateTime,
+}
+
+impl InsertProjectToken {
+    pub fn from_json(
+        token_key: &TokenKey,
+        query_project: &QueryProject,
+        token: JsonNewProjectToken,
+        auth_user: &AuthUser,
+    ) -> Result<Self, HttpError> {
+        let JsonNewProjectToken { name, ttl, scope } = token;
+
+        // TODO Custom max TTL
+        let max_ttl = u32::MAX;
+        let ttl = if let Some(ttl) = ttl {
+            if ttl > max_ttl {
+                return Err(bad_request_error(format!(
+                    "Requested TTL ({ttl}) is greater than max ({max_ttl})"
+                )));
+            }
+            ttl
+        } else {
+            max_ttl
+        };
+
+        let jwt = token_key
+            .new_project_key(auth_user.user.email.clone(), ttl)
+            .map_err(|e| {
+                issue_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create new project API key",
+                    "Failed to create new project API key.",
+                    e,
+                )
+            })?;
+
+        let claims = token_key.validate_project_key(&jwt).map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to validate new project API key",
+                &format!("Failed to validate new project API key: {jwt}"),
+                e,
+            )
+        })?;
+
+        Ok(Self {
+            uuid: ProjectTokenUuid::new(),
+            project_id: query_project.id,
+            creator_id: auth_user.id(),
+            name,
+            jwt,
+            scope: scope.unwrap_or(TokenScope::ReportWrite),
+            creation: claims.issued_at(),
+            expiration: claims.expiration(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = project_token_table)]
+pub struct UpdateProjectToken {
+    pub name: Option<ResourceName>,
+}
+
+impl From<JsonUpdateProjectToken> for UpdateProjectToken {
+    fn from(update: JsonUpdateProjectToken) -> Self {
+        let JsonUpdateProjectToken { name } = update;
+        Self { name }
+    }
+}
@@ -1,20 +1,26 @@
+use std::collections::HashMap;
+
 use bencher_json::{
-    project::report::{
-        Adapter, Iteration, JsonReportAlerts, JsonReportMeasure, JsonReportResult,
-        JsonReportResults,
+    project::{
+        head::VersionNumber,
+        report::{
+            Adapter, Iteration, JsonReportAlerts, JsonReportMeasure, JsonReportResult,
+            JsonReportResults,
+        },
     },
-    DateTime, JsonNewReport, JsonReport, ReportUuid,
+    DateTime, JsonNewReport, JsonReport, MetricUuid, ReportUuid, ResourceName,
 };
 use diesel::{
-    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::count, BelongingToDsl, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl,
+    RunQueryDsl, SelectableHelper,
 };
 use dropshot::HttpError;
 use slog::Logger;
 
 use crate::{
     conn_lock,
-    context::ApiContext,
-    error::resource_not_found_err,
+    context::{ApiContext, DbConnection},
+    error::{resource_conflict_err, resource_not_found_err},
     model::{
         project::{
             benchmark::QueryBenchmark,
@@ -31,15 +37,27 @@ use crate::{
 };
 
 use super::{
-    branch::{head::HeadId, version::VersionId, QueryBranch},
-    metric::QueryMetric,
+    branch::{
+        head::HeadId,
+        version::{QueryVersion, VersionId},
+        QueryBranch,
+    },
+    metric::{InsertMetric, QueryMetric},
     metric_boundary::QueryMetricBoundary,
     threshold::boundary::QueryBoundary,
 };
 
+pub mod report_attachment;
 pub mod report_benchmark;
+pub mod report_failure;
+pub mod report_mirror;
+pub mod report_raw;
 pub mod results;
 
+use report_attachment::QueryReportAttachment;
+use report_benchmark::{InsertReportBenchmark, QueryReportBenchmark, ReportBenchmarkId};
+use report_failure::QueryReportFailure;
+
 crate::util::typed_id::typed_id!(ReportId);
 
 #[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable)]
@@ -48,11 +66,12 @@ crate::util::typed_id::typed_id!(ReportId);
 pub struct QueryReport {
     pub id: ReportId,
     pub uuid: ReportUuid,
-    pub user_id: UserId,
+    pub user_id: Option<UserId>,
     pub project_id: ProjectId,
     pub head_id: HeadId,
     pub version_id: VersionId,
     pub testbed_id: TestbedId,
+    pub environment: Option<ResourceName>,
     pub adapter: Adapter,
     pub start_time: DateTime,
     pub end_time: DateTime,
@@ -63,10 +82,96 @@ impl QueryReport {
     fn_get_id!(report, ReportId, ReportUuid);
     fn_get_uuid!(report, ReportId, ReportUuid);
 
+    /// Delete the report.
+    /// If there are no more reports for its version, then the version is deleted as well,
+    /// and all greater versions for the heads that used it are decremented.
+    /// Any attachments stored in the data store must be deleted separately.
+    pub fn delete(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        version_id: VersionId,
+    ) -> Result<(), HttpError> {
+        diesel::delete(report_table::table.filter(report_table::id.eq(report_id)))
+            .execute(conn)
+            .map_err(resource_conflict_err!(Report, report_id))?;
+
+        // If there are no more reports for this version, delete the version
+        // This is necessary because multiple reports can use the same version via a git hash
+        // This will cascade and delete all head versions for this version
+        // Before doing so, decrement all greater versions
+        // Otherwise, just return since the version is still in use
+        if schema::report::table
+            .filter(schema::report::version_id.eq(version_id))
+            .select(count(schema::report::id))
+            .first::<i64>(conn)
+            .map_err(resource_not_found_err!(Version, (report_id, version_id)))?
+            != 0
+        {
+            return Ok(());
+        }
+
+        let query_version = QueryVersion::get(conn, version_id)?;
+        // Get all heads that use this version
+        let heads = schema::head::table
+            .inner_join(
+                schema::head_version::table.on(schema::head_version::head_id.eq(schema::head::id)),
+            )
+            .filter(schema::head_version::version_id.eq(version_id))
+            .select(schema::head::id)
+            .load::<HeadId>(conn)
+            .map_err(resource_not_found_err!(Head, (report_id, version_id)))?;
+
+        let mut version_map = HashMap::new();
+        // Get all versions greater than this one for each of the heads
+        for head_id in heads {
+            schema::version::table
+                .inner_join(schema::head_version::table)
+                .filter(schema::version::number.gt(query_version.number))
+                .filter(schema::head_version::head_id.eq(head_id))
+                .select((schema::version::id, schema::version::number))
+                .load::<(VersionId, VersionNumber)>(conn)
+                .map_err(resource_not_found_err!(
+                    Version,
+                    (report_id, head_id, &query_version)
+                ))?
+                .into_iter()
+                .for_each(|(version_id, version_number)| {
+                    version_map.insert(version_id, version_number);
+                });
+        }
+
+        // For each version greater than this one, decrement the version number
+        for (version_id, version_number) in version_map {
+            if let Err(e) =
+                diesel::update(schema::version::table.filter(schema::version::id.eq(version_id)))
+                    .set(schema::version::number.eq(version_number.decrement()))
+                    .execute(conn)
+            {
+                debug_assert!(
+                    false,
+                    "Failed to decrement version ({version_id}) number ({version_number}): {e}"
+                );
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+            }
+        }
+
+        // Finally delete the dangling version
+        diesel::delete(schema::version::table.filter(schema::version::id.eq(version_id)))
+            .execute(conn)
+            .map_err(resource_conflict_err!(Version, (report_id, &query_version)))?;
+
+        Ok(())
+    }
+
+    /// Convert the report into JSON.
+    /// If `include_results` is `false`, then the results are left empty,
+    /// and they can be paginated separately with `get_report_results`.
     pub async fn into_json(
         self,
         log: &Logger,
         context: &ApiContext,
+        include_results: bool,
     ) -> Result<JsonReport, HttpError> {
         let Self {
             id,
@@ -76,6 +181,7 @@ impl QueryReport {
             head_id,
             version_id,
             testbed_id,
+            environment,
             adapter,
             start_time,
             end_time,
@@ -83,13 +189,27 @@ impl QueryReport {
         } = self;
 
         let query_project = QueryProject::get(conn_lock!(context), project_id)?;
-        let user = QueryUser::get(conn_lock!(context), user_id)?.into_pub_json();
+        let user = if let Some(user_id) = user_id {
+            Some(QueryUser::get(conn_lock!(context), user_id)?.into_pub_json())
+        } else {
+            None
+        };
         let branch =
             QueryBranch::get_json_for_report(context, &query_project, head_id, version_id).await?;
         let testbed = QueryTestbed::get(conn_lock!(context), testbed_id)?
             .into_json_for_project(&query_project);
-        let results = get_report_results(log, context, &query_project, id).await?;
+        let results = if include_results {
+            get_report_results(log, context, &query_project, id, None).await?
+        } else {
+            Vec::new()
+        };
         let alerts = get_report_alerts(context, &query_project, id, head_id, version_id).await?;
+        let attachments = QueryReportAttachment::list(conn_lock!(context), id)?
+            .into_iter()
+            .map(QueryReportAttachment::into_json)
+            .collect();
+        let failure = QueryReportFailure::from_report_id(conn_lock!(context), id)?
+            .map(QueryReportFailure::into_json);
 
         let project = query_project.into_json(conn_lock!(context))?;
         Ok(JsonReport {
@@ -98,14 +218,86 @@ impl QueryReport {
             project,
             branch,
             testbed,
+            environment,
             start_time,
             end_time,
             adapter,
+            adapter_selection: None,
             results,
             alerts,
+            attachments: Some(attachments),
+            failure,
             created,
         })
     }
+
+    /// Copy the report, along with all of its metrics, onto the given branch head and version.
+    /// Boundaries and alerts are not copied.
+    pub fn graft(
+        &self,
+        conn: &mut DbConnection,
+        head_id: HeadId,
+        version_id: VersionId,
+    ) -> Result<ReportId, HttpError> {
+        let insert_report = InsertReport {
+            uuid: ReportUuid::new(),
+            user_id: self.user_id,
+            project_id: self.project_id,
+            head_id,
+            version_id,
+            testbed_id: self.testbed_id,
+            environment: self.environment.clone(),
+            adapter: self.adapter,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            created: DateTime::now(),
+        };
+        diesel::insert_into(schema::report::table)
+            .values(&insert_report)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Report, insert_report))?;
+        let report_id = QueryReport::get_id(conn, insert_report.uuid)?;
+
+        let report_benchmarks = QueryReportBenchmark::belonging_to(self)
+            .load::<QueryReportBenchmark>(conn)
+            .map_err(resource_not_found_err!(ReportBenchmark, self.id))?;
+        for report_benchmark in report_benchmarks {
+            let insert_report_benchmark = InsertReportBenchmark::from_json(
+                report_id,
+                report_benchmark.iteration,
+                report_benchmark.benchmark_id,
+            );
+            diesel::insert_into(schema::report_benchmark::table)
+                .values(&insert_report_benchmark)
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    ReportBenchmark,
+                    insert_report_benchmark
+                ))?;
+            let report_benchmark_id =
+                QueryReportBenchmark::get_id(conn, insert_report_benchmark.uuid)?;
+
+            let metrics = QueryMetric::belonging_to(&report_benchmark)
+                .load::<QueryMetric>(conn)
+                .map_err(resource_not_found_err!(Metric, report_benchmark.id))?;
+            for metric in metrics {
+                let insert_metric = InsertMetric {
+                    uuid: MetricUuid::new(),
+                    report_benchmark_id,
+                    measure_id: metric.measure_id,
+                    value: metric.value,
+                    lower_value: metric.lower_value,
+                    upper_value: metric.upper_value,
+                };
+                diesel::insert_into(schema::metric::table)
+                    .values(&insert_metric)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(Metric, insert_metric))?;
+            }
+        }
+
+        Ok(report_id)
+    }
 }
 
 type ResultsQuery = (
@@ -116,21 +308,31 @@ type ResultsQuery = (
     Option<(QueryThreshold, QueryModel)>,
 );
 
-async fn get_report_results(
+/// Get the results for a report, grouped by iteration.
+/// If `report_benchmark_ids` is set, then only the results for those report benchmarks are included.
+pub async fn get_report_results(
     log: &Logger,
     context: &ApiContext,
     project: &QueryProject,
     report_id: ReportId,
+    report_benchmark_ids: Option<Vec<ReportBenchmarkId>>,
 ) -> Result<JsonReportResults, HttpError> {
-    schema::report_benchmark::table
-    .filter(schema::report_benchmark::report_id.eq(report_id))
-    .inner_join(schema::benchmark::table)
-    .inner_join(view::metric_boundary::table
-        .inner_join(schema::measure::table)
-        // There may or may not be a boundary for any given metric
-        .left_join(schema::threshold::table)
-        .left_join(schema::model::table)
-    )
+    let mut query = schema::report_benchmark::table
+        .filter(schema::report_benchmark::report_id.eq(report_id))
+        .inner_join(schema::benchmark::table)
+        .inner_join(
+            view::metric_boundary::table
+                .inner_join(schema::measure::table)
+                // There may or may not be a boundary for any given metric
+                .left_join(schema::threshold::table)
+                .left_join(schema::model::table),
+        )
+        .into_boxed();
+    if let Some(report_benchmark_ids) = report_benchmark_ids {
+        query = query.filter(schema::report_benchmark::id.eq_any(report_benchmark_ids));
+    }
+
+    query
     // It is important to order by the iteration first in order to make sure they are grouped together below
     // Then ordering by benchmark and finally measure name makes sure that the benchmarks are in the same order for each iteration
     .order((schema::report_benchmark::iteration, schema::benchmark::name, schema::measure::name))
@@ -144,9 +346,10 @@ async fn get_report_results(
                 schema::threshold::id,
                 schema::threshold::uuid,
                 schema::threshold::project_id,
-                schema::threshold::measure_id,
                 schema::threshold::branch_id,
                 schema::threshold::testbed_id,
+                schema::threshold::measure_id,
+                schema::threshold::benchmark,
                 schema::threshold::model_id,
                 schema::threshold::created,
                 schema::threshold::modified,
@@ -161,6 +364,8 @@ async fn get_report_results(
                 schema::model::window,
                 schema::model::lower_boundary,
                 schema::model::upper_boundary,
+                schema::model::lower_critical,
+                schema::model::upper_critical,
                 schema::model::created,
                 schema::model::replaced,
             )
@@ -323,11 +528,12 @@ async fn get_report_alerts(
 #[diesel(table_name = report_table)]
 pub struct InsertReport {
     pub uuid: ReportUuid,
-    pub user_id: UserId,
+    pub user_id: Option<UserId>,
     pub project_id: ProjectId,
     pub head_id: HeadId,
     pub version_id: VersionId,
     pub testbed_id: TestbedId,
+    pub environment: Option<ResourceName>,
     pub adapter: Adapter,
     pub start_time: DateTime,
     pub end_time: DateTime,
@@ -336,7 +542,7 @@ pub struct InsertReport {
 
 impl InsertReport {
     pub fn from_json(
-        user_id: UserId,
+        user_id: Option<UserId>,
         project_id: ProjectId,
         head_id: HeadId,
         version_id: VersionId,
@@ -351,6 +557,7 @@ impl InsertReport {
             head_id,
             version_id,
             testbed_id,
+            environment: report.environment.clone(),
             adapter,
             start_time: report.start_time,
             end_time: report.end_time,
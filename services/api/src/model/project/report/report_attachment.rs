@@ -0,0 +1,211 @@
+use bencher_json::{AttachmentUuid, DateTime, JsonAttachment};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{bad_request_error, issue_error, resource_not_found_err},
+    schema::report_attachment as report_attachment_table,
+};
+
+use super::{QueryReport, ReportId};
+
+crate::util::typed_id::typed_id!(ReportAttachmentId);
+
+/// The maximum size of a single attachment (8 MB)
+pub const MAX_ATTACHMENT_SIZE: usize = 8 << 20;
+/// The maximum number of attachments for a single report
+pub const MAX_ATTACHMENTS: i64 = 16;
+// The maximum length of an attachment file name
+const MAX_NAME_LEN: usize = 255;
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+#[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable)]
+#[diesel(table_name = report_attachment_table)]
+#[diesel(belongs_to(QueryReport, foreign_key = report_id))]
+pub struct QueryReportAttachment {
+    pub id: ReportAttachmentId,
+    pub uuid: AttachmentUuid,
+    pub report_id: ReportId,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub created: DateTime,
+}
+
+impl QueryReportAttachment {
+    pub fn list(conn: &mut DbConnection, report_id: ReportId) -> Result<Vec<Self>, HttpError> {
+        report_attachment_table::table
+            .filter(report_attachment_table::report_id.eq(report_id))
+            .order(report_attachment_table::name)
+            .select(Self::as_select())
+            .load(conn)
+            .map_err(resource_not_found_err!(ReportAttachment, report_id))
+    }
+
+    pub fn from_uuid(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        uuid: AttachmentUuid,
+    ) -> Result<Self, HttpError> {
+        report_attachment_table::table
+            .filter(report_attachment_table::report_id.eq(report_id))
+            .filter(report_attachment_table::uuid.eq(uuid.to_string()))
+            .select(Self::as_select())
+            .first(conn)
+            .map_err(resource_not_found_err!(ReportAttachment, (report_id, uuid)))
+    }
+
+    pub fn count(conn: &mut DbConnection, report_id: ReportId) -> Result<i64, HttpError> {
+        report_attachment_table::table
+            .filter(report_attachment_table::report_id.eq(report_id))
+            .count()
+            .get_result(conn)
+            .map_err(resource_not_found_err!(ReportAttachment, report_id))
+    }
+
+    /// Get the contents of the attachment,
+    /// either from the database or from the data store if it was stored there.
+    pub async fn data(&self, context: &ApiContext) -> Result<Vec<u8>, HttpError> {
+        let data = report_attachment_table::table
+            .filter(report_attachment_table::id.eq(self.id))
+            .select(report_attachment_table::data)
+            .first::<Option<Vec<u8>>>(conn_lock!(context))
+            .map_err(resource_not_found_err!(ReportAttachment, self.uuid))?;
+        if let Some(data) = data {
+            return Ok(data);
+        }
+        let data_store = context.database.data_store.as_ref().ok_or_else(|| {
+            issue_error(
+                http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to find data store for attachment",
+                &format!(
+                    "Failed to find data store for attachment ({uuid}) even though it is not stored in the database.",
+                    uuid = self.uuid
+                ),
+                "No data store configured",
+            )
+        })?;
+        data_store
+            .get(&data_store_key(self.uuid))
+            .await
+            .map_err(|e| {
+                issue_error(
+                    http::StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to get attachment from data store",
+                    &format!(
+                        "Failed to get attachment ({uuid}) from data store.",
+                        uuid = self.uuid
+                    ),
+                    e,
+                )
+            })
+    }
+
+    /// Whether the contents of the attachment are stored in the data store instead of the database
+    pub fn is_external(&self, conn: &mut DbConnection) -> Result<bool, HttpError> {
+        report_attachment_table::table
+            .filter(report_attachment_table::id.eq(self.id))
+            .select(report_attachment_table::data.is_null())
+            .first(conn)
+            .map_err(resource_not_found_err!(ReportAttachment, self.uuid))
+    }
+
+    /// The data store keys for all of the attachments for a report that are stored in the data store
+    pub fn external_keys(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+    ) -> Result<Vec<String>, HttpError> {
+        report_attachment_table::table
+            .filter(report_attachment_table::report_id.eq(report_id))
+            .filter(report_attachment_table::data.is_null())
+            .select(report_attachment_table::uuid)
+            .load::<AttachmentUuid>(conn)
+            .map(|uuids| uuids.into_iter().map(data_store_key).collect())
+            .map_err(resource_not_found_err!(ReportAttachment, report_id))
+    }
+
+    pub fn data_store_key(&self) -> String {
+        data_store_key(self.uuid)
+    }
+
+    pub fn into_json(self) -> JsonAttachment {
+        let Self {
+            uuid,
+            name,
+            content_type,
+            size,
+            created,
+            ..
+        } = self;
+        JsonAttachment {
+            uuid,
+            name,
+            content_type,
+            size: u64::try_from(size).unwrap_or_default(),
+            created,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = report_attachment_table)]
+pub struct InsertReportAttachment {
+    pub uuid: AttachmentUuid,
+    pub report_id: ReportId,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub data: Option<Vec<u8>>,
+    pub created: DateTime,
+}
+
+impl InsertReportAttachment {
+    pub fn new(
+        report_id: ReportId,
+        name: String,
+        content_type: Option<String>,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            uuid: AttachmentUuid::new(),
+            report_id,
+            name,
+            content_type: content_type.unwrap_or_else(|| DEFAULT_CONTENT_TYPE.to_owned()),
+            size: i64::try_from(data.len()).unwrap_or(i64::MAX),
+            data: Some(data),
+            created: DateTime::now(),
+        }
+    }
+
+    pub fn data_store_key(&self) -> String {
+        data_store_key(self.uuid)
+    }
+}
+
+fn data_store_key(uuid: AttachmentUuid) -> String {
+    format!("attachments/{uuid}")
+}
+
+/// Validate the file name of an attachment.
+/// Any leading directories are removed,
+/// and the name must be safe to use in a `Content-Disposition` header.
+pub fn attachment_name(file_name: &str) -> Result<String, HttpError> {
+    let name = file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    if name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.len() > MAX_NAME_LEN
+        || name.chars().any(|c| c.is_control() || c == '"')
+    {
+        return Err(bad_request_error(format!(
+            "Invalid attachment file name: {file_name}"
+        )));
+    }
+    Ok(name.to_owned())
+}
@@ -0,0 +1,84 @@
+use bencher_json::JsonReportFailure;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::report_failure as report_failure_table,
+};
+
+use super::{QueryReport, ReportId};
+
+crate::util::typed_id::typed_id!(ReportFailureId);
+
+// The maximum size of the stderr that will be stored (64 KB)
+const MAX_STDERR_SIZE: usize = 1 << 16;
+
+#[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = report_failure_table)]
+#[diesel(belongs_to(QueryReport, foreign_key = report_id))]
+pub struct QueryReportFailure {
+    pub id: ReportFailureId,
+    pub report_id: ReportId,
+    pub exit_code: i32,
+    pub stderr: String,
+}
+
+impl QueryReportFailure {
+    pub fn from_report_id(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+    ) -> Result<Option<Self>, HttpError> {
+        report_failure_table::table
+            .filter(report_failure_table::report_id.eq(report_id))
+            .first(conn)
+            .optional()
+            .map_err(resource_not_found_err!(ReportFailure, report_id))
+    }
+
+    pub fn into_json(self) -> JsonReportFailure {
+        let Self {
+            exit_code, stderr, ..
+        } = self;
+        JsonReportFailure { exit_code, stderr }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = report_failure_table)]
+pub struct InsertReportFailure {
+    pub report_id: ReportId,
+    pub exit_code: i32,
+    pub stderr: String,
+}
+
+impl InsertReportFailure {
+    pub fn from_json(report_id: ReportId, failure: JsonReportFailure) -> Self {
+        let JsonReportFailure {
+            exit_code,
+            mut stderr,
+        } = failure;
+        // Keep the end of the stderr, as that is usually where the error is
+        if stderr.len() > MAX_STDERR_SIZE {
+            let mut start = stderr.len() - MAX_STDERR_SIZE;
+            while !stderr.is_char_boundary(start) {
+                start += 1;
+            }
+            stderr.drain(..start);
+        }
+        Self {
+            report_id,
+            exit_code,
+            stderr,
+        }
+    }
+
+    pub fn insert(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::insert_into(report_failure_table::table)
+            .values(self)
+            .execute(conn)
+            .map_err(resource_conflict_err!(ReportFailure, self.report_id))?;
+        Ok(())
+    }
+}
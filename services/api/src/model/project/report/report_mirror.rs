@@ -0,0 +1,91 @@
+#![cfg(feature = "plus")]
+
+use bencher_json::DateTime;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::report_mirror as report_mirror_table,
+};
+
+use super::{QueryReport, ReportId};
+
+crate::util::typed_id::typed_id!(ReportMirrorId);
+
+/// A report that is queued to be mirrored to another Bencher instance
+#[derive(Debug, diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = report_mirror_table)]
+#[diesel(belongs_to(QueryReport, foreign_key = report_id))]
+pub struct QueryReportMirror {
+    pub id: ReportMirrorId,
+    pub report_id: ReportId,
+    /// The slug or UUID of the project on the mirror
+    pub project: String,
+    /// The new report JSON to send to the mirror
+    pub report: String,
+    pub attempts: i32,
+    pub next_attempt: DateTime,
+    pub created: DateTime,
+}
+
+impl QueryReportMirror {
+    /// Get the queued reports that are due to be mirrored, oldest first
+    pub fn due(conn: &mut DbConnection, now: DateTime, limit: i64) -> Result<Vec<Self>, HttpError> {
+        report_mirror_table::table
+            .filter(report_mirror_table::next_attempt.le(now))
+            .order(report_mirror_table::id)
+            .limit(limit)
+            .load(conn)
+            .map_err(resource_not_found_err!(ReportMirror, now))
+    }
+
+    pub fn retry(
+        &self,
+        conn: &mut DbConnection,
+        attempts: i32,
+        next_attempt: DateTime,
+    ) -> Result<(), HttpError> {
+        diesel::update(report_mirror_table::table.filter(report_mirror_table::id.eq(self.id)))
+            .set((
+                report_mirror_table::attempts.eq(attempts),
+                report_mirror_table::next_attempt.eq(next_attempt),
+            ))
+            .execute(conn)
+            .map(|_| ())
+            .map_err(resource_conflict_err!(ReportMirror, self.id))
+    }
+
+    pub fn delete(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::delete(report_mirror_table::table.filter(report_mirror_table::id.eq(self.id)))
+            .execute(conn)
+            .map(|_| ())
+            .map_err(resource_conflict_err!(ReportMirror, self.id))
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = report_mirror_table)]
+pub struct InsertReportMirror {
+    pub report_id: ReportId,
+    pub project: String,
+    pub report: String,
+    pub attempts: i32,
+    pub next_attempt: DateTime,
+    pub created: DateTime,
+}
+
+impl InsertReportMirror {
+    pub fn new(report_id: ReportId, project: String, report: String) -> Self {
+        let now = DateTime::now();
+        Self {
+            report_id,
+            project,
+            report,
+            attempts: 0,
+            next_attempt: now,
+            created: now,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use async_compression::tokio::write::{GzipDecoder, GzipEncoder};
+use bencher_json::{JsonReportRaw, ReportUuid};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use tokio::io::AsyncWriteExt;
+
+use crate::{
+    context::DbConnection,
+    error::{issue_error, resource_not_found_err},
+    schema::report_raw as report_raw_table,
+};
+
+use super::{QueryReport, ReportId};
+
+crate::util::typed_id::typed_id!(ReportRawId);
+
+// The maximum size of the compressed raw results that will be stored (1 MB)
+const MAX_RAW_RESULTS_SIZE: usize = 1 << 20;
+
+#[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = report_raw_table)]
+#[diesel(belongs_to(QueryReport, foreign_key = report_id))]
+pub struct QueryReportRaw {
+    pub id: ReportRawId,
+    pub report_id: ReportId,
+    pub results: Vec<u8>,
+}
+
+impl QueryReportRaw {
+    pub fn from_report_id(conn: &mut DbConnection, report_id: ReportId) -> Result<Self, HttpError> {
+        report_raw_table::table
+            .filter(report_raw_table::report_id.eq(report_id))
+            .first(conn)
+            .map_err(resource_not_found_err!(ReportRaw, report_id))
+    }
+
+    pub async fn into_json(self, report_uuid: ReportUuid) -> Result<JsonReportRaw, HttpError> {
+        let mut decoder = GzipDecoder::new(Vec::new());
+        let written = async {
+            decoder.write_all(&self.results).await?;
+            decoder.shutdown().await
+        }
+        .await;
+        let results = written
+            .map_err(|e| e.to_string())
+            .and_then(|()| serde_json::from_slice(&decoder.into_inner()).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                issue_error(
+                    http::StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to decompress raw report results",
+                    &format!("Failed to decompress raw results for report ({report_uuid})"),
+                    e,
+                )
+            })?;
+        Ok(JsonReportRaw {
+            report: report_uuid,
+            results,
+        })
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = report_raw_table)]
+pub struct InsertReportRaw {
+    pub report_id: ReportId,
+    pub results: Vec<u8>,
+}
+
+impl InsertReportRaw {
+    /// Compress the raw results for a report.
+    /// If the compressed results are too large to store, then `None` is returned.
+    pub async fn from_results(
+        report_id: ReportId,
+        results: &[String],
+    ) -> Result<Option<Self>, std::io::Error> {
+        let json = serde_json::to_vec(results)?;
+        let mut encoder = GzipEncoder::new(Vec::new());
+        encoder.write_all(&json).await?;
+        encoder.shutdown().await?;
+        let results = encoder.into_inner();
+        Ok((results.len() <= MAX_RAW_RESULTS_SIZE).then_some(Self { report_id, results }))
+    }
+}
@@ -1,4 +1,5 @@
 use bencher_boundary::MetricsData;
+use bencher_json::{project::head::VersionNumber, ResourceName};
 use chrono::offset::Utc;
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -15,13 +16,16 @@ use crate::{
 
 use super::threshold::ThresholdModel;
 
+#[allow(clippy::too_many_arguments)]
 pub fn metrics_data(
     log: &Logger,
     conn: &mut DbConnection,
     head_id: HeadId,
     testbed_id: TestbedId,
+    environment: Option<&ResourceName>,
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
+    base_version: Option<VersionNumber>,
     model: &ThresholdModel,
 ) -> Result<MetricsData, HttpError> {
     let mut query = schema::metric::table
@@ -47,6 +51,19 @@ pub fn metrics_data(
         .filter(schema::metric::measure_id.eq(measure_id))
         .into_boxed();
 
+    // Only include metrics from the same environment as the report,
+    // so that different deployments (ie staging and production) do not share a baseline.
+    if let Some(environment) = environment {
+        query = query.filter(schema::report::environment.eq(environment));
+    } else {
+        query = query.filter(schema::report::environment.is_null());
+    }
+
+    // Only include metrics up to and including the base version
+    if let Some(base_version) = base_version {
+        query = query.filter(schema::version::number.le(base_version));
+    }
+
     if let Some(window) = model.window {
         let now = Utc::now().timestamp();
         if let Some(start_time) = now.checked_sub(window.into()) {
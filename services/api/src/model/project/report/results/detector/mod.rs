@@ -1,5 +1,8 @@
-use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
+use bencher_boundary::{MetricsBoundary, MetricsData};
+use bencher_json::{
+    project::{alert::AlertSeverity, boundary::BoundaryLimit, head::VersionNumber},
+    BenchmarkName, BoundaryUuid, ResourceName,
+};
 use diesel::RunQueryDsl;
 use dropshot::HttpError;
 use slog::Logger;
@@ -23,14 +26,16 @@ pub mod data;
 pub mod threshold;
 
 use data::metrics_data;
-use threshold::Threshold;
+use threshold::{Threshold, ThresholdModel};
 
 #[derive(Debug, Clone)]
 pub struct Detector {
     pub head_id: HeadId,
     pub testbed_id: TestbedId,
+    pub environment: Option<ResourceName>,
     pub measure_id: MeasureId,
-    pub threshold: Threshold,
+    pub base_version: Option<VersionNumber>,
+    pub thresholds: Vec<Threshold>,
 }
 
 impl Detector {
@@ -39,15 +44,20 @@ impl Detector {
         branch_id: BranchId,
         head_id: HeadId,
         testbed_id: TestbedId,
+        environment: Option<ResourceName>,
         measure_id: MeasureId,
+        base_version: Option<VersionNumber>,
     ) -> Option<Self> {
-        // Check to see if there is a threshold for the branch/testbed/measure grouping.
+        // Check to see if there are any thresholds for the branch/testbed/measure grouping.
         // If not, then there will be nothing to detect.
-        Threshold::new(conn, branch_id, testbed_id, measure_id).map(|threshold| Self {
+        let thresholds = Threshold::all(conn, branch_id, testbed_id, measure_id);
+        (!thresholds.is_empty()).then_some(Self {
             head_id,
             testbed_id,
+            environment,
             measure_id,
-            threshold,
+            base_version,
+            thresholds,
         })
     }
 
@@ -56,18 +66,27 @@ impl Detector {
         log: &Logger,
         context: &ApiContext,
         benchmark_id: BenchmarkId,
+        benchmark_name: &BenchmarkName,
         query_metric: &QueryMetric,
         ignore_benchmark: bool,
     ) -> Result<(), HttpError> {
+        // Use the most specific threshold for the benchmark.
+        // If there is none, then there is nothing to detect for this benchmark.
+        let Some(threshold) = Threshold::for_benchmark(&self.thresholds, benchmark_name) else {
+            return Ok(());
+        };
+
         // Query the historical population/sample data for the benchmark
         let metrics_data = metrics_data(
             log,
             conn_lock!(context),
             self.head_id,
             self.testbed_id,
+            self.environment.as_ref(),
             benchmark_id,
             self.measure_id,
-            &self.threshold.model,
+            self.base_version,
+            &threshold.model,
         )?;
 
         // Check to see if the metric has a boundary check for the given threshold model.
@@ -75,18 +94,18 @@ impl Detector {
             log,
             query_metric.value,
             &metrics_data,
-            self.threshold.model.test,
-            self.threshold.model.min_sample_size,
-            self.threshold.model.lower_boundary,
-            self.threshold.model.upper_boundary,
+            threshold.model.test,
+            threshold.model.min_sample_size,
+            threshold.model.lower_boundary,
+            threshold.model.upper_boundary,
         )
         .map_err(bad_request_error)?;
 
         let boundary_uuid = BoundaryUuid::new();
         let insert_boundary = InsertBoundary {
             uuid: boundary_uuid,
-            threshold_id: self.threshold.id,
-            model_id: self.threshold.model.id,
+            threshold_id: threshold.id,
+            model_id: threshold.model.id,
             metric_id: query_metric.id,
             baseline: boundary.limits.baseline,
             lower_limit: boundary.limits.lower.map(Into::into),
@@ -103,9 +122,59 @@ impl Detector {
         if ignore_benchmark {
             Ok(())
         } else if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
+            // Alerts from a change-point detection model are drift alerts.
+            let kind = threshold.model.test.into();
+            let severity = Self::severity(
+                log,
+                &threshold.model,
+                query_metric,
+                &metrics_data,
+                boundary_limit,
+            )?;
+            InsertAlert::from_boundary(
+                conn_lock!(context),
+                boundary_uuid,
+                boundary_limit,
+                kind,
+                severity,
+            )
         } else {
             Ok(())
         }
     }
+
+    // If the threshold model has a critical boundary on the side of the outlier,
+    // then only an outlier beyond the critical boundary limit is critical.
+    // Otherwise, all outliers are critical.
+    fn severity(
+        log: &Logger,
+        model: &ThresholdModel,
+        query_metric: &QueryMetric,
+        metrics_data: &MetricsData,
+        boundary_limit: BoundaryLimit,
+    ) -> Result<AlertSeverity, HttpError> {
+        let (lower_critical, upper_critical) = match boundary_limit {
+            BoundaryLimit::Lower => (model.lower_critical, None),
+            BoundaryLimit::Upper => (None, model.upper_critical),
+        };
+        if lower_critical.is_none() && upper_critical.is_none() {
+            return Ok(AlertSeverity::Critical);
+        }
+
+        let critical = MetricsBoundary::new(
+            log,
+            query_metric.value,
+            metrics_data,
+            model.test,
+            model.min_sample_size,
+            lower_critical,
+            upper_critical,
+        )
+        .map_err(bad_request_error)?;
+        Ok(if critical.outlier == Some(boundary_limit) {
+            AlertSeverity::Critical
+        } else {
+            AlertSeverity::Warning
+        })
+    }
 }
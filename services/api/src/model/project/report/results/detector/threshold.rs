@@ -1,4 +1,4 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use bencher_json::{BenchmarkGlob, BenchmarkName, Boundary, ModelTest, SampleSize, Window};
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
@@ -21,6 +21,7 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Threshold {
     pub id: ThresholdId,
+    pub benchmark: Option<BenchmarkGlob>,
     pub model: ThresholdModel,
 }
 
@@ -33,15 +34,19 @@ pub struct ThresholdModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
 }
 
 impl Threshold {
-    pub fn new(
+    /// Get all thresholds with a model for the branch/testbed/measure grouping,
+    /// both for all benchmarks and for specific benchmarks.
+    pub fn all(
         conn: &mut DbConnection,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
-    ) -> Option<Self> {
+    ) -> Vec<Self> {
         schema::model::table
             .inner_join(
                 schema::threshold::table
@@ -50,9 +55,15 @@ impl Threshold {
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
             .filter(schema::threshold::measure_id.eq(measure_id))
-            .select((schema::threshold::id, QueryModel::as_select()))
-            .first::<(ThresholdId, QueryModel)>(conn)
-            .map(|(threshold_id, query_model)| {
+            .select((
+                schema::threshold::id,
+                schema::threshold::benchmark,
+                QueryModel::as_select(),
+            ))
+            .load::<(ThresholdId, Option<BenchmarkGlob>, QueryModel)>(conn)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(threshold_id, benchmark, query_model)| {
                 let QueryModel {
                     id,
                     test,
@@ -61,6 +72,8 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    lower_critical,
+                    upper_critical,
                     ..
                 } = query_model;
                 let model = ThresholdModel {
@@ -71,12 +84,38 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    lower_critical,
+                    upper_critical,
                 };
                 Self {
                     id: threshold_id,
+                    benchmark,
                     model,
                 }
             })
-            .ok()
+            .collect()
+    }
+
+    /// Select the most specific threshold for the benchmark.
+    /// An exact benchmark name match comes first,
+    /// then the matching glob with the most literal characters,
+    /// and finally the threshold for all benchmarks.
+    pub fn for_benchmark<'t>(
+        thresholds: &'t [Self],
+        benchmark_name: &BenchmarkName,
+    ) -> Option<&'t Self> {
+        thresholds
+            .iter()
+            .filter_map(|threshold| match &threshold.benchmark {
+                Some(benchmark) => benchmark.is_match(benchmark_name).then(|| {
+                    (
+                        Some((benchmark.is_exact(), benchmark.specificity())),
+                        threshold,
+                    )
+                }),
+                None => Some((None, threshold)),
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, threshold)| threshold)
     }
 }
@@ -5,18 +5,22 @@ use bencher_adapter::{
     Settings as AdapterSettings,
 };
 use bencher_json::{
-    project::report::{Adapter, Iteration, JsonReportSettings},
-    BenchmarkName, MeasureNameId,
+    project::{
+        head::VersionNumber,
+        measure::built_in::{self, BuiltInMeasure},
+        report::{Adapter, Iteration, JsonAdapterSelection, JsonBenchmarkPair, JsonReportSettings},
+    },
+    BenchmarkName, DateTime, GitHash, JsonNewMetric, JsonResultsMap, MeasureNameId, ResourceName,
 };
-use diesel::RunQueryDsl;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 use http::StatusCode;
 use slog::Logger;
 
 use crate::{
     conn_lock,
-    context::ApiContext,
-    error::{bad_request_error, issue_error, resource_conflict_err},
+    context::{in_span, ApiContext, DbConnection},
+    error::{bad_request_error, issue_error, resource_conflict_err, resource_not_found_err},
     model::project::{
         benchmark::{BenchmarkId, QueryBenchmark},
         branch::{head::HeadId, BranchId},
@@ -24,6 +28,7 @@ use crate::{
         metric::{InsertMetric, QueryMetric},
         report::report_benchmark::{InsertReportBenchmark, QueryReportBenchmark},
         testbed::TestbedId,
+        threshold::template::QueryThresholdTemplate,
         ProjectId,
     },
     schema,
@@ -41,7 +46,9 @@ pub struct ReportResults {
     pub branch_id: BranchId,
     pub head_id: HeadId,
     pub testbed_id: TestbedId,
+    pub environment: Option<ResourceName>,
     pub report_id: ReportId,
+    pub base_version: Option<VersionNumber>,
     pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
     pub measure_cache: HashMap<MeasureNameId, MeasureId>,
     pub detector_cache: HashMap<MeasureId, Option<Detector>>,
@@ -53,6 +60,7 @@ impl ReportResults {
         branch_id: BranchId,
         head_id: HeadId,
         testbed_id: TestbedId,
+        environment: Option<ResourceName>,
         report_id: ReportId,
     ) -> Self {
         Self {
@@ -60,32 +68,66 @@ impl ReportResults {
             branch_id,
             head_id,
             testbed_id,
+            environment,
             report_id,
+            base_version: None,
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
             detector_cache: HashMap::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn process(
         &mut self,
         log: &Logger,
         context: &ApiContext,
         results_array: &[&str],
+        runner_results: Option<Vec<JsonResultsMap>>,
         adapter: Adapter,
         settings: JsonReportSettings,
+        start_time: DateTime,
+        end_time: DateTime,
         #[cfg(feature = "plus")] usage: &mut u32,
-    ) -> Result<(), HttpError> {
+    ) -> Result<Option<JsonAdapterSelection>, HttpError> {
         let adapter_settings = AdapterSettings::new(settings.average);
-        let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
+        let mut results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
             .map_err(|e| {
                 bad_request_error(format!(
                     "Failed to convert results with adapter ({adapter} | {settings:?}): {e}\n\nAre you sure {adapter} is the right adapter?\nRead more about adapters here: https://bencher.dev/docs/explanation/adapters/"
                 ))
             })?;
+        // Group the results before merging in the runner results, which are per iteration
+        if let Some(results_per_iteration) = settings.results_per_iteration {
+            results_array
+                .group(results_per_iteration as usize)
+                .map_err(bad_request_error)?;
+        }
+        if let Some(runner_results) = runner_results {
+            results_array
+                .merge(runner_results)
+                .map_err(bad_request_error)?;
+        }
+        let adapter_selection = results_array.selection.take();
+
+        // Compare against the metrics as of the base commit, if one was given
+        if let Some(base_hash) = settings.base_hash.as_ref() {
+            self.base_version = self.base_version(log, context, base_hash).await?;
+        }
 
+        let pairs = settings.pairs.unwrap_or_default();
+        // The report duration is only added to the first iteration
+        let mut duration_results = settings
+            .duration
+            .unwrap_or_default()
+            .then(|| duration_results(start_time, end_time))
+            .transpose()?;
         if let Some(fold) = settings.fold {
-            let results = results_array.fold(fold);
+            let mut results = results_array.fold(fold);
+            pair_results(&mut results, &pairs)?;
+            if let Some(duration_results) = duration_results {
+                results.merge(duration_results);
+            }
             self.results(
                 log,
                 context,
@@ -96,7 +138,11 @@ impl ReportResults {
             )
             .await?;
         } else {
-            for (iteration, results) in results_array.inner.into_iter().enumerate() {
+            for (iteration, mut results) in results_array.inner.into_iter().enumerate() {
+                pair_results(&mut results, &pairs)?;
+                if let Some(duration_results) = duration_results.take() {
+                    results.merge(duration_results);
+                }
                 self.results(
                     log,
                     context,
@@ -109,7 +155,34 @@ impl ReportResults {
             }
         };
 
-        Ok(())
+        Ok(adapter_selection)
+    }
+
+    // Find the most recent version on the branch head with the base hash.
+    // This includes versions cloned from the branch start point.
+    async fn base_version(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        base_hash: &GitHash,
+    ) -> Result<Option<VersionNumber>, HttpError> {
+        let base_version = schema::version::table
+            .inner_join(schema::head_version::table)
+            .filter(schema::head_version::head_id.eq(self.head_id))
+            .filter(schema::version::hash.eq(base_hash.as_ref()))
+            .order(schema::version::number.desc())
+            .select(schema::version::number)
+            .first::<VersionNumber>(conn_lock!(context))
+            .optional()
+            .map_err(resource_not_found_err!(Version, (self.head_id, base_hash)))?;
+        if base_version.is_none() {
+            slog::warn!(
+                log,
+                "Base hash ({base_hash}) not found for branch head ({}), comparing against the entire branch head instead",
+                self.head_id
+            );
+        }
+        Ok(base_version)
     }
 
     async fn results(
@@ -121,14 +194,17 @@ impl ReportResults {
         #[cfg(feature = "plus")] usage: &mut u32,
     ) -> Result<(), HttpError> {
         for (benchmark_name, metrics) in results.inner {
-            self.metrics(
-                log,
-                context,
-                iteration,
-                &benchmark_name,
-                metrics,
-                #[cfg(feature = "plus")]
-                usage,
+            in_span(
+                "report.benchmark",
+                self.metrics(
+                    log,
+                    context,
+                    iteration,
+                    &benchmark_name,
+                    metrics,
+                    #[cfg(feature = "plus")]
+                    usage,
+                ),
             )
             .await?;
         }
@@ -146,7 +222,7 @@ impl ReportResults {
     ) -> Result<(), HttpError> {
         // If benchmark name is ignored then strip the special suffix before querying
         let (benchmark_name, ignore_benchmark) = benchmark_name.to_strip_ignore();
-        let benchmark_id = self.benchmark_id(context, benchmark_name).await?;
+        let benchmark_id = self.benchmark_id(context, benchmark_name.clone()).await?;
 
         let insert_report_benchmark =
             InsertReportBenchmark::from_json(self.report_id, iteration, benchmark_id);
@@ -175,7 +251,7 @@ impl ReportResults {
                 *usage += 1;
             }
 
-            let Some(detector) = self.detector(context, measure_id).await else {
+            let Some(detector) = self.detector(context, measure_id).await? else {
                 continue;
             };
             let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
@@ -186,9 +262,18 @@ impl ReportResults {
                         e,
                     )
                 })?;
-            detector
-                .detect(log, context, benchmark_id, &query_metric, ignore_benchmark)
-                .await?;
+            in_span(
+                "threshold.detect",
+                detector.detect(
+                    log,
+                    context,
+                    benchmark_id,
+                    &benchmark_name,
+                    &query_metric,
+                    ignore_benchmark,
+                ),
+            )
+            .await?;
         }
 
         Ok(())
@@ -227,19 +312,82 @@ impl ReportResults {
         })
     }
 
-    async fn detector(&mut self, context: &ApiContext, measure_id: MeasureId) -> Option<Detector> {
+    async fn detector(
+        &mut self,
+        context: &ApiContext,
+        measure_id: MeasureId,
+    ) -> Result<Option<Detector>, HttpError> {
         if let Some(detector) = self.detector_cache.get(&measure_id) {
-            detector.clone()
-        } else {
-            let detector = Detector::new(
-                conn_lock!(context),
+            return Ok(detector.clone());
+        }
+
+        let new_detector = |conn: &mut DbConnection| {
+            Detector::new(
+                conn,
                 self.branch_id,
                 self.head_id,
                 self.testbed_id,
+                self.environment.clone(),
                 measure_id,
-            );
-            self.detector_cache.insert(measure_id, detector.clone());
-            detector
+                self.base_version,
+            )
+        };
+        let mut detector = new_detector(conn_lock!(context));
+        // If there is no threshold yet, then try to create one from a threshold template.
+        // A threshold that exists without a model is left as is and not recreated.
+        if detector.is_none()
+            && QueryThresholdTemplate::create_threshold(
+                conn_lock!(context),
+                self.project_id,
+                self.branch_id,
+                self.testbed_id,
+                measure_id,
+            )?
+            .is_some()
+        {
+            detector = new_detector(conn_lock!(context));
         }
+        self.detector_cache.insert(measure_id, detector.clone());
+        Ok(detector)
     }
 }
+
+fn pair_results(
+    results: &mut AdapterResults,
+    pairs: &[JsonBenchmarkPair],
+) -> Result<(), HttpError> {
+    results.pair(pairs).map_err(|e| {
+        bad_request_error(format!(
+            "Failed to compare benchmark pairs ({pairs:?}): {e}"
+        ))
+    })
+}
+
+fn duration_results(start_time: DateTime, end_time: DateTime) -> Result<JsonResultsMap, HttpError> {
+    let duration = (end_time.into_inner() - start_time.into_inner())
+        .to_std()
+        .map_err(|e| {
+            bad_request_error(format!(
+                "Failed to calculate report duration, the end time ({end_time}) is before the start time ({start_time}): {e}"
+            ))
+        })?;
+    let benchmark_name = built_in::report::BENCHMARK_NAME.parse().map_err(|e| {
+        issue_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to parse report duration benchmark name",
+            &format!(
+                "Failed to parse report duration benchmark name ({})",
+                built_in::report::BENCHMARK_NAME
+            ),
+            e,
+        )
+    })?;
+    let metric = JsonNewMetric {
+        value: duration.as_secs_f64().into(),
+        ..Default::default()
+    };
+    Ok(JsonResultsMap::from([(
+        benchmark_name,
+        [(built_in::report::Duration::name_id(), metric)].into(),
+    )]))
+}
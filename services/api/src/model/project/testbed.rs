@@ -1,6 +1,6 @@
 use bencher_json::{
     project::testbed::JsonUpdateTestbed, DateTime, JsonNewTestbed, JsonTestbed, NameId, NameIdKind,
-    ResourceName, Slug, TestbedUuid,
+    ResourceName, ScaleFactor, Slug, TestbedUuid,
 };
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -13,9 +13,9 @@ use crate::{
     schema::{self, testbed as testbed_table},
     util::{
         fn_get::{fn_from_uuid, fn_get, fn_get_id, fn_get_uuid},
-        name_id::{fn_eq_name_id, fn_from_name_id},
+        name_id::{fn_eq_name_id, fn_from_name_id, fn_from_name_or_slug},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
@@ -32,6 +32,7 @@ pub struct QueryTestbed {
     pub project_id: ProjectId,
     pub name: ResourceName,
     pub slug: Slug,
+    pub scale: ScaleFactor,
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
@@ -43,6 +44,7 @@ impl QueryTestbed {
 
     fn_eq_name_id!(ResourceName, testbed);
     fn_from_name_id!(testbed, Testbed);
+    fn_from_name_or_slug!(testbed, Testbed);
 
     fn_get!(testbed, TestbedId);
     fn_get_id!(testbed, TestbedId, TestbedUuid);
@@ -87,13 +89,19 @@ impl QueryTestbed {
             NameIdKind::Slug(slug) => JsonNewTestbed {
                 name: slug.clone().into(),
                 slug: Some(slug),
+                scale: None,
+                upsert: None,
+            },
+            NameIdKind::Name(name) => JsonNewTestbed {
+                name,
+                slug: None,
+                scale: None,
+                upsert: None,
             },
-            NameIdKind::Name(name) => JsonNewTestbed { name, slug: None },
         };
-        let insert_testbed = InsertTestbed::from_json(conn_lock!(context), project_id, testbed)?;
-        diesel::insert_into(schema::testbed::table)
-            .values(&insert_testbed)
-            .execute(conn_lock!(context))
+        let mut insert_testbed =
+            InsertTestbed::from_json(conn_lock!(context), project_id, testbed)?;
+        insert_with_slug!(conn_lock!(context), testbed, insert_testbed)
             .map_err(resource_conflict_err!(Testbed, insert_testbed))?;
 
         Self::from_uuid(conn_lock!(context), project_id, insert_testbed.uuid)
@@ -105,6 +113,7 @@ impl QueryTestbed {
             project_id,
             name,
             slug,
+            scale,
             created,
             modified,
             archived,
@@ -121,6 +130,7 @@ impl QueryTestbed {
             project: project.uuid,
             name,
             slug,
+            scale,
             created,
             modified,
             archived,
@@ -135,6 +145,7 @@ pub struct InsertTestbed {
     pub project_id: ProjectId,
     pub name: ResourceName,
     pub slug: Slug,
+    pub scale: ScaleFactor,
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
@@ -146,14 +157,25 @@ impl InsertTestbed {
         project_id: ProjectId,
         testbed: JsonNewTestbed,
     ) -> Result<Self, HttpError> {
-        let JsonNewTestbed { name, slug } = testbed;
-        let slug = ok_slug!(conn, project_id, &name, slug, testbed, QueryTestbed)?;
+        let JsonNewTestbed {
+            name,
+            slug,
+            scale,
+            upsert,
+        } = testbed;
+        // An upsert always uses the preferred slug
+        let slug = if upsert.unwrap_or_default() {
+            Slug::unwrap_or_new(&name, slug)
+        } else {
+            ok_slug!(conn, project_id, &name, slug, testbed, QueryTestbed)?
+        };
         let timestamp = DateTime::now();
         Ok(Self {
             uuid: TestbedUuid::new(),
             project_id,
             name,
             slug,
+            scale: scale.unwrap_or_default(),
             created: timestamp,
             modified: timestamp,
             archived: None,
@@ -170,6 +192,7 @@ impl InsertTestbed {
 pub struct UpdateTestbed {
     pub name: Option<ResourceName>,
     pub slug: Option<Slug>,
+    pub scale: Option<ScaleFactor>,
     pub modified: DateTime,
     pub archived: Option<Option<DateTime>>,
 }
@@ -179,6 +202,7 @@ impl From<JsonUpdateTestbed> for UpdateTestbed {
         let JsonUpdateTestbed {
             name,
             slug,
+            scale,
             archived,
         } = update;
         let modified = DateTime::now();
@@ -186,6 +210,7 @@ impl From<JsonUpdateTestbed> for UpdateTestbed {
         Self {
             name,
             slug,
+            scale,
             modified,
             archived,
         }
@@ -197,6 +222,7 @@ impl UpdateTestbed {
         JsonUpdateTestbed {
             name: None,
             slug: None,
+            scale: None,
             archived: Some(false),
         }
         .into()
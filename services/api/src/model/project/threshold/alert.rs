@@ -1,6 +1,6 @@
 use bencher_json::{
     project::{
-        alert::{AlertStatus, JsonAlert, JsonPerfAlert, JsonUpdateAlert},
+        alert::{AlertKind, AlertSeverity, AlertStatus, JsonAlert, JsonPerfAlert, JsonUpdateAlert},
         boundary::BoundaryLimit,
         report::Iteration,
     },
@@ -36,6 +36,8 @@ pub struct QueryAlert {
     pub uuid: AlertUuid,
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
     pub status: AlertStatus,
     pub modified: DateTime,
 }
@@ -63,7 +65,10 @@ impl QueryAlert {
             .map_err(resource_not_found_err!(Alert, (project_id, uuid)))
     }
 
-    pub async fn silence_all(context: &ApiContext, head_id: HeadId) -> Result<usize, HttpError> {
+    pub async fn silence_all(
+        context: &ApiContext,
+        head_id: HeadId,
+    ) -> Result<Vec<AlertId>, HttpError> {
         let alerts =
             schema::alert::table
                 .inner_join(schema::boundary::table.inner_join(
@@ -84,7 +89,7 @@ impl QueryAlert {
                 .map_err(resource_conflict_err!(Alert, (alert_id, &silenced_alert)))?;
         }
 
-        Ok(alerts.len())
+        Ok(alerts)
     }
 
     pub async fn into_json(self, context: &ApiContext) -> Result<JsonAlert, HttpError> {
@@ -162,6 +167,8 @@ impl QueryAlert {
         let Self {
             uuid,
             boundary_limit,
+            kind,
+            severity,
             status,
             modified,
             ..
@@ -183,6 +190,8 @@ impl QueryAlert {
             threshold,
             boundary: query_boundary.into_json(),
             limit: boundary_limit,
+            kind,
+            severity,
             status,
             created,
             modified,
@@ -193,6 +202,8 @@ impl QueryAlert {
         let QueryAlert {
             uuid,
             boundary_limit,
+            kind,
+            severity,
             status,
             modified,
             ..
@@ -200,6 +211,8 @@ impl QueryAlert {
         JsonPerfAlert {
             uuid,
             limit: boundary_limit,
+            kind,
+            severity,
             status,
             modified,
         }
@@ -212,6 +225,8 @@ pub struct InsertAlert {
     pub uuid: AlertUuid,
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
     pub status: AlertStatus,
     pub modified: DateTime,
 }
@@ -221,11 +236,15 @@ impl InsertAlert {
         conn: &mut DbConnection,
         boundary_uuid: BoundaryUuid,
         boundary_limit: BoundaryLimit,
+        kind: AlertKind,
+        severity: AlertSeverity,
     ) -> Result<(), HttpError> {
         let insert_alert = InsertAlert {
             uuid: AlertUuid::new(),
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
             boundary_limit,
+            kind,
+            severity,
             status: AlertStatus::default(),
             modified: DateTime::now(),
         };
@@ -0,0 +1,181 @@
+use bencher_json::{
+    Boundary, DateTime, JsonNewThresholdChange, JsonThresholdChange, Model, ModelTest, SampleSize,
+    ThresholdChangeUuid, Window,
+};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{assert_parentage, resource_conflict_err, resource_not_found_err, BencherResource},
+    model::{
+        project::{ProjectId, QueryProject},
+        user::{QueryUser, UserId},
+    },
+    schema::threshold_change as threshold_change_table,
+    util::fn_get::fn_get,
+};
+
+use super::{QueryThreshold, ThresholdId};
+
+crate::util::typed_id::typed_id!(ThresholdChangeId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = threshold_change_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryThresholdChange {
+    pub id: ThresholdChangeId,
+    pub uuid: ThresholdChangeUuid,
+    pub project_id: ProjectId,
+    pub threshold_id: ThresholdId,
+    pub requester_id: UserId,
+    pub approver_id: Option<UserId>,
+    pub test: Option<ModelTest>,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
+    pub created: DateTime,
+    pub approved: Option<DateTime>,
+}
+
+impl QueryThresholdChange {
+    fn_get!(threshold_change, ThresholdChangeId);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: ThresholdChangeUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(query_project)
+            .filter(threshold_change_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                ThresholdChange,
+                (query_project, uuid)
+            ))
+    }
+
+    /// The new model for the threshold, or `None` if the change removes the current model
+    pub fn model(&self) -> Option<Model> {
+        self.test.map(|test| Model {
+            test,
+            min_sample_size: self.min_sample_size,
+            max_sample_size: self.max_sample_size,
+            window: self.window,
+            lower_boundary: self.lower_boundary,
+            upper_boundary: self.upper_boundary,
+            lower_critical: self.lower_critical,
+            upper_critical: self.upper_critical,
+        })
+    }
+
+    /// Record that the change was approved by the approver.
+    /// The change is kept as an audit record of both the requester and the approver.
+    pub fn approve(&self, conn: &mut DbConnection, approver_id: UserId) -> Result<(), HttpError> {
+        let update = (
+            threshold_change_table::approver_id.eq(approver_id),
+            threshold_change_table::approved.eq(DateTime::now()),
+        );
+        diesel::update(
+            threshold_change_table::table
+                .filter(threshold_change_table::id.eq(self.id))
+                .filter(threshold_change_table::approved.is_null()),
+        )
+        .set(update)
+        .execute(conn)
+        .map_err(resource_conflict_err!(ThresholdChange, (self, approver_id)))?;
+        Ok(())
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<JsonThresholdChange, HttpError> {
+        let model = self.model();
+        let Self {
+            uuid,
+            project_id,
+            threshold_id,
+            requester_id,
+            approver_id,
+            created,
+            approved,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            query_project.id,
+            BencherResource::ThresholdChange,
+            project_id,
+        );
+        let threshold = QueryThreshold::get_uuid(conn, threshold_id)?;
+        let requester = QueryUser::get(conn, requester_id)?.into_pub_json();
+        let approver = if let Some(approver_id) = approver_id {
+            Some(QueryUser::get(conn, approver_id)?.into_pub_json())
+        } else {
+            None
+        };
+        Ok(JsonThresholdChange {
+            uuid,
+            project: query_project.uuid,
+            threshold,
+            model,
+            requester,
+            approver,
+            created,
+            approved,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = threshold_change_table)]
+pub struct InsertThresholdChange {
+    pub uuid: ThresholdChangeUuid,
+    pub project_id: ProjectId,
+    pub threshold_id: ThresholdId,
+    pub requester_id: UserId,
+    pub approver_id: Option<UserId>,
+    pub test: Option<ModelTest>,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
+    pub created: DateTime,
+    pub approved: Option<DateTime>,
+}
+
+impl InsertThresholdChange {
+    pub fn from_json(
+        query_threshold: &QueryThreshold,
+        requester_id: UserId,
+        json_threshold_change: &JsonNewThresholdChange,
+    ) -> Self {
+        let JsonNewThresholdChange { model, .. } = *json_threshold_change;
+        Self {
+            uuid: ThresholdChangeUuid::new(),
+            project_id: query_threshold.project_id,
+            threshold_id: query_threshold.id,
+            requester_id,
+            approver_id: None,
+            test: model.map(|model| model.test),
+            min_sample_size: model.and_then(|model| model.min_sample_size),
+            max_sample_size: model.and_then(|model| model.max_sample_size),
+            window: model.and_then(|model| model.window),
+            lower_boundary: model.and_then(|model| model.lower_boundary),
+            upper_boundary: model.and_then(|model| model.upper_boundary),
+            lower_critical: model.and_then(|model| model.lower_critical),
+            upper_critical: model.and_then(|model| model.upper_critical),
+            created: DateTime::now(),
+            approved: None,
+        }
+    }
+}
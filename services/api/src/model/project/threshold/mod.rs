@@ -5,7 +5,7 @@ use bencher_json::{
         report::JsonReportThresholds,
         threshold::{JsonThreshold, JsonThresholdModel},
     },
-    DateTime, Model, ModelUuid, ThresholdUuid,
+    BenchmarkGlob, DateTime, Model, ModelUuid, ThresholdUuid,
 };
 use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -14,7 +14,10 @@ use slog::Logger;
 
 use self::model::{InsertModel, ModelId, QueryModel};
 use super::{
-    branch::{head::HeadId, start_point::StartPoint, version::VersionId, BranchId, QueryBranch},
+    branch::{
+        head::HeadId, protection::BranchAuth, start_point::StartPoint, version::VersionId,
+        BranchId, QueryBranch,
+    },
     measure::{MeasureId, QueryMeasure},
     testbed::{QueryTestbed, TestbedId},
     ProjectId, QueryProject,
@@ -32,7 +35,9 @@ use crate::{
 
 pub mod alert;
 pub mod boundary;
+pub mod change;
 pub mod model;
+pub mod template;
 
 crate::util::typed_id::typed_id!(ThresholdId);
 
@@ -48,6 +53,7 @@ pub struct QueryThreshold {
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
+    pub benchmark: Option<BenchmarkGlob>,
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -77,6 +83,40 @@ impl QueryThreshold {
         }
     }
 
+    /// Verify that the user is allowed to replace the current model with the new model.
+    /// If the threshold belongs to a branch with protected thresholds,
+    /// then only an organization leader can loosen or remove the current model.
+    pub fn check_protected(
+        &self,
+        conn: &mut DbConnection,
+        branch_auth: BranchAuth,
+        model: Option<Model>,
+    ) -> Result<(), HttpError> {
+        let Some(current_model) = self.model(conn)?.map(QueryModel::into_model) else {
+            return Ok(());
+        };
+        let is_looser = model.map_or(true, |model| model.is_looser_than(current_model));
+        if is_looser {
+            QueryBranch::get(conn, self.branch_id)?.check_protected_thresholds(branch_auth)?;
+        }
+        Ok(())
+    }
+
+    /// Verify that the current model can be replaced with the new model directly.
+    /// If the threshold belongs to a branch with protected thresholds,
+    /// then any change to the current model must be requested and then approved by a different user.
+    pub fn check_protected_change(
+        &self,
+        conn: &mut DbConnection,
+        model: Option<Model>,
+    ) -> Result<(), HttpError> {
+        let current_model = self.model(conn)?.map(QueryModel::into_model);
+        if current_model == model {
+            return Ok(());
+        }
+        QueryBranch::get(conn, self.branch_id)?.check_protected_threshold_changes()
+    }
+
     pub async fn update_model_if_changed(
         &self,
         context: &ApiContext,
@@ -209,6 +249,7 @@ impl QueryThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            benchmark,
             created,
             modified,
             ..
@@ -230,6 +271,7 @@ impl QueryThreshold {
             branch,
             testbed,
             measure,
+            benchmark,
             model,
             created,
             modified,
@@ -271,6 +313,7 @@ pub struct InsertThreshold {
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
+    pub benchmark: Option<BenchmarkGlob>,
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -282,6 +325,7 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark: Option<BenchmarkGlob>,
     ) -> Self {
         let timestamp = DateTime::now();
         Self {
@@ -290,6 +334,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            benchmark,
             model_id: None,
             created: timestamp,
             modified: timestamp,
@@ -302,10 +347,12 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark: Option<BenchmarkGlob>,
         model: Model,
     ) -> Result<ThresholdId, HttpError> {
         // Create the new threshold
-        let insert_threshold = InsertThreshold::new(project_id, branch_id, testbed_id, measure_id);
+        let insert_threshold =
+            InsertThreshold::new(project_id, branch_id, testbed_id, measure_id, benchmark);
         diesel::insert_into(schema::threshold::table)
             .values(&insert_threshold)
             .execute(conn)
@@ -349,6 +396,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::lower_boundary(),
         )
     }
@@ -366,10 +414,12 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::upper_boundary(),
         )
     }
 
+    #[allow(clippy::too_many_lines)]
     pub async fn from_start_point(
         log: &Logger,
         context: &ApiContext,
@@ -400,7 +450,16 @@ impl InsertThreshold {
                 &branch_start_point.branch
             ))?
             .into_iter()
-            .map(|threshold| ((threshold.testbed_id, threshold.measure_id), threshold))
+            .map(|threshold| {
+                (
+                    (
+                        threshold.testbed_id,
+                        threshold.measure_id,
+                        threshold.benchmark.clone(),
+                    ),
+                    threshold,
+                )
+            })
             .collect::<HashMap<_, _>>();
         slog::debug!(log, "Current thresholds: {current_thresholds:?}");
 
@@ -412,12 +471,23 @@ impl InsertThreshold {
                 &branch_start_point.branch
             ))?
             .into_iter()
-            .map(|threshold| ((threshold.testbed_id, threshold.measure_id), threshold))
+            .map(|threshold| {
+                (
+                    (
+                        threshold.testbed_id,
+                        threshold.measure_id,
+                        threshold.benchmark.clone(),
+                    ),
+                    threshold,
+                )
+            })
             .collect::<HashMap<_, _>>();
         slog::debug!(log, "Start point thresholds: {start_point_thresholds:?}");
 
-        for ((start_point_testbed_id, start_point_measure_id), start_point_threshold) in
-            start_point_thresholds
+        for (
+            (start_point_testbed_id, start_point_measure_id, start_point_benchmark),
+            start_point_threshold,
+        ) in start_point_thresholds
         {
             let start_point_model = start_point_threshold
                 .model(conn_lock!(context))?
@@ -426,9 +496,11 @@ impl InsertThreshold {
                 log,
                 "Processing start point threshold ({start_point_threshold:?}) with model ({start_point_model:?}) for testbed ({start_point_testbed_id}) and measure ({start_point_measure_id})"
             );
-            if let Some(current_threshold) =
-                current_thresholds.remove(&(start_point_testbed_id, start_point_measure_id))
-            {
+            if let Some(current_threshold) = current_thresholds.remove(&(
+                start_point_testbed_id,
+                start_point_measure_id,
+                start_point_benchmark.clone(),
+            )) {
                 slog::debug!(
                     log,
                     "Updating current threshold ({current_threshold:?}) for testbed ({start_point_testbed_id}) and measure ({start_point_measure_id})"
@@ -451,6 +523,7 @@ impl InsertThreshold {
                     query_branch.id,
                     start_point_testbed_id,
                     start_point_measure_id,
+                    start_point_benchmark,
                     start_point_model,
                 )?;
                 slog::debug!(
@@ -499,11 +572,13 @@ impl InsertThreshold {
             return Ok(());
         }
 
-        // Get all thresholds for the report branch and testbed
+        // Get all thresholds for the report branch and testbed.
+        // Thresholds for specific benchmarks are not managed by the report.
         let mut current_thresholds = schema::threshold::table
             .filter(schema::threshold::project_id.eq(project_id))
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
+            .filter(schema::threshold::benchmark.is_null())
             .load::<QueryThreshold>(conn_lock!(context))
             .map_err(resource_not_found_err!(Threshold, (branch_id, testbed_id)))?
             .into_iter()
@@ -520,6 +595,7 @@ impl InsertThreshold {
                 slog::debug!(log, "Processing threshold for measure {measure_id}");
                 if let Some(current_threshold) = current_thresholds.remove(&measure_id) {
                     slog::debug!(log, "Updating threshold for measure {measure_id}");
+                    current_threshold.check_protected_change(conn_lock!(context), Some(model))?;
                     current_threshold
                         .update_model_if_changed(context, Some(model))
                         .await?;
@@ -532,6 +608,7 @@ impl InsertThreshold {
                         branch_id,
                         testbed_id,
                         measure_id,
+                        None,
                         model,
                     )?;
                     slog::debug!(log, "Created threshold for measure {measure_id}");
@@ -543,6 +620,7 @@ impl InsertThreshold {
         // If the reset flag is set, remove any thresholds that were not in the report
         if reset_thresholds {
             for (_, current_threshold) in current_thresholds {
+                current_threshold.check_protected_change(conn_lock!(context), None)?;
                 current_threshold.remove_current_model(conn_lock!(context))?;
                 slog::debug!(log, "Removed model from threshold {current_threshold:?}");
             }
@@ -28,6 +28,8 @@ pub struct QueryModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -61,6 +63,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             ..
         } = self;
         Model {
@@ -70,6 +74,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         }
     }
 
@@ -83,6 +89,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             created,
             replaced,
             ..
@@ -101,6 +109,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             created,
             replaced,
         }
@@ -118,6 +128,8 @@ pub struct InsertModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -131,6 +143,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         } = model;
         Self {
             uuid: ModelUuid::new(),
@@ -141,6 +155,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             created: DateTime::now(),
             replaced: None,
         }
@@ -154,6 +170,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             created,
             replaced,
             ..
@@ -167,6 +185,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
             created,
             replaced,
         }
@@ -0,0 +1,311 @@
+use bencher_json::{
+    Boundary, DateTime, JsonNewThresholdTemplate, JsonThresholdTemplate, Model, ModelTest,
+    SampleSize, Slug, ThresholdTemplateUuid, Window,
+};
+use diesel::{
+    BelongingToDsl, BoolExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl,
+};
+use dropshot::HttpError;
+
+use super::{InsertThreshold, ThresholdId};
+use crate::{
+    context::DbConnection,
+    error::{assert_parentage, resource_not_found_err, BencherResource},
+    model::{
+        organization::{OrganizationId, QueryOrganization},
+        project::{
+            branch::BranchId,
+            measure::{MeasureId, QueryMeasure},
+            testbed::TestbedId,
+            ProjectId, QueryProject,
+        },
+    },
+    schema::{self, threshold_template as threshold_template_table},
+    util::fn_get::fn_get,
+};
+
+crate::util::typed_id::typed_id!(ThresholdTemplateId);
+
+/// A threshold template is used to create a threshold
+/// for any branch and testbed that do not already have one for the template measure.
+/// Organization templates apply to all projects in the organization,
+/// and project templates take precedence over organization templates.
+#[derive(Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = threshold_template_table)]
+#[diesel(belongs_to(QueryOrganization, foreign_key = organization_id))]
+pub struct QueryThresholdTemplate {
+    pub id: ThresholdTemplateId,
+    pub uuid: ThresholdTemplateUuid,
+    pub organization_id: OrganizationId,
+    pub project_id: Option<ProjectId>,
+    pub measure: Slug,
+    pub test: ModelTest,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryThresholdTemplate {
+    fn_get!(threshold_template, ThresholdTemplateId);
+
+    pub fn get_for_organization(
+        conn: &mut DbConnection,
+        query_organization: &QueryOrganization,
+        uuid: ThresholdTemplateUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(query_organization)
+            .filter(threshold_template_table::project_id.is_null())
+            .filter(threshold_template_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                ThresholdTemplate,
+                (query_organization, uuid)
+            ))
+    }
+
+    pub fn get_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: ThresholdTemplateUuid,
+    ) -> Result<Self, HttpError> {
+        threshold_template_table::table
+            .filter(threshold_template_table::project_id.eq(query_project.id))
+            .filter(threshold_template_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                ThresholdTemplate,
+                (query_project, uuid)
+            ))
+    }
+
+    pub fn all_for_organization(
+        conn: &mut DbConnection,
+        query_organization: &QueryOrganization,
+    ) -> Result<Vec<Self>, HttpError> {
+        Self::belonging_to(query_organization)
+            .filter(threshold_template_table::project_id.is_null())
+            .order(threshold_template_table::created.asc())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                ThresholdTemplate,
+                query_organization
+            ))
+    }
+
+    pub fn all_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Vec<Self>, HttpError> {
+        threshold_template_table::table
+            .filter(threshold_template_table::project_id.eq(query_project.id))
+            .order(threshold_template_table::created.asc())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(ThresholdTemplate, query_project))
+    }
+
+    /// Create a threshold for the branch, testbed, and measure from the best matching template.
+    /// A project template is preferred over an organization template.
+    /// Returns `None` if there is already a threshold for the branch, testbed, and measure,
+    /// even if it does not currently have a model, or if there is no template for the measure.
+    pub fn create_threshold(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        branch_id: BranchId,
+        testbed_id: TestbedId,
+        measure_id: MeasureId,
+    ) -> Result<Option<ThresholdId>, HttpError> {
+        let threshold_id = schema::threshold::table
+            .filter(schema::threshold::branch_id.eq(branch_id))
+            .filter(schema::threshold::testbed_id.eq(testbed_id))
+            .filter(schema::threshold::measure_id.eq(measure_id))
+            .filter(schema::threshold::benchmark.is_null())
+            .select(schema::threshold::id)
+            .first::<ThresholdId>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(
+                Threshold,
+                (branch_id, testbed_id, measure_id)
+            ))?;
+        if threshold_id.is_some() {
+            return Ok(None);
+        }
+
+        let query_project = QueryProject::get(conn, project_id)?;
+        let query_measure = QueryMeasure::get(conn, measure_id)?;
+        // In SQLite, `NULL` is sorted last when in descending order,
+        // so a project template comes before an organization template.
+        let Some(template) = threshold_template_table::table
+            .filter(threshold_template_table::organization_id.eq(query_project.organization_id))
+            .filter(
+                threshold_template_table::project_id
+                    .eq(project_id)
+                    .or(threshold_template_table::project_id.is_null()),
+            )
+            .filter(threshold_template_table::measure.eq(&query_measure.slug))
+            .order((
+                threshold_template_table::project_id.desc(),
+                threshold_template_table::created.desc(),
+            ))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(
+                ThresholdTemplate,
+                (&query_project, &query_measure)
+            ))?
+        else {
+            return Ok(None);
+        };
+
+        InsertThreshold::from_model(
+            conn,
+            project_id,
+            branch_id,
+            testbed_id,
+            measure_id,
+            None,
+            template.into_model(),
+        )
+        .map(Some)
+    }
+
+    pub fn into_model(self) -> Model {
+        let Self {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+            ..
+        } = self;
+        Model {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+        }
+    }
+
+    pub fn into_json(
+        self,
+        organization: &QueryOrganization,
+        project: Option<&QueryProject>,
+    ) -> JsonThresholdTemplate {
+        let Self {
+            uuid,
+            organization_id,
+            project_id,
+            measure,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Organization,
+            organization.id,
+            BencherResource::ThresholdTemplate,
+            organization_id,
+        );
+        if let (Some(project), Some(project_id)) = (project, project_id) {
+            assert_parentage(
+                BencherResource::Project,
+                project.id,
+                BencherResource::ThresholdTemplate,
+                project_id,
+            );
+        }
+        JsonThresholdTemplate {
+            uuid,
+            organization: organization.uuid,
+            project: project.map(|project| project.uuid),
+            measure,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = threshold_template_table)]
+pub struct InsertThresholdTemplate {
+    pub uuid: ThresholdTemplateUuid,
+    pub organization_id: OrganizationId,
+    pub project_id: Option<ProjectId>,
+    pub measure: Slug,
+    pub test: ModelTest,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertThresholdTemplate {
+    pub fn from_json(
+        organization_id: OrganizationId,
+        project_id: Option<ProjectId>,
+        template: JsonNewThresholdTemplate,
+    ) -> Self {
+        let JsonNewThresholdTemplate { measure, model } = template;
+        let Model {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+        } = model;
+        let timestamp = DateTime::now();
+        Self {
+            uuid: ThresholdTemplateUuid::new(),
+            organization_id,
+            project_id,
+            measure,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            lower_critical,
+            upper_critical,
+            created: timestamp,
+            modified: timestamp,
+        }
+    }
+}
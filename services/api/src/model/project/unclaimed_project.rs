@@ -0,0 +1,103 @@
+use bencher_json::{DateTime, Secret};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, unclaimed_project as unclaimed_project_table},
+};
+
+use super::{ProjectId, QueryProject};
+
+crate::util::typed_id::typed_id!(UnclaimedProjectId);
+
+/// A project that was created anonymously and has not yet been claimed by a user.
+/// Anyone with the key can send reports to the project or claim it.
+/// Only a hash of the key is stored.
+#[derive(diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = unclaimed_project_table)]
+pub struct QueryUnclaimedProject {
+    pub id: UnclaimedProjectId,
+    pub project_id: ProjectId,
+    pub key: String,
+    pub created: DateTime,
+}
+
+impl QueryUnclaimedProject {
+    pub fn from_key(conn: &mut DbConnection, key: &Secret) -> Result<Self, HttpError> {
+        unclaimed_project_table::table
+            .filter(unclaimed_project_table::key.eq(hash_key(key)))
+            .select(Self::as_select())
+            .first(conn)
+            .map_err(resource_not_found_err!(UnclaimedProject, key))
+    }
+
+    /// The number of reports sent to the unclaimed project since the given time
+    pub fn reports_since(
+        &self,
+        conn: &mut DbConnection,
+        since: DateTime,
+    ) -> Result<i64, HttpError> {
+        schema::report::table
+            .filter(schema::report::project_id.eq(self.project_id))
+            .filter(schema::report::created.gt(since))
+            .count()
+            .get_result(conn)
+            .map_err(resource_not_found_err!(Report, self.project_id))
+    }
+
+    pub fn project(&self, conn: &mut DbConnection) -> Result<QueryProject, HttpError> {
+        QueryProject::get(conn, self.project_id)
+    }
+
+    /// Once claimed, the key can no longer be used to access the project
+    pub fn claim(self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::delete(
+            unclaimed_project_table::table.filter(unclaimed_project_table::id.eq(self.id)),
+        )
+        .execute(conn)
+        .map_err(resource_conflict_err!(UnclaimedProject, self.project_id))?;
+        Ok(())
+    }
+}
+
+#[derive(diesel::Insertable)]
+#[diesel(table_name = unclaimed_project_table)]
+pub struct InsertUnclaimedProject {
+    pub project_id: ProjectId,
+    pub key: String,
+    pub created: DateTime,
+}
+
+impl InsertUnclaimedProject {
+    /// The number of unclaimed projects created since the given time
+    pub fn count_since(conn: &mut DbConnection, since: DateTime) -> Result<i64, HttpError> {
+        unclaimed_project_table::table
+            .filter(unclaimed_project_table::created.gt(since))
+            .count()
+            .get_result(conn)
+            .map_err(resource_not_found_err!(UnclaimedProject))
+    }
+
+    pub fn insert(conn: &mut DbConnection, project_id: ProjectId) -> Result<Secret, HttpError> {
+        let key = Secret::from(Uuid::new_v4());
+        let insert_unclaimed_project = Self {
+            project_id,
+            key: hash_key(&key),
+            created: DateTime::now(),
+        };
+        diesel::insert_into(schema::unclaimed_project::table)
+            .values(&insert_unclaimed_project)
+            .execute(conn)
+            .map_err(resource_conflict_err!(UnclaimedProject, project_id))?;
+        Ok(key)
+    }
+}
+
+/// The key is a random UUID, so a fast unsalted hash is enough to keep it from being stored as is
+fn hash_key(key: &Secret) -> String {
+    hex::encode(Sha256::digest(key.as_ref().as_bytes()))
+}
@@ -0,0 +1,108 @@
+use bencher_json::{DateTime, JsonNewWebhook, JsonWebhook, Url, WebhookUuid};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use super::{ProjectId, QueryProject};
+use crate::{
+    context::{DbConnection, Encryption},
+    error::{assert_parentage, resource_not_found_err, BencherResource},
+    schema::webhook as webhook_table,
+};
+
+crate::util::typed_id::typed_id!(WebhookId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[diesel(table_name = webhook_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: String,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: WebhookUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(query_project)
+            .filter(webhook_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Webhook, (query_project, uuid)))
+    }
+
+    pub fn all_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Vec<Self>, HttpError> {
+        Self::belonging_to(query_project)
+            .order(webhook_table::created.asc())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(Webhook, query_project))
+    }
+
+    /// Decrypt the webhook secret, so it can be used to sign payloads
+    pub fn decrypt(mut self, encryption: &Encryption) -> Result<Self, HttpError> {
+        self.secret = encryption.decrypt(&self.secret)?;
+        Ok(self)
+    }
+
+    pub fn into_json_for_project(self, project: &QueryProject) -> JsonWebhook {
+        let Self {
+            uuid,
+            project_id,
+            url,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Webhook,
+            project_id,
+        );
+        JsonWebhook {
+            uuid,
+            project: project.uuid,
+            url,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: String,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub fn from_json(
+        encryption: &Encryption,
+        project_id: ProjectId,
+        webhook: JsonNewWebhook,
+    ) -> Result<Self, HttpError> {
+        let JsonNewWebhook { url, secret } = webhook;
+        let timestamp = DateTime::now();
+        Ok(Self {
+            uuid: WebhookUuid::new(),
+            project_id,
+            url,
+            secret: encryption.encrypt(secret.as_ref())?,
+            created: timestamp,
+            modified: timestamp,
+        })
+    }
+}
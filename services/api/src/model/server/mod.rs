@@ -1,6 +1,5 @@
 #![cfg(feature = "plus")]
 
-use std::sync::LazyLock;
 use std::{cmp, sync::Arc};
 
 use bencher_json::{DateTime, JsonServer, JsonServerStats, PlanLevel, ServerUuid, BENCHER_API_URL};
@@ -28,13 +27,9 @@ const SERVER_ID: ServerId = ServerId(1);
 
 const LICENSE_GRACE_PERIOD: usize = 7;
 
-#[allow(clippy::panic)]
-pub static BENCHER_STATS_API_URL: LazyLock<url::Url> = LazyLock::new(|| {
-    BENCHER_API_URL
-        .clone()
-        .join("/v0/server/stats")
-        .unwrap_or_else(|e| panic!("Failed to parse stats API endpoint: {e}"))
-});
+fn bencher_stats_api_url() -> Result<url::Url, url::ParseError> {
+    BENCHER_API_URL.clone().join("/v0/server/stats")
+}
 
 #[derive(Debug, Clone, Copy, diesel::Queryable)]
 pub struct QueryServer {
@@ -140,13 +135,15 @@ impl QueryServer {
                         slog::error!(log, "Failed to send stats: {e}");
                     }
                 } else {
+                    let stats_api_url = match bencher_stats_api_url() {
+                        Ok(stats_api_url) => stats_api_url,
+                        Err(e) => {
+                            slog::error!(log, "Failed to parse stats API endpoint: {e}");
+                            continue;
+                        },
+                    };
                     let client = reqwest::Client::new();
-                    if let Err(e) = client
-                        .post(BENCHER_STATS_API_URL.clone())
-                        .body(json_stats_str)
-                        .send()
-                        .await
-                    {
+                    if let Err(e) = client.post(stats_api_url).body(json_stats_str).send().await {
                         slog::error!(log, "Failed to send stats: {e}");
                     }
                 }
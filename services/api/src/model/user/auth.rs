@@ -1,14 +1,16 @@
-use std::ops::Deref;
+use std::{net::IpAddr, ops::Deref};
 
 use async_trait::async_trait;
 #[cfg(feature = "plus")]
 use bencher_json::system::payment::JsonCustomer;
-use bencher_json::{Email, Jwt, Sanitize};
+use bencher_json::{user::token::TokenScope, Email, Jwt, Sanitize};
 use bencher_rbac::{
+    project::Role as ProjectRole,
     server::Permission,
     user::{OrganizationRoles, ProjectRoles},
     Organization, Project, Server, User as RbacUser,
 };
+use bencher_token::TokenError;
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::{
     ApiEndpointBodyContentType, ExtensionMode, ExtractorMetadata, HttpError, RequestContext,
@@ -20,12 +22,15 @@ use oso::{PolarValue, ToPolar};
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection, Rbac},
-    error::{bad_request_error, forbidden_error, BEARER_TOKEN_FORMAT},
-    model::{organization::OrganizationId, project::ProjectId},
+    error::{bad_request_error, forbidden_error, unauthorized_error, BEARER_TOKEN_FORMAT},
+    model::{
+        organization::OrganizationId,
+        project::{project_token::QueryProjectToken, ProjectId, QueryProject},
+    },
     schema,
 };
 
-use super::{QueryUser, UserId};
+use super::{token::QueryToken, QueryUser, UserId};
 
 #[derive(Debug, Clone)]
 pub struct AuthUser {
@@ -33,6 +38,15 @@ pub struct AuthUser {
     pub organizations: Vec<OrganizationId>,
     pub projects: Vec<OrgProjectId>,
     pub rbac: RbacUser,
+    pub scope: AuthScope,
+}
+
+/// The limits placed on an API token.
+/// Session tokens and API tokens without a scope or project have the same permissions as the user.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AuthScope {
+    pub scope: Option<TokenScope>,
+    pub project_id: Option<ProjectId>,
 }
 
 impl AuthUser {
@@ -66,11 +80,24 @@ impl AuthUser {
         let claims = context
             .token_key
             .validate_client(&bearer_token)
-            .map_err(|e| bad_request_error(format!("Failed to validate JSON Web Token: {e}")))?;
+            .map_err(|e| match e {
+                TokenError::Expired { .. } => {
+                    unauthorized_error(format!("JSON Web Token has expired: {e}"))
+                },
+                TokenError::Encode { .. }
+                | TokenError::Decode { .. }
+                | TokenError::Parse(_)
+                | TokenError::Invite { .. } => {
+                    bad_request_error(format!("Failed to validate JSON Web Token: {e}"))
+                },
+            })?;
         let email = claims.email();
 
         // Hold the connection for all permissions related queries
         let conn = conn_lock!(context);
+        if claims.is_project_key() {
+            return Self::from_project_token(conn, &bearer_token);
+        }
         let mut query_user = QueryUser::get_with_email(conn, email)?;
         if query_user.locked {
             query_user.sanitize();
@@ -80,6 +107,18 @@ impl AuthUser {
         }
         let (org_ids, org_roles) = Self::organization_roles(conn, query_user.id, email)?;
         let (proj_ids, proj_roles) = Self::project_roles(conn, query_user.id, email)?;
+        // Only API tokens are stored, so any other token is unscoped
+        let scope = if let Some((token_id, scope, project_id)) =
+            QueryToken::get_scope(conn, &bearer_token)?
+        {
+            QueryToken::check_allowlist(conn, token_id, bearer_token.remote_ip)?;
+            AuthScope { scope, project_id }
+        } else if claims.is_api_key() {
+            // An API token that is no longer stored has been rotated
+            return Err(unauthorized_error("API token has been revoked"));
+        } else {
+            AuthScope::default()
+        };
 
         let rbac = RbacUser {
             admin: query_user.admin,
@@ -92,6 +131,51 @@ impl AuthUser {
             organizations: org_ids,
             projects: proj_ids,
             rbac,
+            scope,
+        })
+    }
+
+    // A project API token only has the role for its scope on its project,
+    // regardless of the roles of the user who created it.
+    // The creator is still used to attribute any changes made with the token.
+    fn from_project_token(
+        conn: &mut DbConnection,
+        bearer_token: &BearerToken,
+    ) -> Result<Self, HttpError> {
+        let query_token = QueryProjectToken::get_with_jwt(conn, bearer_token)
+            .map_err(|_e| unauthorized_error("Project API token has been deleted"))?;
+        let mut query_user = QueryUser::get(conn, query_token.creator_id)?;
+        if query_user.locked {
+            query_user.sanitize();
+            return Err(forbidden_error(format!(
+                "User account for project API token creator is locked: {query_user:?}"
+            )));
+        }
+        let query_project = QueryProject::get(conn, query_token.project_id)?;
+
+        let role = match query_token.scope {
+            TokenScope::Admin => ProjectRole::Maintainer,
+            TokenScope::ReportWrite => ProjectRole::Developer,
+            TokenScope::ProjectRead => ProjectRole::Viewer,
+        };
+        let rbac = RbacUser {
+            admin: false,
+            locked: false,
+            organizations: OrganizationRoles::new(),
+            projects: ProjectRoles::from([(query_project.id.to_string(), role)]),
+        };
+        Ok(Self {
+            user: query_user,
+            organizations: Vec::new(),
+            projects: vec![OrgProjectId {
+                org_id: query_project.organization_id,
+                project_id: query_project.id,
+            }],
+            rbac,
+            scope: AuthScope {
+                scope: Some(query_token.scope),
+                project_id: Some(query_project.id),
+            },
         })
     }
 
@@ -190,7 +274,7 @@ impl AuthUser {
     }
 
     pub fn is_admin(&self, rbac: &Rbac) -> bool {
-        rbac.is_allowed_unwrap(self, Permission::Administer, Server {})
+        self.scope.is_unlimited() && rbac.is_allowed_unwrap(self, Permission::Administer, Server {})
     }
 
     pub fn organizations(
@@ -201,8 +285,9 @@ impl AuthUser {
         self.organizations
             .iter()
             .filter_map(|org_id| {
-                rbac.is_allowed_unwrap(self, action, Organization::from(*org_id))
-                    .then_some(*org_id)
+                (self.scope.allows_organization(action)
+                    && rbac.is_allowed_unwrap(self, action, Organization::from(*org_id)))
+                .then_some(*org_id)
             })
             .collect()
     }
@@ -215,8 +300,10 @@ impl AuthUser {
         self.projects
             .iter()
             .filter_map(|org_project_id| {
-                rbac.is_allowed_unwrap(self, action, Project::from(*org_project_id))
-                    .then_some(org_project_id.project_id)
+                let project = Project::from(*org_project_id);
+                (self.scope.allows_project(action, &project)
+                    && rbac.is_allowed_unwrap(self, action, project))
+                .then_some(org_project_id.project_id)
             })
             .collect()
     }
@@ -231,6 +318,49 @@ impl AuthUser {
     }
 }
 
+impl AuthScope {
+    /// Whether the token has all of the permissions of the user
+    pub fn is_unlimited(&self) -> bool {
+        matches!(self.scope, None | Some(TokenScope::Admin)) && self.project_id.is_none()
+    }
+
+    pub fn allows_organization(&self, action: bencher_rbac::organization::Permission) -> bool {
+        use bencher_rbac::organization::Permission;
+
+        // A token that is limited to a single project can not act on its organization
+        if self.project_id.is_some() {
+            return false;
+        }
+        match self.scope {
+            None | Some(TokenScope::Admin) => true,
+            Some(TokenScope::ProjectRead | TokenScope::ReportWrite) => {
+                matches!(action, Permission::View)
+            },
+        }
+    }
+
+    pub fn allows_project(
+        &self,
+        action: bencher_rbac::project::Permission,
+        project: &Project,
+    ) -> bool {
+        use bencher_rbac::project::Permission;
+
+        if let Some(project_id) = self.project_id {
+            if project.id != project_id.to_string() {
+                return false;
+            }
+        }
+        match self.scope {
+            None | Some(TokenScope::Admin) => true,
+            Some(TokenScope::ProjectRead) => matches!(action, Permission::View),
+            Some(TokenScope::ReportWrite) => {
+                matches!(action, Permission::View | Permission::Create)
+            },
+        }
+    }
+}
+
 impl Deref for AuthUser {
     type Target = QueryUser;
 
@@ -265,11 +395,18 @@ impl SharedExtractor for Headers {
 }
 
 // https://github.com/oxidecomputer/cio/blob/master/dropshot-verify-request/src/bearer.rs
-pub struct BearerToken(Jwt);
+pub struct BearerToken {
+    jwt: Jwt,
+    // Used to enforce API token IP allowlists
+    remote_ip: Option<IpAddr>,
+}
 
 impl From<Jwt> for BearerToken {
     fn from(jwt: Jwt) -> Self {
-        Self(jwt)
+        Self {
+            jwt,
+            remote_ip: None,
+        }
     }
 }
 
@@ -277,7 +414,7 @@ impl Deref for BearerToken {
     type Target = Jwt;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.jwt
     }
 }
 
@@ -307,11 +444,14 @@ impl SharedExtractor for BearerToken {
             )));
         };
 
-        token
+        let jwt = token
             .trim()
             .parse::<Jwt>()
-            .map(Into::into)
-            .map_err(|e| bad_request_error(format!("Malformed JSON Web Token: {e}")))
+            .map_err(|e| bad_request_error(format!("Malformed JSON Web Token: {e}")))?;
+        Ok(Self {
+            jwt,
+            remote_ip: Some(rqctx.request.remote_addr().ip()),
+        })
     }
 
     fn metadata(_body_content_type: ApiEndpointBodyContentType) -> ExtractorMetadata {
@@ -0,0 +1,101 @@
+use bencher_json::{DateTime, Email, Slug, UserName, UserUuid};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use http::StatusCode;
+
+use crate::{
+    context::DbConnection,
+    error::{issue_error, resource_conflict_err},
+    schema::{self, user_deletion as user_deletion_table},
+};
+
+use super::{QueryUser, UpdateUser, UserId};
+
+const DELETED_USER_NAME: &str = "Deleted User";
+
+/// An audit record of a user whose personal data has been scrubbed.
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = user_deletion_table)]
+pub struct InsertUserDeletion {
+    pub user_id: UserId,
+    pub requester_id: UserId,
+    pub created: DateTime,
+}
+
+/// Scrub all personal data for the user.
+/// The user itself is kept with an opaque name, slug, and email
+/// so that its reports remain attributed to the same user.
+/// All tokens and organization and project roles for the user are removed,
+/// and the user is locked so that it can no longer be used.
+pub fn scrub_user(
+    conn: &mut DbConnection,
+    query_user: &QueryUser,
+    requester_id: UserId,
+) -> Result<(), HttpError> {
+    let update_user = UpdateUser::scrub(query_user.uuid)?;
+    diesel::update(schema::user::table.filter(schema::user::id.eq(query_user.id)))
+        .set(&update_user)
+        .execute(conn)
+        .map_err(resource_conflict_err!(User, (query_user, &update_user)))?;
+
+    diesel::delete(schema::token::table.filter(schema::token::user_id.eq(query_user.id)))
+        .execute(conn)
+        .map_err(resource_conflict_err!(Token, query_user))?;
+    diesel::delete(
+        schema::organization_role::table
+            .filter(schema::organization_role::user_id.eq(query_user.id)),
+    )
+    .execute(conn)
+    .map_err(resource_conflict_err!(OrganizationRole, query_user))?;
+    diesel::delete(
+        schema::project_role::table.filter(schema::project_role::user_id.eq(query_user.id)),
+    )
+    .execute(conn)
+    .map_err(resource_conflict_err!(ProjectRole, query_user))?;
+
+    let insert_user_deletion = InsertUserDeletion {
+        user_id: query_user.id,
+        requester_id,
+        created: DateTime::now(),
+    };
+    diesel::insert_into(user_deletion_table::table)
+        .values(&insert_user_deletion)
+        .execute(conn)
+        .map_err(resource_conflict_err!(UserDeletion, insert_user_deletion))?;
+
+    Ok(())
+}
+
+impl UpdateUser {
+    fn scrub(uuid: UserUuid) -> Result<Self, HttpError> {
+        let scrub_error = |field: &str, e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to scrub user",
+                &format!("Failed to create scrubbed {field} for user ({uuid})"),
+                e,
+            )
+        };
+        Ok(Self {
+            name: Some(
+                DELETED_USER_NAME
+                    .parse::<UserName>()
+                    .map_err(|e| scrub_error("name", e))?,
+            ),
+            slug: Some(
+                format!("deleted-{uuid}")
+                    .parse::<Slug>()
+                    .map_err(|e| scrub_error("slug", e))?,
+            ),
+            // The `.invalid` top-level domain is reserved and can never receive email
+            email: Some(
+                format!("{uuid}@deleted.invalid")
+                    .parse::<Email>()
+                    .map_err(|e| scrub_error("email", e))?,
+            ),
+            admin: Some(false),
+            locked: Some(true),
+            modified: DateTime::now(),
+        })
+    }
+}
@@ -15,19 +15,22 @@ use crate::{
     util::{
         fn_get::{fn_get, fn_get_id, fn_get_uuid},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
-        slug::ok_slug,
+        slug::{insert_with_slug, ok_slug},
     },
 };
 
 pub mod admin;
 pub mod auth;
+pub mod deletion;
 pub mod token;
 
 crate::util::typed_id::typed_id!(UserId);
 
 macro_rules! same_user {
     ($auth_user:ident, $rbac:expr, $user_uuid:expr) => {
-        if !($auth_user.is_admin(&$rbac) || $auth_user.uuid() == $user_uuid) {
+        if !($auth_user.is_admin(&$rbac)
+            || ($auth_user.scope.is_unlimited() && $auth_user.uuid() == $user_uuid))
+        {
             #[allow(unused_qualifications)]
             return Err(crate::error::forbidden_error(format!("User is not admin and the authenticated user ({auth_user}) does not match the requested user ({requested_user})", auth_user = $auth_user.uuid(), requested_user = $user_uuid)));
         }
@@ -216,9 +219,7 @@ impl InsertUser {
         }
 
         // Insert user
-        diesel::insert_into(schema::user::table)
-            .values(&insert_user)
-            .execute(conn)
+        insert_with_slug!(conn, user, insert_user)
             .map_err(resource_conflict_err!(User, insert_user))?;
         let user_id = QueryUser::get_id(conn, insert_user.uuid)?;
 
@@ -226,10 +227,8 @@ impl InsertUser {
             InsertOrganizationRole::from_jwt(conn, token_key, invite, user_id)?
         } else {
             // Create an organization for the user
-            let insert_org = InsertOrganization::from_user(&insert_user);
-            diesel::insert_into(schema::organization::table)
-                .values(&insert_org)
-                .execute(conn)
+            let mut insert_org = InsertOrganization::from_user(&insert_user);
+            insert_with_slug!(conn, organization, insert_org)
                 .map_err(resource_conflict_err!(Organization, insert_org))?;
             let organization_id = QueryOrganization::get_id(conn, insert_org.uuid)?;
 
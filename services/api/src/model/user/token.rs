@@ -1,18 +1,25 @@
+use std::net::IpAddr;
+
 use bencher_json::{
-    user::token::JsonUpdateToken, DateTime, JsonNewToken, JsonToken, Jwt, ResourceId, ResourceName,
-    TokenUuid,
+    user::token::{JsonUpdateToken, TokenScope},
+    Cidr, DateTime, JsonNewToken, JsonToken, Jwt, ResourceId, ResourceName, TokenUuid,
 };
+use bencher_rbac::project::Permission;
 use bencher_token::TokenKey;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 use http::StatusCode;
 
 use crate::{
     context::{DbConnection, Rbac},
     error::{
-        assert_parentage, bad_request_error, issue_error, resource_not_found_err, BencherResource,
+        assert_parentage, bad_request_error, forbidden_error, issue_error, resource_conflict_err,
+        resource_not_found_err, BencherResource,
+    },
+    model::{
+        project::{ProjectId, QueryProject},
+        user::same_user,
     },
-    model::user::same_user,
     schema,
     schema::token as token_table,
     util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
@@ -31,8 +38,13 @@ pub struct QueryToken {
     pub jwt: Jwt,
     pub creation: DateTime,
     pub expiration: DateTime,
+    pub scope: Option<TokenScope>,
+    pub project_id: Option<ProjectId>,
 }
 
+/// The ID, scope, and project restriction for an API token
+pub type ApiTokenScope = (TokenId, Option<TokenScope>, Option<ProjectId>);
+
 impl QueryToken {
     fn_get!(token, TokenId);
     fn_get_id!(token, TokenId, TokenUuid);
@@ -50,12 +62,65 @@ impl QueryToken {
             .map_err(resource_not_found_err!(Token, (user_id, uuid)))
     }
 
+    /// Get the ID, scope, and project restriction for a JSON Web Token, if it is an API token
+    pub fn get_scope(
+        conn: &mut DbConnection,
+        jwt: &Jwt,
+    ) -> Result<Option<ApiTokenScope>, HttpError> {
+        schema::token::table
+            .filter(schema::token::jwt.eq(jwt))
+            .select((
+                schema::token::id,
+                schema::token::scope,
+                schema::token::project_id,
+            ))
+            .first(conn)
+            .optional()
+            .map_err(resource_not_found_err!(Token, jwt))
+    }
+
+    pub fn allowed_ips(conn: &mut DbConnection, token_id: TokenId) -> Result<Vec<Cidr>, HttpError> {
+        schema::token_allowlist::table
+            .filter(schema::token_allowlist::token_id.eq(token_id))
+            .order(schema::token_allowlist::id)
+            .select(schema::token_allowlist::cidr)
+            .load(conn)
+            .map_err(resource_not_found_err!(Token, token_id))
+    }
+
+    /// Check that an API token is allowed to be used from the client IP address.
+    /// A token without an allowlist can be used from any IP address.
+    pub fn check_allowlist(
+        conn: &mut DbConnection,
+        token_id: TokenId,
+        remote_ip: Option<IpAddr>,
+    ) -> Result<(), HttpError> {
+        let allowed_ips = Self::allowed_ips(conn, token_id)?;
+        if allowed_ips.is_empty() {
+            return Ok(());
+        }
+        match remote_ip {
+            Some(remote_ip) if allowed_ips.iter().any(|cidr| cidr.contains(remote_ip)) => Ok(()),
+            Some(remote_ip) => Err(forbidden_error(format!(
+                "API token is not allowed from IP address ({remote_ip})"
+            ))),
+            None => Err(forbidden_error(
+                "API token has an IP allowlist but the client IP address is unknown",
+            )),
+        }
+    }
+
     pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonToken, HttpError> {
         let query_user = QueryUser::get(conn, self.user_id)?;
-        Ok(self.into_json_for_user(&query_user))
+        self.into_json_for_user(conn, &query_user)
     }
 
-    pub fn into_json_for_user(self, query_user: &QueryUser) -> JsonToken {
+    pub fn into_json_for_user(
+        self,
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+    ) -> Result<JsonToken, HttpError> {
+        let allowed_ips = Self::allowed_ips(conn, self.id)?;
         let Self {
             uuid,
             user_id,
@@ -63,22 +128,32 @@ impl QueryToken {
             jwt,
             creation,
             expiration,
+            scope,
+            project_id,
             ..
         } = self;
+        let project = if let Some(project_id) = project_id {
+            Some(QueryProject::get_uuid(conn, project_id)?)
+        } else {
+            None
+        };
         assert_parentage(
             BencherResource::User,
             query_user.id,
             BencherResource::Token,
             user_id,
         );
-        JsonToken {
+        Ok(JsonToken {
             uuid,
             user: query_user.uuid,
             name,
             token: jwt,
             creation,
             expiration,
-        }
+            scope,
+            project,
+            allowed_ips: (!allowed_ips.is_empty()).then_some(allowed_ips),
+        })
     }
 }
 
@@ -91,6 +166,8 @@ pub struct InsertToken {
     pub jwt: Jwt,
     pub creation: DateTime,
     pub expiration: DateTime,
+    pub scope: Option<TokenScope>,
+    pub project_id: Option<ProjectId>,
 }
 
 impl InsertToken {
@@ -102,11 +179,25 @@ impl InsertToken {
         token: JsonNewToken,
         auth_user: &AuthUser,
     ) -> Result<Self, HttpError> {
-        let JsonNewToken { name, ttl } = token;
+        let JsonNewToken {
+            name,
+            ttl,
+            scope,
+            project,
+            // The allowlist is inserted once the token has been created
+            allowed_ips: _,
+        } = token;
 
         let query_user = QueryUser::from_resource_id(conn, user)?;
         same_user!(auth_user, rbac, query_user.uuid);
 
+        // The token can only be limited to a project that the user can view
+        let project_id = if let Some(project) = project {
+            Some(QueryProject::is_allowed(conn, rbac, &project, auth_user, Permission::View)?.id)
+        } else {
+            None
+        };
+
         // TODO Custom max TTL
         let max_ttl = u32::MAX;
         let ttl = if let Some(ttl) = ttl {
@@ -145,19 +236,107 @@ impl InsertToken {
             jwt,
             creation: claims.issued_at(),
             expiration: claims.expiration(),
+            scope,
+            project_id,
         })
     }
 }
 
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = schema::token_allowlist)]
+pub struct InsertTokenAllowlist {
+    pub token_id: TokenId,
+    pub cidr: Cidr,
+}
+
+impl InsertTokenAllowlist {
+    pub fn insert(
+        conn: &mut DbConnection,
+        token_id: TokenId,
+        allowed_ips: Vec<Cidr>,
+    ) -> Result<(), HttpError> {
+        let insert_allowlist = allowed_ips
+            .into_iter()
+            .map(|cidr| Self { token_id, cidr })
+            .collect::<Vec<_>>();
+        diesel::insert_into(schema::token_allowlist::table)
+            .values(&insert_allowlist)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Token, insert_allowlist))?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, diesel::AsChangeset)]
 #[diesel(table_name = token_table)]
 pub struct UpdateToken {
     pub name: Option<ResourceName>,
+    pub jwt: Option<Jwt>,
+    pub creation: Option<DateTime>,
+    pub expiration: Option<DateTime>,
 }
 
-impl From<JsonUpdateToken> for UpdateToken {
-    fn from(update: JsonUpdateToken) -> Self {
-        let JsonUpdateToken { name } = update;
-        Self { name }
+impl UpdateToken {
+    pub fn from_json(
+        token_key: &TokenKey,
+        query_user: &QueryUser,
+        query_token: &QueryToken,
+        update: JsonUpdateToken,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateToken { name, rotate, ttl } = update;
+
+        if !rotate.unwrap_or_default() {
+            if ttl.is_some() {
+                return Err(bad_request_error(
+                    "The TTL for a token can only be set when rotating the token",
+                ));
+            }
+            return Ok(Self {
+                name,
+                jwt: None,
+                creation: None,
+                expiration: None,
+            });
+        }
+
+        // By default, the replacement token has the same TTL as the current token
+        let ttl = ttl.unwrap_or_else(|| {
+            u32::try_from(query_token.expiration.timestamp() - query_token.creation.timestamp())
+                .unwrap_or(u32::MAX)
+        });
+
+        let jwt = token_key
+            .new_api_key(query_user.email.clone(), ttl)
+            .map_err(|e| {
+                issue_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create replacement API key",
+                    "Failed to create replacement API key.",
+                    e,
+                )
+            })?;
+        // The same claims always produce the same token,
+        // so a token cannot be replaced by itself within the same second that it was issued.
+        if jwt == query_token.jwt {
+            return Err(bad_request_error(
+                "API token was just issued and cannot be rotated yet, please try again",
+            ));
+        }
+
+        let claims = token_key.validate_api_key(&jwt).map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to validate replacement API key",
+                &format!("Failed to validate replacement API key: {jwt}"),
+                e,
+            )
+        })?;
+
+        Ok(Self {
+            name,
+            jwt: Some(jwt),
+            creation: Some(claims.issued_at()),
+            expiration: Some(claims.expiration()),
+        })
     }
 }
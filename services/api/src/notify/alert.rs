@@ -0,0 +1,153 @@
+use bencher_json::{
+    project::{alert::AlertStatus, boundary::BoundaryLimit},
+    JsonAlert, JsonReport,
+};
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use slog::Logger;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, Body, ButtonBody, DbConnection, Message, Messenger},
+    error::resource_not_found_err,
+    model::{project::QueryProject, user::QueryUser},
+    schema,
+};
+
+/// Email all of the members of the project about the active alerts generated by the report.
+/// Members include everyone with a role in the project or its organization.
+pub async fn notify_alerts(
+    log: &Logger,
+    context: &ApiContext,
+    query_project: &QueryProject,
+    json_report: &JsonReport,
+) {
+    if !matches!(context.messenger, Messenger::Email(_)) {
+        return;
+    }
+    let alerts = json_report
+        .alerts
+        .iter()
+        .filter(|alert| matches!(alert.status, AlertStatus::Active))
+        .collect::<Vec<_>>();
+    if alerts.is_empty() {
+        return;
+    }
+
+    let members = match project_members(conn_lock!(context), query_project) {
+        Ok(members) => members,
+        Err(e) => {
+            slog::error!(log, "Failed to get members to notify of alerts: {e}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&e);
+            return;
+        },
+    };
+
+    let report_url = report_url(context, query_project, json_report);
+    let subject = format!(
+        "{count} new alert{s} in {project}",
+        count = alerts.len(),
+        s = if alerts.len() == 1 { "" } else { "s" },
+        project = query_project.name,
+    );
+    let pre_body = format!(
+        "The report for branch {branch} on testbed {testbed} generated {subject}: {alerts}",
+        branch = json_report.branch.name,
+        testbed = json_report.testbed.name,
+        subject = subject.to_lowercase(),
+        alerts = alerts
+            .iter()
+            .copied()
+            .map(alert_summary)
+            .collect::<Vec<_>>()
+            .join("; "),
+    );
+    for member in members {
+        let body = Body::Button(Box::new(ButtonBody {
+            title: subject.clone(),
+            preheader: "Click the provided link to view the alerts.".into(),
+            greeting: format!("Ahoy {}!", member.name),
+            pre_body: pre_body.clone(),
+            button_text: "View Report".into(),
+            button_url: report_url.clone(),
+            clipboard_text: "Report URL".into(),
+            clipboard_target: report_url.clone(),
+            post_body: format!(
+                "You are receiving this email because you are a member of {}.",
+                query_project.name
+            ),
+            closing: "See you soon,".into(),
+            signature: "The Bencher Team".into(),
+            settings_url: context
+                .console_url
+                .clone()
+                .join("/help")
+                .map(Into::into)
+                .unwrap_or_default(),
+        }));
+        let message = Message {
+            to_name: Some(member.name.into()),
+            to_email: member.email.into(),
+            subject: Some(subject.clone()),
+            body: Some(body),
+        };
+        context.messenger.send(log, message);
+    }
+}
+
+fn project_members(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+) -> Result<Vec<QueryUser>, HttpError> {
+    schema::user::table
+        .filter(schema::user::locked.eq(false))
+        .filter(
+            schema::user::id
+                .eq_any(
+                    schema::project_role::table
+                        .filter(schema::project_role::project_id.eq(query_project.id))
+                        .select(schema::project_role::user_id),
+                )
+                .or(schema::user::id.eq_any(
+                    schema::organization_role::table
+                        .filter(
+                            schema::organization_role::organization_id
+                                .eq(query_project.organization_id),
+                        )
+                        .select(schema::organization_role::user_id),
+                )),
+        )
+        .load::<QueryUser>(conn)
+        .map_err(resource_not_found_err!(User, query_project))
+}
+
+fn report_url(
+    context: &ApiContext,
+    query_project: &QueryProject,
+    json_report: &JsonReport,
+) -> String {
+    let console_path = if query_project.is_public() {
+        format!("/perf/{}", query_project.slug)
+    } else {
+        format!("/console/projects/{}", query_project.slug)
+    };
+    context
+        .console_url
+        .join(&format!("{console_path}/reports/{}", json_report.uuid))
+        .map_or_else(|_| context.console_url.to_string(), Into::into)
+}
+
+fn alert_summary(alert: &JsonAlert) -> String {
+    let (limit, boundary) = match alert.limit {
+        BoundaryLimit::Lower => ("lower", alert.boundary.lower_limit),
+        BoundaryLimit::Upper => ("upper", alert.boundary.upper_limit),
+    };
+    let boundary = boundary.map_or_else(|| "-".into(), |boundary| boundary.to_string());
+    format!(
+        "{benchmark} ({measure}) with a value of {value} exceeded the {limit} boundary limit of {boundary}",
+        benchmark = alert.benchmark.name,
+        measure = alert.threshold.measure.name,
+        value = alert.metric.value,
+    )
+}
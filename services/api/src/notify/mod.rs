@@ -0,0 +1,14 @@
+//! Notifications for project alerts.
+//! Emails are sent to project members using the `smtp` server config.
+//! If there is no `smtp` server config, then emails are not sent.
+//! Webhooks are sent to the URLs registered for the project,
+//! both for alerts and for report rerun requests.
+//! Requests to user configured URLs are only sent to public addresses.
+
+mod alert;
+mod public;
+mod webhook;
+
+pub use alert::notify_alerts;
+pub use public::{public_client, PublicUrlError};
+pub use webhook::{notify_rerun, notify_webhooks};
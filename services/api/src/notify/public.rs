@@ -0,0 +1,95 @@
+use std::{
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublicUrlError {
+    #[error("URL has no host")]
+    NoHost,
+    #[error("Failed to resolve host ({host}): {err}")]
+    Resolve { host: String, err: io::Error },
+    #[error("Host ({host}) resolves to a non-public address ({addr})")]
+    NonPublic { host: String, addr: IpAddr },
+    #[error("Failed to build client: {0}")]
+    Client(reqwest::Error),
+}
+
+/// Build a client that can only send requests to the public address that the URL host resolves to.
+/// This keeps user configured URLs from being used to reach the private network of the server.
+pub async fn public_client(
+    url: &url::Url,
+    timeout: Duration,
+) -> Result<reqwest::Client, PublicUrlError> {
+    let (host, addr) = resolve_public(url).await?;
+    reqwest::Client::builder()
+        .timeout(timeout)
+        // A redirect could point the request at a non-public address
+        .redirect(reqwest::redirect::Policy::none())
+        // Pin the host to the address that was checked, so it cannot be rebound to another address
+        .resolve(&host, addr)
+        .build()
+        .map_err(PublicUrlError::Client)
+}
+
+// Resolve the host and make sure that all of its addresses are public
+async fn resolve_public(url: &url::Url) -> Result<(String, SocketAddr), PublicUrlError> {
+    let host = url.host_str().ok_or(PublicUrlError::NoHost)?.to_owned();
+    let port = url.port_or_known_default().unwrap_or_default();
+    let addrs = tokio::net::lookup_host((host.trim_matches(['[', ']']), port))
+        .await
+        .map_err(|err| PublicUrlError::Resolve {
+            host: host.clone(),
+            err,
+        })?
+        .collect::<Vec<_>>();
+    if let Some(addr) = addrs.iter().find(|addr| !is_public(addr.ip())) {
+        return Err(PublicUrlError::NonPublic {
+            host,
+            addr: addr.ip(),
+        });
+    }
+    let addr = addrs
+        .first()
+        .copied()
+        .ok_or_else(|| PublicUrlError::Resolve {
+            host: host.clone(),
+            err: io::ErrorKind::NotFound.into(),
+        })?;
+    Ok((host, addr))
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => ip
+            .to_ipv4_mapped()
+            .map_or_else(|| is_public_v6(ip), is_public_v4),
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        // Shared address space (100.64.0.0/10)
+        || (a == 100 && (b & 0b1100_0000) == 64)
+        // This network (0.0.0.0/8)
+        || a == 0)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let [first, ..] = ip.segments();
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local (fc00::/7)
+        || (first & 0xfe00) == 0xfc00
+        // Link local (fe80::/10)
+        || (first & 0xffc0) == 0xfe80)
+}
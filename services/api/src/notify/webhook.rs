@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use bencher_json::{
+    project::{
+        alert::AlertStatus,
+        webhook::{
+            WEBHOOK_ALERTS_EVENT, WEBHOOK_EVENT_HEADER, WEBHOOK_RERUN_EVENT,
+            WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+        },
+    },
+    DateTime, JsonPubUser, JsonReport, JsonWebhookPayload, JsonWebhookRerunPayload, WebhookUuid,
+};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use slog::Logger;
+
+use super::public::{public_client, PublicUrlError};
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    model::project::{webhook::QueryWebhook, QueryProject},
+};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Send the active alerts generated by the report to all of the project webhooks.
+/// Webhooks are sent in the background, so they never delay or fail the report.
+pub async fn notify_webhooks(
+    log: &Logger,
+    context: &ApiContext,
+    query_project: &QueryProject,
+    json_report: &JsonReport,
+) {
+    let alerts = json_report
+        .alerts
+        .iter()
+        .filter(|alert| matches!(alert.status, AlertStatus::Active))
+        .cloned()
+        .collect::<Vec<_>>();
+    if alerts.is_empty() {
+        return;
+    }
+
+    let webhooks = match QueryWebhook::all_for_project(conn_lock!(context), query_project) {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            slog::error!(log, "Failed to get webhooks for project: {e}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&e);
+            return;
+        },
+    };
+
+    for webhook in webhooks {
+        let webhook = match webhook.decrypt(&context.encryption) {
+            Ok(webhook) => webhook,
+            Err(e) => {
+                slog::error!(log, "Failed to decrypt webhook secret: {e}");
+                continue;
+            },
+        };
+        let payload = JsonWebhookPayload {
+            webhook: webhook.uuid,
+            project: json_report.project.clone(),
+            report: json_report.uuid,
+            branch: json_report.branch.clone(),
+            testbed: json_report.testbed.clone(),
+            alerts: alerts.clone(),
+            created: DateTime::now(),
+        };
+        spawn_send(log, webhook, WEBHOOK_ALERTS_EVENT, &payload);
+    }
+}
+
+/// Send a rerun request for the report to all of the given project webhooks,
+/// so that CI systems can benchmark the same version again.
+/// Webhooks are sent in the background, so the UUIDs of the webhooks are returned immediately.
+pub fn notify_rerun(
+    log: &Logger,
+    webhooks: Vec<QueryWebhook>,
+    json_report: &JsonReport,
+    requester: &JsonPubUser,
+) -> Vec<WebhookUuid> {
+    let created = DateTime::now();
+    webhooks
+        .into_iter()
+        .map(|webhook| {
+            let uuid = webhook.uuid;
+            let payload = JsonWebhookRerunPayload {
+                webhook: uuid,
+                project: json_report.project.clone(),
+                report: json_report.uuid,
+                branch: json_report.branch.clone(),
+                testbed: json_report.testbed.clone(),
+                requester: requester.clone(),
+                created,
+            };
+            spawn_send(log, webhook, WEBHOOK_RERUN_EVENT, &payload);
+            uuid
+        })
+        .collect()
+}
+
+// The payload is serialized before spawning the task,
+// so the future only holds the owned body and is always `Send`.
+fn spawn_send<T>(log: &Logger, webhook: QueryWebhook, event: &'static str, payload: &T)
+where
+    T: Serialize,
+{
+    let body = match serde_json::to_vec(payload) {
+        Ok(body) => body,
+        Err(e) => {
+            slog::error!(
+                log,
+                "Failed to serialize {event} webhook ({}): {e}",
+                webhook.uuid
+            );
+            return;
+        },
+    };
+    let log = log.clone();
+    tokio::spawn(async move {
+        if let Err(e) = send(&webhook, event, body).await {
+            slog::warn!(
+                log,
+                "Failed to send {event} webhook ({}): {e}",
+                webhook.uuid
+            );
+        }
+    });
+}
+
+#[derive(Debug, thiserror::Error)]
+enum WebhookError {
+    #[error("Invalid webhook URL: {0}")]
+    Url(url::ParseError),
+    #[error("Invalid webhook URL: {0}")]
+    Public(PublicUrlError),
+    #[error("Invalid webhook secret: {0}")]
+    Secret(hmac::digest::InvalidLength),
+    #[error("Failed to send: {0}")]
+    Send(reqwest::Error),
+}
+
+async fn send(webhook: &QueryWebhook, event: &str, body: Vec<u8>) -> Result<(), WebhookError> {
+    let url = url::Url::parse(webhook.url.as_ref()).map_err(WebhookError::Url)?;
+    let client = public_client(&url, WEBHOOK_TIMEOUT)
+        .await
+        .map_err(WebhookError::Public)?;
+    let timestamp = DateTime::now().timestamp().to_string();
+    let signature = sign(&webhook.secret, &timestamp, &body)?;
+    client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(WEBHOOK_EVENT_HEADER, event)
+        .header(WEBHOOK_TIMESTAMP_HEADER, timestamp)
+        .header(WEBHOOK_SIGNATURE_HEADER, signature)
+        .body(body)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(WebhookError::Send)?;
+    Ok(())
+}
+
+// https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries
+fn sign(secret: &str, timestamp: &str, body: &[u8]) -> Result<String, WebhookError> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(WebhookError::Secret)?;
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
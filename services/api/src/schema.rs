@@ -6,11 +6,39 @@ diesel::table! {
         uuid -> Text,
         boundary_id -> Integer,
         boundary_limit -> Bool,
+        kind -> Integer,
+        severity -> Integer,
         status -> Integer,
         modified -> BigInt,
     }
 }
 
+diesel::table! {
+    alert_issue (id) {
+        id -> Integer,
+        alert_id -> Integer,
+        integration_id -> Integer,
+        number -> BigInt,
+        url -> Text,
+        created -> BigInt,
+        closed -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    backup (id) {
+        id -> Integer,
+        uuid -> Text,
+        operation_id -> Integer,
+        scheduled -> Bool,
+        compress -> Bool,
+        data_store -> Nullable<Integer>,
+        rm -> Bool,
+        file_name -> Nullable<Text>,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     benchmark (id) {
         id -> Integer,
@@ -24,6 +52,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    benchmark_location (id) {
+        id -> Integer,
+        benchmark_id -> Integer,
+        file -> Text,
+        line -> Nullable<Integer>,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     boundary (id) {
         id -> Integer,
@@ -48,6 +86,10 @@ diesel::table! {
         created -> BigInt,
         modified -> BigInt,
         archived -> Nullable<BigInt>,
+        merged -> Nullable<BigInt>,
+        protect_delete -> Bool,
+        protect_thresholds -> Bool,
+        protect_reports -> Bool,
     }
 }
 
@@ -70,6 +112,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    integration (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        provider -> Integer,
+        repository -> Text,
+        api_url -> Text,
+        token -> Text,
+        title -> Text,
+        body -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     measure (id) {
         id -> Integer,
@@ -107,11 +165,27 @@ diesel::table! {
         window -> Nullable<BigInt>,
         lower_boundary -> Nullable<Double>,
         upper_boundary -> Nullable<Double>,
+        lower_critical -> Nullable<Double>,
+        upper_critical -> Nullable<Double>,
         created -> BigInt,
         replaced -> Nullable<BigInt>,
     }
 }
 
+diesel::table! {
+    operation (id) {
+        id -> Integer,
+        uuid -> Text,
+        kind -> Integer,
+        state -> Integer,
+        completed -> Integer,
+        total -> Integer,
+        error -> Nullable<Text>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     organization (id) {
         id -> Integer,
@@ -222,15 +296,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    project_token (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        creator_id -> Integer,
+        name -> Text,
+        jwt -> Text,
+        scope -> Integer,
+        creation -> BigInt,
+        expiration -> BigInt,
+    }
+}
+
 diesel::table! {
     report (id) {
         id -> Integer,
         uuid -> Text,
-        user_id -> Integer,
+        user_id -> Nullable<Integer>,
         project_id -> Integer,
         head_id -> Integer,
         version_id -> Integer,
         testbed_id -> Integer,
+        environment -> Nullable<Text>,
         adapter -> Integer,
         start_time -> BigInt,
         end_time -> BigInt,
@@ -248,6 +337,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    report_attachment (id) {
+        id -> Integer,
+        uuid -> Text,
+        report_id -> Integer,
+        name -> Text,
+        content_type -> Text,
+        size -> BigInt,
+        data -> Nullable<Binary>,
+        created -> BigInt,
+    }
+}
+
+diesel::table! {
+    report_mirror (id) {
+        id -> Integer,
+        report_id -> Integer,
+        project -> Text,
+        report -> Text,
+        attempts -> Integer,
+        next_attempt -> BigInt,
+        created -> BigInt,
+    }
+}
+
+diesel::table! {
+    report_failure (id) {
+        id -> Integer,
+        report_id -> Integer,
+        exit_code -> Integer,
+        stderr -> Text,
+    }
+}
+
+diesel::table! {
+    report_raw (id) {
+        id -> Integer,
+        report_id -> Integer,
+        results -> Binary,
+    }
+}
+
 diesel::table! {
     server (id) {
         id -> Integer,
@@ -263,6 +394,7 @@ diesel::table! {
         project_id -> Integer,
         name -> Text,
         slug -> Text,
+        scale -> Double,
         created -> BigInt,
         modified -> BigInt,
         archived -> Nullable<BigInt>,
@@ -277,12 +409,54 @@ diesel::table! {
         branch_id -> Integer,
         testbed_id -> Integer,
         measure_id -> Integer,
+        benchmark -> Nullable<Text>,
         model_id -> Nullable<Integer>,
         created -> BigInt,
         modified -> BigInt,
     }
 }
 
+diesel::table! {
+    threshold_change (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        threshold_id -> Integer,
+        requester_id -> Integer,
+        approver_id -> Nullable<Integer>,
+        test -> Nullable<Integer>,
+        min_sample_size -> Nullable<BigInt>,
+        max_sample_size -> Nullable<BigInt>,
+        window -> Nullable<BigInt>,
+        lower_boundary -> Nullable<Double>,
+        upper_boundary -> Nullable<Double>,
+        lower_critical -> Nullable<Double>,
+        upper_critical -> Nullable<Double>,
+        created -> BigInt,
+        approved -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    threshold_template (id) {
+        id -> Integer,
+        uuid -> Text,
+        organization_id -> Integer,
+        project_id -> Nullable<Integer>,
+        measure -> Text,
+        test -> Integer,
+        min_sample_size -> Nullable<BigInt>,
+        max_sample_size -> Nullable<BigInt>,
+        window -> Nullable<BigInt>,
+        lower_boundary -> Nullable<Double>,
+        upper_boundary -> Nullable<Double>,
+        lower_critical -> Nullable<Double>,
+        upper_critical -> Nullable<Double>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     token (id) {
         id -> Integer,
@@ -292,6 +466,25 @@ diesel::table! {
         jwt -> Text,
         creation -> BigInt,
         expiration -> BigInt,
+        scope -> Nullable<Integer>,
+        project_id -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    token_allowlist (id) {
+        id -> Integer,
+        token_id -> Integer,
+        cidr -> Text,
+    }
+}
+
+diesel::table! {
+    unclaimed_project (id) {
+        id -> Integer,
+        project_id -> Integer,
+        key -> Text,
+        created -> BigInt,
     }
 }
 
@@ -309,6 +502,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_deletion (id) {
+        id -> Integer,
+        user_id -> Integer,
+        requester_id -> Integer,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     version (id) {
         id -> Integer,
@@ -319,13 +521,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
+diesel::joinable!(alert_issue -> alert (alert_id));
+diesel::joinable!(alert_issue -> integration (integration_id));
+diesel::joinable!(backup -> operation (operation_id));
 diesel::joinable!(benchmark -> project (project_id));
+diesel::joinable!(benchmark_location -> benchmark (benchmark_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
 diesel::joinable!(boundary -> threshold (threshold_id));
 diesel::joinable!(branch -> project (project_id));
 diesel::joinable!(head_version -> version (version_id));
+diesel::joinable!(integration -> project (project_id));
 diesel::joinable!(measure -> project (project_id));
 diesel::joinable!(metric -> measure (measure_id));
 diesel::joinable!(metric -> report_benchmark (report_benchmark_id));
@@ -343,6 +562,8 @@ diesel::joinable!(plot_testbed -> testbed (testbed_id));
 diesel::joinable!(project -> organization (organization_id));
 diesel::joinable!(project_role -> project (project_id));
 diesel::joinable!(project_role -> user (user_id));
+diesel::joinable!(project_token -> project (project_id));
+diesel::joinable!(project_token -> user (creator_id));
 diesel::joinable!(report -> head (head_id));
 diesel::joinable!(report -> project (project_id));
 diesel::joinable!(report -> testbed (testbed_id));
@@ -350,24 +571,41 @@ diesel::joinable!(report -> user (user_id));
 diesel::joinable!(report -> version (version_id));
 diesel::joinable!(report_benchmark -> benchmark (benchmark_id));
 diesel::joinable!(report_benchmark -> report (report_id));
+diesel::joinable!(report_attachment -> report (report_id));
+diesel::joinable!(report_failure -> report (report_id));
+diesel::joinable!(report_mirror -> report (report_id));
+diesel::joinable!(report_raw -> report (report_id));
 diesel::joinable!(testbed -> project (project_id));
 diesel::joinable!(threshold -> branch (branch_id));
 diesel::joinable!(threshold -> measure (measure_id));
 diesel::joinable!(threshold -> project (project_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
+diesel::joinable!(threshold_change -> project (project_id));
+diesel::joinable!(threshold_change -> threshold (threshold_id));
+diesel::joinable!(threshold_template -> organization (organization_id));
+diesel::joinable!(threshold_template -> project (project_id));
+diesel::joinable!(token -> project (project_id));
 diesel::joinable!(token -> user (user_id));
+diesel::joinable!(token_allowlist -> token (token_id));
+diesel::joinable!(unclaimed_project -> project (project_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
+    alert_issue,
+    backup,
     benchmark,
+    benchmark_location,
     boundary,
     branch,
     head,
     head_version,
+    integration,
     measure,
     metric,
     model,
+    operation,
     organization,
     organization_role,
     plan,
@@ -378,12 +616,23 @@ diesel::allow_tables_to_appear_in_same_query!(
     plot_testbed,
     project,
     project_role,
+    project_token,
     report,
+    report_attachment,
     report_benchmark,
+    report_failure,
+    report_mirror,
+    report_raw,
     server,
     testbed,
     threshold,
+    threshold_change,
+    threshold_template,
     token,
+    token_allowlist,
+    unclaimed_project,
     user,
+    user_deletion,
     version,
+    webhook,
 );
@@ -1,17 +1,20 @@
 use std::fmt;
 
-use dropshot::HttpError;
+use bencher_json::JsonPagination;
+use dropshot::{HttpError, RequestContext};
 use http::StatusCode;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::error::issue_error;
+use crate::{context::ApiContext, error::issue_error};
 
 const ALL_ORIGIN: &str = "*";
 const ALL_HEADERS: &str = "*";
 const PUB_HEADERS: &str = "Content-Type";
 const AUTH_HEADERS: &str = "Content-Type, Authorization";
-const EXPOSE_HEADERS: &str = "X-Total-Count";
+const EXPOSE_HEADERS: &str = "X-Total-Count, Link";
+const PAGE_PARAM: &str = "page";
+const PER_PAGE_PARAM: &str = "per_page";
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
@@ -20,6 +23,8 @@ pub struct CorsHeaders {
     pub access_control_allow_methods: String,
     pub access_control_allow_headers: String,
     pub access_control_expose_headers: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
     pub x_total_count: String,
 }
 
@@ -76,12 +81,20 @@ impl CorsHeaders {
         )
     }
 
+    /// Add a `Link` header with the first, previous, next, and last pages
+    #[must_use]
+    pub fn with_page_links(mut self, page_links: &PageLinks, total_count: TotalCount) -> Self {
+        self.link = Some(page_links.link(total_count));
+        self
+    }
+
     fn new_origin_all(methods: String, headers: String, total_count: Option<TotalCount>) -> Self {
         CorsHeaders {
             access_control_allow_origin: ALL_ORIGIN.to_owned(),
             access_control_allow_methods: methods,
             access_control_allow_headers: headers,
             access_control_expose_headers: EXPOSE_HEADERS.to_owned(),
+            link: None,
             x_total_count: total_count.unwrap_or(TotalCount::ONE).to_string(),
         }
     }
@@ -127,3 +140,64 @@ impl TotalCount {
     pub const ZERO: Self = TotalCount(0);
     pub const ONE: Self = TotalCount(1);
 }
+
+/// The request path and query for a paginated list endpoint,
+/// used to generate an RFC 8288 `Link` header for the response.
+/// <https://datatracker.ietf.org/doc/html/rfc8288>
+#[derive(Debug, Clone)]
+pub struct PageLinks {
+    path: String,
+    query: Vec<(String, String)>,
+    page: u32,
+    per_page: u8,
+}
+
+impl PageLinks {
+    pub fn new<S>(rqctx: &RequestContext<ApiContext>, pagination: &JsonPagination<S>) -> Self {
+        let uri = rqctx.request.uri();
+        // All other query parameters, such as filters and sorting, are kept for every link
+        let query = uri
+            .query()
+            .and_then(|query| serde_urlencoded::from_str::<Vec<(String, String)>>(query).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(key, _)| key != PAGE_PARAM && key != PER_PAGE_PARAM)
+            .collect();
+        Self {
+            path: uri.path().to_owned(),
+            query,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        }
+    }
+
+    fn link(&self, total_count: TotalCount) -> String {
+        let per_page = u32::from(self.per_page).max(1);
+        let last = total_count.0.div_ceil(per_page).max(1);
+        let mut links = vec![self.page_link(1, "first")];
+        if self.page > 1 {
+            links.push(self.page_link((self.page - 1).min(last), "prev"));
+        }
+        if self.page < last {
+            links.push(self.page_link(self.page + 1, "next"));
+        }
+        links.push(self.page_link(last, "last"));
+        links.join(", ")
+    }
+
+    fn page_link(&self, page: u32, rel: &str) -> String {
+        let page = page.to_string();
+        let per_page = self.per_page.to_string();
+        let query = self
+            .query
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .chain([
+                (PAGE_PARAM, page.as_str()),
+                (PER_PAGE_PARAM, per_page.as_str()),
+            ])
+            .collect::<Vec<_>>();
+        let query = serde_urlencoded::to_string(query).unwrap_or_default();
+        format!("<{}?{query}>; rel=\"{rel}\"", self.path)
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use dropshot::{
+    ApiEndpointBodyContentType, Body, ExclusiveExtractor, ExtractorMetadata, HttpError,
+    RequestContext, ServerContext, TypedBody,
+};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+use crate::error::{bad_request_error, payload_too_large_error};
+
+/// A JSON request body that may be larger than the server-wide `request_body_max_bytes`.
+/// Dropshot only supports a single body size limit for the whole server,
+/// so the body is not read until `into_inner` is called with the limit for the endpoint.
+/// This keeps the limit small for most endpoints while allowing large reports.
+pub struct LargeBody<T> {
+    body: Body,
+    _type: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> LargeBody<T>
+where
+    T: DeserializeOwned,
+{
+    pub async fn into_inner(self, max_bytes: usize) -> Result<T, HttpError> {
+        let bytes = Limited::new(self.body, max_bytes)
+            .collect()
+            .await
+            .map_err(|e| {
+                if e.downcast_ref::<LengthLimitError>().is_some() {
+                    payload_too_large_error(format!(
+                        "Request body is larger than the maximum size of {max_bytes} bytes"
+                    ))
+                } else {
+                    bad_request_error(format!("Failed to read request body: {e}"))
+                }
+            })?
+            .to_bytes();
+        serde_json::from_slice(&bytes)
+            .map_err(|e| bad_request_error(format!("Failed to parse JSON request body: {e}")))
+    }
+}
+
+#[async_trait]
+impl<T> ExclusiveExtractor for LargeBody<T>
+where
+    T: JsonSchema + DeserializeOwned + Send + Sync + 'static,
+{
+    async fn from_request<Context: ServerContext>(
+        _rqctx: &RequestContext<Context>,
+        request: http::Request<Body>,
+    ) -> Result<Self, HttpError> {
+        Ok(Self {
+            body: request.into_body(),
+            _type: std::marker::PhantomData,
+        })
+    }
+
+    // The body is documented exactly the same as a `TypedBody`
+    fn metadata(body_content_type: ApiEndpointBodyContentType) -> ExtractorMetadata {
+        TypedBody::<T>::metadata(body_content_type)
+    }
+}
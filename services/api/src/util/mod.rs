@@ -1,5 +1,6 @@
 pub mod fn_get;
 pub mod headers;
+pub mod large_body;
 pub mod name_id;
 pub mod resource_id;
 pub mod search;
@@ -62,6 +62,37 @@ macro_rules! fn_from_name_id {
 
 pub(crate) use fn_from_name_id;
 
+macro_rules! fn_from_name_or_slug {
+    ($table:ident, $resource:ident) => {
+        /// Get the resource in the project with either the same name or slug, if one exists
+        #[allow(unused_qualifications)]
+        pub fn from_name_or_slug(
+            conn: &mut crate::context::DbConnection,
+            project_id: crate::model::project::ProjectId,
+            name: &str,
+            slug: &bencher_json::Slug,
+        ) -> Result<Option<Self>, HttpError> {
+            use diesel::{BoolExpressionMethods, OptionalExtension};
+
+            schema::$table::table
+                .filter(schema::$table::project_id.eq(project_id))
+                .filter(
+                    schema::$table::name
+                        .eq(name)
+                        .or(schema::$table::slug.eq(slug.as_ref())),
+                )
+                .first::<Self>(conn)
+                .optional()
+                .map_err(crate::error::resource_not_found_err!(
+                    $resource,
+                    (project_id, name, slug)
+                ))
+        }
+    };
+}
+
+pub(crate) use fn_from_name_or_slug;
+
 macro_rules! filter_name_id {
     ($name:ident, $query:ident, $table:ident, $name_id:ident) => {
         #[allow(unused_qualifications)]
@@ -1,8 +1,12 @@
 use bencher_json::Slug;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use dropshot::HttpError;
 
 use crate::{context::DbConnection, model::project::ProjectId};
 
+/// The maximum number of attempts to insert a resource with a unique slug
+pub const MAX_SLUG_ATTEMPTS: usize = 8;
+
 pub type SlugExistsFn = dyn FnOnce(&mut DbConnection, Option<ProjectId>, &str) -> bool;
 
 pub fn validate_slug<S>(
@@ -45,8 +49,12 @@ macro_rules! ok_slug {
             $name,
             $slug,
             Box::new(|conn, project_id, slug| {
+                // Without a project ID, assume the slug is taken so a unique one is generated
+                let Some(project_id) = project_id else {
+                    return true;
+                };
                 schema::$table::table
-                    .filter(schema::$table::project_id.eq(project_id.expect("Missing Project ID")))
+                    .filter(schema::$table::project_id.eq(project_id))
                     .filter(schema::$table::slug.eq(slug))
                     .first::<$query>(conn)
                     .is_ok()
@@ -56,3 +64,53 @@ macro_rules! ok_slug {
 }
 
 pub(crate) use ok_slug;
+
+/// Whether the error is due to the slug already being in use.
+/// `SQLite` reports unique constraint violations as `UNIQUE constraint failed: <table>.<column>, ...`
+/// with the slug always being the last column for both global and per project slugs.
+pub fn is_slug_conflict(error: &DieselError) -> bool {
+    matches!(
+        error,
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.message().rsplit_once('.').is_some_and(|(_, column)| column == "slug")
+    )
+}
+
+/// Insert a new resource, retrying with a random suffix added to its slug
+/// if the slug is already in use.
+/// Checking that a slug is available before inserting (`ok_slug`) is not enough on its own,
+/// as a concurrent insert may take the slug in between the check and the insert.
+/// An upsert inserts the resource only once with its preferred slug,
+/// so a concurrent upsert of the same resource is a conflict instead of a duplicate.
+macro_rules! insert_with_slug {
+    ($conn:expr, $table:ident, $insert:ident, upsert = $upsert:expr) => {{
+        if $upsert {
+            diesel::insert_into(schema::$table::table)
+                .values(&$insert)
+                .execute($conn)
+        } else {
+            crate::util::slug::insert_with_slug!($conn, $table, $insert)
+        }
+    }};
+    ($conn:expr, $table:ident, $insert:ident) => {{
+        let base_slug = $insert.slug.clone();
+        let mut attempt = 1;
+        loop {
+            match diesel::insert_into(schema::$table::table)
+                .values(&$insert)
+                .execute($conn)
+            {
+                Err(e)
+                    if attempt < crate::util::slug::MAX_SLUG_ATTEMPTS
+                        && crate::util::slug::is_slug_conflict(&e) =>
+                {
+                    $insert.slug = base_slug.clone().with_rand_suffix();
+                    attempt += 1;
+                },
+                result => break result,
+            }
+        }
+    }};
+}
+
+pub(crate) use insert_with_slug;
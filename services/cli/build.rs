@@ -0,0 +1,37 @@
+use std::process::Command;
+
+// Embed build info for `bencher --version --verbose`
+fn main() {
+    println!("cargo:rerun-if-env-changed=BENCHER_CLI_COMMIT");
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs/heads");
+
+    // Release builds set the commit explicitly, falling back to the local git repository
+    let commit = std::env::var("BENCHER_CLI_COMMIT")
+        .ok()
+        .or_else(|| command_output("git", &["rev-parse", "HEAD"]))
+        .unwrap_or_else(|| "unknown".into());
+    println!("cargo:rustc-env=BENCHER_CLI_COMMIT={commit}");
+
+    let commit_date = command_output("git", &["show", "-s", "--format=%cs", &commit])
+        .unwrap_or_else(|| "unknown".into());
+    println!("cargo:rustc-env=BENCHER_CLI_COMMIT_DATE={commit_date}");
+
+    let target = std::env::var("TARGET").unwrap_or_else(|_| "unknown".into());
+    println!("cargo:rustc-env=BENCHER_CLI_TARGET={target}");
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let rustc_version = command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".into());
+    println!("cargo:rustc-env=BENCHER_CLI_RUSTC={rustc_version}");
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8(output.stdout).ok())
+        .flatten()
+        .map(|stdout| stdout.trim().to_owned())
+        .filter(|stdout| !stdout.is_empty())
+}
@@ -1,6 +1,9 @@
 use std::{fmt, ops::Deref};
 
-use bencher_json::{JsonApiVersion, JsonConsole, Jwt, BENCHER_API_URL, BENCHER_URL};
+use bencher_json::{
+    system::version::is_older_version, JsonApiVersion, JsonAttachments, JsonConsole, Jwt,
+    ReportUuid, ResourceId, BENCHER_API_URL, BENCHER_URL,
+};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{cli_eprintln_quietable, parser::CliBackend, CLI_VERSION};
@@ -30,6 +33,8 @@ pub enum BackendError {
     NoToken,
     #[error("Failed to get API server version: {0}")]
     ApiVersion(bencher_client::ClientError),
+    #[error("{0}\nThis error is only raised in `--strict` mode.")]
+    UnsupportedVersion(Box<VersionMismatch>),
     #[error("{err}\nHint: This may be due to a version mismatch. {mismatch}")]
     ClientMismatch {
         mismatch: Box<VersionMismatch>,
@@ -41,6 +46,22 @@ pub enum BackendError {
     BadConsoleUrl(bencher_json::ValidError),
 }
 
+impl BackendError {
+    /// Whether sending to the API server may succeed if tried again later
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::ApiVersion(err) | Self::Client(err) | Self::ClientMismatch { err, .. } => {
+                err.is_transient()
+            },
+            Self::ParseHost(_)
+            | Self::ParseToken(_)
+            | Self::NoToken
+            | Self::UnsupportedVersion(_)
+            | Self::BadConsoleUrl(_) => false,
+        }
+    }
+}
+
 impl TryFrom<CliBackend> for PubBackend {
     type Error = BackendError;
 
@@ -123,6 +144,15 @@ impl AuthBackend {
 }
 
 impl Backend {
+    pub fn log(mut self, log: bool) -> Self {
+        self.client.log = log;
+        self
+    }
+
+    pub fn host(&self) -> &url::Url {
+        &self.client.host
+    }
+
     pub async fn send<F, R, T, E>(&self, sender: F) -> Result<serde_json::Value, BackendError>
     where
         F: Fn(bencher_client::Client) -> R,
@@ -175,6 +205,20 @@ impl Backend {
         })
     }
 
+    pub async fn upload_attachment(
+        &self,
+        project: &ResourceId,
+        report: ReportUuid,
+        file_name: String,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<JsonAttachments, BackendError> {
+        self.client
+            .upload_attachment(project, report, file_name, content_type, data)
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn check_version(&self) -> Result<Option<VersionMismatch>, BackendError> {
         let json_api_version: JsonApiVersion = self
             .client
@@ -185,12 +229,24 @@ impl Backend {
             .send_with(|client| async move { client.server_version_get().send().await })
             .await
             .map_err(BackendError::ApiVersion)?;
-        let api_version = json_api_version.version;
-        let mismatch = VersionMismatch::check(&self.client.host, api_version);
-        if let Some(mismatch) = &mismatch {
+        let mismatch = VersionMismatch::check(&self.client.host, json_api_version);
+        if let Some(mismatch) = mismatch {
+            if self.client.strict && mismatch.is_unsupported() {
+                return Err(BackendError::UnsupportedVersion(Box::new(mismatch)));
+            }
             cli_eprintln_quietable!(self.client.log, "Warning: {mismatch}",);
+            Ok(Some(mismatch))
+        } else {
+            Ok(None)
         }
-        Ok(mismatch)
+    }
+
+    pub async fn ping(&self) -> Result<(), BackendError> {
+        self.client
+            .send(|client| async move { client.server_root_get().send().await })
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
     }
 
     pub async fn get_console_url(&self) -> Result<url::Url, BackendError> {
@@ -213,10 +269,22 @@ pub struct VersionMismatch {
     pub host: url::Url,
     pub api_version: String,
     pub cli_version: String,
+    pub min_cli_version: Option<String>,
 }
 
 impl fmt::Display for VersionMismatch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(min_cli_version) = self
+            .min_cli_version
+            .as_ref()
+            .filter(|_| self.is_unsupported())
+        {
+            return write!(
+                f,
+                "This CLI version {} is older than the minimum version supported by the Bencher API server ({min_cli_version}). The Bencher API server version is {}.\nYou must upgrade the Bencher CLI to avoid incompatible requests.",
+                self.cli_version, self.api_version,
+            );
+        }
         write!(
             f,
             "The Bencher API server version is {}, but this CLI version is {}.\n{}",
@@ -232,11 +300,25 @@ impl fmt::Display for VersionMismatch {
 }
 
 impl VersionMismatch {
-    pub fn check(host: &url::Url, api_version: String) -> Option<Self> {
+    pub fn check(host: &url::Url, json_api_version: JsonApiVersion) -> Option<Self> {
+        let JsonApiVersion {
+            version: api_version,
+            min_client_version: min_cli_version,
+            ..
+        } = json_api_version;
         (api_version != CLI_VERSION).then(|| Self {
             host: host.clone(),
             api_version,
             cli_version: CLI_VERSION.into(),
+            min_cli_version,
         })
     }
+
+    /// Whether this CLI version is older than the minimum version supported by the API server
+    pub fn is_unsupported(&self) -> bool {
+        self.min_cli_version
+            .as_ref()
+            .and_then(|min_cli_version| is_older_version(&self.cli_version, min_cli_version))
+            .unwrap_or_default()
+    }
 }
@@ -1,36 +1,73 @@
-use clap::Parser;
+use std::time::Instant;
 
-use crate::{parser::CliBencher, CliError};
+use clap::{error::ErrorKind, CommandFactory, FromArgMatches};
+
+use crate::{
+    parser::{CliBencher, CliSub},
+    CliError,
+};
 
 pub mod backend;
+pub mod spool;
 pub mod sub;
+pub mod table;
+pub mod telemetry;
+pub mod terminal;
 
 pub use backend::BackendError;
-use sub::{Sub, SubCmd};
+pub use spool::SpoolError;
+use sub::{Sub, SubCmd, Version};
+pub use table::TableError;
+use telemetry::Telemetry;
+pub use telemetry::TelemetryError;
+use terminal::Terminal;
 
 #[derive(Debug)]
 pub struct Bencher {
     sub: Sub,
-}
-
-impl TryFrom<CliBencher> for Bencher {
-    type Error = CliError;
-
-    fn try_from(bencher: CliBencher) -> Result<Self, Self::Error> {
-        Ok(Self {
-            sub: bencher.sub.try_into()?,
-        })
-    }
+    telemetry: Telemetry,
 }
 
 impl Bencher {
     pub fn new() -> Result<Self, CliError> {
-        CliBencher::parse().try_into()
+        let matches = CliBencher::command().get_matches();
+        let bencher = CliBencher::from_arg_matches(&matches)
+            .unwrap_or_else(|err| err.format(&mut CliBencher::command()).exit());
+        if let Err(err) = bencher.validate() {
+            err.exit();
+        }
+        Terminal::init(bencher.color);
+        let telemetry = |adapter| Telemetry::new(&matches, adapter);
+        if bencher.version {
+            return Ok(Self {
+                sub: Sub::Version(Version {
+                    verbose: bencher.verbose,
+                }),
+                telemetry: telemetry(None),
+            });
+        }
+        let Some(sub) = bencher.sub else {
+            CliBencher::command()
+                .error(ErrorKind::MissingSubcommand, "A subcommand is required")
+                .exit();
+        };
+        let adapter = if let CliSub::Run(run) = &sub {
+            Some(run.adapter.clone().into())
+        } else {
+            None
+        };
+        Ok(Self {
+            sub: sub.try_into()?,
+            telemetry: telemetry(adapter),
+        })
     }
 }
 
 impl SubCmd for Bencher {
     async fn exec(&self) -> Result<(), CliError> {
-        self.sub.exec().await
+        let start = Instant::now();
+        let result = self.sub.exec().await;
+        self.telemetry.record(start, result.as_ref().err()).await;
+        result
     }
 }
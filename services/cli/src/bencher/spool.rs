@@ -0,0 +1,117 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bencher_client::types::JsonNewReport;
+use bencher_json::{DateTime, ResourceId};
+use serde::{Deserialize, Serialize};
+
+use super::telemetry::config_dir;
+
+const SPOOL_DIR: &str = "spool";
+const SPOOL_EXTENSION: &str = "json";
+
+#[derive(thiserror::Error, Debug)]
+pub enum SpoolError {
+    #[error("Failed to find a config directory for the report spool. Set `BENCHER_CONFIG_DIR` to use a custom directory.")]
+    NoConfigDir,
+    #[error("Failed to create report spool directory ({}): {err}", path.display())]
+    CreateDir { path: PathBuf, err: std::io::Error },
+    #[error("Failed to read report spool directory ({}): {err}", path.display())]
+    ReadDir { path: PathBuf, err: std::io::Error },
+    #[error("Failed to serialize spooled report: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to write spooled report ({}): {err}", path.display())]
+    Write { path: PathBuf, err: std::io::Error },
+    #[error("Failed to read spooled report ({}): {err}", path.display())]
+    Read { path: PathBuf, err: std::io::Error },
+    #[error("Failed to parse spooled report ({}): {err}", path.display())]
+    Parse {
+        path: PathBuf,
+        err: serde_json::Error,
+    },
+    #[error("Failed to send {0} spooled report(s). They will remain in the spool directory until they are sent.")]
+    Flush(usize),
+    #[error("Failed to remove spooled report ({}): {err}", path.display())]
+    Remove { path: PathBuf, err: std::io::Error },
+}
+
+/// A report that failed to send because the API server was unavailable.
+/// It is saved to the spool directory until it is sent with `bencher report flush`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpooledReport {
+    pub project: ResourceId,
+    pub report: JsonNewReport,
+}
+
+/// The report spool directory, ie `~/.config/bencher/spool`
+pub fn spool_dir() -> Result<PathBuf, SpoolError> {
+    config_dir()
+        .map(|config_dir| config_dir.join(SPOOL_DIR))
+        .ok_or(SpoolError::NoConfigDir)
+}
+
+impl SpooledReport {
+    /// Save the report to the spool directory, returning the path to the spooled report
+    pub fn spool(&self) -> Result<PathBuf, SpoolError> {
+        let spool_dir = spool_dir()?;
+        fs::create_dir_all(&spool_dir).map_err(|err| SpoolError::CreateDir {
+            path: spool_dir.clone(),
+            err,
+        })?;
+        let report = serde_json::to_string_pretty(self).map_err(SpoolError::Serialize)?;
+        // Prefix with the timestamp so spooled reports are sent in the order they were created
+        let timestamp = DateTime::now().into_inner().timestamp_millis();
+        let mut path = spool_dir.join(format!("{timestamp}.{SPOOL_EXTENSION}"));
+        let mut count = 0;
+        while path.exists() {
+            count += 1;
+            path = spool_dir.join(format!("{timestamp}-{count}.{SPOOL_EXTENSION}"));
+        }
+        fs::write(&path, report).map_err(|err| SpoolError::Write {
+            path: path.clone(),
+            err,
+        })?;
+        Ok(path)
+    }
+
+    /// List the paths of all spooled reports, oldest first
+    pub fn list() -> Result<Vec<PathBuf>, SpoolError> {
+        let spool_dir = spool_dir()?;
+        if !spool_dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut paths = fs::read_dir(&spool_dir)
+            .map_err(|err| SpoolError::ReadDir {
+                path: spool_dir.clone(),
+                err,
+            })?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == SPOOL_EXTENSION)
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        Ok(paths)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, SpoolError> {
+        let report = fs::read_to_string(path).map_err(|err| SpoolError::Read {
+            path: path.to_path_buf(),
+            err,
+        })?;
+        serde_json::from_str(&report).map_err(|err| SpoolError::Parse {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    pub fn remove(path: &Path) -> Result<(), SpoolError> {
+        fs::remove_file(path).map_err(|err| SpoolError::Remove {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+}
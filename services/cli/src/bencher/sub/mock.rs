@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use bencher_adapter::{results::adapter_metrics::AdapterMetrics, AdapterResults};
 use bencher_json::{JsonNewMetric, NameId};
@@ -77,10 +77,10 @@ impl Mock {
         let count = self.count.unwrap_or(DEFAULT_COUNT);
         let pow = self.pow.unwrap_or(1);
         let ten_pow = 10.0f64.powi(pow);
-        let mut results = HashMap::with_capacity(count);
+        let mut results = BTreeMap::new();
         let mut rng = rand::thread_rng();
         for c in 0..count {
-            let mut measures_map = HashMap::with_capacity(self.measures.len());
+            let mut measures_map = BTreeMap::new();
             for measure in self.measures.clone() {
                 let low = ten_pow * c as f64;
                 let high = ten_pow * (c + 1) as f64;
@@ -1,16 +1,24 @@
 use crate::{parser::CliSub, CliError};
 
+#[cfg(feature = "docker")]
 mod docker;
+#[cfg(feature = "mock")]
 mod mock;
 mod organization;
 mod project;
 mod sub_cmd;
 mod system;
+mod telemetry;
 mod user;
+mod version;
 
+#[cfg(feature = "docker")]
 pub use docker::DockerError;
+#[cfg(feature = "docker")]
 use docker::{down::Down, logs::Logs, up::Up};
+#[cfg(feature = "mock")]
 use mock::Mock;
+#[cfg(feature = "mock")]
 pub use mock::MockError;
 use organization::{member::Member, organization::Organization};
 use project::{
@@ -30,20 +38,28 @@ use project::{
 };
 pub use project::{
     archive::ArchiveError,
+    report::ReportError,
     run::{runner::output::Output, thresholds::ThresholdsError, RunError},
     threshold::ThresholdError,
 };
 pub use sub_cmd::SubCmd;
+pub use system::server::SelfTestError;
 use system::{auth::Auth, server::Server};
+use telemetry::Telemetry;
 use user::{token::Token, user::User};
+pub use version::Version;
 
 #[derive(Debug)]
 pub enum Sub {
     Run(Box<Run>),
+    #[cfg(feature = "mock")]
     Mock(Mock),
     Archive(Archive),
+    #[cfg(feature = "docker")]
     Up(Up),
+    #[cfg(feature = "docker")]
     Logs(Logs),
+    #[cfg(feature = "docker")]
     Down(Down),
     Organization(Organization),
     Member(Member),
@@ -64,6 +80,8 @@ pub enum Sub {
     Token(Token),
     Server(Server),
     Auth(Auth),
+    Telemetry(Telemetry),
+    Version(Version),
 }
 
 impl TryFrom<CliSub> for Sub {
@@ -72,6 +90,7 @@ impl TryFrom<CliSub> for Sub {
     fn try_from(sub: CliSub) -> Result<Self, Self::Error> {
         Ok(match sub {
             CliSub::Run(run) => Self::Run(Box::new((*run).try_into()?)),
+            #[cfg(feature = "mock")]
             CliSub::Mock(mock) => Self::Mock(mock.into()),
             CliSub::Archive(archive) => {
                 Self::Archive((archive, ArchiveAction::Archive).try_into()?)
@@ -79,8 +98,11 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Unarchive(unarchive) => {
                 Self::Archive((unarchive, ArchiveAction::Unarchive).try_into()?)
             },
+            #[cfg(feature = "docker")]
             CliSub::Up(up) => Self::Up(up.into()),
+            #[cfg(feature = "docker")]
             CliSub::Logs(logs) => Self::Logs(logs.into()),
+            #[cfg(feature = "docker")]
             CliSub::Down(down) => Self::Down(down.into()),
             CliSub::Organization(organization) => Self::Organization(organization.try_into()?),
             CliSub::Member(member) => Self::Member(member.try_into()?),
@@ -101,6 +123,7 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
             CliSub::Auth(auth) => Self::Auth(auth.try_into()?),
+            CliSub::Telemetry(telemetry) => Self::Telemetry(telemetry.into()),
         })
     }
 }
@@ -109,10 +132,14 @@ impl SubCmd for Sub {
     async fn exec(&self) -> Result<(), CliError> {
         match self {
             Self::Run(run) => run.exec().await,
+            #[cfg(feature = "mock")]
             Self::Mock(mock) => mock.exec().await,
             Self::Archive(archive) => archive.exec().await,
+            #[cfg(feature = "docker")]
             Self::Up(up) => up.exec().await,
+            #[cfg(feature = "docker")]
             Self::Logs(logs) => logs.exec().await,
+            #[cfg(feature = "docker")]
             Self::Down(down) => down.exec().await,
             Self::Organization(organization) => organization.exec().await,
             Self::Member(member) => member.exec().await,
@@ -133,6 +160,8 @@ impl SubCmd for Sub {
             Self::Token(token) => token.exec().await,
             Self::Server(server) => server.exec().await,
             Self::Auth(auth) => auth.exec().await,
+            Self::Telemetry(telemetry) => telemetry.exec().await,
+            Self::Version(version) => version.exec().await,
         }
     }
 }
@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, OrgMembersSort};
 use bencher_json::{ResourceId, UserName};
 
 use crate::{
-    bencher::{backend::AuthBackend, sub::SubCmd},
+    bencher::{backend::AuthBackend, sub::SubCmd, table::TableOutput},
     parser::{
         organization::member::{CliMemberList, CliMembersSort},
         CliPagination,
@@ -16,6 +16,7 @@ pub struct List {
     pub name: Option<UserName>,
     pub search: Option<String>,
     pub pagination: Pagination,
+    pub table: Option<TableOutput>,
     pub backend: AuthBackend,
 }
 
@@ -36,14 +37,18 @@ impl TryFrom<CliMemberList> for List {
             name,
             search,
             pagination,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = AuthBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             organization,
             name,
             search,
             pagination: pagination.into(),
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -69,7 +74,7 @@ impl From<CliPagination<CliMembersSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client
@@ -96,6 +101,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -1,7 +1,7 @@
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::AuthBackend, sub::SubCmd},
+    bencher::{backend::AuthBackend, sub::SubCmd, table::TableOutput},
     parser::organization::member::CliMemberView,
     CliError,
 };
@@ -10,6 +10,7 @@ use crate::{
 pub struct View {
     pub organization: ResourceId,
     pub user: ResourceId,
+    pub table: Option<TableOutput>,
     pub backend: AuthBackend,
 }
 
@@ -20,19 +21,23 @@ impl TryFrom<CliMemberView> for View {
         let CliMemberView {
             organization,
             user,
+            table,
             backend,
         } = view;
+        let table = TableOutput::new(table);
+        let backend = AuthBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             organization,
             user,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 client
@@ -43,6 +48,9 @@ impl SubCmd for View {
                     .await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
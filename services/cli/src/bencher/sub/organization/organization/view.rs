@@ -1,7 +1,7 @@
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::AuthBackend, sub::SubCmd},
+    bencher::{backend::AuthBackend, sub::SubCmd, table::TableOutput},
     parser::organization::CliOrganizationView,
     CliError,
 };
@@ -9,6 +9,7 @@ use crate::{
 #[derive(Debug)]
 pub struct View {
     pub organization: ResourceId,
+    pub table: Option<TableOutput>,
     pub backend: AuthBackend,
 }
 
@@ -18,18 +19,22 @@ impl TryFrom<CliOrganizationView> for View {
     fn try_from(view: CliOrganizationView) -> Result<Self, Self::Error> {
         let CliOrganizationView {
             organization,
+            table,
             backend,
         } = view;
+        let table = TableOutput::new(table);
+        let backend = AuthBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             organization,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 client
@@ -39,6 +44,9 @@ impl SubCmd for View {
                     .await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -1,10 +1,10 @@
-use bencher_client::types::{AlertStatus, JsonDirection, ProjAlertsSort};
+use bencher_client::types::{AlertKind, AlertStatus, JsonDirection, ProjAlertsSort};
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
-        project::alert::{CliAlertList, CliAlertStatus, CliAlertsSort},
+        project::alert::{CliAlertKind, CliAlertList, CliAlertStatus, CliAlertsSort},
         CliPagination,
     },
     CliError,
@@ -15,7 +15,9 @@ pub struct List {
     pub project: ResourceId,
     pub pagination: Pagination,
     pub status: Option<AlertStatus>,
+    pub kind: Option<AlertKind>,
     pub archived: bool,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -35,15 +37,21 @@ impl TryFrom<CliAlertList> for List {
             project,
             pagination,
             status,
+            kind,
             archived,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             pagination: pagination.into(),
             status: status.map(Into::into),
+            kind: kind.map(Into::into),
             archived,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -78,9 +86,18 @@ impl From<CliAlertStatus> for AlertStatus {
     }
 }
 
+impl From<CliAlertKind> for AlertKind {
+    fn from(kind: CliAlertKind) -> Self {
+        match kind {
+            CliAlertKind::Limit => Self::Limit,
+            CliAlertKind::Drift => Self::Drift,
+        }
+    }
+}
+
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_alerts_get().project(self.project.clone());
@@ -99,12 +116,18 @@ impl SubCmd for List {
                 if let Some(status) = self.status {
                     client = client.status(status);
                 }
+                if let Some(kind) = self.kind {
+                    client = client.kind(kind);
+                }
                 if self.archived {
                     client = client.archived(self.archived);
                 }
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
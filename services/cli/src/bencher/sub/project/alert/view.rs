@@ -1,7 +1,7 @@
 use bencher_json::{AlertUuid, ResourceId};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::project::alert::CliAlertView,
     CliError,
 };
@@ -10,6 +10,7 @@ use crate::{
 pub struct View {
     pub project: ResourceId,
     pub alert: AlertUuid,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -20,19 +21,23 @@ impl TryFrom<CliAlertView> for View {
         let CliAlertView {
             project,
             alert,
+            table,
             backend,
         } = view;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             alert,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 client
@@ -43,6 +48,9 @@ impl SubCmd for View {
                     .await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -116,6 +116,7 @@ impl Dimension {
             slug: None,
             start_point: None,
             archived: Some(action.into()),
+            protection: None,
         };
         backend
             .send(|client| async move {
@@ -174,6 +175,7 @@ impl Dimension {
         let update = &JsonUpdateTestbed {
             name: None,
             slug: None,
+            scale: None,
             archived: Some(action.into()),
         };
         backend
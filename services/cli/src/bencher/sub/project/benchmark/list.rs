@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, ProjBenchmarksSort};
 use bencher_json::{BenchmarkName, ResourceId};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::benchmark::{CliBenchmarkList, CliBenchmarksSort},
         CliPagination,
@@ -17,6 +17,11 @@ pub struct List {
     pub search: Option<String>,
     pub pagination: Pagination,
     pub archived: bool,
+    pub with_latest: bool,
+    pub branch: Option<ResourceId>,
+    pub testbed: Option<ResourceId>,
+    pub measure: Option<ResourceId>,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -38,15 +43,27 @@ impl TryFrom<CliBenchmarkList> for List {
             search,
             pagination,
             archived,
+            with_latest,
+            branch,
+            testbed,
+            measure,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             name,
             search,
             pagination: pagination.into(),
             archived,
-            backend: backend.try_into()?,
+            with_latest,
+            branch,
+            testbed,
+            measure,
+            table,
+            backend,
         })
     }
 }
@@ -62,6 +79,8 @@ impl From<CliPagination<CliBenchmarksSort>> for Pagination {
         Self {
             sort: sort.map(|sort| match sort {
                 CliBenchmarksSort::Name => ProjBenchmarksSort::Name,
+                CliBenchmarksSort::Created => ProjBenchmarksSort::Created,
+                CliBenchmarksSort::Latest => ProjBenchmarksSort::Latest,
             }),
             direction: direction.map(Into::into),
             page,
@@ -72,7 +91,7 @@ impl From<CliPagination<CliBenchmarksSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_benchmarks_get().project(self.project.clone());
@@ -97,9 +116,24 @@ impl SubCmd for List {
                 if self.archived {
                     client = client.archived(self.archived);
                 }
+                if self.with_latest {
+                    client = client.with_latest(self.with_latest);
+                }
+                if let Some(branch) = self.branch.clone() {
+                    client = client.branch(branch);
+                }
+                if let Some(testbed) = self.testbed.clone() {
+                    client = client.testbed(testbed);
+                }
+                if let Some(measure) = self.measure.clone() {
+                    client = client.measure(measure);
+                }
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
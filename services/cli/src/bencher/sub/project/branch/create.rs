@@ -16,6 +16,7 @@ pub struct Create {
     pub start_point_hash: Option<GitHash>,
     pub start_point_max_versions: u32,
     pub start_point_clone_thresholds: bool,
+    pub upsert: bool,
     pub backend: AuthBackend,
 }
 
@@ -28,6 +29,7 @@ impl TryFrom<CliBranchCreate> for Create {
             name,
             slug,
             start_point,
+            upsert,
             backend,
         } = create;
         let CliStartPointCreate {
@@ -44,6 +46,7 @@ impl TryFrom<CliBranchCreate> for Create {
             start_point_hash,
             start_point_max_versions,
             start_point_clone_thresholds,
+            upsert,
             backend: backend.try_into()?,
         })
     }
@@ -58,6 +61,7 @@ impl From<Create> for JsonNewBranch {
             start_point_hash,
             start_point_max_versions,
             start_point_clone_thresholds,
+            upsert,
             ..
         } = create;
         let start_point = start_point_branch.map(|branch| JsonNewStartPoint {
@@ -70,6 +74,7 @@ impl From<Create> for JsonNewBranch {
             name: name.into(),
             slug: slug.map(Into::into),
             start_point,
+            upsert: Some(upsert),
         }
     }
 }
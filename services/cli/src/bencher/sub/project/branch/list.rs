@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, ProjBranchesSort};
 use bencher_json::{BranchName, ResourceId};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::branch::{CliBranchList, CliBranchesSort},
         CliPagination,
@@ -17,6 +17,7 @@ pub struct List {
     pub search: Option<String>,
     pub pagination: Pagination,
     pub archived: bool,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -38,15 +39,19 @@ impl TryFrom<CliBranchList> for List {
             search,
             pagination,
             archived,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             name,
             search,
             pagination: pagination.into(),
             archived,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -72,7 +77,7 @@ impl From<CliPagination<CliBranchesSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_branches_get().project(self.project.clone());
@@ -100,6 +105,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,75 @@
+use bencher_client::types::JsonMergeBranch;
+use bencher_json::{GitHash, NameId, ResourceId};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::branch::CliBranchMerge,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Merge {
+    pub project: ResourceId,
+    pub branch: ResourceId,
+    pub target: NameId,
+    pub hash: Option<GitHash>,
+    pub graft: bool,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliBranchMerge> for Merge {
+    type Error = CliError;
+
+    fn try_from(merge: CliBranchMerge) -> Result<Self, Self::Error> {
+        let CliBranchMerge {
+            project,
+            branch,
+            target,
+            hash,
+            graft,
+            backend,
+        } = merge;
+        Ok(Self {
+            project,
+            branch,
+            target,
+            hash,
+            graft,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Merge> for JsonMergeBranch {
+    fn from(merge: Merge) -> Self {
+        let Merge {
+            target,
+            hash,
+            graft,
+            ..
+        } = merge;
+        Self {
+            target: target.into(),
+            hash: hash.map(Into::into),
+            graft: Some(graft),
+        }
+    }
+}
+
+impl SubCmd for Merge {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_branch_merge_post()
+                    .project(self.project.clone())
+                    .branch(self.branch.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
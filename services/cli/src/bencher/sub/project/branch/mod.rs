@@ -3,6 +3,7 @@ use crate::{bencher::sub::SubCmd, parser::project::branch::CliBranch, CliError};
 mod create;
 mod delete;
 mod list;
+mod merge;
 pub mod start_point;
 mod update;
 mod view;
@@ -14,6 +15,7 @@ pub enum Branch {
     View(view::View),
     Update(update::Update),
     Delete(delete::Delete),
+    Merge(merge::Merge),
 }
 
 impl TryFrom<CliBranch> for Branch {
@@ -26,6 +28,7 @@ impl TryFrom<CliBranch> for Branch {
             CliBranch::View(view) => Self::View(view.try_into()?),
             CliBranch::Update(update) => Self::Update(update.try_into()?),
             CliBranch::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliBranch::Merge(merge) => Self::Merge(merge.try_into()?),
         })
     }
 }
@@ -38,6 +41,7 @@ impl SubCmd for Branch {
             Self::View(view) => view.exec().await,
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
+            Self::Merge(merge) => merge.exec().await,
         }
     }
 }
@@ -1,9 +1,9 @@
-use bencher_client::types::JsonUpdateBranch;
+use bencher_client::types::{JsonBranchProtection, JsonUpdateBranch};
 use bencher_json::{BranchName, ResourceId, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
-    parser::project::branch::CliBranchUpdate,
+    parser::project::branch::{CliBranchProtect, CliBranchProtection, CliBranchUpdate},
     CliError,
 };
 
@@ -17,6 +17,7 @@ pub struct Update {
     pub slug: Option<Slug>,
     pub start_point: StartPoint,
     pub archived: Option<bool>,
+    pub protection: Option<JsonBranchProtection>,
     pub backend: AuthBackend,
 }
 
@@ -31,6 +32,7 @@ impl TryFrom<CliBranchUpdate> for Update {
             slug,
             start_point,
             archived,
+            protection,
             backend,
         } = create;
         Ok(Self {
@@ -40,6 +42,7 @@ impl TryFrom<CliBranchUpdate> for Update {
             slug,
             start_point: start_point.into(),
             archived: archived.into(),
+            protection: protection.into(),
             backend: backend.try_into()?,
         })
     }
@@ -52,6 +55,7 @@ impl From<Update> for JsonUpdateBranch {
             slug,
             start_point,
             archived,
+            protection,
             ..
         } = update;
         Self {
@@ -59,10 +63,29 @@ impl From<Update> for JsonUpdateBranch {
             slug: slug.map(Into::into),
             start_point: start_point.into(),
             archived,
+            protection,
         }
     }
 }
 
+impl From<CliBranchProtection> for Option<JsonBranchProtection> {
+    fn from(protection: CliBranchProtection) -> Self {
+        let CliBranchProtection { protect, unprotect } = protection;
+        if unprotect {
+            return Some(JsonBranchProtection {
+                delete: false,
+                thresholds: false,
+                reports: false,
+            });
+        }
+        protect.map(|protect| JsonBranchProtection {
+            delete: protect.contains(&CliBranchProtect::Delete),
+            thresholds: protect.contains(&CliBranchProtect::Thresholds),
+            reports: protect.contains(&CliBranchProtect::Reports),
+        })
+    }
+}
+
 impl SubCmd for Update {
     async fn exec(&self) -> Result<(), CliError> {
         let _json = self
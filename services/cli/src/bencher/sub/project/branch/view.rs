@@ -1,7 +1,7 @@
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::project::branch::CliBranchView,
     CliError,
 };
@@ -10,6 +10,7 @@ use crate::{
 pub struct View {
     pub project: ResourceId,
     pub branch: ResourceId,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -20,19 +21,23 @@ impl TryFrom<CliBranchView> for View {
         let CliBranchView {
             project,
             branch,
+            table,
             backend,
         } = view;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             branch,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 client
@@ -43,6 +48,9 @@ impl SubCmd for View {
                     .await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -13,6 +13,7 @@ pub struct Create {
     pub name: ResourceName,
     pub slug: Option<Slug>,
     pub units: ResourceName,
+    pub upsert: bool,
     pub backend: AuthBackend,
 }
 
@@ -25,6 +26,7 @@ impl TryFrom<CliMeasureCreate> for Create {
             name,
             slug,
             units,
+            upsert,
             backend,
         } = create;
         Ok(Self {
@@ -32,6 +34,7 @@ impl TryFrom<CliMeasureCreate> for Create {
             name,
             slug,
             units,
+            upsert,
             backend: backend.try_into()?,
         })
     }
@@ -40,12 +43,17 @@ impl TryFrom<CliMeasureCreate> for Create {
 impl From<Create> for JsonNewMeasure {
     fn from(create: Create) -> Self {
         let Create {
-            name, slug, units, ..
+            name,
+            slug,
+            units,
+            upsert,
+            ..
         } = create;
         Self {
             name: name.into(),
             slug: slug.map(Into::into),
             units: units.into(),
+            upsert: Some(upsert),
         }
     }
 }
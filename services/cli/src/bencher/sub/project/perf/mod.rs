@@ -2,22 +2,20 @@ use std::future::Future;
 use std::pin::Pin;
 
 use bencher_json::{
+    project::perf::{table::perf_table, JsonPerfCursor, JsonPerfMetrics},
     BenchmarkUuid, BranchUuid, DateTime, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ResourceId, TestbedUuid,
+    ReportUuid, ResourceId, ResourceName, TestbedUuid,
 };
-use tabled::Table;
 
 use crate::parser::ElidedOption;
 use crate::{bencher::backend::PubBackend, cli_println, parser::project::perf::CliPerf, CliError};
 
-use crate::bencher::SubCmd;
+use crate::bencher::{table::TableOutput, SubCmd};
 
-mod table_style;
-
-use table_style::TableStyle;
+// The maximum number of results per page when fetching all pages
+const ALL_LIMIT: u32 = 1024;
 
 #[derive(Debug, Clone)]
-#[allow(clippy::option_option)]
 pub struct Perf {
     project: ResourceId,
     branches: Vec<BranchUuid>,
@@ -25,9 +23,13 @@ pub struct Perf {
     testbeds: Vec<TestbedUuid>,
     benchmarks: Vec<BenchmarkUuid>,
     measures: Vec<MeasureUuid>,
+    environment: Option<ResourceName>,
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
-    table: Option<Option<TableStyle>>,
+    after: Option<ReportUuid>,
+    limit: Option<u32>,
+    all: bool,
+    table: Option<TableOutput>,
     backend: PubBackend,
 }
 
@@ -42,12 +44,17 @@ impl TryFrom<CliPerf> for Perf {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
+            after,
+            limit,
+            all,
             table,
             backend,
         } = perf;
-        let backend = PubBackend::try_from(backend)?.log(table.is_none());
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none() && !all);
         Ok(Self {
             project,
             branches,
@@ -55,9 +62,13 @@ impl TryFrom<CliPerf> for Perf {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
-            table: table.map(|t| t.map(Into::into)),
+            after,
+            limit,
+            all,
+            table,
             backend,
         })
     }
@@ -71,6 +82,7 @@ impl From<Perf> for JsonPerfQuery {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
             ..
@@ -81,6 +93,7 @@ impl From<Perf> for JsonPerfQuery {
             testbeds,
             benchmarks,
             measures,
+            environment,
             start_time,
             end_time,
         }
@@ -89,21 +102,113 @@ impl From<Perf> for JsonPerfQuery {
 
 impl SubCmd for Perf {
     async fn exec(&self) -> Result<(), CliError> {
-        let sender = perf_sender(self.project.clone(), self.clone());
-        if let Some(table_style) = self.table {
-            let json_perf: JsonPerf = self.backend.send_with(sender).await?;
-            let mut perf_table: Table = json_perf.into();
-            if let Some(table_style) = table_style {
-                table_style.stylize(&mut perf_table);
+        let cursor = JsonPerfCursor {
+            after: self.after,
+            limit: self.limit,
+        };
+        let json_perf = if self.all {
+            let Some(json_perf) = self.exec_all().await? else {
+                return Ok(());
+            };
+            json_perf
+        } else {
+            let sender = perf_sender(self.project.clone(), self.clone(), cursor);
+            if self.table.is_some() {
+                self.backend.send_with(sender).await?
+            } else {
+                self.backend.send(sender).await?;
+                return Ok(());
             }
-            cli_println!("{perf_table}");
+        };
+
+        if let Some(table) = &self.table {
+            table.print_tabled(&perf_table(json_perf))?;
         } else {
-            self.backend.send(sender).await?;
+            cli_println!(
+                "{}",
+                serde_json::to_string_pretty(&json_perf).map_err(CliError::SerializeResults)?
+            );
         }
         Ok(())
     }
 }
 
+impl Perf {
+    // Query each permutation separately,
+    // following the cursor from the last report of each page until there are no more results.
+    async fn exec_all(&self) -> Result<Option<JsonPerf>, CliError> {
+        let json_perf_query: JsonPerfQuery = self.clone().into();
+        let limit = self.limit.unwrap_or(ALL_LIMIT);
+        let mut json_perf: Option<JsonPerf> = None;
+        for permutation_query in permutations(&json_perf_query) {
+            let mut cursor = JsonPerfCursor {
+                after: self.after,
+                limit: Some(limit),
+            };
+            let mut perf_metrics: Option<JsonPerfMetrics> = None;
+            loop {
+                let sender = perf_sender(self.project.clone(), permutation_query.clone(), cursor);
+                let JsonPerf {
+                    project,
+                    start_time,
+                    end_time,
+                    results,
+                    aggregates: _,
+                } = self.backend.send_with(sender).await?;
+                json_perf.get_or_insert_with(|| JsonPerf {
+                    project,
+                    start_time,
+                    end_time,
+                    results: Vec::new(),
+                    aggregates: None,
+                });
+
+                let Some(page_metrics) = results.into_iter().next() else {
+                    break;
+                };
+                cursor.after = page_metrics.metrics.last().map(|metric| metric.report);
+                if let Some(perf_metrics) = perf_metrics.as_mut() {
+                    perf_metrics.metrics.extend(page_metrics.metrics);
+                } else {
+                    perf_metrics = Some(page_metrics);
+                }
+                if cursor.after.is_none() {
+                    break;
+                }
+            }
+            if let (Some(json_perf), Some(perf_metrics)) = (json_perf.as_mut(), perf_metrics) {
+                json_perf.results.push(perf_metrics);
+            }
+        }
+        Ok(json_perf)
+    }
+}
+
+// Every permutation of branch, testbed, benchmark, and measure as its own query
+fn permutations(json_perf_query: &JsonPerfQuery) -> Vec<JsonPerfQuery> {
+    let mut permutations = Vec::new();
+    for (index, branch) in json_perf_query.branches.iter().enumerate() {
+        let head = json_perf_query.heads.get(index).copied().flatten();
+        for testbed in &json_perf_query.testbeds {
+            for benchmark in &json_perf_query.benchmarks {
+                for measure in &json_perf_query.measures {
+                    permutations.push(JsonPerfQuery {
+                        branches: vec![*branch],
+                        heads: vec![head],
+                        testbeds: vec![*testbed],
+                        benchmarks: vec![*benchmark],
+                        measures: vec![*measure],
+                        environment: json_perf_query.environment.clone(),
+                        start_time: json_perf_query.start_time,
+                        end_time: json_perf_query.end_time,
+                    });
+                }
+            }
+        }
+    }
+    permutations
+}
+
 type PerfQueryResult = Pin<
     Box<
         dyn Future<
@@ -117,6 +222,7 @@ type PerfQueryResult = Pin<
 fn perf_sender(
     project: ResourceId,
     json_perf_query: impl Into<JsonPerfQuery>,
+    cursor: JsonPerfCursor,
 ) -> Box<dyn Fn(bencher_client::Client) -> PerfQueryResult + Send> {
     let json_perf_query: JsonPerfQuery = json_perf_query.into();
     Box::new(move |client: bencher_client::Client| {
@@ -135,6 +241,10 @@ fn perf_sender(
                 client = client.heads(heads);
             }
 
+            if let Some(environment) = json_perf_query.environment() {
+                client = client.environment(environment);
+            }
+
             if let Some(start_time) = json_perf_query.start_time() {
                 client = client.start_time(start_time);
             }
@@ -142,6 +252,13 @@ fn perf_sender(
                 client = client.end_time(end_time);
             }
 
+            if let Some(after) = cursor.after {
+                client = client.after(after);
+            }
+            if let Some(limit) = cursor.limit {
+                client = client.limit(limit);
+            }
+
             client.send().await
         })
     })
@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, ProjPlotsSort};
 use bencher_json::{ResourceId, ResourceName};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::plot::{CliPlotList, CliPlotsSort},
         CliPagination,
@@ -16,6 +16,7 @@ pub struct List {
     pub title: Option<ResourceName>,
     pub search: Option<String>,
     pub pagination: Pagination,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -36,14 +37,18 @@ impl TryFrom<CliPlotList> for List {
             title,
             search,
             pagination,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             title,
             search,
             pagination: pagination.into(),
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -70,7 +75,7 @@ impl From<CliPagination<CliPlotsSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_plots_get().project(self.project.clone());
@@ -95,6 +100,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
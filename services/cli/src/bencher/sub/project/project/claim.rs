@@ -0,0 +1,51 @@
+use bencher_client::types::JsonClaimProject;
+use bencher_json::{ResourceId, Secret};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::CliProjectClaim,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Claim {
+    pub project: ResourceId,
+    pub key: Secret,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectClaim> for Claim {
+    type Error = CliError;
+
+    fn try_from(claim: CliProjectClaim) -> Result<Self, Self::Error> {
+        let CliProjectClaim {
+            project,
+            key,
+            backend,
+        } = claim;
+        Ok(Self {
+            project,
+            key,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Claim {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .project_claim_post()
+                    .project(self.project.clone())
+                    .body(JsonClaimProject {
+                        key: self.key.clone().into(),
+                    })
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
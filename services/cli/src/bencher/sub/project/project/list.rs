@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, OrgProjectsSort, ProjectsSort};
 use bencher_json::{ResourceId, ResourceName};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::{CliProjectList, CliProjectsSort},
         CliPagination,
@@ -16,6 +16,7 @@ pub struct List {
     pub name: Option<ResourceName>,
     pub search: Option<String>,
     pub pagination: Pagination,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -37,14 +38,18 @@ impl TryFrom<CliProjectList> for List {
             name,
             search,
             pagination,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             organization,
             name,
             search,
             pagination: pagination.into(),
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -73,7 +78,7 @@ impl From<CliPagination<CliProjectsSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 if let Some(organization) = self.organization.clone() {
@@ -121,6 +126,9 @@ impl SubCmd for List {
                 }
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
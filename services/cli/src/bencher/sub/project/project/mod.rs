@@ -1,9 +1,11 @@
 use crate::{bencher::sub::SubCmd, parser::project::CliProject, CliError};
 
 mod allowed;
+mod claim;
 mod create;
 mod delete;
 mod list;
+mod schema;
 mod update;
 mod view;
 
@@ -15,6 +17,8 @@ pub enum Project {
     Update(update::Update),
     Delete(delete::Delete),
     Allowed(allowed::Allowed),
+    Schema(schema::Schema),
+    Claim(claim::Claim),
 }
 
 impl TryFrom<CliProject> for Project {
@@ -28,6 +32,8 @@ impl TryFrom<CliProject> for Project {
             CliProject::Update(update) => Self::Update(update.try_into()?),
             CliProject::Delete(delete) => Self::Delete(delete.try_into()?),
             CliProject::Allowed(allowed) => Self::Allowed(allowed.try_into()?),
+            CliProject::Schema(schema) => Self::Schema(schema.try_into()?),
+            CliProject::Claim(claim) => Self::Claim(claim.try_into()?),
         })
     }
 }
@@ -41,6 +47,8 @@ impl SubCmd for Project {
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
             Self::Allowed(allowed) => allowed.exec().await,
+            Self::Schema(schema) => schema.exec().await,
+            Self::Claim(claim) => claim.exec().await,
         }
     }
 }
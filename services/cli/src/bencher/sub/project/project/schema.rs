@@ -0,0 +1,41 @@
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::CliProjectSchema,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Schema {
+    pub project: ResourceId,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliProjectSchema> for Schema {
+    type Error = CliError;
+
+    fn try_from(schema: CliProjectSchema) -> Result<Self, Self::Error> {
+        let CliProjectSchema { project, backend } = schema;
+        Ok(Self {
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Schema {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_schema_get()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::project::CliProjectView,
     CliError,
 };
@@ -9,6 +9,7 @@ use crate::{
 #[derive(Debug)]
 pub struct View {
     pub project: ResourceId,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -16,17 +17,24 @@ impl TryFrom<CliProjectView> for View {
     type Error = CliError;
 
     fn try_from(view: CliProjectView) -> Result<Self, Self::Error> {
-        let CliProjectView { project, backend } = view;
+        let CliProjectView {
+            project,
+            table,
+            backend,
+        } = view;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 client
@@ -36,6 +44,9 @@ impl SubCmd for View {
                     .await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
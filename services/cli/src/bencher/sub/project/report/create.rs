@@ -1,8 +1,8 @@
 use bencher_client::types::{
     Adapter, DateTime, GitHash, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings,
-    JsonUpdateStartPoint, NameId,
+    JsonUpdateStartPoint, NameId, ResourceName,
 };
-use bencher_json::ResourceId;
+use bencher_json::{ReportVersion, ResourceId};
 
 use crate::{
     bencher::{
@@ -23,6 +23,7 @@ pub struct Create {
     pub hash: Option<GitHash>,
     pub start_point: Option<JsonUpdateStartPoint>,
     pub testbed: NameId,
+    pub environment: Option<ResourceName>,
     pub thresholds: Thresholds,
     pub start_time: DateTime,
     pub end_time: DateTime,
@@ -30,6 +31,8 @@ pub struct Create {
     pub adapter: Option<Adapter>,
     pub average: Option<JsonAverage>,
     pub fold: Option<JsonFold>,
+    pub raw: bool,
+    pub duration: bool,
     pub backend: AuthBackend,
 }
 
@@ -43,6 +46,7 @@ impl TryFrom<CliReportCreate> for Create {
             hash,
             start_point,
             testbed,
+            environment,
             thresholds,
             start_time,
             end_time,
@@ -50,6 +54,8 @@ impl TryFrom<CliReportCreate> for Create {
             adapter,
             average,
             fold,
+            raw,
+            duration,
             backend,
         } = create;
         Ok(Self {
@@ -58,6 +64,7 @@ impl TryFrom<CliReportCreate> for Create {
             hash: hash.map(Into::into),
             start_point: StartPoint::from(start_point).into(),
             testbed: testbed.into(),
+            environment: environment.map(Into::into),
             thresholds: thresholds.try_into().map_err(CliError::Thresholds)?,
             start_time: start_time.into(),
             end_time: end_time.into(),
@@ -65,6 +72,8 @@ impl TryFrom<CliReportCreate> for Create {
             adapter: adapter.map(Into::into),
             average: average.map(Into::into),
             fold: fold.map(Into::into),
+            raw,
+            duration,
             backend: backend.try_into()?,
         })
     }
@@ -77,28 +86,40 @@ impl From<Create> for JsonNewReport {
             hash,
             start_point,
             testbed,
+            environment,
             start_time,
             end_time,
             results,
             adapter,
             average,
             fold,
+            raw,
+            duration,
             ..
         } = create;
         Self {
+            version: Some(ReportVersion::CURRENT.into()),
             branch,
             hash,
             start_point,
             testbed,
+            environment,
             thresholds: None,
             start_time,
             end_time,
             results,
+            runner_results: None,
             settings: Some(JsonReportSettings {
                 adapter,
                 average,
                 fold,
+                pairs: None,
+                raw: raw.then_some(true),
+                duration: duration.then_some(true),
+                base_hash: None,
+                results_per_iteration: None,
             }),
+            failure: None,
         }
     }
 }
@@ -1,18 +1,37 @@
-use bencher_json::{ReportUuid, ResourceId};
+use std::io::{BufRead, IsTerminal, Write};
+
+use bencher_json::{
+    project::report::JsonReportsDeleteQuery, DateTime, JsonReportsDeleted, NameId, ReportUuid,
+    ResourceId,
+};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
+    cli_println,
     parser::project::report::CliReportDelete,
     CliError,
 };
 
+use super::ReportError;
+
 #[derive(Debug)]
 pub struct Delete {
     pub project: ResourceId,
-    pub report: ReportUuid,
+    pub target: DeleteTarget,
     pub backend: AuthBackend,
 }
 
+#[derive(Debug)]
+pub enum DeleteTarget {
+    Report(ReportUuid),
+    OlderThan {
+        branch: NameId,
+        before: DateTime,
+        dry_run: bool,
+        yes: bool,
+    },
+}
+
 impl TryFrom<CliReportDelete> for Delete {
     type Error = CliError;
 
@@ -20,11 +39,34 @@ impl TryFrom<CliReportDelete> for Delete {
         let CliReportDelete {
             project,
             report,
+            older_than,
+            branch,
+            dry_run,
+            yes,
             backend,
         } = view;
+        let target = match (report, older_than, branch) {
+            (Some(report), _, _) => DeleteTarget::Report(report),
+            (None, Some(older_than), Some(branch)) => {
+                let before = chrono::TimeDelta::from_std(older_than)
+                    .ok()
+                    .and_then(|older_than| {
+                        DateTime::now().into_inner().checked_sub_signed(older_than)
+                    })
+                    .ok_or(ReportError::OlderThan(older_than))?;
+                DeleteTarget::OlderThan {
+                    branch,
+                    before: before.into(),
+                    dry_run,
+                    yes,
+                }
+            },
+            // Clap requires either the report or both the duration and branch
+            (None, _, _) => return Err(ReportError::NoReport.into()),
+        };
         Ok(Self {
             project,
-            report,
+            target,
             backend: backend.try_into()?,
         })
     }
@@ -32,17 +74,105 @@ impl TryFrom<CliReportDelete> for Delete {
 
 impl SubCmd for Delete {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        match &self.target {
+            DeleteTarget::Report(report) => {
+                let _json = self
+                    .backend
+                    .send(|client| async move {
+                        client
+                            .proj_report_delete()
+                            .project(self.project.clone())
+                            .report(*report)
+                            .send()
+                            .await
+                    })
+                    .await?;
+                Ok(())
+            },
+            DeleteTarget::OlderThan {
+                branch,
+                before,
+                dry_run,
+                yes,
+            } => {
+                let query = JsonReportsDeleteQuery {
+                    branch: branch.clone(),
+                    before: *before,
+                    dry_run: Some(true),
+                };
+                self.exec_older_than(query, *dry_run, *yes).await
+            },
+        }
+    }
+}
+
+impl Delete {
+    async fn exec_older_than(
+        &self,
+        mut query: JsonReportsDeleteQuery,
+        dry_run: bool,
+        yes: bool,
+    ) -> Result<(), CliError> {
+        // Always start with a dry run, so the reports can be listed before they are deleted
+        let json_deleted = self.send_older_than(&query).await?;
+        let count = json_deleted.reports.len();
+        cli_println!(
+            "Found {count} report(s) for branch {branch} that ended before {before}",
+            branch = query.branch,
+            before = query.before,
+        );
+        for report in &json_deleted.reports {
+            cli_println!("  - {report}");
+        }
+        if dry_run || count == 0 {
+            return Ok(());
+        }
+        if !yes && !confirm(count)? {
+            cli_println!("No reports were deleted.");
+            return Ok(());
+        }
+
+        query.dry_run = None;
+        let json_deleted = self.send_older_than(&query).await?;
+        cli_println!("Deleted {} report(s)", json_deleted.reports.len());
+        Ok(())
+    }
+
+    async fn send_older_than(
+        &self,
+        query: &JsonReportsDeleteQuery,
+    ) -> Result<JsonReportsDeleted, CliError> {
+        Ok(self
             .backend
-            .send(|client| async move {
-                client
-                    .proj_report_delete()
+            .send_with(|client| async move {
+                let mut client = client
+                    .proj_reports_delete()
                     .project(self.project.clone())
-                    .report(self.report)
-                    .send()
-                    .await
+                    .branch(query.branch())
+                    .before(query.before());
+                if let Some(dry_run) = query.dry_run {
+                    client = client.dry_run(dry_run);
+                }
+                client.send().await
             })
-            .await?;
-        Ok(())
+            .await?)
+    }
+}
+
+// Ask the user to confirm the deletion, which requires an interactive terminal
+fn confirm(count: usize) -> Result<bool, ReportError> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return Err(ReportError::Unconfirmed(count));
     }
+    let mut stdout = std::io::stdout();
+    write!(stdout, "Delete {count} report(s)? [y/N] ")
+        .and_then(|()| stdout.flush())
+        .map_err(ReportError::Confirm)?;
+    let mut answer = String::new();
+    stdin
+        .lock()
+        .read_line(&mut answer)
+        .map_err(ReportError::Confirm)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
 }
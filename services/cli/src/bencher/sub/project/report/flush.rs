@@ -0,0 +1,81 @@
+use bencher_json::JsonReport;
+
+use crate::{
+    bencher::{
+        backend::AuthBackend,
+        spool::{SpoolError, SpooledReport},
+        sub::SubCmd,
+    },
+    cli_eprintln, cli_println,
+    parser::project::report::CliReportFlush,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Flush {
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliReportFlush> for Flush {
+    type Error = CliError;
+
+    fn try_from(flush: CliReportFlush) -> Result<Self, Self::Error> {
+        let CliReportFlush { backend } = flush;
+        Ok(Self {
+            backend: AuthBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl SubCmd for Flush {
+    async fn exec(&self) -> Result<(), CliError> {
+        let paths = SpooledReport::list()?;
+        if paths.is_empty() {
+            cli_println!("No spooled reports to send.");
+            return Ok(());
+        }
+
+        let mut failed = 0;
+        for path in paths {
+            let SpooledReport { project, report } = SpooledReport::read(&path)?;
+            let result: Result<JsonReport, _> = self
+                .backend
+                .send_with(|client| {
+                    let project = project.clone();
+                    let report = report.clone();
+                    async move {
+                        client
+                            .proj_report_post()
+                            .project(project)
+                            .body(report)
+                            .send()
+                            .await
+                    }
+                })
+                .await;
+            match result {
+                Ok(json_report) => {
+                    SpooledReport::remove(&path)?;
+                    cli_println!(
+                        "Sent spooled report ({}) to project {}: {}",
+                        path.display(),
+                        json_report.project.slug,
+                        json_report.uuid
+                    );
+                },
+                // The API server is still unavailable, so there is no point trying the rest
+                Err(err) if err.is_transient() => return Err(err.into()),
+                Err(err) => {
+                    cli_eprintln!("Failed to send spooled report ({}): {err}", path.display());
+                    failed += 1;
+                },
+            }
+        }
+
+        if failed > 0 {
+            Err(SpoolError::Flush(failed).into())
+        } else {
+            Ok(())
+        }
+    }
+}
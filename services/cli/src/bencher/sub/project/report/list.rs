@@ -1,8 +1,11 @@
-use bencher_client::types::{JsonDirection, ProjReportsSort};
-use bencher_json::{project::report::JsonReportQuery, DateTime, NameId, ResourceId};
+use bencher_client::types::{Adapter, JsonDirection, ProjReportsSort};
+use bencher_json::{
+    project::report::JsonReportQuery, DateTime, JsonReports, NameId, ReportUuid, ResourceId,
+};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
+    cli_println,
     parser::{
         project::report::{CliReportList, CliReportsSort},
         CliPagination,
@@ -10,6 +13,9 @@ use crate::{
     CliError,
 };
 
+// The maximum number of reports per page when fetching all pages
+const ALL_PER_PAGE: u8 = u8::MAX;
+
 #[derive(Debug, Clone)]
 pub struct List {
     pub project: ResourceId,
@@ -17,8 +23,12 @@ pub struct List {
     pub testbed: Option<NameId>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub adapter: Option<Adapter>,
     pub pagination: Pagination,
     pub archived: bool,
+    pub after: Option<ReportUuid>,
+    pub all: bool,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -40,19 +50,29 @@ impl TryFrom<CliReportList> for List {
             testbed,
             start_time,
             end_time,
+            adapter,
             pagination,
             archived,
+            after,
+            all,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none() && !all);
         Ok(Self {
             project,
             branch,
             testbed,
             start_time,
             end_time,
+            adapter: adapter.map(Into::into),
             pagination: pagination.into(),
             archived,
-            backend: backend.try_into()?,
+            after,
+            all,
+            table,
+            backend,
         })
     }
 }
@@ -84,6 +104,7 @@ impl From<List> for JsonReportQuery {
             start_time,
             end_time,
             archived,
+            after,
             ..
         } = list;
         Self {
@@ -91,53 +112,113 @@ impl From<List> for JsonReportQuery {
             testbed,
             start_time,
             end_time,
+            // The adapter filter is sent as a client type
+            adapter: None,
             archived: archived.then_some(archived),
+            after,
         }
     }
 }
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let json_report_query: &JsonReportQuery = &self.clone().into();
-        let _json = self
-            .backend
-            .send(|client| async move {
-                let mut client = client.proj_reports_get().project(self.project.clone());
-
-                if let Some(branch) = json_report_query.branch() {
-                    client = client.branch(branch);
-                }
-                if let Some(testbed) = json_report_query.testbed() {
-                    client = client.testbed(testbed);
-                }
-
-                if let Some(start_time) = json_report_query.start_time() {
-                    client = client.start_time(start_time);
-                }
-                if let Some(end_time) = json_report_query.end_time() {
-                    client = client.end_time(end_time);
-                }
-
-                if let Some(archived) = json_report_query.archived {
-                    client = client.archived(archived);
-                }
-
-                if let Some(sort) = self.pagination.sort {
-                    client = client.sort(sort);
-                }
-                if let Some(direction) = self.pagination.direction {
-                    client = client.direction(direction);
-                }
-                if let Some(per_page) = self.pagination.per_page {
-                    client = client.per_page(per_page);
-                }
-                if let Some(page) = self.pagination.page {
-                    client = client.page(page);
-                }
-
-                client.send().await
-            })
-            .await?;
+        if self.all {
+            self.exec_all().await
+        } else {
+            let json_report_query: JsonReportQuery = self.clone().into();
+            let json = self
+                .backend
+                .send(|client| self.send(client, &json_report_query, self.pagination.per_page))
+                .await?;
+            if let Some(table) = &self.table {
+                table.print_json(&json)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl List {
+    // Follow the cursor from the last report of each page until there are no more reports
+    async fn exec_all(&self) -> Result<(), CliError> {
+        let mut json_report_query: JsonReportQuery = self.clone().into();
+        let per_page = self.pagination.per_page.unwrap_or(ALL_PER_PAGE);
+        let mut reports = Vec::new();
+        loop {
+            let json_reports: JsonReports = self
+                .backend
+                .send_with(|client| self.send(client, &json_report_query, Some(per_page)))
+                .await?;
+            let page_len = json_reports.0.len();
+            json_report_query.after = json_reports.0.last().map(|report| report.uuid);
+            reports.extend(json_reports.0);
+            if page_len < usize::from(per_page) || json_report_query.after.is_none() {
+                break;
+            }
+        }
+        let json_reports = JsonReports::from(reports);
+        if let Some(table) = &self.table {
+            let json = serde_json::to_value(&json_reports).map_err(CliError::SerializeResults)?;
+            table.print_json(&json)?;
+        } else {
+            cli_println!(
+                "{}",
+                serde_json::to_string_pretty(&json_reports).map_err(CliError::SerializeResults)?
+            );
+        }
         Ok(())
     }
+
+    async fn send(
+        &self,
+        client: bencher_client::Client,
+        json_report_query: &JsonReportQuery,
+        per_page: Option<u8>,
+    ) -> Result<
+        progenitor_client::ResponseValue<bencher_client::types::JsonReports>,
+        bencher_client::Error<bencher_client::types::Error>,
+    > {
+        let mut client = client.proj_reports_get().project(self.project.clone());
+
+        if let Some(branch) = json_report_query.branch() {
+            client = client.branch(branch);
+        }
+        if let Some(testbed) = json_report_query.testbed() {
+            client = client.testbed(testbed);
+        }
+
+        if let Some(start_time) = json_report_query.start_time() {
+            client = client.start_time(start_time);
+        }
+        if let Some(end_time) = json_report_query.end_time() {
+            client = client.end_time(end_time);
+        }
+
+        if let Some(adapter) = self.adapter {
+            client = client.adapter(adapter);
+        }
+
+        if let Some(archived) = json_report_query.archived {
+            client = client.archived(archived);
+        }
+
+        if let Some(after) = json_report_query.after {
+            client = client.after(after);
+        }
+
+        if let Some(sort) = self.pagination.sort {
+            client = client.sort(sort);
+        }
+        if let Some(direction) = self.pagination.direction {
+            client = client.direction(direction);
+        }
+        if let Some(per_page) = per_page {
+            client = client.per_page(per_page);
+        }
+        if let Some(page) = self.pagination.page {
+            client = client.page(page);
+        }
+
+        client.send().await
+    }
 }
@@ -2,15 +2,33 @@ use crate::{bencher::sub::SubCmd, parser::project::report::CliReport, CliError};
 
 mod create;
 mod delete;
+mod flush;
 mod list;
+mod raw;
 mod view;
 
+#[derive(Debug, thiserror::Error)]
+pub enum ReportError {
+    #[error("Failed to find a report to delete. Set the report UUID or use the `--older-than` and `--branch` arguments.")]
+    NoReport,
+    #[error(
+        "Failed to calculate the date time for `--older-than`, the duration is too large: {0:?}"
+    )]
+    OlderThan(std::time::Duration),
+    #[error("Refusing to delete {0} report(s) without confirmation. Use the `--yes` flag to delete them without an interactive terminal.")]
+    Unconfirmed(usize),
+    #[error("Failed to confirm deletion: {0}")]
+    Confirm(std::io::Error),
+}
+
 #[derive(Debug)]
 pub enum Report {
     List(list::List),
     Create(create::Create),
     View(view::View),
+    Raw(raw::Raw),
     Delete(delete::Delete),
+    Flush(flush::Flush),
 }
 
 impl TryFrom<CliReport> for Report {
@@ -21,7 +39,9 @@ impl TryFrom<CliReport> for Report {
             CliReport::List(list) => Self::List(list.try_into()?),
             CliReport::Create(create) => Self::Create((*create).try_into()?),
             CliReport::View(view) => Self::View(view.try_into()?),
+            CliReport::Raw(raw) => Self::Raw(raw.try_into()?),
             CliReport::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliReport::Flush(flush) => Self::Flush(flush.try_into()?),
         })
     }
 }
@@ -32,7 +52,9 @@ impl SubCmd for Report {
             Self::List(list) => list.exec().await,
             Self::Create(create) => create.exec().await,
             Self::View(create) => create.exec().await,
+            Self::Raw(raw) => raw.exec().await,
             Self::Delete(delete) => delete.exec().await,
+            Self::Flush(flush) => flush.exec().await,
         }
     }
 }
@@ -0,0 +1,48 @@
+use bencher_json::{ReportUuid, ResourceId};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::report::CliReportRaw,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Raw {
+    pub project: ResourceId,
+    pub report: ReportUuid,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliReportRaw> for Raw {
+    type Error = CliError;
+
+    fn try_from(raw: CliReportRaw) -> Result<Self, Self::Error> {
+        let CliReportRaw {
+            project,
+            report,
+            backend,
+        } = raw;
+        Ok(Self {
+            project,
+            report,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Raw {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_report_raw_get()
+                    .project(self.project.clone())
+                    .report(self.report)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
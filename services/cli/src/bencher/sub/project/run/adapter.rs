@@ -5,32 +5,65 @@ use crate::parser::project::run::CliRunAdapter;
 impl From<CliRunAdapter> for Adapter {
     fn from(adapter: CliRunAdapter) -> Self {
         match adapter {
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Magic => Self::Magic,
             CliRunAdapter::Json => Self::Json,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::CSharp => Self::CSharp,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::CSharpDotNet => Self::CSharpDotNet,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Cpp => Self::Cpp,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::CppCatch2 => Self::CppCatch2,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::CppGoogle => Self::CppGoogle,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Go => Self::Go,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::GoBench => Self::GoBench,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Java => Self::Java,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::JavaJmh => Self::JavaJmh,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Js => Self::Js,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::JsBenchmark => Self::JsBenchmark,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::JsTime => Self::JsTime,
+            #[cfg(feature = "adapters")]
+            CliRunAdapter::PhpBench => Self::PhpBench,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Python => Self::Python,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::PythonAsv => Self::PythonAsv,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::PythonPytest => Self::PythonPytest,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Ruby => Self::Ruby,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::RubyBenchmark => Self::RubyBenchmark,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Rust => Self::Rust,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::RustBench => Self::RustBench,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::RustCriterion => Self::RustCriterion,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::RustIai => Self::RustIai,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            #[cfg(feature = "adapters")]
+            CliRunAdapter::ScalaScalameter => Self::ScalaScalameter,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::Shell => Self::Shell,
+            #[cfg(feature = "adapters")]
             CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
+            #[cfg(feature = "adapters")]
+            CliRunAdapter::ShellMeasureCommand => Self::ShellMeasureCommand,
+            #[cfg(feature = "adapters")]
+            CliRunAdapter::SwiftXctest => Self::SwiftXctest,
         }
     }
 }
@@ -6,6 +6,11 @@ pub enum RunError {
     #[error("Failed to check API version: {0}")]
     ApiVersion(crate::BackendError),
 
+    #[error("The subcommand `run` requires a project. Set the `--project` option or the `BENCHER_PROJECT` environment variable.\nTo send the report to a new public unclaimed project instead, use the `--anonymous` flag.")]
+    NoProject,
+    #[error("An API token is set, but no project was given. Set the `--project` option or the `BENCHER_PROJECT` environment variable.\nReports sent anonymously go to a public unclaimed project, so they can not be sent with an API token.")]
+    AnonymousWithToken,
+
     #[error("{0}")]
     Branch(#[from] super::branch::BranchError),
     #[error("{0}")]
@@ -15,8 +20,12 @@ pub enum RunError {
     Shell,
     #[error("No default shell command flag for target family. Try setting a custom shell command flag with the `--flag` argument.")]
     Flag,
-    #[error("The subcommand `run` requires either a command argument or results via stdin.")]
+    #[error("The subcommand `run` requires either a benchmark command argument, the `--file`, `--file-size`, or `--harness` option, or results via stdin.\nTo read results from stdin, pipe them into `bencher run` or use the `--stdin` flag.")]
     NoCommand,
+    #[error("Failed to read results from stdin: {0}")]
+    StdinRead(std::io::Error),
+    #[error("Results from stdin exceed the maximum input size of {0} bytes. Set a larger size with the `--max-input-size` option.")]
+    StdinTooLarge(u64),
 
     #[error("Set shell ({0}) when running command in exec mode")]
     ShellWithExec(String),
@@ -57,22 +66,79 @@ pub enum RunError {
     SerializeBuildTime(serde_json::Error),
     #[error("Failed to read from output file: {0}")]
     OutputFileRead(std::io::Error),
+    #[error("Failed to read output file directory ({path}): {err}")]
+    OutputFileDir {
+        path: camino::Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to find any output files that match: {0}")]
+    OutputFileGlob(camino::Utf8PathBuf),
+    #[error("Failed to read the same number of output files for every iteration: expected {expected} but found {found}")]
+    ResultsPerIteration { expected: usize, found: usize },
     #[error("Failed to parse the output file name: {0}")]
     OutputFileName(bencher_json::ValidError),
     #[error("Failed to read size of output file: {0}")]
     OutputFileSize(std::io::Error),
     #[error("Failed to serialize file size results: {0}")]
     SerializeFileSize(serde_json::Error),
+    #[error("The `http` benchmark harness requires a URL. Set the `--url` argument.")]
+    HarnessUrl,
+    #[error("Failed to send HTTP request: {0}")]
+    HttpRequest(reqwest::Error),
+    #[error("Failed to get a successful response from `{url}`: {status}")]
+    HttpStatus {
+        url: url::Url,
+        status: reqwest::StatusCode,
+    },
+    #[error("Failed to parse HTTP benchmark name: {0}")]
+    HttpBenchmarkName(bencher_json::ValidError),
+    #[error("Failed to serialize HTTP results: {0}")]
+    SerializeHttp(serde_json::Error),
+    #[error("Failed to serialize streamed results: {0}")]
+    SerializeStream(serde_json::Error),
+    #[error("Streaming results requires the `json` or `magic` adapter, but the `{0:?}` adapter was set.")]
+    StreamAdapter(bencher_client::types::Adapter),
+    #[error("Tracking a measure requires a benchmark command.")]
+    MeasureNoCommand,
+    #[error("Failed to get the max RSS of the benchmark command: {0}")]
+    MaxRss(std::io::Error),
+    #[error("Failed to read the RAPL energy counters: {0}")]
+    Energy(std::io::Error),
+
+    #[error("Failed to read flamegraph file ({path}): {err}")]
+    FlamegraphRead {
+        path: camino::Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to get the flamegraph file name: {0}")]
+    FlamegraphFileName(camino::Utf8PathBuf),
+    #[error("Failed to upload flamegraph: {0}")]
+    FlamegraphUpload(crate::bencher::BackendError),
+    #[error("Failed to get baseline flamegraph: {0}")]
+    FlamegraphBaseline(crate::bencher::BackendError),
 
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
     #[error("Failed to create new report: {0}")]
     SendReport(crate::bencher::BackendError),
+    #[error("Failed to create new report: {err}\nThe report has been saved to: {}\nTo send it once the API server is available, run: bencher report flush", path.display())]
+    SpoolReport {
+        err: crate::bencher::BackendError,
+        path: std::path::PathBuf,
+    },
+    #[error("Failed to create new report: {err}\nFailed to save the report to be sent later: {spool_err}")]
+    SpoolReportFailed {
+        err: crate::bencher::BackendError,
+        spool_err: crate::bencher::SpoolError,
+    },
+    #[error("Failed to remove the previous report after rerunning the benchmarks: {0}")]
+    DeleteReport(crate::bencher::BackendError),
     #[error("Failed to get console URL: {0}")]
     ConsoleUrl(crate::bencher::BackendError),
     #[error("Alerts detected ({0})")]
     Alerts(usize),
 
     #[error("{0}")]
+    #[cfg(feature = "github-actions")]
     Ci(#[from] super::ci::CiError),
 }
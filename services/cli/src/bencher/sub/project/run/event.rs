@@ -0,0 +1,104 @@
+#[cfg(feature = "adapters")]
+use bencher_adapter::{AdapterResultsArray, Settings};
+#[cfg(feature = "adapters")]
+use bencher_client::types::JsonNewReport;
+#[cfg(feature = "adapters")]
+use bencher_json::project::report::JsonReportSettings;
+use bencher_json::ReportUuid;
+use serde::Serialize;
+
+use crate::{cli_eprintln, parser::project::run::CliRunLogFormat};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Human,
+    Json,
+}
+
+impl From<CliRunLogFormat> for LogFormat {
+    fn from(log_format: CliRunLogFormat) -> Self {
+        match log_format {
+            CliRunLogFormat::Human => Self::Human,
+            CliRunLogFormat::Json => Self::Json,
+        }
+    }
+}
+
+/// A machine-readable `bencher run` progress event
+///
+/// Each event is written to standard error as a single line of JSON,
+/// so wrapper tools can follow along without scraping the human output.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RunEvent {
+    CommandStarted {
+        runner: String,
+        iteration: usize,
+    },
+    CommandFinished {
+        iteration: usize,
+        success: bool,
+    },
+    ReportSubmitted {
+        uuid: ReportUuid,
+        project: String,
+    },
+    /// Only emitted if the CLI is built with the adapters, as the output is parsed locally
+    #[cfg(feature = "adapters")]
+    OutputParsed {
+        iterations: usize,
+        benchmarks: usize,
+    },
+    Alerts {
+        count: usize,
+    },
+    Retry {
+        attempt: u32,
+        alerts: usize,
+    },
+}
+
+impl RunEvent {
+    /// Parse the benchmark harness output with the adapter, the same way the server will,
+    /// so the number of benchmarks is known before the report is sent.
+    /// Any parse errors are left for the server to report.
+    #[cfg(feature = "adapters")]
+    pub fn output_parsed(json_new_report: &JsonNewReport) -> Option<Self> {
+        let settings: Option<JsonReportSettings> = serde_json::to_value(&json_new_report.settings)
+            .and_then(serde_json::from_value)
+            .ok()?;
+        let settings = settings.unwrap_or_default();
+        let results = json_new_report
+            .results
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let mut results_array = AdapterResultsArray::new(
+            &results,
+            settings.adapter.unwrap_or_default(),
+            Settings::new(settings.average),
+        )
+        .ok()?;
+        if let Some(results_per_iteration) = settings.results_per_iteration {
+            results_array.group(results_per_iteration as usize).ok()?;
+        }
+        Some(Self::OutputParsed {
+            iterations: results_array.inner.len(),
+            benchmarks: results_array
+                .inner
+                .iter()
+                .map(|results| results.inner.len())
+                .max()
+                .unwrap_or_default(),
+        })
+    }
+
+    pub fn emit(&self, log_format: LogFormat) {
+        if log_format != LogFormat::Json {
+            return;
+        }
+        if let Ok(event) = serde_json::to_string(self) {
+            cli_eprintln!("{event}");
+        }
+    }
+}
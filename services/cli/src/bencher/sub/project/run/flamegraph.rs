@@ -0,0 +1,140 @@
+use bencher_comment::Flamegraphs;
+use bencher_json::{
+    project::report::FLAMEGRAPH_ATTACHMENT, DateTimeMillis, JsonAttachment, JsonReport,
+    JsonReports, ResourceId,
+};
+use camino::Utf8Path;
+use url::Url;
+
+use crate::bencher::backend::Backend;
+
+use super::RunError;
+
+// The number of previous reports to search for a baseline flamegraph
+const BASELINE_REPORTS: u8 = 16;
+
+#[derive(Debug, Clone)]
+pub struct Flamegraph {
+    extension: Option<String>,
+    data: Vec<u8>,
+}
+
+impl Flamegraph {
+    #[allow(clippy::result_large_err)]
+    pub fn read(path: &Utf8Path) -> Result<Self, RunError> {
+        if path.file_name().is_none() {
+            return Err(RunError::FlamegraphFileName(path.to_owned()));
+        }
+        let data = std::fs::read(path).map_err(|err| RunError::FlamegraphRead {
+            path: path.to_owned(),
+            err,
+        })?;
+        Ok(Self {
+            extension: path.extension().map(str::to_lowercase),
+            data,
+        })
+    }
+
+    // The flamegraph is always uploaded with the same file stem,
+    // so that it can be found for the baseline report.
+    fn file_name(&self) -> String {
+        if let Some(extension) = &self.extension {
+            format!("{FLAMEGRAPH_ATTACHMENT}.{extension}")
+        } else {
+            FLAMEGRAPH_ATTACHMENT.to_owned()
+        }
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self.extension.as_deref() {
+            Some("svg") => "image/svg+xml",
+            Some("html" | "htm") => "text/html",
+            Some("json") => "application/json",
+            _ => "application/octet-stream",
+        }
+    }
+
+    /// Upload the flamegraph as an attachment to the report
+    pub async fn upload(
+        self,
+        backend: &Backend,
+        project: &ResourceId,
+        json_report: &mut JsonReport,
+    ) -> Result<(), RunError> {
+        let file_name = self.file_name();
+        let content_type = self.content_type();
+        let json_attachments = backend
+            .upload_attachment(
+                project,
+                json_report.uuid,
+                file_name,
+                content_type,
+                self.data,
+            )
+            .await
+            .map_err(RunError::FlamegraphUpload)?;
+        json_report
+            .attachments
+            .get_or_insert_with(Vec::new)
+            .extend(json_attachments.0);
+        Ok(())
+    }
+}
+
+/// Get the links to the flamegraph for the report and its baseline.
+/// The baseline is the most recent previous report on the same branch and testbed with a flamegraph.
+pub async fn flamegraphs(
+    backend: &Backend,
+    project: &ResourceId,
+    json_report: &JsonReport,
+) -> Result<Option<Flamegraphs>, RunError> {
+    let Some(flamegraph) = json_report.flamegraph() else {
+        return Ok(None);
+    };
+    let report = attachment_url(backend.host(), json_report, flamegraph);
+
+    let branch = json_report.branch.uuid.to_string();
+    let testbed = json_report.testbed.uuid.to_string();
+    let end_time = DateTimeMillis::from(json_report.start_time);
+    let json_reports: JsonReports = backend
+        .send_with(|client| {
+            let project = project.clone();
+            let branch = branch.clone();
+            let testbed = testbed.clone();
+            async move {
+                client
+                    .proj_reports_get()
+                    .project(project)
+                    .branch(branch)
+                    .testbed(testbed)
+                    .end_time(end_time)
+                    .per_page(BASELINE_REPORTS)
+                    .send()
+                    .await
+            }
+        })
+        .await
+        .map_err(RunError::FlamegraphBaseline)?;
+    let baseline = json_reports
+        .0
+        .iter()
+        .filter(|baseline_report| baseline_report.uuid != json_report.uuid)
+        .find_map(|baseline_report| {
+            baseline_report
+                .flamegraph()
+                .map(|flamegraph| attachment_url(backend.host(), baseline_report, flamegraph))
+        });
+
+    Ok(Some(Flamegraphs { report, baseline }))
+}
+
+fn attachment_url(host: &Url, json_report: &JsonReport, attachment: &JsonAttachment) -> Url {
+    let mut url = host.clone();
+    url.set_path(&format!(
+        "/v0/projects/{project}/reports/{report}/attachments/{attachment}",
+        project = json_report.project.slug,
+        report = json_report.uuid,
+        attachment = attachment.uuid,
+    ));
+    url
+}
@@ -0,0 +1,107 @@
+use crate::RunError;
+
+#[cfg(target_os = "linux")]
+const POWERCAP_PATH: &str = "/sys/class/powercap";
+#[cfg(target_os = "linux")]
+const MICROJOULES_PER_JOULE: f64 = 1_000_000.0;
+
+/// A snapshot of the Intel RAPL energy counters for each CPU package
+#[derive(Debug, Clone)]
+pub struct Energy {
+    #[cfg(target_os = "linux")]
+    zones: Vec<Zone>,
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+struct Zone {
+    path: std::path::PathBuf,
+    energy_uj: u64,
+    max_energy_range_uj: u64,
+}
+
+#[cfg(target_os = "linux")]
+impl Energy {
+    pub fn start() -> Result<Self, RunError> {
+        let mut zones = Vec::new();
+        for entry in std::fs::read_dir(POWERCAP_PATH).map_err(RunError::Energy)? {
+            let path = entry.map_err(RunError::Energy)?.path();
+            // Only use the top level package zones (ie `intel-rapl:0`)
+            // The subzones (ie `intel-rapl:0:0`) are already included in their package zone.
+            let is_package = path
+                .file_name()
+                .and_then(std::ffi::OsStr::to_str)
+                .and_then(|name| name.strip_prefix("intel-rapl:"))
+                .is_some_and(|zone| !zone.contains(':'));
+            if is_package {
+                zones.push(Zone::new(path)?);
+            }
+        }
+        if zones.is_empty() {
+            return Err(RunError::Energy(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no Intel RAPL package zones found in {POWERCAP_PATH}"),
+            )));
+        }
+        Ok(Self { zones })
+    }
+
+    /// The total energy used by all CPU packages since the snapshot was taken
+    pub fn joules(self) -> Result<f64, RunError> {
+        let mut microjoules = 0u64;
+        for zone in self.zones {
+            microjoules = microjoules.saturating_add(zone.elapsed()?);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        Ok(microjoules as f64 / MICROJOULES_PER_JOULE)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Zone {
+    fn new(path: std::path::PathBuf) -> Result<Self, RunError> {
+        let energy_uj = read_counter(&path, "energy_uj")?;
+        let max_energy_range_uj = read_counter(&path, "max_energy_range_uj")?;
+        Ok(Self {
+            path,
+            energy_uj,
+            max_energy_range_uj,
+        })
+    }
+
+    fn elapsed(&self) -> Result<u64, RunError> {
+        let energy_uj = read_counter(&self.path, "energy_uj")?;
+        Ok(if energy_uj >= self.energy_uj {
+            energy_uj - self.energy_uj
+        } else {
+            // The counter wraps around once it reaches the max energy range
+            self.max_energy_range_uj
+                .saturating_sub(self.energy_uj)
+                .saturating_add(energy_uj)
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_counter(path: &std::path::Path, file: &str) -> Result<u64, RunError> {
+    let counter = std::fs::read_to_string(path.join(file)).map_err(RunError::Energy)?;
+    counter
+        .trim()
+        .parse()
+        .map_err(|e| RunError::Energy(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))
+}
+
+#[cfg(not(target_os = "linux"))]
+impl Energy {
+    pub fn start() -> Result<Self, RunError> {
+        Err(RunError::Energy(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "energy is only supported on Linux",
+        )))
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn joules(self) -> Result<f64, RunError> {
+        Ok(0.0)
+    }
+}
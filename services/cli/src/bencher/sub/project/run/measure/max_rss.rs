@@ -0,0 +1,24 @@
+use crate::RunError;
+
+// The peak resident set size of the largest child process that has been waited on
+#[cfg(unix)]
+pub fn max_rss() -> Result<f64, RunError> {
+    use nix::sys::resource::{getrusage, UsageWho};
+
+    let usage = getrusage(UsageWho::RUSAGE_CHILDREN).map_err(|e| RunError::MaxRss(e.into()))?;
+    // macOS reports the max RSS in bytes, while other Unix platforms report it in kilobytes
+    #[cfg(target_os = "macos")]
+    let bytes = usage.max_rss();
+    #[cfg(not(target_os = "macos"))]
+    let bytes = usage.max_rss().saturating_mul(1024);
+    #[allow(clippy::cast_precision_loss)]
+    Ok(bytes as f64)
+}
+
+#[cfg(not(unix))]
+pub fn max_rss() -> Result<f64, RunError> {
+    Err(RunError::MaxRss(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "max RSS is only supported on Unix platforms",
+    )))
+}
@@ -0,0 +1,115 @@
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+
+use bencher_client::types::JsonNewMetric;
+use bencher_json::{
+    project::measure::built_in::{self, BuiltInMeasure},
+    BenchmarkName,
+};
+
+use crate::parser::project::run::CliRunMeasure;
+
+use super::{runner::Runner, RunError};
+
+mod energy;
+mod max_rss;
+
+use energy::Energy;
+use max_rss::max_rss;
+
+/// The results measured by the CLI for a single iteration
+pub type RunnerResults = HashMap<String, HashMap<String, JsonNewMetric>>;
+
+#[derive(Debug, Clone)]
+pub struct Measures {
+    benchmark_name: BenchmarkName,
+    measures: Vec<Measure>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Measure {
+    MaxRss,
+    Energy,
+}
+
+/// The state of each measure from before the benchmark command was run
+#[derive(Debug)]
+pub struct MeasuresStart(Vec<MeasureStart>);
+
+#[derive(Debug)]
+enum MeasureStart {
+    MaxRss,
+    Energy(Energy),
+}
+
+impl From<CliRunMeasure> for Measure {
+    fn from(measure: CliRunMeasure) -> Self {
+        match measure {
+            CliRunMeasure::MaxRss => Self::MaxRss,
+            CliRunMeasure::Energy => Self::Energy,
+        }
+    }
+}
+
+impl Measures {
+    pub fn new(measures: Vec<CliRunMeasure>, runner: &Runner) -> Result<Option<Self>, RunError> {
+        if measures.is_empty() {
+            return Ok(None);
+        }
+        let command = runner.command().ok_or(RunError::MeasureNoCommand)?;
+        let benchmark_name = command.to_string().parse().map_err(RunError::CommandName)?;
+        let mut measures = measures.into_iter().map(Into::into).collect::<Vec<_>>();
+        measures.sort_unstable();
+        measures.dedup();
+        Ok(Some(Self {
+            benchmark_name,
+            measures,
+        }))
+    }
+
+    /// Start each measure right before the benchmark command is run
+    pub fn start(&self) -> Result<MeasuresStart, RunError> {
+        self.measures
+            .iter()
+            .map(|measure| measure.start())
+            .collect::<Result<Vec<_>, _>>()
+            .map(MeasuresStart)
+    }
+
+    /// Finish each measure right after the benchmark command has completed
+    pub fn results(&self, start: MeasuresStart) -> Result<RunnerResults, RunError> {
+        let mut metrics = HashMap::with_capacity(start.0.len());
+        for measure_start in start.0 {
+            let (measure, metric) = measure_start.finish()?;
+            metrics.insert(measure, metric);
+        }
+        Ok(HashMap::from([(self.benchmark_name.to_string(), metrics)]))
+    }
+}
+
+impl Measure {
+    fn start(self) -> Result<MeasureStart, RunError> {
+        Ok(match self {
+            Self::MaxRss => MeasureStart::MaxRss,
+            Self::Energy => MeasureStart::Energy(Energy::start()?),
+        })
+    }
+}
+
+impl MeasureStart {
+    fn finish(self) -> Result<(String, JsonNewMetric), RunError> {
+        let (measure, value) = match self {
+            Self::MaxRss => (built_in::json::MaxRss::name_id(), max_rss()?),
+            Self::Energy(energy) => (built_in::json::Energy::name_id(), energy.joules()?),
+        };
+        Ok((
+            measure.to_string(),
+            JsonNewMetric {
+                value,
+                lower_value: None,
+                upper_value: None,
+            },
+        ))
+    }
+}
@@ -1,12 +1,19 @@
 use std::{future::Future, pin::Pin};
 
-use bencher_client::types::{Adapter, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings};
+use bencher_client::types::{
+    Adapter, JsonAverage, JsonBenchmarkPair, JsonFold, JsonNewReport, JsonNewRun,
+    JsonReportSettings,
+};
 use bencher_comment::ReportComment;
-use bencher_json::{DateTime, JsonReport, NameId, ResourceId};
+use bencher_json::{
+    project::alert::AlertSeverity, DateTime, GitHash, JsonReport, JsonRun, NameId, ReportUuid,
+    ReportVersion, ResourceId, ResourceName, Secret,
+};
+use camino::Utf8PathBuf;
 
 use crate::{
-    bencher::backend::AuthBackend,
-    cli_eprintln_quietable, cli_println, cli_println_quietable,
+    bencher::{backend::Backend, spool::SpooledReport},
+    cli_eprintln, cli_eprintln_quietable, cli_println, cli_println_quietable,
     parser::project::run::{CliRun, CliRunOutput},
     CliError,
 };
@@ -14,17 +21,27 @@ use crate::{
 mod adapter;
 mod average;
 mod branch;
+#[cfg(feature = "github-actions")]
 mod ci;
 mod error;
+mod event;
+mod flamegraph;
 mod fold;
 mod format;
+mod measure;
 pub mod runner;
+mod severity;
+mod table;
 pub mod thresholds;
 
 use branch::Branch;
+#[cfg(feature = "github-actions")]
 use ci::Ci;
 pub use error::RunError;
+use event::{LogFormat, RunEvent};
+use flamegraph::Flamegraph;
 use format::Format;
+use measure::Measures;
 use runner::Runner;
 use thresholds::Thresholds;
 
@@ -33,24 +50,41 @@ use crate::bencher::SubCmd;
 #[derive(Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Run {
-    project: ResourceId,
+    project: RunProject,
     branch: Branch,
     testbed: NameId,
+    environment: Option<ResourceName>,
     adapter: Adapter,
     average: Option<JsonAverage>,
     iter: usize,
     fold: Option<JsonFold>,
+    pairs: Vec<JsonBenchmarkPair>,
+    raw: bool,
+    duration: bool,
+    flamegraph: Option<Utf8PathBuf>,
     backdate: Option<DateTime>,
+    base_hash: Option<GitHash>,
     allow_failure: bool,
     thresholds: Thresholds,
-    err: bool,
+    err: Option<AlertSeverity>,
+    retry_on_alert: u32,
     format: Format,
     log: bool,
+    log_format: LogFormat,
+    #[cfg(feature = "github-actions")]
     ci: Option<Ci>,
     runner: Runner,
+    measures: Option<Measures>,
     #[allow(clippy::struct_field_names)]
     dry_run: bool,
-    backend: AuthBackend,
+    backend: Backend,
+}
+
+#[derive(Debug)]
+enum RunProject {
+    Project(ResourceId),
+    /// A project created anonymously, addressed by its key
+    Unclaimed(Option<Secret>),
 }
 
 impl TryFrom<CliRun> for Run {
@@ -59,40 +93,101 @@ impl TryFrom<CliRun> for Run {
     fn try_from(run: CliRun) -> Result<Self, Self::Error> {
         let CliRun {
             project,
+            anonymous,
+            project_key,
             branch,
             testbed,
+            environment,
             adapter,
             average,
             iter,
             fold,
+            pair,
+            raw,
+            duration,
+            flamegraph,
             backdate,
+            base_hash,
             allow_failure,
             thresholds,
             err,
-            output: CliRunOutput { format, quiet },
+            err_on,
+            retry_on_alert,
+            output:
+                CliRunOutput {
+                    format,
+                    quiet,
+                    log_format,
+                },
+            #[cfg(feature = "github-actions")]
             ci,
-            cmd,
+            mut cmd,
             dry_run,
             backend,
         } = run;
+        let measures = std::mem::take(&mut cmd.measure);
+        let adapter: Adapter = adapter.into();
+        // Streamed results are always collected as Bencher Metric Format (BMF) JSON
+        if cmd.stream && !matches!(adapter, Adapter::Json | Adapter::Magic) {
+            return Err(RunError::StreamAdapter(adapter).into());
+        }
+        let runner = cmd.try_into()?;
+        let measures = Measures::new(measures, &runner)?;
+        // Only send the report anonymously when explicitly asked to,
+        // as unclaimed projects are always public.
+        let project = match (project, anonymous, project_key) {
+            (Some(project), _, _) => RunProject::Project(project),
+            (None, _, _) if backend.token.is_some() => {
+                return Err(RunError::AnonymousWithToken.into())
+            },
+            (None, true, None) => RunProject::Unclaimed(None),
+            (None, _, Some(project_key)) => RunProject::Unclaimed(Some(project_key)),
+            (None, false, None) => return Err(RunError::NoProject.into()),
+        };
+        // An API token is only required when sending the report to an existing project
+        let is_public = matches!(project, RunProject::Unclaimed(_));
+        let log_format = log_format.into();
         Ok(Self {
             project,
             branch: branch.try_into().map_err(RunError::Branch)?,
             testbed,
-            adapter: adapter.into(),
+            environment,
+            adapter,
             average: average.map(Into::into),
             iter,
             fold: fold.map(Into::into),
+            pairs: pair
+                .chunks_exact(2)
+                .filter_map(|pair| match pair {
+                    [baseline, candidate] => Some(JsonBenchmarkPair {
+                        baseline: baseline.clone().into(),
+                        candidate: candidate.clone().into(),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            raw,
+            duration,
+            flamegraph,
             backdate,
+            base_hash,
             allow_failure,
             thresholds: thresholds.try_into().map_err(RunError::Thresholds)?,
-            err,
+            // Erroring on any alert is the same as erroring on warning or above
+            err: err_on
+                .map(Into::into)
+                .or(err.then_some(AlertSeverity::Warning)),
+            retry_on_alert: retry_on_alert.unwrap_or_default(),
             format: format.into(),
-            log: !quiet,
+            // The JSON event stream replaces the human progress logs
+            log: !quiet && log_format == LogFormat::Human,
+            log_format,
+            #[cfg(feature = "github-actions")]
             ci: ci.try_into().map_err(RunError::Ci)?,
-            runner: cmd.try_into()?,
+            runner,
+            measures,
             dry_run,
-            backend: AuthBackend::try_from(backend)?.log(false),
+            backend: Backend::try_from((backend, is_public))?.log(false),
         })
     }
 }
@@ -114,6 +209,7 @@ impl Run {
             cli_eprintln_quietable!(self.log, "Warning: {mismatch}");
         }
 
+        #[cfg(feature = "github-actions")]
         if let Some(ci) = &self.ci {
             ci.safety_check(self.log)?;
         }
@@ -129,37 +225,248 @@ impl Run {
             serde_json::to_string_pretty(&json_new_report).map_err(RunError::SerializeReport)?
         );
 
+        // Read the flamegraph before sending the report, as it is likely generated by the benchmark command
+        let flamegraph = self
+            .flamegraph
+            .as_deref()
+            .map(Flamegraph::read)
+            .transpose()?;
+
         // If performing a dry run, don't actually send the report
         if self.dry_run {
             return Ok(());
         }
 
-        let sender = report_sender(self.project.clone(), json_new_report);
-        let json_report: JsonReport = self
-            .backend
-            .send_with(sender)
-            .await
-            .map_err(RunError::SendReport)?;
+        let (json_report, unclaimed_key) = self.send_report(json_new_report.clone()).await?;
 
-        let alerts_count = json_report.alerts.len();
-        self.display_results(json_report).await?;
+        // Alerts may just be noise, so rerun the benchmarks and fold in the new results
+        let mut json_report = self.retry_on_alert(json_new_report, json_report).await?;
+        if let Some(adapter_selection) = &json_report.adapter_selection {
+            cli_eprintln_quietable!(self.log, "\nMagic adapter selected: {adapter_selection}");
+        }
+        RunEvent::ReportSubmitted {
+            uuid: json_report.uuid,
+            project: json_report.project.slug.to_string(),
+        }
+        .emit(self.log_format);
+
+        if let (Some(flamegraph), RunProject::Project(project)) = (flamegraph, &self.project) {
+            flamegraph
+                .upload(&self.backend, project, &mut json_report)
+                .await?;
+        }
 
-        if self.err && alerts_count > 0 {
+        let alerts_count = self.err.map_or(0, |severity| {
+            json_report
+                .alerts
+                .iter()
+                .filter(|alert| alert.severity >= severity)
+                .count()
+        });
+        RunEvent::Alerts {
+            count: json_report.alerts.len(),
+        }
+        .emit(self.log_format);
+        self.display_results(json_report, unclaimed_key.as_ref())
+            .await?;
+
+        if alerts_count > 0 {
             Err(RunError::Alerts(alerts_count))
         } else {
             Ok(())
         }
     }
 
+    async fn send_report(
+        &self,
+        json_new_report: JsonNewReport,
+    ) -> Result<(JsonReport, Option<Secret>), RunError> {
+        Ok(match &self.project {
+            RunProject::Project(project) => {
+                let sender = report_sender(project.clone(), json_new_report.clone());
+                let json_report: JsonReport = match self.backend.send_with(sender).await {
+                    Ok(json_report) => json_report,
+                    // Save the report to be sent later, so the results are not lost
+                    Err(err) if err.is_transient() => {
+                        let spooled_report = SpooledReport {
+                            project: project.clone(),
+                            report: json_new_report,
+                        };
+                        return Err(match spooled_report.spool() {
+                            Ok(path) => RunError::SpoolReport { err, path },
+                            Err(spool_err) => RunError::SpoolReportFailed { err, spool_err },
+                        });
+                    },
+                    Err(err) => return Err(RunError::SendReport(err)),
+                };
+                (json_report, None)
+            },
+            RunProject::Unclaimed(key) => {
+                let sender = run_sender(key.clone(), json_new_report);
+                let json_run: JsonRun = self
+                    .backend
+                    .send_with(sender)
+                    .await
+                    .map_err(RunError::SendReport)?;
+                (json_run.report, Some(json_run.key))
+            },
+        })
+    }
+
+    /// Rerun the benchmarks while the report has alerts, folding the results from every run into a new report.
+    /// Returns the last report that was accepted.
+    async fn retry_on_alert(
+        &self,
+        mut json_new_report: JsonNewReport,
+        mut json_report: JsonReport,
+    ) -> Result<JsonReport, RunError> {
+        for retry in 1..=self.retry_on_alert {
+            let alerts_count = self.retry_alerts_count(&json_report);
+            if alerts_count == 0 {
+                break;
+            }
+            let RunProject::Project(project) = &self.project else {
+                break;
+            };
+            cli_eprintln_quietable!(
+                self.log,
+                "\nAlerts detected ({alerts_count}). Rerunning benchmarks ({retry}/{})...",
+                self.retry_on_alert
+            );
+            RunEvent::Retry {
+                attempt: retry,
+                alerts: alerts_count,
+            }
+            .emit(self.log_format);
+            let Some(retry_report) = self.generate_report().await? else {
+                break;
+            };
+            json_new_report = self.fold_reports(json_new_report, retry_report);
+            let previous_report = json_report.uuid;
+            (json_report, _) = self.send_report(json_new_report.clone()).await?;
+            // Only remove the previous report once the new report has been accepted,
+            // as its results are now part of the new report.
+            // This requires `delete` permissions, so a failure only warns and keeps both reports.
+            if let Err(err) = self.delete_report(project, previous_report).await {
+                cli_eprintln_quietable!(
+                    self.log,
+                    "Warning: Failed to delete previous report ({previous_report}): {err}"
+                );
+            }
+        }
+        Ok(json_report)
+    }
+
+    // Without an error severity, any alert is worth a rerun
+    fn retry_alerts_count(&self, json_report: &JsonReport) -> usize {
+        json_report
+            .alerts
+            .iter()
+            .filter(|alert| self.err.map_or(true, |severity| alert.severity >= severity))
+            .count()
+    }
+
+    async fn delete_report(
+        &self,
+        project: &ResourceId,
+        report: ReportUuid,
+    ) -> Result<(), RunError> {
+        self.backend
+            .send(|client| async move {
+                client
+                    .proj_report_delete()
+                    .project(project.clone())
+                    .report(report)
+                    .send()
+                    .await
+            })
+            .await
+            .map_err(RunError::DeleteReport)?;
+        Ok(())
+    }
+
+    /// Combine the results from every run so far into a single report,
+    /// folded into one result per benchmark.
+    fn fold_reports(
+        &self,
+        json_new_report: JsonNewReport,
+        retry_report: JsonNewReport,
+    ) -> JsonNewReport {
+        let JsonNewReport {
+            start_time,
+            mut results,
+            runner_results,
+            settings,
+            ..
+        } = json_new_report;
+        results.extend(retry_report.results);
+        let runner_results = match (runner_results, retry_report.runner_results) {
+            (Some(mut runner_results), Some(retry_runner_results)) => {
+                runner_results.extend(retry_runner_results);
+                Some(runner_results)
+            },
+            (runner_results, retry_runner_results) => runner_results.or(retry_runner_results),
+        };
+        let settings = settings.map(|settings| JsonReportSettings {
+            fold: Some(self.fold.unwrap_or(JsonFold::Median)),
+            ..settings
+        });
+        JsonNewReport {
+            start_time,
+            results,
+            runner_results,
+            settings,
+            ..retry_report
+        }
+    }
+
     async fn generate_report(&self) -> Result<Option<JsonNewReport>, RunError> {
         let start_time = DateTime::now();
         let mut results = Vec::with_capacity(self.iter);
-        for _ in 0..self.iter {
+        let mut runner_results = Vec::with_capacity(self.iter);
+        // Multiple results files are read for each iteration and then merged by the server
+        let mut results_per_iteration = None;
+        // The most recent failure of the command, if failures are allowed
+        let mut failure = None;
+        for iteration in 0..self.iter {
+            RunEvent::CommandStarted {
+                runner: self.runner.to_string(),
+                iteration,
+            }
+            .emit(self.log_format);
+            let measures_start = self.measures.as_ref().map(Measures::start).transpose()?;
             let output = self.runner.run(self.log).await?;
+            RunEvent::CommandFinished {
+                iteration,
+                success: output.is_success(),
+            }
+            .emit(self.log_format);
             if output.is_success() {
-                results.push(output.result());
+                let output_results = output.results();
+                match results_per_iteration {
+                    Some(expected) if expected != output_results.len() => {
+                        return Err(RunError::ResultsPerIteration {
+                            expected,
+                            found: output_results.len(),
+                        });
+                    },
+                    Some(_) => {},
+                    None => results_per_iteration = Some(output_results.len()),
+                }
+                results.extend(output_results);
+                if let (Some(measures), Some(start)) = (&self.measures, measures_start) {
+                    runner_results.push(measures.results(start)?);
+                }
+            } else if output.is_partial() {
+                cli_eprintln_quietable!(
+                    self.log,
+                    "Keeping partial streamed results from failure:\n{output}"
+                );
+                failure = output.failure();
+                results.extend(output.results());
             } else if self.allow_failure {
                 cli_eprintln_quietable!(self.log, "Skipping failure:\n{output}");
+                failure = output.failure();
             } else {
                 return Err(RunError::ExitStatus {
                     runner: Box::new(self.runner.clone()),
@@ -183,51 +490,116 @@ impl Run {
         };
 
         let (branch, hash, start_point) = self.branch.clone().into();
-        Ok(Some(JsonNewReport {
+        let json_new_report = JsonNewReport {
+            version: Some(ReportVersion::CURRENT.into()),
             branch,
             hash,
             start_point,
             testbed: self.testbed.clone().into(),
+            environment: self.environment.clone().map(Into::into),
             thresholds: self.thresholds.clone().into(),
             start_time: start_time.into(),
             end_time: end_time.into(),
             results,
+            runner_results: (!runner_results.is_empty()).then_some(runner_results),
             settings: Some(JsonReportSettings {
                 adapter: Some(self.adapter),
                 average: self.average,
                 fold: self.fold,
+                pairs: (!self.pairs.is_empty()).then(|| self.pairs.clone()),
+                raw: self.raw.then_some(true),
+                duration: self.duration.then_some(true),
+                base_hash: self.base_hash.clone().map(Into::into),
+                results_per_iteration: results_per_iteration
+                    .filter(|results_per_iteration| *results_per_iteration > 1)
+                    .and_then(|results_per_iteration| u32::try_from(results_per_iteration).ok()),
             }),
-        }))
+            failure,
+        };
+
+        // Only parse the output locally if the event will actually be emitted
+        #[cfg(feature = "adapters")]
+        if self.log_format == LogFormat::Json {
+            if let Some(output_parsed) = RunEvent::output_parsed(&json_new_report) {
+                output_parsed.emit(self.log_format);
+            }
+        }
+
+        Ok(Some(json_new_report))
     }
 
-    async fn display_results(&self, json_report: JsonReport) -> Result<(), RunError> {
+    async fn display_results(
+        &self,
+        json_report: JsonReport,
+        unclaimed_key: Option<&Secret>,
+    ) -> Result<(), RunError> {
         let console_url = self
             .backend
             .get_console_url()
             .await
             .map_err(RunError::ConsoleUrl)?;
-        let report_comment = ReportComment::new(
-            console_url,
-            json_report,
-            self.ci
-                .as_ref()
-                .map_or_else(|| "cli".to_owned(), Ci::source),
-        );
+        // Only look for the baseline flamegraph if there is an alert to compare against
+        let flamegraphs = match &self.project {
+            RunProject::Project(project) if !json_report.alerts.is_empty() => {
+                flamegraph::flamegraphs(&self.backend, project, &json_report).await?
+            },
+            RunProject::Project(_) | RunProject::Unclaimed(_) => None,
+        };
+        let project_slug = json_report.project.slug.clone();
+        let results_table = match self.format {
+            Format::Human => table::results_table(&json_report),
+            Format::Json | Format::Html => None,
+        };
+        let mut report_comment = ReportComment::new(console_url, json_report, self.source());
+        if let Some(flamegraphs) = flamegraphs {
+            report_comment = report_comment.with_flamegraphs(flamegraphs);
+        }
 
         let report_str = match self.format {
-            Format::Human => report_comment.human(),
+            Format::Human => {
+                let human = report_comment.human();
+                if let Some(results_table) = results_table {
+                    format!("{results_table}\n\n{human}")
+                } else {
+                    human
+                }
+            },
             Format::Json => report_comment.json().map_err(RunError::SerializeReport)?,
             Format::Html => report_comment.html(false, None),
         };
         let newline_prefix = if self.log { "\n" } else { "" };
         cli_println!("{newline_prefix}{report_str}");
 
+        // Always show how to get back to an unclaimed project, as it is otherwise inaccessible
+        if let Some(key) = unclaimed_key {
+            let key = key.as_ref();
+            cli_eprintln!(
+                "\nThis report was sent anonymously to the unclaimed project: {project_slug}"
+            );
+            cli_eprintln!("To send more reports to this project, set `--project-key` or `BENCHER_PROJECT_KEY` to: {key}");
+            cli_eprintln!("To claim this project, sign up for Bencher and then run: bencher project claim {project_slug} --key {key}");
+        }
+
+        #[cfg(feature = "github-actions")]
         if let Some(ci) = &self.ci {
             ci.run(&report_comment, self.log).await?;
         }
 
         Ok(())
     }
+
+    #[cfg(feature = "github-actions")]
+    fn source(&self) -> String {
+        self.ci
+            .as_ref()
+            .map_or_else(|| "cli".to_owned(), Ci::source)
+    }
+
+    #[cfg(not(feature = "github-actions"))]
+    #[allow(clippy::unused_self)]
+    fn source(&self) -> String {
+        "cli".to_owned()
+    }
 }
 
 type ReportResult = Pin<
@@ -257,3 +629,26 @@ fn report_sender(
         })
     })
 }
+
+type RunResult = Pin<
+    Box<
+        dyn Future<
+                Output = Result<
+                    progenitor_client::ResponseValue<bencher_client::types::JsonRun>,
+                    bencher_client::Error<bencher_client::types::Error>,
+                >,
+            > + Send,
+    >,
+>;
+fn run_sender(
+    key: Option<Secret>,
+    json_new_report: JsonNewReport,
+) -> Box<dyn Fn(bencher_client::Client) -> RunResult + Send> {
+    Box::new(move |client: bencher_client::Client| {
+        let json_new_run = JsonNewRun {
+            key: key.clone().map(Into::into),
+            report: json_new_report.clone(),
+        };
+        Box::pin(async move { client.run_post().body(json_new_run).send().await })
+    })
+}
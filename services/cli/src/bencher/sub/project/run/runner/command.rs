@@ -1,12 +1,20 @@
 use std::{fmt, process::Stdio};
 
+use bencher_json::JsonResultsMap;
 use chrono::Utc;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    process::ChildStdout,
+};
 
 use super::build_time::{BuildCommand, BuildTime};
 use super::file_path::FilePath;
 use super::file_size::FileSize;
-use super::{flag::Flag, output::Output, shell::Shell};
+use super::{
+    flag::Flag,
+    output::{ExitStatus, Output},
+    shell::Shell,
+};
 use crate::{bencher::sub::RunError, parser::project::run::CliRunShell};
 use crate::{cli_eprintln_quietable, cli_println_quietable};
 
@@ -42,9 +50,11 @@ impl fmt::Display for Command {
 impl Command {
     pub fn new_shell(sh_c: CliRunShell, command: String) -> Result<Self, RunError> {
         let CliRunShell { shell, flag } = sh_c;
+        let shell = Shell::try_from(shell)?;
+        let flag = Flag::new(flag, &shell)?;
         Ok(Self::Shell {
-            shell: shell.try_into()?,
-            flag: flag.try_into()?,
+            shell,
+            flag,
             command,
         })
     }
@@ -58,24 +68,33 @@ impl Command {
         log: bool,
         build_time: Option<BuildTime>,
     ) -> Result<CommandOutput, RunError> {
-        let (output, duration) = self.run_inner(log).await?;
+        let (output, duration) = self.run_inner(log, false).await?;
         let build_command = build_time.map(|bt| bt.command(self.to_string(), duration));
         Ok(CommandOutput::new(build_command, output))
     }
 
-    async fn run_inner(&self, log: bool) -> Result<(Output, f64), RunError> {
+    /// Run the command, parsing each line of stdout as a Bencher Metric Format (BMF) JSON object as it is emitted.
+    /// If the command fails after emitting results, then the output is marked as partial.
+    pub async fn run_stream(&self, log: bool) -> Result<Output, RunError> {
+        self.run_inner(log, true).await.map(|(output, _)| output)
+    }
+
+    async fn run_inner(&self, log: bool, stream: bool) -> Result<(Output, f64), RunError> {
         let start_time = Utc::now();
         let mut child = match self {
             Self::Shell {
                 shell,
                 flag,
                 command,
-            } => tokio::process::Command::new(shell.as_ref())
-                .arg(flag.as_ref())
-                .arg(command)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn(),
+            } => {
+                let mut shell_command = tokio::process::Command::new(shell.as_ref());
+                shell_command.arg(flag.as_ref());
+                shell_command_arg(&mut shell_command, shell, command);
+                shell_command
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped())
+                    .spawn()
+            },
             Self::Exec { program, arguments } => tokio::process::Command::new(program)
                 .args(arguments)
                 .stdout(Stdio::piped())
@@ -91,22 +110,7 @@ impl Command {
             .stdout
             .take()
             .ok_or_else(|| RunError::PipeStdout(self.clone()))?;
-        let stdout = tokio::spawn(async move {
-            let stdout_reader = BufReader::new(child_stdout);
-            let mut stdout_lines = stdout_reader.lines();
-
-            let mut stdout = String::new();
-            while let Ok(Some(line)) = stdout_lines.next_line().await {
-                cli_println_quietable!(log, "{line}");
-                if stdout.is_empty() {
-                    stdout = line;
-                } else {
-                    stdout = format!("{stdout}\n{line}");
-                }
-            }
-
-            stdout
-        });
+        let stdout = tokio::spawn(read_stdout(child_stdout, log, stream));
 
         let child_stderr = child
             .stderr
@@ -136,10 +140,14 @@ impl Command {
             command: self.clone(),
             err,
         })?;
-        let stdout = stdout.map_err(|err| RunError::StdoutJoinError {
+        let (stdout, results) = stdout.map_err(|err| RunError::StdoutJoinError {
             command: self.clone(),
             err,
         })?;
+        let result = results
+            .map(|results| serde_json::to_string(&results))
+            .transpose()
+            .map_err(RunError::SerializeStream)?;
         let stderr = stderr.map_err(|err| RunError::StderrJoinError {
             command: self.clone(),
             err,
@@ -160,18 +168,70 @@ impl Command {
             })
             .unwrap_or_default();
 
+        let status: ExitStatus = status.into();
         Ok((
             Output {
-                status: status.into(),
+                partial: result.is_some() && !status.is_success(),
+                status,
                 stdout,
                 stderr,
-                result: None,
+                results: result.into_iter().collect(),
             },
             duration,
         ))
     }
 }
 
+// Read and log stdout line by line.
+// If streaming, each line that is a Bencher Metric Format (BMF) JSON object is also kept as a result.
+async fn read_stdout(
+    child_stdout: ChildStdout,
+    log: bool,
+    stream: bool,
+) -> (String, Option<JsonResultsMap>) {
+    let stdout_reader = BufReader::new(child_stdout);
+    let mut stdout_lines = stdout_reader.lines();
+
+    let mut stdout = String::new();
+    let mut results: Option<JsonResultsMap> = None;
+    while let Ok(Some(line)) = stdout_lines.next_line().await {
+        cli_println_quietable!(log, "{line}");
+        // Any line that is not a BMF JSON object is just logged
+        if let Some(line_results) = stream
+            .then(|| serde_json::from_str::<JsonResultsMap>(&line).ok())
+            .flatten()
+        {
+            let results = results.get_or_insert_with(JsonResultsMap::new);
+            for (benchmark, metrics) in line_results {
+                results.entry(benchmark).or_default().extend(metrics);
+            }
+        }
+        if stdout.is_empty() {
+            stdout = line;
+        } else {
+            stdout = format!("{stdout}\n{line}");
+        }
+    }
+
+    (stdout, results)
+}
+
+// `cmd` parses its own command line instead of following the usual Windows argument quoting rules,
+// so the command must be passed through verbatim for any quotes within it to be kept.
+#[cfg(target_family = "windows")]
+fn shell_command_arg(shell_command: &mut tokio::process::Command, shell: &Shell, command: &str) {
+    if shell.is_cmd() {
+        shell_command.raw_arg(command);
+    } else {
+        shell_command.arg(command);
+    }
+}
+
+#[cfg(not(target_family = "windows"))]
+fn shell_command_arg(shell_command: &mut tokio::process::Command, _shell: &Shell, command: &str) {
+    shell_command.arg(command);
+}
+
 #[derive(Debug, Default)]
 pub struct CommandOutput {
     build_command: Option<BuildCommand>,
@@ -189,7 +249,7 @@ impl CommandOutput {
     pub fn build(mut self) -> Result<Output, RunError> {
         if let Some(build_command) = self.build_command {
             let results = build_command.get_results()?;
-            self.output.result = Some(results);
+            self.output.results = vec![results];
         }
         Ok(self.output)
     }
@@ -200,13 +260,13 @@ impl CommandOutput {
             "Build command should not be set for file path"
         );
         let results = file_path.get_results()?;
-        self.output.result = Some(results);
+        self.output.results.extend(results);
         Ok(self.output)
     }
 
     pub fn build_with_file_size(mut self, file_size: &FileSize) -> Result<Output, RunError> {
         let results = file_size.get_results(self.build_command.as_ref())?;
-        self.output.result = Some(results);
+        self.output.results = vec![results];
         Ok(self.output)
     }
 }
@@ -1,24 +1,211 @@
 use std::fmt;
 
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 
 use crate::RunError;
 
+const ANY_CHARS: char = '*';
+const ANY_CHAR: char = '?';
+
 #[derive(Debug, Clone)]
-pub struct FilePath(Utf8PathBuf);
+pub struct FilePath(Vec<Utf8PathBuf>);
 
 impl fmt::Display for FilePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|p| p.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
     }
 }
 
 impl FilePath {
-    pub fn new(file_path: Utf8PathBuf) -> Self {
-        Self(file_path)
+    pub fn new(file_paths: Vec<Utf8PathBuf>) -> Self {
+        Self(file_paths)
+    }
+
+    /// Read the results from each of the files, in order.
+    /// A glob pattern is expanded to all of the matching files, sorted by path.
+    pub fn get_results(&self) -> Result<Vec<String>, RunError> {
+        let mut results = Vec::with_capacity(self.0.len());
+        for file_path in &self.0 {
+            for file_path in expand_glob(file_path)? {
+                results.push(std::fs::read_to_string(file_path).map_err(RunError::OutputFileRead)?);
+            }
+        }
+        Ok(results)
+    }
+}
+
+// Only the file name may contain wildcards, ie `results/*.json`
+fn expand_glob(file_path: &Utf8Path) -> Result<Vec<Utf8PathBuf>, RunError> {
+    let Some(pattern) = file_path
+        .file_name()
+        .filter(|file_name| file_name.contains([ANY_CHARS, ANY_CHAR]))
+    else {
+        return Ok(vec![file_path.to_path_buf()]);
+    };
+    let dir = match file_path.parent() {
+        Some(parent) if !parent.as_str().is_empty() => parent,
+        Some(_) | None => Utf8Path::new("."),
+    };
+    let entries = dir.read_dir_utf8().map_err(|err| RunError::OutputFileDir {
+        path: dir.to_path_buf(),
+        err,
+    })?;
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let mut file_paths = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| RunError::OutputFileDir {
+            path: dir.to_path_buf(),
+            err,
+        })?;
+        let name = entry.file_name().chars().collect::<Vec<_>>();
+        if glob_match(&pattern, &name) && entry.path().is_file() {
+            file_paths.push(entry.into_path());
+        }
+    }
+    if file_paths.is_empty() {
+        return Err(RunError::OutputFileGlob(file_path.to_path_buf()));
+    }
+    file_paths.sort();
+    Ok(file_paths)
+}
+
+// Iterative wildcard matching with backtracking to the last `*`
+fn glob_match(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while let Some(&name_char) = name.get(n) {
+        match pattern.get(p) {
+            Some(&ANY_CHARS) => {
+                backtrack = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == ANY_CHAR || c == name_char => {
+                p += 1;
+                n += 1;
+            },
+            _ => {
+                let Some((star_p, star_n)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star_p, star_n + 1));
+                p = star_p + 1;
+                n = star_n + 1;
+            },
+        }
+    }
+    pattern
+        .get(p..)
+        .map_or(true, |rest| rest.iter().all(|c| *c == ANY_CHARS))
+}
+
+#[cfg(test)]
+mod test {
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    use super::{expand_glob, glob_match};
+
+    fn is_match(pattern: &str, name: &str) -> bool {
+        glob_match(
+            &pattern.chars().collect::<Vec<_>>(),
+            &name.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    // A unique directory for each test, so tests can run in parallel
+    fn test_dir(name: &str) -> Utf8PathBuf {
+        let dir = Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("bencher_file_path_{}_{name}", std::process::id()));
+        drop(std::fs::remove_dir_all(&dir));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_glob_match_literal() {
+        assert!(is_match("results.json", "results.json"));
+        assert!(!is_match("results.json", "results.jsonl"));
+        assert!(!is_match("results.json", "result.json"));
+        assert!(is_match("", ""));
+        assert!(!is_match("", "a"));
+    }
+
+    #[test]
+    fn test_glob_match_any_char() {
+        assert!(is_match("results-?.json", "results-1.json"));
+        assert!(!is_match("results-?.json", "results-.json"));
+        assert!(!is_match("results-?.json", "results-10.json"));
+        assert!(is_match("???", "abc"));
+        assert!(!is_match("???", "ab"));
+    }
+
+    #[test]
+    fn test_glob_match_any_chars() {
+        assert!(is_match("*", ""));
+        assert!(is_match("*", "results.json"));
+        assert!(is_match("*.json", "results.json"));
+        assert!(is_match("*.json", ".json"));
+        assert!(!is_match("*.json", "results.jsonl"));
+        assert!(is_match("results*", "results"));
+        assert!(is_match("a*b*c", "abc"));
+        assert!(is_match("a*b*c", "a-b-b-c"));
+        assert!(!is_match("a*b*c", "a-b-b-d"));
+        assert!(is_match("**", "abc"));
+    }
+
+    #[test]
+    fn test_glob_match_backtrack() {
+        // The first `.json` does not end the name, so the `*` must consume it
+        assert!(is_match("*.json", "a.json.json"));
+        assert!(is_match("*-?.json", "a-b-1.json"));
+        assert!(!is_match("*-?.json", "a-b-12.json"));
+    }
+
+    #[test]
+    fn test_expand_glob_no_wildcard() {
+        let file_path = Utf8Path::new("does/not/exist.json");
+        assert_eq!(
+            expand_glob(file_path).unwrap(),
+            vec![file_path.to_path_buf()]
+        );
+    }
+
+    #[test]
+    fn test_expand_glob_sorted() {
+        let dir = test_dir("sorted");
+        for name in ["b.json", "a.json", "c.txt"] {
+            std::fs::write(dir.join(name), "{}").unwrap();
+        }
+        std::fs::create_dir(dir.join("d.json")).unwrap();
+
+        let file_paths = expand_glob(&dir.join("*.json")).unwrap();
+        assert_eq!(file_paths, vec![dir.join("a.json"), dir.join("b.json")]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_expand_glob_no_match() {
+        let dir = test_dir("no_match");
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+
+        assert!(expand_glob(&dir.join("*.json")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 
-    pub fn get_results(&self) -> Result<String, RunError> {
-        std::fs::read_to_string(&self.0).map_err(RunError::OutputFileRead)
+    #[test]
+    fn test_expand_glob_missing_dir() {
+        let dir = test_dir("missing_dir");
+        assert!(expand_glob(&dir.join("missing").join("*.json")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -2,26 +2,38 @@ use std::fmt;
 
 use crate::bencher::sub::RunError;
 
+use super::shell::Shell;
+
 const UNIX_FLAG: &str = "-c";
 const WINDOWS_FLAG: &str = "/C";
+const POWERSHELL_FLAG: &str = "-Command";
 
 #[derive(Debug, Clone)]
 pub enum Flag {
     Unix,
     Windows,
+    PowerShell,
     Custom(String),
 }
 
-impl TryFrom<Option<String>> for Flag {
-    type Error = RunError;
-
-    fn try_from(shell: Option<String>) -> Result<Self, Self::Error> {
-        Ok(if let Some(shell) = shell {
-            Self::Custom(shell)
+impl Flag {
+    /// Use the custom flag if given, otherwise the default flag for the shell
+    pub fn new(flag: Option<String>, shell: &Shell) -> Result<Self, RunError> {
+        Ok(if let Some(flag) = flag {
+            Self::Custom(flag)
+        } else if shell.is_powershell() {
+            Self::PowerShell
+        } else if shell.is_cmd() {
+            Self::Windows
         } else if cfg!(target_family = "unix") {
             Self::Unix
         } else if cfg!(target_family = "windows") {
-            Self::Windows
+            // A custom shell on Windows that is not `cmd` or PowerShell is likely a Unix-like shell, ie `bash`
+            if matches!(shell, Shell::Custom(_)) {
+                Self::Unix
+            } else {
+                Self::Windows
+            }
         } else {
             return Err(RunError::Flag);
         })
@@ -33,6 +45,7 @@ impl AsRef<str> for Flag {
         match self {
             Self::Unix => UNIX_FLAG,
             Self::Windows => WINDOWS_FLAG,
+            Self::PowerShell => POWERSHELL_FLAG,
             Self::Custom(shell) => shell,
         }
     }
@@ -0,0 +1,143 @@
+use std::{fmt, time::Instant};
+
+use bencher_json::{
+    project::{
+        measure::built_in::{self, BuiltInMeasure},
+        metric::MetricResults,
+    },
+    JsonNewMetric,
+};
+use url::Url;
+
+use crate::RunError;
+
+use super::output::Output;
+
+const DEFAULT_REQUESTS: u32 = 10;
+// The percentiles reported for all requests after the first
+const PERCENTILES: [usize; 3] = [50, 90, 99];
+
+#[derive(Debug, Clone)]
+pub struct Http {
+    url: Url,
+    requests: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Timing {
+    ttfb: f64,
+    latency: f64,
+}
+
+impl fmt::Display for Http {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GET {} ({} requests)", self.url, self.requests)
+    }
+}
+
+impl Http {
+    pub fn new(url: Url, requests: Option<u32>) -> Self {
+        Self {
+            url,
+            requests: requests.unwrap_or(DEFAULT_REQUESTS),
+        }
+    }
+
+    pub async fn run(&self) -> Result<Output, RunError> {
+        let client = reqwest::Client::new();
+        let mut timings = Vec::with_capacity(self.requests as usize);
+        for _ in 0..self.requests {
+            timings.push(self.request(&client).await?);
+        }
+        let results = JsonNewMetric::results(self.to_metric_results(timings)?);
+        let result = serde_json::to_string(&results).map_err(RunError::SerializeHttp)?;
+        Ok(Output {
+            results: vec![result],
+            ..Default::default()
+        })
+    }
+
+    async fn request(&self, client: &reqwest::Client) -> Result<Timing, RunError> {
+        let start = Instant::now();
+        let response = client
+            .get(self.url.clone())
+            .send()
+            .await
+            .map_err(RunError::HttpRequest)?;
+        // The response is returned as soon as the headers have been received
+        let ttfb = start.elapsed();
+        let status = response.status();
+        if !status.is_success() {
+            return Err(RunError::HttpStatus {
+                url: self.url.clone(),
+                status,
+            });
+        }
+        response.bytes().await.map_err(RunError::HttpRequest)?;
+        let latency = start.elapsed();
+        Ok(Timing {
+            ttfb: ttfb.as_secs_f64() * 1e9,
+            latency: latency.as_secs_f64() * 1e9,
+        })
+    }
+
+    // The first request includes connection setup, so it is reported as the cold start.
+    // All of the following requests reuse the connection and are reported as percentiles.
+    fn to_metric_results(&self, mut timings: Vec<Timing>) -> Result<MetricResults, RunError> {
+        let mut metric_results = Vec::with_capacity(PERCENTILES.len() + 1);
+        if timings.is_empty() {
+            return Ok(metric_results);
+        }
+        let cold_start = timings.remove(0);
+        metric_results.push((
+            self.benchmark_name("cold start")?,
+            metrics(cold_start.ttfb, cold_start.latency),
+        ));
+
+        let mut ttfbs = timings.iter().map(|t| t.ttfb).collect::<Vec<_>>();
+        let mut latencies = timings.iter().map(|t| t.latency).collect::<Vec<_>>();
+        ttfbs.sort_by(f64::total_cmp);
+        latencies.sort_by(f64::total_cmp);
+        for p in PERCENTILES {
+            if let (Some(ttfb), Some(latency)) = (percentile(&ttfbs, p), percentile(&latencies, p))
+            {
+                metric_results.push((
+                    self.benchmark_name(&format!("p{p}"))?,
+                    metrics(ttfb, latency),
+                ));
+            }
+        }
+        Ok(metric_results)
+    }
+
+    fn benchmark_name(&self, suffix: &str) -> Result<bencher_json::BenchmarkName, RunError> {
+        format!("GET {} ({suffix})", self.url)
+            .parse()
+            .map_err(RunError::HttpBenchmarkName)
+    }
+}
+
+fn metrics(ttfb: f64, latency: f64) -> Vec<(bencher_json::MeasureNameId, JsonNewMetric)> {
+    vec![
+        (
+            built_in::json::TimeToFirstByte::name_id(),
+            JsonNewMetric {
+                value: ttfb.into(),
+                ..Default::default()
+            },
+        ),
+        (
+            built_in::default::Latency::name_id(),
+            JsonNewMetric {
+                value: latency.into(),
+                ..Default::default()
+            },
+        ),
+    ]
+}
+
+// Nearest-rank percentile of a sorted list
+fn percentile(sorted: &[f64], p: usize) -> Option<f64> {
+    let rank = (sorted.len() * p).div_ceil(100);
+    sorted.get(rank.checked_sub(1)?).copied()
+}
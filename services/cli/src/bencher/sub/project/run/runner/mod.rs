@@ -2,13 +2,14 @@
 
 use std::fmt;
 
-use crate::parser::project::run::CliRunCommand;
+use crate::parser::project::run::{is_stdin_piped, CliRunCommand, CliRunHarness};
 
 mod build_time;
 pub mod command;
 mod file_path;
 mod file_size;
 mod flag;
+mod http;
 pub mod output;
 mod pipe;
 mod shell;
@@ -17,6 +18,7 @@ use build_time::BuildTime;
 use command::{Command, CommandOutput};
 use file_path::FilePath;
 use file_size::FileSize;
+use http::Http;
 use output::Output;
 use pipe::Pipe;
 
@@ -25,17 +27,28 @@ use super::RunError;
 #[derive(Debug, Clone)]
 pub enum Runner {
     Pipe(Pipe),
+    PipeWithFile(Pipe, FilePath),
     Command(Command, Option<BuildTime>),
+    CommandStream(Command),
     CommandToFile(Command, FilePath),
     CommandToFileSize(Command, Option<BuildTime>, FileSize),
     File(FilePath),
     FileSize(FileSize),
+    Http(Http),
 }
 
 impl TryFrom<CliRunCommand> for Runner {
     type Error = RunError;
 
     fn try_from(cmd: CliRunCommand) -> Result<Self, Self::Error> {
+        if let Some(harness) = cmd.harness {
+            return match harness {
+                CliRunHarness::Http => {
+                    let url = cmd.url.ok_or(RunError::HarnessUrl)?;
+                    Ok(Self::Http(Http::new(url, cmd.requests)))
+                },
+            };
+        }
         let program_arguments = cmd.command.and_then(|c| {
             let mut c = c.into_iter();
             c.next().map(|program| (program, c.collect::<Vec<_>>()))
@@ -52,20 +65,28 @@ impl TryFrom<CliRunCommand> for Runner {
                 Command::new_exec(program, arguments)
             };
             let build_time = cmd.build_time.then_some(BuildTime);
-            Ok(if let Some(file_path) = cmd.file {
-                Self::CommandToFile(command, FilePath::new(file_path))
+            Ok(if cmd.stream {
+                Self::CommandStream(command)
+            } else if let Some(file_paths) = cmd.file {
+                Self::CommandToFile(command, FilePath::new(file_paths))
             } else if let Some(file_paths) = cmd.file_size {
                 Self::CommandToFileSize(command, build_time, FileSize::new(file_paths))
             } else {
                 Self::Command(command, build_time)
             })
-        } else if let Some(file_path) = cmd.file {
-            Ok(Self::File(FilePath::new(file_path)))
+        } else if let Some(file_paths) = cmd.file {
+            let file_path = FilePath::new(file_paths);
+            if cmd.stdin {
+                Pipe::new(cmd.max_input_size).map(|pipe| Self::PipeWithFile(pipe, file_path))
+            } else {
+                Ok(Self::File(file_path))
+            }
         } else if let Some(file_paths) = cmd.file_size {
             Ok(Self::FileSize(FileSize::new(file_paths)))
-        } else if let Some(pipe) = Pipe::new() {
-            Ok(Self::Pipe(pipe))
+        } else if cmd.stdin || is_stdin_piped() {
+            Pipe::new(cmd.max_input_size).map(Self::Pipe)
         } else {
+            // Do not wait on an interactive or empty stdin
             Err(RunError::NoCommand)
         }
     }
@@ -75,6 +96,7 @@ impl fmt::Display for Runner {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Pipe(pipe) => write!(f, "{pipe}"),
+            Self::PipeWithFile(pipe, file_path) => write!(f, "{pipe} + {file_path}"),
             Self::Command(command, build_time) => write!(
                 f,
                 "{command}{build_time}",
@@ -84,6 +106,7 @@ impl fmt::Display for Runner {
                     ""
                 }
             ),
+            Self::CommandStream(command) => write!(f, "{command} (stream)"),
             Self::CommandToFile(command, file_path) => {
                 write!(f, "{command} > {file_path}")
             },
@@ -100,6 +123,7 @@ impl fmt::Display for Runner {
             },
             Self::File(file_path) => write!(f, "{file_path}"),
             Self::FileSize(file_path) => write!(f, "{file_path} (size)"),
+            Self::Http(http) => write!(f, "{http}"),
         }
     }
 }
@@ -108,7 +132,14 @@ impl Runner {
     pub async fn run(&self, log: bool) -> Result<Output, RunError> {
         match self {
             Self::Pipe(pipe) => Ok(pipe.output()),
+            Self::PipeWithFile(pipe, file_path) => {
+                let mut output = pipe.output();
+                // The results piped via stdin come before the results from the files
+                output.results.push(output.stdout.clone());
+                CommandOutput::new(None, output).build_with_file_path(file_path)
+            },
             Self::Command(command, build_time) => command.run(log, *build_time).await?.build(),
+            Self::CommandStream(command) => command.run_stream(log).await,
             Self::CommandToFile(command, file_path) => command
                 .run(log, None)
                 .await?
@@ -119,6 +150,21 @@ impl Runner {
                 .build_with_file_size(file_size),
             Self::File(file_path) => CommandOutput::default().build_with_file_path(file_path),
             Self::FileSize(file_size) => CommandOutput::default().build_with_file_size(file_size),
+            Self::Http(http) => http.run().await,
+        }
+    }
+
+    pub fn command(&self) -> Option<&Command> {
+        match self {
+            Self::Command(command, _)
+            | Self::CommandStream(command)
+            | Self::CommandToFile(command, _)
+            | Self::CommandToFileSize(command, _, _) => Some(command),
+            Self::Pipe(_)
+            | Self::PipeWithFile(_, _)
+            | Self::File(_)
+            | Self::FileSize(_)
+            | Self::Http(_) => None,
         }
     }
 }
@@ -1,11 +1,16 @@
 use std::fmt;
 
+use bencher_client::types::JsonReportFailure;
+
 #[derive(Debug, Clone, Default)]
 pub struct Output {
     pub status: ExitStatus,
     pub stdout: String,
     pub stderr: String,
-    pub result: Option<String>,
+    /// The results, if not the stdout of the command
+    pub results: Vec<String>,
+    /// The command failed, but some results were already streamed
+    pub partial: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -19,16 +24,11 @@ impl From<std::process::ExitStatus> for ExitStatus {
 
 impl fmt::Display for Output {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let result = if let Some(result) = self.result.as_deref() {
-            format!("\n{result}")
-        } else {
-            String::new()
-        };
-        write!(
-            f,
-            "{}\n{}\n{}{result}",
-            self.status, self.stdout, self.stderr
-        )
+        write!(f, "{}\n{}\n{}", self.status, self.stdout, self.stderr)?;
+        for result in &self.results {
+            write!(f, "\n{result}")?;
+        }
+        Ok(())
     }
 }
 
@@ -43,8 +43,24 @@ impl Output {
         self.status.is_success()
     }
 
-    pub fn result(self) -> String {
-        self.result.unwrap_or(self.stdout)
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    /// The exit code and stderr of the command, if it failed
+    pub fn failure(&self) -> Option<JsonReportFailure> {
+        (!self.is_success()).then(|| JsonReportFailure {
+            exit_code: self.status.0,
+            stderr: self.stderr.clone(),
+        })
+    }
+
+    pub fn results(self) -> Vec<String> {
+        if self.results.is_empty() {
+            vec![self.stdout]
+        } else {
+            self.results
+        }
     }
 }
 
@@ -1,24 +1,30 @@
-use std::{fmt, io::BufRead};
+use std::{fmt, io::Read};
 
-use super::Output;
+use super::{Output, RunError};
+
+// 64 MiB
+pub const DEFAULT_MAX_INPUT_SIZE: u64 = 64 * 1024 * 1024;
 
 #[derive(Debug, Clone)]
 pub struct Pipe(Output);
 
 impl Pipe {
-    pub fn new() -> Option<Self> {
+    pub fn new(max_input_size: Option<u64>) -> Result<Self, RunError> {
+        let max_input_size = max_input_size.unwrap_or(DEFAULT_MAX_INPUT_SIZE);
         let mut stdin = String::new();
-        let mut stdin_handle = std::io::stdin().lock();
-        while let Ok(size) = stdin_handle.read_line(&mut stdin) {
-            if size == 0 {
-                break;
-            }
-        }
+        // Read one byte past the limit to detect input that is too large
+        std::io::stdin()
+            .lock()
+            .take(max_input_size.saturating_add(1))
+            .read_to_string(&mut stdin)
+            .map_err(RunError::StdinRead)?;
 
-        if stdin.is_empty() {
-            None
+        if u64::try_from(stdin.len()).unwrap_or(u64::MAX) > max_input_size {
+            Err(RunError::StdinTooLarge(max_input_size))
+        } else if stdin.is_empty() {
+            Err(RunError::NoCommand)
         } else {
-            Some(Self(Output {
+            Ok(Self(Output {
                 stdout: stdin,
                 ..Default::default()
             }))
@@ -28,6 +28,37 @@ impl TryFrom<Option<String>> for Shell {
     }
 }
 
+impl Shell {
+    /// Whether the shell is `cmd`, which does not follow the usual Windows argument quoting rules
+    pub fn is_cmd(&self) -> bool {
+        match self {
+            Self::Windows => true,
+            Self::Unix => false,
+            Self::Custom(shell) => shell_name_is(shell, &["cmd"]),
+        }
+    }
+
+    /// Whether the shell is `PowerShell`, either `powershell` or `pwsh`
+    pub fn is_powershell(&self) -> bool {
+        match self {
+            Self::Unix | Self::Windows => false,
+            Self::Custom(shell) => shell_name_is(shell, &["powershell", "pwsh"]),
+        }
+    }
+}
+
+// Match the shell by its file name, ignoring any directory or extension (ie `C:\Windows\System32\cmd.exe`)
+fn shell_name_is(shell: &str, names: &[&str]) -> bool {
+    std::path::Path::new(shell)
+        .file_stem()
+        .and_then(|file_stem| file_stem.to_str())
+        .is_some_and(|file_stem| {
+            names
+                .iter()
+                .any(|name| file_stem.eq_ignore_ascii_case(name))
+        })
+}
+
 impl AsRef<str> for Shell {
     fn as_ref(&self) -> &str {
         match self {
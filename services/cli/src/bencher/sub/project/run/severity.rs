@@ -0,0 +1,12 @@
+use bencher_json::project::alert::AlertSeverity;
+
+use crate::parser::project::run::CliRunSeverity;
+
+impl From<CliRunSeverity> for AlertSeverity {
+    fn from(severity: CliRunSeverity) -> Self {
+        match severity {
+            CliRunSeverity::Warning => Self::Warning,
+            CliRunSeverity::Critical => Self::Critical,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use bencher_json::{
+    project::{
+        alert::AlertSeverity,
+        report::{JsonReportMeasure, JsonReportResult},
+    },
+    JsonAlert, JsonReport, Units,
+};
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::bencher::terminal::{Color, Terminal};
+
+const EMPTY_CELL: &str = "-";
+
+/// A row comparing the new value for a benchmark measure against its branch baseline
+#[derive(Tabled)]
+struct ResultRow {
+    #[tabled(rename = "Benchmark")]
+    benchmark: String,
+    #[tabled(rename = "Measure")]
+    measure: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Baseline")]
+    baseline: String,
+    #[tabled(rename = "Delta")]
+    delta: String,
+    #[tabled(rename = "Change")]
+    change: String,
+    #[tabled(rename = "Alert")]
+    alert: String,
+}
+
+/// Create a table comparing each benchmark result in the report against the branch baseline.
+/// Returns `None` if the report does not have any results.
+pub fn results_table(json_report: &JsonReport) -> Option<String> {
+    let multiple_iterations = json_report.results.len() > 1;
+    let rows = json_report
+        .results
+        .iter()
+        .flatten()
+        .flat_map(|result| {
+            result.measures.iter().map(move |report_measure| {
+                ResultRow::new(
+                    result,
+                    report_measure,
+                    &json_report.alerts,
+                    multiple_iterations,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    Terminal::get().wrap(&mut table);
+    Some(table.to_string())
+}
+
+impl ResultRow {
+    fn new(
+        result: &JsonReportResult,
+        report_measure: &JsonReportMeasure,
+        alerts: &[JsonAlert],
+        multiple_iterations: bool,
+    ) -> Self {
+        let benchmark = if multiple_iterations {
+            format!("{} (Iteration {})", result.benchmark.name, result.iteration)
+        } else {
+            result.benchmark.name.to_string()
+        };
+
+        let value = f64::from(report_measure.metric.value);
+        let baseline = report_measure
+            .boundary
+            .and_then(|boundary| boundary.baseline)
+            .map(f64::from);
+        // Scale all of the values for the row by the smaller of the value and the baseline
+        let min = baseline.map_or(value, |baseline| value.min(baseline));
+        let units = Units::new(min, report_measure.measure.units.clone());
+        let factor = f64::from(units.scale_factor());
+        let scale_units = units.scale_units();
+        let scaled =
+            |number: f64| format!("{} {scale_units}", Units::format_float(number / factor));
+
+        let (baseline, delta, change) = if let Some(baseline) = baseline {
+            let delta = value - baseline;
+            let change = if baseline.is_normal() {
+                let percent = (delta / baseline) * 100.0;
+                let plus = if percent > 0.0 { "+" } else { "" };
+                format!("{plus}{}%", Units::format_float(percent))
+            } else {
+                EMPTY_CELL.to_owned()
+            };
+            let plus = if delta > 0.0 { "+" } else { "" };
+            (scaled(baseline), format!("{plus}{}", scaled(delta)), change)
+        } else {
+            (
+                EMPTY_CELL.to_owned(),
+                EMPTY_CELL.to_owned(),
+                EMPTY_CELL.to_owned(),
+            )
+        };
+
+        let alert = alerts
+            .iter()
+            .find(|alert| {
+                alert.iteration == result.iteration
+                    && alert.benchmark.uuid == result.benchmark.uuid
+                    && alert.threshold.measure.uuid == report_measure.measure.uuid
+            })
+            .map_or_else(String::new, |alert| {
+                let terminal = Terminal::get();
+                match alert.severity {
+                    AlertSeverity::Warning => {
+                        terminal.paint(&format!("⚠️ {}", alert.severity), Color::Yellow)
+                    },
+                    AlertSeverity::Critical => {
+                        terminal.paint(&format!("🚨 {}", alert.severity), Color::Red)
+                    },
+                }
+            });
+
+        Self {
+            benchmark,
+            measure: report_measure.measure.name.to_string(),
+            value: scaled(value),
+            baseline,
+            delta,
+            change,
+            alert,
+        }
+    }
+}
@@ -42,6 +42,10 @@ pub enum ThresholdsError {
     ExtraLowerBoundaries(Vec<ElidedOption<Boundary>>),
     #[error("There are more upper boundaries than model tests")]
     ExtraUpperBoundaries(Vec<ElidedOption<Boundary>>),
+    #[error("There are more lower critical boundaries than model tests")]
+    ExtraLowerCriticals(Vec<ElidedOption<Boundary>>),
+    #[error("There are more upper critical boundaries than model tests")]
+    ExtraUpperCriticals(Vec<ElidedOption<Boundary>>),
 }
 
 impl TryFrom<CliRunThresholds> for Thresholds {
@@ -56,6 +60,8 @@ impl TryFrom<CliRunThresholds> for Thresholds {
             threshold_window,
             threshold_lower_boundary,
             threshold_upper_boundary,
+            threshold_lower_critical,
+            threshold_upper_critical,
             thresholds_reset,
         } = thresholds;
 
@@ -67,6 +73,8 @@ impl TryFrom<CliRunThresholds> for Thresholds {
         let mut windows = threshold_window.into_iter();
         let mut lower_boundaries = threshold_lower_boundary.into_iter();
         let mut upper_boundaries = threshold_upper_boundary.into_iter();
+        let mut lower_criticals = threshold_lower_critical.into_iter();
+        let mut upper_criticals = threshold_upper_critical.into_iter();
         for measure in threshold_measure {
             let test = tests
                 .next()
@@ -76,6 +84,8 @@ impl TryFrom<CliRunThresholds> for Thresholds {
             let window = windows.next();
             let lower_boundary = lower_boundaries.next();
             let upper_boundary = upper_boundaries.next();
+            let lower_critical = lower_criticals.next();
+            let upper_critical = upper_criticals.next();
 
             let cli_model = CliModel {
                 test,
@@ -84,6 +94,8 @@ impl TryFrom<CliRunThresholds> for Thresholds {
                 window: window.and_then(Into::into),
                 lower_boundary: lower_boundary.and_then(Into::into),
                 upper_boundary: upper_boundary.and_then(Into::into),
+                lower_critical: lower_critical.and_then(Into::into),
+                upper_critical: upper_critical.and_then(Into::into),
             };
             let model = Model::try_from(cli_model).map_err(|err| ThresholdsError::BadModel {
                 measure: measure.clone(),
@@ -125,6 +137,18 @@ impl TryFrom<CliRunThresholds> for Thresholds {
                 remaining_upper_boundaries,
             ));
         }
+        let remaining_lower_criticals = lower_criticals.collect::<Vec<_>>();
+        if !remaining_lower_criticals.is_empty() {
+            return Err(ThresholdsError::ExtraLowerCriticals(
+                remaining_lower_criticals,
+            ));
+        }
+        let remaining_upper_criticals = upper_criticals.collect::<Vec<_>>();
+        if !remaining_upper_criticals.is_empty() {
+            return Err(ThresholdsError::ExtraUpperCriticals(
+                remaining_upper_criticals,
+            ));
+        }
 
         Ok(Self {
             // Do not short circuit early if there are no measures
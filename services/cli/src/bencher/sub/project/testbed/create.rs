@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewTestbed;
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_json::{ResourceId, ResourceName, ScaleFactor, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
@@ -12,6 +12,8 @@ pub struct Create {
     pub project: ResourceId,
     pub name: ResourceName,
     pub slug: Option<Slug>,
+    pub scale: Option<ScaleFactor>,
+    pub upsert: bool,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +25,16 @@ impl TryFrom<CliTestbedCreate> for Create {
             project,
             name,
             slug,
+            scale,
+            upsert,
             backend,
         } = create;
         Ok(Self {
             project,
             name,
             slug,
+            scale,
+            upsert,
             backend: backend.try_into()?,
         })
     }
@@ -36,10 +42,18 @@ impl TryFrom<CliTestbedCreate> for Create {
 
 impl From<Create> for JsonNewTestbed {
     fn from(create: Create) -> Self {
-        let Create { name, slug, .. } = create;
+        let Create {
+            name,
+            slug,
+            scale,
+            upsert,
+            ..
+        } = create;
         Self {
             name: name.into(),
             slug: slug.map(Into::into),
+            scale: scale.map(Into::into),
+            upsert: Some(upsert),
         }
     }
 }
@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, ProjTestbedsSort};
 use bencher_json::{ResourceId, ResourceName};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::testbed::{CliTestbedList, CliTestbedsSort},
         CliPagination,
@@ -17,6 +17,7 @@ pub struct List {
     pub search: Option<String>,
     pub pagination: Pagination,
     pub archived: bool,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -38,15 +39,19 @@ impl TryFrom<CliTestbedList> for List {
             search,
             pagination,
             archived,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             name,
             search,
             archived,
             pagination: pagination.into(),
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -72,7 +77,7 @@ impl From<CliPagination<CliTestbedsSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_testbeds_get().project(self.project.clone());
@@ -100,6 +105,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -1,5 +1,5 @@
 use bencher_client::types::JsonUpdateTestbed;
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_json::{ResourceId, ResourceName, ScaleFactor, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
@@ -13,6 +13,7 @@ pub struct Update {
     pub testbed: ResourceId,
     pub name: Option<ResourceName>,
     pub slug: Option<Slug>,
+    pub scale: Option<ScaleFactor>,
     pub archived: Option<bool>,
     pub backend: AuthBackend,
 }
@@ -26,6 +27,7 @@ impl TryFrom<CliTestbedUpdate> for Update {
             testbed,
             name,
             slug,
+            scale,
             archived,
             backend,
         } = create;
@@ -34,6 +36,7 @@ impl TryFrom<CliTestbedUpdate> for Update {
             testbed,
             name,
             slug,
+            scale,
             archived: archived.into(),
             backend: backend.try_into()?,
         })
@@ -45,12 +48,14 @@ impl From<Update> for JsonUpdateTestbed {
         let Update {
             name,
             slug,
+            scale,
             archived,
             ..
         } = update;
         Self {
             name: name.map(Into::into),
             slug: slug.map(Into::into),
+            scale: scale.map(Into::into),
             archived,
         }
     }
@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewThreshold;
-use bencher_json::{NameId, ResourceId};
+use bencher_json::{BenchmarkGlob, NameId, ResourceId};
 
 use super::{model::Model, ThresholdError};
 use crate::{
@@ -14,6 +14,7 @@ pub struct Create {
     pub branch: NameId,
     pub testbed: NameId,
     pub measure: NameId,
+    pub benchmark: Option<BenchmarkGlob>,
     pub model: Model,
     pub backend: AuthBackend,
 }
@@ -27,6 +28,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark,
             model,
             backend,
         } = create;
@@ -35,6 +37,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark,
             model: model.try_into()?,
             backend: backend.try_into()?,
         })
@@ -58,6 +61,7 @@ impl From<Create> for JsonNewThreshold {
             testbed,
             model,
             measure,
+            benchmark,
             ..
         } = create;
         let Model {
@@ -67,17 +71,22 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         } = model;
         Self {
             branch: branch.into(),
             testbed: testbed.into(),
             measure: measure.into(),
+            benchmark: benchmark.map(Into::into),
             test,
             min_sample_size,
             max_sample_size,
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         }
     }
 }
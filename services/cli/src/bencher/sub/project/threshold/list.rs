@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, ProjThresholdsSort};
 use bencher_json::{project::threshold::JsonThresholdQuery, NameId, ResourceId};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::{
         project::threshold::{CliThresholdList, CliThresholdsSort},
         CliPagination,
@@ -18,6 +18,7 @@ pub struct List {
     pub measure: Option<NameId>,
     pub pagination: Pagination,
     pub archived: bool,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -40,8 +41,11 @@ impl TryFrom<CliThresholdList> for List {
             measure,
             pagination,
             archived,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             branch,
@@ -49,7 +53,8 @@ impl TryFrom<CliThresholdList> for List {
             measure,
             pagination: pagination.into(),
             archived,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -95,7 +100,7 @@ impl From<List> for JsonThresholdQuery {
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         let json_threshold_query: &JsonThresholdQuery = &self.clone().into();
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.proj_thresholds_get().project(self.project.clone());
@@ -130,6 +135,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
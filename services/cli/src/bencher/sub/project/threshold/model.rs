@@ -12,6 +12,8 @@ pub struct Model {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub lower_critical: Option<Boundary>,
+    pub upper_critical: Option<Boundary>,
 }
 
 impl TryFrom<CliModel> for Model {
@@ -25,6 +27,8 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         } = model;
         bencher_json::Model {
             test: test.into(),
@@ -33,6 +37,8 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         }
         .validate()
         .map_err(ThresholdError::BadModel)?;
@@ -43,6 +49,8 @@ impl TryFrom<CliModel> for Model {
             window: window.map(Into::into),
             lower_boundary: lower_boundary.map(Into::into),
             upper_boundary: upper_boundary.map(Into::into),
+            lower_critical: lower_critical.map(Into::into),
+            upper_critical: upper_critical.map(Into::into),
         })
     }
 }
@@ -57,6 +65,8 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::Percentile => Self::Percentile,
+            CliModelTest::Cusum => Self::Cusum,
         }
     }
 }
@@ -71,6 +81,8 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::Percentile => Self::Percentile,
+            CliModelTest::Cusum => Self::Cusum,
         }
     }
 }
@@ -84,6 +96,8 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         } = model;
         #[allow(clippy::inconsistent_struct_constructor)]
         bencher_client::types::Model {
@@ -93,6 +107,8 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            lower_critical,
+            upper_critical,
         }
     }
 }
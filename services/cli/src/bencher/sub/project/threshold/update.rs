@@ -31,6 +31,8 @@ impl TryFrom<CliThresholdUpdate> for Update {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    lower_critical,
+                    upper_critical,
                     remove_model,
                 },
             backend,
@@ -44,6 +46,8 @@ impl TryFrom<CliThresholdUpdate> for Update {
                 window,
                 lower_boundary,
                 upper_boundary,
+                lower_critical,
+                upper_critical,
             };
             Some(cli_model.try_into()?)
         } else if remove_model {
@@ -72,6 +76,8 @@ impl From<Update> for JsonUpdateThreshold {
                 window,
                 lower_boundary,
                 upper_boundary,
+                lower_critical,
+                upper_critical,
             } = model;
             #[allow(clippy::inconsistent_struct_constructor)]
             Self {
@@ -82,6 +88,8 @@ impl From<Update> for JsonUpdateThreshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    lower_critical,
+                    upper_critical,
                 }),
                 subtype_1: None,
             }
@@ -1,7 +1,7 @@
 use bencher_json::{ModelUuid, ResourceId, ThresholdUuid};
 
 use crate::{
-    bencher::{backend::PubBackend, sub::SubCmd},
+    bencher::{backend::PubBackend, sub::SubCmd, table::TableOutput},
     parser::project::threshold::CliThresholdView,
     CliError,
 };
@@ -11,6 +11,7 @@ pub struct View {
     pub project: ResourceId,
     pub threshold: ThresholdUuid,
     pub model: Option<ModelUuid>,
+    pub table: Option<TableOutput>,
     pub backend: PubBackend,
 }
 
@@ -22,20 +23,24 @@ impl TryFrom<CliThresholdView> for View {
             project,
             threshold,
             model,
+            table,
             backend,
         } = view;
+        let table = TableOutput::new(table);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             project,
             threshold,
             model,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client
@@ -50,6 +55,9 @@ impl SubCmd for View {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -37,6 +37,8 @@ impl From<CliBackupDataStore> for JsonDataStore {
     fn from(data_store: CliBackupDataStore) -> Self {
         match data_store {
             CliBackupDataStore::AwsS3 => Self::AwsS3,
+            CliBackupDataStore::Local => Self::Local,
+            CliBackupDataStore::AzureBlob => Self::AzureBlob,
         }
     }
 }
@@ -0,0 +1,85 @@
+use bencher_client::types::{JsonMaintenance, JsonMaintenanceOperation};
+use bencher_json::JsonMaintenanceResults;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    cli_eprintln, cli_println,
+    parser::system::server::{CliMaintenance, CliMaintenanceOperation},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Maintenance {
+    pub operations: Vec<JsonMaintenanceOperation>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliMaintenance> for Maintenance {
+    type Error = CliError;
+
+    fn try_from(maintenance: CliMaintenance) -> Result<Self, Self::Error> {
+        let CliMaintenance {
+            operations,
+            backend,
+        } = maintenance;
+        let operations = if operations.is_empty() {
+            vec![
+                JsonMaintenanceOperation::IntegrityCheck,
+                JsonMaintenanceOperation::Analyze,
+                JsonMaintenanceOperation::Vacuum,
+                JsonMaintenanceOperation::WalCheckpoint,
+            ]
+        } else {
+            operations.into_iter().map(Into::into).collect()
+        };
+        Ok(Self {
+            operations,
+            backend: AuthBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl From<CliMaintenanceOperation> for JsonMaintenanceOperation {
+    fn from(operation: CliMaintenanceOperation) -> Self {
+        match operation {
+            CliMaintenanceOperation::IntegrityCheck => Self::IntegrityCheck,
+            CliMaintenanceOperation::Analyze => Self::Analyze,
+            CliMaintenanceOperation::Vacuum => Self::Vacuum,
+            CliMaintenanceOperation::WalCheckpoint => Self::WalCheckpoint,
+        }
+    }
+}
+
+impl SubCmd for Maintenance {
+    // Run each operation as its own request in order to report progress
+    async fn exec(&self) -> Result<(), CliError> {
+        let total = self.operations.len();
+        let mut results = Vec::with_capacity(total);
+        for (index, operation) in self.operations.iter().enumerate() {
+            cli_eprintln!("Running {operation} ({}/{total})...", index + 1);
+            let json_results: JsonMaintenanceResults = self
+                .backend
+                .send_with(|client| async move {
+                    client
+                        .server_maintenance_post()
+                        .body(JsonMaintenance {
+                            operations: Some(vec![*operation]),
+                        })
+                        .send()
+                        .await
+                })
+                .await?;
+            for result in &json_results.results {
+                let status = if result.ok { "ok" } else { "FAILED" };
+                cli_eprintln!("Finished {operation} ({}/{total}): {status}", index + 1);
+            }
+            results.extend(json_results.results);
+        }
+        cli_println!(
+            "{}",
+            serde_json::to_string_pretty(&JsonMaintenanceResults { results })
+                .map_err(CliError::SerializeResults)?
+        );
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
+pub use self_test::SelfTestError;
+
 use crate::{bencher::sub::SubCmd, parser::system::server::CliServer, CliError};
 
 mod backup;
 mod config;
+mod maintenance;
+mod operation;
 mod restart;
+mod self_test;
 mod spec;
 mod stats;
 mod version;
@@ -14,6 +19,9 @@ pub enum Server {
     Restart(restart::Restart),
     Config(config::Config),
     Backup(backup::Backup),
+    Maintenance(maintenance::Maintenance),
+    Operation(operation::Operation),
+    SelfTest(self_test::SelfTest),
     #[cfg(feature = "plus")]
     Stats(stats::ServerStats),
 }
@@ -28,6 +36,9 @@ impl TryFrom<CliServer> for Server {
             CliServer::Restart(restart) => Self::Restart(restart.try_into()?),
             CliServer::Config(config) => Self::Config(config.try_into()?),
             CliServer::Backup(backup) => Self::Backup(backup.try_into()?),
+            CliServer::Maintenance(maintenance) => Self::Maintenance(maintenance.try_into()?),
+            CliServer::Operation(operation) => Self::Operation(operation.try_into()?),
+            CliServer::SelfTest(self_test) => Self::SelfTest(self_test.try_into()?),
             #[cfg(feature = "plus")]
             CliServer::Stats(stats) => Self::Stats(stats.try_into()?),
         })
@@ -42,6 +53,9 @@ impl SubCmd for Server {
             Self::Restart(restart) => restart.exec().await,
             Self::Config(config) => config.exec().await,
             Self::Backup(backup) => backup.exec().await,
+            Self::Maintenance(maintenance) => maintenance.exec().await,
+            Self::Operation(operation) => operation.exec().await,
+            Self::SelfTest(self_test) => self_test.exec().await,
             #[cfg(feature = "plus")]
             Self::Stats(stats) => stats.exec().await,
         }
@@ -0,0 +1,41 @@
+use bencher_json::OperationUuid;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::system::server::CliOperation,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub operation: OperationUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliOperation> for Operation {
+    type Error = CliError;
+
+    fn try_from(operation: CliOperation) -> Result<Self, Self::Error> {
+        let CliOperation { uuid, backend } = operation;
+        Ok(Self {
+            operation: uuid,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Operation {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .operation_get()
+                    .operation(self.operation)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use bencher_json::{JsonApiVersion, JsonOrganizations};
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::{
+    bencher::{
+        backend::{PubBackend, VersionMismatch},
+        sub::SubCmd,
+    },
+    cli_println,
+    parser::system::server::CliSelfTest,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct SelfTest {
+    pub has_token: bool,
+    pub backend: PubBackend,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SelfTestError {
+    #[error("Server self-test failed: {0} check(s) did not pass")]
+    Failed(usize),
+}
+
+impl TryFrom<CliSelfTest> for SelfTest {
+    type Error = CliError;
+
+    fn try_from(self_test: CliSelfTest) -> Result<Self, Self::Error> {
+        let CliSelfTest { backend } = self_test;
+        let has_token = backend.token.is_some();
+        Ok(Self {
+            has_token,
+            backend: PubBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Pass => "✅ pass",
+                Self::Warn => "⚠️ warn",
+                Self::Fail => "❌ fail",
+                Self::Skip => "⏭️ skip",
+            }
+        )
+    }
+}
+
+#[derive(Tabled)]
+struct Check {
+    #[tabled(rename = "Check")]
+    name: &'static str,
+    #[tabled(rename = "Status")]
+    status: Status,
+    #[tabled(rename = "Details")]
+    details: String,
+}
+
+impl SubCmd for SelfTest {
+    async fn exec(&self) -> Result<(), CliError> {
+        let checks = vec![self.ping().await, self.version().await, self.auth().await];
+        let failed = checks
+            .iter()
+            .filter(|check| check.status == Status::Fail)
+            .count();
+
+        let mut table = Table::new(checks);
+        table.with(Style::modern());
+        cli_println!("{table}");
+
+        if failed > 0 {
+            Err(SelfTestError::Failed(failed).into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl SelfTest {
+    async fn ping(&self) -> Check {
+        let (status, details) = match self.backend.ping().await {
+            Ok(()) => (Status::Pass, "Server is reachable".to_owned()),
+            Err(err) => (Status::Fail, err.to_string()),
+        };
+        Check {
+            name: "Ping",
+            status,
+            details,
+        }
+    }
+
+    async fn version(&self) -> Check {
+        let result: Result<JsonApiVersion, _> = self
+            .backend
+            .send_with(|client| async move { client.server_version_get().send().await })
+            .await;
+        let (status, details) = match result {
+            Ok(json_api_version) => {
+                let api_version = json_api_version.version.clone();
+                if let Some(mismatch) =
+                    VersionMismatch::check(self.backend.host(), json_api_version)
+                {
+                    let status = if mismatch.is_unsupported() {
+                        Status::Fail
+                    } else {
+                        Status::Warn
+                    };
+                    (status, mismatch.to_string())
+                } else {
+                    (
+                        Status::Pass,
+                        format!("Server and CLI are both {api_version}"),
+                    )
+                }
+            },
+            Err(err) => (Status::Fail, err.to_string()),
+        };
+        Check {
+            name: "Version",
+            status,
+            details,
+        }
+    }
+
+    async fn auth(&self) -> Check {
+        if !self.has_token {
+            return Check {
+                name: "Auth",
+                status: Status::Skip,
+                details: "No API token provided. Set the `--token` flag or the `BENCHER_API_TOKEN` environment variable.".to_owned(),
+            };
+        }
+
+        let result: Result<JsonOrganizations, _> = self
+            .backend
+            .send_with(|client| async move { client.organizations_get().send().await })
+            .await;
+        let (status, details) = match result {
+            Ok(json_organizations) => (
+                Status::Pass,
+                format!(
+                    "API token is valid with access to {} organization(s)",
+                    Vec::from(json_organizations).len()
+                ),
+            ),
+            Err(err) => (Status::Fail, err.to_string()),
+        };
+        Check {
+            name: "Auth",
+            status,
+            details,
+        }
+    }
+}
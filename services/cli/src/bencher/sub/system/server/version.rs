@@ -1,31 +1,107 @@
+use bencher_json::{system::version::JsonServerFeatures, JsonApiVersion};
+use tabled::{settings::Style, Table, Tabled};
+
 use crate::{
     bencher::{backend::PubBackend, sub::SubCmd},
+    cli_println,
     parser::system::server::CliVersion,
-    CliError,
+    CliError, CLI_VERSION,
 };
 
+const UNKNOWN: &str = "unknown";
+
 #[derive(Debug, Clone)]
 pub struct Version {
     pub backend: PubBackend,
 }
 
+#[derive(Tabled)]
+struct Row {
+    #[tabled(rename = "Name")]
+    name: &'static str,
+    #[tabled(rename = "Value")]
+    value: String,
+}
+
 impl TryFrom<CliVersion> for Version {
     type Error = CliError;
 
     fn try_from(version: CliVersion) -> Result<Self, Self::Error> {
         let CliVersion { backend } = version;
         Ok(Self {
-            backend: backend.try_into()?,
+            backend: PubBackend::try_from(backend)?.log(false),
         })
     }
 }
 
 impl SubCmd for Version {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json_api_version: JsonApiVersion = self
             .backend
-            .send(|client| async move { client.server_version_get().send().await })
+            .send_with(|client| async move { client.server_version_get().send().await })
             .await?;
+        let JsonApiVersion {
+            version,
+            min_client_version,
+            latest_client_version,
+            schema_version,
+            features,
+        } = json_api_version;
+
+        // Older servers do not report everything, so mark it as unknown
+        let unknown = || UNKNOWN.to_owned();
+        let rows = vec![
+            Row {
+                name: "CLI version",
+                value: CLI_VERSION.to_owned(),
+            },
+            Row {
+                name: "Server version",
+                value: version,
+            },
+            Row {
+                name: "API schema version",
+                value: schema_version.unwrap_or_else(unknown),
+            },
+            Row {
+                name: "Minimum CLI version",
+                value: min_client_version.unwrap_or_else(unknown),
+            },
+            Row {
+                name: "Latest CLI version",
+                value: latest_client_version.unwrap_or_else(unknown),
+            },
+            Row {
+                name: "TLS",
+                value: feature(features, |features| features.tls),
+            },
+            Row {
+                name: "GitHub OAuth",
+                value: feature(features, |features| features.oauth),
+            },
+            Row {
+                name: "Plans",
+                value: feature(features, |features| features.plans),
+            },
+        ];
+
+        let mut table = Table::new(rows);
+        table.with(Style::modern());
+        cli_println!("Host: {}", self.backend.host());
+        cli_println!("{table}");
+
         Ok(())
     }
 }
+
+fn feature(
+    features: Option<JsonServerFeatures>,
+    enabled: fn(JsonServerFeatures) -> bool,
+) -> String {
+    match features.map(enabled) {
+        Some(true) => "enabled",
+        Some(false) => "disabled",
+        None => UNKNOWN,
+    }
+    .to_owned()
+}
@@ -0,0 +1,53 @@
+use crate::{
+    bencher::{sub::SubCmd, telemetry},
+    cli_println,
+    parser::telemetry::CliTelemetry,
+    CliError,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Telemetry {
+    On,
+    Off,
+    Status,
+}
+
+impl From<CliTelemetry> for Telemetry {
+    fn from(telemetry: CliTelemetry) -> Self {
+        match telemetry {
+            CliTelemetry::On => Self::On,
+            CliTelemetry::Off => Self::Off,
+            CliTelemetry::Status => Self::Status,
+        }
+    }
+}
+
+impl SubCmd for Telemetry {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::On => {
+                let config_path = telemetry::set_enabled(true)?;
+                cli_println!("Anonymous CLI usage telemetry is on.");
+                cli_println!(
+                    "Only the subcommand, adapter, duration, and class of error are recorded."
+                );
+                cli_println!("Benchmark data is never included.");
+                cli_println!("To opt out, run `bencher telemetry off` or set `DO_NOT_TRACK=1`.");
+                cli_println!("Config: {}", config_path.display());
+            },
+            Self::Off => {
+                let config_path = telemetry::set_enabled(false)?;
+                cli_println!("Anonymous CLI usage telemetry is off.");
+                cli_println!("Config: {}", config_path.display());
+            },
+            Self::Status => {
+                if telemetry::is_enabled() {
+                    cli_println!("Anonymous CLI usage telemetry is on.");
+                } else {
+                    cli_println!("Anonymous CLI usage telemetry is off.");
+                }
+            },
+        }
+        Ok(())
+    }
+}
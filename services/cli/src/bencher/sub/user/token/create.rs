@@ -1,9 +1,9 @@
-use bencher_client::types::JsonNewToken;
-use bencher_json::{ResourceId, ResourceName};
+use bencher_client::types::{JsonNewToken, TokenScope};
+use bencher_json::{Cidr, ResourceId, ResourceName};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
-    parser::user::token::CliTokenCreate,
+    parser::user::token::{CliTokenCreate, CliTokenScope},
     CliError,
 };
 
@@ -12,6 +12,9 @@ pub struct Create {
     pub user: ResourceId,
     pub name: ResourceName,
     pub ttl: Option<u32>,
+    pub scope: Option<CliTokenScope>,
+    pub project: Option<ResourceId>,
+    pub allowed_ips: Option<Vec<Cidr>>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +26,18 @@ impl TryFrom<CliTokenCreate> for Create {
             user,
             name,
             ttl,
+            scope,
+            project,
+            allowed_ips,
             backend,
         } = create;
         Ok(Self {
             user,
             name,
             ttl,
+            scope,
+            project,
+            allowed_ips,
             backend: backend.try_into()?,
         })
     }
@@ -36,10 +45,31 @@ impl TryFrom<CliTokenCreate> for Create {
 
 impl From<Create> for JsonNewToken {
     fn from(create: Create) -> Self {
-        let Create { name, ttl, .. } = create;
+        let Create {
+            name,
+            ttl,
+            scope,
+            project,
+            allowed_ips,
+            ..
+        } = create;
         Self {
             name: name.into(),
             ttl,
+            scope: scope.map(Into::into),
+            project: project.map(Into::into),
+            allowed_ips: allowed_ips
+                .map(|allowed_ips| allowed_ips.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<CliTokenScope> for TokenScope {
+    fn from(scope: CliTokenScope) -> Self {
+        match scope {
+            CliTokenScope::Admin => Self::Admin,
+            CliTokenScope::ProjectRead => Self::ProjectRead,
+            CliTokenScope::ReportWrite => Self::ReportWrite,
         }
     }
 }
@@ -12,6 +12,8 @@ pub struct Update {
     pub user: ResourceId,
     pub token: TokenUuid,
     pub name: Option<ResourceName>,
+    pub rotate: bool,
+    pub ttl: Option<u32>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +25,16 @@ impl TryFrom<CliTokenUpdate> for Update {
             user,
             uuid: token,
             name,
+            rotate,
+            ttl,
             backend,
         } = view;
         Ok(Self {
             user,
             token,
             name,
+            rotate,
+            ttl,
             backend: backend.try_into()?,
         })
     }
@@ -36,9 +42,13 @@ impl TryFrom<CliTokenUpdate> for Update {
 
 impl From<Update> for JsonUpdateToken {
     fn from(update: Update) -> Self {
-        let Update { name, .. } = update;
+        let Update {
+            name, rotate, ttl, ..
+        } = update;
         Self {
             name: name.map(Into::into),
+            rotate: rotate.then_some(true),
+            ttl,
         }
     }
 }
@@ -0,0 +1,35 @@
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::user::CliUserDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub user: ResourceId,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliUserDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliUserDelete) -> Result<Self, Self::Error> {
+        let CliUserDelete { user, backend } = delete;
+        Ok(Self {
+            user,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move { client.user_delete().user(self.user.clone()).send().await })
+            .await?;
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use bencher_client::types::{JsonDirection, UsersSort};
 use bencher_json::UserName;
 
 use crate::{
-    bencher::{backend::AuthBackend, sub::SubCmd},
+    bencher::{backend::AuthBackend, sub::SubCmd, table::TableOutput},
     parser::{
         user::{CliUserList, CliUsersSort},
         CliPagination,
@@ -15,6 +15,7 @@ pub struct List {
     pub name: Option<UserName>,
     pub search: Option<String>,
     pub pagination: Pagination,
+    pub table: Option<TableOutput>,
     pub backend: AuthBackend,
 }
 
@@ -34,13 +35,17 @@ impl TryFrom<CliUserList> for List {
             name,
             search,
             pagination,
+            table,
             backend,
         } = list;
+        let table = TableOutput::new(table);
+        let backend = AuthBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             name,
             search,
             pagination: pagination.into(),
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
@@ -66,7 +71,7 @@ impl From<CliPagination<CliUsersSort>> for Pagination {
 
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move {
                 let mut client = client.users_get();
@@ -91,6 +96,9 @@ impl SubCmd for List {
                 client.send().await
             })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use crate::{bencher::sub::SubCmd, parser::user::CliUser, CliError};
 
+mod delete;
 mod list;
 mod update;
 mod view;
@@ -9,6 +10,7 @@ pub enum User {
     List(list::List),
     View(view::View),
     Update(update::Update),
+    Delete(delete::Delete),
 }
 
 impl TryFrom<CliUser> for User {
@@ -19,6 +21,7 @@ impl TryFrom<CliUser> for User {
             CliUser::List(list) => Self::List(list.try_into()?),
             CliUser::View(view) => Self::View(view.try_into()?),
             CliUser::Update(update) => Self::Update(update.try_into()?),
+            CliUser::Delete(delete) => Self::Delete(delete.try_into()?),
         })
     }
 }
@@ -29,6 +32,7 @@ impl SubCmd for User {
             Self::List(list) => list.exec().await,
             Self::View(view) => view.exec().await,
             Self::Update(update) => update.exec().await,
+            Self::Delete(delete) => delete.exec().await,
         }
     }
 }
@@ -1,7 +1,7 @@
 use bencher_json::ResourceId;
 
 use crate::{
-    bencher::{backend::AuthBackend, sub::SubCmd},
+    bencher::{backend::AuthBackend, sub::SubCmd, table::TableOutput},
     parser::user::CliUserView,
     CliError,
 };
@@ -9,6 +9,7 @@ use crate::{
 #[derive(Debug)]
 pub struct View {
     pub user: ResourceId,
+    pub table: Option<TableOutput>,
     pub backend: AuthBackend,
 }
 
@@ -16,20 +17,30 @@ impl TryFrom<CliUserView> for View {
     type Error = CliError;
 
     fn try_from(view: CliUserView) -> Result<Self, Self::Error> {
-        let CliUserView { user, backend } = view;
+        let CliUserView {
+            user,
+            table,
+            backend,
+        } = view;
+        let table = TableOutput::new(table);
+        let backend = AuthBackend::try_from(backend)?.log(table.is_none());
         Ok(Self {
             user,
-            backend: backend.try_into()?,
+            table,
+            backend,
         })
     }
 }
 
 impl SubCmd for View {
     async fn exec(&self) -> Result<(), CliError> {
-        let _json = self
+        let json = self
             .backend
             .send(|client| async move { client.user_get().user(self.user.clone()).send().await })
             .await?;
+        if let Some(table) = &self.table {
+            table.print_json(&json)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,24 @@
+use crate::{bencher::sub::SubCmd, cli_println, CliError, CLI_VERSION};
+
+const COMMIT: &str = env!("BENCHER_CLI_COMMIT");
+const COMMIT_DATE: &str = env!("BENCHER_CLI_COMMIT_DATE");
+const TARGET: &str = env!("BENCHER_CLI_TARGET");
+const RUSTC: &str = env!("BENCHER_CLI_RUSTC");
+
+#[derive(Debug, Clone, Copy)]
+pub struct Version {
+    pub verbose: bool,
+}
+
+impl SubCmd for Version {
+    async fn exec(&self) -> Result<(), CliError> {
+        cli_println!("bencher {CLI_VERSION}");
+        if self.verbose {
+            cli_println!("commit: {COMMIT}");
+            cli_println!("commit-date: {COMMIT_DATE}");
+            cli_println!("target: {TARGET}");
+            cli_println!("rustc: {RUSTC}");
+        }
+        Ok(())
+    }
+}
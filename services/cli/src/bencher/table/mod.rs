@@ -0,0 +1,255 @@
+use serde_json::Value;
+use tabled::{builder::Builder, Tabled};
+
+use crate::{bencher::terminal::Terminal, cli_println, parser::CliTable};
+
+mod style;
+
+pub use style::TableStyle;
+
+// The column name used for rows that are not JSON objects
+const VALUE_COLUMN: &str = "value";
+
+#[derive(thiserror::Error, Debug)]
+pub enum TableError {
+    #[error("Unknown table column ({column}). Available columns: {}", available.join(", "))]
+    UnknownColumn {
+        column: String,
+        available: Vec<String>,
+    },
+}
+
+/// Render command results as a table or as tab or comma-separated values
+#[derive(Debug, Clone)]
+pub struct TableOutput {
+    format: TableFormat,
+    columns: Option<Vec<String>>,
+    header: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TableFormat {
+    Table(Option<TableStyle>),
+    Tsv,
+    Csv,
+}
+
+impl TableOutput {
+    /// Returns `None` if the results should be output as JSON instead
+    pub fn new(table: CliTable) -> Option<Self> {
+        let CliTable {
+            table,
+            tsv,
+            csv,
+            columns,
+            no_header,
+        } = table;
+        let format = if tsv {
+            TableFormat::Tsv
+        } else if csv {
+            TableFormat::Csv
+        } else if let Some(table_style) = table {
+            TableFormat::Table(table_style.map(Into::into))
+        } else if columns.is_some() || no_header {
+            TableFormat::Table(None)
+        } else {
+            return None;
+        };
+        Some(Self {
+            format,
+            columns,
+            header: !no_header,
+        })
+    }
+
+    /// Print a JSON response, with each element of an array as a row.
+    /// The default columns are the top-level fields of the rows.
+    pub fn print_json(&self, json: &Value) -> Result<(), TableError> {
+        let rows: Vec<&Value> = match json {
+            Value::Array(rows) => rows.iter().collect(),
+            row @ (Value::Null
+            | Value::Bool(_)
+            | Value::Number(_)
+            | Value::String(_)
+            | Value::Object(_)) => vec![row],
+        };
+
+        let mut available = Vec::new();
+        for row in &rows {
+            if let Value::Object(object) = row {
+                for key in object.keys() {
+                    if !available.contains(key) {
+                        available.push(key.clone());
+                    }
+                }
+            } else if !available.iter().any(|column| column == VALUE_COLUMN) {
+                available.push(VALUE_COLUMN.to_owned());
+            }
+        }
+
+        let columns = if let Some(columns) = &self.columns {
+            for column in columns {
+                let is_known =
+                    rows.is_empty() || rows.iter().any(|row| lookup(row, column).is_some());
+                if !is_known {
+                    return Err(TableError::UnknownColumn {
+                        column: column.clone(),
+                        available,
+                    });
+                }
+            }
+            columns.clone()
+        } else {
+            available
+        };
+
+        let records = rows
+            .into_iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| lookup(row, column).map(cell).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+        self.print(columns, records);
+        Ok(())
+    }
+
+    /// Print rows that have already been flattened into a table.
+    /// Columns are selected by header, in `snake_case`.
+    pub fn print_tabled<T>(&self, rows: &[T]) -> Result<(), TableError>
+    where
+        T: Tabled,
+    {
+        let available: Vec<String> = T::headers()
+            .iter()
+            .map(|header| header.to_lowercase().replace(' ', "_"))
+            .collect();
+
+        let indices = if let Some(columns) = &self.columns {
+            let mut indices = Vec::with_capacity(columns.len());
+            for column in columns {
+                let Some(index) = available.iter().position(|header| header == column) else {
+                    return Err(TableError::UnknownColumn {
+                        column: column.clone(),
+                        available,
+                    });
+                };
+                indices.push(index);
+            }
+            indices
+        } else {
+            (0..available.len()).collect()
+        };
+
+        let headers = T::headers();
+        let columns = indices
+            .iter()
+            .filter_map(|&index| headers.get(index).map(ToString::to_string))
+            .collect();
+        let records = rows
+            .iter()
+            .map(|row| {
+                let fields = row.fields();
+                indices
+                    .iter()
+                    .map(|&index| {
+                        fields
+                            .get(index)
+                            .map(ToString::to_string)
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        self.print(columns, records);
+        Ok(())
+    }
+
+    fn print(&self, columns: Vec<String>, records: Vec<Vec<String>>) {
+        match self.format {
+            TableFormat::Table(table_style) => {
+                let mut builder = Builder::default();
+                if self.header {
+                    builder.push_record(columns);
+                }
+                for record in records {
+                    builder.push_record(record);
+                }
+                let mut table = builder.build();
+                if let Some(table_style) = table_style {
+                    table_style.stylize(&mut table);
+                }
+                Terminal::get().wrap(&mut table);
+                cli_println!("{table}");
+            },
+            TableFormat::Tsv => {
+                if self.header {
+                    cli_println!("{}", tsv_record(&columns));
+                }
+                for record in records {
+                    cli_println!("{}", tsv_record(&record));
+                }
+            },
+            TableFormat::Csv => {
+                if self.header {
+                    cli_println!("{}", csv_record(&columns));
+                }
+                for record in records {
+                    cli_println!("{}", csv_record(&record));
+                }
+            },
+        }
+    }
+}
+
+// Look up a `.` separated path of object keys or array indices
+fn lookup<'v>(row: &'v Value, column: &str) -> Option<&'v Value> {
+    if !row.is_object() {
+        return (column == VALUE_COLUMN).then_some(row);
+    }
+    column.split('.').try_fold(row, |value, key| match value {
+        Value::Object(object) => object.get(key),
+        Value::Array(array) => key.parse::<usize>().ok().and_then(|index| array.get(index)),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => None,
+    })
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+// Escape the characters that would otherwise break the tab-separated structure
+fn tsv_record(record: &[String]) -> String {
+    record
+        .iter()
+        .map(|field| {
+            field
+                .replace('\\', "\\\\")
+                .replace('\t', "\\t")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+// Quote any fields that would otherwise break the comma-separated structure (RFC 4180)
+fn csv_record(record: &[String]) -> String {
+    record
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
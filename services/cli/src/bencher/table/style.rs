@@ -1,6 +1,6 @@
 use tabled::{settings::Style, Table};
 
-use crate::parser::project::perf::CliPerfTableStyle;
+use crate::parser::CliTableStyle;
 
 #[derive(Debug, Clone, Copy)]
 pub enum TableStyle {
@@ -18,21 +18,21 @@ pub enum TableStyle {
     Dots,
 }
 
-impl From<CliPerfTableStyle> for TableStyle {
-    fn from(table_style: CliPerfTableStyle) -> Self {
+impl From<CliTableStyle> for TableStyle {
+    fn from(table_style: CliTableStyle) -> Self {
         match table_style {
-            CliPerfTableStyle::Empty => TableStyle::Empty,
-            CliPerfTableStyle::Blank => TableStyle::Blank,
-            CliPerfTableStyle::Ascii => TableStyle::Ascii,
-            CliPerfTableStyle::AsciiRounded => TableStyle::AsciiRounded,
-            CliPerfTableStyle::Modern => TableStyle::Modern,
-            CliPerfTableStyle::Sharp => TableStyle::Sharp,
-            CliPerfTableStyle::Rounded => TableStyle::Rounded,
-            CliPerfTableStyle::Psql => TableStyle::Psql,
-            CliPerfTableStyle::Markdown => TableStyle::Markdown,
-            CliPerfTableStyle::ReStructuredText => TableStyle::ReStructuredText,
-            CliPerfTableStyle::Extended => TableStyle::Extended,
-            CliPerfTableStyle::Dots => TableStyle::Dots,
+            CliTableStyle::Empty => TableStyle::Empty,
+            CliTableStyle::Blank => TableStyle::Blank,
+            CliTableStyle::Ascii => TableStyle::Ascii,
+            CliTableStyle::AsciiRounded => TableStyle::AsciiRounded,
+            CliTableStyle::Modern => TableStyle::Modern,
+            CliTableStyle::Sharp => TableStyle::Sharp,
+            CliTableStyle::Rounded => TableStyle::Rounded,
+            CliTableStyle::Psql => TableStyle::Psql,
+            CliTableStyle::Markdown => TableStyle::Markdown,
+            CliTableStyle::ReStructuredText => TableStyle::ReStructuredText,
+            CliTableStyle::Extended => TableStyle::Extended,
+            CliTableStyle::Dots => TableStyle::Dots,
         }
     }
 }
@@ -0,0 +1,213 @@
+use std::{
+    env::consts,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use bencher_client::types::{Adapter, JsonTelemetry, JsonTelemetryEvent};
+use bencher_json::{DateTime, BENCHER_API_URL};
+use serde::{Deserialize, Serialize};
+
+use crate::{CliError, CLI_VERSION};
+
+const BENCHER_CONFIG_DIR: &str = "BENCHER_CONFIG_DIR";
+// https://consoledonottrack.com
+const DO_NOT_TRACK: &str = "DO_NOT_TRACK";
+const CONFIG_FILE: &str = "telemetry.json";
+const QUEUE_FILE: &str = "telemetry_queue.jsonl";
+const TELEMETRY_PATH: &str = "/v0/server/telemetry";
+// Send the queued events once there are this many
+const FLUSH_SIZE: usize = 20;
+// Drop the oldest queued events once there are more than this many
+const MAX_QUEUE_SIZE: usize = 100;
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(thiserror::Error, Debug)]
+pub enum TelemetryError {
+    #[error(
+        "Failed to find a config directory. Set `BENCHER_CONFIG_DIR` to use a custom directory."
+    )]
+    NoConfigDir,
+    #[error("Failed to create config directory ({}): {err}", path.display())]
+    CreateConfigDir { path: PathBuf, err: std::io::Error },
+    #[error("Failed to write telemetry config ({}): {err}", path.display())]
+    WriteConfig { path: PathBuf, err: std::io::Error },
+    #[error("Failed to remove telemetry queue ({}): {err}", path.display())]
+    RemoveQueue { path: PathBuf, err: std::io::Error },
+    #[error("Failed to serialize telemetry config: {0}")]
+    SerializeConfig(serde_json::Error),
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+struct TelemetryConfig {
+    enabled: bool,
+}
+
+/// Anonymous CLI usage telemetry.
+/// Only the subcommand, adapter, duration, and class of error are ever recorded.
+/// Benchmark data is never included.
+#[derive(Debug)]
+pub struct Telemetry {
+    command: String,
+    adapter: Option<Adapter>,
+}
+
+impl Telemetry {
+    pub fn new(matches: &clap::ArgMatches, adapter: Option<Adapter>) -> Self {
+        let mut command = Vec::new();
+        let mut matches = matches;
+        while let Some((name, sub_matches)) = matches.subcommand() {
+            command.push(name);
+            matches = sub_matches;
+        }
+        Self {
+            command: command.join(" "),
+            adapter,
+        }
+    }
+
+    /// Record the subcommand if telemetry is enabled.
+    /// Telemetry must never get in the way of the user, so all errors are ignored.
+    pub async fn record(&self, start: Instant, err: Option<&CliError>) {
+        // Printing the version and toggling telemetry are never recorded
+        if self.command.is_empty() || self.command.starts_with("telemetry") || !is_enabled() {
+            return;
+        }
+        let Some(config_dir) = config_dir() else {
+            return;
+        };
+        let event = JsonTelemetryEvent {
+            command: self.command.clone(),
+            adapter: self.adapter,
+            duration: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+            error: err.map(error_class),
+            created: DateTime::now().into(),
+        };
+        let queue_path = config_dir.join(QUEUE_FILE);
+        let mut events = read_queue(&queue_path);
+        events.push(event);
+        if events.len() >= FLUSH_SIZE && flush(events.clone()).await {
+            drop(fs::remove_file(&queue_path));
+            return;
+        }
+        let overflow = events.len().saturating_sub(MAX_QUEUE_SIZE);
+        write_queue(
+            &config_dir,
+            &queue_path,
+            events.get(overflow..).unwrap_or_default(),
+        );
+    }
+}
+
+/// Whether the user has opted in to telemetry
+pub fn is_enabled() -> bool {
+    if std::env::var(DO_NOT_TRACK).is_ok_and(|value| !value.is_empty() && value != "0") {
+        return false;
+    }
+    config_dir()
+        .and_then(|config_dir| fs::read_to_string(config_dir.join(CONFIG_FILE)).ok())
+        .and_then(|config| serde_json::from_str::<TelemetryConfig>(&config).ok())
+        .is_some_and(|config| config.enabled)
+}
+
+/// Opt in to or out of telemetry.
+/// Opting out also removes any events that have not been sent yet.
+pub fn set_enabled(enabled: bool) -> Result<PathBuf, TelemetryError> {
+    let config_dir = config_dir().ok_or(TelemetryError::NoConfigDir)?;
+    fs::create_dir_all(&config_dir).map_err(|err| TelemetryError::CreateConfigDir {
+        path: config_dir.clone(),
+        err,
+    })?;
+    let config_path = config_dir.join(CONFIG_FILE);
+    let config = serde_json::to_string_pretty(&TelemetryConfig { enabled })
+        .map_err(TelemetryError::SerializeConfig)?;
+    fs::write(&config_path, config).map_err(|err| TelemetryError::WriteConfig {
+        path: config_path.clone(),
+        err,
+    })?;
+    if !enabled {
+        let queue_path = config_dir.join(QUEUE_FILE);
+        if queue_path.exists() {
+            fs::remove_file(&queue_path).map_err(|err| TelemetryError::RemoveQueue {
+                path: queue_path,
+                err,
+            })?;
+        }
+    }
+    Ok(config_path)
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(config_dir) = std::env::var_os(BENCHER_CONFIG_DIR) {
+        return Some(PathBuf::from(config_dir));
+    }
+    let config_dir = if let Some(config_dir) = std::env::var_os("XDG_CONFIG_HOME") {
+        PathBuf::from(config_dir)
+    } else if cfg!(windows) {
+        PathBuf::from(std::env::var_os("APPDATA")?)
+    } else {
+        PathBuf::from(std::env::var_os("HOME")?).join(".config")
+    };
+    Some(config_dir.join("bencher"))
+}
+
+// Only keep the names of the error variants, ie `Run::NoCommand`.
+// Anything after the first string literal is dropped,
+// so no user provided values are ever included.
+fn error_class(err: &CliError) -> String {
+    let debug = format!("{err:?}");
+    let debug = debug.split('"').next().unwrap_or_default();
+    debug
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|name| !name.is_empty())
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn read_queue(queue_path: &Path) -> Vec<JsonTelemetryEvent> {
+    fs::read_to_string(queue_path)
+        .map(|queue| {
+            queue
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn write_queue(config_dir: &Path, queue_path: &Path, events: &[JsonTelemetryEvent]) {
+    if fs::create_dir_all(config_dir).is_err() {
+        return;
+    }
+    let Ok(mut queue) = fs::File::create(queue_path) else {
+        return;
+    };
+    for event in events {
+        if let Ok(line) = serde_json::to_string(event) {
+            drop(writeln!(queue, "{line}"));
+        }
+    }
+}
+
+async fn flush(events: Vec<JsonTelemetryEvent>) -> bool {
+    let Ok(url) = BENCHER_API_URL.join(TELEMETRY_PATH) else {
+        return false;
+    };
+    let telemetry = JsonTelemetry {
+        version: CLI_VERSION.into(),
+        os: consts::OS.into(),
+        events,
+    };
+    let Ok(client) = reqwest::Client::builder().timeout(FLUSH_TIMEOUT).build() else {
+        return false;
+    };
+    client
+        .post(url)
+        .json(&telemetry)
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success())
+}
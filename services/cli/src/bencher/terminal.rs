@@ -0,0 +1,89 @@
+use std::{io::IsTerminal, sync::OnceLock};
+
+use tabled::{
+    settings::{peaker::PriorityMax, Width},
+    Table,
+};
+
+use crate::parser::CliColor;
+
+// Used when the output is not a terminal and `COLUMNS` is not set, such as in CI logs
+const DEFAULT_WIDTH: usize = 120;
+// Tables are never wrapped narrower than this, so every column stays readable
+const MIN_WIDTH: usize = 40;
+
+static TERMINAL: OnceLock<Terminal> = OnceLock::new();
+
+/// How output should be rendered for the current terminal
+#[derive(Debug, Clone, Copy)]
+pub struct Terminal {
+    color: bool,
+    width: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Color {
+    Red,
+    Yellow,
+}
+
+impl Terminal {
+    /// Set the terminal settings for the rest of the process.
+    /// This should be called once, before any output is rendered.
+    pub fn init(color: CliColor) {
+        let _set = TERMINAL.set(Self::new(color));
+    }
+
+    pub fn get() -> Self {
+        *TERMINAL.get_or_init(|| Self::new(CliColor::Auto))
+    }
+
+    fn new(color: CliColor) -> Self {
+        let is_terminal = std::io::stdout().is_terminal();
+        let color = match color {
+            CliColor::Always => true,
+            CliColor::Never => false,
+            // https://no-color.org
+            CliColor::Auto => {
+                let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+                let dumb = std::env::var_os("TERM").is_some_and(|term| term == "dumb");
+                is_terminal && !no_color && !dumb
+            },
+        };
+        // An explicit `COLUMNS` takes precedence, so the width can be set for CI logs
+        let width = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.parse().ok())
+            .or_else(|| {
+                is_terminal
+                    .then(terminal_size::terminal_size)
+                    .flatten()
+                    .map(|(terminal_size::Width(width), _)| usize::from(width))
+            })
+            .unwrap_or(DEFAULT_WIDTH)
+            .max(MIN_WIDTH);
+        Self { color, width }
+    }
+
+    /// Color the text, if color output is enabled
+    pub fn paint(self, text: &str, color: Color) -> String {
+        if !self.color || text.is_empty() {
+            return text.to_owned();
+        }
+        let code = match color {
+            Color::Red => "31",
+            Color::Yellow => "33",
+        };
+        format!("\x1b[{code}m{text}\x1b[0m")
+    }
+
+    /// Wrap the table to fit the terminal width.
+    /// The widest columns, usually benchmark names, are wrapped first.
+    pub fn wrap(self, table: &mut Table) {
+        table.with(
+            Width::wrap(self.width)
+                .priority(PriorityMax)
+                .keep_words(true),
+        );
+    }
+}
@@ -5,6 +5,10 @@ pub enum CliError {
     #[error("{0}")]
     Backend(#[from] crate::bencher::BackendError),
     #[error("{0}")]
+    Table(#[from] crate::bencher::TableError),
+    #[error("{0}")]
+    Report(#[from] crate::bencher::sub::ReportError),
+    #[error("{0}")]
     Run(#[from] crate::bencher::sub::RunError),
     #[error("{0}")]
     Archive(#[from] crate::bencher::sub::ArchiveError),
@@ -13,10 +17,20 @@ pub enum CliError {
     #[error("{0}")]
     Thresholds(#[from] crate::bencher::sub::ThresholdsError),
     #[error("{0}")]
+    Telemetry(#[from] crate::bencher::TelemetryError),
+    #[error("{0}")]
+    Spool(#[from] crate::bencher::SpoolError),
+    #[error("{0}")]
+    SelfTest(#[from] crate::bencher::sub::SelfTestError),
+    #[cfg(feature = "mock")]
+    #[error("{0}")]
     Mock(#[from] crate::bencher::sub::MockError),
+    #[cfg(feature = "docker")]
     #[error("{0}")]
     Docker(#[from] crate::bencher::sub::DockerError),
 
     #[error("Failed to serialize config: {0}")]
     SerializeConfig(serde_json::Error),
+    #[error("Failed to serialize results: {0}")]
+    SerializeResults(serde_json::Error),
 }
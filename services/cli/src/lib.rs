@@ -2,9 +2,11 @@ mod bencher;
 mod error;
 mod parser;
 
+#[cfg(feature = "mock")]
+pub use bencher::sub::MockError;
 use bencher::{sub::SubCmd, Bencher};
 pub use bencher::{
-    sub::{MockError, RunError, ThresholdError},
+    sub::{RunError, ThresholdError},
     BackendError,
 };
 pub use error::CliError;
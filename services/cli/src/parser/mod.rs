@@ -3,14 +3,19 @@ use std::str::FromStr;
 use bencher_json::{Jwt, Url, BENCHER_API_URL_STR};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
+#[cfg(feature = "docker")]
 pub mod docker;
+#[cfg(feature = "mock")]
 pub mod mock;
 pub mod organization;
 pub mod project;
 pub mod system;
+pub mod telemetry;
 pub mod user;
 
+#[cfg(feature = "docker")]
 use docker::{CliDown, CliLogs, CliUp};
+#[cfg(feature = "mock")]
 use mock::CliMock;
 use organization::{member::CliMember, CliOrganization};
 use project::{
@@ -19,21 +24,66 @@ use project::{
     run::CliRun, testbed::CliTestbed, threshold::CliThreshold, CliProject,
 };
 use system::{auth::CliAuth, server::CliServer};
+use telemetry::CliTelemetry;
 use user::{token::CliToken, CliUser};
 
 /// Bencher CLI
 #[derive(Parser, Debug)]
-#[clap(name = "bencher", author, version, about, long_about = None)]
+#[clap(
+    name = "bencher",
+    author,
+    version,
+    about,
+    long_about = None,
+    disable_version_flag = true,
+    args_conflicts_with_subcommands = true,
+    arg_required_else_help = true
+)]
 pub struct CliBencher {
+    /// Print version
+    #[clap(short = 'V', long)]
+    pub version: bool,
+
+    /// Print the commit, target, and compiler along with the version
+    #[clap(long, requires = "version")]
+    pub verbose: bool,
+
+    /// When to use color output (`auto` respects `NO_COLOR`)
+    #[clap(long, value_name = "WHEN", global = true, default_value = "auto")]
+    pub color: CliColor,
+
     /// Bencher subcommands
     #[clap(subcommand)]
-    pub sub: CliSub,
+    pub sub: Option<CliSub>,
+}
+
+impl CliBencher {
+    /// Validate the parsed arguments beyond what `clap` is able to check on its own
+    pub fn validate(&self) -> Result<(), clap::Error> {
+        match &self.sub {
+            Some(CliSub::Run(run)) => run.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Supported Color Choices
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliColor {
+    /// Use color if the output is a terminal and `NO_COLOR` is not set
+    Auto,
+    /// Always use color
+    Always,
+    /// Never use color
+    Never,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum CliSub {
     /// Run benchmarks
     Run(Box<CliRun>),
+    #[cfg(feature = "mock")]
     /// Generate mock benchmark data
     Mock(CliMock),
 
@@ -42,10 +92,13 @@ pub enum CliSub {
     /// Unarchive a dimension
     Unarchive(CliArchive),
 
+    #[cfg(feature = "docker")]
     /// Create and start Bencher Self-Hosted containers
     Up(CliUp),
+    #[cfg(feature = "docker")]
     /// View output from Bencher Self-Hosted containers
     Logs(CliLogs),
+    #[cfg(feature = "docker")]
     /// Stop and remove Bencher Self-Hosted containers
     Down(CliDown),
 
@@ -110,6 +163,10 @@ pub enum CliSub {
     /// Server authentication & authorization
     #[clap(subcommand)]
     Auth(CliAuth),
+
+    /// Anonymous CLI usage telemetry (opt-in)
+    #[clap(subcommand)]
+    Telemetry(CliTelemetry),
 }
 
 #[allow(clippy::doc_markdown)]
@@ -127,15 +184,75 @@ pub struct CliBackend {
     #[clap(long, value_name = "COUNT", default_value = "10")]
     pub attempts: usize,
 
-    /// Initial seconds to wait between attempts (exponential backoff)
-    #[clap(long, value_name = "SECONDS", default_value = "1")]
+    /// Initial seconds to wait between attempts (exponential backoff with jitter)
+    #[clap(
+        long,
+        visible_alias = "retry-delay",
+        value_name = "SECONDS",
+        default_value = "1"
+    )]
     pub retry_after: u64,
 
-    /// Strictly parse JSON responses
+    /// Strictly parse JSON responses and fail if this CLI version is no longer supported by the API server
     #[clap(long)]
     pub strict: bool,
 }
 
+#[derive(Args, Debug)]
+#[allow(clippy::option_option)]
+pub struct CliTable {
+    /// Output results in a table, optionally with a table style
+    #[clap(long, value_name = "STYLE")]
+    pub table: Option<Option<CliTableStyle>>,
+
+    /// Output results as tab-separated values
+    #[clap(long, conflicts_with = "table")]
+    pub tsv: bool,
+
+    /// Output results as comma-separated values
+    #[clap(long, conflicts_with_all = ["table", "tsv"])]
+    pub csv: bool,
+
+    /// Comma separated list of columns to output (default all)
+    /// Nested fields can be selected with a `.` separator (ex: `branch.name`)
+    #[clap(long, value_name = "COLUMNS", value_delimiter = ',')]
+    pub columns: Option<Vec<String>>,
+
+    /// Do not output the table header
+    #[clap(long)]
+    pub no_header: bool,
+}
+
+/// Supported Table Formats
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliTableStyle {
+    /// No styling options
+    Empty,
+    /// Analog of `empty` but with a vertical space (` `) line
+    Blank,
+    /// Style which relays only on ASCII charset
+    Ascii,
+    /// Analog of `ascii` but with rounded corners and without horizontal lines
+    AsciiRounded,
+    /// Analog of `ascii` which uses UTF-8 charset
+    Modern,
+    /// Analog of `modern` but without horizontal lines except a header
+    Sharp,
+    /// Analog of `sharp` but with rounded corners
+    Rounded,
+    /// Mimics a `PostgreSQL` table style
+    Psql,
+    /// Mimics a Markdown table style
+    Markdown,
+    /// Mimics a `ReStructuredText` table style
+    ReStructuredText,
+    /// Style using chars which resembles 2 lines
+    Extended,
+    /// Style using only ‘.’ and ‘:’ chars with vertical and horizontal split lines
+    Dots,
+}
+
 #[derive(Args, Debug)]
 pub struct CliPagination<T>
 where
@@ -1,7 +1,7 @@
 use bencher_json::{Email, ResourceId, UserName};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliMember {
@@ -37,6 +37,9 @@ pub struct CliMemberList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliMembersSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -77,6 +80,9 @@ pub struct CliMemberView {
     /// User slug or UUID
     pub user: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,7 +1,7 @@
 use bencher_json::{ResourceId, ResourceName, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::CliBackend;
+use crate::parser::{CliBackend, CliTable};
 
 pub mod member;
 pub mod plan;
@@ -10,7 +10,9 @@ pub mod usage;
 #[cfg(feature = "plus")]
 use self::usage::CliOrganizationUsage;
 
-use super::{CliPagination, ElidedOption};
+use super::CliPagination;
+#[cfg(feature = "plus")]
+use super::ElidedOption;
 
 #[derive(Subcommand, Debug)]
 pub enum CliOrganization {
@@ -51,6 +53,9 @@ pub struct CliOrganizationList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliOrganizationsSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -81,6 +86,9 @@ pub struct CliOrganizationView {
     /// Organization slug or UUID
     pub organization: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
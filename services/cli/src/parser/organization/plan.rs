@@ -3,7 +3,7 @@
 use bencher_json::{Entitlements, NonEmpty, OrganizationUuid, ResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::CliBackend;
+use crate::parser::{CliBackend, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliOrganizationPlan {
@@ -52,6 +52,9 @@ pub struct CliPlanView {
     /// Organization slug or UUID
     pub organization: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,7 +1,7 @@
 use bencher_json::{AlertUuid, ResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliAlert {
@@ -28,10 +28,17 @@ pub struct CliAlertList {
     #[clap(long)]
     pub status: Option<CliAlertStatus>,
 
+    /// Filter by alert kind
+    #[clap(long)]
+    pub kind: Option<CliAlertKind>,
+
     /// Filter for alerts with an archived branch, testbed, or measure
     #[clap(long)]
     pub archived: bool,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -56,6 +63,15 @@ pub enum CliAlertStatus {
     Silenced,
 }
 
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertKind {
+    /// Boundary limit
+    Limit,
+    /// Change-point drift
+    Drift,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliAlertView {
     /// Project slug or UUID
@@ -64,6 +80,9 @@ pub struct CliAlertView {
     /// Alert UUID
     pub alert: AlertUuid,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
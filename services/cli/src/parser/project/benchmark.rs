@@ -1,7 +1,7 @@
 use bencher_json::{BenchmarkName, ResourceId, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliArchived, CliBackend, CliPagination};
+use crate::parser::{CliArchived, CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliBenchmark {
@@ -42,6 +42,28 @@ pub struct CliBenchmarkList {
     #[clap(long)]
     pub archived: bool,
 
+    /// Include the most recent metric for each benchmark
+    #[clap(long)]
+    pub with_latest: bool,
+
+    /// Only use the most recent metrics for this branch slug or UUID
+    /// (requires `--with-latest` or `--sort latest`)
+    #[clap(long)]
+    pub branch: Option<ResourceId>,
+
+    /// Only use the most recent metrics for this testbed slug or UUID
+    /// (requires `--with-latest` or `--sort latest`)
+    #[clap(long)]
+    pub testbed: Option<ResourceId>,
+
+    /// Only use the most recent metrics for this measure slug or UUID
+    /// (requires `--with-latest` or `--sort latest`)
+    #[clap(long)]
+    pub measure: Option<ResourceId>,
+
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -51,6 +73,10 @@ pub struct CliBenchmarkList {
 pub enum CliBenchmarksSort {
     /// Name of the benchmark
     Name,
+    /// Date time the benchmark was created
+    Created,
+    /// Value of the most recent metric for the benchmark
+    Latest,
 }
 
 #[derive(Parser, Debug)]
@@ -78,6 +104,9 @@ pub struct CliBenchmarkView {
     /// Benchmark slug or UUID
     pub benchmark: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,7 +1,7 @@
 use bencher_json::{BranchName, GitHash, NameId, ResourceId, Slug};
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliArchived, CliBackend, CliPagination};
+use crate::parser::{CliArchived, CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliBranch {
@@ -20,6 +20,8 @@ pub enum CliBranch {
     /// Delete a branch
     #[clap(alias = "rm")]
     Delete(CliBranchDelete),
+    /// Merge a branch
+    Merge(CliBranchMerge),
 }
 
 #[derive(Parser, Debug)]
@@ -42,6 +44,9 @@ pub struct CliBranchList {
     #[clap(long)]
     pub archived: bool,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -66,6 +71,10 @@ pub struct CliBranchCreate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Return the existing branch if one with the same name or slug already exists
+    #[clap(long)]
+    pub upsert: bool,
+
     #[clap(flatten)]
     pub start_point: CliStartPointCreate,
 
@@ -107,6 +116,9 @@ pub struct CliBranchView {
     /// Branch slug or UUID
     pub branch: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -133,10 +145,42 @@ pub struct CliBranchUpdate {
     #[clap(flatten)]
     pub archived: CliArchived,
 
+    #[clap(flatten)]
+    pub protection: CliBranchProtection,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+#[derive(Args, Debug)]
+#[clap(group(
+    ArgGroup::new("protection")
+        .multiple(false)
+        .args(&["protect", "unprotect"]),
+))]
+pub struct CliBranchProtection {
+    /// Protect the branch, replacing any current protection.
+    /// Requires `manage` permissions for the organization.
+    #[clap(long, value_enum, value_delimiter = ',', value_name = "PROTECTION")]
+    pub protect: Option<Vec<CliBranchProtect>>,
+
+    /// Remove all protection from the branch.
+    /// Requires `manage` permissions for the organization.
+    #[clap(long)]
+    pub unprotect: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "snake_case")]
+pub enum CliBranchProtect {
+    /// The branch can not be deleted
+    Delete,
+    /// Only organization leaders can loosen or remove thresholds for the branch
+    Thresholds,
+    /// Only API tokens limited to a scope or project can create reports for the branch
+    Reports,
+}
+
 #[allow(clippy::struct_field_names)]
 #[derive(Args, Debug)]
 pub struct CliStartPointUpdate {
@@ -182,3 +226,27 @@ pub struct CliBranchDelete {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliBranchMerge {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Branch slug or UUID
+    pub branch: ResourceId,
+
+    /// Branch name, slug, or UUID that `branch` was merged into
+    #[clap(long, value_name = "BRANCH")]
+    pub target: NameId,
+
+    /// Full `git` hash of the merge commit on the target branch (requires: `--graft`)
+    #[clap(long, requires = "graft")]
+    pub hash: Option<GitHash>,
+
+    /// Graft the most recent metrics for `branch` onto the target branch at the merge commit
+    #[clap(long)]
+    pub graft: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
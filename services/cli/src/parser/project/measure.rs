@@ -1,7 +1,7 @@
 use bencher_json::{ResourceId, ResourceName, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliArchived, CliBackend, CliPagination};
+use crate::parser::{CliArchived, CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliMeasure {
@@ -42,6 +42,9 @@ pub struct CliMeasureList {
     #[clap(long)]
     pub archived: bool,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -66,6 +69,10 @@ pub struct CliMeasureCreate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Return the existing measure if one with the same name or slug already exists
+    #[clap(long)]
+    pub upsert: bool,
+
     /// Units of measure
     #[clap(long)]
     pub units: ResourceName,
@@ -82,6 +89,9 @@ pub struct CliMeasureView {
     /// Measure slug or UUID
     pub measure: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,7 +1,7 @@
 use bencher_json::{MetricUuid, ResourceId};
 use clap::{Parser, Subcommand};
 
-use crate::parser::CliBackend;
+use crate::parser::{CliBackend, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliMetric {
@@ -18,6 +18,9 @@ pub struct CliMetricView {
     /// Metric UUID
     pub metric: MetricUuid,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
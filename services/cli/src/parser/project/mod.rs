@@ -1,7 +1,7 @@
-use bencher_json::{ResourceId, ResourceName, Slug, Url};
+use bencher_json::{ResourceId, ResourceName, Secret, Slug, Url};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::CliBackend;
+use crate::parser::{CliBackend, CliTable};
 
 use super::{CliPagination, ElidedOption};
 
@@ -36,6 +36,10 @@ pub enum CliProject {
     Delete(CliProjectDelete),
     /// Check project permission
     Allowed(CliProjectAllowed),
+    /// Export project schema
+    Schema(CliProjectSchema),
+    /// Claim an unclaimed project
+    Claim(CliProjectClaim),
 }
 
 #[derive(Parser, Debug)]
@@ -54,6 +58,9 @@ pub struct CliProjectList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliProjectsSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -103,6 +110,9 @@ pub struct CliProjectView {
     /// Project slug or UUID
     pub project: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -157,6 +167,28 @@ pub struct CliProjectAllowed {
     pub backend: CliBackend,
 }
 
+#[derive(Parser, Debug)]
+pub struct CliProjectSchema {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectClaim {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Key for the unclaimed project
+    #[clap(long, env = "BENCHER_PROJECT_KEY")]
+    pub key: Secret,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
 /// Project permission
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -1,12 +1,12 @@
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ResourceId, TestbedUuid,
+    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ReportUuid, ResourceId,
+    ResourceName, TestbedUuid,
 };
-use clap::{Parser, ValueEnum};
+use clap::Parser;
 
-use crate::parser::{CliBackend, ElidedOption};
+use crate::parser::{CliBackend, CliTable, ElidedOption};
 
 #[derive(Parser, Debug)]
-#[allow(clippy::option_option)]
 pub struct CliPerf {
     /// Project slug or UUID
     pub project: ResourceId,
@@ -32,6 +32,10 @@ pub struct CliPerf {
     #[clap(long, required = true, value_name = "MEASURE")]
     pub measures: Vec<MeasureUuid>,
 
+    /// Only include results run against this environment (ie `staging` or `production`)
+    #[clap(long)]
+    pub environment: Option<ResourceName>,
+
     /// Start time (seconds since epoch)
     #[clap(long, value_name = "SECONDS")]
     pub start_time: Option<DateTime>,
@@ -40,40 +44,21 @@ pub struct CliPerf {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
-    /// Output results in a table
+    /// Only include results from reports after this report UUID, using keyset pagination
+    #[clap(long, value_name = "REPORT")]
+    pub after: Option<ReportUuid>,
+
+    /// The maximum number of results for each permutation
+    #[clap(long, value_name = "COUNT")]
+    pub limit: Option<u32>,
+
+    /// Fetch every page of results for each permutation
     #[clap(long)]
-    pub table: Option<Option<CliPerfTableStyle>>,
+    pub all: bool,
 
     #[clap(flatten)]
-    pub backend: CliBackend,
-}
+    pub table: CliTable,
 
-/// Supported Table Formats
-#[derive(ValueEnum, Debug, Clone)]
-#[clap(rename_all = "snake_case")]
-pub enum CliPerfTableStyle {
-    /// No styling options
-    Empty,
-    /// Analog of `empty` but with a vertical space (` `) line
-    Blank,
-    /// Style which relays only on ASCII charset
-    Ascii,
-    /// Analog of `ascii` but with rounded corners and without horizontal lines
-    AsciiRounded,
-    /// Analog of `ascii` which uses UTF-8 charset
-    Modern,
-    /// Analog of `modern` but without horizontal lines except a header
-    Sharp,
-    /// Analog of `sharp` but with rounded corners
-    Rounded,
-    /// Mimics a `PostgreSQL` table style
-    Psql,
-    /// Mimics a Markdown table style
-    Markdown,
-    /// Mimics a `ReStructuredText` table style
-    ReStructuredText,
-    /// Style using chars which resembles 2 lines
-    Extended,
-    /// Style using only ‘.’ and ‘:’ chars with vertical and horizontal split lines
-    Dots,
+    #[clap(flatten)]
+    pub backend: CliBackend,
 }
@@ -4,7 +4,7 @@ use bencher_json::{
 };
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination, ElidedOption};
+use crate::parser::{CliBackend, CliPagination, CliTable, ElidedOption};
 
 #[derive(Subcommand, Debug)]
 pub enum CliPlot {
@@ -41,6 +41,9 @@ pub struct CliPlotList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliPlotsSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -135,6 +138,9 @@ pub struct CliPlotView {
     /// Plot UUID
     pub plot: PlotUuid,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,11 +1,11 @@
-use bencher_json::{DateTime, GitHash, NameId, ReportUuid, ResourceId};
+use bencher_json::{DateTime, GitHash, NameId, ReportUuid, ResourceId, ResourceName};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use super::{
     branch::CliStartPointUpdate,
     run::{CliRunAdapter, CliRunAverage, CliRunFold, CliRunThresholds},
 };
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliReport {
@@ -18,9 +18,13 @@ pub enum CliReport {
     /// View a report
     #[clap(alias = "get")]
     View(CliReportView),
+    /// View the raw results for a report
+    Raw(CliReportRaw),
     /// Delete a report
     #[clap(alias = "rm")]
     Delete(CliReportDelete),
+    /// Send the reports that were saved after failing to send
+    Flush(CliReportFlush),
 }
 
 #[derive(Parser, Debug)]
@@ -44,6 +48,10 @@ pub struct CliReportList {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
+    /// Benchmark harness adapter
+    #[clap(value_enum, long)]
+    pub adapter: Option<CliRunAdapter>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliReportsSort>,
 
@@ -51,6 +59,17 @@ pub struct CliReportList {
     #[clap(long)]
     pub archived: bool,
 
+    /// Only list reports after this report UUID, using keyset pagination
+    #[clap(long, value_name = "REPORT", conflicts_with = "page")]
+    pub after: Option<ReportUuid>,
+
+    /// Fetch every page of reports
+    #[clap(long, conflicts_with = "page")]
+    pub all: bool,
+
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -82,6 +101,10 @@ pub struct CliReportCreate {
     #[clap(long)]
     pub testbed: NameId,
 
+    /// Environment the benchmarks were run against (ie `staging` or `production`)
+    #[clap(long)]
+    pub environment: Option<ResourceName>,
+
     #[clap(flatten)]
     pub thresholds: CliRunThresholds,
 
@@ -109,6 +132,14 @@ pub struct CliReportCreate {
     #[clap(value_enum, long)]
     pub fold: Option<CliRunFold>,
 
+    /// Store the raw benchmark harness output with the report
+    #[clap(long)]
+    pub raw: bool,
+
+    /// Track the total report wall time as the `Report Duration` measure for the `bencher::report` benchmark
+    #[clap(long)]
+    pub duration: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -121,12 +152,15 @@ pub struct CliReportView {
     /// Report UUID
     pub report: ReportUuid,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
 #[derive(Parser, Debug)]
-pub struct CliReportDelete {
+pub struct CliReportRaw {
     /// Project slug or UUID
     pub project: ResourceId,
 
@@ -136,3 +170,64 @@ pub struct CliReportDelete {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliReportDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Report UUID
+    #[clap(required_unless_present = "older_than", conflicts_with = "older_than")]
+    pub report: Option<ReportUuid>,
+
+    /// Delete all reports for the branch that ended longer ago than the given duration (ie `30d`)
+    /// The duration is a whole number followed by a unit: `s`, `m`, `h`, `d`, or `w`.
+    #[clap(long, value_name = "DURATION", value_parser = parse_duration, requires = "branch")]
+    pub older_than: Option<std::time::Duration>,
+
+    /// Branch name, slug, or UUID to delete old reports for
+    #[clap(long, requires = "older_than")]
+    pub branch: Option<NameId>,
+
+    /// List the reports that would be deleted without deleting them
+    #[clap(long, requires = "older_than")]
+    pub dry_run: bool,
+
+    /// Delete the reports without asking for confirmation
+    #[clap(long, requires = "older_than", conflicts_with = "dry_run")]
+    pub yes: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Parse a duration in the form `<number><unit>`, ie `30d`
+fn parse_duration(arg: &str) -> Result<std::time::Duration, String> {
+    let index = arg.find(|c: char| !c.is_ascii_digit()).ok_or_else(|| {
+        format!(
+            "Failed to parse duration, no unit (`s`, `m`, `h`, `d`, or `w`) was found in: `{arg}`"
+        )
+    })?;
+    let (number, unit) = arg.split_at(index);
+    let number = number
+        .parse::<u64>()
+        .map_err(|e| format!("Failed to parse duration number in `{arg}`: {e}"))?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("Failed to parse duration, unknown unit `{unit}` in: `{arg}`. Expected one of `s`, `m`, `h`, `d`, or `w`.")),
+    };
+    number
+        .checked_mul(seconds)
+        .map(std::time::Duration::from_secs)
+        .ok_or_else(|| format!("Failed to parse duration, `{arg}` is too large"))
+}
+
+#[derive(Parser, Debug)]
+pub struct CliReportFlush {
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,20 +1,31 @@
 use bencher_json::{
-    project::testbed::TESTBED_LOCALHOST_STR, Boundary, DateTime, GitHash, NameId, ResourceId,
-    SampleSize, Window,
+    project::testbed::TESTBED_LOCALHOST_STR, BenchmarkName, Boundary, DateTime, GitHash, NameId,
+    ResourceId, ResourceName, SampleSize, Secret, Window,
 };
 use camino::Utf8PathBuf;
-use clap::{ArgGroup, Args, Parser, ValueEnum};
+use clap::{error::ErrorKind, ArgGroup, Args, CommandFactory, Parser, ValueEnum};
+use url::Url;
 
-use crate::parser::{CliBackend, ElidedOption};
+use crate::parser::{CliBackend, CliBencher, ElidedOption};
 
 use super::threshold::CliModelTest;
 
 #[derive(Parser, Debug)]
 #[allow(clippy::option_option, clippy::struct_excessive_bools)]
 pub struct CliRun {
-    /// Project slug or UUID
+    /// Project slug or UUID.
+    /// Required unless the report is sent anonymously with `--anonymous` or `--project-key`.
     #[clap(long, env = "BENCHER_PROJECT")]
-    pub project: ResourceId,
+    pub project: Option<ResourceId>,
+
+    /// Send the report anonymously to a new unclaimed project.
+    /// Unclaimed projects are always public.
+    #[clap(long, conflicts_with_all = ["project", "project_key"])]
+    pub anonymous: bool,
+
+    /// Key for an unclaimed project, as returned by a previous anonymous run.
+    #[clap(long, env = "BENCHER_PROJECT_KEY", conflicts_with = "project")]
+    pub project_key: Option<Secret>,
 
     #[clap(flatten)]
     pub branch: CliRunBranch,
@@ -24,8 +35,20 @@ pub struct CliRun {
     #[clap(long, env = "BENCHER_TESTBED", default_value = TESTBED_LOCALHOST_STR)]
     pub testbed: NameId,
 
+    /// Environment the benchmarks are run against (ie `staging` or `production`).
+    /// This allows the same testbed to be used across deployments.
+    #[clap(long, env = "BENCHER_ENVIRONMENT")]
+    pub environment: Option<ResourceName>,
+
     /// Benchmark harness adapter
-    #[clap(value_enum, long, env = "BENCHER_ADAPTER", default_value = "magic")]
+    #[cfg_attr(
+        feature = "adapters",
+        clap(value_enum, long, env = "BENCHER_ADAPTER", default_value = "magic")
+    )]
+    #[cfg_attr(
+        not(feature = "adapters"),
+        clap(value_enum, long, env = "BENCHER_ADAPTER", default_value = "json")
+    )]
     pub adapter: CliRunAdapter,
 
     /// Benchmark harness suggested central tendency (ie average)
@@ -36,15 +59,40 @@ pub struct CliRun {
     #[clap(long, value_name = "COUNT", default_value = "1")]
     pub iter: usize,
 
-    /// Fold multiple results into a single result using an aggregate function
-    #[clap(value_enum, long, requires = "iter", value_name = "AGGREGATE_FUNCTION")]
+    /// Fold multiple results into a single result using an aggregate function (requires: `--iter` > 1 or `--retry-on-alert`)
+    #[clap(value_enum, long, value_name = "AGGREGATE_FUNCTION")]
     pub fold: Option<CliRunFold>,
 
-    /// Backdate the report (seconds since epoch)
+    /// Compare a candidate benchmark to a baseline benchmark from the same run.
+    /// The ratio of the candidate to the baseline is added as the `CANDIDATE / BASELINE` benchmark.
+    #[clap(long, num_args = 2, value_names = ["BASELINE", "CANDIDATE"])]
+    pub pair: Vec<BenchmarkName>,
+
+    /// Store the raw benchmark harness output with the report
+    #[clap(long)]
+    pub raw: bool,
+
+    /// Track the total report wall time as the `Report Duration` measure for the `bencher::report` benchmark
+    #[clap(long)]
+    pub duration: bool,
+
+    /// Flamegraph file path to upload with the report.
+    /// If an alert is generated, links to the flamegraphs for the report and its baseline are included.
+    /// Requires a project.
+    #[clap(long, value_name = "PATH", requires = "project")]
+    pub flamegraph: Option<Utf8PathBuf>,
+
+    /// Backdate the report (seconds since epoch) (requires: `--hash`)
     /// NOTE: This will NOT effect the ordering of past reports
-    #[clap(long, value_name = "SECONDS")]
+    #[clap(long, value_name = "SECONDS", requires = "hash")]
     pub backdate: Option<DateTime>,
 
+    /// Base `git` commit hash to compare against for threshold evaluation, such as the merge base of a pull request.
+    /// Only metrics up to and including this hash on the branch are used as the baseline.
+    /// If the hash is not found on the branch, then the entire branch is used.
+    #[clap(long)]
+    pub base_hash: Option<GitHash>,
+
     /// Allow benchmark test failure
     #[clap(long)]
     pub allow_failure: bool,
@@ -56,10 +104,23 @@ pub struct CliRun {
     #[clap(long)]
     pub err: bool,
 
+    /// Error on alerts at or above the given severity
+    #[clap(value_enum, long, conflicts_with = "err")]
+    pub err_on: Option<CliRunSeverity>,
+
+    /// Rerun the benchmarks up to this many times while the report has alerts (requires: `--project`).
+    /// If `--err` or `--err-on` is set, only alerts at or above that severity are counted.
+    /// Each rerun replaces the previous report with a new report of the results from every run so far,
+    /// folded with `--fold` (default: `median`).
+    /// This only errors on alerts if they persist after the last rerun.
+    #[clap(long, value_name = "COUNT", requires = "project")]
+    pub retry_on_alert: Option<u32>,
+
     #[clap(flatten)]
     pub output: CliRunOutput,
 
     /// CI integrations
+    #[cfg(feature = "github-actions")]
     #[clap(flatten)]
     pub ci: CliRunCi,
 
@@ -74,6 +135,21 @@ pub struct CliRun {
     pub backend: CliBackend,
 }
 
+impl CliRun {
+    /// Validate the combinations of flags that can not be declared with `clap` attributes.
+    /// This catches invalid combinations before any benchmarks are run.
+    pub fn validate(&self) -> Result<(), clap::Error> {
+        if self.fold.is_some() && self.iter <= 1 && self.retry_on_alert.is_none() {
+            return Err(run_error(
+                ErrorKind::MissingRequiredArgument,
+                "the argument '--fold <AGGREGATE_FUNCTION>' requires '--iter <COUNT>' to be greater than 1 or '--retry-on-alert <COUNT>'"
+                    .to_owned(),
+            ));
+        }
+        self.cmd.validate()
+    }
+}
+
 #[derive(Args, Debug)]
 #[allow(clippy::option_option)]
 pub struct CliRunBranch {
@@ -171,6 +247,18 @@ pub struct CliRunThresholds {
     #[clap(long, requires = "threshold_test")]
     pub threshold_upper_boundary: Vec<ElidedOption<Boundary>>,
 
+    /// Lower critical boundary
+    /// Alerts beyond the lower boundary but within the lower critical boundary are warnings.
+    /// To ignore a this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_lower_boundary")]
+    pub threshold_lower_critical: Vec<ElidedOption<Boundary>>,
+
+    /// Upper critical boundary
+    /// Alerts beyond the upper boundary but within the upper critical boundary are warnings.
+    /// To ignore a this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_upper_boundary")]
+    pub threshold_upper_critical: Vec<ElidedOption<Boundary>>,
+
     /// Reset all unspecified Thresholds for the `branch` and `testbed`
     /// If a Threshold already exists and is not specified, its current Model will be removed.
     #[clap(long)]
@@ -178,19 +266,68 @@ pub struct CliRunThresholds {
 }
 
 #[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct CliRunCommand {
     /// Track the build time of the benchmark command
     #[clap(long, requires = "command", conflicts_with = "file")]
     pub build_time: bool,
 
-    /// Benchmark command output file path
+    /// Track an additional measure of the benchmark command
+    /// This can be specified multiple times.
+    #[clap(value_enum, long, requires = "command")]
+    pub measure: Vec<CliRunMeasure>,
+
+    /// Benchmark command output file path or glob, ie `results/*.json`
+    /// This can be specified multiple times.
+    /// All of the matching files are parsed and merged into a single set of results.
     #[clap(long, conflicts_with = "file_size")]
-    pub file: Option<Utf8PathBuf>,
+    pub file: Option<Vec<Utf8PathBuf>>,
 
     /// Track the size of a file at the given file path
     #[clap(long, conflicts_with = "file")]
     pub file_size: Option<Vec<Utf8PathBuf>>,
 
+    /// Parse the benchmark command output as JSON Lines as it is emitted
+    /// Each line of stdout that is a Bencher Metric Format (BMF) JSON object is kept as a result.
+    /// If the benchmark command fails or is killed, the results parsed so far are still reported.
+    #[clap(
+        long,
+        requires = "command",
+        conflicts_with_all = ["build_time", "file", "file_size"]
+    )]
+    pub stream: bool,
+
+    /// Built-in benchmark harness to use instead of a benchmark command
+    #[clap(
+        value_enum,
+        long,
+        requires = "url",
+        conflicts_with_all = ["command", "file", "file_size", "build_time"]
+    )]
+    pub harness: Option<CliRunHarness>,
+
+    /// URL to request for the `http` benchmark harness
+    #[clap(long, requires = "harness")]
+    pub url: Option<Url>,
+
+    /// Number of requests to make for the `http` benchmark harness (default: 10)
+    /// The first request is reported as the cold start.
+    #[clap(long, requires = "harness", value_name = "COUNT", value_parser = clap::value_parser!(u32).range(1..))]
+    pub requests: Option<u32>,
+
+    /// Read benchmark results from stdin instead of running a benchmark command
+    /// This is the default without a benchmark command if stdin is piped (Unix only).
+    /// If the `--file` option is also given, then the results from stdin and the files are merged.
+    #[clap(
+        long,
+        conflicts_with_all = ["command", "file_size", "harness"]
+    )]
+    pub stdin: bool,
+
+    /// Maximum size in bytes of the benchmark results read from stdin (default: 64 MiB)
+    #[clap(long, value_name = "BYTES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_input_size: Option<u64>,
+
     #[clap(flatten)]
     pub sh_c: CliRunShell,
 
@@ -212,6 +349,50 @@ pub struct CliRunCommand {
     pub command: Option<Vec<String>>,
 }
 
+impl CliRunCommand {
+    pub fn validate(&self) -> Result<(), clap::Error> {
+        // Without a command, results are only read from both the files and stdin if explicitly requested
+        if let Some(file) = self.file.as_ref().and_then(|file| file.first()) {
+            if self.command.is_none() && !self.stdin && is_stdin_piped() {
+                return Err(run_error(
+                    ErrorKind::ArgumentConflict,
+                    format!(
+                        "the argument '--file {file}' cannot be used with results piped via stdin without '--stdin'"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+#[allow(clippy::filetype_is_file)]
+pub fn is_stdin_piped() -> bool {
+    use std::os::unix::fs::FileTypeExt;
+
+    // Only an actual pipe or redirected file counts, as CI runners often redirect stdin from `/dev/null`
+    // and an interactive terminal would otherwise block waiting on input.
+    std::fs::metadata("/dev/stdin").is_ok_and(|metadata| {
+        let file_type = metadata.file_type();
+        file_type.is_fifo() || file_type.is_file()
+    })
+}
+
+#[cfg(not(unix))]
+pub fn is_stdin_piped() -> bool {
+    false
+}
+
+fn run_error(kind: ErrorKind, message: String) -> clap::Error {
+    let mut cmd = CliBencher::command();
+    if let Some(run) = cmd.find_subcommand_mut("run") {
+        run.error(kind, message)
+    } else {
+        cmd.error(kind, message)
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct CliRunShell {
     /// Shell command path
@@ -223,71 +404,134 @@ pub struct CliRunShell {
     pub flag: Option<String>,
 }
 
+/// Measures of the benchmark command that are tracked by the CLI
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "kebab-case")]
+pub enum CliRunMeasure {
+    /// Peak resident set size in bytes (Unix only)
+    /// With multiple iterations, this is the peak across all iterations so far.
+    MaxRss,
+    /// Energy used by all CPU packages in joules from the Intel RAPL counters (Linux only)
+    /// Reading the RAPL counters may require elevated permissions.
+    Energy,
+}
+
+/// Built-in benchmark harnesses
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunHarness {
+    /// Time to first byte and total latency for HTTP GET requests
+    Http,
+}
+
 /// Supported Adapters
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
 pub enum CliRunAdapter {
+    #[cfg(feature = "adapters")]
     /// 🪄 Magic
     Magic,
     /// {...} JSON
     Json,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     CSharp,
+    #[cfg(feature = "adapters")]
     /// #️⃣ C# `DotNet`
     CSharpDotNet,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Cpp,
+    #[cfg(feature = "adapters")]
     /// ➕ C++ Catch2
     CppCatch2,
+    #[cfg(feature = "adapters")]
     /// ➕ C++ Google
     CppGoogle,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Go,
+    #[cfg(feature = "adapters")]
     /// 🕳 Go Bench
     GoBench,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Java,
+    #[cfg(feature = "adapters")]
     /// ☕️ Java JMH
     JavaJmh,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Js,
+    #[cfg(feature = "adapters")]
     /// 🕸 JavaScript Benchmark
     JsBenchmark,
+    #[cfg(feature = "adapters")]
     /// 🕸 JavaScript Time
     JsTime,
+    #[cfg(feature = "adapters")]
+    // The help text is not Markdown
+    #[allow(clippy::doc_markdown)]
+    /// 🐘 PHP PHPBench
+    PhpBench,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Python,
+    #[cfg(feature = "adapters")]
     /// 🐍 Python ASV
     PythonAsv,
+    #[cfg(feature = "adapters")]
     /// 🐍 Python Pytest
     PythonPytest,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Ruby,
+    #[cfg(feature = "adapters")]
     /// ♦️ Ruby Benchmark
     RubyBenchmark,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Rust,
+    #[cfg(feature = "adapters")]
     /// 🦀 Rust Bench
     RustBench,
+    #[cfg(feature = "adapters")]
     /// 🦀 Rust Criterion
     RustCriterion,
+    #[cfg(feature = "adapters")]
     /// 🦀 Rust Iai
     RustIai,
+    #[cfg(feature = "adapters")]
     /// 🦀 Rust Iai-Callgrind
     RustIaiCallgrind,
+    #[cfg(feature = "adapters")]
+    // The help text is not Markdown
+    #[allow(clippy::doc_markdown)]
+    /// 🪜 Scala ScalaMeter
+    ScalaScalameter,
+    #[cfg(feature = "adapters")]
     // TODO remove in due time
     #[clap(hide = true)]
     Shell,
+    #[cfg(feature = "adapters")]
     /// ❯_ Shell Hyperfine
     ShellHyperfine,
+    #[cfg(feature = "adapters")]
+    /// ❯_ Shell Measure-Command
+    ShellMeasureCommand,
+    #[cfg(feature = "adapters")]
+    // The help text is not Markdown
+    #[allow(clippy::doc_markdown)]
+    /// 🐦 Swift XCTest
+    SwiftXctest,
 }
 
 /// Suggested Central Tendency (Average)
@@ -314,6 +558,16 @@ pub enum CliRunFold {
     Median,
 }
 
+/// Alert Severity Levels
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunSeverity {
+    /// Warning and critical alerts
+    Warning,
+    /// Only critical alerts
+    Critical,
+}
+
 #[derive(Args, Debug)]
 pub struct CliRunOutput {
     /// Format for the final Report
@@ -322,6 +576,20 @@ pub struct CliRunOutput {
     /// Quite mode, only output the final Report to standard out
     #[clap(short, long)]
     pub quiet: bool,
+    /// Format for progress logs.
+    /// The `json` format emits one machine-readable event per line to standard error and implies `--quiet`.
+    #[clap(long, default_value = "human")]
+    pub log_format: CliRunLogFormat,
+}
+
+/// Supported Log Formats
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunLogFormat {
+    /// Human
+    Human,
+    /// JSON Lines
+    Json,
 }
 
 /// Supported Report Formats
@@ -336,6 +604,7 @@ pub enum CliRunFormat {
     Html,
 }
 
+#[cfg(feature = "github-actions")]
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 #[clap(group(
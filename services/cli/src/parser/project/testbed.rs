@@ -1,7 +1,7 @@
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_json::{ResourceId, ResourceName, ScaleFactor, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliArchived, CliBackend, CliPagination};
+use crate::parser::{CliArchived, CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliTestbed {
@@ -42,6 +42,9 @@ pub struct CliTestbedList {
     #[clap(long)]
     pub archived: bool,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -66,6 +69,14 @@ pub struct CliTestbedCreate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Return the existing testbed if one with the same name or slug already exists
+    #[clap(long)]
+    pub upsert: bool,
+
+    /// Scale factor used to normalize metrics across testbeds (default 1.0)
+    #[clap(long)]
+    pub scale: Option<ScaleFactor>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -78,6 +89,9 @@ pub struct CliTestbedView {
     /// Testbed slug or UUID
     pub testbed: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -98,6 +112,10 @@ pub struct CliTestbedUpdate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Scale factor used to normalize metrics across testbeds
+    #[clap(long)]
+    pub scale: Option<ScaleFactor>,
+
     #[clap(flatten)]
     pub archived: CliArchived,
 
@@ -1,7 +1,9 @@
-use bencher_json::{Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window};
+use bencher_json::{
+    BenchmarkGlob, Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window,
+};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliThreshold {
@@ -46,6 +48,9 @@ pub struct CliThresholdList {
     #[clap(long)]
     pub archived: bool,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -76,6 +81,11 @@ pub struct CliThresholdCreate {
     #[clap(long)]
     pub measure: NameId,
 
+    /// Benchmark name or glob pattern of benchmark names (ie `bench_*`).
+    /// If not set, then the threshold applies to all benchmarks without a more specific threshold.
+    #[clap(long)]
+    pub benchmark: Option<BenchmarkGlob>,
+
     #[clap(flatten)]
     pub model: CliModel,
 
@@ -125,6 +135,16 @@ pub struct CliModel {
     /// Upper boundary
     #[clap(long, value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
+
+    /// Lower critical boundary.
+    /// Alerts beyond the lower boundary but within the lower critical boundary are warnings.
+    #[clap(long, requires = "lower_boundary", value_name = "BOUNDARY")]
+    pub lower_critical: Option<Boundary>,
+
+    /// Upper critical boundary.
+    /// Alerts beyond the upper boundary but within the upper critical boundary are warnings.
+    #[clap(long, requires = "upper_boundary", value_name = "BOUNDARY")]
+    pub upper_critical: Option<Boundary>,
 }
 
 /// Supported threshold model tests
@@ -147,6 +167,10 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Percentile of the historical data
+    Percentile,
+    /// Cumulative sum (CUSUM) change-point detection
+    Cusum,
 }
 
 #[derive(Parser, Debug)]
@@ -161,6 +185,9 @@ pub struct CliThresholdView {
     #[clap(long)]
     pub model: Option<ModelUuid>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -212,6 +239,16 @@ pub struct CliUpdateModel {
     #[clap(long, requires = "test", value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
 
+    /// Lower critical boundary.
+    /// Alerts beyond the lower boundary but within the lower critical boundary are warnings.
+    #[clap(long, requires = "lower_boundary", value_name = "BOUNDARY")]
+    pub lower_critical: Option<Boundary>,
+
+    /// Upper critical boundary.
+    /// Alerts beyond the upper boundary but within the upper critical boundary are warnings.
+    #[clap(long, requires = "upper_boundary", value_name = "BOUNDARY")]
+    pub upper_critical: Option<Boundary>,
+
     /// Remove the threshold model
     #[clap(long)]
     pub remove_model: bool,
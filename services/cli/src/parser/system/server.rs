@@ -1,10 +1,11 @@
+use bencher_json::OperationUuid;
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::CliBackend;
 
 #[derive(Subcommand, Debug)]
 pub enum CliServer {
-    /// Server version
+    /// Server version and compatibility
     Version(CliVersion),
     /// Server `OpenAPI` Spec
     Spec(CliSpec),
@@ -15,6 +16,12 @@ pub enum CliServer {
     Config(CliConfig),
     /// Backup database
     Backup(CliBackup),
+    /// Run database maintenance
+    Maintenance(CliMaintenance),
+    /// View a long-running server operation
+    Operation(CliOperation),
+    /// Check server connectivity, version, and API token
+    SelfTest(CliSelfTest),
     #[cfg(feature = "plus")]
     /// Server usage statistics
     Stats(CliServerStats),
@@ -103,6 +110,49 @@ pub struct CliBackup {
 pub enum CliBackupDataStore {
     /// AWS S3
     AwsS3,
+    /// Local file system
+    Local,
+    /// Azure Blob Storage
+    AzureBlob,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliOperation {
+    /// Operation UUID
+    pub uuid: OperationUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliMaintenance {
+    /// Maintenance operation to run, in order (default: all operations)
+    #[clap(long = "operation", value_name = "OPERATION")]
+    pub operations: Vec<CliMaintenanceOperation>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Supported Maintenance Operations
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliMaintenanceOperation {
+    /// Check the integrity of the database
+    IntegrityCheck,
+    /// Gather query planner statistics
+    Analyze,
+    /// Rebuild the database file
+    Vacuum,
+    /// Checkpoint and truncate the write-ahead log
+    WalCheckpoint,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliSelfTest {
+    #[clap(flatten)]
+    pub backend: CliBackend,
 }
 
 #[cfg(feature = "plus")]
@@ -0,0 +1,11 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum CliTelemetry {
+    /// Opt in to anonymous CLI usage telemetry
+    On,
+    /// Opt out of anonymous CLI usage telemetry
+    Off,
+    /// Show whether anonymous CLI usage telemetry is enabled
+    Status,
+}
@@ -1,7 +1,7 @@
 use bencher_json::{Email, ResourceId, Slug, UserName};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 pub mod token;
 
@@ -16,6 +16,9 @@ pub enum CliUser {
     // Update a user
     #[clap(alias = "edit")]
     Update(CliUserUpdate),
+    /// Delete a user by scrubbing their personal data
+    #[clap(alias = "rm")]
+    Delete(CliUserDelete),
 }
 
 #[derive(Parser, Debug)]
@@ -31,6 +34,9 @@ pub struct CliUserList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliUsersSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -47,6 +53,9 @@ pub struct CliUserView {
     /// User slug or UUID
     pub user: ResourceId,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -75,3 +84,12 @@ pub struct CliUserUpdate {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliUserDelete {
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,7 +1,7 @@
-use bencher_json::{ResourceId, ResourceName, TokenUuid};
+use bencher_json::{Cidr, ResourceId, ResourceName, TokenUuid};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::parser::{CliBackend, CliPagination};
+use crate::parser::{CliBackend, CliPagination, CliTable};
 
 #[derive(Subcommand, Debug)]
 pub enum CliToken {
@@ -35,6 +35,9 @@ pub struct CliTokenList {
     #[clap(flatten)]
     pub pagination: CliPagination<CliTokensSort>,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -59,10 +62,35 @@ pub struct CliTokenCreate {
     #[clap(long)]
     pub ttl: Option<u32>,
 
+    /// Token scope (default: same permissions as the user)
+    #[clap(value_enum, long)]
+    pub scope: Option<CliTokenScope>,
+
+    /// Project slug or UUID to limit the token to
+    #[clap(long)]
+    pub project: Option<ResourceId>,
+
+    /// IP address or CIDR range the token can be used from (may be used more than once)
+    #[clap(long = "allowed-ip", value_name = "CIDR")]
+    pub allowed_ips: Option<Vec<Cidr>>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum CliTokenScope {
+    /// The same permissions as the user
+    #[value(name = "admin")]
+    Admin,
+    /// View projects
+    #[value(name = "project:read")]
+    ProjectRead,
+    /// View projects and create reports
+    #[value(name = "report:write")]
+    ReportWrite,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliTokenView {
     /// User slug or UUID
@@ -71,6 +99,9 @@ pub struct CliTokenView {
     /// Token UUID
     pub uuid: TokenUuid,
 
+    #[clap(flatten)]
+    pub table: CliTable,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -87,6 +118,14 @@ pub struct CliTokenUpdate {
     #[clap(long)]
     pub name: Option<ResourceName>,
 
+    /// Rotate the token, issuing a replacement and revoking the current token
+    #[clap(long)]
+    pub rotate: bool,
+
+    /// Time to live for the replacement token (seconds, default: same as the current token)
+    #[clap(long, requires = "rotate")]
+    pub ttl: Option<u32>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
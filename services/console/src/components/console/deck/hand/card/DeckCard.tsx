@@ -54,6 +54,7 @@ const DeckCard = (props: Props) => {
 				</Match>
 				<Match when={props.card?.kind === Card.REPORT}>
 					<ReportCard
+						apiUrl={props.apiUrl}
 						isConsole={props.isConsole}
 						params={props.params}
 						value={props.data}
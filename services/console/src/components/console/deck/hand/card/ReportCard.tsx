@@ -23,10 +23,12 @@ import {
 	type JsonThreshold,
 } from "../../../../../types/bencher";
 import { dateTimeMillis, prettyPrintFloat } from "../../../../../util/convert";
+import { apiUrl } from "../../../../../util/http";
 import { scale_factor, scale_units } from "../../../../../util/scale";
 import { BACK_PARAM, encodePath } from "../../../../../util/url";
 
 export interface Props {
+	apiUrl?: string;
 	isConsole?: boolean;
 	params: Params;
 	value: Resource<JsonReport>;
@@ -498,6 +500,33 @@ const ReportCard = (props: Props) => {
 							);
 						}}
 					</For>
+					<Show
+						when={
+							props.apiUrl && (props.value()?.attachments?.length ?? 0) > 0
+						}
+					>
+						<hr />
+						<h3 class="title is-3">Attachments</h3>
+						<ul>
+							<For each={props.value()?.attachments}>
+								{(attachment) => (
+									<li>
+										<a
+											href={apiUrl(
+												props.apiUrl as string,
+												`/v0/projects/${props.value()?.project?.slug}/reports/${
+													props.value()?.uuid
+												}/attachments/${attachment.uuid}`,
+											)}
+										>
+											{attachment.name}
+										</a>{" "}
+										({attachment.content_type})
+									</li>
+								)}
+							</For>
+						</ul>
+					</Show>
 				</div>
 			</div>
 		</div>
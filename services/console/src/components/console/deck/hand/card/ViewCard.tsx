@@ -354,6 +354,8 @@ const AdapterCard = (props: Props) => {
 						return "-javascript-benchmark";
 					case Adapter.JsTime:
 						return "-javascript-time";
+					case Adapter.PhpBench:
+						return "-php-phpbench";
 					case Adapter.PythonAsv:
 						return "-python-asv";
 					case Adapter.PythonPytest:
@@ -368,8 +370,14 @@ const AdapterCard = (props: Props) => {
 						return "-rust-iai";
 					case Adapter.RustIaiCallgrind:
 						return "-rust-iai-callgrind";
+					case Adapter.ScalaScalameter:
+						return "-scala-scalameter";
 					case Adapter.ShellHyperfine:
 						return "_%EF%B8%8F-shell-hyperfine";
+					case Adapter.ShellMeasureCommand:
+						return "_%EF%B8%8F-shell-measure-command";
+					case Adapter.SwiftXctest:
+						return "-swift-xctest";
 					default:
 						return "";
 				}
@@ -399,6 +407,8 @@ const AdapterCard = (props: Props) => {
 							return "JavaScript Benchmark.js";
 						case Adapter.JsTime:
 							return "JavaScript console.time/console.timeEnd";
+						case Adapter.PhpBench:
+							return "PHP PHPBench";
 						case Adapter.PythonAsv:
 							return "Python airspeed velocity (asv)";
 						case Adapter.PythonPytest:
@@ -413,8 +423,14 @@ const AdapterCard = (props: Props) => {
 							return "Rust Iai";
 						case Adapter.RustIaiCallgrind:
 							return "Rust Iai-Callgrind";
+						case Adapter.ScalaScalameter:
+							return "Scala ScalaMeter";
 						case Adapter.ShellHyperfine:
 							return "Shell Hyperfine";
+						case Adapter.ShellMeasureCommand:
+							return "Shell Measure-Command";
+						case Adapter.SwiftXctest:
+							return "Swift XCTest";
 						default:
 							return `${props.value}`;
 					}
@@ -450,10 +466,14 @@ const ModelTestCard = (props: Props) => {
 							return "t-test";
 						case ModelTest.LogNormal:
 							return "Log Normal";
+						case ModelTest.Percentile:
+							return "Percentile";
 						case ModelTest.Iqr:
 							return "Interquartile Range (IQR)";
 						case ModelTest.DeltaIqr:
 							return "Delta Interquartile Range (ΔIQR)";
+						case ModelTest.Cusum:
+							return "Cumulative Sum (CUSUM)";
 						default:
 							return `${props.value}`;
 					}
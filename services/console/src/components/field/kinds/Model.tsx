@@ -3,6 +3,7 @@ import { ModelTest } from "../../../types/bencher";
 import {
 	validBoundary,
 	validCdfBoundary,
+	validCusumBoundary,
 	validIqrBoundary,
 	validPercentageBoundary,
 	validSampleSize,
@@ -92,6 +93,20 @@ const STATISTIC_FIELDS = {
 		help: "Must be any multiplier greater than or equal to zero",
 		validate: validIqrBoundary,
 	},
+	cusum_lower_boundary: {
+		type: "input",
+		placeholder: "4.0",
+		icon: "fas fa-arrow-down",
+		help: "Must be any number of standard deviations greater than zero",
+		validate: validCusumBoundary,
+	},
+	cusum_upper_boundary: {
+		type: "input",
+		placeholder: "4.0",
+		icon: "fas fa-arrow-up",
+		help: "Must be any number of standard deviations greater than zero",
+		validate: validCusumBoundary,
+	},
 	min_sample_size: {
 		type: "number",
 		placeholder: "30",
@@ -139,6 +154,10 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.LogNormal,
 				option: "Log Normal",
 			},
+			{
+				value: ModelTest.Percentile,
+				option: "Percentile",
+			},
 			{
 				value: ModelTest.Iqr,
 				option: "Interquartile Range (IQR)",
@@ -147,6 +166,10 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: "Delta Interquartile Range (ΔIQR)",
 			},
+			{
+				value: ModelTest.Cusum,
+				option: "Cumulative Sum (CUSUM)",
+			},
 		],
 	};
 };
@@ -193,10 +216,14 @@ export const testFragment = (modelTest: ModelTest) => {
 			return "t-test-thresholds";
 		case ModelTest.LogNormal:
 			return "log-normal-thresholds";
+		case ModelTest.Percentile:
+			return "percentile-thresholds";
 		case ModelTest.Iqr:
 			return "iqr-thresholds";
 		case ModelTest.DeltaIqr:
 			return "delta-iqr-thresholds";
+		case ModelTest.Cusum:
+			return "cusum-thresholds";
 	}
 };
 
@@ -254,6 +281,33 @@ const iqrConfig = (modelTest: ModelTest) => {
 	];
 };
 
+const cusumConfig = (modelTest: ModelTest) => {
+	return [
+		testSelectConfig(modelTest),
+		{
+			kind: FieldKind.NUMBER,
+			label: "Lower Boundary",
+			key: "lower_boundary",
+			value: "",
+			valid: true,
+			validate: true,
+			nullable: true,
+			config: STATISTIC_FIELDS.cusum_lower_boundary,
+		},
+		{
+			kind: FieldKind.NUMBER,
+			label: "Upper Boundary",
+			key: "upper_boundary",
+			value: "",
+			valid: true,
+			validate: true,
+			nullable: true,
+			config: STATISTIC_FIELDS.cusum_upper_boundary,
+		},
+		...SAMPLE_SIZE,
+	];
+};
+
 const SAMPLE_SIZE = [
 	{
 		kind: FieldKind.NUMBER,
@@ -338,8 +392,10 @@ const FIELDS = {
 	[ModelTest.ZScore]: cdfConfig(ModelTest.ZScore),
 	[ModelTest.TTest]: cdfConfig(ModelTest.TTest),
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
+	[ModelTest.Percentile]: cdfConfig(ModelTest.Percentile),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.Cusum]: cusumConfig(ModelTest.Cusum),
 };
 
 const initForm = (fields: object[]) => {
@@ -8,6 +8,7 @@ import {
 import { ActionButton, Button, Card, Display, Operation, Row } from "../types";
 import { addPath, createdUuidPath, parentPath, viewUuidPath } from "../util";
 import { PubResourceKind } from "../../components/perf/util";
+import { validOptionBenchmarkGlob } from "../../util/valid";
 
 const paginationUrl = (
 	params: undefined | Params,
@@ -49,6 +50,13 @@ const THRESHOLD_FIELDS = {
 		url: (params: undefined | Params, per_page: number, page: number) =>
 			paginationUrl(params, "measures", per_page, page),
 	},
+	benchmark: {
+		type: "text",
+		placeholder: "bench_*",
+		icon: "fas fa-tachometer-alt",
+		help: "Optional benchmark name or glob pattern (`*` and `?`) to only apply the threshold to matching benchmarks",
+		validate: validOptionBenchmarkGlob,
+	},
 };
 
 const thresholdsConfig = {
@@ -150,6 +158,16 @@ const thresholdsConfig = {
 					validate: true,
 					config: THRESHOLD_FIELDS.measure,
 				},
+				{
+					kind: FieldKind.INPUT,
+					label: "Benchmark",
+					key: "benchmark",
+					value: "",
+					valid: true,
+					validate: true,
+					nullable: true,
+					config: THRESHOLD_FIELDS.benchmark,
+				},
 				{
 					kind: FieldKind.MODEL,
 					label: null,
@@ -223,6 +241,12 @@ const thresholdsConfig = {
 					key: "measure",
 					display: Display.MEASURE,
 				},
+				{
+					kind: Card.FIELD,
+					label: "Benchmark",
+					key: "benchmark",
+					display: Display.RAW,
+				},
 				{
 					kind: Card.NESTED_FIELD,
 					label: "Threshold Model Test",
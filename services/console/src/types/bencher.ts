@@ -6,6 +6,12 @@ export type VersionNumber = number;
 
 export type BenchmarkName = string;
 
+/**
+ * A benchmark name or a glob pattern of benchmark names.
+ * A `*` matches any number of characters and a `?` matches exactly one character.
+ */
+export type BenchmarkGlob = string;
+
 export type NameId = string;
 
 export type MeasureNameId = NameId;
@@ -34,6 +40,11 @@ export interface JsonBenchmark {
 	created: string;
 	modified: string;
 	archived?: string;
+	/**
+	 * The most recent metric for each branch, testbed, and measure.
+	 * This is only included when requested.
+	 */
+	latest?: JsonBenchmarkLatest[];
 }
 
 export type ResourceName = string;
@@ -56,6 +67,17 @@ export interface JsonMetric {
 	upper_value?: number;
 }
 
+/** The most recent metric for a benchmark on the current head of a branch for a testbed and measure. */
+export interface JsonBenchmarkLatest {
+	branch: Uuid;
+	testbed: Uuid;
+	measure: Uuid;
+	report: Uuid;
+	start_time: string;
+	end_time: string;
+	metric: JsonMetric;
+}
+
 export enum ModelTest {
 	Static = "static",
 	Percentage = "percentage",
@@ -64,6 +86,8 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	Percentile = "percentile",
+	Cusum = "cusum",
 }
 
 export type SampleSize = number;
@@ -80,6 +104,8 @@ export interface JsonModel {
 	window?: Window;
 	lower_boundary?: Boundary;
 	upper_boundary?: Boundary;
+	lower_critical?: Boundary;
+	upper_critical?: Boundary;
 	created: string;
 	replaced?: string;
 }
@@ -97,6 +123,16 @@ export interface JsonBoundary {
 	upper_limit?: number;
 }
 
+export interface JsonReportFailure {
+	/** The exit code of the benchmark command. */
+	exit_code: number;
+	/**
+	 * The standard error output of the benchmark command.
+	 * If the output is larger than 64 KB, then only the end of the output is stored.
+	 */
+	stderr: string;
+}
+
 export interface JsonReportMeasure {
 	measure: JsonMeasure;
 	metric: JsonMetric;
@@ -114,10 +150,45 @@ export type JsonReportIteration = JsonReportResult[];
 
 export type JsonReportResults = JsonReportIteration[];
 
+export interface JsonReportRerun {
+	/** The UUID for the report. */
+	report: Uuid;
+	/** The project webhooks that were sent the rerun request. */
+	webhooks: Uuid[];
+	created: string;
+}
+
+export interface JsonAttachment {
+	uuid: Uuid;
+	/** The file name of the attachment. */
+	name: string;
+	/** The media type of the attachment, ie `image/svg+xml`. */
+	content_type: string;
+	/** The size of the attachment in bytes. */
+	size: number;
+	created: string;
+}
+
+export type JsonReportAttachments = JsonAttachment[];
+
 export type BranchName = string;
 
 export type GitHash = string;
 
+/**
+ * An IP address range in CIDR notation, ie `192.0.2.0/24` or `2001:db8::/32`.
+ * A single IP address is also accepted.
+ */
+export type Cidr = string;
+
+/**
+ * A cron schedule with five fields: minute, hour, day of the month, month, and day of the week.
+ * Each field may be `*`, a value, a range (`1-5`), a step (`*/15` or `0-30/10`), or a list of these (`0,30`).
+ * The `@yearly`, `@monthly`, `@weekly`, `@daily`, and `@hourly` shortcuts are also supported.
+ * All times are in UTC.
+ */
+export type Cron = string;
+
 export interface JsonVersion {
 	number: VersionNumber;
 	hash?: GitHash;
@@ -137,6 +208,21 @@ export interface JsonHead {
 	replaced?: string;
 }
 
+export interface JsonBranchProtection {
+	/** The branch can not be deleted. */
+	delete: boolean;
+	/**
+	 * The thresholds for the branch can only be loosened or removed
+	 * by a user with `manage` permissions for the organization.
+	 */
+	thresholds: boolean;
+	/**
+	 * Reports for the branch are only accepted from API tokens
+	 * that are limited to a scope or a single project.
+	 */
+	reports: boolean;
+}
+
 export interface JsonBranch {
 	uuid: Uuid;
 	project: Uuid;
@@ -146,13 +232,18 @@ export interface JsonBranch {
 	created: string;
 	modified: string;
 	archived?: string;
+	merged?: string;
+	protection: JsonBranchProtection;
 }
 
+export type ScaleFactor = number;
+
 export interface JsonTestbed {
 	uuid: Uuid;
 	project: Uuid;
 	name: ResourceName;
 	slug: Slug;
+	scale: ScaleFactor;
 	created: string;
 	modified: string;
 	archived?: string;
@@ -164,16 +255,58 @@ export interface JsonThreshold {
 	branch: JsonBranch;
 	testbed: JsonTestbed;
 	measure: JsonMeasure;
+	benchmark?: BenchmarkGlob;
 	model?: JsonModel;
 	created: string;
 	modified: string;
 }
 
+export interface JsonThresholdTemplate {
+	uuid: Uuid;
+	organization: Uuid;
+	/**
+	 * If set, the template only applies to this project.
+	 * Otherwise, the template applies to all projects in the organization.
+	 */
+	project?: Uuid;
+	measure: Slug;
+	test: ModelTest;
+	min_sample_size?: SampleSize;
+	max_sample_size?: SampleSize;
+	window?: Window;
+	lower_boundary?: Boundary;
+	upper_boundary?: Boundary;
+	lower_critical?: Boundary;
+	upper_critical?: Boundary;
+	created: string;
+	modified: string;
+}
+
 export enum BoundaryLimit {
 	Lower = "lower",
 	Upper = "upper",
 }
 
+export enum AlertKind {
+	/** A single metric exceeded the boundary limit. */
+	Limit = "limit",
+	/**
+	 * A change-point detection model found a statistically significant shift
+	 * in the metric history, such as a gradual regression.
+	 */
+	Drift = "drift",
+}
+
+export enum AlertSeverity {
+	/** The metric exceeded the boundary limit but not the critical boundary limit. */
+	Warning = "warning",
+	/**
+	 * The metric exceeded the critical boundary limit,
+	 * or the threshold model does not have a critical boundary.
+	 */
+	Critical = "critical",
+}
+
 export enum AlertStatus {
 	/** The alert is active. */
 	Active = "active",
@@ -192,6 +325,8 @@ export interface JsonAlert {
 	threshold: JsonThreshold;
 	boundary: JsonBoundary;
 	limit: BoundaryLimit;
+	kind: AlertKind;
+	severity: AlertSeverity;
 	status: AlertStatus;
 	created: string;
 	modified: string;
@@ -380,6 +515,8 @@ export interface JsonUpdateAlert {
 export interface JsonPerfAlert {
 	uuid: Uuid;
 	limit: BoundaryLimit;
+	kind: AlertKind;
+	severity: AlertSeverity;
 	status: AlertStatus;
 	modified: string;
 }
@@ -400,6 +537,127 @@ export interface JsonOneMetric {
 	alert?: JsonPerfAlert;
 }
 
+export enum IntegrationProvider {
+	/** GitHub Issues */
+	GitHub = "github",
+	/** GitLab Issues */
+	GitLab = "gitlab",
+}
+
+export interface JsonNewIntegration {
+	/** The issue tracker provider. */
+	provider: IntegrationProvider;
+	/**
+	 * The repository to open issues in.
+	 * For GitHub, this is `owner/repo`.
+	 * For GitLab, this is the full path of the project, ie `group/project`.
+	 */
+	repository: NonEmpty;
+	/**
+	 * The base URL for the provider API.
+	 * If not set, then the public GitHub or GitLab API is used.
+	 */
+	api_url?: Url;
+	/**
+	 * The API token used to open and close issues.
+	 * It must have permission to write issues for the repository.
+	 */
+	token: Secret;
+	/**
+	 * The template for the issue title.
+	 * If not set, then a default template is used.
+	 */
+	title?: NonEmpty;
+	/**
+	 * The template for the issue body.
+	 * If not set, then a default template is used.
+	 */
+	body?: NonEmpty;
+}
+
+/**
+ * An issue tracker integration for a project.
+ * When a critical alert is generated, an issue is opened.
+ * When a later report no longer generates an alert for the same threshold and benchmark,
+ * the alert is resolved and the issue is closed.
+ * The issue is also closed if the alert is dismissed or silenced.
+ * Templates may use the placeholders `{project}`, `{benchmark}`, `{measure}`, `{branch}`, `{testbed}`,
+ * `{value}`, `{baseline}`, `{delta}`, `{limit}`, `{boundary}`, `{alert_url}`, and `{report_url}`.
+ */
+export interface JsonIntegration {
+	uuid: Uuid;
+	project: Uuid;
+	provider: IntegrationProvider;
+	repository: NonEmpty;
+	api_url: Url;
+	title: NonEmpty;
+	body: NonEmpty;
+	created: string;
+	modified: string;
+}
+
+export interface JsonUpdateIntegration {
+	/** The new repository to open issues in. */
+	repository?: NonEmpty;
+	/** The new base URL for the provider API. */
+	api_url?: Url;
+	/** The new API token used to open and close issues. */
+	token?: Secret;
+	/** The new template for the issue title. */
+	title?: NonEmpty;
+	/** The new template for the issue body. */
+	body?: NonEmpty;
+}
+
+export interface JsonNewWebhook {
+	/** The URL to send the webhook to. */
+	url: Url;
+	/**
+	 * The secret used to sign the webhook payload.
+	 * The secret is never returned.
+	 */
+	secret: Secret;
+}
+
+/**
+ * A webhook for a project.
+ * When a report generates alerts or a rerun is requested for a report,
+ * a signed JSON payload is sent to the webhook URL.
+ */
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	created: string;
+	modified: string;
+}
+
+/** The payload sent to a webhook when a report generates alerts. */
+export interface JsonWebhookPayload {
+	webhook: Uuid;
+	project: JsonProject;
+	report: Uuid;
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	alerts: JsonAlert[];
+	created: string;
+}
+
+/**
+ * The payload sent to a webhook when a rerun is requested for a report.
+ * The branch head version is the version of the code that should be benchmarked again.
+ */
+export interface JsonWebhookRerunPayload {
+	webhook: Uuid;
+	project: JsonProject;
+	report: Uuid;
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	/** The user who requested the rerun. */
+	requester: JsonPubUser;
+	created: string;
+}
+
 export enum Visibility {
 	Public = "public",
 	Private = "private",
@@ -451,6 +709,7 @@ export interface JsonPerfQuery {
 	testbeds: Uuid[];
 	benchmarks: Uuid[];
 	measures: Uuid[];
+	environment?: ResourceName;
 	start_time?: string;
 	end_time?: string;
 }
@@ -475,11 +734,39 @@ export interface JsonPerfMetrics {
 	metrics: JsonPerfMetric[];
 }
 
+export enum JsonPerfAggregate {
+	Sum = "sum",
+	Mean = "mean",
+	Max = "max",
+}
+
+export interface JsonPerfAggregateMetric {
+	report: Uuid;
+	iteration: Iteration;
+	start_time: string;
+	end_time: string;
+	version: JsonVersion;
+	metric: JsonNewMetric;
+	/** The number of benchmarks with a metric for the report iteration */
+	benchmarks: number;
+}
+
+/** The metrics for a set of benchmarks aggregated into a single series */
+export interface JsonPerfAggregateMetrics {
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	measure: JsonMeasure;
+	aggregate: JsonPerfAggregate;
+	benchmarks: JsonBenchmark[];
+	metrics: JsonPerfAggregateMetric[];
+}
+
 export interface JsonPerf {
 	project: JsonProject;
 	start_time?: string;
 	end_time?: string;
 	results: JsonPerfMetrics[];
+	aggregates?: JsonPerfAggregateMetrics[];
 }
 
 export enum XAxis {
@@ -586,19 +873,55 @@ export enum Adapter {
 	RubyBenchmark = "ruby_benchmark",
 	Shell = "shell",
 	ShellHyperfine = "shell_hyperfine",
+	ShellMeasureCommand = "shell_measure_command",
+	ScalaScalameter = "scala_scalameter",
+	PhpBench = "php_bench",
+	SwiftXctest = "swift_xctest",
+}
+
+export enum JsonAdapterConfidence {
+	/** Another adapter parsed more benchmarks than the selected adapter. */
+	Low = "low",
+	/**
+	 * Other adapters were also able to parse the results,
+	 * but none of them parsed more benchmarks than the selected adapter.
+	 */
+	Medium = "medium",
+	/** No other adapter was able to parse the results. */
+	High = "high",
+}
+
+/** The adapter selected by the `magic` adapter to parse the results. */
+export interface JsonAdapterSelection {
+	/** The adapter used to parse the results. */
+	adapter: Adapter;
+	/** How confident the `magic` adapter is that it selected the right adapter. */
+	confidence: JsonAdapterConfidence;
+	/** Any other adapters that were also able to parse the results. */
+	alternatives: Adapter[];
 }
 
 export interface JsonReport {
 	uuid: Uuid;
-	user: JsonPubUser;
+	user?: JsonPubUser;
 	project: JsonProject;
 	branch: JsonBranch;
 	testbed: JsonTestbed;
+	environment?: ResourceName;
 	start_time: string;
 	end_time: string;
 	adapter: Adapter;
+	/**
+	 * The adapter selected to parse the results, if the `magic` adapter was used.
+	 * This is only included in the response when creating a report.
+	 */
+	adapter_selection?: JsonAdapterSelection;
 	results: JsonReportResults;
 	alerts: JsonReportAlerts;
+	/** The artifacts attached to the report, such as flamegraphs. */
+	attachments?: JsonReportAttachments;
+	/** The exit code and standard error output of the benchmark command, if it failed. */
+	failure?: JsonReportFailure;
 	created: string;
 }
 
@@ -707,6 +1030,19 @@ export interface JsonUpdateUser {
 	locked?: boolean;
 }
 
+/**
+ * The scope of an API token.
+ * The `admin` scope includes `report:write`, which includes `project:read`.
+ */
+export enum TokenScope {
+	/** The same permissions as the user. */
+	Admin = "admin",
+	/** View projects. */
+	ProjectRead = "project:read",
+	/** View projects and create reports, along with their branches, testbeds, and measures. */
+	ReportWrite = "report:write",
+}
+
 export interface JsonNewToken {
 	/**
 	 * The name of the token.
@@ -718,6 +1054,21 @@ export interface JsonNewToken {
 	 * If not provided, the token will not expire for over 128 years.
 	 */
 	ttl?: number;
+	/**
+	 * The scope of the token.
+	 * If not provided, the token has the same permissions as the user.
+	 */
+	scope?: TokenScope;
+	/**
+	 * The slug or UUID of the only project the token can access.
+	 * If not provided, the token can access any project the user can.
+	 */
+	project?: ResourceId;
+	/**
+	 * The IP addresses or CIDR ranges that the token can be used from.
+	 * If not provided, the token can be used from any IP address.
+	 */
+	allowed_ips?: Cidr[];
 }
 
 export interface JsonToken {
@@ -727,6 +1078,44 @@ export interface JsonToken {
 	token: Jwt;
 	creation: string;
 	expiration: string;
+	scope?: TokenScope;
+	project?: Uuid;
+	allowed_ips?: Cidr[];
+}
+
+export interface JsonNewProjectToken {
+	/**
+	 * The name of the token.
+	 * Maximum length is 64 characters.
+	 */
+	name: ResourceName;
+	/**
+	 * The time-to-live (TTL) for the token in seconds.
+	 * If not provided, the token will not expire for over 128 years.
+	 */
+	ttl?: number;
+	/**
+	 * The scope of the token within the project.
+	 * If not provided, the token has the `report:write` scope.
+	 */
+	scope?: TokenScope;
+}
+
+/**
+ * An API token for a project.
+ * The token can only access its project, with the permissions of its scope,
+ * regardless of the project role of the user who created it.
+ */
+export interface JsonProjectToken {
+	uuid: Uuid;
+	project: Uuid;
+	/** The user who created the token. */
+	creator: Uuid;
+	name: ResourceName;
+	token: Jwt;
+	scope: TokenScope;
+	creation: string;
+	expiration: string;
 }
 
 export enum OrganizationPermission {
@@ -759,6 +1148,7 @@ export enum PerfQueryKey {
 	Testbeds = "testbeds",
 	Benchmarks = "benchmarks",
 	Measures = "measures",
+	Environment = "environment",
 	StartTime = "start_time",
 	EndTime = "end_time",
 }
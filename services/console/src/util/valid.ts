@@ -1,4 +1,5 @@
 import {
+	is_valid_benchmark_glob,
 	is_valid_benchmark_name,
 	is_valid_boundary,
 	is_valid_branch_name,
@@ -20,6 +21,7 @@ import {
 	is_valid_percentage_boundary,
 	is_valid_cdf_boundary,
 	is_valid_iqr_boundary,
+	is_valid_cusum_boundary,
 	is_valid_model,
 	is_valid_window,
 } from "bencher_valid";
@@ -64,6 +66,10 @@ export const validBranchName = (branch_name: string): boolean =>
 export const validBenchmarkName = (benchmark_name: string): boolean =>
 	validString(benchmark_name, is_valid_benchmark_name);
 
+export const validOptionBenchmarkGlob = (
+	benchmark_glob: undefined | null | string,
+): boolean => validOptionString(benchmark_glob, is_valid_benchmark_glob);
+
 export const validNonEmpty = (non_empty: string): boolean =>
 	validString(non_empty, is_valid_non_empty);
 
@@ -138,6 +144,9 @@ export const validCdfBoundary = (boundary: string): boolean =>
 export const validIqrBoundary = (boundary: string): boolean =>
 	validateNumber(boundary, is_valid_iqr_boundary);
 
+export const validCusumBoundary = (boundary: string): boolean =>
+	validateNumber(boundary, is_valid_cusum_boundary);
+
 export const validSampleSize = (sample_size: string) =>
 	validU32(sample_size) && validateNumber(sample_size, is_valid_sample_size);
 
@@ -0,0 +1,93 @@
+use camino::Utf8PathBuf;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+pub struct TaskTask {
+    #[clap(subcommand)]
+    pub sub: TaskSub,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TaskSub {
+    /// Build the CLI for a release target
+    Build(TaskBuild),
+    /// Strip the CLI bin for a release target
+    Strip(TaskStrip),
+    /// Package the CLI bin for a release target
+    Package(TaskPackage),
+    /// Build, strip, and package the CLI for a release target
+    All(TaskAll),
+}
+
+#[derive(Args, Debug)]
+pub struct TaskTarget {
+    /// Release target
+    #[clap(long)]
+    pub target: TaskReleaseTarget,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum TaskReleaseTarget {
+    /// `x86_64-unknown-linux-musl`
+    #[value(name = "linux-x86-64")]
+    LinuxX86_64,
+    /// `aarch64-unknown-linux-musl`
+    #[value(name = "linux-arm-64")]
+    LinuxArm64,
+    /// `x86_64-apple-darwin`
+    #[value(name = "macos-x86-64")]
+    MacosX86_64,
+    /// `aarch64-apple-darwin`
+    #[value(name = "macos-arm-64")]
+    MacosArm64,
+    /// `x86_64-pc-windows-msvc`
+    #[value(name = "windows-x86-64")]
+    WindowsX86_64,
+    /// `aarch64-pc-windows-msvc`
+    #[value(name = "windows-arm-64")]
+    WindowsArm64,
+}
+
+#[derive(Parser, Debug)]
+pub struct TaskBuild {
+    #[clap(flatten)]
+    pub target: TaskTarget,
+
+    /// Build the minimal CLI (Linux only)
+    #[clap(long)]
+    pub minimal: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct TaskStrip {
+    #[clap(flatten)]
+    pub target: TaskTarget,
+
+    /// Strip tool to use
+    #[clap(long, default_value = "strip")]
+    pub tool: String,
+}
+
+#[derive(Parser, Debug)]
+pub struct TaskPackage {
+    #[clap(flatten)]
+    pub target: TaskTarget,
+
+    /// Package output directory
+    #[clap(long, default_value = "dist")]
+    pub dir: Utf8PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct TaskAll {
+    #[clap(flatten)]
+    pub target: TaskTarget,
+
+    /// Strip tool to use
+    #[clap(long, default_value = "strip")]
+    pub tool: String,
+
+    /// Package output directory
+    #[clap(long, default_value = "dist")]
+    pub dir: Utf8PathBuf,
+}
@@ -0,0 +1,77 @@
+use std::process::Command;
+
+use crate::parser::TaskBuild;
+
+use super::target::{git, workspace_root, ReleaseTarget, PROFILE};
+
+#[derive(Debug)]
+pub struct Build {
+    target: ReleaseTarget,
+    minimal: bool,
+}
+
+impl From<TaskBuild> for Build {
+    fn from(build: TaskBuild) -> Self {
+        let TaskBuild { target, minimal } = build;
+        Self {
+            target: target.target.into(),
+            minimal,
+        }
+    }
+}
+
+impl Build {
+    pub fn target(&self) -> ReleaseTarget {
+        self.target
+    }
+
+    pub fn exec(&self) -> anyhow::Result<()> {
+        // The minimal CLI is only meant to be a static binary for embedded CI runners and containers
+        anyhow::ensure!(
+            !self.minimal || self.target.is_linux(),
+            "The minimal CLI can only be built for Linux targets, not {}",
+            self.target.build()
+        );
+        let root = workspace_root()?;
+        let commit = git(&["rev-parse", "HEAD"])?;
+        // Use the commit timestamp so the same commit always produces the same artifact
+        // https://reproducible-builds.org/docs/source-date-epoch/
+        let source_date_epoch = git(&["log", "-1", "--format=%ct"])?;
+
+        // Linux targets are statically linked against musl using Zig as the linker
+        // https://github.com/rust-cross/cargo-zigbuild
+        let cargo_sub = if self.target.is_linux() {
+            "zigbuild"
+        } else {
+            "build"
+        };
+        println!(
+            "Building {} ({}) at {commit}",
+            self.target.build(),
+            self.target.triple()
+        );
+        let mut cargo = Command::new("cargo");
+        cargo.current_dir(&root).args([
+            cargo_sub,
+            "--package",
+            "bencher_cli",
+            "--profile",
+            PROFILE,
+            "--target",
+            self.target.triple(),
+        ]);
+        if self.minimal {
+            cargo.args(["--no-default-features", "--features", "minimal"]);
+        }
+        let status = cargo
+            .env("BENCHER_CLI_COMMIT", &commit)
+            .env("SOURCE_DATE_EPOCH", &source_date_epoch)
+            // Remove local paths from the bin
+            .env("RUSTFLAGS", format!("--remap-path-prefix={root}=bencher"))
+            .status()?;
+        anyhow::ensure!(status.success(), "Failed to build CLI: {status}");
+
+        println!("Built {}", self.target.bin_path()?);
+        Ok(())
+    }
+}
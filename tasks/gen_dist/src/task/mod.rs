@@ -0,0 +1,79 @@
+use clap::Parser;
+
+use crate::parser::{TaskAll, TaskBuild, TaskSub, TaskTask};
+
+mod build;
+mod package;
+mod strip;
+mod target;
+
+use build::Build;
+use package::Package;
+use strip::Strip;
+
+#[derive(Debug)]
+pub struct Task {
+    sub: Sub,
+}
+
+#[derive(Debug)]
+pub enum Sub {
+    Build(Build),
+    Strip(Strip),
+    Package(Package),
+    All(Build, Strip, Package),
+}
+
+impl TryFrom<TaskTask> for Task {
+    type Error = anyhow::Error;
+
+    fn try_from(task: TaskTask) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sub: task.sub.into(),
+        })
+    }
+}
+
+impl From<TaskSub> for Sub {
+    fn from(sub: TaskSub) -> Self {
+        match sub {
+            TaskSub::Build(build) => Self::Build(build.into()),
+            TaskSub::Strip(strip) => Self::Strip(strip.into()),
+            TaskSub::Package(package) => Self::Package(package.into()),
+            TaskSub::All(all) => {
+                let TaskAll { target, tool, dir } = all;
+                let build = Build::from(TaskBuild {
+                    target,
+                    minimal: false,
+                });
+                let target = build.target();
+                Self::All(build, Strip::new(target, tool), Package::new(target, dir))
+            },
+        }
+    }
+}
+
+impl Task {
+    pub fn new() -> anyhow::Result<Self> {
+        TaskTask::parse().try_into()
+    }
+
+    pub fn exec(&self) -> anyhow::Result<()> {
+        self.sub.exec()
+    }
+}
+
+impl Sub {
+    pub fn exec(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Build(build) => build.exec(),
+            Self::Strip(strip) => strip.exec(),
+            Self::Package(package) => package.exec(),
+            Self::All(build, strip, package) => {
+                build.exec()?;
+                strip.exec()?;
+                package.exec()
+            },
+        }
+    }
+}
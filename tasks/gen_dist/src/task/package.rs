@@ -0,0 +1,93 @@
+use std::{fmt::Write as _, process::Command};
+
+use camino::Utf8PathBuf;
+use sha2::{Digest, Sha256};
+
+use crate::{parser::TaskPackage, API_VERSION};
+
+use super::target::ReleaseTarget;
+
+#[derive(Debug)]
+pub struct Package {
+    target: ReleaseTarget,
+    dir: Utf8PathBuf,
+}
+
+impl From<TaskPackage> for Package {
+    fn from(package: TaskPackage) -> Self {
+        let TaskPackage { target, dir } = package;
+        Self {
+            target: target.target.into(),
+            dir,
+        }
+    }
+}
+
+impl Package {
+    pub fn new(target: ReleaseTarget, dir: Utf8PathBuf) -> Self {
+        Self { target, dir }
+    }
+
+    pub fn exec(&self) -> anyhow::Result<()> {
+        let bin_path = self.target.bin_path()?;
+        anyhow::ensure!(
+            bin_path.exists(),
+            "CLI bin not found ({bin_path}). Run `cargo gen-dist build --target {}` first.",
+            self.target.build()
+        );
+
+        // ie `bencher-v0.4.32-linux-x86-64`
+        let name = format!("bencher-v{API_VERSION}-{}", self.target.build());
+        let stage_dir = self.dir.join(&name);
+        if stage_dir.exists() {
+            std::fs::remove_dir_all(&stage_dir)?;
+        }
+        std::fs::create_dir_all(&stage_dir)?;
+        std::fs::copy(&bin_path, stage_dir.join(self.target.bin_name()))?;
+
+        // `tar` is available on Linux, macOS, and Windows 10+
+        // On Windows, `bsdtar` picks the archive format from the `.zip` extension
+        let archive_name = if self.target.is_windows() {
+            format!("{name}.zip")
+        } else {
+            format!("{name}.tar.gz")
+        };
+        let archive_path = self.dir.join(&archive_name);
+        let mut tar = Command::new("tar");
+        tar.current_dir(&self.dir);
+        if self.target.is_windows() {
+            tar.args(["-a", "-c", "-f", &archive_name, &name]);
+        } else if cfg!(target_os = "macos") {
+            // Normalize ownership so the archive does not depend on the local user
+            tar.args(["--uid", "0", "--gid", "0", "-czf", &archive_name, &name]);
+        } else {
+            tar.args([
+                "--owner=0",
+                "--group=0",
+                "--numeric-owner",
+                "-czf",
+                &archive_name,
+                &name,
+            ]);
+        }
+        let status = tar.status()?;
+        anyhow::ensure!(
+            status.success(),
+            "Failed to create {archive_path}: {status}"
+        );
+        std::fs::remove_dir_all(&stage_dir)?;
+
+        let checksum_path = self.dir.join(format!("{archive_name}.sha256"));
+        let digest = Sha256::digest(std::fs::read(&archive_path)?);
+        let mut checksum = digest.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+        let _ = writeln!(checksum, "  {archive_name}");
+        std::fs::write(&checksum_path, checksum)?;
+
+        println!("Packaged {archive_path}");
+        println!("Checksum {checksum_path}");
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use std::process::Command;
+
+use crate::parser::TaskStrip;
+
+use super::target::ReleaseTarget;
+
+#[derive(Debug)]
+pub struct Strip {
+    target: ReleaseTarget,
+    tool: String,
+}
+
+impl From<TaskStrip> for Strip {
+    fn from(strip: TaskStrip) -> Self {
+        let TaskStrip { target, tool } = strip;
+        Self {
+            target: target.target.into(),
+            tool,
+        }
+    }
+}
+
+impl Strip {
+    pub fn new(target: ReleaseTarget, tool: String) -> Self {
+        Self { target, tool }
+    }
+
+    pub fn exec(&self) -> anyhow::Result<()> {
+        // MSVC already keeps debug info in a separate `.pdb` file
+        if self.target.is_windows() {
+            println!("Skipping strip for {}", self.target.build());
+            return Ok(());
+        }
+
+        let bin_path = self.target.bin_path()?;
+        let size_before = std::fs::metadata(&bin_path)?.len();
+        let status = Command::new(&self.tool).arg(&bin_path).status()?;
+        anyhow::ensure!(status.success(), "Failed to strip {bin_path}: {status}");
+        let size_after = std::fs::metadata(&bin_path)?.len();
+
+        println!("Stripped {bin_path} ({size_before} -> {size_after} bytes)");
+        Ok(())
+    }
+}
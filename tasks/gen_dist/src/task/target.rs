@@ -0,0 +1,105 @@
+use std::process::Command;
+
+use camino::Utf8PathBuf;
+
+use crate::parser::TaskReleaseTarget;
+
+const BIN_NAME: &str = "bencher";
+// Keep in sync with the release profile used in CI
+pub const PROFILE: &str = "release-small";
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReleaseTarget {
+    LinuxX86_64,
+    LinuxArm64,
+    MacosX86_64,
+    MacosArm64,
+    WindowsX86_64,
+    WindowsArm64,
+}
+
+impl From<TaskReleaseTarget> for ReleaseTarget {
+    fn from(target: TaskReleaseTarget) -> Self {
+        match target {
+            TaskReleaseTarget::LinuxX86_64 => Self::LinuxX86_64,
+            TaskReleaseTarget::LinuxArm64 => Self::LinuxArm64,
+            TaskReleaseTarget::MacosX86_64 => Self::MacosX86_64,
+            TaskReleaseTarget::MacosArm64 => Self::MacosArm64,
+            TaskReleaseTarget::WindowsX86_64 => Self::WindowsX86_64,
+            TaskReleaseTarget::WindowsArm64 => Self::WindowsArm64,
+        }
+    }
+}
+
+impl ReleaseTarget {
+    /// The name used for release artifacts, ie `linux-x86-64`
+    pub fn build(self) -> &'static str {
+        match self {
+            Self::LinuxX86_64 => "linux-x86-64",
+            Self::LinuxArm64 => "linux-arm-64",
+            Self::MacosX86_64 => "macos-x86-64",
+            Self::MacosArm64 => "macos-arm-64",
+            Self::WindowsX86_64 => "windows-x86-64",
+            Self::WindowsArm64 => "windows-arm-64",
+        }
+    }
+
+    /// The Rust target triple
+    pub fn triple(self) -> &'static str {
+        match self {
+            Self::LinuxX86_64 => "x86_64-unknown-linux-musl",
+            Self::LinuxArm64 => "aarch64-unknown-linux-musl",
+            Self::MacosX86_64 => "x86_64-apple-darwin",
+            Self::MacosArm64 => "aarch64-apple-darwin",
+            Self::WindowsX86_64 => "x86_64-pc-windows-msvc",
+            Self::WindowsArm64 => "aarch64-pc-windows-msvc",
+        }
+    }
+
+    pub fn is_linux(self) -> bool {
+        matches!(self, Self::LinuxX86_64 | Self::LinuxArm64)
+    }
+
+    pub fn is_windows(self) -> bool {
+        matches!(self, Self::WindowsX86_64 | Self::WindowsArm64)
+    }
+
+    pub fn bin_name(self) -> String {
+        if self.is_windows() {
+            format!("{BIN_NAME}.exe")
+        } else {
+            BIN_NAME.to_owned()
+        }
+    }
+
+    /// The path to the CLI bin once it has been built
+    pub fn bin_path(self) -> anyhow::Result<Utf8PathBuf> {
+        Ok(target_dir()?
+            .join(self.triple())
+            .join(PROFILE)
+            .join(self.bin_name()))
+    }
+}
+
+fn target_dir() -> anyhow::Result<Utf8PathBuf> {
+    if let Ok(target_dir) = std::env::var("CARGO_TARGET_DIR") {
+        return Ok(target_dir.into());
+    }
+    Ok(workspace_root()?.join("target"))
+}
+
+/// The root of the git repository, which is also the root of the Cargo workspace
+pub fn workspace_root() -> anyhow::Result<Utf8PathBuf> {
+    git(&["rev-parse", "--show-toplevel"]).map(Into::into)
+}
+
+pub fn git(args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git").args(args).output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "Failed to run `git {}`: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?.trim().to_owned())
+}
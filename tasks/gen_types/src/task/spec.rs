@@ -2,6 +2,7 @@ use std::fs;
 
 use bencher_api::endpoints::Api;
 use dropshot::{ApiDescription, EndpointTagPolicy, TagConfig, TagDetails};
+use openapiv3::{OpenAPI, ReferenceOr, Response, StatusCode};
 
 use crate::{parser::TaskSpec, API_VERSION};
 
@@ -35,7 +36,7 @@ impl Spec {
             true,
         )
         .map_err(|e| anyhow::anyhow!("Failed to register API: {e}"))?;
-        let mut spec_file = fs::File::create(SPEC_PATH)?;
+        let mut spec = Vec::new();
 
         api_description.tag_config(TagConfig {
             allow_other_tags: false,
@@ -55,13 +56,16 @@ impl Spec {
                 "thresholds" => TagDetails { description: Some("Thresholds".into()), external_docs: None},
                 "models" => TagDetails { description: Some("Models".into()), external_docs: None},
                 "alerts" => TagDetails { description: Some("Alerts".into()), external_docs: None},
+                "integrations" => TagDetails { description: Some("Integrations".into()), external_docs: None},
+                "webhooks" => TagDetails { description: Some("Webhooks".into()), external_docs: None},
                 "users" => TagDetails { description: Some("Users".into()), external_docs: None},
                 "tokens" => TagDetails { description: Some("API Tokens".into()), external_docs: None},
                 "server" => TagDetails { description: Some("Server".into()), external_docs: None},
         }})
             .openapi(bencher_api::config::API_NAME, API_VERSION)
-            .write(&mut spec_file)
+            .write(&mut spec)
             ?;
+        fs::write(SPEC_PATH, add_upsert_responses(&spec)?)?;
 
         println!("Saved OpenAPI JSON file to: {SPEC_PATH}");
 
@@ -73,6 +77,40 @@ impl Spec {
     }
 }
 
+/// `dropshot` only documents a single success response for each operation,
+/// so add the `200 OK` response for an existing resource to each upsert operation.
+fn add_upsert_responses(spec: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut openapi: OpenAPI = serde_json::from_slice(spec)?;
+    for path_item in openapi.paths.paths.values_mut() {
+        let ReferenceOr::Item(path_item) = path_item else {
+            continue;
+        };
+        let Some(operation) = path_item.post.as_mut() else {
+            continue;
+        };
+        if !operation
+            .operation_id
+            .as_deref()
+            .is_some_and(|id| Api::UPSERT_OPERATIONS.contains(&id))
+        {
+            continue;
+        }
+        let responses = &mut operation.responses.responses;
+        let Some(ReferenceOr::Item(created)) = responses.get(&StatusCode::Code(201)) else {
+            anyhow::bail!("No created response found for {:?}", operation.operation_id);
+        };
+        let existing = Response {
+            description: "successful operation".to_owned(),
+            ..created.clone()
+        };
+        responses.shift_insert(0, StatusCode::Code(200), ReferenceOr::Item(existing));
+    }
+
+    let mut spec = serde_json::to_vec_pretty(&openapi)?;
+    spec.push(b'\n');
+    Ok(spec)
+}
+
 pub fn test_spec() -> anyhow::Result<()> {
     let spec_str = fs::read_to_string(SPEC_PATH)?;
     let spec: bencher_json::JsonSpec = serde_json::from_str(&spec_str)?;
@@ -1279,6 +1279,24 @@ impl SeedTest {
         let _alert: bencher_json::JsonAlert =
             serde_json::from_slice(&assert.get_output().stdout).unwrap();
 
+        // cargo run -- project schema --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "schema",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let schema: bencher_json::JsonProjectSchema =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(schema.slug.as_ref(), PROJECT_SLUG);
+        assert!(!schema.thresholds.is_empty(), "{schema:?}");
+
         std::thread::sleep(std::time::Duration::from_secs(1));
 
         // If the start point is missing, then the branch should just be reset
@@ -1360,6 +1378,39 @@ impl SeedTest {
             "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
         );
 
+        // Run the benchmark command with each of the shells available on the platform
+        // cargo run -- run --host http://localhost:61016 --token $BENCHER_API_TOKEN --project the-computer --branch master --testbed base --dry-run --shell powershell "bencher mock"
+        let shells: &[&str] = if cfg!(target_family = "windows") {
+            &["cmd", "powershell"]
+        } else {
+            &["sh"]
+        };
+        for shell in shells {
+            let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+            let bencher_cmd = cmd.get_program().to_string_lossy().to_string();
+            cmd.args([
+                "run",
+                HOST_ARG,
+                host,
+                TOKEN_ARG,
+                token,
+                PROJECT_ARG,
+                PROJECT_SLUG,
+                BRANCH_ARG,
+                BRANCH_SLUG,
+                TESTBED_ARG,
+                TESTBED_SLUG,
+                "--dry-run",
+                "--shell",
+                shell,
+                &format!("{bencher_cmd} mock --measure latency"),
+            ])
+            .current_dir(CLI_DIR);
+            let assert = cmd.assert().success();
+            let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+            assert!(stdout.contains("bencher::mock_0"), "{shell}: {stdout}");
+        }
+
         Ok(())
     }
 }